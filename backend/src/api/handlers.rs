@@ -1,21 +1,97 @@
+use std::collections::HashMap;
 use actix_web::{web, HttpResponse, Responder};
-use chrono::Utc;
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use utoipa::ToSchema;
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::api::{AppState, error_response, success_response};
-use crate::strategy::{StrategyParams, TradeDirection, TimeInForce};
-use crate::order::{Order, OrderType};
+use crate::api::{AppState, ErrorResponse, SuccessResponse, ValidationErrorResponse, SYMBOL_RE, error_response, not_found_response, success_response, trading_error_response, validate_request, validation_summary};
+use crate::exchange::{AccountBalance, AccountMargin, AccountTransaction, Position};
+use crate::market_data::{DataQualityStats, FundingRate, OrderBookDepth};
+use crate::strategy::{hot_swap_shared_strategy, AssetData, HotSwapTransition, SelectionObjective, StrategyParams, StrategyResult, TradeDirection, TimeInForce};
+use crate::order::{Execution, JournalEntry, Order, OrderAmendment, OrderHistoryFilter, OrderStatistics, OrderStatus, OrderType, TwapExecution, TwapExecutor, TwapProgress};
+use crate::risk::{CircuitBreakerStatus, DrawdownSnapshot, PositionHedger, VarMethod, MIN_VAR_OBSERVATIONS};
+use crate::models::{CorrelationEntry, Price};
+use crate::position::{AccountPnl, StrategyPnl};
+use crate::notifications::Notification;
+use crate::channel::ChannelStats;
+use crate::backtest::{BacktestComparison, BacktestRun, BacktestSpec, MonteCarloJob, MonteCarloJobStatus, MAX_MONTE_CARLO_ITERATIONS};
+
+/// Service status, with what currently stops orders from being placed
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct HealthResponse {
+    pub status: String,
+    pub timestamp: DateTime<Utc>,
+    pub trading_halted: bool,
+    pub circuit_breaker: Option<CircuitBreakerStatus>, // None when no breaker is configured
+    pub disabled_symbols: Vec<String>,
+}
 
 // Health check handler
-pub async fn health_check() -> impl Responder {
-    HttpResponse::Ok().json(serde_json::json!({
-        "status": "ok",
-        "timestamp": Utc::now().to_rfc3339(),
-    }))
+#[utoipa::path(
+    get,
+    path = "/api/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "Service is healthy; includes the circuit breaker state when one is configured and any symbols disabled for trading", body = HealthResponse)
+    )
+)]
+pub async fn health_check(
+    state: web::Data<AppState>,
+) -> impl Responder {
+    let order_manager = state.order_manager.read().await;
+    let circuit_breaker = order_manager.circuit_breaker_status().await;
+    let disabled_symbols = order_manager.disabled_symbols().await;
+    
+    HttpResponse::Ok().json(HealthResponse {
+        status: "ok".to_string(),
+        timestamp: Utc::now(),
+        trading_halted: circuit_breaker.as_ref().map(|status| status.halted).unwrap_or(false),
+        circuit_breaker,
+        disabled_symbols,
+    })
+}
+
+/// Event channel metrics, including events dropped by backpressure
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct EventChannelMetrics {
+    pub order_events: ChannelStats,
+    pub market_events: ChannelStats,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/metrics",
+    tag = "health",
+    responses(
+        (status = 200, description = "Queue depth and dropped events of the order and market data event channels", body = SuccessResponse<EventChannelMetrics>)
+    )
+)]
+pub async fn get_metrics(
+    state: web::Data<AppState>,
+) -> impl Responder {
+    let order_events = state.order_manager.read().await.event_channel_stats();
+    let market_events = state.market_data_manager.read().await.event_channel_stats();
+    
+    success_response(EventChannelMetrics { order_events, market_events })
 }
 
 // Market data handlers
+#[utoipa::path(
+    get,
+    path = "/api/market/data/{symbol}",
+    tag = "market",
+    params(
+        ("symbol" = String, Path, description = "Symbol to fetch market data for")
+    ),
+    responses(
+        (status = 200, description = "Current market data for the symbol", body = SuccessResponse<AssetData>),
+        (status = 400, description = "No data available for the symbol", body = ErrorResponse)
+    )
+)]
 pub async fn get_market_data(
     state: web::Data<AppState>,
     path: web::Path<String>,
@@ -37,6 +113,67 @@ pub async fn get_market_data(
     }
 }
 
+#[derive(Deserialize, Validate)]
+pub struct QuotesQuery {
+    #[validate(length(min = 1, max = 1000, message = "must be 1 to 1000 characters"))]
+    symbols: String, // Comma-separated
+}
+
+/// Market data for each requested symbol that has any
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct QuotesResponse {
+    pub quotes: HashMap<String, AssetData>,
+    pub missing: Vec<String>, // Requested symbols with no data, in the order requested
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/market/quotes",
+    tag = "market",
+    params(
+        ("symbols" = String, Query, description = "Comma-separated symbols to fetch market data for")
+    ),
+    responses(
+        (status = 200, description = "Current market data by symbol, with the symbols that had none", body = SuccessResponse<QuotesResponse>),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn get_quotes(
+    state: web::Data<AppState>,
+    query: web::Query<QuotesQuery>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
+    
+    let current_data = state.market_data_manager.read().await.get_current_data();
+    let data = current_data.read().await;
+    
+    let mut quotes = HashMap::new();
+    let mut missing = Vec::new();
+    for symbol in query.symbols.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        if quotes.contains_key(symbol) || missing.iter().any(|m| m == symbol) {
+            continue;
+        }
+        match data.asset_data.get(symbol) {
+            Some(asset_data) => {
+                quotes.insert(symbol.to_string(), asset_data.clone());
+            },
+            None => missing.push(symbol.to_string()),
+        }
+    }
+    
+    success_response(QuotesResponse { quotes, missing })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/market/symbols",
+    tag = "market",
+    responses(
+        (status = 200, description = "All symbols with market data", body = SuccessResponse<Vec<String>>)
+    )
+)]
 pub async fn get_symbols(
     state: web::Data<AppState>,
 ) -> impl Responder {
@@ -52,7 +189,102 @@ pub async fn get_symbols(
     success_response(symbols)
 }
 
+/// Price levels returned per side when no depth is requested
+const DEFAULT_ORDER_BOOK_DEPTH: usize = 20;
+
+#[derive(Deserialize)]
+pub struct OrderBookQuery {
+    depth: Option<usize>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/market/orderbook/{symbol}",
+    tag = "market",
+    params(
+        ("symbol" = String, Path, description = "Symbol to fetch the order book for"),
+        ("depth" = Option<usize>, Query, description = "Price levels per side (default 20)")
+    ),
+    responses(
+        (status = 200, description = "Best bids and asks, best price first", body = SuccessResponse<OrderBookDepth>),
+        (status = 404, description = "No order book for the symbol", body = ErrorResponse)
+    )
+)]
+pub async fn get_order_book(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    query: web::Query<OrderBookQuery>,
+) -> impl Responder {
+    let symbol = path.into_inner();
+    let depth = query.depth.unwrap_or(DEFAULT_ORDER_BOOK_DEPTH);
+    
+    let book = state.market_data_manager.read().await.get_order_book(&symbol);
+    match book {
+        Some(book) => success_response(book.read().unwrap().depth(depth)),
+        None => not_found_response(&format!("No order book for symbol: {}", symbol)),
+    }
+}
+
+/// A perpetual contract's funding rate and the time left until it is paid
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct FundingRateStatus {
+    #[serde(flatten)]
+    pub funding_rate: FundingRate,
+    pub seconds_to_next_funding: i64, // Zero once the payment is due
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/market/funding/{symbol}",
+    tag = "market",
+    params(
+        ("symbol" = String, Path, description = "Perpetual futures symbol to fetch the funding rate for")
+    ),
+    responses(
+        (status = 200, description = "Latest funding rate with a countdown to the next payment", body = SuccessResponse<FundingRateStatus>),
+        (status = 404, description = "No funding rate for the symbol", body = ErrorResponse)
+    )
+)]
+pub async fn get_funding_rate(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let symbol = path.into_inner();
+    
+    let funding_rate = state.market_data_manager.read().await.get_funding_monitor().get(&symbol);
+    match funding_rate {
+        Some(funding_rate) => {
+            let seconds_to_next_funding = funding_rate.time_to_next_funding(Utc::now()).num_seconds();
+            success_response(FundingRateStatus { funding_rate, seconds_to_next_funding })
+        },
+        None => not_found_response(&format!("No funding rate for symbol: {}", symbol)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/market/data-quality",
+    tag = "market",
+    responses(
+        (status = 200, description = "Price updates received and dropped as erroneous, by failed check", body = SuccessResponse<DataQualityStats>)
+    )
+)]
+pub async fn get_data_quality(
+    state: web::Data<AppState>,
+) -> impl Responder {
+    let validator = state.market_data_manager.read().await.get_data_quality_validator();
+    success_response(validator.stats())
+}
+
 // Strategy handlers
+#[utoipa::path(
+    get,
+    path = "/api/strategy",
+    tag = "strategy",
+    responses(
+        (status = 200, description = "Names of all available strategies", body = SuccessResponse<Vec<String>>)
+    )
+)]
 pub async fn get_strategies(
     state: web::Data<AppState>,
 ) -> impl Responder {
@@ -72,45 +304,87 @@ pub async fn get_strategies(
     success_response(strategies)
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/strategy/active",
+    tag = "strategy",
+    responses(
+        (status = 200, description = "Name of the active strategy, or null when none is set", body = SuccessResponse<Option<String>>)
+    )
+)]
 pub async fn get_active_strategy(
     state: web::Data<AppState>,
 ) -> impl Responder {
-    // Get strategy manager
-    let _strategy_manager = state.strategy_manager.read().await;
-    
-    // TODO: Implement this function in StrategyManager
-    // For now, return mock data
-    let active_strategy = "Statistical Arbitrage".to_string();
-    
-    success_response(active_strategy)
+    let strategy_manager = state.strategy_manager.read().await;
+    success_response(strategy_manager.active_strategy())
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema, Validate)]
 pub struct SetActiveStrategyRequest {
+    #[validate(length(min = 1, max = 100, message = "must be 1 to 100 characters"))]
     name: String,
+    #[serde(default)]
+    transition: HotSwapTransition, // What happens to the outgoing strategy's open orders
+}
+
+/// Outcome of a change made through the API
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct MessageResponse {
+    pub success: bool,
+    pub message: String,
+}
+
+impl MessageResponse {
+    fn success(message: String) -> Self {
+        MessageResponse { success: true, message }
+    }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/strategy/active",
+    tag = "strategy",
+    request_body = SetActiveStrategyRequest,
+    responses(
+        (status = 200, description = "Active strategy updated", body = SuccessResponse<MessageResponse>),
+        (status = 400, description = "The outgoing strategy's orders could not be cancelled", body = ErrorResponse),
+        (status = 404, description = "Strategy not found", body = ErrorResponse),
+        (status = 409, description = "The outgoing strategy's orders did not finish in time", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
 pub async fn set_active_strategy(
     state: web::Data<AppState>,
     req: web::Json<SetActiveStrategyRequest>,
 ) -> impl Responder {
-    // Get strategy manager
-    let mut strategy_manager = state.strategy_manager.write().await;
+    if let Some(response) = validate_request(&*req) {
+        return response;
+    }
     
-    // Try to set the active strategy
-    match strategy_manager.set_active_strategy(&req.name) {
+    let order_manager = state.order_manager.read().await;
+    
+    match hot_swap_shared_strategy(&state.strategy_manager, &req.name, req.transition, &order_manager).await {
         Ok(()) => {
-            success_response(serde_json::json!({
-                "success": true,
-                "message": format!("Active strategy set to: {}", req.name),
-            }))
+            success_response(MessageResponse::success(format!("Active strategy set to: {}", req.name)))
         },
         Err(e) => {
-            error_response(&e)
+            trading_error_response(&e)
         }
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/strategy/{name}/params",
+    tag = "strategy",
+    params(
+        ("name" = String, Path, description = "Strategy name")
+    ),
+    responses(
+        (status = 200, description = "Current strategy parameters", body = SuccessResponse<serde_json::Value>),
+        (status = 404, description = "Strategy not found", body = ErrorResponse)
+    )
+)]
 pub async fn get_strategy_params(
     _state: web::Data<AppState>,
     path: web::Path<String>,
@@ -159,13 +433,27 @@ pub async fn get_strategy_params(
             })
         },
         _ => {
-            return error_response(&format!("Strategy not found: {}", name));
+            return not_found_response(&format!("Strategy not found: {}", name));
         }
     };
     
     success_response(params)
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/strategy/{name}/params",
+    tag = "strategy",
+    params(
+        ("name" = String, Path, description = "Strategy name")
+    ),
+    request_body(content = serde_json::Value, description = "Map of parameter names to new values"),
+    responses(
+        (status = 200, description = "Parameters updated", body = SuccessResponse<MessageResponse>),
+        (status = 400, description = "Invalid parameters", body = ErrorResponse),
+        (status = 404, description = "Strategy not found", body = ErrorResponse)
+    )
+)]
 pub async fn update_strategy_params(
     state: web::Data<AppState>,
     path: web::Path<String>,
@@ -188,20 +476,56 @@ pub async fn update_strategy_params(
     // Update the strategy parameters
     match strategy_manager.update_strategy_params(&name, strategy_params) {
         Ok(()) => {
-            success_response(serde_json::json!({
-                "success": true,
-                "message": format!("Updated parameters for strategy: {}", name),
-            }))
+            success_response(MessageResponse::success(format!("Updated parameters for strategy: {}", name)))
         },
         Err(e) => {
-            error_response(&e)
+            trading_error_response(&e)
         }
     }
 }
 
+#[derive(Deserialize)]
+pub struct EvaluateStrategiesQuery {
+    objective: Option<SelectionObjective>,
+}
+
+/// How one strategy scored in an evaluation
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct StrategyEvaluation {
+    pub strategy: String,
+    pub confidence: f64,
+    pub expected_profit: f64,
+    pub required_capital: f64, // Notional of the strategy's signals at current prices
+    pub signals: usize,
+    pub is_best: bool,
+}
+
+/// Every strategy's evaluation against the current market data
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct StrategyEvaluationsResponse {
+    pub timestamp: DateTime<Utc>, // Of the market data evaluated
+    pub objective: SelectionObjective,
+    pub results: Vec<StrategyEvaluation>,
+    pub best_strategy: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/strategy/evaluate",
+    tag = "strategy",
+    params(
+        ("objective" = Option<SelectionObjective>, Query, description = "profit (default) picks the highest score; roi the highest score per unit of required capital")
+    ),
+    responses(
+        (status = 200, description = "Evaluation results for all strategies, with the capital each one's signals require", body = SuccessResponse<StrategyEvaluationsResponse>)
+    )
+)]
 pub async fn evaluate_strategies(
     state: web::Data<AppState>,
+    query: web::Query<EvaluateStrategiesQuery>,
 ) -> impl Responder {
+    let objective = query.objective.unwrap_or_default();
+    
     // Get strategy manager and market data
     let strategy_manager = state.strategy_manager.read().await;
     let market_data_manager = state.market_data_manager.read().await;
@@ -214,83 +538,166 @@ pub async fn evaluate_strategies(
     let results = strategy_manager.evaluate_strategies(&data);
     
     // Get the best strategy
-    let best_strategy = strategy_manager.get_best_strategy(&results);
+    let best_strategy = strategy_manager.get_best_strategy_for(&results, &data, objective);
     
-    // Format the results for response
-    let formatted_results: serde_json::Value = serde_json::json!({
-        "timestamp": data.timestamp.to_rfc3339(),
-        "results": results.iter().map(|(name, result)| {
-            serde_json::json!({
-                "strategy": name,
-                "confidence": result.confidence,
-                "expected_profit": result.expected_profit,
-                "signals": result.signals.len(),
-                "is_best": best_strategy.as_ref().map_or(false, |best| best == name),
-            })
-        }).collect::<Vec<_>>(),
-        "best_strategy": best_strategy,
-    });
+    let results = results.iter().map(|(name, result)| StrategyEvaluation {
+        strategy: name.clone(),
+        confidence: result.confidence,
+        expected_profit: result.expected_profit,
+        required_capital: result.required_capital(&data),
+        signals: result.signals.len(),
+        is_best: best_strategy.as_ref() == Some(name),
+    }).collect();
+    
+    success_response(StrategyEvaluationsResponse {
+        timestamp: data.timestamp,
+        objective,
+        results,
+        best_strategy,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/strategy/{name}/evaluate",
+    tag = "strategy",
+    params(
+        ("name" = String, Path, description = "Strategy name")
+    ),
+    responses(
+        (status = 200, description = "Evaluation result including individual signals", body = SuccessResponse<StrategyResult>),
+        (status = 404, description = "Strategy not registered", body = ErrorResponse)
+    )
+)]
+pub async fn evaluate_strategy(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let name = path.into_inner();
+    
+    // Get strategy manager and market data
+    let strategy_manager = state.strategy_manager.read().await;
+    let market_data_manager = state.market_data_manager.read().await;
     
-    success_response(formatted_results)
+    // Get current market data
+    let current_data = market_data_manager.get_current_data();
+    let data = current_data.read().await;
+    
+    match strategy_manager.evaluate_one(&name, &data) {
+        Some(result) => success_response(result),
+        None => not_found_response(&format!("Strategy not found: {}", name)),
+    }
 }
 
 // Order handlers
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema, Validate)]
 pub struct PlaceOrderRequest {
+    #[validate(
+        length(min = 1, max = 20, message = "must be 1 to 20 characters"),
+        regex(path = *SYMBOL_RE, message = "must be letters and digits, optionally joined by / . _ or -")
+    )]
     symbol: String,
+    #[validate(length(max = 20, message = "must be at most 20 characters"))]
     direction: String, // "buy" or "sell"
+    #[validate(length(max = 20, message = "must be at most 20 characters"))]
     order_type: String, // "market", "limit", etc.
+    #[validate(range(min = 0.000001, message = "must be at least 0.000001"))]
     quantity: f64,
-    price: Option<f64>,
-    stop_price: Option<f64>,
+    #[validate(custom(function = "validate_positive_price"))]
+    price: Option<Price>, // Decimal string or number
+    #[validate(custom(function = "validate_positive_price"))]
+    stop_price: Option<Price>,
+    #[validate(length(max = 20, message = "must be at most 20 characters"))]
     time_in_force: Option<String>, // "gtc", "ioc", etc.
+    #[validate(length(max = 100, message = "must be at most 100 characters"))]
     strategy_id: Option<String>,
+    #[validate(length(max = 50, message = "must have at most 50 entries"))]
+    tags: Option<Vec<String>>, // Labels for filtering, e.g. "hedging"
+    post_only: Option<bool>, // Reject rather than take liquidity; limit orders only
+}
+
+fn validate_positive_price(price: &Price) -> Result<(), validator::ValidationError> {
+    if price.is_sign_positive() {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("range").with_message("must be positive".into()))
+    }
 }
 
+/// An order accepted for routing
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct PlaceOrderResponse {
+    pub order_id: Uuid,
+    pub status: OrderStatus,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/order",
+    tag = "order",
+    request_body = PlaceOrderRequest,
+    responses(
+        (status = 200, description = "Order accepted", body = SuccessResponse<PlaceOrderResponse>),
+        (status = 400, description = "Invalid order, or rejected by the exchange", body = ErrorResponse),
+        (status = 409, description = "Order breaches a risk limit", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse),
+        (status = 503, description = "No exchange can take the order", body = ErrorResponse)
+    )
+)]
 pub async fn place_order(
     state: web::Data<AppState>,
     req: web::Json<PlaceOrderRequest>,
 ) -> impl Responder {
-    // Convert request to Order
-    let direction = match req.direction.to_lowercase().as_str() {
-        "buy" => TradeDirection::Buy,
-        "sell" => TradeDirection::Sell,
-        _ => return error_response("Invalid direction: must be 'buy' or 'sell'"),
-    };
+    if let Some(response) = validate_request(&*req) {
+        return response;
+    }
     
-    let order_type = match req.order_type.to_lowercase().as_str() {
-        "market" => OrderType::Market,
-        "limit" => OrderType::Limit,
-        "stop" | "stoploss" => OrderType::StopLoss,
-        "stoplimit" => OrderType::StopLimit,
-        "trailingstop" => OrderType::TrailingStop,
-        _ => return error_response("Invalid order type"),
+    let order = match order_from_request(&req) {
+        Ok(order) => order,
+        Err(e) => return error_response(&e),
     };
     
+    // Get order manager
+    let order_manager = state.order_manager.read().await;
+    
+    // Place the order
+    match order_manager.place_order(order).await {
+        Ok(order_id) => {
+            success_response(PlaceOrderResponse { order_id, status: OrderStatus::Created })
+        },
+        Err(e) => {
+            trading_error_response(&e)
+        }
+    }
+}
+
+// Convert a request to an order, checking the basic order parameters
+fn order_from_request(req: &PlaceOrderRequest) -> Result<Order, String> {
+    req.validate().map_err(|errors| validation_summary(&errors))?;
+    
+    let direction: TradeDirection = req.direction.parse()
+        .map_err(|_| "Invalid direction: must be 'buy' or 'sell'".to_string())?;
+    let order_type: OrderType = req.order_type.parse()
+        .map_err(|_| "Invalid order type".to_string())?;
     let time_in_force = match req.time_in_force.as_deref() {
-        Some("ioc") => TimeInForce::ImmediateOrCancel,
-        Some("fok") => TimeInForce::FillOrKill,
-        Some("gtc") => TimeInForce::GoodTilCancelled,
-        Some("day") => TimeInForce::Day,
+        Some(time_in_force) => time_in_force.parse().map_err(|_| "Invalid time in force".to_string())?,
         None => TimeInForce::GoodTilCancelled,
-        _ => return error_response("Invalid time in force"),
     };
     
     // Validate basic order parameters
     if req.quantity <= 0.0 {
-        return error_response("Quantity must be positive");
+        return Err("Quantity must be positive".to_string());
     }
     
     if order_type == OrderType::Limit && req.price.is_none() {
-        return error_response("Limit orders require a price");
+        return Err("Limit orders require a price".to_string());
     }
     
     if (order_type == OrderType::StopLoss || order_type == OrderType::StopLimit) && req.stop_price.is_none() {
-        return error_response("Stop orders require a stop price");
+        return Err("Stop orders require a stop price".to_string());
     }
     
-    // Create order object
-    let order = Order {
+    Ok(Order {
         id: Uuid::new_v4(),
         client_order_id: format!("API-{}", Uuid::new_v4().as_simple()),
         symbol: req.symbol.clone(),
@@ -307,59 +714,309 @@ pub async fn place_order(
         updated_at: Utc::now(),
         filled_at: None,
         average_fill_price: None,
+        unfilled_quantity: None,
         strategy_id: req.strategy_id.clone(),
         notes: None,
+        tags: req.tags.clone().unwrap_or_default(),
+        post_only: req.post_only.unwrap_or(false),
+        amendment_history: Vec::new(),
+    })
+}
+
+/// Outcome of one order in a batch, at its position in the request
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct BatchOrderResult {
+    pub index: usize,
+    pub order_id: Option<String>,
+    pub error: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/order/batch",
+    tag = "order",
+    request_body = Vec<PlaceOrderRequest>,
+    responses(
+        (status = 200, description = "Result of each order, in request order", body = SuccessResponse<Vec<BatchOrderResult>>),
+        (status = 400, description = "Empty batch", body = ErrorResponse)
+    )
+)]
+pub async fn place_orders(
+    state: web::Data<AppState>,
+    req: web::Json<Vec<PlaceOrderRequest>>,
+) -> impl Responder {
+    if req.is_empty() {
+        return error_response("Batch contains no orders");
+    }
+    
+    // Orders that fail conversion keep their error; the rest are placed together
+    let converted: Vec<Result<Order, String>> = req.iter().map(order_from_request).collect();
+    let valid_orders: Vec<Order> = converted.iter()
+        .filter_map(|order| order.as_ref().ok().cloned())
+        .collect();
+    
+    let order_manager = state.order_manager.read().await;
+    let mut placed = order_manager.place_orders(valid_orders).await.into_iter();
+    
+    let results: Vec<BatchOrderResult> = converted.into_iter()
+        .enumerate()
+        .map(|(index, order)| {
+            let outcome = order.and_then(|_| placed.next().expect("one placement per valid order").map_err(|e| e.to_string()));
+            match outcome {
+                Ok(order_id) => BatchOrderResult { index, order_id: Some(order_id.to_string()), error: None },
+                Err(e) => BatchOrderResult { index, order_id: None, error: Some(e) },
+            }
+        })
+        .collect();
+    
+    success_response(results)
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct TwapOrderRequest {
+    #[serde(flatten)]
+    #[validate(nested)]
+    order: PlaceOrderRequest, // The parent order, split into equal slices
+    duration_secs: u64, // Time over which the slices are spread
+    num_slices: usize,
+}
+
+/// A TWAP execution's child orders and progress
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct TwapExecutionResponse {
+    pub parent_id: Uuid,
+    pub child_ids: Vec<Uuid>,
+    pub progress: TwapProgress,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filled_quantity: Option<f64>, // Summed over the children; only when looked up after starting
+}
+
+impl TwapExecutionResponse {
+    fn new(execution: &TwapExecution, filled_quantity: Option<f64>) -> Self {
+        TwapExecutionResponse {
+            parent_id: execution.parent_id,
+            child_ids: execution.child_ids.clone(),
+            progress: execution.progress_snapshot(),
+            filled_quantity,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/order/twap",
+    tag = "order",
+    request_body = TwapOrderRequest,
+    responses(
+        (status = 200, description = "TWAP execution started; slices are submitted in the background", body = SuccessResponse<TwapExecutionResponse>),
+        (status = 400, description = "Invalid order or schedule", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn place_twap_order(
+    state: web::Data<AppState>,
+    req: web::Json<TwapOrderRequest>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*req) {
+        return response;
+    }
+    
+    let order = match order_from_request(&req.order) {
+        Ok(order) => order,
+        Err(e) => return error_response(&e),
+    };
+    
+    let executor = match TwapExecutor::new(std::time::Duration::from_secs(req.duration_secs), req.num_slices) {
+        Ok(executor) => executor,
+        Err(e) => return error_response(&e),
+    };
+    
+    match executor.execute(order, state.order_manager.clone()).await {
+        Ok(execution) => success_response(TwapExecutionResponse::new(&execution, None)),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/order/twap/{parent_id}",
+    tag = "order",
+    params(
+        ("parent_id" = String, Path, description = "Parent order ID returned when the TWAP was started")
+    ),
+    responses(
+        (status = 200, description = "Child order IDs, submission progress and quantity filled so far", body = SuccessResponse<TwapExecutionResponse>),
+        (status = 400, description = "Invalid parent order ID", body = ErrorResponse),
+        (status = 404, description = "Unknown TWAP execution", body = ErrorResponse)
+    )
+)]
+pub async fn get_twap_execution(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let parent_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => return error_response("Invalid parent order ID format"),
     };
     
-    // Get order manager
     let order_manager = state.order_manager.read().await;
+    let execution = match order_manager.get_twap_execution(parent_id).await {
+        Some(execution) => execution,
+        None => return not_found_response(&format!("TWAP execution not found: {}", parent_id)),
+    };
     
-    // Place the order
-    match order_manager.place_order(order).await {
-        Ok(order_id) => {
-            success_response(serde_json::json!({
-                "order_id": order_id.to_string(),
-                "status": "created",
-            }))
-        },
-        Err(e) => {
-            error_response(&e)
+    let mut filled_quantity = 0.0;
+    for child_id in &execution.child_ids {
+        if let Some(child) = order_manager.get_order(*child_id).await {
+            filled_quantity += child.filled_quantity;
         }
     }
+    
+    success_response(TwapExecutionResponse::new(&execution, Some(filled_quantity)))
+}
+
+/// An order as listed, without its routing and fill details
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct OrderSummaryResponse {
+    pub id: Uuid,
+    pub symbol: String,
+    pub direction: TradeDirection,
+    pub order_type: OrderType,
+    pub quantity: f64,
+    pub filled_quantity: f64,
+    pub price: Option<Price>,
+    pub stop_price: Option<Price>,
+    pub status: OrderStatus,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Order> for OrderSummaryResponse {
+    fn from(order: &Order) -> Self {
+        OrderSummaryResponse {
+            id: order.id,
+            symbol: order.symbol.clone(),
+            direction: order.direction,
+            order_type: order.order_type.clone(),
+            quantity: order.quantity,
+            filled_quantity: order.filled_quantity,
+            price: order.price,
+            stop_price: order.stop_price,
+            status: order.status.clone(),
+            tags: order.tags.clone(),
+            created_at: order.created_at,
+            updated_at: order.updated_at,
+        }
+    }
+}
+
+/// Everything known about an order
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct OrderResponse {
+    pub id: Uuid,
+    pub client_order_id: String,
+    pub symbol: String,
+    pub direction: TradeDirection,
+    pub order_type: OrderType,
+    pub quantity: f64,
+    pub filled_quantity: f64,
+    pub price: Option<Price>,
+    pub stop_price: Option<Price>,
+    pub time_in_force: TimeInForce,
+    pub status: OrderStatus,
+    pub exchange: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub filled_at: Option<DateTime<Utc>>,
+    pub average_fill_price: Option<Price>,
+    pub unfilled_quantity: Option<f64>,
+    pub strategy_id: Option<String>,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+    pub post_only: bool,
+    pub amendment_history: Vec<OrderAmendment>,
+}
+
+impl From<Order> for OrderResponse {
+    fn from(order: Order) -> Self {
+        OrderResponse {
+            id: order.id,
+            client_order_id: order.client_order_id,
+            symbol: order.symbol,
+            direction: order.direction,
+            order_type: order.order_type,
+            quantity: order.quantity,
+            filled_quantity: order.filled_quantity,
+            price: order.price,
+            stop_price: order.stop_price,
+            time_in_force: order.time_in_force,
+            status: order.status,
+            exchange: order.exchange,
+            created_at: order.created_at,
+            updated_at: order.updated_at,
+            filled_at: order.filled_at,
+            average_fill_price: order.average_fill_price,
+            unfilled_quantity: order.unfilled_quantity,
+            strategy_id: order.strategy_id,
+            notes: order.notes,
+            tags: order.tags,
+            post_only: order.post_only,
+            amendment_history: order.amendment_history,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate)]
+pub struct OrdersQuery {
+    #[validate(length(max = 100, message = "must be at most 100 characters"))]
+    tag: Option<String>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/order",
+    tag = "order",
+    params(
+        ("tag" = Option<String>, Query, description = "Return every order carrying this tag, including finished ones")
+    ),
+    responses(
+        (status = 200, description = "All active orders, or all orders with the given tag", body = SuccessResponse<Vec<OrderSummaryResponse>>),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
 pub async fn get_orders(
     state: web::Data<AppState>,
+    query: web::Query<OrdersQuery>,
 ) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
+    
     // Get order manager
     let order_manager = state.order_manager.read().await;
     
-    // Get active orders
-    let orders = order_manager.get_active_orders().await;
-    
-    // Format orders for response
-    let formatted_orders: Vec<serde_json::Value> = orders.iter().map(|order| {
-        serde_json::json!({
-            "id": order.id.to_string(),
-            "symbol": order.symbol,
-            "direction": match order.direction {
-                TradeDirection::Buy => "buy",
-                TradeDirection::Sell => "sell",
-            },
-            "order_type": format!("{:?}", order.order_type).to_lowercase(),
-            "quantity": order.quantity,
-            "filled_quantity": order.filled_quantity,
-            "price": order.price,
-            "stop_price": order.stop_price,
-            "status": format!("{:?}", order.status).to_lowercase(),
-            "created_at": order.created_at.to_rfc3339(),
-            "updated_at": order.updated_at.to_rfc3339(),
-        })
-    }).collect();
+    // Get tagged orders, or active ones when no tag is given
+    let orders = match &query.tag {
+        Some(tag) => order_manager.get_orders_by_tag(tag).await,
+        None => order_manager.get_active_orders().await,
+    };
     
-    success_response(formatted_orders)
+    success_response(orders.iter().map(OrderSummaryResponse::from).collect::<Vec<_>>())
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/order/{id}",
+    tag = "order",
+    params(
+        ("id" = String, Path, description = "Order ID")
+    ),
+    responses(
+        (status = 200, description = "Order details", body = SuccessResponse<OrderResponse>),
+        (status = 400, description = "Invalid or unknown order ID", body = ErrorResponse)
+    )
+)]
 pub async fn get_order(
     state: web::Data<AppState>,
     path: web::Path<String>,
@@ -375,50 +1032,189 @@ pub async fn get_order(
     
     // Get the order
     match order_manager.get_order(order_id).await {
-        Some(order) => {
-            // Format order for response
-            let formatted_order = serde_json::json!({
-                "id": order.id.to_string(),
-                "client_order_id": order.client_order_id,
-                "symbol": order.symbol,
-                "direction": match order.direction {
-                    TradeDirection::Buy => "buy",
-                    TradeDirection::Sell => "sell",
-                },
-                "order_type": format!("{:?}", order.order_type).to_lowercase(),
-                "quantity": order.quantity,
-                "filled_quantity": order.filled_quantity,
-                "price": order.price,
-                "stop_price": order.stop_price,
-                "time_in_force": format!("{:?}", order.time_in_force).to_lowercase(),
-                "status": format!("{:?}", order.status).to_lowercase(),
-                "exchange": order.exchange,
-                "created_at": order.created_at.to_rfc3339(),
-                "updated_at": order.updated_at.to_rfc3339(),
-                "filled_at": order.filled_at.map(|dt| dt.to_rfc3339()),
-                "average_fill_price": order.average_fill_price,
-                "strategy_id": order.strategy_id,
-                "notes": order.notes,
-            });
-            
-            success_response(formatted_order)
-        },
+        Some(order) => success_response(OrderResponse::from(order)),
         None => {
             error_response(&format!("Order not found: {}", order_id))
         }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Validate)]
+pub struct OrderStatisticsQuery {
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    #[validate(length(max = 1000, message = "must be at most 1000 characters"))]
+    symbols: Option<String>, // Comma-separated
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/order/{id}/fills",
+    tag = "order",
+    params(
+        ("id" = String, Path, description = "Order ID")
+    ),
+    responses(
+        (status = 200, description = "Executions reported for the order, oldest first", body = SuccessResponse<Vec<Execution>>),
+        (status = 400, description = "Invalid order ID", body = ErrorResponse),
+        (status = 404, description = "Unknown order", body = ErrorResponse)
+    )
+)]
+pub async fn get_order_fills(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let order_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => return error_response("Invalid order ID format"),
+    };
+    
+    let order_manager = state.order_manager.read().await;
+    if order_manager.get_order(order_id).await.is_none() {
+        return not_found_response(&format!("Order not found: {}", order_id));
+    }
+    
+    success_response(order_manager.get_executions(order_id).await)
+}
+
+#[derive(Deserialize, Validate)]
+pub struct OrderAmendmentQuery {
+    #[validate(range(min = 1, max = MAX_HISTORY_PAGE_SIZE, message = "must be between 1 and 1000"))]
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+/// One page of an order's amendment history, oldest first
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct OrderAmendmentPage {
+    pub amendments: Vec<OrderAmendment>,
+    pub total: usize, // Amendments to the order, across every page
+    pub limit: usize,
+    pub offset: usize,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/order/{id}/history",
+    tag = "order",
+    params(
+        ("id" = String, Path, description = "Order ID"),
+        ("limit" = Option<usize>, Query, description = "Amendments per page, 100 by default and at most 1000"),
+        ("offset" = Option<usize>, Query, description = "Amendments to skip from the oldest")
+    ),
+    responses(
+        (status = 200, description = "Status changes and other amendments to the order, oldest first", body = SuccessResponse<OrderAmendmentPage>),
+        (status = 400, description = "Invalid order ID", body = ErrorResponse),
+        (status = 404, description = "Unknown order", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn get_order_history(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    query: web::Query<OrderAmendmentQuery>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
+    let order_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => return error_response("Invalid order ID format"),
+    };
+    
+    let Some(order) = state.order_manager.read().await.get_order(order_id).await else {
+        return not_found_response(&format!("Order not found: {}", order_id));
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_HISTORY_PAGE_SIZE);
+    let offset = query.offset.unwrap_or(0);
+    success_response(OrderAmendmentPage {
+        total: order.amendment_history.len(),
+        amendments: order.amendment_history.into_iter().skip(offset).take(limit).collect(),
+        limit,
+        offset,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/order/statistics",
+    tag = "order",
+    params(
+        ("start" = Option<String>, Query, description = "Only orders created at or after this RFC 3339 time"),
+        ("end" = Option<String>, Query, description = "Only orders created at or before this RFC 3339 time"),
+        ("symbols" = Option<String>, Query, description = "Comma-separated symbols to include")
+    ),
+    responses(
+        (status = 200, description = "Execution quality statistics", body = SuccessResponse<OrderStatistics>),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn get_order_statistics(
+    state: web::Data<AppState>,
+    query: web::Query<OrderStatisticsQuery>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
+    
+    let query = query.into_inner();
+    let filter = OrderHistoryFilter {
+        start: query.start,
+        end: query.end,
+        symbols: query.symbols
+            .map(|symbols| symbols.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect())
+            .unwrap_or_default(),
+    };
+    
+    // Get order manager
+    let order_manager = state.order_manager.read().await;
+    
+    success_response(order_manager.get_order_statistics(filter).await)
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
 pub struct CancelOrderRequest {
+    #[validate(length(max = 500, message = "must be at most 500 characters"))]
     reason: Option<String>,
 }
 
+/// A cancelled order and why it was cancelled
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct CancelOrderResponse {
+    pub order_id: Uuid,
+    pub status: OrderStatus,
+    pub reason: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/order/{id}/cancel",
+    tag = "order",
+    params(
+        ("id" = String, Path, description = "Order ID")
+    ),
+    request_body = CancelOrderRequest,
+    responses(
+        (status = 200, description = "Order cancelled", body = SuccessResponse<CancelOrderResponse>),
+        (status = 400, description = "Invalid order ID", body = ErrorResponse),
+        (status = 404, description = "Unknown order", body = ErrorResponse),
+        (status = 409, description = "Order cannot be cancelled in its current status", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse),
+        (status = 503, description = "The exchange could not be reached", body = ErrorResponse)
+    )
+)]
 pub async fn cancel_order(
     state: web::Data<AppState>,
     path: web::Path<String>,
     req: web::Json<CancelOrderRequest>,
 ) -> impl Responder {
+    if let Some(response) = validate_request(&*req) {
+        return response;
+    }
+    
     // Parse order ID
     let order_id = match Uuid::parse_str(&path.into_inner()) {
         Ok(id) => id,
@@ -434,125 +1230,457 @@ pub async fn cancel_order(
     // Cancel the order
     match order_manager.cancel_order(order_id, reason.clone()).await {
         Ok(()) => {
-            success_response(serde_json::json!({
-                "order_id": order_id.to_string(),
-                "status": "cancelled",
-                "reason": reason,
-            }))
+            success_response(CancelOrderResponse { order_id, status: OrderStatus::Cancelled, reason })
         },
         Err(e) => {
-            error_response(&e)
+            trading_error_response(&e)
         }
     }
 }
 
+/// An order's status and fills after asking its exchange
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct OrderRefreshResponse {
+    pub order_id: Uuid,
+    pub status: OrderStatus,
+    pub filled_quantity: f64,
+    pub average_fill_price: Option<Price>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/order/{id}/refresh",
+    tag = "order",
+    params(
+        ("id" = String, Path, description = "Order ID")
+    ),
+    responses(
+        (status = 200, description = "Order status as reported by its exchange", body = SuccessResponse<OrderRefreshResponse>),
+        (status = 400, description = "Invalid order ID", body = ErrorResponse),
+        (status = 404, description = "Unknown order", body = ErrorResponse),
+        (status = 503, description = "The exchange could not be queried", body = ErrorResponse)
+    )
+)]
+pub async fn refresh_order(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let order_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => return error_response("Invalid order ID format"),
+    };
+    
+    let order_manager = state.order_manager.read().await;
+    if order_manager.get_order(order_id).await.is_none() {
+        return not_found_response(&format!("Order {} not found", order_id));
+    }
+    
+    if let Err(e) = order_manager.refresh_order_from_exchange(order_id).await {
+        return trading_error_response(&e);
+    }
+    
+    match order_manager.get_order(order_id).await {
+        Some(order) => success_response(OrderRefreshResponse {
+            order_id: order.id,
+            status: order.status,
+            filled_quantity: order.filled_quantity,
+            average_fill_price: order.average_fill_price,
+            updated_at: order.updated_at,
+        }),
+        None => not_found_response(&format!("Order {} not found", order_id)),
+    }
+}
+
 // Account handlers
+
+/// Currency the account balance is also reported in, whatever it is held in
+pub const BALANCE_EQUIVALENT_CURRENCY: &str = "USD";
+
+/// Aggregate balance with its value in US dollars
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct AccountBalanceResponse {
+    #[serde(flatten)]
+    pub balance: AccountBalance,
+    pub total_usd_equivalent: Option<f64>, // Every balance converted at market rates; null if a rate is missing
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/account/balance",
+    tag = "account",
+    responses(
+        (status = 200, description = "Balance summed across connected exchanges", body = SuccessResponse<AccountBalanceResponse>),
+        (status = 503, description = "No exchange reported a balance", body = ErrorResponse)
+    )
+)]
 pub async fn get_account_balance(
-    _state: web::Data<AppState>,
+    state: web::Data<AppState>,
 ) -> impl Responder {
-    // TODO: Implement this once we have account management
-    // For now, return mock data
+    let exchange_manager = state.exchange_manager.read().await;
     
-    let balance = serde_json::json!({
-        "total": 1000000.0,
-        "available": 750000.0,
-        "currency": "USD",
-        "additional_balances": [
-            {"currency": "BTC", "amount": 2.5},
-            {"currency": "ETH", "amount": 30.0},
-            {"currency": "SOL", "amount": 150.0},
-        ],
-        "timestamp": Utc::now().to_rfc3339(),
-    });
+    let balance = match exchange_manager.get_aggregate_balance().await {
+        Ok(balance) => balance,
+        Err(e) => return trading_error_response(&e),
+    };
     
-    success_response(balance)
+    let fx = state.market_data_manager.read().await.get_fx_provider();
+    let total_usd_equivalent = match balance.total_in_base_currency(BALANCE_EQUIVALENT_CURRENCY, &fx).await {
+        Ok(total) => Some(total),
+        Err(e) => {
+            warn!("Cannot value the account balance in {}: {}", BALANCE_EQUIVALENT_CURRENCY, e);
+            None
+        },
+    };
+    
+    success_response(AccountBalanceResponse { balance, total_usd_equivalent })
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/account/positions",
+    tag = "account",
+    responses(
+        (status = 200, description = "Open positions across connected exchanges", body = SuccessResponse<Vec<Position>>)
+    )
+)]
 pub async fn get_positions(
-    _state: web::Data<AppState>,
+    state: web::Data<AppState>,
 ) -> impl Responder {
-    // TODO: Implement this once we have position tracking
-    // For now, return mock data
+    let exchange_manager = state.exchange_manager.read().await;
+    success_response(exchange_manager.get_aggregate_positions().await)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/account/pnl",
+    tag = "account",
+    responses(
+        (status = 200, description = "Realized and unrealized P&L, with open positions marked to current market data; positions without current data keep their last mark and are listed as stale", body = SuccessResponse<AccountPnl>)
+    )
+)]
+pub async fn get_account_pnl(
+    state: web::Data<AppState>,
+) -> impl Responder {
+    let position_manager = state.order_manager.read().await.get_position_manager();
+    let current_data = state.market_data_manager.read().await.get_current_data();
     
-    let positions = serde_json::json!([
-        {
-            "symbol": "BTC/USD",
-            "quantity": 2.5,
-            "avg_price": 34500.0,
-            "current_price": 35200.0,
-            "unrealized_pnl": 1750.0,
-            "realized_pnl": 2500.0,
-            "timestamp": Utc::now().to_rfc3339(),
-        },
-        {
-            "symbol": "ETH/USD",
-            "quantity": 30.0,
-            "avg_price": 2100.0,
-            "current_price": 2250.0,
-            "unrealized_pnl": 4500.0,
-            "realized_pnl": 1200.0,
-            "timestamp": Utc::now().to_rfc3339(),
-        },
-        {
-            "symbol": "AAPL",
-            "quantity": 500.0,
-            "avg_price": 175.0,
-            "current_price": 178.5,
-            "unrealized_pnl": 1750.0,
-            "realized_pnl": 3000.0,
-            "timestamp": Utc::now().to_rfc3339(),
-        },
-    ]);
+    position_manager.mark_to_market(&*current_data.read().await).await;
+    success_response(position_manager.get_account_pnl().await)
+}
+
+#[derive(Deserialize, Validate)]
+pub struct MarginQuery {
+    #[validate(regex(path = *SYMBOL_RE, message = "must be letters and digits, optionally joined by / . _ or -"))]
+    symbol: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/account/margin",
+    tag = "account",
+    params(
+        ("symbol" = Option<String>, Query, description = "Symbol to give liquidation prices for")
+    ),
+    responses(
+        (status = 200, description = "Available and used margin on each connected exchange that trades on margin, with their totals", body = SuccessResponse<AccountMargin>),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn get_account_margin(
+    state: web::Data<AppState>,
+    query: web::Query<MarginQuery>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
     
-    success_response(positions)
+    let exchange_manager = state.exchange_manager.read().await;
+    success_response(exchange_manager.get_aggregate_margin(query.symbol.as_deref().unwrap_or_default()).await)
+}
+
+#[derive(Deserialize, Validate)]
+pub struct JournalQuery {
+    #[validate(length(max = 10, message = "must be at most 10 characters"))]
+    format: Option<String>, // Only "csv", the default
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/account/journal",
+    tag = "account",
+    params(
+        ("format" = Option<String>, Query, description = "Export format; only csv is supported"),
+        ("from" = Option<String>, Query, description = "Only fills at or after this RFC 3339 time"),
+        ("to" = Option<String>, Query, description = "Only fills before this RFC 3339 time")
+    ),
+    responses(
+        (status = 200, description = "Every fill in the range, oldest first, as CSV with columns order_id, symbol, side, quantity, price, fee and timestamp", body = String, content_type = "text/csv"),
+        (status = 400, description = "Unsupported format, or from is not before to", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn get_trade_journal(
+    state: web::Data<AppState>,
+    query: web::Query<JournalQuery>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
+    
+    let query = query.into_inner();
+    if let Some(format) = query.format.as_deref().filter(|format| !format.eq_ignore_ascii_case("csv")) {
+        return error_response(&format!("Unsupported journal format: {}", format));
+    }
+    if let (Some(from), Some(to)) = (query.from, query.to) {
+        if from >= to {
+            return error_response("from must be before to");
+        }
+    }
+    
+    let entries = state.order_manager.read().await.get_journal(query.from, query.to).await;
+    
+    // Rows are formatted as the body is sent rather than all at once
+    let rows = std::iter::once(JournalEntry::CSV_HEADER.to_string())
+        .chain(entries.into_iter().map(|entry| entry.to_csv_row()))
+        .map(|row| Ok::<_, actix_web::Error>(web::Bytes::from(row)));
+    HttpResponse::Ok()
+        .content_type("text/csv")
+        .insert_header(("Content-Disposition", "attachment; filename=\"journal.csv\""))
+        .streaming(futures::stream::iter(rows))
+}
+
+/// Transactions per page of account history when no limit is given
+pub const DEFAULT_HISTORY_PAGE_SIZE: usize = 100;
+
+/// Most transactions one page of account history may hold
+pub const MAX_HISTORY_PAGE_SIZE: usize = 1000;
+
+/// Longest range of account history one request may cover, a leap year
+pub const MAX_HISTORY_RANGE_DAYS: i64 = 366;
+
+/// Range of account history returned when none is given, ending now
+pub const DEFAULT_HISTORY_RANGE_DAYS: i64 = 30;
+
+#[derive(Deserialize, Validate)]
+pub struct AccountHistoryQuery {
+    #[validate(length(max = 32, message = "must be at most 32 characters"))]
+    from: Option<String>,
+    #[validate(length(max = 32, message = "must be at most 32 characters"))]
+    to: Option<String>,
+    #[validate(range(min = 1, max = MAX_HISTORY_PAGE_SIZE, message = "must be between 1 and 1000"))]
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+/// One page of account transactions, oldest first
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct AccountHistoryPage {
+    pub transactions: Vec<AccountTransaction>,
+    pub total: usize, // Transactions in the whole range, across every page
+    pub limit: usize,
+    pub offset: usize,
+}
+
+// End of a history range: a plain date includes the whole of that day
+fn parse_history_end(value: &str) -> Option<DateTime<Utc>> {
+    let end = parse_request_date(value)?;
+    if chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok() {
+        Some(end + chrono::Duration::days(1))
+    } else {
+        Some(end)
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/account/history",
+    tag = "account",
+    params(
+        ("from" = Option<String>, Query, description = "Only transactions at or after this date or RFC 3339 time; 30 days before to by default"),
+        ("to" = Option<String>, Query, description = "Only transactions up to the end of this date, or before this RFC 3339 time; now by default"),
+        ("limit" = Option<usize>, Query, description = "Transactions per page, 100 by default and at most 1000"),
+        ("offset" = Option<usize>, Query, description = "Transactions to skip from the oldest")
+    ),
+    responses(
+        (status = 200, description = "Deposits, withdrawals, trade settlements and other balance changes on connected exchanges, oldest first", body = SuccessResponse<AccountHistoryPage>),
+        (status = 400, description = "Invalid dates, from not before to, or a range over 366 days", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn get_account_history(
+    state: web::Data<AppState>,
+    query: web::Query<AccountHistoryQuery>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
+    
+    let query = query.into_inner();
+    let to = match query.to.as_deref().map(parse_history_end) {
+        None => Utc::now(),
+        Some(Some(to)) => to,
+        Some(None) => return error_response("Dates must be YYYY-MM-DD or RFC 3339 timestamps"),
+    };
+    let from = match query.from.as_deref().map(parse_request_date) {
+        None => to - chrono::Duration::days(DEFAULT_HISTORY_RANGE_DAYS),
+        Some(Some(from)) => from,
+        Some(None) => return error_response("Dates must be YYYY-MM-DD or RFC 3339 timestamps"),
+    };
+    if from >= to {
+        return error_response("from must be before to");
+    }
+    if to - from > chrono::Duration::days(MAX_HISTORY_RANGE_DAYS) {
+        return error_response(&format!("History range must be at most {} days", MAX_HISTORY_RANGE_DAYS));
+    }
+    
+    let transactions = state.exchange_manager.read().await.get_account_history(from, to).await;
+    let limit = query.limit.unwrap_or(DEFAULT_HISTORY_PAGE_SIZE);
+    let offset = query.offset.unwrap_or(0);
+    success_response(AccountHistoryPage {
+        total: transactions.len(),
+        transactions: transactions.into_iter().skip(offset).take(limit).collect(),
+        limit,
+        offset,
+    })
+}
+
+/// Key of the all-strategies total in the P&L attribution response
+pub const COMBINED_PNL_KEY: &str = "combined";
+
+#[utoipa::path(
+    get,
+    path = "/api/account/pnl/by-strategy",
+    tag = "account",
+    responses(
+        (status = 200, description = "P&L of each strategy with closed positions, and a \"combined\" total over all positions including manual trades", body = SuccessResponse<HashMap<String, StrategyPnl>>)
+    )
+)]
+pub async fn get_pnl_by_strategy(
+    state: web::Data<AppState>,
+) -> impl Responder {
+    let position_manager = state.order_manager.read().await.get_position_manager();
+    
+    let mut by_strategy = position_manager.get_pnl_by_strategy().await;
+    by_strategy.insert(COMBINED_PNL_KEY.to_string(), position_manager.get_combined_pnl().await);
+    success_response(by_strategy)
 }
 
 // Backtest handlers
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema, Validate)]
 pub struct BacktestRequest {
+    #[validate(length(min = 1, max = 100, message = "must be 1 to 100 characters"))]
     strategy: String,
+    #[validate(length(max = 32, message = "must be at most 32 characters"))]
     start_date: String,
+    #[validate(length(max = 32, message = "must be at most 32 characters"))]
     end_date: String,
+    #[validate(length(max = 50, message = "must have at most 50 entries"), custom(function = "validate_symbols"))]
     symbols: Vec<String>,
+    #[validate(range(exclusive_min = 0.0, message = "must be positive"))]
     initial_capital: f64,
-    #[allow(dead_code)]
-    parameters: serde_json::Value,
+    parameters: serde_json::Value, // Strategy parameters by name, or null for none
+}
+
+// Every symbol must be a well-formed trading symbol
+fn validate_symbols(symbols: &[String]) -> Result<(), validator::ValidationError> {
+    if symbols.iter().all(|symbol| symbol.len() <= 20 && SYMBOL_RE.is_match(symbol)) {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("symbol").with_message("each must be a trading symbol of at most 20 characters".into()))
+    }
+}
+
+// A date in a request, as an RFC 3339 timestamp or a plain date taken as midnight UTC
+fn parse_request_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.with_timezone(&Utc))
+        .ok()
+        .or_else(|| chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date| date.and_utc()))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/backtest",
+    tag = "backtest",
+    request_body = BacktestRequest,
+    responses(
+        (status = 200, description = "Backtest started; poll its id for the result", body = SuccessResponse<BacktestRun>),
+        (status = 400, description = "Invalid dates or parameters", body = ErrorResponse),
+        (status = 404, description = "Unknown strategy", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
 pub async fn run_backtest(
+    state: web::Data<AppState>,
     req: web::Json<BacktestRequest>,
 ) -> impl Responder {
-    // TODO: Implement actual backtesting
-    // For now, return mock data
+    if let Some(response) = validate_request(&*req) {
+        return response;
+    }
     
-    // Generate a random backtest ID
-    let backtest_id = Uuid::new_v4();
-    
-    // Simulate backtesting delay
-    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    
-    let result = serde_json::json!({
-        "id": backtest_id.to_string(),
-        "strategy": req.strategy,
-        "start_date": req.start_date,
-        "end_date": req.end_date,
-        "symbols": req.symbols,
-        "initial_capital": req.initial_capital,
-        "final_capital": req.initial_capital * 1.15, // 15% return
-        "return_pct": 15.0,
-        "annualized_return_pct": 28.5,
-        "sharpe_ratio": 1.8,
-        "max_drawdown_pct": 8.5,
-        "trades": 120,
-        "win_rate_pct": 62.5,
-        "status": "completed",
-        "timestamp": Utc::now().to_rfc3339(),
+    let (start, end) = match (parse_request_date(&req.start_date), parse_request_date(&req.end_date)) {
+        (Some(start), Some(end)) if start < end => (start, end),
+        (Some(_), Some(_)) => return error_response("start_date must be before end_date"),
+        _ => return error_response("Dates must be YYYY-MM-DD or RFC 3339 timestamps"),
+    };
+    let params = match &req.parameters {
+        serde_json::Value::Null => HashMap::new(),
+        serde_json::Value::Object(params) => params.clone().into_iter().collect(),
+        _ => return error_response("parameters must be an object"),
+    };
+    if !state.backtest_runner.has_strategy(&req.strategy) {
+        return not_found_response(&format!("Strategy {} not found", req.strategy));
+    }
+    
+    let spec = BacktestSpec {
+        strategy: req.strategy.clone(),
+        symbols: req.symbols.clone(),
+        start,
+        end,
+        initial_capital: req.initial_capital,
+        params: StrategyParams { params },
+    };
+    let run = BacktestRun::new(&spec);
+    let backtest_id = run.id;
+    state.backtests.insert(run.clone()).await;
+    
+    // Replaying history is CPU-bound, so it runs off the async worker threads
+    let backtests = state.backtests.clone();
+    let runner = state.backtest_runner.clone();
+    tokio::spawn(async move {
+        let outcome = match tokio::task::spawn_blocking(move || runner.run(&spec)).await {
+            Ok(outcome) => outcome,
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(e) = &outcome {
+            warn!("Backtest {} failed: {}", backtest_id, e);
+        }
+        backtests.complete(backtest_id, outcome).await;
     });
     
-    success_response(result)
+    success_response(run)
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/backtest/{id}",
+    tag = "backtest",
+    params(
+        ("id" = String, Path, description = "Backtest ID")
+    ),
+    responses(
+        (status = 200, description = "Backtest status, with the result once completed", body = SuccessResponse<BacktestRun>),
+        (status = 400, description = "Invalid backtest ID", body = ErrorResponse),
+        (status = 404, description = "Unknown backtest", body = ErrorResponse)
+    )
+)]
 pub async fn get_backtest_result(
+    state: web::Data<AppState>,
     path: web::Path<String>,
 ) -> impl Responder {
     // Parse backtest ID
@@ -561,42 +1689,377 @@ pub async fn get_backtest_result(
         Err(_) => return error_response("Invalid backtest ID format"),
     };
     
-    // TODO: Implement actual backtest result retrieval
-    // For now, return mock data
+    match state.backtests.get(backtest_id).await {
+        Some(run) => success_response(run),
+        None => not_found_response(&format!("Backtest {} not found", backtest_id)),
+    }
+}
+
+#[derive(Deserialize, Validate)]
+pub struct BacktestCompareQuery {
+    #[validate(length(max = 36, message = "must be at most 36 characters"))]
+    a: String,
+    #[validate(length(max = 36, message = "must be at most 36 characters"))]
+    b: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/backtest/compare",
+    tag = "backtest",
+    params(
+        ("a" = String, Query, description = "Backtest ID to compare from"),
+        ("b" = String, Query, description = "Backtest ID to compare with a")
+    ),
+    responses(
+        (status = 200, description = "Differences between the two results and their settings, as b minus a", body = SuccessResponse<BacktestComparison>),
+        (status = 400, description = "Invalid backtest ID", body = ErrorResponse),
+        (status = 404, description = "Unknown or unfinished backtest, named as a or b", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn compare_backtests(
+    state: web::Data<AppState>,
+    query: web::Query<BacktestCompareQuery>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
     
-    let result = serde_json::json!({
-        "id": backtest_id.to_string(),
-        "strategy": "Statistical Arbitrage",
-        "start_date": "2023-01-01",
-        "end_date": "2023-12-31",
-        "symbols": ["BTC/USD", "ETH/USD"],
-        "initial_capital": 1000000.0,
-        "final_capital": 1150000.0, // 15% return
-        "return_pct": 15.0,
-        "annualized_return_pct": 28.5,
-        "sharpe_ratio": 1.8,
-        "max_drawdown_pct": 8.5,
-        "trades": 120,
-        "win_rate_pct": 62.5,
-        "status": "completed",
-        "timestamp": Utc::now().to_rfc3339(),
-        "monthly_returns": [
-            {"month": "2023-01", "return_pct": 2.1},
-            {"month": "2023-02", "return_pct": 1.5},
-            {"month": "2023-03", "return_pct": -0.8},
-            {"month": "2023-04", "return_pct": 3.2},
-            {"month": "2023-05", "return_pct": 1.7},
-            {"month": "2023-06", "return_pct": -1.2},
-            {"month": "2023-07", "return_pct": 2.5},
-            {"month": "2023-08", "return_pct": 1.9},
-            {"month": "2023-09", "return_pct": 0.8},
-            {"month": "2023-10", "return_pct": -0.5},
-            {"month": "2023-11", "return_pct": 1.6},
-            {"month": "2023-12", "return_pct": 2.2},
-        ],
+    let (a_id, b_id) = match (Uuid::parse_str(&query.a), Uuid::parse_str(&query.b)) {
+        (Ok(a_id), Ok(b_id)) => (a_id, b_id),
+        _ => return error_response("Invalid backtest ID format"),
+    };
+    let (a, b) = match (state.backtests.get(a_id).await, state.backtests.get(b_id).await) {
+        (Some(a), Some(b)) => (a, b),
+        (None, _) => return not_found_response(&format!("Backtest a ({}) not found", a_id)),
+        (_, None) => return not_found_response(&format!("Backtest b ({}) not found", b_id)),
+    };
+    
+    match BacktestComparison::new(&a, &b) {
+        Ok(comparison) => success_response(comparison),
+        Err(id) => {
+            let label = if id == a_id { "a" } else { "b" };
+            not_found_response(&format!("Backtest {} ({}) has no result yet", label, id))
+        },
+    }
+}
+
+#[derive(Deserialize, Validate)]
+pub struct MonteCarloQuery {
+    #[validate(range(min = 1, max = MAX_MONTE_CARLO_ITERATIONS, message = "must be between 1 and 100000"))]
+    iterations: Option<usize>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/backtest/{id}/monte-carlo",
+    tag = "backtest",
+    params(
+        ("id" = String, Path, description = "Backtest ID"),
+        ("iterations" = Option<usize>, Query, description = "Number of simulations, 1 to 100000 (default 10000)")
+    ),
+    responses(
+        (status = 200, description = "Monte Carlo job started", body = SuccessResponse<MonteCarloJob>),
+        (status = 400, description = "Invalid backtest ID", body = ErrorResponse),
+        (status = 404, description = "Unknown or unfinished backtest", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn start_monte_carlo(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    query: web::Query<MonteCarloQuery>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*query) {
+        return response;
+    }
+    
+    let backtest_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => return error_response("Invalid backtest ID format"),
+    };
+    
+    let backtest = match state.backtests.result(backtest_id).await {
+        Some(result) => result,
+        None => return not_found_response(&format!("Completed backtest {} not found", backtest_id)),
+    };
+    
+    let iterations = query.iterations.unwrap_or(10_000);
+    let job = MonteCarloJob::new(backtest_id, iterations);
+    let job_id = job.job_id;
+    state.monte_carlo_jobs.write().await.insert(job_id, job.clone());
+    
+    // Resampling is CPU-bound, so it runs off the async worker threads
+    let jobs = state.monte_carlo_jobs.clone();
+    tokio::spawn(async move {
+        let outcome = tokio::task::spawn_blocking(move || backtest.monte_carlo(iterations)).await;
+        if let Some(job) = jobs.write().await.get_mut(&job_id) {
+            match outcome {
+                Ok(result) => {
+                    job.status = MonteCarloJobStatus::Completed;
+                    job.result = Some(result);
+                },
+                Err(e) => {
+                    job.status = MonteCarloJobStatus::Failed;
+                    job.error = Some(e.to_string());
+                },
+            }
+            job.completed_at = Some(Utc::now());
+        }
     });
     
-    success_response(result)
+    success_response(job)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/backtest/{id}/monte-carlo/{job_id}",
+    tag = "backtest",
+    params(
+        ("id" = String, Path, description = "Backtest ID"),
+        ("job_id" = String, Path, description = "Monte Carlo job ID")
+    ),
+    responses(
+        (status = 200, description = "Job status, with the result once completed", body = SuccessResponse<MonteCarloJob>),
+        (status = 400, description = "Invalid backtest or job ID", body = ErrorResponse),
+        (status = 404, description = "No such job for this backtest", body = ErrorResponse)
+    )
+)]
+pub async fn get_monte_carlo(
+    state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (backtest_id, job_id) = path.into_inner();
+    let (backtest_id, job_id) = match (Uuid::parse_str(&backtest_id), Uuid::parse_str(&job_id)) {
+        (Ok(backtest_id), Ok(job_id)) => (backtest_id, job_id),
+        _ => return error_response("Invalid backtest or job ID format"),
+    };
+    
+    match state.monte_carlo_jobs.read().await.get(&job_id) {
+        Some(job) if job.backtest_id == backtest_id => success_response(job.clone()),
+        _ => not_found_response(&format!("Monte Carlo job {} not found for backtest {}", job_id, backtest_id)),
+    }
+}
+
+// Risk handlers
+#[utoipa::path(
+    get,
+    path = "/api/risk/drawdown",
+    tag = "risk",
+    responses(
+        (status = 200, description = "Current drawdown, high-water mark and worst drawdown seen", body = SuccessResponse<DrawdownSnapshot>)
+    )
+)]
+pub async fn get_drawdown(
+    state: web::Data<AppState>,
+) -> impl Responder {
+    // Get strategy manager
+    let strategy_manager = state.strategy_manager.read().await;
+    
+    success_response(strategy_manager.get_drawdown_monitor().snapshot())
+}
+
+#[derive(Deserialize)]
+pub struct VarQuery {
+    confidence: Option<f64>,
+    method: Option<VarMethod>,
+}
+
+/// One-day portfolio Value at Risk and Conditional VaR, in account currency
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct VarResponse {
+    pub confidence: f64,
+    pub method: VarMethod,
+    pub var: f64,
+    pub cvar: f64,
+    pub portfolio_value: f64,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/risk/var",
+    tag = "risk",
+    params(
+        ("confidence" = Option<f64>, Query, description = "Confidence level between 0 and 1 (default 0.95)"),
+        ("method" = Option<VarMethod>, Query, description = "historical (default) or parametric")
+    ),
+    responses(
+        (status = 200, description = "One-day portfolio VaR and CVaR in account currency", body = SuccessResponse<VarResponse>),
+        (status = 400, description = "Invalid confidence or insufficient return history", body = ErrorResponse)
+    )
+)]
+pub async fn get_value_at_risk(
+    state: web::Data<AppState>,
+    query: web::Query<VarQuery>,
+) -> impl Responder {
+    let confidence = query.confidence.unwrap_or(0.95);
+    let method = query.method.unwrap_or(VarMethod::Historical);
+    
+    if confidence <= 0.0 || confidence >= 1.0 {
+        return error_response("Confidence must be between 0 and 1");
+    }
+    
+    // Get position manager
+    let position_manager = state.order_manager.read().await.get_position_manager();
+    
+    let var = position_manager.current_portfolio_var(confidence, method).await;
+    let cvar = position_manager.current_portfolio_cvar(confidence).await;
+    
+    match (var, cvar) {
+        (Some(var), Some(cvar)) => success_response(VarResponse {
+            confidence,
+            method,
+            var,
+            cvar,
+            portfolio_value: position_manager.portfolio_value().await,
+        }),
+        _ => error_response(&format!(
+            "Insufficient return history: {} observations, at least {} required",
+            position_manager.get_daily_returns().await.len(),
+            MIN_VAR_OBSERVATIONS,
+        )),
+    }
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct UpdateCorrelationsRequest {
+    #[validate(length(max = 1000, message = "must have at most 1000 entries"))]
+    pub correlations: Vec<CorrelationEntry>,
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/risk/correlations",
+    tag = "risk",
+    request_body = UpdateCorrelationsRequest,
+    responses(
+        (status = 200, description = "All configured correlations after the update", body = SuccessResponse<Vec<CorrelationEntry>>),
+        (status = 400, description = "Correlation outside [-1, 1] or invalid symbol pair", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse)
+    )
+)]
+pub async fn update_correlations(
+    state: web::Data<AppState>,
+    request: web::Json<UpdateCorrelationsRequest>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*request) {
+        return response;
+    }
+    
+    // Get portfolio manager
+    let portfolio_manager = state.order_manager.read().await.get_portfolio_manager();
+    
+    match portfolio_manager.set_correlations(request.into_inner().correlations).await {
+        Ok(()) => success_response(portfolio_manager.get_correlations().await),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct HedgeRequest {
+    #[validate(
+        length(min = 1, max = 20, message = "must be 1 to 20 characters"),
+        regex(path = *SYMBOL_RE, message = "must be letters and digits, optionally joined by / . _ or -")
+    )]
+    symbol: String,
+    #[validate(range(exclusive_min = 0.0, message = "must be positive"))]
+    position_usd: f64, // Value of the position to hedge
+    #[validate(range(exclusive_min = 0.0, message = "must be positive"))]
+    hedge_ratio: Option<f64>, // Fraction of the value to hedge, 1.0 when omitted
+    #[validate(length(min = 1, max = 50, message = "must be 1 to 50 characters"))]
+    hedge_exchange: String,
+}
+
+/// A hedge order placed against the net position in a symbol
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct HedgeResponse {
+    pub order_id: Uuid,
+    pub symbol: String,
+    pub direction: TradeDirection,
+    pub quantity: f64,
+    pub exchange: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/risk/hedge",
+    tag = "risk",
+    request_body = HedgeRequest,
+    responses(
+        (status = 200, description = "Hedge order placed on the hedge exchange", body = SuccessResponse<HedgeResponse>),
+        (status = 400, description = "No position to hedge, or the exchange does not support the symbol", body = ErrorResponse),
+        (status = 404, description = "Hedge exchange not found", body = ErrorResponse),
+        (status = 409, description = "Hedge order breaches a risk limit", body = ErrorResponse),
+        (status = 422, description = "Request failed validation", body = ValidationErrorResponse),
+        (status = 503, description = "No price for the symbol, or the exchange cannot take the order", body = ErrorResponse)
+    )
+)]
+pub async fn hedge_position(
+    state: web::Data<AppState>,
+    request: web::Json<HedgeRequest>,
+) -> impl Responder {
+    if let Some(response) = validate_request(&*request) {
+        return response;
+    }
+    
+    let hedger = PositionHedger::new(state.order_manager.clone(), state.market_data_manager.clone());
+    let hedge_ratio = request.hedge_ratio.unwrap_or(1.0);
+    let order_id = match hedger.hedge_position(&request.symbol, request.position_usd, hedge_ratio, &request.hedge_exchange).await {
+        Ok(order_id) => order_id,
+        Err(e) => {
+            warn!("Could not hedge {}: {}", request.symbol, e);
+            return trading_error_response(&e);
+        },
+    };
+    
+    match state.order_manager.read().await.get_order(order_id).await {
+        Some(order) => success_response(HedgeResponse {
+            order_id,
+            symbol: order.symbol,
+            direction: order.direction,
+            quantity: order.quantity,
+            exchange: request.hedge_exchange.clone(),
+        }),
+        None => not_found_response(&format!("Hedge order {} not found", order_id)),
+    }
+}
+
+/// Where a test notification went and what failed to deliver it
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct TestNotificationResponse {
+    pub handlers: Vec<String>,
+    pub delivered: usize,
+    pub errors: Vec<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/notifications/test",
+    tag = "notifications",
+    responses(
+        (status = 200, description = "Test notification sent to every handler, with any delivery errors", body = SuccessResponse<TestNotificationResponse>)
+    )
+)]
+pub async fn send_test_notification(
+    state: web::Data<AppState>,
+) -> impl Responder {
+    let notification_manager = &state.notification_manager;
+    
+    // Sent at the threshold level so it is never filtered out
+    let notification = Notification::new(
+        notification_manager.min_level(),
+        "Test notification",
+        "Sent from the notifications test endpoint",
+    );
+    let results = notification_manager.notify(notification).await;
+    
+    let errors: Vec<String> = results.iter().filter_map(|r| r.clone().err()).collect();
+    success_response(TestNotificationResponse {
+        handlers: notification_manager.handler_names(),
+        delivered: results.len() - errors.len(),
+        errors,
+    })
 }
 
 // Update the function signatures with unused state parameters
@@ -618,24 +2081,6 @@ async fn get_health(
     }))
 }
 
-#[allow(dead_code)]
-async fn get_api_documentation(
-    _state: web::Data<AppState>,
-) -> impl Responder {
-    HttpResponse::Ok().json(serde_json::json!({
-        "documentation": "API documentation available at /api-docs",
-        "version": "0.1.0",
-        "endpoints": [
-            "/api/health",
-            "/api/market/symbols",
-            "/api/market/data/{symbol}",
-            "/api/strategy",
-            "/api/strategy/{name}/params",
-            "/api/account/balance"
-        ]
-    }))
-}
-
 #[allow(dead_code)]
 async fn websocket_documentation(
     _state: web::Data<AppState>,
@@ -1,31 +1,204 @@
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, LazyLock};
 use actix_web::{web, App, HttpServer, HttpResponse};
 use actix_web::middleware::Logger;
 use serde::Serialize;
+use regex::Regex;
 use tokio::sync::RwLock;
 use tracing::info;
+use utoipa::{OpenApi, ToSchema};
+use uuid::Uuid;
+use utoipa_swagger_ui::SwaggerUi;
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
 
+use crate::error::TradingError;
 use crate::strategy::StrategyManager;
 use crate::market_data::MarketDataManager;
 use crate::order::OrderManager;
+use crate::exchange::manager::ExchangeManager;
+use crate::notifications::NotificationManager;
+use crate::backtest::{BacktestRunner, BacktestStore, MonteCarloJob};
+use crate::models::SymbolNormalizer;
 
 mod handlers;
-mod websocket;
+pub mod websocket;
+
+pub use handlers::{
+    AccountBalanceResponse, AccountHistoryPage, BatchOrderResult, CancelOrderResponse, EventChannelMetrics,
+    FundingRateStatus, HealthResponse, HedgeResponse, MessageResponse, OrderAmendmentPage, OrderRefreshResponse,
+    OrderResponse, OrderSummaryResponse, PlaceOrderResponse, QuotesResponse, StrategyEvaluation,
+    StrategyEvaluationsResponse, TestNotificationResponse, TwapExecutionResponse, VarResponse,
+};
+/// Largest request body accepted, in bytes
+pub const MAX_PAYLOAD_BYTES: usize = 1024 * 1024;
+
+/// Trading symbols: letters and digits, optionally joined by '/', '.', '_' or '-' (e.g. "BTC/USD", "BRK.B")
+pub static SYMBOL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Za-z0-9]+([/._-][A-Za-z0-9]+)*$").unwrap());
+
 // Comment out missing modules
 // mod routes;
 // mod auth;
 
+/// OpenAPI specification collecting every REST handler and its schemas
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "ARB Platform API",
+        description = "REST API for the ARB trading platform",
+    ),
+    paths(
+        handlers::health_check,
+        handlers::get_metrics,
+        handlers::get_market_data,
+        handlers::get_symbols,
+        handlers::get_quotes,
+        handlers::get_order_book,
+        handlers::get_funding_rate,
+        handlers::get_data_quality,
+        handlers::get_strategies,
+        handlers::get_active_strategy,
+        handlers::set_active_strategy,
+        handlers::get_strategy_params,
+        handlers::update_strategy_params,
+        handlers::evaluate_strategies,
+        handlers::evaluate_strategy,
+        handlers::place_order,
+        handlers::place_orders,
+        handlers::place_twap_order,
+        handlers::get_twap_execution,
+        handlers::get_orders,
+        handlers::get_order,
+        handlers::get_order_fills,
+        handlers::get_order_history,
+        handlers::get_order_statistics,
+        handlers::cancel_order,
+        handlers::refresh_order,
+        handlers::get_account_balance,
+        handlers::get_positions,
+        handlers::get_account_pnl,
+        handlers::get_account_margin,
+        handlers::get_trade_journal,
+        handlers::get_account_history,
+        handlers::get_pnl_by_strategy,
+        handlers::run_backtest,
+        handlers::get_backtest_result,
+        handlers::compare_backtests,
+        handlers::start_monte_carlo,
+        handlers::get_monte_carlo,
+        handlers::get_drawdown,
+        handlers::get_value_at_risk,
+        handlers::hedge_position,
+        handlers::update_correlations,
+        handlers::send_test_notification,
+    ),
+    components(schemas(
+        ErrorResponse,
+        ValidationErrorResponse,
+        handlers::SetActiveStrategyRequest,
+        handlers::PlaceOrderRequest,
+        handlers::BatchOrderResult,
+        handlers::EventChannelMetrics,
+        handlers::FundingRateStatus,
+        handlers::AccountBalanceResponse,
+        handlers::AccountHistoryPage,
+        handlers::QuotesResponse,
+        handlers::HealthResponse,
+        handlers::MessageResponse,
+        handlers::StrategyEvaluation,
+        handlers::StrategyEvaluationsResponse,
+        handlers::PlaceOrderResponse,
+        handlers::TwapExecutionResponse,
+        handlers::OrderSummaryResponse,
+        handlers::OrderResponse,
+        handlers::OrderAmendmentPage,
+        handlers::CancelOrderResponse,
+        handlers::OrderRefreshResponse,
+        handlers::VarResponse,
+        handlers::TestNotificationResponse,
+        handlers::TwapOrderRequest,
+        handlers::CancelOrderRequest,
+        handlers::BacktestRequest,
+        handlers::UpdateCorrelationsRequest,
+        handlers::HedgeRequest,
+        handlers::HedgeResponse,
+        crate::backtest::BacktestRun,
+        crate::backtest::BacktestStatus,
+        crate::backtest::BacktestResult,
+        crate::backtest::BacktestComparison,
+        crate::backtest::BacktestTrade,
+        crate::backtest::MonteCarloJob,
+        crate::backtest::MonteCarloJobStatus,
+        crate::backtest::MonteCarloResult,
+        crate::channel::BackpressurePolicy,
+        crate::channel::ChannelStats,
+        crate::exchange::AccountBalance,
+        crate::exchange::AccountTransaction,
+        crate::exchange::TransactionType,
+        crate::exchange::AccountMargin,
+        crate::exchange::MarginInfo,
+        crate::exchange::Position,
+        crate::market_data::DataQualityStats,
+        crate::market_data::FundingRate,
+        crate::market_data::OrderBookDepth,
+        crate::market_data::PriceLevel,
+        crate::models::CorrelationEntry,
+        crate::notifications::Notification,
+        crate::notifications::NotificationLevel,
+        crate::order::Execution,
+        crate::order::OrderAmendment,
+        crate::order::OrderStatus,
+        crate::order::OrderType,
+        crate::order::OrderStatistics,
+        crate::order::TwapProgress,
+        crate::position::AccountPnl,
+        crate::position::StrategyPnl,
+        crate::risk::DrawdownSnapshot,
+        crate::risk::CircuitBreakerStatus,
+        crate::risk::VarMethod,
+        websocket::WsMessage,
+        crate::strategy::AssetData,
+        crate::strategy::AssetType,
+        crate::strategy::HotSwapTransition,
+        crate::strategy::SelectionObjective,
+        crate::strategy::StrategyResult,
+        crate::strategy::TradeSignal,
+        crate::strategy::TradeDirection,
+        crate::strategy::TimeInForce,
+    )),
+    tags(
+        (name = "health", description = "Service health"),
+        (name = "market", description = "Market data"),
+        (name = "strategy", description = "Strategy management and evaluation"),
+        (name = "order", description = "Order management"),
+        (name = "account", description = "Account balances and positions"),
+        (name = "backtest", description = "Strategy backtesting"),
+        (name = "risk", description = "Risk monitoring"),
+        (name = "notifications", description = "Operator alerts"),
+    )
+)]
+pub struct ApiDoc;
+
 #[derive(Clone)]
 pub struct AppState {
     pub strategy_manager: Arc<RwLock<StrategyManager>>,
     pub market_data_manager: Arc<RwLock<MarketDataManager>>,
     pub order_manager: Arc<RwLock<OrderManager>>,
+    pub exchange_manager: Arc<RwLock<ExchangeManager>>,
+    pub notification_manager: Arc<NotificationManager>,
+    pub backtests: Arc<BacktestStore>, // Backtest runs and their results by id
+    pub backtest_runner: Arc<BacktestRunner>,
+    pub monte_carlo_jobs: Arc<RwLock<HashMap<Uuid, MonteCarloJob>>>,
+    pub symbol_normalizer: Arc<SymbolNormalizer>, // Shared with the order and market data managers
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn start_api_server(
     strategy_manager: Arc<RwLock<StrategyManager>>,
     market_data_manager: Arc<RwLock<MarketDataManager>>,
     order_manager: Arc<RwLock<OrderManager>>,
+    exchange_manager: Arc<RwLock<ExchangeManager>>,
+    notification_manager: Arc<NotificationManager>,
+    symbol_normalizer: Arc<SymbolNormalizer>,
     host: &str,
     port: u16,
 ) -> std::io::Result<()> {
@@ -33,6 +206,12 @@ pub async fn start_api_server(
         strategy_manager,
         market_data_manager,
         order_manager,
+        exchange_manager,
+        notification_manager,
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer,
     };
     
     info!("Starting API server on {}:{}", host, port);
@@ -41,68 +220,133 @@ pub async fn start_api_server(
         App::new()
             .app_data(web::Data::new(app_state.clone()))
             .wrap(Logger::default())
-            .service(
-                web::scope("/api")
-                    // Health check
-                    .route("/health", web::get().to(handlers::health_check))
-                    
-                    // Market data routes
-                    .service(
-                        web::scope("/market")
-                            .route("/data/{symbol}", web::get().to(handlers::get_market_data))
-                            .route("/symbols", web::get().to(handlers::get_symbols))
-                    )
-                    
-                    // Strategy routes
-                    .service(
-                        web::scope("/strategy")
-                            .route("", web::get().to(handlers::get_strategies))
-                            .route("/active", web::get().to(handlers::get_active_strategy))
-                            .route("/active", web::put().to(handlers::set_active_strategy))
-                            .route("/{name}/params", web::get().to(handlers::get_strategy_params))
-                            .route("/{name}/params", web::put().to(handlers::update_strategy_params))
-                            .route("/evaluate", web::post().to(handlers::evaluate_strategies))
-                    )
-                    
-                    // Order routes
-                    .service(
-                        web::scope("/order")
-                            .route("", web::post().to(handlers::place_order))
-                            .route("", web::get().to(handlers::get_orders))
-                            .route("/{id}", web::get().to(handlers::get_order))
-                            .route("/{id}/cancel", web::post().to(handlers::cancel_order))
-                    )
-                    
-                    // Account routes
-                    .service(
-                        web::scope("/account")
-                            .route("/balance", web::get().to(handlers::get_account_balance))
-                            .route("/positions", web::get().to(handlers::get_positions))
-                    )
-                    
-                    // Backtest routes
-                    .service(
-                        web::scope("/backtest")
-                            .route("", web::post().to(handlers::run_backtest))
-                            .route("/{id}", web::get().to(handlers::get_backtest_result))
-                    )
-            )
+            .configure(configure_routes)
+            
             // WebSocket for real-time updates
             .route("/ws", web::get().to(websocket::ws_index))
+            
+            // Interactive API documentation
+            .service(
+                SwaggerUi::new("/api-docs/{_:.*}")
+                    .url("/api-docs/openapi.json", ApiDoc::openapi())
+            )
     })
     .bind((host, port))?
+    .disable_signals() // Shutdown signals go to the ShutdownCoordinator, which drains orders first
     .run()
     .await
 }
 
+/// The generated OpenAPI spec, as JSON
+async fn openapi_spec() -> HttpResponse {
+    HttpResponse::Ok().json(ApiDoc::openapi())
+}
+
+/// Register the REST API routes under `/api`
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.app_data(web::JsonConfig::default().limit(MAX_PAYLOAD_BYTES))
+        .app_data(web::PayloadConfig::default().limit(MAX_PAYLOAD_BYTES));
+    
+    cfg.service(
+        web::scope("/api")
+            // Health check
+            .route("/health", web::get().to(handlers::health_check))
+            .route("/metrics", web::get().to(handlers::get_metrics))
+            .route("/openapi.json", web::get().to(openapi_spec))
+            
+            // Market data routes
+            .service(
+                web::scope("/market")
+                    .route("/data/{symbol}", web::get().to(handlers::get_market_data))
+                    .route("/symbols", web::get().to(handlers::get_symbols))
+                    .route("/quotes", web::get().to(handlers::get_quotes))
+                    .route("/orderbook/{symbol}", web::get().to(handlers::get_order_book))
+                    .route("/funding/{symbol}", web::get().to(handlers::get_funding_rate))
+                    .route("/data-quality", web::get().to(handlers::get_data_quality))
+            )
+            
+            // Strategy routes
+            .service(
+                web::scope("/strategy")
+                    .route("", web::get().to(handlers::get_strategies))
+                    .route("/active", web::get().to(handlers::get_active_strategy))
+                    .route("/active", web::put().to(handlers::set_active_strategy))
+                    .route("/{name}/params", web::get().to(handlers::get_strategy_params))
+                    .route("/{name}/params", web::put().to(handlers::update_strategy_params))
+                    .route("/evaluate", web::post().to(handlers::evaluate_strategies))
+                    .route("/{name}/evaluate", web::get().to(handlers::evaluate_strategy))
+            )
+            
+            // Order routes
+            .service(
+                web::scope("/order")
+                    .route("", web::post().to(handlers::place_order))
+                    .route("/batch", web::post().to(handlers::place_orders))
+                    .route("/twap", web::post().to(handlers::place_twap_order))
+                    .route("/twap/{parent_id}", web::get().to(handlers::get_twap_execution))
+                    .route("", web::get().to(handlers::get_orders))
+                    .route("/statistics", web::get().to(handlers::get_order_statistics))
+                    .route("/{id}", web::get().to(handlers::get_order))
+                    .route("/{id}/fills", web::get().to(handlers::get_order_fills))
+                    .route("/{id}/history", web::get().to(handlers::get_order_history))
+                    .route("/{id}/cancel", web::post().to(handlers::cancel_order))
+                    .route("/{id}/refresh", web::post().to(handlers::refresh_order))
+            )
+            
+            // Account routes
+            .service(
+                web::scope("/account")
+                    .route("/balance", web::get().to(handlers::get_account_balance))
+                    .route("/positions", web::get().to(handlers::get_positions))
+                    .route("/pnl", web::get().to(handlers::get_account_pnl))
+                    .route("/pnl/by-strategy", web::get().to(handlers::get_pnl_by_strategy))
+                    .route("/margin", web::get().to(handlers::get_account_margin))
+                    .route("/journal", web::get().to(handlers::get_trade_journal))
+                    .route("/history", web::get().to(handlers::get_account_history))
+            )
+            
+            // Backtest routes
+            .service(
+                web::scope("/backtest")
+                    .route("", web::post().to(handlers::run_backtest))
+                    .route("/compare", web::get().to(handlers::compare_backtests))
+                    .route("/{id}", web::get().to(handlers::get_backtest_result))
+                    .route("/{id}/monte-carlo", web::post().to(handlers::start_monte_carlo))
+                    .route("/{id}/monte-carlo/{job_id}", web::get().to(handlers::get_monte_carlo))
+            )
+            
+            // Risk routes
+            .service(
+                web::scope("/risk")
+                    .route("/drawdown", web::get().to(handlers::get_drawdown))
+                    .route("/var", web::get().to(handlers::get_value_at_risk))
+                    .route("/correlations", web::put().to(handlers::update_correlations))
+                    .route("/hedge", web::post().to(handlers::hedge_position))
+            )
+            
+            // Notification routes
+            .service(
+                web::scope("/notifications")
+                    .route("/test", web::post().to(handlers::send_test_notification))
+            )
+    );
+}
+
 // Default error response format
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub error: String,
 }
 
+/// Request that failed validation, with the problems found in each field
+#[derive(Serialize, ToSchema)]
+pub struct ValidationErrorResponse {
+    pub error: String,
+    pub fields: BTreeMap<String, Vec<String>>, // Field path, e.g. "symbol" or "order.quantity", to its problems
+}
+
 // Standard success response
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct SuccessResponse<T> {
     pub data: T,
 }
@@ -114,6 +358,75 @@ pub fn error_response(message: &str) -> HttpResponse {
     })
 }
 
+// Helper function to create a not found error response
+pub fn not_found_response(message: &str) -> HttpResponse {
+    HttpResponse::NotFound().json(ErrorResponse {
+        error: message.to_string(),
+    })
+}
+
+/// Error response whose status matches the kind of failure: 400 for invalid or
+/// rejected requests, 404 for unknown resources, 409 when the request clashes
+/// with current state or a risk limit, and 503 when the exchange side is down
+pub fn trading_error_response(error: &TradingError) -> HttpResponse {
+    let mut response = match error {
+        TradingError::Validation(_) | TradingError::Rejected(_) => HttpResponse::BadRequest(),
+        TradingError::NotFound(_) => HttpResponse::NotFound(),
+        TradingError::Conflict(_) | TradingError::RiskViolation(_) | TradingError::ExcessiveMarketImpact { .. } | TradingError::InsufficientMargin { .. } => HttpResponse::Conflict(),
+        TradingError::NotConnected(_) | TradingError::Unavailable(_) | TradingError::Exchange(_) | TradingError::ExchangeTimeout { .. } => HttpResponse::ServiceUnavailable(),
+    };
+    response.json(ErrorResponse {
+        error: error.to_string(),
+    })
+}
+
+/// Check a request against its validation rules, returning an HTTP 422
+/// response listing the problems in each field when it fails
+pub fn validate_request<T: Validate>(request: &T) -> Option<HttpResponse> {
+    request.validate().err().map(|errors| {
+        HttpResponse::UnprocessableEntity().json(ValidationErrorResponse {
+            error: "Request validation failed".to_string(),
+            fields: field_errors(&errors),
+        })
+    })
+}
+
+/// Validation problems on one line, e.g. "quantity: must be at least 0.000001",
+/// for responses that report errors per item
+pub fn validation_summary(errors: &ValidationErrors) -> String {
+    field_errors(errors).iter()
+        .map(|(field, messages)| format!("{}: {}", field, messages.join(", ")))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn field_errors(errors: &ValidationErrors) -> BTreeMap<String, Vec<String>> {
+    let mut fields = BTreeMap::new();
+    collect_field_errors(errors, "", &mut fields);
+    fields
+}
+
+// Flatten nested validation errors into dotted field paths
+fn collect_field_errors(errors: &ValidationErrors, prefix: &str, fields: &mut BTreeMap<String, Vec<String>>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() { field.to_string() } else { format!("{}.{}", prefix, field) };
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                let messages = fields.entry(path).or_default();
+                for error in field_errors {
+                    messages.push(error.message.as_ref().map(|m| m.to_string()).unwrap_or_else(|| error.code.to_string()));
+                }
+            },
+            ValidationErrorsKind::Struct(nested) => collect_field_errors(nested, &path, fields),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect_field_errors(nested, &format!("{}[{}]", path, index), fields);
+                }
+            },
+        }
+    }
+}
+
 // Helper function to create a standard success response
 pub fn success_response<T: Serialize>(data: T) -> HttpResponse {
     HttpResponse::Ok().json(SuccessResponse { data })
@@ -1,11 +1,32 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
 use actix_web::{web, Error, HttpRequest, HttpResponse};
+use actix_ws::{AggregatedMessage, AggregatedMessageStream, Closed, Session};
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tokio::time::MissedTickBehavior;
 use tracing::{debug, info};
+use utoipa::ToSchema;
+use uuid::Uuid;
 
 use crate::api::AppState;
+use crate::strategy::MarketData;
+
+/// Header on the upgrade request selecting the session's message format
+pub const WS_FORMAT_HEADER: &str = "X-WS-Format";
+/// `X-WS-Format` value selecting MessagePack; anything else means JSON
+pub const WS_FORMAT_MSGPACK: &str = "msgpack";
+/// Feed carrying `MarketData` updates for the subscribed symbols
+pub const MARKET_DATA_FEED: &str = "market_data";
+
+/// Time between market data updates pushed to subscribers
+const MARKET_DATA_PUSH_INTERVAL: Duration = Duration::from_secs(1);
+/// Time between heartbeats sent to every client
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
 
 /// WebSocket message types for client-server communication
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
 #[serde(tag = "type", content = "payload")]
 pub enum WsMessage {
     /// Server heartbeat
@@ -61,27 +82,198 @@ pub enum WsMessage {
     },
 }
 
-/// Placeholder WebSocket route that returns a message for simulation mode
-#[allow(dead_code)]
-pub async fn websocket_route(req: HttpRequest, _stream: web::Payload) -> Result<HttpResponse, Error> {
-    info!("WebSocket connection attempt from {:?}", req.peer_addr());
-    
-    // In simulation mode, just return a message indicating WebSocket is not supported
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .body(r#"{"status":"error","message":"WebSocket not implemented in simulation mode"}"#))
+/// Encoding of the messages a session sends and receives. JSON goes out in
+/// text frames and MessagePack in binary frames; either is read from both.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WsCodec {
+    #[default]
+    Json,
+    Msgpack,
+}
+
+/// An encoded message, ready to go out as a WebSocket frame
+#[derive(Debug, Clone, PartialEq)]
+pub enum WsFrame {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl WsCodec {
+    /// The codec an upgrade request asks for in its `X-WS-Format` header
+    pub fn from_request(req: &HttpRequest) -> Self {
+        match req.headers().get(WS_FORMAT_HEADER).and_then(|value| value.to_str().ok()) {
+            Some(format) if format.trim().eq_ignore_ascii_case(WS_FORMAT_MSGPACK) => WsCodec::Msgpack,
+            _ => WsCodec::Json,
+        }
+    }
+
+    pub fn encode(&self, message: &WsMessage) -> Result<WsFrame, String> {
+        match self {
+            WsCodec::Json => serde_json::to_string(message)
+                .map(WsFrame::Text)
+                .map_err(|e| format!("Failed to encode message as JSON: {}", e)),
+            // Structs as maps, so clients can decode fields by name
+            WsCodec::Msgpack => rmp_serde::to_vec_named(message)
+                .map(WsFrame::Binary)
+                .map_err(|e| format!("Failed to encode message as MessagePack: {}", e)),
+        }
+    }
+
+    pub fn decode(&self, frame: &[u8]) -> Result<WsMessage, String> {
+        match self {
+            WsCodec::Json => serde_json::from_slice(frame)
+                .map_err(|e| format!("Invalid JSON message: {}", e)),
+            WsCodec::Msgpack => rmp_serde::from_slice(frame)
+                .map_err(|e| format!("Invalid MessagePack message: {}", e)),
+        }
+    }
+}
+
+/// One client connection: answers its subscriptions and pushes the market
+/// data it subscribed to, encoded with the codec it asked for
+pub struct WsSession {
+    client_id: String,
+    codec: WsCodec,
+    session: Session,
+    market_data: Arc<RwLock<MarketData>>,
+    all_symbols: bool, // Subscribed to market data for every symbol
+    symbols: BTreeSet<String>, // Subscribed to market data for these symbols
+}
+
+impl WsSession {
+    pub fn new(codec: WsCodec, session: Session, market_data: Arc<RwLock<MarketData>>) -> Self {
+        WsSession {
+            client_id: Uuid::new_v4().to_string(),
+            codec,
+            session,
+            market_data,
+            all_symbols: false,
+            symbols: BTreeSet::new(),
+        }
+    }
+
+    /// Serve the client until it closes the connection or the connection drops
+    pub async fn run(mut self, mut messages: AggregatedMessageStream) {
+        let mut push = tokio::time::interval(MARKET_DATA_PUSH_INTERVAL);
+        push.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        let mut heartbeat = tokio::time::interval_at(tokio::time::Instant::now() + HEARTBEAT_INTERVAL, HEARTBEAT_INTERVAL);
+
+        let connect = WsMessage::Connect { client_id: self.client_id.clone() };
+        let mut result = self.send(&connect).await;
+        while result.is_ok() {
+            result = tokio::select! {
+                message = messages.recv() => match message {
+                    Some(Ok(AggregatedMessage::Text(text))) => self.handle_frame(text.as_bytes()).await,
+                    Some(Ok(AggregatedMessage::Binary(bytes))) => self.handle_frame(&bytes).await,
+                    Some(Ok(AggregatedMessage::Ping(bytes))) => self.session.pong(&bytes).await,
+                    Some(Ok(AggregatedMessage::Pong(_))) => Ok(()),
+                    Some(Ok(AggregatedMessage::Close(reason))) => {
+                        let _ = self.session.clone().close(reason).await;
+                        break;
+                    },
+                    Some(Err(e)) => {
+                        debug!("WebSocket client {} sent an invalid frame: {}", self.client_id, e);
+                        break;
+                    },
+                    None => break,
+                },
+                _ = push.tick() => self.push_market_data().await,
+                _ = heartbeat.tick() => self.send(&WsMessage::Heartbeat).await,
+            };
+        }
+
+        info!("WebSocket client {} disconnected", self.client_id);
+    }
+
+    async fn handle_frame(&mut self, frame: &[u8]) -> Result<(), Closed> {
+        let message = match self.codec.decode(frame) {
+            Ok(message) => message,
+            Err(e) => return self.send_error("invalid_message", &e).await,
+        };
+
+        match message {
+            WsMessage::Subscribe { feed, symbols } if feed == MARKET_DATA_FEED => {
+                match symbols {
+                    Some(symbols) => self.symbols.extend(symbols),
+                    None => self.all_symbols = true,
+                }
+                // Current prices straight away rather than on the next push
+                self.push_market_data().await
+            },
+            WsMessage::Unsubscribe { feed, symbols } if feed == MARKET_DATA_FEED => {
+                match symbols {
+                    Some(symbols) => symbols.iter().for_each(|symbol| { self.symbols.remove(symbol); }),
+                    None => {
+                        self.all_symbols = false;
+                        self.symbols.clear();
+                    },
+                }
+                Ok(())
+            },
+            WsMessage::Subscribe { feed, .. } | WsMessage::Unsubscribe { feed, .. } => {
+                self.send_error("unknown_feed", &format!("Unknown feed: {}", feed)).await
+            },
+            WsMessage::Heartbeat => Ok(()),
+            _ => self.send_error("unsupported_message", "Only subscribe, unsubscribe and heartbeat messages are accepted").await,
+        }
+    }
+
+    // Send the latest data for every subscribed symbol that has any
+    async fn push_market_data(&mut self) -> Result<(), Closed> {
+        let updates: Vec<WsMessage> = {
+            let market_data = self.market_data.read().await;
+            let mut assets: Vec<_> = market_data.asset_data.values()
+                .filter(|asset| self.all_symbols || self.symbols.contains(&asset.symbol))
+                .collect();
+            assets.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+            assets.into_iter()
+                .map(|asset| WsMessage::MarketData {
+                    symbol: asset.symbol.clone(),
+                    price: asset.price.to_f64(),
+                    bid: asset.bid.to_f64(),
+                    ask: asset.ask.to_f64(),
+                    volume: asset.volume,
+                    timestamp: asset.last_update.to_rfc3339(),
+                })
+                .collect()
+        };
+
+        for update in &updates {
+            self.send(update).await?;
+        }
+        Ok(())
+    }
+
+    async fn send_error(&mut self, code: &str, message: &str) -> Result<(), Closed> {
+        self.send(&WsMessage::Error { code: code.to_string(), message: message.to_string() }).await
+    }
+
+    async fn send(&mut self, message: &WsMessage) -> Result<(), Closed> {
+        match self.codec.encode(message) {
+            Ok(WsFrame::Text(text)) => self.session.text(text).await,
+            Ok(WsFrame::Binary(bytes)) => self.session.binary(bytes).await,
+            Err(e) => {
+                debug!("Dropping message to WebSocket client {}: {}", self.client_id, e);
+                Ok(())
+            },
+        }
+    }
 }
 
-/// WebSocket index handler - also a placeholder for simulation mode
+/// Upgrade to a WebSocket session speaking JSON, or MessagePack when the
+/// request carries `X-WS-Format: msgpack`
 pub async fn ws_index(
-    _req: HttpRequest, 
-    _stream: web::Payload,
-    _data: web::Data<AppState>
+    req: HttpRequest,
+    stream: web::Payload,
+    data: web::Data<AppState>,
 ) -> Result<HttpResponse, Error> {
-    debug!("WebSocket connection attempt at /ws");
-    
-    // In simulation mode, just return a message
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .body(r#"{"status":"error","message":"WebSocket not implemented in simulation mode"}"#))
-} 
\ No newline at end of file
+    let codec = WsCodec::from_request(&req);
+    let (response, session, messages) = actix_ws::handle(&req, stream)?;
+    let market_data = data.market_data_manager.read().await.get_current_data();
+
+    let session = WsSession::new(codec, session, market_data);
+    info!("WebSocket client {} connected from {:?} using {:?}", session.client_id, req.peer_addr(), codec);
+    actix_web::rt::spawn(session.run(messages.aggregate_continuations()));
+
+    Ok(response)
+}
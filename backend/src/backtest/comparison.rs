@@ -0,0 +1,66 @@
+// Side by side comparison of two completed backtest runs
+use std::collections::{BTreeSet, HashMap};
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::BacktestRun;
+
+/// How run `b` differs from run `a`. Every delta is b's figure minus a's.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BacktestComparison {
+    pub a_id: Uuid,
+    pub b_id: Uuid,
+    pub return_delta: f64,
+    pub sharpe_delta: f64,
+    pub max_drawdown_delta: f64,
+    pub win_rate_delta: f64,
+    pub trade_count_delta: i64,
+    /// Settings and strategy parameters that differ, as (a, b). Strategy
+    /// parameters are keyed `parameters.<name>`; one only a single run set
+    /// is null on the other side.
+    pub parameter_differences: HashMap<String, (Value, Value)>,
+}
+
+impl BacktestComparison {
+    /// Compare two runs, or return the id of the first one without a result
+    pub fn new(a: &BacktestRun, b: &BacktestRun) -> Result<Self, Uuid> {
+        let a_result = a.result.as_ref().ok_or(a.id)?;
+        let b_result = b.result.as_ref().ok_or(b.id)?;
+
+        let (a_config, b_config) = (run_config(a), run_config(b));
+        let keys: BTreeSet<&String> = a_config.keys().chain(b_config.keys()).collect();
+        let parameter_differences = keys.into_iter()
+            .filter_map(|key| {
+                let a_value = a_config.get(key).cloned().unwrap_or(Value::Null);
+                let b_value = b_config.get(key).cloned().unwrap_or(Value::Null);
+                (a_value != b_value).then(|| (key.clone(), (a_value, b_value)))
+            })
+            .collect();
+
+        Ok(BacktestComparison {
+            a_id: a.id,
+            b_id: b.id,
+            return_delta: b_result.total_return - a_result.total_return,
+            sharpe_delta: b_result.sharpe_ratio - a_result.sharpe_ratio,
+            max_drawdown_delta: b_result.max_drawdown - a_result.max_drawdown,
+            win_rate_delta: b_result.win_rate() - a_result.win_rate(),
+            trade_count_delta: b_result.trades as i64 - a_result.trades as i64,
+            parameter_differences,
+        })
+    }
+}
+
+// What a run was asked to do, one value per setting or strategy parameter
+fn run_config(run: &BacktestRun) -> HashMap<String, Value> {
+    let mut config = HashMap::from([
+        ("strategy".to_string(), json!(run.strategy)),
+        ("symbols".to_string(), json!(run.symbols)),
+        ("start".to_string(), json!(run.start)),
+        ("end".to_string(), json!(run.end)),
+        ("initial_capital".to_string(), json!(run.initial_capital)),
+    ]);
+    config.extend(run.parameters.iter().map(|(name, value)| (format!("parameters.{}", name), value.clone())));
+    config
+}
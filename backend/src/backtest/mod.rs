@@ -0,0 +1,186 @@
+// Backtesting: replay historical market data through a strategy
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tracing::debug;
+use utoipa::ToSchema;
+
+use crate::strategy::{MarketData, Strategy, StrategyParams, TradeDirection};
+
+pub mod comparison;
+pub mod monte_carlo;
+pub mod runner;
+pub mod store;
+pub mod walk_forward;
+
+pub use comparison::BacktestComparison;
+pub use monte_carlo::{MonteCarloJob, MonteCarloJobStatus, MonteCarloResult, MAX_MONTE_CARLO_ITERATIONS};
+pub use runner::{BacktestRunner, BacktestSpec, HistoryLoader};
+pub use store::{BacktestRun, BacktestStatus, BacktestStore};
+pub use walk_forward::{WalkForwardConfig, WalkForwardPeriod};
+
+/// Periods per year used to annualize the Sharpe ratio, assuming daily snapshots
+pub const PERIODS_PER_YEAR: f64 = 252.0;
+
+/// Builds a fresh instance of the strategy under test for each backtest run
+pub type StrategyFactory = Box<dyn Fn() -> Box<dyn Strategy> + Send + Sync>;
+
+/// Performance of a strategy over one backtest run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BacktestResult {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub periods: usize,
+    pub initial_capital: f64,
+    pub final_capital: f64,
+    pub total_return: f64,  // Fraction of initial capital
+    pub sharpe_ratio: f64,  // Annualized; 0 when returns don't vary
+    pub max_drawdown: f64,  // Fraction of the equity peak
+    pub trades: usize,
+    pub returns: Vec<f64>,  // Return of each period, as a fraction of equity
+    pub trade_log: Vec<BacktestTrade>, // Every position held, in the order taken
+}
+
+impl BacktestResult {
+    /// Fraction of the positions held that made money, 0 without any
+    pub fn win_rate(&self) -> f64 {
+        if self.trade_log.is_empty() {
+            return 0.0;
+        }
+        self.trade_log.iter().filter(|trade| trade.pnl > 0.0).count() as f64 / self.trade_log.len() as f64
+    }
+}
+
+/// One position held for one period of a backtest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BacktestTrade {
+    pub timestamp: DateTime<Utc>, // Start of the period
+    pub asset: String,
+    pub quantity: f64, // Negative for a short
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub pnl: f64,
+}
+
+/// Replays a series of market data snapshots through a strategy. Period `i`
+/// runs from snapshot `i` to snapshot `i + 1`: the strategy's signals at
+/// snapshot `i` set the positions held, which are marked to market at the
+/// next snapshot.
+pub struct BacktestEngine {
+    history: Vec<MarketData>, // Oldest first
+    strategy_factory: StrategyFactory,
+    initial_capital: f64,
+}
+
+impl BacktestEngine {
+    pub fn new(history: Vec<MarketData>, strategy_factory: StrategyFactory, initial_capital: f64) -> Self {
+        BacktestEngine {
+            history,
+            strategy_factory,
+            initial_capital,
+        }
+    }
+
+    /// Number of periods the history covers, one fewer than its snapshots
+    pub fn periods(&self) -> usize {
+        self.history.len().saturating_sub(1)
+    }
+
+    /// Timestamp of the snapshot that starts period `index`, or of the last
+    /// snapshot when `index == periods()`
+    pub fn period_start(&self, index: usize) -> Option<DateTime<Utc>> {
+        self.history.get(index).map(|data| data.timestamp)
+    }
+
+    /// Run the strategy with `params` over periods `start..end`
+    pub fn backtest(&self, params: &StrategyParams, start: usize, end: usize) -> Result<BacktestResult, String> {
+        if start >= end || end > self.periods() {
+            return Err(format!("Invalid backtest range {}..{} over {} periods", start, end, self.periods()));
+        }
+
+        let mut strategy = (self.strategy_factory)();
+        strategy.update_params(params.clone()).map_err(|e| e.to_string())?;
+
+        let mut equity = self.initial_capital;
+        let mut peak = equity;
+        let mut max_drawdown: f64 = 0.0;
+        let mut trades = 0;
+        let mut returns = Vec::with_capacity(end - start);
+        let mut trade_log = Vec::new();
+
+        for index in start..end {
+            let current = &self.history[index];
+            let next = &self.history[index + 1];
+            let result = strategy.evaluate(current);
+
+            // Signals set the holding for this period; the last signal for an asset wins
+            let mut holdings: HashMap<&str, f64> = HashMap::new();
+            for signal in &result.signals {
+                let quantity = match signal.direction {
+                    TradeDirection::Buy => signal.quantity,
+                    TradeDirection::Sell => -signal.quantity,
+                };
+                holdings.insert(signal.asset.as_str(), quantity);
+                trades += 1;
+            }
+
+            // Sorted so the trade log doesn't depend on hash order
+            let mut held: Vec<(&str, f64)> = holdings.into_iter().collect();
+            held.sort_by(|a, b| a.0.cmp(b.0));
+
+            let mut pnl = 0.0;
+            for (asset, quantity) in held {
+                let (Some(entry), Some(exit)) = (current.asset_data.get(asset), next.asset_data.get(asset)) else {
+                    continue;
+                };
+                let trade_pnl = quantity * (exit.price - entry.price).to_f64();
+                pnl += trade_pnl;
+                trade_log.push(BacktestTrade {
+                    timestamp: current.timestamp,
+                    asset: asset.to_string(),
+                    quantity,
+                    entry_price: entry.price.to_f64(),
+                    exit_price: exit.price.to_f64(),
+                    pnl: trade_pnl,
+                });
+            }
+
+            returns.push(if equity != 0.0 { pnl / equity } else { 0.0 });
+            equity += pnl;
+            peak = peak.max(equity);
+            if peak > 0.0 {
+                max_drawdown = max_drawdown.max((peak - equity) / peak);
+            }
+        }
+
+        debug!("Backtest {}..{}: {:.2} -> {:.2} over {} trades", start, end, self.initial_capital, equity, trades);
+
+        Ok(BacktestResult {
+            start: self.history[start].timestamp,
+            end: self.history[end].timestamp,
+            periods: end - start,
+            initial_capital: self.initial_capital,
+            final_capital: equity,
+            total_return: (equity - self.initial_capital) / self.initial_capital,
+            sharpe_ratio: sharpe_ratio(&returns),
+            max_drawdown,
+            trades,
+            returns,
+            trade_log,
+        })
+    }
+}
+
+// Annualized mean over standard deviation of periodic returns
+fn sharpe_ratio(returns: &[f64]) -> f64 {
+    if returns.len() < 2 {
+        return 0.0;
+    }
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    if variance <= 0.0 {
+        return 0.0;
+    }
+    mean / variance.sqrt() * PERIODS_PER_YEAR.sqrt()
+}
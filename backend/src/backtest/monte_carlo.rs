@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::BacktestResult;
+
+/// Most iterations a single Monte Carlo run may ask for
+pub const MAX_MONTE_CARLO_ITERATIONS: usize = 100_000;
+
+/// Distribution of total returns over bootstrapped resamples of a backtest's trades
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MonteCarloResult {
+    pub p5_total_return: f64, // Fractions of initial capital
+    pub p50_total_return: f64,
+    pub p95_total_return: f64,
+    pub probability_of_loss: f64, // Share of simulations that lost money
+    pub simulations: Vec<f64>, // Total return of each simulation, in the order run
+}
+
+impl BacktestResult {
+    /// Resample the trade log `iterations` times, with replacement and at its
+    /// original size, and collect the total return of each resample
+    pub fn monte_carlo(&self, iterations: usize) -> MonteCarloResult {
+        self.monte_carlo_with_rng(iterations, &mut rand::thread_rng())
+    }
+
+    /// `monte_carlo` with a caller-supplied random number generator, so runs can be reproduced
+    pub fn monte_carlo_with_rng<R: Rng + ?Sized>(&self, iterations: usize, rng: &mut R) -> MonteCarloResult {
+        let simulations: Vec<f64> = (0..iterations)
+            .map(|_| {
+                let pnl: f64 = (0..self.trade_log.len())
+                    .filter_map(|_| self.trade_log.choose(rng))
+                    .map(|trade| trade.pnl)
+                    .sum();
+                if self.initial_capital != 0.0 { pnl / self.initial_capital } else { 0.0 }
+            })
+            .collect();
+
+        let mut sorted = simulations.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let losses = simulations.iter().filter(|total_return| **total_return < 0.0).count();
+
+        MonteCarloResult {
+            p5_total_return: percentile(&sorted, 0.05),
+            p50_total_return: percentile(&sorted, 0.50),
+            p95_total_return: percentile(&sorted, 0.95),
+            probability_of_loss: if simulations.is_empty() { 0.0 } else { losses as f64 / simulations.len() as f64 },
+            simulations,
+        }
+    }
+}
+
+// Linearly interpolated percentile of an ascending series; 0 when it is empty
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        len => {
+            let rank = p.clamp(0.0, 1.0) * (len - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MonteCarloJobStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// A Monte Carlo run on a stored backtest, started in the background
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MonteCarloJob {
+    pub job_id: Uuid,
+    pub backtest_id: Uuid,
+    pub iterations: usize,
+    pub status: MonteCarloJobStatus,
+    pub result: Option<MonteCarloResult>, // Set once the job has completed
+    pub error: Option<String>, // Why a failed job failed
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl MonteCarloJob {
+    pub fn new(backtest_id: Uuid, iterations: usize) -> Self {
+        MonteCarloJob {
+            job_id: Uuid::new_v4(),
+            backtest_id,
+            iterations,
+            status: MonteCarloJobStatus::Running,
+            result: None,
+            error: None,
+            started_at: Utc::now(),
+            completed_at: None,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+
+use super::{BacktestEngine, BacktestResult, StrategyFactory};
+use crate::strategy::{MarketData, StrategyParams};
+
+/// Loads the market data snapshots, oldest first, covering `symbols` from `start` to `end`
+pub type HistoryLoader = Box<dyn Fn(&[String], DateTime<Utc>, DateTime<Utc>) -> Result<Vec<MarketData>, String> + Send + Sync>;
+
+/// What to backtest: a registered strategy over a range of history
+#[derive(Debug, Clone)]
+pub struct BacktestSpec {
+    pub strategy: String,
+    pub symbols: Vec<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub initial_capital: f64,
+    pub params: StrategyParams,
+}
+
+/// Runs backtests of strategies registered by name over history from a loader
+#[derive(Default)]
+pub struct BacktestRunner {
+    strategies: HashMap<String, Arc<StrategyFactory>>,
+    history_loader: Option<HistoryLoader>,
+}
+
+impl BacktestRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_strategy(mut self, name: &str, factory: StrategyFactory) -> Self {
+        self.strategies.insert(name.to_string(), Arc::new(factory));
+        self
+    }
+
+    pub fn with_history_loader(mut self, loader: HistoryLoader) -> Self {
+        self.history_loader = Some(loader);
+        self
+    }
+
+    pub fn has_strategy(&self, name: &str) -> bool {
+        self.strategies.contains_key(name)
+    }
+
+    /// Run `spec` over every period of the history loaded for it. Blocks for
+    /// as long as the backtest takes.
+    pub fn run(&self, spec: &BacktestSpec) -> Result<BacktestResult, String> {
+        let factory = self.strategies.get(&spec.strategy)
+            .cloned()
+            .ok_or_else(|| format!("Unknown strategy: {}", spec.strategy))?;
+        let loader = self.history_loader.as_ref()
+            .ok_or_else(|| "No history loader configured for backtests".to_string())?;
+        if spec.start >= spec.end {
+            return Err(format!("Backtest start {} is not before its end {}", spec.start, spec.end));
+        }
+
+        let history = loader(&spec.symbols, spec.start, spec.end)?;
+        let engine = BacktestEngine::new(history, Box::new(move || factory()), spec.initial_capital);
+        engine.backtest(&spec.params, 0, engine.periods())
+    }
+}
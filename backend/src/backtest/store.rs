@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{BacktestResult, BacktestSpec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BacktestStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// A backtest started through the store, with its result once it has completed
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BacktestRun {
+    pub id: Uuid,
+    pub strategy: String,
+    pub symbols: Vec<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub initial_capital: f64,
+    pub parameters: HashMap<String, serde_json::Value>, // Strategy parameters the run used
+    pub status: BacktestStatus,
+    pub result: Option<BacktestResult>, // Set once the run has completed
+    pub error: Option<String>, // Why a failed run failed
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl BacktestRun {
+    pub fn new(spec: &BacktestSpec) -> Self {
+        BacktestRun {
+            id: Uuid::new_v4(),
+            strategy: spec.strategy.clone(),
+            symbols: spec.symbols.clone(),
+            start: spec.start,
+            end: spec.end,
+            initial_capital: spec.initial_capital,
+            parameters: spec.params.params.clone(),
+            status: BacktestStatus::Running,
+            result: None,
+            error: None,
+            started_at: Utc::now(),
+            completed_at: None,
+        }
+    }
+}
+
+/// Backtest runs by id, kept so a result can be fetched again exactly as it was produced
+#[derive(Debug, Default)]
+pub struct BacktestStore {
+    runs: RwLock<HashMap<Uuid, BacktestRun>>,
+}
+
+impl BacktestStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a run, replacing any with the same id
+    pub async fn insert(&self, run: BacktestRun) {
+        self.runs.write().await.insert(run.id, run);
+    }
+
+    /// Store an already completed result under a new id, returning the id
+    pub async fn insert_result(&self, spec: &BacktestSpec, result: BacktestResult) -> Uuid {
+        let mut run = BacktestRun::new(spec);
+        let id = run.id;
+        run.status = BacktestStatus::Completed;
+        run.result = Some(result);
+        run.completed_at = Some(Utc::now());
+        self.insert(run).await;
+        id
+    }
+
+    /// Record the outcome of a running backtest. Returns false for an unknown id.
+    pub async fn complete(&self, id: Uuid, outcome: Result<BacktestResult, String>) -> bool {
+        let mut runs = self.runs.write().await;
+        let Some(run) = runs.get_mut(&id) else {
+            return false;
+        };
+        match outcome {
+            Ok(result) => {
+                run.status = BacktestStatus::Completed;
+                run.result = Some(result);
+            },
+            Err(e) => {
+                run.status = BacktestStatus::Failed;
+                run.error = Some(e);
+            },
+        }
+        run.completed_at = Some(Utc::now());
+        true
+    }
+
+    pub async fn get(&self, id: Uuid) -> Option<BacktestRun> {
+        self.runs.read().await.get(&id).cloned()
+    }
+
+    /// The result of a completed run
+    pub async fn result(&self, id: Uuid) -> Option<BacktestResult> {
+        self.runs.read().await.get(&id).and_then(|run| run.result.clone())
+    }
+
+    pub async fn len(&self) -> usize {
+        self.runs.read().await.len()
+    }
+
+    pub async fn is_empty(&self) -> bool {
+        self.runs.read().await.is_empty()
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tracing::{info, warn};
+
+use crate::strategy::StrategyParams;
+use super::BacktestEngine;
+
+/// Rolling windows for walk-forward analysis, in backtest periods
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardConfig {
+    pub train_periods: usize,
+    pub test_periods: usize,
+    pub step_periods: usize, // How far each window moves forward
+    pub param_grid: HashMap<String, Vec<serde_json::Value>>, // Candidate values per parameter
+}
+
+/// Parameters chosen on one training window and how they did out of sample
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardPeriod {
+    pub train_start: DateTime<Utc>,
+    pub train_end: DateTime<Utc>,
+    pub test_start: DateTime<Utc>,
+    pub test_end: DateTime<Utc>,
+    pub best_params: StrategyParams,
+    pub test_sharpe: f64,
+    pub test_return: f64,
+}
+
+impl BacktestEngine {
+    /// Slide a training window followed by a test window across the history.
+    /// On each training window every combination in the parameter grid is
+    /// backtested and the one with the highest Sharpe ratio is then run on
+    /// the test window. Combinations the strategy rejects are skipped.
+    pub fn walk_forward(&self, config: WalkForwardConfig) -> Vec<WalkForwardPeriod> {
+        if config.train_periods == 0 || config.test_periods == 0 || config.step_periods == 0 {
+            warn!("Walk-forward windows and step must be at least one period");
+            return Vec::new();
+        }
+
+        let combinations = param_combinations(&config.param_grid);
+        let window = config.train_periods + config.test_periods;
+        let mut periods = Vec::new();
+
+        let mut train_start = 0;
+        while train_start + window <= self.periods() {
+            let train_end = train_start + config.train_periods;
+            let test_end = train_start + window;
+
+            let best = combinations.iter()
+                .filter_map(|params| match self.backtest(params, train_start, train_end) {
+                    Ok(result) => Some((params, result.sharpe_ratio)),
+                    Err(e) => {
+                        warn!("Skipping parameters {:?}: {}", params.params, e);
+                        None
+                    }
+                })
+                .fold(None, |best: Option<(&StrategyParams, f64)>, (params, sharpe)| match best {
+                    Some((_, best_sharpe)) if best_sharpe >= sharpe => best,
+                    _ => Some((params, sharpe)),
+                });
+
+            if let Some((best_params, train_sharpe)) = best {
+                match self.backtest(best_params, train_end, test_end) {
+                    Ok(test) => {
+                        info!("Walk-forward {}..{}: train Sharpe {:.2}, test Sharpe {:.2}", train_start, test_end, train_sharpe, test.sharpe_ratio);
+                        periods.push(WalkForwardPeriod {
+                            train_start: self.history[train_start].timestamp,
+                            train_end: test.start,
+                            test_start: test.start,
+                            test_end: test.end,
+                            best_params: best_params.clone(),
+                            test_sharpe: test.sharpe_ratio,
+                            test_return: test.total_return,
+                        });
+                    },
+                    Err(e) => warn!("Walk-forward test window {}..{} failed: {}", train_end, test_end, e),
+                }
+            } else {
+                warn!("No parameter combination could be backtested on periods {}..{}", train_start, train_end);
+            }
+
+            train_start += config.step_periods;
+        }
+
+        periods
+    }
+}
+
+// Every combination of the grid's values, with parameters in name order so
+// ties resolve the same way each run. An empty grid yields one empty set.
+fn param_combinations(grid: &HashMap<String, Vec<serde_json::Value>>) -> Vec<StrategyParams> {
+    let mut names: Vec<&String> = grid.keys().collect();
+    names.sort();
+
+    let mut combinations = vec![HashMap::new()];
+    for name in names {
+        let mut next = Vec::new();
+        for combination in &combinations {
+            for value in &grid[name] {
+                let mut extended = combination.clone();
+                extended.insert(name.clone(), value.clone());
+                next.push(extended);
+            }
+        }
+        combinations = next;
+    }
+
+    combinations.into_iter().map(|params| StrategyParams { params }).collect()
+}
@@ -0,0 +1,236 @@
+// Bounded event channels with a configurable policy for when they fill up
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use serde::{Serialize, Deserialize};
+use tokio::sync::Notify;
+use tracing::{warn, Span};
+use schemars::JsonSchema;
+use utoipa::ToSchema;
+
+/// What a send does when the channel is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BackpressurePolicy {
+    /// Wait for the receiver to make room
+    Block,
+    /// Discard the oldest queued event to make room for the new one
+    DropOldest,
+    /// Discard the new event
+    DropNewestWithCounter,
+}
+
+impl BackpressurePolicy {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_lowercase().as_str() {
+            "block" => Ok(BackpressurePolicy::Block),
+            "drop_oldest" => Ok(BackpressurePolicy::DropOldest),
+            "drop_newest" | "drop_newest_with_counter" => Ok(BackpressurePolicy::DropNewestWithCounter),
+            other => Err(format!("Unknown backpressure policy: {}", other)),
+        }
+    }
+}
+
+/// Size and backpressure policy of an event channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelConfig {
+    pub capacity: usize,
+    pub policy: BackpressurePolicy,
+}
+
+impl ChannelConfig {
+    pub fn new(capacity: usize, policy: BackpressurePolicy) -> Self {
+        ChannelConfig { capacity, policy }
+    }
+}
+
+/// Point-in-time view of an event channel, for metrics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct ChannelStats {
+    pub capacity: usize,
+    pub policy: BackpressurePolicy,
+    pub queued: usize,
+    pub dropped_events: u64,
+}
+
+/// Returned by `send` once the receiver is gone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelClosed;
+
+impl fmt::Display for ChannelClosed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "channel closed")
+    }
+}
+
+struct State<T> {
+    queue: VecDeque<(T, Span)>, // Each event with the span it was sent from
+    senders: usize,
+    receiver_alive: bool,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    config: ChannelConfig,
+    dropped: AtomicU64,
+    item_available: Notify,
+    space_available: Notify,
+}
+
+/// Create a bounded channel. A capacity of zero is raised to one.
+pub fn event_channel<T>(config: ChannelConfig) -> (EventSender<T>, EventReceiver<T>) {
+    let config = ChannelConfig::new(config.capacity.max(1), config.policy);
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::with_capacity(config.capacity.min(1024)),
+            senders: 1,
+            receiver_alive: true,
+        }),
+        config,
+        dropped: AtomicU64::new(0),
+        item_available: Notify::new(),
+        space_available: Notify::new(),
+    });
+    (EventSender { shared: shared.clone() }, EventReceiver { shared })
+}
+
+/// Sending half of an event channel. Clones feed the same receiver.
+pub struct EventSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> EventSender<T> {
+    /// Queue an event along with the current span. Under `Block` this waits
+    /// while the channel is full; the drop policies never wait, and count what
+    /// they discard instead.
+    pub async fn send(&self, event: T) -> Result<(), ChannelClosed> {
+        let mut event = Some((event, Span::current()));
+        loop {
+            // Registered before checking for room so a receive in between still wakes us
+            let space_available = self.shared.space_available.notified();
+            tokio::pin!(space_available);
+            space_available.as_mut().enable();
+
+            {
+                let mut state = self.shared.state.lock().unwrap();
+                if !state.receiver_alive {
+                    return Err(ChannelClosed);
+                }
+
+                if state.queue.len() >= self.shared.config.capacity {
+                    match self.shared.config.policy {
+                        BackpressurePolicy::Block => {},
+                        BackpressurePolicy::DropOldest => {
+                            state.queue.pop_front();
+                            self.record_drop();
+                        },
+                        BackpressurePolicy::DropNewestWithCounter => {
+                            self.record_drop();
+                            return Ok(());
+                        },
+                    }
+                }
+
+                if state.queue.len() < self.shared.config.capacity {
+                    state.queue.push_back(event.take().expect("event is sent once"));
+                    drop(state);
+                    self.shared.item_available.notify_one();
+                    return Ok(());
+                }
+            }
+
+            space_available.await;
+        }
+    }
+
+    pub fn stats(&self) -> ChannelStats {
+        ChannelStats {
+            capacity: self.shared.config.capacity,
+            policy: self.shared.config.policy,
+            queued: self.shared.state.lock().unwrap().queue.len(),
+            dropped_events: self.shared.dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    fn record_drop(&self) {
+        let dropped = self.shared.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+        // Log the first drop and every thousandth after, rather than flooding the log
+        if dropped == 1 || dropped.is_multiple_of(1000) {
+            warn!("Event channel full, {} events dropped so far ({:?})", dropped, self.shared.config.policy);
+        }
+    }
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.state.lock().unwrap().senders += 1;
+        EventSender { shared: self.shared.clone() }
+    }
+}
+
+impl<T> Drop for EventSender<T> {
+    fn drop(&mut self) {
+        let last = {
+            let mut state = self.shared.state.lock().unwrap();
+            state.senders -= 1;
+            state.senders == 0
+        };
+        if last {
+            self.shared.item_available.notify_one();
+        }
+    }
+}
+
+/// Receiving half of an event channel
+pub struct EventReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> EventReceiver<T> {
+    /// Next event, oldest first, or `None` once every sender is gone and the
+    /// queue is empty
+    pub async fn recv(&mut self) -> Option<T> {
+        self.recv_with_span().await.map(|(event, _)| event)
+    }
+
+    /// Next event along with the span it was sent from, so handling it can be
+    /// traced as part of the operation that sent it
+    pub async fn recv_with_span(&mut self) -> Option<(T, Span)> {
+        loop {
+            let item_available = self.shared.item_available.notified();
+            tokio::pin!(item_available);
+            item_available.as_mut().enable();
+
+            {
+                let mut state = self.shared.state.lock().unwrap();
+                if let Some(event) = state.queue.pop_front() {
+                    drop(state);
+                    self.shared.space_available.notify_one();
+                    return Some(event);
+                }
+                if state.senders == 0 {
+                    return None;
+                }
+            }
+
+            item_available.await;
+        }
+    }
+
+    /// Next queued event, without waiting
+    pub fn try_recv(&mut self) -> Option<T> {
+        let event = self.shared.state.lock().unwrap().queue.pop_front();
+        if event.is_some() {
+            self.shared.space_available.notify_one();
+        }
+        event.map(|(event, _)| event)
+    }
+}
+
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().receiver_alive = false;
+        self.shared.space_available.notify_waiters();
+    }
+}
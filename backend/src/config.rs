@@ -0,0 +1,98 @@
+use std::collections::BTreeSet;
+use std::env::{self, VarError};
+use std::fmt;
+use std::path::Path;
+
+use crate::exchange::manager::load_exchange_configs;
+use crate::exchange::ExchangeConfig;
+
+/// Error reading the platform configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    MissingVar(String), // Name of the required environment variable
+    InvalidVar { name: String, message: String },
+    File(String), // The config file could not be read or parsed
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingVar(name) => write!(f, "Missing environment variable {}", name),
+            ConfigError::InvalidVar { name, message } => write!(f, "Invalid environment variable {}: {}", name, message),
+            ConfigError::File(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Value of the environment variable `name`; unset and blank both give `None`
+pub fn env_var(name: &str) -> Result<Option<String>, ConfigError> {
+    match env::var(name) {
+        Ok(value) if value.trim().is_empty() => Ok(None),
+        Ok(value) => Ok(Some(value)),
+        Err(VarError::NotPresent) => Ok(None),
+        Err(VarError::NotUnicode(_)) => Err(ConfigError::InvalidVar {
+            name: name.to_string(),
+            message: "not valid Unicode".to_string(),
+        }),
+    }
+}
+
+/// Prefix of the environment variables configuring the `n`th exchange, e.g. `EXCHANGE_1`
+pub fn exchange_env_prefix(n: usize) -> String {
+    format!("EXCHANGE_{}", n)
+}
+
+/// Platform configuration, from the exchange config file and the environment
+#[derive(Debug, Clone, Default)]
+pub struct TradingConfig {
+    pub exchanges: Vec<ExchangeConfig>,
+}
+
+impl TradingConfig {
+    /// Exchanges configured entirely by `EXCHANGE_1_*`, `EXCHANGE_2_*` and so
+    /// on, in that order. Gaps in the numbering are skipped.
+    pub fn from_env() -> Result<TradingConfig, ConfigError> {
+        let exchanges = exchange_env_prefixes().iter()
+            .map(|prefix| ExchangeConfig::from_env(prefix))
+            .collect::<Result<_, _>>()?;
+        Ok(TradingConfig { exchanges })
+    }
+    
+    /// Exchanges from the JSON config file at `path`, when there is one, with
+    /// the environment variables applied over them
+    pub fn load(path: &str) -> Result<TradingConfig, ConfigError> {
+        let exchanges = if Path::new(path).exists() {
+            load_exchange_configs(path).map_err(ConfigError::File)?
+        } else {
+            Vec::new()
+        };
+        let mut config = TradingConfig { exchanges };
+        config.apply_env()?;
+        Ok(config)
+    }
+    
+    /// Apply the `EXCHANGE_n_*` variables. Those whose `EXCHANGE_n_NAME` names an
+    /// exchange already configured override its fields; any others add an
+    /// exchange, and must configure it fully.
+    pub fn apply_env(&mut self) -> Result<(), ConfigError> {
+        for prefix in exchange_env_prefixes() {
+            let name = env_var(&format!("{}_NAME", prefix))?;
+            match name.and_then(|name| self.exchanges.iter_mut().find(|exchange| exchange.name == name)) {
+                Some(exchange) => exchange.apply_env(&prefix)?,
+                None => self.exchanges.push(ExchangeConfig::from_env(&prefix)?),
+            }
+        }
+        Ok(())
+    }
+}
+
+// Prefixes `EXCHANGE_n` with at least one variable set, in order of `n`
+fn exchange_env_prefixes() -> Vec<String> {
+    let indices: BTreeSet<usize> = env::vars_os()
+        .filter_map(|(name, _)| name.into_string().ok())
+        .filter_map(|name| name.strip_prefix("EXCHANGE_")?.split_once('_')?.0.parse().ok())
+        .collect();
+    indices.into_iter().map(exchange_env_prefix).collect()
+}
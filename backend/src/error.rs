@@ -0,0 +1,44 @@
+use std::fmt;
+
+/// Error from the exchange, order and strategy APIs. The variant tells callers
+/// what kind of failure it was; the message says what happened.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TradingError {
+    NotConnected(String), // Name of the exchange that is not connected
+    NotFound(String),
+    Validation(String),
+    RiskViolation(String), // A risk or trading limit refused the order
+    ExcessiveMarketImpact { estimated_bps: f64, limit_bps: f64 }, // The order would move the book further than allowed
+    InsufficientMargin { required: f64, available: f64 }, // The order needs more margin than the exchange has available
+    Conflict(String), // The request clashes with the current state, such as cancelling a filled order
+    Rejected(String), // The exchange refused the order, for this reason
+    Unavailable(String), // Nothing can serve the request for now, such as every circuit breaker being open
+    Exchange(String), // The exchange could not be reached or failed the request
+    ExchangeTimeout { exchange: String, operation: String, elapsed_ms: u64 }, // The exchange did not answer in time
+}
+
+impl fmt::Display for TradingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TradingError::NotConnected(exchange) => write!(f, "Exchange {} is not connected", exchange),
+            TradingError::Rejected(reason) => write!(f, "Rejected: {}", reason),
+            TradingError::ExcessiveMarketImpact { estimated_bps, limit_bps } => write!(
+                f, "Estimated market impact of {:.1} bps exceeds the limit of {:.1} bps", estimated_bps, limit_bps
+            ),
+            TradingError::InsufficientMargin { required, available } => write!(
+                f, "Insufficient margin: order requires {:.2}, {:.2} available", required, available
+            ),
+            TradingError::ExchangeTimeout { exchange, operation, elapsed_ms } => write!(
+                f, "{} timed out on {} after {} ms", exchange, operation, elapsed_ms
+            ),
+            TradingError::NotFound(message)
+            | TradingError::Validation(message)
+            | TradingError::RiskViolation(message)
+            | TradingError::Conflict(message)
+            | TradingError::Unavailable(message)
+            | TradingError::Exchange(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for TradingError {}
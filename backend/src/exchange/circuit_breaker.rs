@@ -0,0 +1,143 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use crate::clock::{Clock, SystemClock};
+
+/// Consecutive failures that open the breaker by default
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+/// Consecutive successful probes that close a half-open breaker by default
+pub const DEFAULT_SUCCESS_THRESHOLD: u32 = 1;
+/// Time an open breaker refuses requests for by default
+pub const DEFAULT_OPEN_DURATION: Duration = Duration::from_secs(30);
+
+/// When an exchange's circuit breaker opens and how it recovers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    pub failure_threshold: u32,
+    pub success_threshold: u32,
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        CircuitBreakerConfig {
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            success_threshold: DEFAULT_SUCCESS_THRESHOLD,
+            open_duration: DEFAULT_OPEN_DURATION,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open { until: DateTime<Utc> },
+    HalfOpen, // Letting single probe requests through to test recovery
+}
+
+/// Stops requests to an exchange after repeated consecutive failures. Once
+/// open it refuses requests for the open duration, then lets one probe
+/// through at a time; enough successful probes close it again and a failed
+/// one reopens it.
+pub struct ExchangeCircuitBreaker {
+    config: CircuitBreakerConfig,
+    clock: Arc<dyn Clock>, // Time the open duration is measured on
+    state: CircuitState,
+    consecutive_failures: u32,
+    consecutive_successes: u32, // Successful probes since the breaker went half-open
+    probe_in_flight: bool,
+}
+
+impl Default for ExchangeCircuitBreaker {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
+}
+
+impl ExchangeCircuitBreaker {
+    /// Thresholds below 1 are treated as 1
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self::with_clock(config, Arc::new(SystemClock))
+    }
+
+    /// A breaker that times its open state on `clock`
+    pub fn with_clock(config: CircuitBreakerConfig, clock: Arc<dyn Clock>) -> Self {
+        ExchangeCircuitBreaker {
+            config: CircuitBreakerConfig {
+                failure_threshold: config.failure_threshold.max(1),
+                success_threshold: config.success_threshold.max(1),
+                ..config
+            },
+            clock,
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            consecutive_successes: 0,
+            probe_in_flight: false,
+        }
+    }
+
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// The current state, moving an open breaker whose time is up to half-open
+    pub fn state(&mut self) -> CircuitState {
+        if let CircuitState::Open { until } = self.state {
+            if self.clock.now() >= until {
+                self.state = CircuitState::HalfOpen;
+                self.consecutive_successes = 0;
+                self.probe_in_flight = false;
+            }
+        }
+        self.state
+    }
+
+    /// Whether a request may go out now. In the half-open state this admits
+    /// one probe and refuses everything else until its outcome is recorded.
+    pub fn allow_request(&mut self) -> bool {
+        match self.state() {
+            CircuitState::Closed => true,
+            CircuitState::Open { .. } => false,
+            CircuitState::HalfOpen if self.probe_in_flight => false,
+            CircuitState::HalfOpen => {
+                self.probe_in_flight = true;
+                true
+            },
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        if self.state == CircuitState::HalfOpen {
+            self.probe_in_flight = false;
+            self.consecutive_successes += 1;
+            if self.consecutive_successes >= self.config.success_threshold {
+                info!("Circuit breaker closed after {} successful probes", self.consecutive_successes);
+                self.state = CircuitState::Closed;
+            }
+        }
+    }
+
+    pub fn record_failure(&mut self) {
+        self.consecutive_failures += 1;
+        let trips = match self.state {
+            CircuitState::Closed => self.consecutive_failures >= self.config.failure_threshold,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open { .. } => false,
+        };
+        if trips {
+            warn!("Circuit breaker opened for {:?} after {} consecutive failures", self.config.open_duration, self.consecutive_failures);
+            // Longer than chrono can represent is as good as forever
+            let open_duration = chrono::Duration::from_std(self.config.open_duration).unwrap_or(chrono::Duration::MAX);
+            let until = self.clock.now().checked_add_signed(open_duration).unwrap_or(DateTime::<Utc>::MAX_UTC);
+            self.state = CircuitState::Open { until };
+            self.probe_in_flight = false;
+        }
+    }
+
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+}
@@ -1,16 +1,26 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
-use chrono::Utc;
+use std::time::{Duration, Instant};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use tracing::{info, warn, debug};
 use uuid::Uuid;
 use async_trait::async_trait;
+use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday};
 
 use super::{
     Exchange, ExchangeType, ExchangeConfig, 
-    MarketSnapshot, OrderStatusResponse, AccountBalance, Position, 
-    OrderStatus as ExchangeOrderStatus,
+    MarketSnapshot, OrderStatusResponse, AccountBalance, Position, CancellationResult,
+    MarginInfo, AccountTransaction, TransactionType, FeeSchedule, OrderStatus as ExchangeOrderStatus, rejection_error,
 };
-use crate::order::Order;
+use super::fill_model::{FillModel, ConstantSlippageModel, fill_model_from_params, walk_book, MAKER_FEE_BPS_PARAM, TAKER_FEE_BPS_PARAM};
+use super::fill_schedule::FillSchedule;
+use crate::clock::{Clock, SystemClock};
+use crate::error::TradingError;
+use crate::market_data::PriceLevel;
+use crate::models::Price;
+use crate::order::{Order, OrderType};
+use crate::strategy::TradeDirection;
 use crate::order::OrderStatus as OrderOrderStatus;
 
 // Add a conversion function from OrderOrderStatus to ExchangeOrderStatus
@@ -42,14 +52,247 @@ fn convert_exchange_status(status: &ExchangeOrderStatus) -> OrderOrderStatus {
     }
 }
 
+// additional_params keys controlling simulated submission behaviour
+pub const SIMULATED_LATENCY_MS_PARAM: &str = "simulated_latency_ms";
+pub const REJECT_PROBABILITY_PARAM: &str = "reject_probability";
+pub const FAIL_PROBABILITY_PARAM: &str = "fail_probability";
+pub const SIMULATION_SEED_PARAM: &str = "simulation_seed";
+pub const BOOK_DEPTH_LEVELS_PARAM: &str = "book_depth_levels";
+pub const BOOK_LEVEL_SPACING_BPS_PARAM: &str = "book_level_spacing_bps";
+pub const MAX_LEVERAGE_PARAM: &str = "max_leverage";
+pub const FILL_SCHEDULE_PARAM: &str = "fill_schedule";
+/// Comma-separated `ASSET=fee` pairs; the trading fees are the fill model's
+/// `maker_fee_bps` and `taker_fee_bps`
+pub const WITHDRAWAL_FEES_PARAM: &str = "withdrawal_fees";
+
+// additional_params keys for the HTTP client
+pub const REQUEST_TIMEOUT_MS_PARAM: &str = "request_timeout_ms";
+pub const CONNECT_TIMEOUT_MS_PARAM: &str = "connect_timeout_ms";
+pub const MAX_IDLE_CONNECTIONS_PARAM: &str = "max_idle_connections";
+pub const VERIFY_CONNECTION_PARAM: &str = "verify_connection";
+
+/// Default limit on a whole HTTP request to the exchange, connecting included
+pub const DEFAULT_REQUEST_TIMEOUT_MS: u64 = 10_000;
+
+/// Default limit on establishing a connection to the exchange
+pub const DEFAULT_CONNECT_TIMEOUT_MS: u64 = 5_000;
+
+/// Default number of idle connections kept open to the exchange
+pub const DEFAULT_MAX_IDLE_CONNECTIONS: usize = 10;
+
+/// Default delay for a simulated order submission
+const DEFAULT_SUBMIT_LATENCY: Duration = Duration::from_millis(100);
+
+/// Default number of simulated price levels on each side of the book
+const DEFAULT_BOOK_DEPTH_LEVELS: usize = 10;
+
+/// Default gap between simulated price levels, in basis points of the top price
+const DEFAULT_BOOK_LEVEL_SPACING_BPS: f64 = 5.0;
+
+/// Default leverage the simulated margin account allows
+const DEFAULT_MAX_LEVERAGE: f64 = 5.0;
+
+// Simulated account balance, in USD
+const SIMULATED_BALANCE_TOTAL: f64 = 100000.0;
+const SIMULATED_BALANCE_AVAILABLE: f64 = 75000.0;
+
+// Simulated account history: a deposit each Monday, a withdrawal each Friday
+// and interest on the first of the month, in USD, besides daily trades and funding
+const SIMULATED_DEPOSIT: f64 = 10000.0;
+const SIMULATED_WITHDRAWAL: f64 = 2500.0;
+const SIMULATED_WITHDRAWAL_FEE: f64 = 5.0;
+const SIMULATED_MONTHLY_INTEREST: f64 = 120.0;
+const SIMULATED_MONTHLY_FEE: f64 = 10.0;
+const SIMULATED_TRADE_FEE_RATE: f64 = 0.001;
+
+// Quantity resting at each simulated bid and ask level
+const SIMULATED_BID_SIZE: f64 = 1.5;
+const SIMULATED_ASK_SIZE: f64 = 1.2;
+
+/// Simulated submission behaviour, read from the exchange config's additional params
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationSettings {
+    pub submit_latency: Duration,
+    pub reject_probability: f64,
+    pub fail_probability: f64,
+    pub seed: Option<u64>,
+    pub book_depth_levels: usize, // Market orders larger than this depth partially fill
+    pub book_level_spacing_bps: f64,
+    pub max_leverage: f64, // At least 1
+    pub fill_schedule: FillSchedule,
+}
+
+impl Default for SimulationSettings {
+    fn default() -> Self {
+        SimulationSettings {
+            submit_latency: DEFAULT_SUBMIT_LATENCY,
+            reject_probability: 0.0,
+            fail_probability: 0.0,
+            seed: None,
+            book_depth_levels: DEFAULT_BOOK_DEPTH_LEVELS,
+            book_level_spacing_bps: DEFAULT_BOOK_LEVEL_SPACING_BPS,
+            max_leverage: DEFAULT_MAX_LEVERAGE,
+            fill_schedule: FillSchedule::default(),
+        }
+    }
+}
+
+impl SimulationSettings {
+    /// Parse settings from additional params. Invalid values are logged and
+    /// replaced with defaults; probabilities are clamped to [0, 1].
+    pub fn from_params(name: &str, params: &HashMap<String, String>) -> Self {
+        let mut settings = SimulationSettings::default();
+        
+        if let Some(latency) = parse_param::<u64>(name, params, SIMULATED_LATENCY_MS_PARAM) {
+            settings.submit_latency = Duration::from_millis(latency);
+        }
+        if let Some(p) = parse_param::<f64>(name, params, REJECT_PROBABILITY_PARAM) {
+            settings.reject_probability = p.clamp(0.0, 1.0);
+        }
+        if let Some(p) = parse_param::<f64>(name, params, FAIL_PROBABILITY_PARAM) {
+            settings.fail_probability = p.clamp(0.0, 1.0);
+        }
+        settings.seed = parse_param::<u64>(name, params, SIMULATION_SEED_PARAM);
+        if let Some(levels) = parse_param::<usize>(name, params, BOOK_DEPTH_LEVELS_PARAM) {
+            settings.book_depth_levels = levels.max(1);
+        }
+        if let Some(bps) = parse_param::<f64>(name, params, BOOK_LEVEL_SPACING_BPS_PARAM) {
+            settings.book_level_spacing_bps = bps.max(0.0);
+        }
+        if let Some(leverage) = parse_param::<f64>(name, params, MAX_LEVERAGE_PARAM) {
+            settings.max_leverage = leverage.max(1.0);
+        }
+        if let Some(schedule) = parse_param::<FillSchedule>(name, params, FILL_SCHEDULE_PARAM) {
+            settings.fill_schedule = schedule;
+        }
+        
+        settings
+    }
+}
+
+/// Fee schedule from additional params. Invalid values are logged and left out.
+pub fn fee_schedule_from_params(name: &str, params: &HashMap<String, String>) -> FeeSchedule {
+    let fee = |key| parse_param::<f64>(name, params, key).filter(|bps| *bps >= 0.0).unwrap_or(0.0);
+    let withdrawal_fees = match params.get(WITHDRAWAL_FEES_PARAM).map(|value| parse_withdrawal_fees(value)) {
+        Some(Ok(fees)) => fees,
+        Some(Err(e)) => {
+            warn!("Ignoring {} for {}: {}", WITHDRAWAL_FEES_PARAM, name, e);
+            BTreeMap::new()
+        },
+        None => BTreeMap::new(),
+    };
+    
+    FeeSchedule {
+        maker_bps: fee(MAKER_FEE_BPS_PARAM),
+        taker_bps: fee(TAKER_FEE_BPS_PARAM),
+        withdrawal_fees,
+    }
+}
+
+/// Parse `ASSET=fee` pairs separated by commas, e.g. `BTC=0.0005,ETH=0.005`
+pub fn parse_withdrawal_fees(value: &str) -> Result<BTreeMap<String, f64>, String> {
+    value.split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (asset, fee) = pair.split_once('=')
+                .ok_or_else(|| format!("Expected ASSET=fee, got '{}'", pair))?;
+            match fee.trim().parse::<f64>() {
+                Ok(fee) if fee >= 0.0 && !asset.trim().is_empty() => Ok((asset.trim().to_string(), fee)),
+                _ => Err(format!("Invalid withdrawal fee '{}'", pair)),
+            }
+        })
+        .collect()
+}
+
+fn parse_param<T: std::str::FromStr>(name: &str, params: &HashMap<String, String>, key: &str) -> Option<T> {
+    let value = params.get(key)?;
+    match value.trim().parse() {
+        Ok(parsed) => Some(parsed),
+        Err(_) => {
+            warn!("Ignoring invalid {} '{}' for {}", key, value, name);
+            None
+        }
+    }
+}
+
+/// HTTP client settings, read from the exchange config's additional params
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionConfig {
+    pub request_timeout_ms: u64,
+    pub connect_timeout_ms: u64,
+    pub max_idle_connections: usize,
+    pub verify_connection: bool, // Whether connecting requests the API URL to check the exchange answers
+}
+
+impl Default for ConnectionConfig {
+    fn default() -> Self {
+        ConnectionConfig {
+            request_timeout_ms: DEFAULT_REQUEST_TIMEOUT_MS,
+            connect_timeout_ms: DEFAULT_CONNECT_TIMEOUT_MS,
+            max_idle_connections: DEFAULT_MAX_IDLE_CONNECTIONS,
+            verify_connection: false,
+        }
+    }
+}
+
+impl ConnectionConfig {
+    /// Parse settings from additional params. Invalid values, including zero
+    /// timeouts, are logged and replaced with defaults.
+    pub fn from_params(name: &str, params: &HashMap<String, String>) -> Self {
+        let mut config = ConnectionConfig::default();
+        
+        if let Some(timeout) = parse_param::<u64>(name, params, REQUEST_TIMEOUT_MS_PARAM).filter(|ms| *ms > 0) {
+            config.request_timeout_ms = timeout;
+        }
+        if let Some(timeout) = parse_param::<u64>(name, params, CONNECT_TIMEOUT_MS_PARAM).filter(|ms| *ms > 0) {
+            config.connect_timeout_ms = timeout;
+        }
+        if let Some(connections) = parse_param::<usize>(name, params, MAX_IDLE_CONNECTIONS_PARAM) {
+            config.max_idle_connections = connections;
+        }
+        if let Some(verify) = parse_param::<bool>(name, params, VERIFY_CONNECTION_PARAM) {
+            config.verify_connection = verify;
+        }
+        
+        config
+    }
+    
+    // Client applying these settings to every request
+    fn build_client(&self) -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(Duration::from_millis(self.request_timeout_ms))
+            .connect_timeout(Duration::from_millis(self.connect_timeout_ms))
+            .pool_max_idle_per_host(self.max_idle_connections)
+            .build()
+            .unwrap_or_else(|e| {
+                warn!("Using a default HTTP client: {}", e);
+                reqwest::Client::new()
+            })
+    }
+}
+
+// Outcome of a simulated order submission
+enum SubmitOutcome {
+    Accept,
+    Reject,
+    Fail,
+}
+
 /// Implementation of a cryptocurrency exchange
 #[derive(Clone)]
 pub struct CryptoExchange {
     config: ExchangeConfig,
-    #[allow(dead_code)]
     client: reqwest::Client,
+    connection: ConnectionConfig,
+    fees: FeeSchedule,
     connected: bool,
     orders: Arc<Mutex<HashMap<Uuid, OrderState>>>,
+    simulation: SimulationSettings,
+    rng: Arc<Mutex<StdRng>>, // Seeded from the config when deterministic outcomes are needed
+    fill_model: Arc<dyn FillModel>,
+    clock: Arc<dyn Clock>, // Drives timestamps and the simulated fill progression
+    order_types: Vec<OrderType>, // Every type unless the config restricts them
 }
 
 #[derive(Clone)]
@@ -59,27 +302,239 @@ struct OrderState {
     status: ExchangeOrderStatus,
     filled_quantity: f64,
     average_price: Option<f64>,
+    fill_price: f64, // Price the fill model settled on at submission
+    fillable_quantity: f64, // Quantity the book could absorb at submission
+    commission: f64, // Fees charged on the quantity filled so far
     last_update: chrono::DateTime<chrono::Utc>,
 }
 
 #[allow(dead_code)]
 impl CryptoExchange {
+    /// Create an exchange using the fill model named in the config. An invalid
+    /// fill model is logged and replaced by fills at the market price.
     pub fn new(config: ExchangeConfig) -> Self {
+        let fill_model = match fill_model_from_params(&config.additional_params) {
+            Ok(Some(model)) => model,
+            Ok(None) => Box::new(ConstantSlippageModel::default()),
+            Err(e) => {
+                warn!("Ignoring fill model for {}: {}", config.name, e);
+                Box::new(ConstantSlippageModel::default())
+            }
+        };
+        
+        Self::with_fill_model(config, fill_model)
+    }
+    
+    pub fn with_fill_model(config: ExchangeConfig, fill_model: Box<dyn FillModel>) -> Self {
+        let simulation = SimulationSettings::from_params(&config.name, &config.additional_params);
+        let connection = ConnectionConfig::from_params(&config.name, &config.additional_params);
+        let fees = fee_schedule_from_params(&config.name, &config.additional_params);
+        let rng = match simulation.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let order_types = match config.supported_order_types() {
+            Ok(order_types) => order_types.unwrap_or_else(|| OrderType::ALL.to_vec()),
+            Err(e) => {
+                warn!("Ignoring order type restriction: {}", e);
+                OrderType::ALL.to_vec()
+            }
+        };
+        
         CryptoExchange {
             config,
-            client: reqwest::Client::new(),
+            client: connection.build_client(),
+            connection,
+            fees,
             connected: false,
             orders: Arc::new(Mutex::new(HashMap::new())),
+            simulation,
+            rng: Arc::new(Mutex::new(rng)),
+            fill_model: Arc::from(fill_model),
+            clock: Arc::new(SystemClock),
+            order_types,
+        }
+    }
+    
+    /// Take timestamps and fill progression timing from `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Progress orders by `schedule` instead of the configured fill schedule
+    pub fn with_fill_schedule(mut self, schedule: FillSchedule) -> Self {
+        self.simulation.fill_schedule = schedule;
+        self
+    }
+    
+    pub fn simulation_settings(&self) -> &SimulationSettings {
+        &self.simulation
+    }
+    
+    /// Make HTTP requests with `connection` instead of the configured settings
+    pub fn with_connection_config(mut self, connection: ConnectionConfig) -> Self {
+        self.client = connection.build_client();
+        self.connection = connection;
+        self
+    }
+    
+    pub fn connection_config(&self) -> &ConnectionConfig {
+        &self.connection
+    }
+    
+    // Request the API URL to check the exchange answers, failing with
+    // `ExchangeTimeout` when it does not answer in time
+    async fn verify_reachable(&self) -> Result<(), TradingError> {
+        let started = Instant::now();
+        self.client.get(&self.config.api_url).send().await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| self.request_error("connect", started, e))?;
+        debug!("{} answered in {:?}", self.config.name, started.elapsed());
+        Ok(())
+    }
+    
+    // Error for a failed HTTP request, telling timeouts apart
+    fn request_error(&self, operation: &str, started: Instant, error: reqwest::Error) -> TradingError {
+        if error.is_timeout() {
+            TradingError::ExchangeTimeout {
+                exchange: self.config.name.clone(),
+                operation: operation.to_string(),
+                elapsed_ms: started.elapsed().as_millis() as u64,
+            }
+        } else {
+            TradingError::Exchange(format!("{} {} request failed: {}", self.config.name, operation, error))
         }
     }
     
-    async fn authenticate(&self) -> Result<(), String> {
+    // Positions the simulated account holds
+    fn simulated_positions(&self) -> Vec<Position> {
+        vec![
+            Position {
+                symbol: "BTC/USD".to_string(),
+                quantity: 1.5,
+                avg_price: 34500.0,
+                current_price: 35200.0,
+                unrealized_pnl: 1.5 * (35200.0 - 34500.0),
+                realized_pnl: 2500.0,
+                timestamp: self.clock.now(),
+                strategy_id: None,
+                opened_at: None,
+            },
+            Position {
+                symbol: "ETH/USD".to_string(),
+                quantity: 20.0,
+                avg_price: 2100.0,
+                current_price: 2250.0,
+                unrealized_pnl: 20.0 * (2250.0 - 2100.0),
+                realized_pnl: 1200.0,
+                timestamp: self.clock.now(),
+                strategy_id: None,
+                opened_at: None,
+            },
+            Position {
+                symbol: "SOL/USD".to_string(),
+                quantity: 100.0,
+                avg_price: 80.0,
+                current_price: 82.5,
+                unrealized_pnl: 100.0 * (82.5 - 80.0),
+                realized_pnl: 500.0,
+                timestamp: self.clock.now(),
+                strategy_id: None,
+                opened_at: None,
+            },
+        ]
+    }
+    
+    // Transactions the simulated account made on `day`, oldest first. Each day
+    // has its own generator, so a day's transactions are the same whatever
+    // range is asked for.
+    fn simulated_history(&self, day: NaiveDate) -> Vec<AccountTransaction> {
+        let mut rng = StdRng::seed_from_u64(day.num_days_from_ce() as u64);
+        let midnight = day.and_hms_opt(0, 0, 0).expect("midnight exists").and_utc();
+        let mut transactions = Vec::new();
+        let mut record = |transaction_type, hour: i64, amount: f64, fee: f64, description: String| {
+            transactions.push(AccountTransaction {
+                id: format!("{}-{}-{}", self.config.name, day.format("%Y%m%d"), transactions.len() + 1),
+                transaction_type,
+                currency: "USD".to_string(),
+                amount,
+                fee,
+                timestamp: midnight + chrono::Duration::hours(hour),
+                description: Some(description),
+            });
+        };
+        
+        if day.day() == 1 {
+            record(TransactionType::Interest, 0, SIMULATED_MONTHLY_INTEREST, 0.0, "Monthly interest".to_string());
+            record(TransactionType::Fee, 0, -SIMULATED_MONTHLY_FEE, 0.0, "Monthly account fee".to_string());
+        }
+        let funding = ((rng.gen::<f64>() - 0.5) * 50.0 * 100.0).round() / 100.0;
+        record(TransactionType::Funding, 8, funding, 0.0, "BTC-PERP funding".to_string());
+        if day.weekday() == Weekday::Mon {
+            record(TransactionType::Deposit, 9, SIMULATED_DEPOSIT, 0.0, "Bank deposit".to_string());
+        }
+        for (i, hour) in [10, 13, 15].into_iter().enumerate().take(rng.gen_range(1..=3)) {
+            let asset = ["BTC", "ETH", "SOL"][i];
+            let amount = ((rng.gen::<f64>() - 0.5) * 10000.0 * 100.0).round() / 100.0;
+            let fee = (amount.abs() * SIMULATED_TRADE_FEE_RATE * 100.0).round() / 100.0;
+            let side = if amount >= 0.0 { "Sell" } else { "Buy" };
+            record(TransactionType::Trade, hour, amount, fee, format!("{} {}/USD settlement", side, asset));
+        }
+        if day.weekday() == Weekday::Fri {
+            record(TransactionType::Withdrawal, 17, -SIMULATED_WITHDRAWAL, SIMULATED_WITHDRAWAL_FEE, "Bank withdrawal".to_string());
+        }
+        transactions
+    }
+    
+    /// Price the order will fill at, as decided by the fill model on submission
+    pub fn fill_price(&self, order_id: Uuid) -> Option<f64> {
+        self.orders.lock().unwrap().get(&order_id).map(|state| state.fill_price)
+    }
+    
+    /// Quantity of the order the simulated book could fill at submission. Less
+    /// than the order quantity when a market order outran the book's depth.
+    pub fn fillable_quantity(&self, order_id: Uuid) -> Option<f64> {
+        self.orders.lock().unwrap().get(&order_id).map(|state| state.fillable_quantity)
+    }
+    
+    /// Fees charged so far for the order
+    pub fn commission(&self, order_id: Uuid) -> Option<f64> {
+        self.orders.lock().unwrap().get(&order_id).map(|state| state.commission)
+    }
+    
+    // Roll the outcome of a submission against the configured probabilities
+    fn roll_submit_outcome(&self) -> SubmitOutcome {
+        let roll: f64 = self.rng.lock().unwrap().gen();
+        
+        if roll < self.simulation.reject_probability {
+            SubmitOutcome::Reject
+        } else if roll < self.simulation.reject_probability + self.simulation.fail_probability {
+            SubmitOutcome::Fail
+        } else {
+            SubmitOutcome::Accept
+        }
+    }
+    
+    // Advance the cumulative fill to `filled_quantity`, charging fees on the new quantity
+    fn apply_fill(&self, order_state: &mut OrderState, filled_quantity: f64) {
+        let fill_qty = filled_quantity - order_state.filled_quantity;
+        if fill_qty <= 0.0 {
+            return;
+        }
+        
+        order_state.commission += self.fill_model.compute_commission(&order_state.order, order_state.fill_price, fill_qty);
+        order_state.filled_quantity = filled_quantity;
+        order_state.average_price = Some(order_state.fill_price);
+    }
+    
+    async fn authenticate(&self) -> Result<(), TradingError> {
         // In a real implementation, this would handle authentication with the exchange
         
         // Check if API credentials are provided
         if self.config.api_key.is_none() || self.config.api_secret.is_none() {
             warn!("Missing API credentials for {}", self.config.name);
-            return Err("API key and secret are required".to_string());
+            return Err(TradingError::Validation("API key and secret are required".to_string()));
         }
         
         // Simulate authentication delay
@@ -89,29 +544,65 @@ impl CryptoExchange {
         Ok(())
     }
     
-    async fn get_ticker(&self, symbol: &str) -> Result<MarketSnapshot, String> {
+    async fn get_ticker(&self, symbol: &str) -> Result<MarketSnapshot, TradingError> {
         // In a real implementation, this would make an API request to get current market data
         
         // Simulate API request
         tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
         
-        // Simulate a response
-        let price = 35000.0 + rand::random::<f64>() * 1000.0;
+        // Simulate a response. Prices come from the exchange's generator so a
+        // seeded simulation quotes the same prices on every run.
+        let (price, volume) = {
+            let mut rng = self.rng.lock().unwrap();
+            (35000.0 + rng.gen::<f64>() * 1000.0, 100.0 + rng.gen::<f64>() * 50.0)
+        };
         let spread = price * 0.001; // 0.1% spread
+        let bid = price - spread / 2.0;
+        let ask = price + spread / 2.0;
         
         Ok(MarketSnapshot {
             symbol: symbol.to_string(),
-            price,
-            bid: price - spread / 2.0,
-            ask: price + spread / 2.0,
-            bid_size: 1.5,
-            ask_size: 1.2,
-            volume: 100.0 + rand::random::<f64>() * 50.0,
-            timestamp: Utc::now(),
+            price: Price::from(price),
+            bid: Price::from(bid),
+            ask: Price::from(ask),
+            bid_size: SIMULATED_BID_SIZE,
+            ask_size: SIMULATED_ASK_SIZE,
+            volume,
+            timestamp: self.clock.now(),
+            bids: self.synthetic_levels(bid, -1.0, SIMULATED_BID_SIZE),
+            asks: self.synthetic_levels(ask, 1.0, SIMULATED_ASK_SIZE),
         })
     }
     
-    async fn fetch_order_status(&self, _exchange_order_id: &str) -> Result<ExchangeOrderStatus, String> {
+    // Evenly spaced levels of equal size moving away from the top price in `direction`
+    fn synthetic_levels(&self, top: f64, direction: f64, size: f64) -> Vec<PriceLevel> {
+        let spacing = top * self.simulation.book_level_spacing_bps / 10000.0;
+        (0..self.simulation.book_depth_levels)
+            .map(|i| PriceLevel {
+                price: top + direction * spacing * i as f64,
+                quantity: size,
+            })
+            .collect()
+    }
+    
+    // Walk the book for a market order, returning the fill price moved by the
+    // impact of levels beyond the top and the quantity the book can absorb
+    fn apply_book_impact(order: &Order, ticker: &MarketSnapshot, fill_price: f64) -> (f64, f64) {
+        if order.order_type != OrderType::Market {
+            return (fill_price, order.quantity);
+        }
+        
+        let levels = match order.direction {
+            TradeDirection::Buy => &ticker.asks,
+            TradeDirection::Sell => &ticker.bids,
+        };
+        match (levels.first(), walk_book(levels, order.quantity)) {
+            (Some(top), Some((filled, average))) => (fill_price + average - top.price, filled),
+            _ => (fill_price, order.quantity), // No depth reported
+        }
+    }
+    
+    async fn fetch_order_status(&self, _exchange_order_id: &str) -> Result<ExchangeOrderStatus, TradingError> {
         // In a real implementation, this would make an API request to check order status
         
         // Simulate API request
@@ -136,18 +627,25 @@ impl Exchange for CryptoExchange {
     }
     
     fn exchange_type(&self) -> ExchangeType {
-        self.config.exchange_type.clone()
+        self.config.exchange_type
     }
     
     fn is_connected(&self) -> bool {
         self.connected
     }
     
-    async fn connect(&mut self) -> Result<(), String> {
+    fn supported_order_types(&self) -> Vec<OrderType> {
+        self.order_types.clone()
+    }
+    
+    async fn connect(&mut self) -> Result<(), TradingError> {
         info!("Connecting to crypto exchange: {}", self.config.name);
         
         // Authenticate with the exchange
         self.authenticate().await?;
+        if self.connection.verify_connection {
+            self.verify_reachable().await?;
+        }
         
         self.connected = true;
         info!("Connected to {}", self.config.name);
@@ -155,7 +653,7 @@ impl Exchange for CryptoExchange {
         Ok(())
     }
     
-    async fn disconnect(&mut self) -> Result<(), String> {
+    async fn disconnect(&mut self) -> Result<(), TradingError> {
         info!("Disconnecting from crypto exchange: {}", self.config.name);
         
         // In a real implementation, this would properly close connections and log out
@@ -166,9 +664,9 @@ impl Exchange for CryptoExchange {
         Ok(())
     }
     
-    async fn get_supported_assets(&self) -> Result<Vec<String>, String> {
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError> {
         if !self.connected {
-            return Err("Not connected to exchange".to_string());
+            return Err(TradingError::NotConnected(self.config.name.clone()));
         }
         
         // In a real implementation, this would query the exchange for supported assets
@@ -184,17 +682,18 @@ impl Exchange for CryptoExchange {
         ])
     }
     
-    async fn get_market_data(&self, symbol: &str) -> Result<MarketSnapshot, String> {
+    async fn get_market_data(&self, symbol: &str) -> Result<MarketSnapshot, TradingError> {
         if !self.connected {
-            return Err("Not connected to exchange".to_string());
+            return Err(TradingError::NotConnected(self.config.name.clone()));
         }
         
         self.get_ticker(symbol).await
     }
     
-    async fn submit_order(&self, order: Order) -> Result<(), String> {
+    #[tracing::instrument(skip(self, order), fields(symbol = %order.symbol, qty = order.quantity, order_id = %order.id))]
+    async fn submit_order(&self, order: Order) -> Result<(), TradingError> {
         if !self.connected {
-            return Err("Not connected to exchange".to_string());
+            return Err(TradingError::NotConnected(self.config.name.clone()));
         }
         
         info!("Submitting order to {}: {} {} {} at {:?}",
@@ -210,7 +709,35 @@ impl Exchange for CryptoExchange {
         // In a real implementation, this would submit the order to the exchange API
         
         // Simulate API request
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        tokio::time::sleep(self.simulation.submit_latency).await;
+        
+        match self.roll_submit_outcome() {
+            SubmitOutcome::Accept => {},
+            SubmitOutcome::Reject => {
+                warn!("{} rejected order {} (simulated)", self.config.name, order.id);
+                return Err(rejection_error(&format!("Simulated rejection by {}", self.config.name)));
+            },
+            SubmitOutcome::Fail => {
+                warn!("Submission of order {} to {} failed (simulated)", order.id, self.config.name);
+                return Err(TradingError::Exchange(format!("Simulated failure submitting to {}", self.config.name)));
+            },
+        }
+        
+        // Settle the fill price against the market at submission. Market orders
+        // also pay for the depth they take beyond the top of the book.
+        let ticker = self.get_ticker(&order.symbol).await?;
+        if order.post_only && order.crosses_spread(ticker.bid, ticker.ask) {
+            warn!("{} rejected post-only order {} crossing the spread", self.config.name, order.id);
+            return Err(rejection_error(&format!(
+                "Post-only order at {} would cross the spread (bid {}, ask {})", order.price.unwrap_or_default(), ticker.bid, ticker.ask
+            )));
+        }
+        let model_price = self.fill_model.compute_fill_price(&order, &ticker);
+        let (fill_price, fillable_quantity) = Self::apply_book_impact(&order, &ticker, model_price);
+        if fillable_quantity < order.quantity {
+            warn!("Order {} for {} exceeds simulated depth on {}; only {} can fill",
+                order.id, order.quantity, self.config.name, fillable_quantity);
+        }
         
         // Generate a fake exchange order ID
         let exchange_order_id = format!("EX-{}", Uuid::new_v4().simple());
@@ -223,29 +750,32 @@ impl Exchange for CryptoExchange {
             status: ExchangeOrderStatus::Pending,
             filled_quantity: 0.0,
             average_price: None,
-            last_update: Utc::now(),
+            fill_price,
+            fillable_quantity,
+            commission: 0.0,
+            last_update: self.clock.now(),
         });
         
-        debug!("Order submitted to {}: internal ID={}, exchange ID={}",
-            self.config.name, order.id, exchange_order_id);
+        debug!("Order submitted to {}: internal ID={}, exchange ID={}, fill price={}",
+            self.config.name, order.id, exchange_order_id, fill_price);
         
         Ok(())
     }
     
-    async fn cancel_order(&self, order_id: Uuid) -> Result<(), String> {
+    async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError> {
         if !self.connected {
-            return Err("Not connected to exchange".to_string());
+            return Err(TradingError::NotConnected(self.config.name.clone()));
         }
         
         // Look up the order
         let exchange_order_id = {
             let orders = self.orders.lock().unwrap();
             let order_state = orders.get(&order_id)
-                .ok_or_else(|| format!("Order {} not found", order_id))?;
+                .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))?;
                 
             match &order_state.exchange_order_id {
                 Some(id) => id.clone(),
-                None => return Err(format!("Order {} has no exchange ID", order_id)),
+                None => return Err(TradingError::Exchange(format!("Order {} has no exchange ID", order_id))),
             }
         };
         
@@ -259,20 +789,24 @@ impl Exchange for CryptoExchange {
         
         // Update the order status
         let mut orders = self.orders.lock().unwrap();
-        if let Some(order_state) = orders.get_mut(&order_id) {
-            order_state.status = ExchangeOrderStatus::Cancelled;
-            order_state.last_update = Utc::now();
-        }
+        let order_state = orders.get_mut(&order_id)
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))?;
+        order_state.status = ExchangeOrderStatus::Cancelled;
+        order_state.last_update = self.clock.now();
         
         debug!("Order cancelled on {}: internal ID={}, exchange ID={}",
             self.config.name, order_id, exchange_order_id);
             
-        Ok(())
+        Ok(CancellationResult {
+            exchange_order_id: Some(exchange_order_id),
+            cancelled_at: order_state.last_update,
+            unfilled_quantity: order_state.order.quantity - order_state.filled_quantity,
+        })
     }
     
-    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, String> {
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
         if !self.connected {
-            return Err("Exchange not connected".to_string());
+            return Err(TradingError::NotConnected(self.config.name.clone()));
         }
         
         // Find the order in our records
@@ -282,22 +816,21 @@ impl Exchange for CryptoExchange {
         };
         
         if let Some(mut order_state) = order_state {
-            // Simulate status updates based on time
-            let elapsed = (Utc::now() - order_state.last_update).num_seconds();
-            
-            // Determine the next status based on elapsed time
-            if elapsed > 2 && order_state.status == ExchangeOrderStatus::Pending {
-                order_state.status = ExchangeOrderStatus::Open;
-            } else if elapsed > 5 && order_state.status == ExchangeOrderStatus::Open {
-                order_state.status = ExchangeOrderStatus::PartiallyFilled;
-                order_state.filled_quantity = order_state.order.quantity * 0.5;
-                
-                // Get ticker price without holding the MutexGuard
-                let ticker = self.get_ticker(&order_state.order.symbol).await?;
-                order_state.average_price = Some(ticker.price);
-            } else if elapsed > 10 && order_state.status == ExchangeOrderStatus::PartiallyFilled {
-                order_state.status = ExchangeOrderStatus::Filled;
-                order_state.filled_quantity = order_state.order.quantity;
+            // Simulate status updates from the time since submission
+            let live = matches!(order_state.status,
+                ExchangeOrderStatus::Pending | ExchangeOrderStatus::Open | ExchangeOrderStatus::PartiallyFilled);
+            let elapsed = (self.clock.now() - order_state.last_update).to_std().unwrap_or_default();
+            if let Some(fraction) = self.simulation.fill_schedule.filled_fraction(elapsed).filter(|_| live) {
+                // Orders larger than the book stay partially filled with the rest open
+                let filled = (order_state.order.quantity * fraction).min(order_state.fillable_quantity);
+                self.apply_fill(&mut order_state, filled);
+                order_state.status = if order_state.filled_quantity >= order_state.order.quantity {
+                    ExchangeOrderStatus::Filled
+                } else if order_state.filled_quantity > 0.0 {
+                    ExchangeOrderStatus::PartiallyFilled
+                } else {
+                    ExchangeOrderStatus::Open
+                };
             }
             
             // Update the order in storage
@@ -310,7 +843,7 @@ impl Exchange for CryptoExchange {
             
             // Convert order to response
             let response = OrderStatusResponse {
-                order_id: order_id,
+                order_id,
                 exchange_order_id: order_state.exchange_order_id.clone(),
                 status: order_state.status.clone(),
                 filled_quantity: order_state.filled_quantity,
@@ -321,13 +854,46 @@ impl Exchange for CryptoExchange {
             
             Ok(response)
         } else {
-            Err(format!("Order not found: {}", order_id))
+            Err(TradingError::NotFound(format!("Order not found: {}", order_id)))
         }
     }
     
-    async fn get_account_balance(&self) -> Result<AccountBalance, String> {
+    async fn amend_order(&self, order_id: Uuid, new_quantity: f64) -> Result<(), TradingError> {
         if !self.connected {
-            return Err("Not connected to exchange".to_string());
+            return Err(TradingError::NotConnected(self.config.name.clone()));
+        }
+        
+        // In a real implementation, this would send an amend request to the exchange API
+        
+        // Simulate API request
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        
+        let mut orders = self.orders.lock().unwrap();
+        let order_state = orders.get_mut(&order_id)
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))?;
+        let resting = matches!(order_state.status,
+            ExchangeOrderStatus::Pending | ExchangeOrderStatus::Open | ExchangeOrderStatus::PartiallyFilled);
+        if !resting {
+            return Err(rejection_error(&format!("Order {} is no longer open", order_id)));
+        }
+        if new_quantity <= order_state.filled_quantity || new_quantity >= order_state.order.quantity {
+            return Err(rejection_error(&format!(
+                "Amended quantity {} must be above the {} filled and below the current {}",
+                new_quantity, order_state.filled_quantity, order_state.order.quantity
+            )));
+        }
+        
+        debug!("Order amended on {}: internal ID={}, quantity {} -> {}",
+            self.config.name, order_id, order_state.order.quantity, new_quantity);
+        order_state.order.quantity = new_quantity;
+        order_state.fillable_quantity = order_state.fillable_quantity.min(new_quantity);
+        order_state.last_update = self.clock.now();
+        Ok(())
+    }
+    
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError> {
+        if !self.connected {
+            return Err(TradingError::NotConnected(self.config.name.clone()));
         }
         
         // In a real implementation, this would query the exchange API
@@ -337,21 +903,21 @@ impl Exchange for CryptoExchange {
         
         // Return a simulated balance
         Ok(AccountBalance {
-            total: 100000.0,
-            available: 75000.0,
+            total: SIMULATED_BALANCE_TOTAL,
+            available: SIMULATED_BALANCE_AVAILABLE,
             currency: "USD".to_string(),
             additional_balances: vec![
                 ("BTC".to_string(), 1.5),
                 ("ETH".to_string(), 20.0),
                 ("SOL".to_string(), 100.0),
             ],
-            timestamp: Utc::now(),
+            timestamp: self.clock.now(),
         })
     }
     
-    async fn get_positions(&self) -> Result<Vec<Position>, String> {
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError> {
         if !self.connected {
-            return Err("Not connected to exchange".to_string());
+            return Err(TradingError::NotConnected(self.config.name.clone()));
         }
         
         // In a real implementation, this would query the exchange API
@@ -359,35 +925,60 @@ impl Exchange for CryptoExchange {
         // Simulate API request
         tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
         
-        // Return simulated positions
-        Ok(vec![
-            Position {
-                symbol: "BTC/USD".to_string(),
-                quantity: 1.5,
-                avg_price: 34500.0,
-                current_price: 35200.0,
-                unrealized_pnl: 1.5 * (35200.0 - 34500.0),
-                realized_pnl: 2500.0,
-                timestamp: Utc::now(),
-            },
-            Position {
-                symbol: "ETH/USD".to_string(),
-                quantity: 20.0,
-                avg_price: 2100.0,
-                current_price: 2250.0,
-                unrealized_pnl: 20.0 * (2250.0 - 2100.0),
-                realized_pnl: 1200.0,
-                timestamp: Utc::now(),
-            },
-            Position {
-                symbol: "SOL/USD".to_string(),
-                quantity: 100.0,
-                avg_price: 80.0,
-                current_price: 82.5,
-                unrealized_pnl: 100.0 * (82.5 - 80.0),
-                realized_pnl: 500.0,
-                timestamp: Utc::now(),
-            },
-        ])
+        Ok(self.simulated_positions())
     }
-} 
\ No newline at end of file
+    
+    // Configured rather than queried, so known while disconnected
+    async fn get_fee_schedule(&self) -> Result<FeeSchedule, TradingError> {
+        Ok(self.fees.clone())
+    }
+    
+    async fn get_margin_info(&self, symbol: &str) -> Result<Option<MarginInfo>, TradingError> {
+        if !self.connected {
+            return Err(TradingError::NotConnected(self.config.name.clone()));
+        }
+        
+        // In a real implementation, this would query the exchange API
+        
+        // Simulate API request
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        
+        // The unavailable part of the balance backs open positions. A position
+        // is liquidated once it has lost its margin at the maximum leverage.
+        let max_leverage = self.simulation.max_leverage;
+        let used_margin = SIMULATED_BALANCE_TOTAL - SIMULATED_BALANCE_AVAILABLE;
+        let liquidation_price = self.simulated_positions().into_iter()
+            .find(|position| position.symbol == symbol)
+            .map(|position| position.avg_price * (1.0 - position.quantity.signum() / max_leverage));
+        
+        Ok(Some(MarginInfo {
+            available_margin: SIMULATED_BALANCE_AVAILABLE,
+            used_margin,
+            margin_level: SIMULATED_BALANCE_TOTAL / used_margin * 100.0,
+            max_leverage,
+            liquidation_price,
+        }))
+    }
+    
+    async fn get_account_history(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountTransaction>, TradingError> {
+        if !self.connected {
+            return Err(TradingError::NotConnected(self.config.name.clone()));
+        }
+        if from >= to {
+            return Ok(Vec::new());
+        }
+        
+        // In a real implementation, this would query the exchange API
+        
+        // Simulate API request
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        
+        let last_day = (to - chrono::Duration::nanoseconds(1)).date_naive();
+        Ok(from.date_naive().iter_days()
+            .take_while(|day| *day <= last_day)
+            .flat_map(|day| self.simulated_history(day))
+            .filter(|transaction| transaction.timestamp >= from && transaction.timestamp < to)
+            .collect())
+    }
+}
+ 
\ No newline at end of file
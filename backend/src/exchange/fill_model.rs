@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use super::MarketSnapshot;
+use crate::market_data::PriceLevel;
+use crate::order::{Order, OrderType};
+use crate::strategy::TradeDirection;
+
+// additional_params keys selecting and configuring the fill model
+pub const FILL_MODEL_PARAM: &str = "fill_model";
+pub const SLIPPAGE_BPS_PARAM: &str = "slippage_bps";
+pub const IMPACT_FACTOR_PARAM: &str = "impact_factor";
+pub const TAKER_FEE_BPS_PARAM: &str = "taker_fee_bps";
+pub const MAKER_FEE_BPS_PARAM: &str = "maker_fee_bps";
+
+const BPS: f64 = 10000.0;
+
+/// Decides the price and fees of simulated fills
+pub trait FillModel: Send + Sync {
+    fn compute_fill_price(&self, order: &Order, market: &MarketSnapshot) -> f64;
+    fn compute_commission(&self, order: &Order, fill_price: f64, fill_qty: f64) -> f64;
+}
+
+/// Fills every order a fixed number of basis points away from the market price
+#[derive(Debug, Clone, Default)]
+pub struct ConstantSlippageModel {
+    pub slippage_bps: f64,
+}
+
+impl FillModel for ConstantSlippageModel {
+    fn compute_fill_price(&self, order: &Order, market: &MarketSnapshot) -> f64 {
+        let price = apply_slippage(order.direction, market.price.to_f64(), self.slippage_bps / BPS);
+        cap_at_limit(order, price)
+    }
+
+    fn compute_commission(&self, _order: &Order, _fill_price: f64, _fill_qty: f64) -> f64 {
+        0.0
+    }
+}
+
+/// Slippage grows with the order's share of the traded volume
+#[derive(Debug, Clone)]
+pub struct LinearImpactModel {
+    pub impact_factor: f64,
+}
+
+impl FillModel for LinearImpactModel {
+    fn compute_fill_price(&self, order: &Order, market: &MarketSnapshot) -> f64 {
+        // Without volume there is nothing to size the impact against
+        let participation = if market.volume > 0.0 {
+            order.quantity / market.volume
+        } else {
+            0.0
+        };
+        let price = apply_slippage(order.direction, market.price.to_f64(), self.impact_factor * participation);
+        cap_at_limit(order, price)
+    }
+
+    fn compute_commission(&self, _order: &Order, _fill_price: f64, _fill_qty: f64) -> f64 {
+        0.0
+    }
+}
+
+/// Exchange fee schedule. Limit orders rest on the book and pay the maker fee at
+/// their limit price; everything else crosses the spread and pays the taker fee.
+#[derive(Debug, Clone)]
+pub struct TakerMakerModel {
+    pub taker_fee_bps: f64,
+    pub maker_fee_bps: f64,
+}
+
+impl TakerMakerModel {
+    pub(crate) fn is_maker(order: &Order) -> bool {
+        matches!(order.order_type, OrderType::Limit | OrderType::StopLimit) && order.price.is_some()
+    }
+}
+
+impl FillModel for TakerMakerModel {
+    fn compute_fill_price(&self, order: &Order, market: &MarketSnapshot) -> f64 {
+        match (Self::is_maker(order), order.price) {
+            (true, Some(limit)) => limit.to_f64(),
+            _ => match order.direction {
+                TradeDirection::Buy => market.ask.to_f64(),
+                TradeDirection::Sell => market.bid.to_f64(),
+            },
+        }
+    }
+
+    fn compute_commission(&self, order: &Order, fill_price: f64, fill_qty: f64) -> f64 {
+        let fee_bps = if Self::is_maker(order) {
+            self.maker_fee_bps
+        } else {
+            self.taker_fee_bps
+        };
+        fill_price * fill_qty * fee_bps / BPS
+    }
+}
+
+/// Take `quantity` from book levels, best price first. Returns the quantity
+/// the levels could fill and its size-weighted average price, or `None` when
+/// the levels hold nothing.
+pub fn walk_book(levels: &[PriceLevel], quantity: f64) -> Option<(f64, f64)> {
+    let mut filled = 0.0;
+    let mut notional = 0.0;
+    for level in levels {
+        if filled >= quantity {
+            break;
+        }
+        let take = level.quantity.min(quantity - filled);
+        filled += take;
+        notional += take * level.price;
+    }
+
+    if filled > 0.0 {
+        Some((filled, notional / filled))
+    } else {
+        None
+    }
+}
+
+// Move the price against the order by `fraction`
+fn apply_slippage(direction: TradeDirection, price: f64, fraction: f64) -> f64 {
+    match direction {
+        TradeDirection::Buy => price * (1.0 + fraction),
+        TradeDirection::Sell => price * (1.0 - fraction),
+    }
+}
+
+// A limit order never fills beyond its limit price
+fn cap_at_limit(order: &Order, price: f64) -> f64 {
+    match (&order.order_type, order.price) {
+        (OrderType::Limit, Some(limit)) => match order.direction {
+            TradeDirection::Buy => price.min(limit.to_f64()),
+            TradeDirection::Sell => price.max(limit.to_f64()),
+        },
+        _ => price,
+    }
+}
+
+/// Build the fill model named by the `fill_model` param. Returns `Ok(None)` when
+/// no model is configured, and an error for unknown models or invalid settings.
+pub fn fill_model_from_params(params: &HashMap<String, String>) -> Result<Option<Box<dyn FillModel>>, String> {
+    let name = match params.get(FILL_MODEL_PARAM) {
+        Some(name) => name.trim(),
+        None => return Ok(None),
+    };
+
+    let model: Box<dyn FillModel> = match name {
+        "constant_slippage" => Box::new(ConstantSlippageModel {
+            slippage_bps: non_negative_param(params, SLIPPAGE_BPS_PARAM)?,
+        }),
+        "linear_impact" => Box::new(LinearImpactModel {
+            impact_factor: non_negative_param(params, IMPACT_FACTOR_PARAM)?,
+        }),
+        "taker_maker" => Box::new(TakerMakerModel {
+            taker_fee_bps: non_negative_param(params, TAKER_FEE_BPS_PARAM)?,
+            maker_fee_bps: non_negative_param(params, MAKER_FEE_BPS_PARAM)?,
+        }),
+        other => return Err(format!("Unknown fill model: {}", other)),
+    };
+
+    Ok(Some(model))
+}
+
+// Missing settings default to zero
+fn non_negative_param(params: &HashMap<String, String>, key: &str) -> Result<f64, String> {
+    let value = match params.get(key) {
+        Some(value) => value,
+        None => return Ok(0.0),
+    };
+
+    match value.trim().parse::<f64>() {
+        Ok(v) if v >= 0.0 => Ok(v),
+        _ => Err(format!("{} must be a non-negative number, got '{}'", key, value)),
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Fraction of an order's quantity filled once `after` has passed since submission
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillStep {
+    pub after: Duration,
+    pub fraction: f64, // Within [0, 1]
+}
+
+impl FillStep {
+    pub fn new(after: Duration, fraction: f64) -> Self {
+        FillStep { after, fraction }
+    }
+}
+
+/// How simulated orders progress after submission. An order is pending until
+/// the first step, then open and filled up to the fraction of the latest step
+/// reached. The default opens after 2s, half fills after 5s and fully fills
+/// after 10s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillSchedule {
+    steps: Vec<FillStep>,
+}
+
+impl Default for FillSchedule {
+    fn default() -> Self {
+        FillSchedule {
+            steps: vec![
+                FillStep::new(Duration::from_secs(2), 0.0),
+                FillStep::new(Duration::from_secs(5), 0.5),
+                FillStep::new(Duration::from_secs(10), 1.0),
+            ],
+        }
+    }
+}
+
+impl FillSchedule {
+    /// Steps must be in time order and fill fractions may not shrink
+    pub fn new(steps: Vec<FillStep>) -> Result<Self, String> {
+        if steps.is_empty() {
+            return Err("Fill schedule needs at least one step".to_string());
+        }
+        for step in &steps {
+            if !(0.0..=1.0).contains(&step.fraction) {
+                return Err(format!("Fill fraction {} is outside [0, 1]", step.fraction));
+            }
+        }
+        for pair in steps.windows(2) {
+            if pair[1].after < pair[0].after {
+                return Err("Fill schedule steps must be in time order".to_string());
+            }
+            if pair[1].fraction < pair[0].fraction {
+                return Err("Fill fractions must not decrease".to_string());
+            }
+        }
+        Ok(FillSchedule { steps })
+    }
+
+    /// Orders fill completely as soon as they are submitted
+    pub fn instant() -> Self {
+        FillSchedule { steps: vec![FillStep::new(Duration::ZERO, 1.0)] }
+    }
+
+    /// Orders open as soon as they are submitted and never fill
+    pub fn never() -> Self {
+        FillSchedule { steps: vec![FillStep::new(Duration::ZERO, 0.0)] }
+    }
+
+    pub fn steps(&self) -> &[FillStep] {
+        &self.steps
+    }
+
+    /// Fraction of the quantity filled `elapsed` after submission, or `None`
+    /// while the order is still pending
+    pub fn filled_fraction(&self, elapsed: Duration) -> Option<f64> {
+        self.steps.iter()
+            .take_while(|step| step.after <= elapsed)
+            .last()
+            .map(|step| step.fraction)
+    }
+}
+
+/// Parses `instant`, `never`, or comma-separated `seconds:fraction` steps
+/// such as `2:0,5:0.5,10:1`
+impl FromStr for FillSchedule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "instant" => return Ok(FillSchedule::instant()),
+            "never" => return Ok(FillSchedule::never()),
+            _ => {}
+        }
+
+        let steps = s.split(',')
+            .map(|step| {
+                let (seconds, fraction) = step.split_once(':')
+                    .ok_or_else(|| format!("Fill step '{}' is not seconds:fraction", step.trim()))?;
+                let seconds: f64 = seconds.trim().parse()
+                    .map_err(|_| format!("Invalid fill step time '{}'", seconds.trim()))?;
+                let after = Duration::try_from_secs_f64(seconds)
+                    .map_err(|_| format!("Invalid fill step time '{}'", seconds))?;
+                let fraction: f64 = fraction.trim().parse()
+                    .map_err(|_| format!("Invalid fill fraction '{}'", fraction.trim()))?;
+                Ok(FillStep::new(after, fraction))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        FillSchedule::new(steps)
+    }
+}
@@ -0,0 +1,757 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use chrono::Utc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::task::JoinHandle;
+use tracing::{info, warn, debug};
+use uuid::Uuid;
+use async_trait::async_trait;
+
+use super::{
+    Exchange, ExchangeType, ExchangeConfig,
+    MarketSnapshot, OrderStatusResponse, AccountBalance, Position, CancellationResult,
+    OrderStatus as ExchangeOrderStatus, rejection_error,
+};
+use crate::error::TradingError;
+use crate::order::{Order, OrderType};
+use crate::strategy::{TradeDirection, TimeInForce};
+
+pub const FIX_4_2: &str = "FIX.4.2";
+
+/// Field separator on the wire
+pub const SOH: char = '\x01';
+
+// Tags used by the messages we build and read
+pub const TAG_AVG_PX: u32 = 6;
+pub const TAG_BEGIN_STRING: u32 = 8;
+pub const TAG_BODY_LENGTH: u32 = 9;
+pub const TAG_CHECKSUM: u32 = 10;
+pub const TAG_CL_ORD_ID: u32 = 11;
+pub const TAG_CUM_QTY: u32 = 14;
+pub const TAG_HANDL_INST: u32 = 21;
+pub const TAG_MSG_SEQ_NUM: u32 = 34;
+pub const TAG_MSG_TYPE: u32 = 35;
+pub const TAG_ORDER_ID: u32 = 37;
+pub const TAG_ORDER_QTY: u32 = 38;
+pub const TAG_ORD_STATUS: u32 = 39;
+pub const TAG_ORD_TYPE: u32 = 40;
+pub const TAG_ORIG_CL_ORD_ID: u32 = 41;
+pub const TAG_PRICE: u32 = 44;
+pub const TAG_REF_SEQ_NUM: u32 = 45;
+pub const TAG_SENDER_COMP_ID: u32 = 49;
+pub const TAG_SENDING_TIME: u32 = 52;
+pub const TAG_SIDE: u32 = 54;
+pub const TAG_SYMBOL: u32 = 55;
+pub const TAG_TARGET_COMP_ID: u32 = 56;
+pub const TAG_TEXT: u32 = 58;
+pub const TAG_TIME_IN_FORCE: u32 = 59;
+pub const TAG_TRANSACT_TIME: u32 = 60;
+pub const TAG_ENCRYPT_METHOD: u32 = 98;
+pub const TAG_STOP_PX: u32 = 99;
+pub const TAG_HEART_BT_INT: u32 = 108;
+pub const TAG_TEST_REQ_ID: u32 = 112;
+pub const TAG_LEAVES_QTY: u32 = 151;
+
+// Header fields written in this order ahead of the body
+const HEADER_TAGS: [u32; 5] = [TAG_MSG_TYPE, TAG_SENDER_COMP_ID, TAG_TARGET_COMP_ID, TAG_MSG_SEQ_NUM, TAG_SENDING_TIME];
+
+const FIX_TIME_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixMsgType {
+    Heartbeat,
+    TestRequest,
+    ResendRequest,
+    Reject,
+    SequenceReset,
+    Logout,
+    ExecutionReport,
+    OrderCancelReject,
+    Logon,
+    NewOrderSingle,
+    OrderCancelRequest,
+    OrderStatusRequest,
+}
+
+impl FixMsgType {
+    pub const ALL: [FixMsgType; 12] = [
+        FixMsgType::Heartbeat,
+        FixMsgType::TestRequest,
+        FixMsgType::ResendRequest,
+        FixMsgType::Reject,
+        FixMsgType::SequenceReset,
+        FixMsgType::Logout,
+        FixMsgType::ExecutionReport,
+        FixMsgType::OrderCancelReject,
+        FixMsgType::Logon,
+        FixMsgType::NewOrderSingle,
+        FixMsgType::OrderCancelRequest,
+        FixMsgType::OrderStatusRequest,
+    ];
+
+    /// The MsgType (35) value
+    pub fn code(&self) -> &'static str {
+        match self {
+            FixMsgType::Heartbeat => "0",
+            FixMsgType::TestRequest => "1",
+            FixMsgType::ResendRequest => "2",
+            FixMsgType::Reject => "3",
+            FixMsgType::SequenceReset => "4",
+            FixMsgType::Logout => "5",
+            FixMsgType::ExecutionReport => "8",
+            FixMsgType::OrderCancelReject => "9",
+            FixMsgType::Logon => "A",
+            FixMsgType::NewOrderSingle => "D",
+            FixMsgType::OrderCancelRequest => "F",
+            FixMsgType::OrderStatusRequest => "H",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<FixMsgType> {
+        FixMsgType::ALL.iter().copied().find(|t| t.code() == code)
+    }
+}
+
+/// A FIX message as tag-value pairs. BodyLength (9) and CheckSum (10) are
+/// derived when the message is serialized and checked when it is parsed, so
+/// they are never stored. Repeating groups are not supported: a repeated tag
+/// keeps its last value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixMessage {
+    fields: HashMap<u32, String>,
+}
+
+impl FixMessage {
+    /// An empty FIX 4.2 message of the given type
+    pub fn new(msg_type: FixMsgType) -> Self {
+        let mut message = FixMessage { fields: HashMap::new() };
+        message.set(TAG_BEGIN_STRING, FIX_4_2);
+        message.set(TAG_MSG_TYPE, msg_type.code());
+        message
+    }
+
+    /// Parse a message delimited by SOH or, for logs and tests, by `|`.
+    /// BodyLength and CheckSum are verified when present.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let delimiter = if raw.contains(SOH) { SOH } else { '|' };
+        let normalized = raw.replace(delimiter, &SOH.to_string());
+
+        let mut fields = HashMap::new();
+        let mut body_start = None;
+        let mut trailer_start = None;
+        let mut offset = 0;
+
+        for field in normalized.split(SOH) {
+            let field_start = offset;
+            offset += field.len() + 1;
+            if field.is_empty() {
+                continue;
+            }
+
+            let (tag, value) = field.split_once('=')
+                .ok_or_else(|| format!("Malformed field '{}'", field))?;
+            let tag: u32 = tag.parse()
+                .map_err(|_| format!("Invalid tag '{}'", tag))?;
+
+            if fields.is_empty() && tag != TAG_BEGIN_STRING {
+                return Err("Message must start with BeginString (8)".to_string());
+            }
+            match tag {
+                TAG_BODY_LENGTH => body_start = Some(offset),
+                TAG_CHECKSUM => trailer_start = Some(field_start),
+                _ => {},
+            }
+            fields.insert(tag, value.to_string());
+        }
+
+        if !fields.contains_key(&TAG_MSG_TYPE) {
+            return Err("Message has no MsgType (35)".to_string());
+        }
+
+        if let (Some(start), Some(end), Some(declared)) = (body_start, trailer_start, fields.get(&TAG_BODY_LENGTH)) {
+            let actual = end.saturating_sub(start);
+            if declared.parse::<usize>().ok() != Some(actual) {
+                return Err(format!("BodyLength {} does not match body of {} bytes", declared, actual));
+            }
+        }
+        if let (Some(end), Some(declared)) = (trailer_start, fields.get(&TAG_CHECKSUM)) {
+            let actual = checksum(&normalized[..end]);
+            if declared.parse::<u32>().ok() != Some(actual) {
+                return Err(format!("CheckSum {} does not match computed {:03}", declared, actual));
+            }
+        }
+
+        fields.remove(&TAG_BODY_LENGTH);
+        fields.remove(&TAG_CHECKSUM);
+        Ok(FixMessage { fields })
+    }
+
+    /// Build a FIX 4.2 NewOrderSingle for `order`. The order id is sent as the
+    /// ClOrdID so execution reports can be matched back to it.
+    pub fn new_order_single(order: &Order) -> FixMessage {
+        let mut message = FixMessage::new(FixMsgType::NewOrderSingle);
+        message
+            .set(TAG_CL_ORD_ID, order.id.to_string())
+            .set(TAG_HANDL_INST, "1") // Automated execution, no broker intervention
+            .set(TAG_SYMBOL, order.symbol.clone())
+            .set(TAG_SIDE, side_code(order.direction))
+            .set(TAG_TRANSACT_TIME, Utc::now().format(FIX_TIME_FORMAT).to_string())
+            .set(TAG_ORDER_QTY, order.quantity.to_string())
+            .set(TAG_ORD_TYPE, ord_type_code(&order.order_type))
+            .set(TAG_TIME_IN_FORCE, time_in_force_code(&order.time_in_force));
+
+        if let Some(price) = order.price {
+            message.set(TAG_PRICE, price.to_string());
+        }
+        if let Some(stop_price) = order.stop_price {
+            message.set(TAG_STOP_PX, stop_price.to_string());
+        }
+
+        message
+    }
+
+    pub fn set(&mut self, tag: u32, value: impl Into<String>) -> &mut Self {
+        self.fields.insert(tag, value.into());
+        self
+    }
+
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields.get(&tag).map(|v| v.as_str())
+    }
+
+    pub fn fields(&self) -> &HashMap<u32, String> {
+        &self.fields
+    }
+
+    pub fn msg_type(&self) -> Option<FixMsgType> {
+        self.get(TAG_MSG_TYPE).and_then(FixMsgType::from_code)
+    }
+
+    /// The ClOrdID (11) identifying our order
+    pub fn order_id(&self) -> Option<&str> {
+        self.get(TAG_CL_ORD_ID)
+    }
+
+    /// The OrderID (37) the exchange assigned
+    pub fn exchange_order_id(&self) -> Option<&str> {
+        self.get(TAG_ORDER_ID)
+    }
+
+    pub fn symbol(&self) -> Option<&str> {
+        self.get(TAG_SYMBOL)
+    }
+
+    pub fn side(&self) -> Option<TradeDirection> {
+        match self.get(TAG_SIDE)? {
+            "1" => Some(TradeDirection::Buy),
+            "2" => Some(TradeDirection::Sell),
+            _ => None,
+        }
+    }
+
+    pub fn price(&self) -> Option<f64> {
+        self.get_f64(TAG_PRICE)
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.get(TAG_TEXT)
+    }
+
+    pub fn seq_num(&self) -> Option<u64> {
+        self.get(TAG_MSG_SEQ_NUM).and_then(|v| v.parse().ok())
+    }
+
+    fn get_f64(&self, tag: u32) -> Option<f64> {
+        self.get(tag).and_then(|v| v.parse().ok())
+    }
+
+    /// Serialize with SOH delimiters, computing BodyLength and CheckSum
+    pub fn to_wire(&self) -> String {
+        let mut body = String::new();
+        for tag in HEADER_TAGS {
+            if let Some(value) = self.fields.get(&tag) {
+                push_field(&mut body, tag, value);
+            }
+        }
+
+        let mut tags: Vec<u32> = self.fields.keys()
+            .copied()
+            .filter(|tag| *tag != TAG_BEGIN_STRING && !HEADER_TAGS.contains(tag))
+            .collect();
+        tags.sort_unstable();
+        for tag in tags {
+            push_field(&mut body, tag, &self.fields[&tag]);
+        }
+
+        let mut wire = String::new();
+        push_field(&mut wire, TAG_BEGIN_STRING, self.get(TAG_BEGIN_STRING).unwrap_or(FIX_4_2));
+        push_field(&mut wire, TAG_BODY_LENGTH, &body.len().to_string());
+        wire.push_str(&body);
+
+        let sum = checksum(&wire);
+        push_field(&mut wire, TAG_CHECKSUM, &format!("{:03}", sum));
+        wire
+    }
+}
+
+/// The wire format with `|` in place of SOH, as FIX messages are usually logged
+impl fmt::Display for FixMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_wire().replace(SOH, "|"))
+    }
+}
+
+fn push_field(out: &mut String, tag: u32, value: &str) {
+    out.push_str(&tag.to_string());
+    out.push('=');
+    out.push_str(value);
+    out.push(SOH);
+}
+
+fn checksum(data: &str) -> u32 {
+    data.bytes().map(|b| b as u32).sum::<u32>() % 256
+}
+
+fn side_code(direction: TradeDirection) -> &'static str {
+    match direction {
+        TradeDirection::Buy => "1",
+        TradeDirection::Sell => "2",
+    }
+}
+
+fn ord_type_code(order_type: &OrderType) -> &'static str {
+    match order_type {
+        OrderType::Market => "1",
+        OrderType::Limit => "2",
+        OrderType::StopLoss => "3",
+        OrderType::StopLimit => "4",
+        OrderType::TrailingStop => "P", // Pegged
+    }
+}
+
+fn time_in_force_code(time_in_force: &TimeInForce) -> &'static str {
+    match time_in_force {
+        TimeInForce::Day => "0",
+        TimeInForce::GoodTilCancelled => "1",
+        TimeInForce::ImmediateOrCancel => "3",
+        TimeInForce::FillOrKill => "4",
+    }
+}
+
+// OrdStatus (39) as reported in execution reports
+fn ord_status(code: &str) -> ExchangeOrderStatus {
+    match code {
+        "A" => ExchangeOrderStatus::Pending,
+        "0" | "6" | "E" => ExchangeOrderStatus::Open, // New, pending cancel, pending replace
+        "1" => ExchangeOrderStatus::PartiallyFilled,
+        "2" => ExchangeOrderStatus::Filled,
+        "4" | "C" => ExchangeOrderStatus::Cancelled, // Cancelled, expired
+        "8" => ExchangeOrderStatus::Rejected,
+        _ => ExchangeOrderStatus::Unknown,
+    }
+}
+
+// additional_params keys for the FIX session
+pub const FIX_HOST_PARAM: &str = "fix_host";
+pub const FIX_PORT_PARAM: &str = "fix_port";
+pub const SENDER_COMP_ID_PARAM: &str = "sender_comp_id";
+pub const TARGET_COMP_ID_PARAM: &str = "target_comp_id";
+pub const HEARTBEAT_INTERVAL_PARAM: &str = "heartbeat_interval_secs";
+pub const RESPONSE_TIMEOUT_PARAM: &str = "response_timeout_ms";
+
+const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 30;
+const DEFAULT_RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Where and as whom to open the FIX session. Host and port come from the
+/// `fix_host`/`fix_port` params, falling back to an `api_url` of the form
+/// `[tcp://]host:port`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixSessionConfig {
+    pub host: String,
+    pub port: u16,
+    pub sender_comp_id: String,
+    pub target_comp_id: String,
+    pub heartbeat_interval: Duration,
+    pub response_timeout: Duration,
+}
+
+impl FixSessionConfig {
+    pub fn from_exchange_config(config: &ExchangeConfig) -> Result<Self, String> {
+        let params = &config.additional_params;
+        let address = config.api_url.trim_start_matches("tcp://");
+        let (url_host, url_port) = match address.rsplit_once(':') {
+            Some((host, port)) => (Some(host), Some(port)),
+            None => (None, None),
+        };
+
+        let host = params.get(FIX_HOST_PARAM).map(|h| h.as_str())
+            .or(url_host)
+            .filter(|h| !h.is_empty())
+            .ok_or_else(|| format!("No FIX host configured for {}", config.name))?
+            .to_string();
+        let port = params.get(FIX_PORT_PARAM).map(|p| p.as_str())
+            .or(url_port)
+            .ok_or_else(|| format!("No FIX port configured for {}", config.name))?;
+        let port = port.trim().parse::<u16>()
+            .map_err(|_| format!("Invalid FIX port '{}' for {}", port, config.name))?;
+
+        let sender_comp_id = params.get(SENDER_COMP_ID_PARAM)
+            .cloned()
+            .ok_or_else(|| format!("No {} configured for {}", SENDER_COMP_ID_PARAM, config.name))?;
+        let target_comp_id = params.get(TARGET_COMP_ID_PARAM)
+            .cloned()
+            .unwrap_or_else(|| config.name.clone());
+
+        let heartbeat_secs = parse_duration_param(params, HEARTBEAT_INTERVAL_PARAM, &config.name)?
+            .unwrap_or(DEFAULT_HEARTBEAT_INTERVAL_SECS);
+        let response_timeout = parse_duration_param(params, RESPONSE_TIMEOUT_PARAM, &config.name)?
+            .map(Duration::from_millis)
+            .unwrap_or(DEFAULT_RESPONSE_TIMEOUT);
+
+        Ok(FixSessionConfig {
+            host,
+            port,
+            sender_comp_id,
+            target_comp_id,
+            heartbeat_interval: Duration::from_secs(heartbeat_secs),
+            response_timeout,
+        })
+    }
+}
+
+fn parse_duration_param(params: &HashMap<String, String>, key: &str, name: &str) -> Result<Option<u64>, String> {
+    match params.get(key) {
+        Some(value) => value.trim().parse::<u64>()
+            .map(Some)
+            .map_err(|_| format!("Invalid {} '{}' for {}", key, value, name)),
+        None => Ok(None),
+    }
+}
+
+// An open TCP session. Requests hold the lock from send until their response
+// arrives, so replies can't be taken by another request.
+struct FixSession {
+    stream: TcpStream,
+    buffer: Vec<u8>,
+    next_seq_num: u64,
+}
+
+impl FixSession {
+    async fn send(&mut self, config: &FixSessionConfig, message: &mut FixMessage) -> Result<u64, TradingError> {
+        let seq_num = self.next_seq_num;
+        message
+            .set(TAG_SENDER_COMP_ID, config.sender_comp_id.clone())
+            .set(TAG_TARGET_COMP_ID, config.target_comp_id.clone())
+            .set(TAG_MSG_SEQ_NUM, seq_num.to_string())
+            .set(TAG_SENDING_TIME, Utc::now().format(FIX_TIME_FORMAT).to_string());
+
+        debug!("FIX out: {}", message);
+        self.stream.write_all(message.to_wire().as_bytes()).await
+            .map_err(|e| TradingError::Exchange(format!("Failed to send FIX message: {}", e)))?;
+        self.next_seq_num += 1;
+        Ok(seq_num)
+    }
+
+    async fn receive(&mut self) -> Result<FixMessage, TradingError> {
+        loop {
+            if let Some(end) = message_end(&self.buffer) {
+                let raw: Vec<u8> = self.buffer.drain(..end).collect();
+                let raw = String::from_utf8(raw)
+                    .map_err(|_| TradingError::Exchange("FIX message is not valid UTF-8".to_string()))?;
+                let message = FixMessage::parse(&raw).map_err(TradingError::Exchange)?;
+                debug!("FIX in: {}", message);
+                return Ok(message);
+            }
+
+            let mut chunk = [0u8; 4096];
+            let read = self.stream.read(&mut chunk).await
+                .map_err(|e| TradingError::Exchange(format!("Failed to read FIX message: {}", e)))?;
+            if read == 0 {
+                return Err(TradingError::Exchange("FIX session closed by counterparty".to_string()));
+            }
+            self.buffer.extend_from_slice(&chunk[..read]);
+        }
+    }
+}
+
+// Length of the first complete message in `buffer`: up to and including the
+// SOH that ends its CheckSum field
+fn message_end(buffer: &[u8]) -> Option<usize> {
+    let trailer = b"\x0110=";
+    let start = buffer.windows(trailer.len()).position(|w| w == trailer)? + trailer.len();
+    let end = buffer[start..].iter().position(|b| *b == SOH as u8)?;
+    Some(start + end + 1)
+}
+
+/// An exchange reached over a FIX 4.2 order-routing session. Market data,
+/// balances and positions are not available over the session.
+pub struct FixExchange {
+    config: ExchangeConfig,
+    session_config: FixSessionConfig,
+    connected: bool,
+    session: Arc<tokio::sync::Mutex<Option<FixSession>>>,
+    orders: Arc<Mutex<HashMap<Uuid, Order>>>,
+    heartbeat_task: Option<JoinHandle<()>>,
+    order_types: Vec<OrderType>, // Every type unless the config restricts them
+}
+
+impl FixExchange {
+    pub fn new(config: ExchangeConfig) -> Result<Self, String> {
+        let session_config = FixSessionConfig::from_exchange_config(&config)?;
+        let order_types = config.supported_order_types()?.unwrap_or_else(|| OrderType::ALL.to_vec());
+
+        Ok(FixExchange {
+            config,
+            session_config,
+            connected: false,
+            session: Arc::new(tokio::sync::Mutex::new(None)),
+            orders: Arc::new(Mutex::new(HashMap::new())),
+            heartbeat_task: None,
+            order_types,
+        })
+    }
+
+    pub fn session_config(&self) -> &FixSessionConfig {
+        &self.session_config
+    }
+
+    // Send `message` and wait for the first incoming message `is_response`
+    // accepts. Test requests are answered while waiting; a session-level
+    // Reject of the request fails it.
+    async fn request<F>(&self, mut message: FixMessage, is_response: F) -> Result<FixMessage, TradingError>
+    where
+        F: Fn(&FixMessage) -> bool,
+    {
+        if !self.connected {
+            return Err(TradingError::NotConnected(self.config.name.clone()));
+        }
+
+        let mut guard = self.session.lock().await;
+        let session = guard.as_mut().ok_or_else(|| TradingError::Exchange("FIX session is not open".to_string()))?;
+        let seq_num = session.send(&self.session_config, &mut message).await?;
+
+        let wait = async {
+            loop {
+                let incoming = session.receive().await?;
+                match incoming.msg_type() {
+                    Some(FixMsgType::TestRequest) => {
+                        let mut heartbeat = FixMessage::new(FixMsgType::Heartbeat);
+                        if let Some(id) = incoming.get(TAG_TEST_REQ_ID) {
+                            heartbeat.set(TAG_TEST_REQ_ID, id);
+                        }
+                        session.send(&self.session_config, &mut heartbeat).await?;
+                    },
+                    Some(FixMsgType::Reject) if incoming.get(TAG_REF_SEQ_NUM) == Some(seq_num.to_string().as_str()) => {
+                        return Err(rejection_error(incoming.text().unwrap_or("Rejected by FIX session")));
+                    },
+                    Some(FixMsgType::Logout) => {
+                        return Err(TradingError::Exchange(format!("{} logged out: {}", self.config.name, incoming.text().unwrap_or("no reason given"))));
+                    },
+                    _ if is_response(&incoming) => return Ok(incoming),
+                    _ => debug!("Ignoring unsolicited FIX message: {}", incoming),
+                }
+            }
+        };
+
+        tokio::time::timeout(self.session_config.response_timeout, wait).await
+            .map_err(|_| TradingError::Exchange(format!("Timed out waiting for a response from {}", self.config.name)))?
+    }
+
+    fn status_response(&self, order_id: Uuid, order: &Order, report: &FixMessage) -> OrderStatusResponse {
+        let filled_quantity = report.get_f64(TAG_CUM_QTY).unwrap_or(0.0);
+
+        OrderStatusResponse {
+            order_id,
+            exchange_order_id: report.exchange_order_id().map(|id| id.to_string()),
+            status: report.get(TAG_ORD_STATUS).map(ord_status).unwrap_or(ExchangeOrderStatus::Unknown),
+            filled_quantity,
+            remaining_quantity: report.get_f64(TAG_LEAVES_QTY).unwrap_or(order.quantity - filled_quantity),
+            average_price: report.get_f64(TAG_AVG_PX).filter(|px| *px > 0.0),
+            last_update: Utc::now(),
+        }
+    }
+
+    fn submitted_order(&self, order_id: Uuid) -> Result<Order, TradingError> {
+        self.orders.lock().unwrap()
+            .get(&order_id)
+            .cloned()
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))
+    }
+}
+
+#[async_trait]
+impl Exchange for FixExchange {
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    fn exchange_type(&self) -> ExchangeType {
+        self.config.exchange_type
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    fn supported_order_types(&self) -> Vec<OrderType> {
+        self.order_types.clone()
+    }
+
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        let address = format!("{}:{}", self.session_config.host, self.session_config.port);
+        info!("Connecting to FIX exchange {} at {}", self.config.name, address);
+
+        let stream = TcpStream::connect(&address).await
+            .map_err(|e| TradingError::Exchange(format!("Failed to connect to {}: {}", address, e)))?;
+        *self.session.lock().await = Some(FixSession {
+            stream,
+            buffer: Vec::new(),
+            next_seq_num: 1,
+        });
+        self.connected = true;
+
+        let mut logon = FixMessage::new(FixMsgType::Logon);
+        logon
+            .set(TAG_ENCRYPT_METHOD, "0")
+            .set(TAG_HEART_BT_INT, self.session_config.heartbeat_interval.as_secs().to_string());
+        if let Err(e) = self.request(logon, |m| m.msg_type() == Some(FixMsgType::Logon)).await {
+            self.connected = false;
+            *self.session.lock().await = None;
+            return Err(TradingError::Exchange(format!("FIX logon to {} failed: {}", self.config.name, e)));
+        }
+
+        // Keep the session alive while no requests are flowing
+        let session = self.session.clone();
+        let session_config = self.session_config.clone();
+        self.heartbeat_task = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(session_config.heartbeat_interval);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let mut guard = session.lock().await;
+                let Some(session) = guard.as_mut() else { break };
+                if let Err(e) = session.send(&session_config, &mut FixMessage::new(FixMsgType::Heartbeat)).await {
+                    warn!("FIX heartbeat failed: {}", e);
+                    break;
+                }
+            }
+        }));
+
+        info!("Logged on to {}", self.config.name);
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> Result<(), TradingError> {
+        info!("Disconnecting from FIX exchange: {}", self.config.name);
+
+        if let Some(task) = self.heartbeat_task.take() {
+            task.abort();
+        }
+        if let Some(mut session) = self.session.lock().await.take() {
+            // Best effort: the counterparty may already have dropped the connection
+            if let Err(e) = session.send(&self.session_config, &mut FixMessage::new(FixMsgType::Logout)).await {
+                warn!("Failed to log out of {}: {}", self.config.name, e);
+            }
+        }
+
+        self.connected = false;
+        info!("Disconnected from {}", self.config.name);
+        Ok(())
+    }
+
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError> {
+        Err(TradingError::Exchange(format!("{} does not list assets over FIX", self.config.name)))
+    }
+
+    async fn get_market_data(&self, _symbol: &str) -> Result<MarketSnapshot, TradingError> {
+        Err(TradingError::Exchange(format!("{} does not provide market data over FIX", self.config.name)))
+    }
+
+    async fn submit_order(&self, order: Order) -> Result<(), TradingError> {
+        let cl_ord_id = order.id.to_string();
+        info!("Submitting order {} to {} over FIX", order.id, self.config.name);
+
+        let report = self.request(FixMessage::new_order_single(&order), |m| {
+            m.msg_type() == Some(FixMsgType::ExecutionReport) && m.order_id() == Some(cl_ord_id.as_str())
+        }).await?;
+
+        if report.get(TAG_ORD_STATUS).map(ord_status) == Some(ExchangeOrderStatus::Rejected) {
+            let reason = report.text().unwrap_or("No reason given");
+            warn!("{} rejected order {}: {}", self.config.name, order.id, reason);
+            return Err(rejection_error(reason));
+        }
+
+        debug!("Order {} accepted by {}, exchange ID={:?}", order.id, self.config.name, report.exchange_order_id());
+        self.orders.lock().unwrap().insert(order.id, order);
+        Ok(())
+    }
+
+    async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError> {
+        let order = self.submitted_order(order_id)?;
+        let orig_cl_ord_id = order_id.to_string();
+        let cancel_id = Uuid::new_v4().to_string();
+
+        let mut cancel = FixMessage::new(FixMsgType::OrderCancelRequest);
+        cancel
+            .set(TAG_ORIG_CL_ORD_ID, orig_cl_ord_id.clone())
+            .set(TAG_CL_ORD_ID, cancel_id.clone())
+            .set(TAG_SYMBOL, order.symbol.clone())
+            .set(TAG_SIDE, side_code(order.direction))
+            .set(TAG_ORDER_QTY, order.quantity.to_string())
+            .set(TAG_TRANSACT_TIME, Utc::now().format(FIX_TIME_FORMAT).to_string());
+
+        let response = self.request(cancel, |m| match m.msg_type() {
+            Some(FixMsgType::ExecutionReport) | Some(FixMsgType::OrderCancelReject) => {
+                m.order_id() == Some(cancel_id.as_str()) || m.get(TAG_ORIG_CL_ORD_ID) == Some(orig_cl_ord_id.as_str())
+            },
+            _ => false,
+        }).await?;
+
+        if response.msg_type() == Some(FixMsgType::OrderCancelReject) {
+            return Err(TradingError::Conflict(format!("Cancel of order {} rejected: {}", order_id, response.text().unwrap_or("No reason given"))));
+        }
+
+        info!("Order {} cancelled on {}", order_id, self.config.name);
+        Ok(CancellationResult {
+            exchange_order_id: response.exchange_order_id().map(|id| id.to_string()),
+            cancelled_at: Utc::now(),
+            unfilled_quantity: order.quantity - response.get_f64(TAG_CUM_QTY).unwrap_or(0.0),
+        })
+    }
+
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
+        let order = self.submitted_order(order_id)?;
+        let cl_ord_id = order_id.to_string();
+
+        let mut status_request = FixMessage::new(FixMsgType::OrderStatusRequest);
+        status_request
+            .set(TAG_CL_ORD_ID, cl_ord_id.clone())
+            .set(TAG_SYMBOL, order.symbol.clone())
+            .set(TAG_SIDE, side_code(order.direction));
+
+        let report = self.request(status_request, |m| {
+            m.msg_type() == Some(FixMsgType::ExecutionReport) && m.order_id() == Some(cl_ord_id.as_str())
+        }).await?;
+
+        Ok(self.status_response(order_id, &order, &report))
+    }
+
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError> {
+        Err(TradingError::Exchange(format!("{} does not report balances over FIX", self.config.name)))
+    }
+
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError> {
+        Err(TradingError::Exchange(format!("{} does not report positions over FIX", self.config.name)))
+    }
+}
+
+impl Drop for FixExchange {
+    fn drop(&mut self) {
+        if let Some(task) = self.heartbeat_task.take() {
+            task.abort();
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::{Semaphore, SemaphorePermit};
+use uuid::Uuid;
+
+use super::{
+    Exchange, ExchangeType,
+    MarketSnapshot, OrderStatusResponse, AccountBalance, Position, CancellationResult, MarginInfo, FeeSchedule,
+    AccountTransaction,
+};
+use crate::error::TradingError;
+use crate::order::{Order, OrderType};
+
+/// Caps the requests in flight to an exchange. Market data, order submission
+/// and order status calls beyond the cap wait for one in flight to finish,
+/// so slow responses queue up here instead of piling up connections. The
+/// wrapper implements `Exchange`, so it is registered like the exchange itself.
+pub struct ConcurrencyLimitedExchange {
+    inner: Box<dyn Exchange>,
+    permits: Arc<Semaphore>,
+    limit: usize,
+}
+
+impl ConcurrencyLimitedExchange {
+    /// Wrap `inner`, allowing at most `limit` requests in flight. A limit of zero is raised to one.
+    pub fn new(inner: Box<dyn Exchange>, limit: usize) -> Self {
+        let limit = limit.max(1);
+        ConcurrencyLimitedExchange {
+            inner,
+            permits: Arc::new(Semaphore::new(limit)),
+            limit,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Limited requests currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.limit - self.permits.available_permits()
+    }
+
+    async fn acquire(&self) -> Result<SemaphorePermit<'_>, TradingError> {
+        self.permits.acquire().await
+            .map_err(|_| TradingError::Unavailable(format!("Request limiter for {} is closed", self.inner.name())))
+    }
+}
+
+#[async_trait]
+impl Exchange for ConcurrencyLimitedExchange {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn exchange_type(&self) -> ExchangeType {
+        self.inner.exchange_type()
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    fn supported_order_types(&self) -> Vec<OrderType> {
+        self.inner.supported_order_types()
+    }
+
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        self.inner.connect().await
+    }
+
+    async fn disconnect(&mut self) -> Result<(), TradingError> {
+        self.inner.disconnect().await
+    }
+
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError> {
+        self.inner.get_supported_assets().await
+    }
+
+    async fn get_market_data(&self, symbol: &str) -> Result<MarketSnapshot, TradingError> {
+        let _permit = self.acquire().await?;
+        self.inner.get_market_data(symbol).await
+    }
+
+    async fn submit_order(&self, order: Order) -> Result<(), TradingError> {
+        let _permit = self.acquire().await?;
+        self.inner.submit_order(order).await
+    }
+
+    async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError> {
+        self.inner.cancel_order(order_id).await
+    }
+
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
+        let _permit = self.acquire().await?;
+        self.inner.get_order_status(order_id).await
+    }
+
+    async fn amend_order(&self, order_id: Uuid, new_quantity: f64) -> Result<(), TradingError> {
+        let _permit = self.acquire().await?;
+        self.inner.amend_order(order_id, new_quantity).await
+    }
+
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError> {
+        self.inner.get_account_balance().await
+    }
+
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError> {
+        self.inner.get_positions().await
+    }
+
+    async fn get_margin_info(&self, symbol: &str) -> Result<Option<MarginInfo>, TradingError> {
+        self.inner.get_margin_info(symbol).await
+    }
+
+    async fn get_fee_schedule(&self) -> Result<FeeSchedule, TradingError> {
+        self.inner.get_fee_schedule().await
+    }
+
+    async fn get_account_history(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountTransaction>, TradingError> {
+        self.inner.get_account_history(from, to).await
+    }
+}
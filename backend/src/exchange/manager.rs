@@ -0,0 +1,246 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use crate::error::TradingError;
+use crate::market_data::PriceConverter;
+use super::{Exchange, ExchangeConfig, ExchangeFactory, AccountBalance, AccountMargin, AccountTransaction, ConvertedBalance, CurrencyHolding, Position};
+
+/// Registry of the exchanges the platform trades on, keyed by name
+pub struct ExchangeManager {
+    exchanges: HashMap<String, Arc<dyn Exchange>>,
+}
+
+impl Default for ExchangeManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExchangeManager {
+    pub fn new() -> Self {
+        ExchangeManager {
+            exchanges: HashMap::new(),
+        }
+    }
+
+    /// Create and connect an exchange for each config. Exchanges that can't be
+    /// created or connected are logged and left out.
+    pub async fn from_configs(configs: Vec<ExchangeConfig>) -> Self {
+        let mut manager = ExchangeManager::new();
+
+        for config in configs {
+            let name = config.name.clone();
+            let mut exchange = match ExchangeFactory::create_exchange(config) {
+                Ok(exchange) => exchange,
+                Err(e) => {
+                    warn!("Skipping exchange {}: {}", name, e);
+                    continue;
+                }
+            };
+
+            if let Err(e) = exchange.connect().await {
+                warn!("Failed to connect to {}: {}", name, e);
+                continue;
+            }
+            if let Err(e) = manager.add_exchange(exchange) {
+                warn!("Skipping exchange {}: {}", name, e);
+            }
+        }
+
+        manager
+    }
+
+    pub fn add_exchange(&mut self, exchange: Box<dyn Exchange>) -> Result<(), TradingError> {
+        let name = exchange.name().to_string();
+        if self.exchanges.contains_key(&name) {
+            return Err(TradingError::Conflict(format!("Exchange {} already registered", name)));
+        }
+
+        info!("Adding exchange: {}", name);
+        self.exchanges.insert(name, Arc::from(exchange));
+        Ok(())
+    }
+
+    pub fn get_exchange(&self, name: &str) -> Option<Arc<dyn Exchange>> {
+        self.exchanges.get(name).cloned()
+    }
+
+    /// Every exchange, connected or not, in name order
+    pub fn exchanges(&self) -> Vec<Arc<dyn Exchange>> {
+        let mut exchanges: Vec<Arc<dyn Exchange>> = self.exchanges.values().cloned().collect();
+        exchanges.sort_by(|a, b| a.name().cmp(b.name()));
+        exchanges
+    }
+
+    pub fn exchange_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.exchanges.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    // Connected exchanges in name order, so aggregates come out the same each time
+    fn connected_exchanges(&self) -> Vec<&Arc<dyn Exchange>> {
+        let mut exchanges: Vec<&Arc<dyn Exchange>> = self.exchanges.values()
+            .filter(|exchange| exchange.is_connected())
+            .collect();
+        exchanges.sort_by(|a, b| a.name().cmp(b.name()));
+        exchanges
+    }
+
+    /// Sum balances across connected exchanges. The result is in the currency
+    /// of the first exchange by name; other exchanges' main balances in that
+    /// currency add to its totals, and everything else is consolidated by
+    /// currency into the additional balances. Exchanges that fail to report
+    /// are logged and left out.
+    pub async fn get_aggregate_balance(&self) -> Result<AccountBalance, TradingError> {
+        let mut balances = Vec::new();
+        for exchange in self.connected_exchanges() {
+            match exchange.get_account_balance().await {
+                Ok(balance) => balances.push(balance),
+                Err(e) => warn!("Leaving {} out of the aggregate balance: {}", exchange.name(), e),
+            }
+        }
+
+        let currency = match balances.first() {
+            Some(balance) => balance.currency.clone(),
+            None => return Err(TradingError::Unavailable("No connected exchange reported a balance".to_string())),
+        };
+
+        let mut total = 0.0;
+        let mut available = 0.0;
+        let mut additional: BTreeMap<String, f64> = BTreeMap::new();
+        for balance in balances {
+            if balance.currency == currency {
+                total += balance.total;
+                available += balance.available;
+            } else {
+                *additional.entry(balance.currency).or_insert(0.0) += balance.total;
+            }
+
+            for (other_currency, amount) in balance.additional_balances {
+                *additional.entry(other_currency).or_insert(0.0) += amount;
+            }
+        }
+
+        // Extra balances in the main currency count towards the total only
+        if let Some(amount) = additional.remove(&currency) {
+            total += amount;
+        }
+
+        Ok(AccountBalance {
+            total,
+            available,
+            currency,
+            additional_balances: additional.into_iter().collect(),
+            timestamp: Utc::now(),
+        })
+    }
+
+    /// Balances on every connected exchange, main and additional, summed by
+    /// currency and converted into `quote` at current market prices.
+    /// Currencies with no rate to `quote` are reported in `missing_rates` and
+    /// left out of the total.
+    pub async fn get_balance_in(&self, converter: &PriceConverter, quote: &str) -> Result<ConvertedBalance, TradingError> {
+        let balance = self.get_aggregate_balance().await?;
+
+        let mut amounts: BTreeMap<String, f64> = BTreeMap::new();
+        let held = std::iter::once((balance.currency, balance.total)).chain(balance.additional_balances);
+        for (currency, amount) in held {
+            *amounts.entry(currency).or_insert(0.0) += amount;
+        }
+
+        let mut holdings = Vec::with_capacity(amounts.len());
+        let mut total = 0.0;
+        let mut missing_rates = Vec::new();
+        for (currency, amount) in amounts {
+            let rate = converter.rate(&currency, quote).await;
+            let value = rate.map(|rate| amount * rate);
+            match value {
+                Some(value) => total += value,
+                None => missing_rates.push(currency.clone()),
+            }
+            holdings.push(CurrencyHolding { currency, amount, rate, value });
+        }
+
+        Ok(ConvertedBalance {
+            quote_currency: quote.to_string(),
+            holdings,
+            total,
+            missing_rates,
+            timestamp: balance.timestamp,
+        })
+    }
+
+    /// Total value of the balances on every connected exchange, main and
+    /// additional, in `base` currency at current market prices. Currencies
+    /// with no rate to `base` are logged and left out.
+    pub async fn get_total_balance_in(&self, converter: &PriceConverter, base: &str) -> Result<f64, TradingError> {
+        let balance = self.get_balance_in(converter, base).await?;
+        for holding in balance.holdings.iter().filter(|holding| holding.value.is_none()) {
+            warn!("No {}/{} rate, leaving {} {} out of the total balance", holding.currency, base, holding.amount, holding.currency);
+        }
+
+        Ok(balance.total)
+    }
+
+    /// Positions held on every connected exchange, one entry per exchange and
+    /// symbol. Exchanges that fail to report are logged and left out.
+    pub async fn get_aggregate_positions(&self) -> Vec<Position> {
+        let mut positions = Vec::new();
+        for exchange in self.connected_exchanges() {
+            match exchange.get_positions().await {
+                Ok(exchange_positions) => positions.extend(exchange_positions),
+                Err(e) => warn!("Leaving {} out of the aggregate positions: {}", exchange.name(), e),
+            }
+        }
+
+        positions
+    }
+
+    /// Margin on every connected exchange that trades on margin, with
+    /// liquidation prices for positions in `symbol`. Exchanges that fail to
+    /// report are logged and left out.
+    pub async fn get_aggregate_margin(&self, symbol: &str) -> AccountMargin {
+        let mut margin = AccountMargin::default();
+        for exchange in self.connected_exchanges() {
+            match exchange.get_margin_info(symbol).await {
+                Ok(Some(info)) => {
+                    margin.total_available_margin += info.available_margin;
+                    margin.total_used_margin += info.used_margin;
+                    margin.exchanges.insert(exchange.name().to_string(), info);
+                },
+                Ok(None) => {},
+                Err(e) => warn!("Leaving {} out of the aggregate margin: {}", exchange.name(), e),
+            }
+        }
+
+        margin
+    }
+
+    /// Transactions on every connected exchange from `from` up to but not
+    /// including `to`, oldest first. Exchanges that fail to report are logged
+    /// and left out.
+    pub async fn get_account_history(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<AccountTransaction> {
+        let mut transactions = Vec::new();
+        for exchange in self.connected_exchanges() {
+            match exchange.get_account_history(from, to).await {
+                Ok(exchange_transactions) => transactions.extend(exchange_transactions),
+                Err(e) => warn!("Leaving {} out of the account history: {}", exchange.name(), e),
+            }
+        }
+
+        // Stable, so transactions at the same time keep the order of exchanges by name
+        transactions.sort_by_key(|transaction| transaction.timestamp);
+        transactions
+    }
+}
+
+/// Read exchange configs from a JSON file holding an array of `ExchangeConfig`
+pub fn load_exchange_configs(path: &str) -> Result<Vec<ExchangeConfig>, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read exchange config {}: {}", path, e))?;
+    serde_json::from_str(&contents)
+        .map_err(|e| format!("Invalid exchange config {}: {}", path, e))
+}
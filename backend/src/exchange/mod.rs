@@ -1,10 +1,26 @@
+use std::collections::BTreeMap;
 use uuid::Uuid;
 use serde::{Serialize, Deserialize};
 use async_trait::async_trait;
+use schemars::JsonSchema;
+use utoipa::ToSchema;
 
-use crate::order::Order;
+use crate::config::{env_var, ConfigError};
+use crate::error::TradingError;
+use crate::market_data::{FxRateProvider, PriceLevel};
+use crate::models::{Price, SymbolNormalizer};
+use crate::order::{Order, OrderEvent, OrderType, OrderStatus as OrderOrderStatus};
+use crate::strategy::TradeDirection;
+use crate::utils::text::name_key;
 
+pub mod circuit_breaker;
 pub mod crypto;
+pub mod fill_model;
+pub mod fill_schedule;
+pub mod fix;
+pub mod limit;
+pub mod manager;
+pub mod pool;
 // Comment out missing modules
 // pub mod stock;
 // pub mod forex;
@@ -18,18 +34,61 @@ pub trait Exchange: Send + Sync {
     fn exchange_type(&self) -> ExchangeType;
     fn is_connected(&self) -> bool;
     
-    async fn connect(&mut self) -> Result<(), String>;
-    async fn disconnect(&mut self) -> Result<(), String>;
+    async fn connect(&mut self) -> Result<(), TradingError>;
+    async fn disconnect(&mut self) -> Result<(), TradingError>;
     
-    async fn get_supported_assets(&self) -> Result<Vec<String>, String>;
-    async fn get_market_data(&self, symbol: &str) -> Result<MarketSnapshot, String>;
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError>;
+    async fn get_market_data(&self, symbol: &str) -> Result<MarketSnapshot, TradingError>;
     
-    async fn submit_order(&self, order: Order) -> Result<(), String>;
-    async fn cancel_order(&self, order_id: Uuid) -> Result<(), String>;
-    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, String>;
+    /// Order types `submit_order` accepts; the router turns away the rest
+    fn supported_order_types(&self) -> Vec<OrderType> {
+        OrderType::ALL.to_vec()
+    }
+    
+    async fn submit_order(&self, order: Order) -> Result<(), TradingError>;
+    async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError>;
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError>;
+    
+    /// Lower the quantity of a resting order to `new_quantity`, keeping its
+    /// place and what has filled. Exchanges that cannot amend orders refuse.
+    async fn amend_order(&self, order_id: Uuid, _new_quantity: f64) -> Result<(), TradingError> {
+        Err(rejection_error(&format!("{} cannot amend order {}", self.name(), order_id)))
+    }
+    
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError>;
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError>;
     
-    async fn get_account_balance(&self) -> Result<AccountBalance, String>;
-    async fn get_positions(&self) -> Result<Vec<Position>, String>;
+    /// Margin available for trading `symbol`, or `None` for an account that
+    /// does not trade on margin
+    async fn get_margin_info(&self, _symbol: &str) -> Result<Option<MarginInfo>, TradingError> {
+        Ok(None)
+    }
+    
+    /// Trading and withdrawal fees the account pays; none unless the exchange
+    /// reports them
+    async fn get_fee_schedule(&self) -> Result<FeeSchedule, TradingError> {
+        Ok(FeeSchedule::default())
+    }
+    
+    /// Deposits, withdrawals, trade settlements and other balance changes from
+    /// `from` up to but not including `to`, oldest first
+    async fn get_account_history(&self, _from: chrono::DateTime<chrono::Utc>, _to: chrono::DateTime<chrono::Utc>) -> Result<Vec<AccountTransaction>, TradingError> {
+        Err(TradingError::Unavailable(format!("{} does not report account history", self.name())))
+    }
+}
+
+/// Build a submission error that reports the order was rejected for `reason`,
+/// as opposed to a failure to reach the exchange
+pub fn rejection_error(reason: &str) -> TradingError {
+    TradingError::Rejected(reason.to_string())
+}
+
+/// The rejection reason if `error` is a rejection by the exchange
+pub fn rejection_reason(error: &TradingError) -> Option<&str> {
+    match error {
+        TradingError::Rejected(reason) => Some(reason),
+        _ => None,
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -43,16 +102,38 @@ pub enum ExchangeType {
     Future,
 }
 
+impl std::str::FromStr for ExchangeType {
+    type Err = String;
+
+    /// Accepts the variant name in any case, e.g. `crypto`
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name_key(name).as_str() {
+            "stock" => Ok(ExchangeType::Stock),
+            "crypto" => Ok(ExchangeType::Crypto),
+            "forex" => Ok(ExchangeType::Forex),
+            "bond" => Ok(ExchangeType::Bond),
+            "commodity" => Ok(ExchangeType::Commodity),
+            "option" => Ok(ExchangeType::Option),
+            "future" => Ok(ExchangeType::Future),
+            _ => Err(format!("Unknown exchange type: {}", name)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketSnapshot {
     pub symbol: String,
-    pub price: f64,
-    pub bid: f64,
-    pub ask: f64,
+    pub price: Price,
+    pub bid: Price,
+    pub ask: Price,
     pub bid_size: f64,
     pub ask_size: f64,
     pub volume: f64,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub bids: Vec<PriceLevel>, // Depth behind the top of book, best price first; empty if unknown
+    #[serde(default)]
+    pub asks: Vec<PriceLevel>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,6 +147,50 @@ pub struct OrderStatusResponse {
     pub last_update: chrono::DateTime<chrono::Utc>,
 }
 
+impl OrderStatusResponse {
+    /// Derive the order status implied by this report, taking fill quantities into account.
+    /// Exchanges don't always flip the status when the remaining quantity reaches zero,
+    /// so the quantities win over the reported status. Returns `None` for unknown statuses.
+    pub fn derived_status(&self) -> Option<OrderOrderStatus> {
+        let fully_filled = self.filled_quantity > 0.0 && self.remaining_quantity <= 0.0;
+        
+        match self.status {
+            OrderStatus::Filled => Some(OrderOrderStatus::Filled),
+            OrderStatus::Pending | OrderStatus::Open | OrderStatus::PartiallyFilled => {
+                if fully_filled {
+                    Some(OrderOrderStatus::Filled)
+                } else if self.filled_quantity > 0.0 {
+                    Some(OrderOrderStatus::PartiallyFilled)
+                } else {
+                    Some(OrderOrderStatus::Submitted)
+                }
+            },
+            OrderStatus::Cancelled => Some(OrderOrderStatus::Cancelled),
+            OrderStatus::Rejected => Some(OrderOrderStatus::Rejected),
+            OrderStatus::Unknown => None,
+        }
+    }
+    
+    /// Convert this report into the order update event the order manager understands.
+    /// `filled_qty` is the cumulative filled quantity reported by the exchange.
+    pub fn to_order_event(&self) -> OrderEvent {
+        OrderEvent::Update {
+            order_id: self.order_id,
+            status: self.derived_status(),
+            filled_qty: Some(self.filled_quantity),
+            avg_fill_price: self.average_price,
+        }
+    }
+}
+
+/// Acknowledgement of a cancelled order, as reported by the exchange
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CancellationResult {
+    pub exchange_order_id: Option<String>,
+    pub cancelled_at: chrono::DateTime<chrono::Utc>,
+    pub unfilled_quantity: f64, // Quantity that will now never fill
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderStatus {
     Pending,
@@ -77,7 +202,7 @@ pub enum OrderStatus {
     Unknown,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
 pub struct AccountBalance {
     pub total: f64,
     pub available: f64,
@@ -86,7 +211,48 @@ pub struct AccountBalance {
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl AccountBalance {
+    /// The total and every additional balance, converted into `base` and summed.
+    /// Fails if any currency held has no rate into `base`.
+    pub async fn total_in_base_currency(&self, base: &str, fx: &dyn FxRateProvider) -> Result<f64, TradingError> {
+        let mut total = self.total * fx.get_rate(&self.currency, base).await?;
+        for (currency, amount) in &self.additional_balances {
+            total += amount * fx.get_rate(currency, base).await?;
+        }
+        Ok(total)
+    }
+}
+
+/// Amount of one currency held across exchanges and its value in the quote
+/// currency of a `ConvertedBalance`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CurrencyHolding {
+    pub currency: String,
+    pub amount: f64,
+    pub rate: Option<f64>, // Quote currency per unit; None when no market links them
+    pub value: Option<f64>,
+}
+
+/// Balances across exchanges broken down by currency and valued in one quote
+/// currency. Currencies without a rate are listed in `missing_rates` and left
+/// out of the total rather than counted as zero.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ConvertedBalance {
+    pub quote_currency: String,
+    pub holdings: Vec<CurrencyHolding>, // In currency name order
+    pub total: f64,
+    pub missing_rates: Vec<String>,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+impl ConvertedBalance {
+    /// Whether every currency held could be valued
+    pub fn is_complete(&self) -> bool {
+        self.missing_rates.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Position {
     pub symbol: String,
     pub quantity: f64,
@@ -95,8 +261,100 @@ pub struct Position {
     pub unrealized_pnl: f64,
     pub realized_pnl: f64,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub strategy_id: Option<String>, // Strategy whose fill opened the position
+    #[serde(default)]
+    pub opened_at: Option<chrono::DateTime<chrono::Utc>>, // When it was opened from flat, if known
+}
+
+/// What moved money in or out of an account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub enum TransactionType {
+    Trade, // Settlement of a fill
+    Deposit,
+    Withdrawal,
+    Fee, // Charged apart from any trade
+    Interest,
+    Funding, // Perpetual futures funding payment
+}
+
+/// One change to an account balance, as reported by the exchange
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct AccountTransaction {
+    pub id: String,
+    pub transaction_type: TransactionType,
+    pub currency: String,
+    pub amount: f64, // Positive when credited, negative when debited
+    pub fee: f64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub description: Option<String>,
+}
+
+/// Margin of a leveraged account, in its balance currency
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MarginInfo {
+    pub available_margin: f64,
+    pub used_margin: f64,
+    pub margin_level: f64, // Equity as a percentage of used margin; 0 when none is used
+    pub max_leverage: f64,
+    pub liquidation_price: Option<f64>, // Of the position held in the symbol asked about, if any
+}
+
+impl MarginInfo {
+    /// Margin an order for `quantity` at `price` ties up at the maximum leverage
+    pub fn required_margin(&self, quantity: f64, price: f64) -> f64 {
+        quantity.abs() * price / self.max_leverage.max(1.0)
+    }
+}
+
+/// Fees an exchange charges, for comparing venues on all-in cost
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FeeSchedule {
+    pub maker_bps: f64, // On orders that rest on the book
+    pub taker_bps: f64, // On orders that take liquidity
+    pub withdrawal_fees: BTreeMap<String, f64>, // Flat fee per withdrawal, by asset, in that asset
+}
+
+impl FeeSchedule {
+    /// Fee the order pays, in basis points of its notional. As in the
+    /// taker/maker fill model, limit orders pay the maker fee.
+    pub fn fee_bps(&self, order: &Order) -> f64 {
+        if fill_model::TakerMakerModel::is_maker(order) {
+            self.maker_bps
+        } else {
+            self.taker_bps
+        }
+    }
+    
+    /// What trading at `price` costs, or brings in for a sell, once the
+    /// order's fee is paid
+    pub fn price_after_fees(&self, order: &Order, price: f64) -> f64 {
+        let fee = price * self.fee_bps(order) / 10000.0;
+        match order.direction {
+            TradeDirection::Buy => price + fee,
+            TradeDirection::Sell => price - fee,
+        }
+    }
 }
 
+/// Margin across every exchange that trades on margin, and on each of them by name
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AccountMargin {
+    pub total_available_margin: f64,
+    pub total_used_margin: f64,
+    pub exchanges: BTreeMap<String, MarginInfo>,
+}
+
+/// additional_params key selecting the wire protocol for an exchange
+pub const PROTOCOL_PARAM: &str = "protocol";
+/// additional_params key capping the requests in flight to an exchange
+pub const MAX_CONCURRENT_REQUESTS_PARAM: &str = "max_concurrent_requests";
+/// additional_params key listing, comma-separated, the order types an exchange accepts
+pub const SUPPORTED_ORDER_TYPES_PARAM: &str = "supported_order_types";
+/// additional_params key listing, comma-separated, `canonical=exchange_symbol`
+/// pairs for symbols the exchange writes its own way
+pub const SYMBOL_MAP_PARAM: &str = "symbol_map";
+
 #[allow(dead_code)]
 pub struct ExchangeFactory;
 
@@ -104,7 +362,44 @@ pub struct ExchangeFactory;
 impl ExchangeFactory {
     // Return CryptoExchange directly instead of Box<dyn Exchange>
     pub fn create_crypto_exchange(config: ExchangeConfig) -> Result<crypto::CryptoExchange, String> {
-        Ok(crypto::CryptoExchange::new(config))
+        config.supported_order_types()?;
+        if let Some(schedule) = config.additional_params.get(crypto::FILL_SCHEDULE_PARAM) {
+            schedule.parse::<fill_schedule::FillSchedule>()
+                .map_err(|e| format!("Invalid fill schedule for {}: {}", config.name, e))?;
+        }
+        if let Some(fees) = config.additional_params.get(crypto::WITHDRAWAL_FEES_PARAM) {
+            crypto::parse_withdrawal_fees(fees)
+                .map_err(|e| format!("Invalid withdrawal fees for {}: {}", config.name, e))?;
+        }
+        let fill_model = fill_model::fill_model_from_params(&config.additional_params)
+            .map_err(|e| format!("Invalid fill model for {}: {}", config.name, e))?
+            .unwrap_or_else(|| Box::new(fill_model::ConstantSlippageModel::default()));
+        
+        Ok(crypto::CryptoExchange::with_fill_model(config, fill_model))
+    }
+    
+    /// Create the exchange a config describes: a FIX session when its `protocol`
+    /// param is "fix", otherwise the implementation for its exchange type. With
+    /// a `max_concurrent_requests` param, its requests in flight are capped.
+    pub fn create_exchange(config: ExchangeConfig) -> Result<Box<dyn Exchange>, String> {
+        let limit = config.max_concurrent_requests()?;
+        let exchange: Box<dyn Exchange> = if config.additional_params.get(PROTOCOL_PARAM).map(|p| p.as_str()) == Some("fix") {
+            Box::new(Self::create_fix_exchange(config)?)
+        } else {
+            match config.exchange_type {
+                ExchangeType::Crypto => Box::new(Self::create_crypto_exchange(config)?),
+                other => return Err(format!("No {:?} exchange implementation for {}", other, config.name)),
+            }
+        };
+        
+        Ok(match limit {
+            Some(limit) => Box::new(limit::ConcurrencyLimitedExchange::new(exchange, limit)),
+            None => exchange,
+        })
+    }
+    
+    pub fn create_fix_exchange(config: ExchangeConfig) -> Result<fix::FixExchange, String> {
+        fix::FixExchange::new(config)
     }
     
     // Add other methods for different exchange types as needed
@@ -112,6 +407,17 @@ impl ExchangeFactory {
     // pub fn create_forex_exchange(...) 
 }
 
+// Additional params from `{prefix}_PARAM_{KEY}` variables, keyed by lower-cased `KEY`
+fn env_params(prefix: &str) -> std::collections::HashMap<String, String> {
+    let param_prefix = format!("{}_PARAM_", prefix);
+    std::env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+        .filter_map(|(name, value)| name.strip_prefix(&param_prefix)
+            .filter(|key| !key.is_empty())
+            .map(|key| (key.to_lowercase(), value)))
+        .collect()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExchangeConfig {
     pub name: String,
@@ -120,4 +426,84 @@ pub struct ExchangeConfig {
     pub api_key: Option<String>,
     pub api_secret: Option<String>,
     pub additional_params: std::collections::HashMap<String, String>,
+}
+
+impl ExchangeConfig {
+    /// Config from the environment variables `{prefix}_NAME`, `{prefix}_API_URL`,
+    /// `{prefix}_EXCHANGE_TYPE`, and optionally `{prefix}_API_KEY` and
+    /// `{prefix}_API_SECRET`. Each `{prefix}_PARAM_{KEY}` becomes the additional
+    /// param `key`, e.g. `EXCHANGE_1_PARAM_FILL_SCHEDULE` sets `fill_schedule`.
+    pub fn from_env(prefix: &str) -> Result<ExchangeConfig, ConfigError> {
+        let required = |field: &str| {
+            let name = format!("{}_{}", prefix, field);
+            env_var(&name)?.ok_or(ConfigError::MissingVar(name))
+        };
+        let exchange_type_var = format!("{}_EXCHANGE_TYPE", prefix);
+        let exchange_type = required("EXCHANGE_TYPE")?.parse()
+            .map_err(|message| ConfigError::InvalidVar { name: exchange_type_var, message })?;
+        
+        Ok(ExchangeConfig {
+            name: required("NAME")?,
+            exchange_type,
+            api_url: required("API_URL")?,
+            api_key: env_var(&format!("{}_API_KEY", prefix))?,
+            api_secret: env_var(&format!("{}_API_SECRET", prefix))?,
+            additional_params: env_params(prefix),
+        })
+    }
+    
+    /// Replace the fields set by `{prefix}_*` environment variables, keeping the
+    /// rest; params are overridden one by one
+    pub fn apply_env(&mut self, prefix: &str) -> Result<(), ConfigError> {
+        if let Some(name) = env_var(&format!("{}_NAME", prefix))? {
+            self.name = name;
+        }
+        let exchange_type_var = format!("{}_EXCHANGE_TYPE", prefix);
+        if let Some(exchange_type) = env_var(&exchange_type_var)? {
+            self.exchange_type = exchange_type.parse()
+                .map_err(|message| ConfigError::InvalidVar { name: exchange_type_var, message })?;
+        }
+        if let Some(api_url) = env_var(&format!("{}_API_URL", prefix))? {
+            self.api_url = api_url;
+        }
+        if let Some(api_key) = env_var(&format!("{}_API_KEY", prefix))? {
+            self.api_key = Some(api_key);
+        }
+        if let Some(api_secret) = env_var(&format!("{}_API_SECRET", prefix))? {
+            self.api_secret = Some(api_secret);
+        }
+        self.additional_params.extend(env_params(prefix));
+        Ok(())
+    }
+    
+    /// Cap on requests in flight from the `max_concurrent_requests` param, if set
+    pub fn max_concurrent_requests(&self) -> Result<Option<usize>, String> {
+        match self.additional_params.get(MAX_CONCURRENT_REQUESTS_PARAM) {
+            None => Ok(None),
+            Some(value) => match value.trim().parse::<usize>() {
+                Ok(limit) if limit > 0 => Ok(Some(limit)),
+                _ => Err(format!("Invalid {} '{}' for {}: must be a positive integer", MAX_CONCURRENT_REQUESTS_PARAM, value, self.name)),
+            },
+        }
+    }
+
+    /// Order types from the `supported_order_types` param, if set
+    pub fn supported_order_types(&self) -> Result<Option<Vec<OrderType>>, String> {
+        let Some(value) = self.additional_params.get(SUPPORTED_ORDER_TYPES_PARAM) else {
+            return Ok(None);
+        };
+        let order_types = value.split(',')
+            .map(|name| name.trim().parse::<OrderType>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Invalid {} '{}' for {}: {}", SUPPORTED_ORDER_TYPES_PARAM, value, self.name, e))?;
+        Ok(Some(order_types))
+    }
+
+    /// Register the mappings in the `symbol_map` param, if set, with `normalizer`
+    pub fn register_symbol_map(&self, normalizer: &SymbolNormalizer) -> Result<(), String> {
+        match self.additional_params.get(SYMBOL_MAP_PARAM) {
+            Some(mappings) => normalizer.register_mappings(&self.name, mappings),
+            None => Ok(()),
+        }
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{info, warn, debug};
+use uuid::Uuid;
+
+use super::{
+    Exchange, ExchangeType, ExchangeConfig,
+    MarketSnapshot, OrderStatusResponse, AccountBalance, Position, CancellationResult, MarginInfo, FeeSchedule,
+    AccountTransaction,
+};
+use crate::error::TradingError;
+use crate::order::{Order, OrderType};
+
+/// Interval between background health checks of pooled connections
+pub const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Pool of connections to the same exchange. Order submissions are spread
+/// round-robin across healthy connections; a background task reconnects any
+/// connection that drops. The pool itself implements `Exchange`, so it can be
+/// registered with the `OrderRouter` like a single connection.
+pub struct ConnectionPool<E: Exchange + 'static> {
+    config: ExchangeConfig,
+    connections: Vec<Arc<Mutex<E>>>,
+    healthy: Arc<Vec<AtomicBool>>,
+    next: AtomicUsize,
+    order_connections: std::sync::Mutex<HashMap<Uuid, usize>>, // Maps order to the connection that submitted it
+    health_check_interval: Duration,
+    health_task: std::sync::Mutex<Option<JoinHandle<()>>>,
+    order_types: Vec<OrderType>, // Taken from the first connection, as every connection is to the same exchange
+}
+
+impl<E: Exchange + 'static> ConnectionPool<E> {
+    pub fn new(factory: impl Fn() -> E, size: usize, config: ExchangeConfig) -> Self {
+        let size = size.max(1);
+        let connections: Vec<E> = (0..size).map(|_| factory()).collect();
+        let order_types = connections[0].supported_order_types();
+        let connections = connections.into_iter().map(|connection| Arc::new(Mutex::new(connection))).collect();
+        let healthy = (0..size).map(|_| AtomicBool::new(false)).collect();
+
+        ConnectionPool {
+            config,
+            connections,
+            healthy: Arc::new(healthy),
+            next: AtomicUsize::new(0),
+            order_connections: std::sync::Mutex::new(HashMap::new()),
+            health_check_interval: HEALTH_CHECK_INTERVAL,
+            health_task: std::sync::Mutex::new(None),
+            order_types,
+        }
+    }
+
+    pub fn with_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = interval;
+        self
+    }
+
+    pub fn size(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub fn healthy_count(&self) -> usize {
+        self.healthy.iter().filter(|h| h.load(Ordering::SeqCst)).count()
+    }
+
+    /// Check every connection once, reconnecting any that have dropped
+    pub async fn check_health(&self) {
+        run_health_check(&self.connections, &self.healthy).await;
+    }
+
+    // Pick the next healthy connection in round-robin order
+    fn next_healthy(&self) -> Result<usize, TradingError> {
+        let size = self.connections.len();
+        let start = self.next.fetch_add(1, Ordering::SeqCst);
+
+        (0..size)
+            .map(|offset| (start + offset) % size)
+            .find(|&index| self.healthy[index].load(Ordering::SeqCst))
+            .ok_or_else(|| TradingError::Unavailable(format!("No healthy connections to {}", self.config.name)))
+    }
+
+    // Connection that submitted the order, falling back to any healthy one
+    fn connection_for_order(&self, order_id: Uuid) -> Result<usize, TradingError> {
+        let known = self.order_connections.lock().unwrap().get(&order_id).copied();
+        match known {
+            Some(index) => Ok(index),
+            None => self.next_healthy(),
+        }
+    }
+
+    // Record the connection's state after a failed call so it is skipped until reconnected
+    async fn refresh_health(&self, index: usize) {
+        let connected = self.connections[index].lock().await.is_connected();
+        self.healthy[index].store(connected, Ordering::SeqCst);
+    }
+
+    fn stop_health_task(&self) {
+        if let Some(handle) = self.health_task.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+}
+
+async fn run_health_check<E: Exchange>(connections: &[Arc<Mutex<E>>], healthy: &[AtomicBool]) {
+    for (index, connection) in connections.iter().enumerate() {
+        let mut connection = connection.lock().await;
+
+        if connection.is_connected() {
+            healthy[index].store(true, Ordering::SeqCst);
+            continue;
+        }
+
+        warn!("Pooled connection {} to {} is down, reconnecting", index, connection.name());
+        match connection.connect().await {
+            Ok(()) => {
+                info!("Pooled connection {} to {} reconnected", index, connection.name());
+                healthy[index].store(true, Ordering::SeqCst);
+            },
+            Err(e) => {
+                warn!("Failed to reconnect pooled connection {} to {}: {}", index, connection.name(), e);
+                healthy[index].store(false, Ordering::SeqCst);
+            }
+        }
+    }
+}
+
+impl<E: Exchange + 'static> Drop for ConnectionPool<E> {
+    fn drop(&mut self) {
+        self.stop_health_task();
+    }
+}
+
+#[async_trait]
+impl<E: Exchange + 'static> Exchange for ConnectionPool<E> {
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    fn exchange_type(&self) -> ExchangeType {
+        self.config.exchange_type
+    }
+
+    fn is_connected(&self) -> bool {
+        self.healthy_count() > 0
+    }
+
+    fn supported_order_types(&self) -> Vec<OrderType> {
+        self.order_types.clone()
+    }
+
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        self.check_health().await;
+        if self.healthy_count() == 0 {
+            return Err(TradingError::Exchange(format!("Failed to connect any pooled connection to {}", self.config.name)));
+        }
+
+        self.stop_health_task();
+        let connections = self.connections.clone();
+        let healthy = self.healthy.clone();
+        let interval = self.health_check_interval;
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await; // First tick completes immediately
+            loop {
+                ticker.tick().await;
+                run_health_check(&connections, &healthy).await;
+            }
+        });
+        *self.health_task.lock().unwrap() = Some(handle);
+
+        info!("Connection pool for {} connected ({}/{} healthy)", self.config.name, self.healthy_count(), self.size());
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> Result<(), TradingError> {
+        self.stop_health_task();
+
+        for (index, connection) in self.connections.iter().enumerate() {
+            let mut connection = connection.lock().await;
+            if connection.is_connected() {
+                connection.disconnect().await?;
+            }
+            self.healthy[index].store(false, Ordering::SeqCst);
+        }
+
+        info!("Connection pool for {} disconnected", self.config.name);
+        Ok(())
+    }
+
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError> {
+        let index = self.next_healthy()?;
+        let connection = self.connections[index].lock().await;
+        connection.get_supported_assets().await
+    }
+
+    async fn get_market_data(&self, symbol: &str) -> Result<MarketSnapshot, TradingError> {
+        let index = self.next_healthy()?;
+        let connection = self.connections[index].lock().await;
+        connection.get_market_data(symbol).await
+    }
+
+    async fn submit_order(&self, order: Order) -> Result<(), TradingError> {
+        let index = self.next_healthy()?;
+        let order_id = order.id;
+        debug!("Submitting order {} via pooled connection {} to {}", order_id, index, self.config.name);
+
+        let result = self.connections[index].lock().await.submit_order(order).await;
+        match result {
+            Ok(()) => {
+                self.order_connections.lock().unwrap().insert(order_id, index);
+                Ok(())
+            },
+            Err(e) => {
+                self.refresh_health(index).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError> {
+        let index = self.connection_for_order(order_id)?;
+        let connection = self.connections[index].lock().await;
+        connection.cancel_order(order_id).await
+    }
+
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
+        let index = self.connection_for_order(order_id)?;
+        let connection = self.connections[index].lock().await;
+        connection.get_order_status(order_id).await
+    }
+
+    async fn amend_order(&self, order_id: Uuid, new_quantity: f64) -> Result<(), TradingError> {
+        let index = self.connection_for_order(order_id)?;
+        let connection = self.connections[index].lock().await;
+        connection.amend_order(order_id, new_quantity).await
+    }
+
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError> {
+        let index = self.next_healthy()?;
+        let connection = self.connections[index].lock().await;
+        connection.get_account_balance().await
+    }
+
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError> {
+        let index = self.next_healthy()?;
+        let connection = self.connections[index].lock().await;
+        connection.get_positions().await
+    }
+
+    async fn get_margin_info(&self, symbol: &str) -> Result<Option<MarginInfo>, TradingError> {
+        let index = self.next_healthy()?;
+        let connection = self.connections[index].lock().await;
+        connection.get_margin_info(symbol).await
+    }
+
+    async fn get_fee_schedule(&self) -> Result<FeeSchedule, TradingError> {
+        let index = self.next_healthy()?;
+        let connection = self.connections[index].lock().await;
+        connection.get_fee_schedule().await
+    }
+
+    async fn get_account_history(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountTransaction>, TradingError> {
+        let index = self.next_healthy()?;
+        let connection = self.connections[index].lock().await;
+        connection.get_account_history(from, to).await
+    }
+}
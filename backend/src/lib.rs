@@ -1,6 +1,17 @@
 // Re-export modules for testing
 pub mod api;
+pub mod backtest;
+pub mod channel;
+pub mod clock;
+pub mod config;
+pub mod error;
 pub mod exchange;
 pub mod market_data;
+pub mod models;
+pub mod notifications;
 pub mod order;
-pub mod strategy; 
\ No newline at end of file
+pub mod position;
+pub mod risk;
+pub mod shutdown;
+pub mod strategy;
+pub mod utils;
\ No newline at end of file
@@ -1,19 +1,24 @@
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{info, Level};
+use tracing::{info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 
-mod api;
-mod exchange;
-mod market_data;
-mod order;
-mod strategy;
-// Comment out missing modules
-// mod config; 
-// mod trade;
-// mod risk;
-// mod models;
-// mod utils;
+use arb_platform::{api, channel, config, exchange, market_data, models, notifications, order, risk, shutdown, strategy};
+
+/// Share of full Kelly the Kelly sizer bets when ARB_POSITION_SIZER_FRACTION is unset
+const DEFAULT_KELLY_FRACTION: f64 = 0.5;
+
+/// How often open day orders are checked for expiry
+const DAY_ORDER_EXPIRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Default time between the market prices portfolio VaR estimates volatility from
+const DEFAULT_VAR_SAMPLE_INTERVAL_SECS: u64 = 300;
+
+/// How often the circuit breaker is fed the latest P&L
+const CIRCUIT_BREAKER_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How often portfolio VaR is checked against the auto-hedge threshold
+const AUTO_HEDGE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -26,20 +31,335 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     info!("Starting ARB trading platform");
     
-    // Create the application state
-    let strategy_manager = Arc::new(RwLock::new(strategy::StrategyManager::new()));
-    let market_data_manager = Arc::new(RwLock::new(market_data::MarketDataManager::new()));
-    let order_manager = Arc::new(RwLock::new(order::OrderManager::new()));
+    // Alerts go to the log, and to a webhook when one is configured
+    let mut notifier = notifications::NotificationManager::new();
+    notifier.register_handler(Box::new(notifications::LogNotificationHandler::new()));
+    if let Ok(url) = std::env::var("ARB_NOTIFICATION_WEBHOOK_URL") {
+        notifier.register_handler(Box::new(notifications::WebhookNotificationHandler::new(&url)));
+    }
+    let notification_manager = Arc::new(notifier);
+    
+    // Create the application state. Orders and market data use canonical
+    // symbols, translated to and from each exchange's own by one normalizer.
+    let symbol_normalizer = Arc::new(models::SymbolNormalizer::new());
+    let mut market_data = market_data::MarketDataManager::with_channel_config(
+        channel_config_from_env("ARB_MARKET_DATA_CHANNEL", market_data::DEFAULT_MARKET_EVENT_CAPACITY));
+    market_data.set_symbol_normalizer(symbol_normalizer.clone());
+    let mut strategies = strategy::StrategyManager::new();
+    strategies.register_strategy(Box::new(strategy::InformationArbitrageStrategy::new(market_data.get_sentiment_buffer())));
+    strategies.register_strategy(Box::new(strategy::StatisticalArbitrageStrategy::new()));
+    strategies.register_strategy(Box::new(strategy::MarketMakingStrategy::new(market_data.get_order_books())));
+    strategies.register_strategy(Box::new(strategy::MomentumStrategy::new()));
+    strategies.set_notification_manager(notification_manager.clone());
+    strategies.set_position_sizer(position_sizer_from_env());
+    
+    let strategy_manager = Arc::new(RwLock::new(strategies));
+    
+    // Connect the data sources at once; the platform starts without any that
+    // fail rather than not at all
+    let failed_sources: Vec<String> = market_data.connect_all_sources().await.into_iter()
+        .filter_map(|(name, result)| result.err().map(|e| format!("{} ({})", name, e)))
+        .collect();
+    if !failed_sources.is_empty() {
+        warn!("Starting without data sources: {}", failed_sources.join(", "));
+    }
+    
+    let price_converter = market_data.get_price_converter();
+    let order_books = market_data.get_order_books();
+    let market_data_manager = Arc::new(RwLock::new(market_data));
+    // Order events always block when the channel is full, since a dropped one
+    // is a lost status update
+    if let Ok(policy) = std::env::var("ARB_ORDER_CHANNEL_BACKPRESSURE") {
+        warn!("Ignoring ARB_ORDER_CHANNEL_BACKPRESSURE={}: order events always block", policy);
+    }
+    let mut orders = order::OrderManager::with_event_capacity(
+        channel_capacity_from_env("ARB_ORDER_CHANNEL", order::DEFAULT_ORDER_EVENT_CAPACITY));
+    
+    // Optional limit on the 95% one-day portfolio VaR that new orders may lead to
+    if let Ok(max_var) = std::env::var("ARB_MAX_PORTFOLIO_VAR") {
+        match max_var.parse::<f64>() {
+            Ok(max_var) => orders.set_max_portfolio_var(Some(max_var)),
+            Err(e) => warn!("Ignoring ARB_MAX_PORTFOLIO_VAR={}: {}", max_var, e),
+        }
+    }
+    
+    // Optional cap on the gross value of all positions, in base currency
+    if let Ok(max_exposure) = std::env::var("ARB_MAX_TOTAL_EXPOSURE") {
+        match max_exposure.parse::<f64>() {
+            Ok(max_exposure) => orders.set_max_total_exposure(Some(max_exposure)),
+            Err(e) => warn!("Ignoring ARB_MAX_TOTAL_EXPOSURE={}: {}", max_exposure, e),
+        }
+    }
+    
+    // Optional cap, in basis points, on how far an order may walk the order book
+    orders.set_order_books(order_books);
+    if let Ok(max_slippage) = std::env::var("ARB_MAX_SLIPPAGE_BPS") {
+        match max_slippage.parse::<f64>() {
+            Ok(max_slippage) => orders.set_max_slippage_bps(Some(max_slippage)),
+            Err(e) => warn!("Ignoring ARB_MAX_SLIPPAGE_BPS={}: {}", max_slippage, e),
+        }
+    }
+    
+    // Optional pause on strategy entries into a symbol after a stop order in it fills
+    if let Ok(cooldown) = std::env::var("ARB_POST_STOP_COOLDOWN_SECS") {
+        match cooldown.parse::<u64>() {
+            Ok(cooldown) => orders.set_post_stop_cooldown(std::time::Duration::from_secs(cooldown)),
+            Err(e) => warn!("Ignoring ARB_POST_STOP_COOLDOWN_SECS={}: {}", cooldown, e),
+        }
+    }
+    
+    // Optional best-price routing for orders that name no exchange, e.g.
+    // best_price_after_fees to compare venues on all-in cost
+    if let Ok(policy) = std::env::var("ARB_ROUTING_POLICY") {
+        match policy.parse::<order::RoutingPolicy>() {
+            Ok(policy) => orders.get_order_router().set_routing_policy(policy).await,
+            Err(e) => warn!("Ignoring ARB_ROUTING_POLICY={}: {}", policy, e),
+        }
+    }
+    
+    // Optional halt on rapid intraday drawdown, measured against the given capital
+    if let (Ok(max_drawdown), Ok(capital)) = (std::env::var("ARB_CIRCUIT_BREAKER_DRAWDOWN"), std::env::var("ARB_CIRCUIT_BREAKER_CAPITAL")) {
+        let breaker = match (max_drawdown.parse::<f64>(), capital.parse::<f64>()) {
+            (Ok(max_drawdown), Ok(capital)) => risk::CircuitBreaker::new(max_drawdown, capital),
+            _ => Err(format!("ARB_CIRCUIT_BREAKER_DRAWDOWN={} and ARB_CIRCUIT_BREAKER_CAPITAL={} must be numbers", max_drawdown, capital)),
+        };
+        match breaker {
+            Ok(breaker) => orders.set_circuit_breaker(Some(breaker)),
+            Err(e) => warn!("Ignoring circuit breaker settings: {}", e),
+        }
+    }
+    
+    // Value positions in other quote currencies in the base currency at live prices
+    let base_currency = std::env::var("ARB_BASE_CURRENCY").unwrap_or_else(|_| market_data::DEFAULT_BASE_CURRENCY.to_string());
+    orders.set_price_converter(price_converter, &base_currency);
+    orders.set_notification_manager(notification_manager.clone());
+    orders.set_symbol_normalizer(symbol_normalizer.clone());
+    
+    // Sample market prices for portfolio VaR's volatility and correlation
+    // estimates every ARB_VAR_SAMPLE_INTERVAL_SECS
+    let var_sample_secs = match std::env::var("ARB_VAR_SAMPLE_INTERVAL_SECS") {
+        Ok(value) => match value.parse::<u64>() {
+            Ok(secs) if secs > 0 => secs,
+            _ => {
+                warn!("Ignoring ARB_VAR_SAMPLE_INTERVAL_SECS={}: expected a positive number of seconds", value);
+                DEFAULT_VAR_SAMPLE_INTERVAL_SECS
+            },
+        },
+        Err(_) => DEFAULT_VAR_SAMPLE_INTERVAL_SECS,
+    };
+    let var_sample_interval = std::time::Duration::from_secs(var_sample_secs);
+    if let Err(e) = orders.get_portfolio_manager().set_sample_interval(var_sample_interval) {
+        warn!("Keeping the default VaR sample interval: {}", e);
+    }
+    let order_manager = Arc::new(RwLock::new(orders));
+    
+    let sampling = order_manager.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(var_sample_interval);
+        loop {
+            interval.tick().await;
+            sampling.read().await.record_portfolio_prices().await;
+        }
+    });
+    
+    // Mark the circuit breaker to the latest P&L so it trips on losses
+    // between orders, not only when the next one is placed
+    let breaker_refresh = order_manager.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(CIRCUIT_BREAKER_REFRESH_INTERVAL);
+        loop {
+            interval.tick().await;
+            breaker_refresh.read().await.refresh_circuit_breaker().await;
+        }
+    });
+    
+    // Cancel day orders still open once their trading day is over
+    let expiring = order_manager.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DAY_ORDER_EXPIRY_INTERVAL);
+        loop {
+            interval.tick().await;
+            expiring.read().await.expire_day_orders().await;
+        }
+    });
+    
+    // Optional hedging of every open position on ARB_AUTO_HEDGE_EXCHANGE while
+    // portfolio VaR is above ARB_AUTO_HEDGE_VAR_THRESHOLD, fully unless
+    // ARB_AUTO_HEDGE_RATIO says otherwise
+    if let (Ok(threshold), Ok(hedge_exchange)) = (std::env::var("ARB_AUTO_HEDGE_VAR_THRESHOLD"), std::env::var("ARB_AUTO_HEDGE_EXCHANGE")) {
+        let hedge_ratio = std::env::var("ARB_AUTO_HEDGE_RATIO").unwrap_or_else(|_| "1.0".to_string());
+        let config = match (threshold.parse::<f64>(), hedge_ratio.parse::<f64>()) {
+            (Ok(threshold), Ok(hedge_ratio)) => risk::AutoHedgeConfig::new(threshold, hedge_ratio, &hedge_exchange),
+            _ => Err(format!("ARB_AUTO_HEDGE_VAR_THRESHOLD={} and ARB_AUTO_HEDGE_RATIO={} must be numbers", threshold, hedge_ratio)),
+        };
+        match config {
+            Ok(config) => {
+                let hedger = risk::PositionHedger::new(order_manager.clone(), market_data_manager.clone()).with_auto_hedge(config);
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(AUTO_HEDGE_INTERVAL);
+                    loop {
+                        interval.tick().await;
+                        hedger.auto_hedge().await;
+                    }
+                });
+            },
+            Err(e) => warn!("Ignoring auto-hedge settings: {}", e),
+        }
+    }
+    
+    // Connect to the exchanges in the exchange config, if there is one, and
+    // those set by EXCHANGE_n_* variables, which override the file
+    let config_path = std::env::var("ARB_EXCHANGE_CONFIG").unwrap_or_else(|_| "exchanges.json".to_string());
+    let exchange_configs = match config::TradingConfig::load(&config_path) {
+        Ok(config) => config.exchanges,
+        Err(e) => {
+            warn!("Starting without exchanges: {}", e);
+            Vec::new()
+        }
+    };
+    for exchange_config in &exchange_configs {
+        if let Err(e) = exchange_config.register_symbol_map(&symbol_normalizer) {
+            warn!("Ignoring symbol map: {}", e);
+        }
+    }
+    let exchanges = exchange::manager::ExchangeManager::from_configs(exchange_configs).await;
+    info!("Connected exchanges: {:?}", exchanges.exchange_names());
+    
+    // Orders go to the same exchanges. Those naming no exchange go to their
+    // symbol's primary from ARB_PRIMARY_EXCHANGES, e.g. BTC/USD=Kraken, or else
+    // to ARB_DEFAULT_EXCHANGE, which defaults to the first exchange by name.
+    let router = order_manager.read().await.get_order_router();
+    for exchange in exchanges.exchanges() {
+        if let Err(e) = router.register_shared_exchange(exchange).await {
+            warn!("Not routing orders to an exchange: {}", e);
+        }
+    }
+    if let Ok(primaries) = std::env::var("ARB_PRIMARY_EXCHANGES") {
+        for pair in primaries.split(',').map(str::trim).filter(|pair| !pair.is_empty()) {
+            match pair.split_once('=') {
+                Some((symbol, exchange)) => router.set_primary_exchange(symbol.trim(), exchange.trim()).await?,
+                None => warn!("Ignoring ARB_PRIMARY_EXCHANGES entry '{}': expected SYMBOL=exchange", pair),
+            }
+        }
+    }
+    let default_exchange = std::env::var("ARB_DEFAULT_EXCHANGE").ok()
+        .or_else(|| exchanges.exchange_names().into_iter().next());
+    if let Some(default_exchange) = default_exchange {
+        if let Err(e) = router.set_default_exchange(&default_exchange).await {
+            warn!("No default exchange: {}", e);
+        }
+    }
+    let exchange_manager = Arc::new(RwLock::new(exchanges));
+    
+    // Evaluate the active strategy on a schedule when ARB_EVALUATION_INTERVAL_MS is set
+    let mut coordinator = shutdown::ShutdownCoordinator::new(order_manager.clone(), market_data_manager.clone());
+    if let Ok(interval) = std::env::var("ARB_EVALUATION_INTERVAL_MS") {
+        match interval.parse::<u64>() {
+            Ok(interval) => {
+                let mut scheduler = strategy::StrategyEvaluationScheduler::new(
+                    strategy_manager.clone(),
+                    market_data_manager.clone(),
+                    order_manager.clone(),
+                    std::time::Duration::from_millis(interval),
+                );
+                // Track equity on ARB_STRATEGY_CAPITAL for drawdown pauses and position sizing
+                if let Ok(capital) = std::env::var("ARB_STRATEGY_CAPITAL") {
+                    match capital.parse::<f64>() {
+                        Ok(value) if value > 0.0 => scheduler = scheduler.with_capital(value),
+                        _ => warn!("Ignoring ARB_STRATEGY_CAPITAL={}: expected a positive amount", capital),
+                    }
+                }
+                match scheduler.start() {
+                    Ok(()) => coordinator = coordinator.with_scheduler(scheduler),
+                    Err(e) => warn!("Not scheduling strategy evaluation: {}", e),
+                }
+            },
+            Err(e) => warn!("Ignoring ARB_EVALUATION_INTERVAL_MS={}: {}", interval, e),
+        }
+    }
+    
+    // Time open orders get to finish on SIGTERM or Ctrl-C before the process exits
+    if let Ok(drain_timeout) = std::env::var("ARB_SHUTDOWN_DRAIN_TIMEOUT_SECS") {
+        match drain_timeout.parse::<u64>() {
+            Ok(drain_timeout) => coordinator = coordinator.with_drain_timeout(std::time::Duration::from_secs(drain_timeout)),
+            Err(e) => warn!("Ignoring ARB_SHUTDOWN_DRAIN_TIMEOUT_SECS={}: {}", drain_timeout, e),
+        }
+    }
     
     // In simulation mode, start the API server directly
     info!("Starting API server in simulation mode");
-    api::start_api_server(
+    let server = api::start_api_server(
         strategy_manager,
         market_data_manager,
         order_manager,
+        exchange_manager,
+        notification_manager,
+        symbol_normalizer,
         "0.0.0.0",
         8000,
-    ).await?;
+    );
+    
+    tokio::select! {
+        result = server => result?,
+        outstanding = coordinator.run() => {
+            if !outstanding.is_empty() {
+                warn!("Exiting with {} orders still active", outstanding.len());
+            }
+        },
+    }
     
     Ok(())
-} 
\ No newline at end of file
+}
+
+// Event channel size and backpressure policy from `<prefix>_CAPACITY` and
+// `<prefix>_BACKPRESSURE`, blocking with the default capacity when unset
+fn channel_capacity_from_env(prefix: &str, default_capacity: usize) -> usize {
+    match std::env::var(format!("{}_CAPACITY", prefix)) {
+        Ok(value) => value.parse::<usize>().unwrap_or_else(|e| {
+            warn!("Ignoring {}_CAPACITY={}: {}", prefix, value, e);
+            default_capacity
+        }),
+        Err(_) => default_capacity,
+    }
+}
+
+fn channel_config_from_env(prefix: &str, default_capacity: usize) -> channel::ChannelConfig {
+    let capacity = channel_capacity_from_env(prefix, default_capacity);
+    let policy = match std::env::var(format!("{}_BACKPRESSURE", prefix)) {
+        Ok(value) => channel::BackpressurePolicy::parse(&value).unwrap_or_else(|e| {
+            warn!("Ignoring {}_BACKPRESSURE: {}", prefix, e);
+            channel::BackpressurePolicy::Block
+        }),
+        Err(_) => channel::BackpressurePolicy::Block,
+    };
+    channel::ChannelConfig::new(capacity, policy)
+} 
+
+// ARB_POSITION_SIZER picks how strategy signals are sized: "kelly" for the
+// Kelly criterion scaled by ARB_POSITION_SIZER_FRACTION (half Kelly unless
+// set), or "fixed" for that fraction of equity in every position
+fn position_sizer_from_env() -> Option<Box<dyn risk::PositionSizer>> {
+    let sizer = std::env::var("ARB_POSITION_SIZER").ok()?;
+    let fraction = match std::env::var("ARB_POSITION_SIZER_FRACTION") {
+        Ok(value) => match value.parse::<f64>() {
+            Ok(fraction) if fraction > 0.0 && fraction <= 1.0 => Some(fraction),
+            _ => {
+                warn!("Ignoring ARB_POSITION_SIZER_FRACTION={}: expected a fraction above 0 and at most 1", value);
+                None
+            },
+        },
+        Err(_) => None,
+    };
+    match (sizer.to_lowercase().as_str(), fraction) {
+        ("kelly", fraction) => Some(Box::new(risk::KellySizer::new(fraction.unwrap_or(DEFAULT_KELLY_FRACTION)))),
+        ("fixed", Some(fraction)) => Some(Box::new(risk::FixedFractionalSizer { fraction })),
+        ("fixed", None) => {
+            warn!("Not sizing positions: ARB_POSITION_SIZER=fixed needs ARB_POSITION_SIZER_FRACTION");
+            None
+        },
+        _ => {
+            warn!("Ignoring ARB_POSITION_SIZER={}: expected kelly or fixed", sizer);
+            None
+        },
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::strategy::MarketData;
+
+/// Currency exposure is reported in unless configured otherwise
+pub const DEFAULT_BASE_CURRENCY: &str = "USD";
+
+/// Split a pair symbol such as `BTC/USD` into its base and quote currencies
+pub fn split_symbol(symbol: &str) -> Option<(&str, &str)> {
+    let (base, quote) = symbol.split_once('/')?;
+    if base.is_empty() || quote.is_empty() {
+        return None;
+    }
+    Some((base, quote))
+}
+
+/// Converts amounts between currencies at the latest market prices. Rates come
+/// from `FROM/TO` pairs, the inverse of `TO/FROM` pairs, or a single hop
+/// through a currency both sides trade against (e.g. ETH to BTC via USD).
+#[derive(Clone)]
+pub struct PriceConverter {
+    current_data: Arc<RwLock<MarketData>>,
+}
+
+impl PriceConverter {
+    pub fn new(current_data: Arc<RwLock<MarketData>>) -> Self {
+        PriceConverter { current_data }
+    }
+
+    /// Units of `to` per unit of `from`, or `None` when no market links them
+    pub async fn rate(&self, from: &str, to: &str) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+
+        let data = self.current_data.read().await;
+        let rates = direct_rates(&data);
+        if let Some(rate) = rates.get(&(from, to)) {
+            return Some(*rate);
+        }
+
+        // One hop through a shared currency, taking the first in name order so
+        // the result is stable
+        let mut via: Vec<(&str, f64)> = rates.iter()
+            .filter(|((a, _), _)| *a == from)
+            .filter_map(|((_, mid), first)| rates.get(&(*mid, to)).map(|second| (*mid, first * second)))
+            .collect();
+        via.sort_by(|a, b| a.0.cmp(b.0));
+        via.first().map(|(_, rate)| *rate)
+    }
+
+    /// Latest price of every symbol with a positive one, in name order
+    pub async fn prices(&self) -> Vec<(String, f64)> {
+        let data = self.current_data.read().await;
+        let mut prices: Vec<(String, f64)> = data.asset_data.values()
+            .filter(|asset| asset.price.is_sign_positive())
+            .map(|asset| (asset.symbol.clone(), asset.price.to_f64()))
+            .collect();
+        prices.sort_by(|a, b| a.0.cmp(&b.0));
+        prices
+    }
+
+    /// Convert `amount` of `from` into `to`. Returns `None` when no rate is available.
+    pub async fn convert(&self, amount: f64, from: &str, to: &str) -> Option<f64> {
+        self.rate(from, to).await.map(|rate| amount * rate)
+    }
+}
+
+// Rates in both directions for every pair with a positive price
+fn direct_rates(data: &MarketData) -> HashMap<(&str, &str), f64> {
+    let mut rates = HashMap::new();
+    for asset in data.asset_data.values() {
+        if !asset.price.is_sign_positive() {
+            continue;
+        }
+        let price = asset.price.to_f64();
+        if let Some((base, quote)) = split_symbol(&asset.symbol) {
+            rates.insert((base, quote), price);
+            rates.insert((quote, base), 1.0 / price);
+        }
+    }
+    rates
+}
@@ -0,0 +1,331 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tokio::task::JoinHandle;
+use tracing::{info, debug, warn};
+use schemars::JsonSchema;
+use utoipa::ToSchema;
+
+use super::{DataSource, DataSourceType, MarketEvent};
+use crate::channel::EventSender;
+use crate::error::TradingError;
+use crate::strategy::TradeDirection;
+
+/// Funding payments a perpetual contract makes per year, at one every 8 hours
+pub const FUNDING_PERIODS_PER_YEAR: f64 = 3.0 * 365.0;
+
+/// How often a `FundingRateDataSource` polls its endpoint by default
+pub const DEFAULT_FUNDING_POLL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Latest funding rate of a perpetual futures contract
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct FundingRate {
+    pub symbol: String,
+    pub rate: f64, // Per funding period, as a fraction of notional; longs pay shorts when positive
+    pub next_funding: DateTime<Utc>,
+    pub annualized_rate: f64,
+}
+
+impl FundingRate {
+    pub fn new(symbol: &str, rate: f64, next_funding: DateTime<Utc>) -> Self {
+        FundingRate {
+            symbol: symbol.to_string(),
+            rate,
+            next_funding,
+            annualized_rate: rate * FUNDING_PERIODS_PER_YEAR,
+        }
+    }
+
+    /// Funding a position receives at the next payment, per unit of notional:
+    /// positive when it is paid, negative when it pays
+    pub fn carry(&self, direction: TradeDirection) -> f64 {
+        match direction {
+            TradeDirection::Buy => -self.rate,
+            TradeDirection::Sell => self.rate,
+        }
+    }
+
+    /// Time left until the next payment, zero once it is due
+    pub fn time_to_next_funding(&self, now: DateTime<Utc>) -> chrono::Duration {
+        (self.next_funding - now).max(chrono::Duration::zero())
+    }
+}
+
+/// Latest funding rate per symbol, fed from the market data pipeline. Clones
+/// share the same rates, and a std lock lets strategies read them from the
+/// synchronous `Strategy::evaluate`.
+#[derive(Debug, Clone, Default)]
+pub struct FundingRateMonitor {
+    rates: Arc<RwLock<HashMap<String, FundingRate>>>,
+}
+
+impl FundingRateMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&self, symbol: &str, rate: f64, next_funding: DateTime<Utc>) {
+        self.rates.write().unwrap().insert(symbol.to_string(), FundingRate::new(symbol, rate, next_funding));
+    }
+
+    /// Record the rate carried by a funding rate event. Returns false for any other event.
+    pub fn record_event(&self, event: &MarketEvent) -> bool {
+        match event {
+            MarketEvent::FundingRate { symbol, rate, next_funding, .. } => {
+                self.update(symbol, *rate, *next_funding);
+                true
+            },
+            _ => false,
+        }
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<FundingRate> {
+        self.rates.read().unwrap().get(symbol).cloned()
+    }
+
+    /// Every known rate, by symbol
+    pub fn all(&self) -> Vec<FundingRate> {
+        let mut rates: Vec<FundingRate> = self.rates.read().unwrap().values().cloned().collect();
+        rates.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        rates
+    }
+
+    /// Funding a position of `notional` receives at the next payment on
+    /// `symbol`, negative when it pays; zero for symbols without a known rate
+    pub fn funding_payment(&self, symbol: &str, direction: TradeDirection, notional: f64) -> f64 {
+        self.get(symbol)
+            .map(|rate| rate.carry(direction) * notional.abs())
+            .unwrap_or(0.0)
+    }
+}
+
+/// Fetches the body of a funding rate endpoint. Abstracted so the polling can
+/// run against canned responses.
+#[async_trait]
+pub trait FundingRateFetcher: Send + Sync {
+    async fn fetch(&self, url: &str) -> Result<String, String>;
+}
+
+/// Fetcher backed by reqwest
+pub struct HttpFundingRateFetcher {
+    client: reqwest::Client,
+}
+
+impl Default for HttpFundingRateFetcher {
+    fn default() -> Self {
+        HttpFundingRateFetcher { client: reqwest::Client::new() }
+    }
+}
+
+#[async_trait]
+impl FundingRateFetcher for HttpFundingRateFetcher {
+    async fn fetch(&self, url: &str) -> Result<String, String> {
+        let response = self.client.get(url).send().await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Funding rate request to {} failed: {}", url, e))?;
+        response.text().await
+            .map_err(|e| format!("Could not read funding rates from {}: {}", url, e))
+    }
+}
+
+// Funding rate as served by the endpoint
+#[derive(Deserialize)]
+struct FundingRateReport {
+    symbol: String,
+    rate: f64,
+    next_funding: DateTime<Utc>,
+}
+
+/// Parse an endpoint response, a JSON array of `{symbol, rate, next_funding}`
+/// objects, into funding rate events from `exchange`
+pub fn parse_funding_rates(exchange: &str, body: &str) -> Result<Vec<MarketEvent>, String> {
+    let reports: Vec<FundingRateReport> = serde_json::from_str(body)
+        .map_err(|e| format!("Invalid funding rate response: {}", e))?;
+    Ok(reports.into_iter()
+        .filter(|report| report.rate.is_finite())
+        .map(|report| MarketEvent::FundingRate {
+            symbol: report.symbol,
+            rate: report.rate,
+            next_funding: report.next_funding,
+            exchange: exchange.to_string(),
+        })
+        .collect())
+}
+
+/// Market data source polling an HTTP endpoint for perpetual futures funding
+/// rates. Only subscribed symbols are forwarded, or every symbol while there
+/// are no subscriptions.
+pub struct FundingRateDataSource {
+    name: String,
+    source_type: DataSourceType,
+    url: String,
+    fetcher: Arc<dyn FundingRateFetcher>,
+    poll_interval: Duration,
+    subscriptions: Arc<Mutex<BTreeSet<String>>>, // Shared with the polling task
+    event_sender: EventSender<MarketEvent>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl FundingRateDataSource {
+    pub fn new(name: &str, url: &str, event_sender: EventSender<MarketEvent>) -> Self {
+        Self::with_fetcher(name, url, event_sender, Arc::new(HttpFundingRateFetcher::default()))
+    }
+
+    pub fn with_fetcher(
+        name: &str,
+        url: &str,
+        event_sender: EventSender<MarketEvent>,
+        fetcher: Arc<dyn FundingRateFetcher>,
+    ) -> Self {
+        FundingRateDataSource {
+            name: name.to_string(),
+            source_type: DataSourceType::CryptoExchange(name.to_string()),
+            url: url.to_string(),
+            fetcher,
+            poll_interval: DEFAULT_FUNDING_POLL_INTERVAL,
+            subscriptions: Arc::new(Mutex::new(BTreeSet::new())),
+            event_sender,
+            task: None,
+        }
+    }
+
+    /// Change how often the endpoint is polled. Takes effect on the next connect.
+    pub fn set_poll_interval(&mut self, interval: Duration) {
+        self.poll_interval = interval;
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions.lock().unwrap().iter().cloned().collect()
+    }
+
+    fn stop_task(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+// Everything the polling task needs, detached from the source
+struct PollingTask {
+    name: String,
+    url: String,
+    fetcher: Arc<dyn FundingRateFetcher>,
+    poll_interval: Duration,
+    subscriptions: Arc<Mutex<BTreeSet<String>>>,
+    event_sender: EventSender<MarketEvent>,
+}
+
+impl PollingTask {
+    // Poll straight away, then once per interval, until the source disconnects
+    // or the manager goes away
+    async fn run(self) {
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            if !self.poll().await {
+                return;
+            }
+        }
+    }
+
+    // Fetch the rates and forward them. False once the event channel has closed.
+    async fn poll(&self) -> bool {
+        let events = match self.fetcher.fetch(&self.url).await.and_then(|body| parse_funding_rates(&self.name, &body)) {
+            Ok(events) => events,
+            Err(e) => {
+                warn!("{} funding rate poll failed: {}", self.name, e);
+                return true;
+            },
+        };
+
+        let subscriptions = self.subscriptions.lock().unwrap().clone();
+        let wanted: Vec<MarketEvent> = events.into_iter()
+            .filter(|event| match event {
+                MarketEvent::FundingRate { symbol, .. } => subscriptions.is_empty() || subscriptions.contains(symbol),
+                _ => false,
+            })
+            .collect();
+        debug!("{} polled {} funding rates", self.name, wanted.len());
+
+        for event in wanted {
+            if self.event_sender.send(event).await.is_err() {
+                info!("{} stopping, market event channel closed", self.name);
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[async_trait]
+impl DataSource for FundingRateDataSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn source_type(&self) -> &DataSourceType {
+        &self.source_type
+    }
+
+    /// Start the polling task; the first poll is made in the background
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        if self.task.is_some() {
+            return Err(TradingError::Conflict(format!("{} is already connected", self.name)));
+        }
+        if self.poll_interval.is_zero() {
+            return Err(TradingError::Validation(format!("{} poll interval must be positive", self.name)));
+        }
+
+        let task = PollingTask {
+            name: self.name.clone(),
+            url: self.url.clone(),
+            fetcher: self.fetcher.clone(),
+            poll_interval: self.poll_interval,
+            subscriptions: self.subscriptions.clone(),
+            event_sender: self.event_sender.clone(),
+        };
+
+        info!("Polling {} for funding rates every {:?}", self.url, self.poll_interval);
+        self.task = Some(tokio::spawn(task.run()));
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> Result<(), String> {
+        self.stop_task();
+        info!("Disconnected {}", self.name);
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.task.as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    // Polls return every symbol, so subscribing only changes what is forwarded
+    async fn subscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        self.subscriptions.lock().unwrap().extend(symbols.iter().cloned());
+        Ok(())
+    }
+
+    async fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        for symbol in symbols {
+            subscriptions.remove(symbol);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for FundingRateDataSource {
+    fn drop(&mut self) {
+        self.stop_task();
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::error::TradingError;
+use crate::strategy::MarketData;
+use super::converter::PriceConverter;
+
+// Source of exchange rates between currencies, used to express balances and
+// exposures held in several currencies in a single one
+#[async_trait]
+pub trait FxRateProvider: Send + Sync {
+    /// Units of `to` per unit of `from`
+    async fn get_rate(&self, from: &str, to: &str) -> Result<f64, TradingError>;
+}
+
+/// Rates from the latest market prices: the `FROM/TO` pair, the inverse of the
+/// `TO/FROM` pair, or failing both a single hop as `PriceConverter` finds it
+#[derive(Clone)]
+pub struct MarketDataFxProvider {
+    converter: PriceConverter,
+}
+
+impl MarketDataFxProvider {
+    pub fn new(current_data: Arc<RwLock<MarketData>>) -> Self {
+        MarketDataFxProvider { converter: PriceConverter::new(current_data) }
+    }
+}
+
+impl From<PriceConverter> for MarketDataFxProvider {
+    fn from(converter: PriceConverter) -> Self {
+        MarketDataFxProvider { converter }
+    }
+}
+
+#[async_trait]
+impl FxRateProvider for MarketDataFxProvider {
+    async fn get_rate(&self, from: &str, to: &str) -> Result<f64, TradingError> {
+        self.converter.rate(from, to).await
+            .ok_or_else(|| TradingError::NotFound(format!("No {}/{} rate in current market data", from, to)))
+    }
+}
+
+/// Fixed rates, for tests and offline use. Each rate also answers the inverse pair.
+#[derive(Debug, Clone, Default)]
+pub struct StaticFxProvider {
+    rates: HashMap<(String, String), f64>,
+}
+
+impl StaticFxProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the rate of `from` in `to`, i.e. units of `to` per unit of `from`
+    pub fn with_rate(mut self, from: &str, to: &str, rate: f64) -> Self {
+        self.rates.insert((from.to_string(), to.to_string()), rate);
+        self
+    }
+}
+
+#[async_trait]
+impl FxRateProvider for StaticFxProvider {
+    async fn get_rate(&self, from: &str, to: &str) -> Result<f64, TradingError> {
+        if from == to {
+            return Ok(1.0);
+        }
+        if let Some(rate) = self.rates.get(&(from.to_string(), to.to_string())) {
+            return Ok(*rate);
+        }
+        match self.rates.get(&(to.to_string(), from.to_string())) {
+            Some(rate) if *rate != 0.0 => Ok(1.0 / rate),
+            _ => Err(TradingError::NotFound(format!("No {}/{} rate configured", from, to))),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+use utoipa::ToSchema;
+
+/// How often the manager checks its sources for silence by default
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long a connected source may go without an event before it is unhealthy
+pub const DEFAULT_MAX_SOURCE_SILENCE: Duration = Duration::from_secs(30);
+
+/// Health changes a subscriber may fall behind by before it starts missing them
+pub const SOURCE_HEALTH_BROADCAST_CAPACITY: usize = 256;
+
+/// How the manager watches its connected sources
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthCheckConfig {
+    pub check_interval: Duration,
+    pub max_silence: Duration, // Time without events before a source is unhealthy
+    pub auto_reconnect: bool, // Reconnect unhealthy sources, retrying once per max_silence
+}
+
+impl Default for HealthCheckConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_HEALTH_CHECK_INTERVAL, DEFAULT_MAX_SOURCE_SILENCE)
+    }
+}
+
+impl HealthCheckConfig {
+    pub fn new(check_interval: Duration, max_silence: Duration) -> Self {
+        HealthCheckConfig {
+            check_interval,
+            max_silence,
+            auto_reconnect: true,
+        }
+    }
+
+    /// Whether to reconnect silent sources or only flag them, leaving them as they are
+    pub fn with_auto_reconnect(mut self, auto_reconnect: bool) -> Self {
+        self.auto_reconnect = auto_reconnect;
+        self
+    }
+}
+
+/// Health of one connected data source
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SourceHealth {
+    pub healthy: bool,
+    pub last_event: Option<DateTime<Utc>>, // When its latest event arrived, none since it connected
+    pub reconnect_attempts: u32, // Made since it last sent an event
+    pub last_error: Option<String>, // Why the latest reconnect failed
+}
+
+/// A source turning unhealthy or recovering
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceHealthEvent {
+    pub source_name: String,
+    pub healthy: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct WatchedSource {
+    health: SourceHealth,
+    quiet_since: DateTime<Utc>, // Latest event, connect or reconnect attempt
+}
+
+/// Tracks when each connected source last sent an event. Clones share the
+/// same state, so the event processor and the health check see one record.
+#[derive(Debug, Clone)]
+pub struct SourceHealthMonitor {
+    sources: Arc<Mutex<HashMap<String, WatchedSource>>>,
+    changes: broadcast::Sender<SourceHealthEvent>,
+}
+
+impl Default for SourceHealthMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SourceHealthMonitor {
+    pub fn new() -> Self {
+        SourceHealthMonitor {
+            sources: Arc::new(Mutex::new(HashMap::new())),
+            changes: broadcast::channel(SOURCE_HEALTH_BROADCAST_CAPACITY).0,
+        }
+    }
+
+    /// Start watching a source that has just connected. One already watched
+    /// keeps its record, with its silence counted from `now`.
+    pub fn watch(&self, source_name: &str, now: DateTime<Utc>) {
+        self.sources.lock().unwrap()
+            .entry(source_name.to_string())
+            .and_modify(|source| source.quiet_since = now)
+            .or_insert_with(|| WatchedSource {
+                health: SourceHealth {
+                    healthy: true,
+                    last_event: None,
+                    reconnect_attempts: 0,
+                    last_error: None,
+                },
+                quiet_since: now,
+            });
+    }
+
+    /// Stop watching a source, as when it is disconnected on purpose
+    pub fn unwatch(&self, source_name: &str) {
+        self.sources.lock().unwrap().remove(source_name);
+    }
+
+    /// Note an event from a source. An unhealthy source recovers. Returns false
+    /// for sources not being watched.
+    pub fn record_event(&self, source_name: &str, now: DateTime<Utc>) -> bool {
+        let mut sources = self.sources.lock().unwrap();
+        let Some(source) = sources.get_mut(source_name) else {
+            return false;
+        };
+
+        source.health.last_event = Some(now);
+        source.quiet_since = now;
+        if !source.health.healthy {
+            info!("Data source {} recovered after {} reconnect attempts", source_name, source.health.reconnect_attempts);
+            source.health = SourceHealth {
+                healthy: true,
+                last_event: Some(now),
+                reconnect_attempts: 0,
+                last_error: None,
+            };
+            self.publish(source_name, true, now);
+        }
+        true
+    }
+
+    /// Sources that have sent nothing for at least `max_silence`, in sorted
+    /// order. Each is marked unhealthy, announcing those that were healthy.
+    pub fn silent_sources(&self, now: DateTime<Utc>, max_silence: chrono::Duration) -> Vec<String> {
+        let mut sources = self.sources.lock().unwrap();
+        let mut silent = Vec::new();
+        for (name, source) in sources.iter_mut() {
+            if now - source.quiet_since < max_silence {
+                continue;
+            }
+            if source.health.healthy {
+                warn!("Data source {} has sent no events since {}", name, source.quiet_since);
+                source.health.healthy = false;
+                self.publish(name, false, now);
+            }
+            silent.push(name.clone());
+        }
+        silent.sort();
+        silent
+    }
+
+    /// Note an attempt to reconnect a silent source, with its error if it
+    /// failed. Its silence is counted afresh from `now`.
+    pub fn record_reconnect(&self, source_name: &str, error: Option<String>, now: DateTime<Utc>) {
+        if let Some(source) = self.sources.lock().unwrap().get_mut(source_name) {
+            source.health.reconnect_attempts += 1;
+            source.health.last_error = error;
+            source.quiet_since = now;
+        }
+    }
+
+    /// Health of every watched source, by name
+    pub fn snapshot(&self) -> HashMap<String, SourceHealth> {
+        self.sources.lock().unwrap().iter()
+            .map(|(name, source)| (name.clone(), source.health.clone()))
+            .collect()
+    }
+
+    /// Receive every health change from now on. A subscriber more than
+    /// `SOURCE_HEALTH_BROADCAST_CAPACITY` changes behind misses the oldest.
+    pub fn subscribe(&self) -> broadcast::Receiver<SourceHealthEvent> {
+        self.changes.subscribe()
+    }
+
+    // Nobody listening is fine; the change is still in the snapshot
+    fn publish(&self, source_name: &str, healthy: bool, timestamp: DateTime<Utc>) {
+        let _ = self.changes.send(SourceHealthEvent {
+            source_name: source_name.to_string(),
+            healthy,
+            timestamp,
+        });
+    }
+}
@@ -1,15 +1,43 @@
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::{RwLock, mpsc, oneshot};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use tokio::sync::{broadcast, RwLock, oneshot};
 use chrono::{DateTime, Utc};
 use tracing::{info, debug, warn};
 
+use serde::{Deserialize, Serialize};
+
+use crate::error::TradingError;
+use crate::models::{Price, SymbolNormalizer};
 use crate::strategy::{AssetType, MarketData, AssetData};
+use crate::channel::{event_channel, BackpressurePolicy, ChannelConfig, ChannelStats, EventReceiver, EventSender};
+
+pub mod converter;
+pub mod funding;
+pub mod fx;
+pub mod health;
+pub mod order_book;
+pub mod recorder;
+pub mod replay;
+pub mod sentiment;
+pub mod validator;
+pub mod websocket;
+
+pub use converter::{PriceConverter, split_symbol, DEFAULT_BASE_CURRENCY};
+pub use funding::{FundingRate, FundingRateDataSource, FundingRateMonitor, DEFAULT_FUNDING_POLL_INTERVAL};
+pub use fx::{FxRateProvider, MarketDataFxProvider, StaticFxProvider};
+pub use health::{HealthCheckConfig, SourceHealth, SourceHealthEvent, SourceHealthMonitor, DEFAULT_HEALTH_CHECK_INTERVAL, DEFAULT_MAX_SOURCE_SILENCE};
+pub use order_book::{ImpactEstimate, OrderBook, OrderBookDepth, OrderBooks, PriceLevel};
+pub use recorder::{MarketEventRecorder, RecorderConfig, RotationPolicy};
+pub use replay::{ReplaySource, ReplaySpeed};
+pub use sentiment::{SentimentBuffer, SentimentObservation};
+pub use validator::{DataQualityStats, DataQualityValidator, DEFAULT_MAX_STD_DEVS};
+pub use websocket::{WebSocketDataSource, WsConnectionState, WsReconnectConfig};
 
 // Comment out missing modules
 // mod sources;
 // mod api_clients;
-// mod websocket;
 // mod historical;
 
 // Data source types
@@ -26,8 +54,36 @@ pub enum DataSourceType {
     Custom(String),             // Custom data source
 }
 
-// Market data event
-#[derive(Debug, Clone)]
+/// A price update for one symbol as sent by a feed. Prices arrive as decimal
+/// strings or JSON numbers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tick {
+    pub symbol: String,
+    pub price: Price,
+    pub volume: Option<f64>,
+    pub bid: Option<Price>,
+    pub ask: Option<Price>,
+}
+
+impl Tick {
+    /// The price update event for this tick from `exchange`
+    pub fn into_event(self, exchange: &str, timestamp: DateTime<Utc>) -> MarketEvent {
+        MarketEvent::PriceUpdate {
+            symbol: self.symbol,
+            price: self.price.to_f64(),
+            volume: self.volume,
+            bid: self.bid.map(Price::to_f64),
+            ask: self.ask.map(Price::to_f64),
+            exchange: exchange.to_string(),
+            timestamp,
+        }
+    }
+}
+
+// Market data event. Serialized tagged by kind, as in `{"type": "price_update", ...}`,
+// which is also the format of replay files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 #[allow(dead_code)]
 pub enum MarketEvent {
     PriceUpdate {
@@ -73,9 +129,49 @@ pub enum MarketEvent {
         sentiment: Option<f64>, // -1.0 to 1.0
         timestamp: DateTime<Utc>,
     },
+    // Latest funding rate of a perpetual futures contract, per funding period
+    FundingRate {
+        symbol: String,
+        rate: f64,
+        next_funding: DateTime<Utc>,
+        exchange: String,
+    },
+    // A source came back after dropping its connection; data may have been missed
+    SourceReconnected {
+        source_name: String,
+    },
 }
 
-#[derive(Debug, Clone, Copy)]
+impl MarketEvent {
+    /// Name of the source or exchange the event came from, none for events
+    /// that do not say
+    pub fn source(&self) -> Option<&str> {
+        match self {
+            MarketEvent::PriceUpdate { exchange, .. }
+            | MarketEvent::OrderBookUpdate { exchange, .. }
+            | MarketEvent::TradeExecution { exchange, .. }
+            | MarketEvent::FundingRate { exchange, .. } => Some(exchange),
+            MarketEvent::NewsItem { source, .. }
+            | MarketEvent::SocialMediaPost { source, .. } => Some(source),
+            MarketEvent::SourceReconnected { source_name } => Some(source_name),
+        }
+    }
+    
+    /// When the event happened, none for events that do not say
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        match self {
+            MarketEvent::PriceUpdate { timestamp, .. }
+            | MarketEvent::OrderBookUpdate { timestamp, .. }
+            | MarketEvent::TradeExecution { timestamp, .. }
+            | MarketEvent::NewsItem { timestamp, .. }
+            | MarketEvent::SocialMediaPost { timestamp, .. } => Some(*timestamp),
+            MarketEvent::FundingRate { .. } | MarketEvent::SourceReconnected { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 #[allow(dead_code)]
 pub enum TradeSide {
     Buy,
@@ -83,58 +179,166 @@ pub enum TradeSide {
     Unknown,
 }
 
-// Interface for all data sources
+// Interface for all data sources. Subscription calls are async so sources
+// can wait on the network for an acknowledgement.
+#[async_trait]
 #[allow(dead_code)]
 pub trait DataSource: Send + Sync {
     fn name(&self) -> &str;
     fn source_type(&self) -> &DataSourceType;
-    fn connect(&mut self) -> Result<(), String>;
+    async fn connect(&mut self) -> Result<(), TradingError>;
     fn disconnect(&mut self) -> Result<(), String>;
     fn is_connected(&self) -> bool;
-    fn subscribe(&mut self, symbols: &[String]) -> Result<(), String>;
-    fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String>;
+    async fn subscribe(&mut self, symbols: &[String]) -> Result<(), String>;
+    async fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String>;
+    
+    /// Restore subscriptions lost when the source reconnected. Sources that
+    /// restore their own subscriptions can override this to skip the resend.
+    async fn resubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        self.subscribe(symbols).await
+    }
+}
+
+// Sources by name. Shared with the event task so it can restore subscriptions
+// when a source reports a reconnect. An async lock, as subscribing waits on the source.
+type DataSources = Arc<tokio::sync::Mutex<HashMap<String, Box<dyn DataSource>>>>;
+
+// Names of the sources each symbol is subscribed on, or will be once they
+// connect, by symbol. A symbol with no sources is still wanted and goes to
+// sources added later.
+type Subscriptions = Arc<Mutex<HashMap<String, HashSet<String>>>>;
+
+// Tick size by symbol, applied to the symbol's asset data on each price update
+type TickSizes = Arc<Mutex<HashMap<String, Price>>>;
+
+// Lot size by symbol, stamped on the symbol's asset data like the tick size
+type LotSizes = Arc<Mutex<HashMap<String, f64>>>;
+
+// Everything the event task reads or updates, detached from the manager
+#[derive(Clone)]
+struct EventTargets {
+    current_data: Arc<RwLock<MarketData>>,
+    sentiment: SentimentBuffer,
+    order_books: OrderBooks,
+    funding_rates: FundingRateMonitor,
+    validator: DataQualityValidator,
+    data_sources: DataSources,
+    subscriptions: Subscriptions,
+    tick_sizes: TickSizes,
+    lot_sizes: LotSizes,
+    health: SourceHealthMonitor,
+    symbol_normalizer: Arc<SymbolNormalizer>,
 }
 
+/// Market events buffered by default before the backpressure policy applies
+pub const DEFAULT_MARKET_EVENT_CAPACITY: usize = 10000;
+
+/// Market events a subscriber may fall behind by before it starts missing them
+pub const MARKET_EVENT_BROADCAST_CAPACITY: usize = 4096;
+
+/// Time a source is given to connect before it counts as failed
+pub const DEFAULT_SOURCE_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
 // Market data manager
 #[allow(dead_code)]
 pub struct MarketDataManager {
-    data_sources: HashMap<String, Box<dyn DataSource>>,
+    data_sources: DataSources,
+    subscriptions: Subscriptions,
     current_data: Arc<RwLock<MarketData>>,
-    event_sender: mpsc::Sender<MarketEvent>,
-    event_receiver: Option<mpsc::Receiver<MarketEvent>>,
+    sentiment: SentimentBuffer,
+    order_books: OrderBooks,
+    funding_rates: FundingRateMonitor,
+    validator: DataQualityValidator, // Screens price updates before they reach current_data
+    tick_sizes: TickSizes,
+    lot_sizes: LotSizes,
+    health: SourceHealthMonitor, // When each connected source last sent an event
+    symbol_normalizer: Arc<SymbolNormalizer>, // Turns each exchange's symbols into canonical ones
+    connect_timeout: Duration,
+    event_sender: EventSender<MarketEvent>,
+    event_receiver: Option<EventReceiver<MarketEvent>>,
+    event_broadcast: broadcast::Sender<MarketEvent>, // Every event as received, for subscribers
     shutdown_signal: Option<tokio::sync::oneshot::Sender<()>>,
+    health_check_shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl Default for MarketDataManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[allow(dead_code, unused_variables)]
 impl MarketDataManager {
     pub fn new() -> Self {
-        let (event_sender, event_receiver) = mpsc::channel(10000); // Buffer size for events
+        Self::with_channel_config(ChannelConfig::new(DEFAULT_MARKET_EVENT_CAPACITY, BackpressurePolicy::Block))
+    }
+    
+    /// Create a manager whose event channel has the given size and backpressure
+    /// policy. Dropping stale price updates under load keeps feeds from stalling.
+    pub fn with_channel_config(channel_config: ChannelConfig) -> Self {
+        let (event_sender, event_receiver) = event_channel(channel_config);
         
         MarketDataManager {
-            data_sources: HashMap::new(),
+            data_sources: DataSources::default(),
+            subscriptions: Subscriptions::default(),
             current_data: Arc::new(RwLock::new(MarketData {
                 timestamp: Utc::now(),
                 asset_data: HashMap::new(),
             })),
+            sentiment: SentimentBuffer::default(),
+            order_books: OrderBooks::default(),
+            funding_rates: FundingRateMonitor::default(),
+            validator: DataQualityValidator::default(),
+            tick_sizes: TickSizes::default(),
+            lot_sizes: LotSizes::default(),
+            health: SourceHealthMonitor::default(),
+            symbol_normalizer: Arc::new(SymbolNormalizer::new()),
+            connect_timeout: DEFAULT_SOURCE_CONNECT_TIMEOUT,
             event_sender,
             event_receiver: Some(event_receiver),
+            event_broadcast: broadcast::channel(MARKET_EVENT_BROADCAST_CAPACITY).0,
             shutdown_signal: None,
+            health_check_shutdown: None,
         }
     }
     
-    pub fn add_data_source(&mut self, source: Box<dyn DataSource>) -> Result<(), String> {
+    /// Add a source. Symbols already subscribed through the manager are
+    /// subscribed on it too: straight away if it is connected, otherwise when
+    /// it connects.
+    pub async fn add_data_source(&mut self, mut source: Box<dyn DataSource>) -> Result<(), String> {
         let name = source.name().to_string();
-        if self.data_sources.contains_key(&name) {
+        let mut data_sources = self.data_sources.lock().await;
+        if data_sources.contains_key(&name) {
             return Err(format!("Data source with name '{}' already exists", name));
         }
         
         info!("Adding data source: {} ({:?})", name, source.source_type());
-        self.data_sources.insert(name, source);
+        let symbols = self.subscribed_symbols();
+        if !symbols.is_empty() {
+            if !source.is_connected() {
+                self.record_subscriptions(&name, &symbols);
+            } else {
+                info!("Subscribing {} to {} known symbols", name, symbols.len());
+                match source.subscribe(&symbols).await {
+                    Ok(()) => self.record_subscriptions(&name, &symbols),
+                    Err(e) => warn!("Could not subscribe {} to known symbols: {}", name, e),
+                }
+            }
+        }
+        if source.is_connected() {
+            self.health.watch(&name, Utc::now());
+        }
+        data_sources.insert(name, source);
         Ok(())
     }
     
-    pub fn remove_data_source(&mut self, name: &str) -> Result<(), String> {
-        if let Some(mut source) = self.data_sources.remove(name) {
+    pub async fn remove_data_source(&mut self, name: &str) -> Result<(), String> {
+        let removed = self.data_sources.lock().await.remove(name);
+        if let Some(mut source) = removed {
+            self.health.unwatch(name);
+            for sources in self.subscriptions.lock().unwrap().values_mut() {
+                sources.remove(name);
+            }
             if source.is_connected() {
                 source.disconnect()?;
             }
@@ -145,35 +349,274 @@ impl MarketDataManager {
         }
     }
     
-    pub fn connect_all_sources(&mut self) -> Vec<Result<(), String>> {
-        let mut results = Vec::new();
+    /// Change how long each source is given to connect
+    pub fn set_connect_timeout(&mut self, timeout: Duration) {
+        self.connect_timeout = timeout;
+    }
+    
+    pub fn connect_timeout(&self) -> Duration {
+        self.connect_timeout
+    }
+    
+    /// Connect one source, restoring the symbols it was subscribed to
+    pub async fn connect_source(&mut self, name: &str) -> Result<(), TradingError> {
+        let mut data_sources = self.data_sources.lock().await;
+        let source = data_sources.get_mut(name)
+            .ok_or_else(|| TradingError::NotFound(format!("Data source '{}' not found", name)))?;
         
-        for (name, source) in &mut self.data_sources {
-            info!("Connecting to data source: {}", name);
-            results.push(source.connect());
-        }
+        Self::connect_and_restore(name, source.as_mut(), &self.subscriptions, self.connect_timeout).await?;
+        self.health.watch(name, Utc::now());
+        Ok(())
+    }
+    
+    /// Connect every source at once, each with its subscriptions restored and
+    /// within the connect timeout. Returns each source's name and outcome, by
+    /// name; a source that failed is left disconnected.
+    pub async fn connect_all_sources(&mut self) -> Vec<(String, Result<(), TradingError>)> {
+        let started = Instant::now();
+        let mut data_sources = self.data_sources.lock().await;
+        let mut sources: Vec<(&String, &mut Box<dyn DataSource>)> = data_sources.iter_mut().collect();
+        sources.sort_by(|a, b| a.0.cmp(b.0));
+        
+        let subscriptions = &self.subscriptions;
+        let timeout = self.connect_timeout;
+        let results = futures::future::join_all(sources.into_iter().map(|(name, source)| async move {
+            let result = Self::connect_and_restore(name, source.as_mut(), subscriptions, timeout).await;
+            (name.clone(), result)
+        })).await;
         
+        for (name, result) in &results {
+            match result {
+                Ok(()) => self.health.watch(name, Utc::now()),
+                Err(e) => warn!("Could not connect data source {}: {}", name, e),
+            }
+        }
+        let failed = results.iter().filter(|(_, result)| result.is_err()).count();
+        info!("Connected {} of {} data sources in {:?}, {} failed", results.len() - failed, results.len(), started.elapsed(), failed);
         results
     }
     
-    pub fn disconnect_all_sources(&mut self) -> Vec<Result<(), String>> {
+    /// Connect every source or none: if any fails, those that connected are
+    /// disconnected again and the error lists each failed source.
+    pub async fn connect_all_sources_strict(&mut self) -> Result<(), TradingError> {
+        let results = self.connect_all_sources().await;
+        let failures: Vec<String> = results.iter()
+            .filter_map(|(name, result)| result.as_ref().err().map(|e| format!("{} ({})", name, e)))
+            .collect();
+        if failures.is_empty() {
+            return Ok(());
+        }
+        
+        let mut data_sources = self.data_sources.lock().await;
+        for (name, _) in results.iter().filter(|(_, result)| result.is_ok()) {
+            if let Some(source) = data_sources.get_mut(name) {
+                info!("Disconnecting data source {} after other sources failed to connect", name);
+                self.health.unwatch(name);
+                if let Err(e) = source.disconnect() {
+                    warn!("Could not disconnect data source {}: {}", name, e);
+                }
+            }
+        }
+        Err(TradingError::Unavailable(format!("Failed to connect data sources: {}", failures.join(", "))))
+    }
+    
+    pub async fn disconnect_all_sources(&mut self) -> Vec<Result<(), String>> {
         let mut results = Vec::new();
         
-        for (name, source) in &mut self.data_sources {
+        for (name, source) in self.data_sources.lock().await.iter_mut() {
             info!("Disconnecting from data source: {}", name);
+            self.health.unwatch(name);
             results.push(source.disconnect());
         }
         
         results
     }
     
-    pub fn subscribe_to_symbols(&mut self, source_name: &str, symbols: &[String]) -> Result<(), String> {
-        if let Some(source) = self.data_sources.get_mut(source_name) {
-            info!("Subscribing to {} symbols on {}", symbols.len(), source_name);
-            source.subscribe(symbols)
+    /// Subscribe to symbols on every source, skipping those a source already
+    /// has. Connected sources are subscribed now and the rest when they
+    /// connect. Every source is tried; the error names the ones that failed.
+    pub async fn subscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        {
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            for symbol in symbols {
+                subscriptions.entry(symbol.clone()).or_default();
+            }
+        }
+        
+        let mut data_sources = self.data_sources.lock().await;
+        let mut names: Vec<String> = data_sources.keys().cloned().collect();
+        names.sort();
+        
+        let mut failures = Vec::new();
+        for name in names {
+            let new_symbols: Vec<String> = {
+                let subscriptions = self.subscriptions.lock().unwrap();
+                symbols.iter()
+                    .filter(|symbol| !subscriptions.get(*symbol).is_some_and(|sources| sources.contains(&name)))
+                    .cloned()
+                    .collect::<BTreeSet<String>>()
+                    .into_iter()
+                    .collect()
+            };
+            if new_symbols.is_empty() {
+                continue;
+            }
+            
+            let source = data_sources.get_mut(&name).expect("source names come from the map");
+            if !source.is_connected() {
+                self.record_subscriptions(&name, &new_symbols);
+                continue;
+            }
+            
+            info!("Subscribing to {} symbols on {}", new_symbols.len(), name);
+            match source.subscribe(&new_symbols).await {
+                Ok(()) => self.record_subscriptions(&name, &new_symbols),
+                Err(e) => {
+                    warn!("Could not subscribe to {} symbols on {}: {}", new_symbols.len(), name, e);
+                    failures.push(format!("{} ({})", name, e));
+                },
+            }
+        }
+        
+        if failures.is_empty() {
+            Ok(())
         } else {
-            Err(format!("Data source '{}' not found", source_name))
+            Err(format!("Failed to subscribe on data sources: {}", failures.join(", ")))
+        }
+    }
+    
+    /// Unsubscribe from symbols on every source that has them. The symbols are
+    /// forgotten even where a source fails; the error names those sources.
+    pub async fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        let mut by_source: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        {
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            for symbol in symbols {
+                for source in subscriptions.remove(symbol).unwrap_or_default() {
+                    by_source.entry(source).or_default().push(symbol.clone());
+                }
+            }
         }
+        
+        let mut data_sources = self.data_sources.lock().await;
+        let mut failures = Vec::new();
+        for (name, symbols) in by_source {
+            let Some(source) = data_sources.get_mut(&name) else {
+                continue;
+            };
+            
+            // Disconnected sources are told too, as some restore their own subscriptions
+            info!("Unsubscribing from {} symbols on {}", symbols.len(), name);
+            if let Err(e) = source.unsubscribe(&symbols).await {
+                warn!("Could not unsubscribe from {} symbols on {}: {}", symbols.len(), name, e);
+                failures.push(format!("{} ({})", name, e));
+            }
+        }
+        
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Failed to unsubscribe on data sources: {}", failures.join(", ")))
+        }
+    }
+    
+    pub async fn subscribe_to_symbols(&mut self, source_name: &str, symbols: &[String]) -> Result<(), String> {
+        let mut data_sources = self.data_sources.lock().await;
+        let source = data_sources.get_mut(source_name)
+            .ok_or_else(|| format!("Data source '{}' not found", source_name))?;
+        
+        info!("Subscribing to {} symbols on {}", symbols.len(), source_name);
+        source.subscribe(symbols).await?;
+        self.record_subscriptions(source_name, symbols);
+        Ok(())
+    }
+    
+    pub async fn unsubscribe_from_symbols(&mut self, source_name: &str, symbols: &[String]) -> Result<(), String> {
+        let mut data_sources = self.data_sources.lock().await;
+        let source = data_sources.get_mut(source_name)
+            .ok_or_else(|| format!("Data source '{}' not found", source_name))?;
+        
+        info!("Unsubscribing from {} symbols on {}", symbols.len(), source_name);
+        source.unsubscribe(symbols).await?;
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        for symbol in symbols {
+            if let Some(sources) = subscriptions.get_mut(symbol) {
+                sources.remove(source_name);
+                if sources.is_empty() {
+                    subscriptions.remove(symbol);
+                }
+            }
+        }
+        Ok(())
+    }
+    
+    /// Symbols the manager has subscribed to on a source, in sorted order
+    pub fn get_subscriptions(&self, source_name: &str) -> Vec<String> {
+        Self::symbols_on(source_name, &self.subscriptions)
+    }
+    
+    /// Every symbol subscribed through the manager, on any source, in sorted order
+    pub fn subscribed_symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self.subscriptions.lock().unwrap().keys().cloned().collect();
+        symbols.sort();
+        symbols
+    }
+    
+    /// Sources a symbol is subscribed on, in sorted order
+    pub fn get_symbol_sources(&self, symbol: &str) -> Vec<String> {
+        let mut sources: Vec<String> = self.subscriptions.lock().unwrap()
+            .get(symbol)
+            .map(|sources| sources.iter().cloned().collect())
+            .unwrap_or_default();
+        sources.sort();
+        sources
+    }
+    
+    fn record_subscriptions(&self, source_name: &str, symbols: &[String]) {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        for symbol in symbols {
+            subscriptions.entry(symbol.clone()).or_default().insert(source_name.to_string());
+        }
+    }
+    
+    // Symbols recorded for a source, in sorted order
+    fn symbols_on(source_name: &str, subscriptions: &Subscriptions) -> Vec<String> {
+        let mut symbols: Vec<String> = subscriptions.lock().unwrap().iter()
+            .filter(|(_, sources)| sources.contains(source_name))
+            .map(|(symbol, _)| symbol.clone())
+            .collect();
+        symbols.sort();
+        symbols
+    }
+    
+    // Connect a source and restore its subscriptions. A source that connects but
+    // cannot be re-subscribed, or does not connect in time, is disconnected
+    // again, so failure always means not connected rather than connected
+    // without its symbols.
+    async fn connect_and_restore(name: &str, source: &mut dyn DataSource, subscriptions: &Subscriptions, timeout: Duration) -> Result<(), TradingError> {
+        info!("Connecting to data source: {}", name);
+        let restored = match tokio::time::timeout(timeout, source.connect()).await {
+            Ok(Ok(())) => Self::restore_subscriptions(name, source, subscriptions).await.map_err(TradingError::Exchange),
+            Ok(Err(e)) => return Err(e),
+            Err(_) => Err(TradingError::Unavailable(format!("Data source {} did not connect within {:?}", name, timeout))),
+        };
+        if restored.is_err() {
+            if let Err(e) = source.disconnect() {
+                warn!("Could not disconnect data source {}: {}", name, e);
+            }
+        }
+        restored
+    }
+    
+    // Re-subscribe a source to every symbol recorded for it
+    async fn restore_subscriptions(name: &str, source: &mut dyn DataSource, subscriptions: &Subscriptions) -> Result<(), String> {
+        let symbols = Self::symbols_on(name, subscriptions);
+        if symbols.is_empty() {
+            return Ok(());
+        }
+        
+        info!("Restoring {} subscriptions on {}", symbols.len(), name);
+        source.resubscribe(&symbols).await
     }
     
     pub async fn start_processing(&mut self) -> Result<(), String> {
@@ -183,7 +626,20 @@ impl MarketDataManager {
         let mut event_receiver = self.event_receiver.take()
             .ok_or_else(|| "Event receiver already taken".to_string())?;
             
-        let current_data_clone = self.current_data.clone();
+        let targets = EventTargets {
+            current_data: self.current_data.clone(),
+            sentiment: self.sentiment.clone(),
+            order_books: self.order_books.clone(),
+            funding_rates: self.funding_rates.clone(),
+            validator: self.validator.clone(),
+            data_sources: self.data_sources.clone(),
+            subscriptions: self.subscriptions.clone(),
+            tick_sizes: self.tick_sizes.clone(),
+            lot_sizes: self.lot_sizes.clone(),
+            health: self.health.clone(),
+            symbol_normalizer: self.symbol_normalizer.clone(),
+        };
+        let event_broadcast = self.event_broadcast.clone();
         
         // Spawn a task to process incoming market events
         tokio::spawn(async move {
@@ -193,7 +649,11 @@ impl MarketDataManager {
                 tokio::select! {
                     // Process new market events
                     Some(event) = event_receiver.recv() => {
-                        Self::process_market_event(event, current_data_clone.clone()).await;
+                        // Sending never waits: a subscriber that falls behind misses events instead
+                        if event_broadcast.receiver_count() > 0 {
+                            let _ = event_broadcast.send(event.clone());
+                        }
+                        Self::process_market_event(event, &targets).await;
                     }
                     
                     // Use mutable reference to prevent moving
@@ -210,13 +670,26 @@ impl MarketDataManager {
         Ok(())
     }
     
-    async fn process_market_event(event: MarketEvent, current_data: Arc<RwLock<MarketData>>) {
+    async fn process_market_event(event: MarketEvent, targets: &EventTargets) {
+        // Any event shows its source is alive, even one the checks below drop
+        if let Some(source) = event.source() {
+            targets.health.record_event(source, Utc::now());
+        }
+        
         // Process the market event and update the current data
         match event {
             MarketEvent::PriceUpdate { symbol, price, volume, bid, ask, exchange, timestamp } => {
+                let symbol = targets.symbol_normalizer.to_canonical(&exchange, &symbol).unwrap_or(symbol);
                 debug!("Price update: {} @ ${} on {}", symbol, price, exchange);
                 
-                let mut data = current_data.write().await;
+                // Erroneous ticks are dropped; the validator logs the failed check
+                if targets.validator.check_price_update(&symbol, price, volume, bid, ask).is_err() {
+                    return;
+                }
+                
+                let tick_size = targets.tick_sizes.lock().unwrap().get(&symbol).copied();
+                let lot_size = targets.lot_sizes.lock().unwrap().get(&symbol).copied();
+                let mut data = targets.current_data.write().await;
                 data.timestamp = timestamp;
                 
                 // Update or insert the asset data
@@ -225,26 +698,64 @@ impl MarketDataManager {
                     AssetData {
                         symbol: symbol.clone(),
                         asset_type: AssetType::Stock, // Default, should be determined properly
-                        price: 0.0,
+                        price: Price::ZERO,
                         volume: 0.0,
-                        bid: 0.0,
-                        ask: 0.0,
+                        bid: Price::ZERO,
+                        ask: Price::ZERO,
+                        tick_size,
+                        lot_size,
                         exchange: exchange.clone(),
+                        last_update: timestamp,
                     }
                 });
                 
-                // Update the values
-                asset_data.price = price;
+                // Update the values, rounded to the symbol's tick size
+                asset_data.tick_size = tick_size;
+                asset_data.lot_size = lot_size;
+                asset_data.price = Price::from_f64_with_tick(price, tick_size);
                 if let Some(vol) = volume {
                     asset_data.volume = vol;
                 }
                 if let Some(b) = bid {
-                    asset_data.bid = b;
+                    asset_data.bid = Price::from_f64_with_tick(b, tick_size);
                 }
                 if let Some(a) = ask {
-                    asset_data.ask = a;
+                    asset_data.ask = Price::from_f64_with_tick(a, tick_size);
                 }
                 asset_data.exchange = exchange;
+                asset_data.last_update = timestamp;
+            },
+            
+            MarketEvent::OrderBookUpdate { symbol, bids, asks, exchange, timestamp } => {
+                let symbol = targets.symbol_normalizer.to_canonical(&exchange, &symbol).unwrap_or(symbol);
+                debug!("Order book update: {} ({} bids, {} asks) on {}", symbol, bids.len(), asks.len(), exchange);
+                
+                let book = targets.order_books.write().unwrap()
+                    .entry(symbol.clone())
+                    .or_insert_with(|| Arc::new(std::sync::RwLock::new(OrderBook::new(&symbol, &exchange))))
+                    .clone();
+                book.write().unwrap().apply_update(&bids, &asks, timestamp);
+            },
+            
+            MarketEvent::SourceReconnected { source_name } => {
+                warn!("Data source {} reconnected, data received during the outage was missed", source_name);
+                
+                let mut data_sources = targets.data_sources.lock().await;
+                if let Some(source) = data_sources.get_mut(&source_name) {
+                    if let Err(e) = Self::restore_subscriptions(&source_name, source.as_mut(), &targets.subscriptions).await {
+                        warn!("Failed to restore subscriptions on {}: {}", source_name, e);
+                    }
+                }
+            },
+            
+            MarketEvent::NewsItem { .. } | MarketEvent::SocialMediaPost { .. } => {
+                let recorded = targets.sentiment.record_event(&event);
+                debug!("Sentiment event (recorded={}): {:?}", recorded, event);
+            },
+            
+            MarketEvent::FundingRate { ref symbol, rate, .. } => {
+                debug!("Funding rate: {} {:.4}% per period", symbol, rate * 100.0);
+                targets.funding_rates.record_event(&event);
             },
             
             // Handle other event types
@@ -254,19 +765,197 @@ impl MarketDataManager {
         }
     }
     
-    pub fn get_event_sender(&self) -> mpsc::Sender<MarketEvent> {
+    /// Check connected sources every `check_interval`. One that has sent no
+    /// events for `max_silence` is marked unhealthy and, with `auto_reconnect`,
+    /// disconnected and connected again with its subscriptions restored, once
+    /// per `max_silence` until it recovers. Events are only seen once
+    /// `start_processing` has been called.
+    pub fn start_health_monitoring(&mut self, config: HealthCheckConfig) -> Result<(), String> {
+        if self.health_check_shutdown.is_some() {
+            return Err("Health monitoring already started".to_string());
+        }
+        if config.check_interval.is_zero() {
+            return Err("Health check interval must be positive".to_string());
+        }
+        let max_silence = chrono::Duration::from_std(config.max_silence)
+            .map_err(|_| "Maximum source silence is too long".to_string())?;
+        
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+        self.health_check_shutdown = Some(shutdown_tx);
+        let health = self.health.clone();
+        let data_sources = self.data_sources.clone();
+        let subscriptions = self.subscriptions.clone();
+        let connect_timeout = self.connect_timeout;
+        
+        tokio::spawn(async move {
+            info!("Checking data source health every {:?}", config.check_interval);
+            let mut interval = tokio::time::interval(config.check_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        let silent = health.silent_sources(Utc::now(), max_silence);
+                        if config.auto_reconnect && !silent.is_empty() {
+                            Self::reconnect_sources(&silent, &health, &data_sources, &subscriptions, connect_timeout).await;
+                        }
+                    }
+                    
+                    _ = &mut shutdown_rx => {
+                        info!("Data source health monitoring stopped");
+                        break;
+                    }
+                }
+            }
+        });
+        
+        Ok(())
+    }
+    
+    // Drop and re-establish each source's connection, noting every attempt
+    async fn reconnect_sources(names: &[String], health: &SourceHealthMonitor, data_sources: &DataSources, subscriptions: &Subscriptions, connect_timeout: Duration) {
+        let mut data_sources = data_sources.lock().await;
+        for name in names {
+            let Some(source) = data_sources.get_mut(name) else {
+                health.unwatch(name);
+                continue;
+            };
+            
+            warn!("Reconnecting silent data source {}", name);
+            if source.is_connected() {
+                if let Err(e) = source.disconnect() {
+                    warn!("Could not disconnect data source {}: {}", name, e);
+                }
+            }
+            let error = Self::connect_and_restore(name, source.as_mut(), subscriptions, connect_timeout).await.err();
+            if let Some(e) = &error {
+                warn!("Could not reconnect data source {}: {}", name, e);
+            }
+            health.record_reconnect(name, error.map(|e| e.to_string()), Utc::now());
+        }
+    }
+    
+    /// Health of every connected source, by name
+    pub fn get_source_health(&self) -> HashMap<String, SourceHealth> {
+        self.health.snapshot()
+    }
+    
+    /// Receive every change in source health from now on: a source going
+    /// silent, or sending events again after it did
+    pub fn subscribe_source_health(&self) -> broadcast::Receiver<SourceHealthEvent> {
+        self.health.subscribe()
+    }
+    
+    /// Receive every market event from now on, as the sources sent it and in
+    /// the order processed. A subscriber more than
+    /// `MARKET_EVENT_BROADCAST_CAPACITY` events behind gets `RecvError::Lagged`
+    /// with the number it missed, then carries on from the oldest event still held.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<MarketEvent> {
+        self.event_broadcast.subscribe()
+    }
+    
+    pub fn get_event_sender(&self) -> EventSender<MarketEvent> {
         self.event_sender.clone()
     }
     
+    /// Queue depth and events dropped by the backpressure policy
+    pub fn event_channel_stats(&self) -> ChannelStats {
+        self.event_sender.stats()
+    }
+    
     pub fn get_current_data(&self) -> Arc<RwLock<MarketData>> {
         self.current_data.clone()
     }
     
+    /// Currency converter priced from this manager's live market data
+    pub fn get_price_converter(&self) -> PriceConverter {
+        PriceConverter::new(self.current_data.clone())
+    }
+    
+    /// Exchange rates from the latest market prices
+    pub fn get_fx_provider(&self) -> MarketDataFxProvider {
+        MarketDataFxProvider::new(self.current_data.clone())
+    }
+    
+    /// Symbols that have not been updated within `max_age`
+    pub async fn get_stale_symbols(&self, max_age: chrono::Duration) -> Vec<String> {
+        self.current_data.read().await.stale_symbols(max_age, Utc::now())
+    }
+    
+    pub fn get_order_book(&self, symbol: &str) -> Option<Arc<std::sync::RwLock<OrderBook>>> {
+        self.order_books.read().unwrap().get(symbol).cloned()
+    }
+    
+    /// Handle to every order book, shared with the event processor
+    pub fn get_order_books(&self) -> OrderBooks {
+        self.order_books.clone()
+    }
+    
+    /// Store prices and order books under canonical symbols, as mapped by
+    /// `normalizer`. Takes effect when processing starts.
+    pub fn set_symbol_normalizer(&mut self, normalizer: Arc<SymbolNormalizer>) {
+        self.symbol_normalizer = normalizer;
+    }
+    
+    /// Handle to the validator screening price updates, shared with the event processor
+    pub fn get_data_quality_validator(&self) -> DataQualityValidator {
+        self.validator.clone()
+    }
+    
+    pub fn get_sentiment_buffer(&self) -> SentimentBuffer {
+        self.sentiment.clone()
+    }
+    
+    /// Round the symbol's prices to multiples of `tick_size` from its next
+    /// price update on
+    pub async fn set_tick_size(&self, symbol: &str, tick_size: Price) -> Result<(), String> {
+        if !tick_size.is_sign_positive() {
+            return Err(format!("Tick size for {} must be positive", symbol));
+        }
+        
+        self.tick_sizes.lock().unwrap().insert(symbol.to_string(), tick_size);
+        if let Some(asset_data) = self.current_data.write().await.asset_data.get_mut(symbol) {
+            asset_data.tick_size = Some(tick_size);
+        }
+        Ok(())
+    }
+    
+    pub fn get_tick_size(&self, symbol: &str) -> Option<Price> {
+        self.tick_sizes.lock().unwrap().get(symbol).copied()
+    }
+    
+    /// Have strategy signals on the symbol rounded down to multiples of
+    /// `lot_size`, from now on
+    pub async fn set_lot_size(&self, symbol: &str, lot_size: f64) -> Result<(), String> {
+        if !(lot_size.is_finite() && lot_size > 0.0) {
+            return Err(format!("Lot size for {} must be positive", symbol));
+        }
+        
+        self.lot_sizes.lock().unwrap().insert(symbol.to_string(), lot_size);
+        if let Some(asset_data) = self.current_data.write().await.asset_data.get_mut(symbol) {
+            asset_data.lot_size = Some(lot_size);
+        }
+        Ok(())
+    }
+    
+    pub fn get_lot_size(&self, symbol: &str) -> Option<f64> {
+        self.lot_sizes.lock().unwrap().get(symbol).copied()
+    }
+    
+    /// Handle to the latest funding rates, shared with the event processor
+    pub fn get_funding_monitor(&self) -> FundingRateMonitor {
+        self.funding_rates.clone()
+    }
+    
     pub async fn shutdown(&mut self) -> Result<(), String> {
         info!("Shutting down market data manager");
         
         // Disconnect all data sources
-        self.disconnect_all_sources();
+        self.disconnect_all_sources().await;
+        
+        if let Some(health_check_shutdown) = self.health_check_shutdown.take() {
+            let _ = health_check_shutdown.send(());
+        }
         
         // Send shutdown signal to event processor
         if let Some(shutdown_signal) = self.shutdown_signal.take() {
@@ -0,0 +1,169 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+use chrono::{DateTime, Utc};
+use ordered_float::NotNan;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+
+use super::TradeSide;
+
+/// Order books by symbol. Each book has its own lock so readers of one symbol
+/// don't block updates to another. Uses std locks because strategies read books
+/// from the synchronous `Strategy::evaluate`.
+pub type OrderBooks = Arc<RwLock<HashMap<String, Arc<RwLock<OrderBook>>>>>;
+
+/// Level-2 depth for one symbol: resting quantity at each price
+#[derive(Debug, Clone)]
+pub struct OrderBook {
+    pub symbol: String,
+    pub exchange: String,
+    pub bids: BTreeMap<NotNan<f64>, f64>, // Price -> quantity, best bid last
+    pub asks: BTreeMap<NotNan<f64>, f64>, // Price -> quantity, best ask first
+    pub last_update: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PriceLevel {
+    pub price: f64,
+    pub quantity: f64,
+}
+
+/// The top levels of a book on each side, best price first
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderBookDepth {
+    pub symbol: String,
+    pub exchange: String,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+    pub last_update: DateTime<Utc>,
+}
+
+/// What taking liquidity from a book would cost, estimated by walking its levels
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ImpactEstimate {
+    pub expected_fill_price: f64, // Average price over the quantity the book can fill; 0 if none
+    pub slippage_bps: f64, // Of the expected fill price from the best price, in basis points
+    pub book_levels_consumed: usize, // Levels taken from, including one only partly taken
+    pub is_fully_fillable: bool, // Whether the book holds the whole quantity
+}
+
+impl OrderBook {
+    pub fn new(symbol: &str, exchange: &str) -> Self {
+        OrderBook {
+            symbol: symbol.to_string(),
+            exchange: exchange.to_string(),
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            last_update: Utc::now(),
+        }
+    }
+
+    /// Merge incremental levels into the book. Each level replaces the quantity
+    /// at its price, and a zero quantity removes the level. NaN prices are ignored.
+    pub fn apply_update(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)], timestamp: DateTime<Utc>) {
+        merge_levels(&mut self.bids, bids);
+        merge_levels(&mut self.asks, asks);
+        self.last_update = timestamp;
+    }
+
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.iter().next_back().map(to_level)
+    }
+
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.iter().next().map(to_level)
+    }
+
+    pub fn mid_price(&self) -> Option<f64> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => Some((bid.price + ask.price) / 2.0),
+            _ => None,
+        }
+    }
+
+    pub fn spread(&self) -> Option<f64> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => Some(ask.price - bid.price),
+            _ => None,
+        }
+    }
+
+    /// Total quantity over the best `levels` levels on each side, as (bids, asks)
+    pub fn volume_at_depth(&self, levels: usize) -> (f64, f64) {
+        let bids = self.bids.values().rev().take(levels).sum();
+        let asks = self.asks.values().take(levels).sum();
+        (bids, asks)
+    }
+
+    /// Estimate the fill of a market order for `quantity` against the book: a
+    /// buy takes asks from the best upwards and a sell takes bids from the best
+    /// downwards. When the book runs out, the estimate covers what it holds.
+    pub fn estimate_market_impact(&self, side: TradeSide, quantity: f64) -> ImpactEstimate {
+        let levels: Box<dyn Iterator<Item = (&NotNan<f64>, &f64)>> = match side {
+            TradeSide::Buy => Box::new(self.asks.iter()),
+            TradeSide::Sell => Box::new(self.bids.iter().rev()),
+            TradeSide::Unknown => Box::new(std::iter::empty()),
+        };
+
+        let mut remaining = quantity.max(0.0);
+        let mut filled = 0.0;
+        let mut cost = 0.0;
+        let mut best_price = None;
+        let mut book_levels_consumed = 0;
+        for level in levels.map(to_level) {
+            if remaining <= 0.0 {
+                break;
+            }
+            let taken = level.quantity.min(remaining);
+            best_price.get_or_insert(level.price);
+            filled += taken;
+            cost += taken * level.price;
+            remaining -= taken;
+            book_levels_consumed += 1;
+        }
+
+        let expected_fill_price = if filled > 0.0 { cost / filled } else { 0.0 };
+        let slippage_bps = match best_price {
+            Some(best) if best > 0.0 => (expected_fill_price - best).abs() / best * 10_000.0,
+            _ => 0.0,
+        };
+        ImpactEstimate {
+            expected_fill_price,
+            slippage_bps,
+            book_levels_consumed,
+            is_fully_fillable: remaining <= 0.0,
+        }
+    }
+
+    pub fn depth(&self, levels: usize) -> OrderBookDepth {
+        OrderBookDepth {
+            symbol: self.symbol.clone(),
+            exchange: self.exchange.clone(),
+            bids: self.bids.iter().rev().take(levels).map(to_level).collect(),
+            asks: self.asks.iter().take(levels).map(to_level).collect(),
+            last_update: self.last_update,
+        }
+    }
+}
+
+fn merge_levels(side: &mut BTreeMap<NotNan<f64>, f64>, levels: &[(f64, f64)]) {
+    for &(price, quantity) in levels {
+        let price = match NotNan::new(price) {
+            Ok(price) => price,
+            Err(_) => continue,
+        };
+
+        if quantity > 0.0 {
+            side.insert(price, quantity);
+        } else {
+            side.remove(&price);
+        }
+    }
+}
+
+fn to_level((price, quantity): (&NotNan<f64>, &f64)) -> PriceLevel {
+    PriceLevel {
+        price: price.into_inner(),
+        quantity: *quantity,
+    }
+}
@@ -0,0 +1,240 @@
+// Recording of the live market event stream to disk, in the format replay reads
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use chrono::Utc;
+use tokio::fs::File;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::{broadcast, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use super::MarketEvent;
+
+/// When a recording moves on to a new file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RotationPolicy {
+    /// One file for the whole recording
+    Never,
+    /// Before a line would take the file past this many bytes
+    MaxBytes(u64),
+    /// Once the file has been open this long, checked as events arrive
+    Interval(Duration),
+}
+
+/// Where and how a `MarketEventRecorder` writes
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecorderConfig {
+    pub directory: PathBuf,
+    pub file_prefix: String,
+    pub rotation: RotationPolicy,
+}
+
+impl RecorderConfig {
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        RecorderConfig {
+            directory: directory.as_ref().to_path_buf(),
+            file_prefix: "market-events".to_string(),
+            rotation: RotationPolicy::Never,
+        }
+    }
+
+    pub fn with_file_prefix(mut self, file_prefix: &str) -> Self {
+        self.file_prefix = file_prefix.to_string();
+        self
+    }
+
+    pub fn with_rotation(mut self, rotation: RotationPolicy) -> Self {
+        self.rotation = rotation;
+        self
+    }
+}
+
+/// Appends every market event to JSON lines files that `ReplaySource` can play
+/// back. Events come from a broadcast subscription and are written by a task of
+/// their own through a buffer, so a slow disk never holds up event processing;
+/// if the recorder falls too far behind it misses events, which are counted.
+/// Dropping the recorder stops it as `stop` would, without waiting.
+pub struct MarketEventRecorder {
+    config: RecorderConfig,
+    files: Arc<Mutex<Vec<PathBuf>>>, // Every file written, oldest first
+    recorded: Arc<AtomicU64>,
+    missed: Arc<AtomicU64>,
+    stop_signal: Option<oneshot::Sender<()>>,
+    task: Option<JoinHandle<Result<(), String>>>,
+}
+
+impl MarketEventRecorder {
+    pub fn new(config: RecorderConfig) -> Self {
+        MarketEventRecorder {
+            config,
+            files: Arc::default(),
+            recorded: Arc::default(),
+            missed: Arc::default(),
+            stop_signal: None,
+            task: None,
+        }
+    }
+
+    /// Start recording the events received on `events`, usually from
+    /// `MarketDataManager::subscribe_events`. The first file is created
+    /// before this returns, so a directory that cannot be written fails here.
+    pub async fn start(&mut self, events: broadcast::Receiver<MarketEvent>) -> Result<(), String> {
+        if self.is_recording() {
+            return Err("Market event recorder already started".to_string());
+        }
+        match self.config.rotation {
+            RotationPolicy::MaxBytes(0) => return Err("Recording file size limit must be positive".to_string()),
+            RotationPolicy::Interval(interval) if interval.is_zero() => {
+                return Err("Recording rotation interval must be positive".to_string());
+            },
+            _ => {},
+        }
+
+        tokio::fs::create_dir_all(&self.config.directory).await
+            .map_err(|e| format!("Failed to create recording directory {}: {}", self.config.directory.display(), e))?;
+        let task = RecordingTask {
+            config: self.config.clone(),
+            events,
+            files: self.files.clone(),
+            recorded: self.recorded.clone(),
+            missed: self.missed.clone(),
+        };
+        let file = task.open_file().await?;
+
+        let (stop_tx, stop_rx) = oneshot::channel();
+        self.stop_signal = Some(stop_tx);
+        self.task = Some(tokio::spawn(task.run(file, stop_rx)));
+        Ok(())
+    }
+
+    /// Stop recording once the events already received are written, and flush
+    /// the file. Returns the error that ended the recording early, if any.
+    pub async fn stop(&mut self) -> Result<(), String> {
+        self.stop_signal.take();
+        match self.task.take() {
+            Some(task) => task.await.map_err(|e| format!("Market event recorder failed: {}", e))?,
+            None => Ok(()),
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.task.as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    /// Every file written so far, oldest first
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.files.lock().unwrap().clone()
+    }
+
+    pub fn recorded_events(&self) -> u64 {
+        self.recorded.load(Ordering::Relaxed)
+    }
+
+    /// Events that went past while the recorder was behind
+    pub fn missed_events(&self) -> u64 {
+        self.missed.load(Ordering::Relaxed)
+    }
+}
+
+// The file being written
+struct RecordingFile {
+    writer: BufWriter<File>,
+    bytes: u64,
+    opened: Instant,
+}
+
+// Everything the writing task needs, detached from the recorder
+struct RecordingTask {
+    config: RecorderConfig,
+    events: broadcast::Receiver<MarketEvent>,
+    files: Arc<Mutex<Vec<PathBuf>>>,
+    recorded: Arc<AtomicU64>,
+    missed: Arc<AtomicU64>,
+}
+
+impl RecordingTask {
+    // Write events until stopped or the stream ends, then flush. A stop still
+    // writes the events received before it.
+    async fn run(mut self, mut file: RecordingFile, mut stop: oneshot::Receiver<()>) -> Result<(), String> {
+        let result = loop {
+            tokio::select! {
+                biased;
+
+                received = self.events.recv() => match received {
+                    Ok(event) => {
+                        if let Err(e) = self.record(&mut file, &event).await {
+                            break Err(e);
+                        }
+                    },
+                    Err(broadcast::error::RecvError::Lagged(missed)) => self.note_missed(missed),
+                    Err(broadcast::error::RecvError::Closed) => break Ok(()),
+                },
+
+                _ = &mut stop => break self.drain(&mut file).await,
+            }
+        };
+
+        let flushed = file.writer.flush().await.map_err(|e| format!("Failed to flush market event recording: {}", e));
+        let result = result.and(flushed);
+        match &result {
+            Ok(()) => info!("Stopped recording market events, {} recorded", self.recorded.load(Ordering::Relaxed)),
+            Err(e) => error!("Market event recording stopped: {}", e),
+        }
+        result
+    }
+
+    // Write whatever was received before the stop
+    async fn drain(&mut self, file: &mut RecordingFile) -> Result<(), String> {
+        loop {
+            match self.events.try_recv() {
+                Ok(event) => self.record(file, &event).await?,
+                Err(broadcast::error::TryRecvError::Lagged(missed)) => self.note_missed(missed),
+                Err(_) => return Ok(()),
+            }
+        }
+    }
+
+    async fn record(&self, file: &mut RecordingFile, event: &MarketEvent) -> Result<(), String> {
+        let mut line = serde_json::to_vec(event).map_err(|e| format!("Failed to serialize market event: {}", e))?;
+        line.push(b'\n');
+
+        let rotate = file.bytes > 0 && match self.config.rotation {
+            RotationPolicy::Never => false,
+            RotationPolicy::MaxBytes(max_bytes) => file.bytes + line.len() as u64 > max_bytes,
+            RotationPolicy::Interval(interval) => file.opened.elapsed() >= interval,
+        };
+        if rotate {
+            file.writer.flush().await.map_err(|e| format!("Failed to flush market event recording: {}", e))?;
+            *file = self.open_file().await?;
+        }
+
+        file.writer.write_all(&line).await.map_err(|e| format!("Failed to write market event recording: {}", e))?;
+        file.bytes += line.len() as u64;
+        self.recorded.fetch_add(1, Ordering::Relaxed);
+
+        // Flush whenever caught up, so a quiet stream is on disk promptly
+        if self.events.is_empty() {
+            file.writer.flush().await.map_err(|e| format!("Failed to flush market event recording: {}", e))?;
+        }
+        Ok(())
+    }
+
+    fn note_missed(&self, missed: u64) {
+        warn!("Market event recorder fell behind and missed {} events", missed);
+        self.missed.fetch_add(missed, Ordering::Relaxed);
+    }
+
+    async fn open_file(&self) -> Result<RecordingFile, String> {
+        let sequence = self.files.lock().unwrap().len();
+        let name = format!("{}-{}-{:04}.jsonl", self.config.file_prefix, Utc::now().format("%Y%m%dT%H%M%S"), sequence);
+        let path = self.config.directory.join(name);
+        let file = File::create(&path).await
+            .map_err(|e| format!("Failed to create recording file {}: {}", path.display(), e))?;
+
+        info!("Recording market events to {}", path.display());
+        self.files.lock().unwrap().push(path);
+        Ok(RecordingFile { writer: BufWriter::new(file), bytes: 0, opened: Instant::now() })
+    }
+}
@@ -0,0 +1,150 @@
+// Replay of a recorded market data session through the normal event pipeline
+use std::path::{Path, PathBuf};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::task::JoinHandle;
+use tracing::info;
+
+use crate::channel::EventSender;
+use crate::error::TradingError;
+use super::{DataSource, DataSourceType, MarketEvent};
+
+/// How quickly a recording is replayed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// Every event as soon as the channel takes it
+    Max,
+    /// The recorded spacing between event timestamps divided by the multiplier,
+    /// so 1.0 is the original pace and 10.0 ten times faster
+    Scaled(f64),
+}
+
+/// Parse a recording: one JSON `MarketEvent` per line, blank lines skipped
+pub fn parse_replay_events(contents: &str) -> Result<Vec<MarketEvent>, String> {
+    contents.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| serde_json::from_str(line)
+            .map_err(|e| format!("Invalid market event on line {}: {}", index + 1, e)))
+        .collect()
+}
+
+/// Market data source replaying a recorded session from a JSON lines file, so
+/// the session runs through strategies and order logic again the same way.
+/// The whole recording is sent in file order on each connect, whatever the
+/// subscriptions, and the source reports itself disconnected once it is done.
+pub struct ReplaySource {
+    name: String,
+    source_type: DataSourceType,
+    path: PathBuf,
+    speed: ReplaySpeed,
+    event_sender: EventSender<MarketEvent>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl ReplaySource {
+    pub fn new(name: &str, path: impl AsRef<Path>, event_sender: EventSender<MarketEvent>) -> Self {
+        ReplaySource {
+            name: name.to_string(),
+            source_type: DataSourceType::Custom(name.to_string()),
+            path: path.as_ref().to_path_buf(),
+            speed: ReplaySpeed::Max,
+            event_sender,
+            task: None,
+        }
+    }
+
+    pub fn with_speed(mut self, speed: ReplaySpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn speed(&self) -> ReplaySpeed {
+        self.speed
+    }
+
+    fn stop_task(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+// Send the events, waiting out the recorded gaps unless replaying at max speed.
+// Events without a timestamp go straight after the one before.
+async fn replay(name: String, events: Vec<MarketEvent>, speed: ReplaySpeed, event_sender: EventSender<MarketEvent>) {
+    let mut previous: Option<DateTime<Utc>> = None;
+    let total = events.len();
+    for event in events {
+        if let (ReplaySpeed::Scaled(multiplier), Some(timestamp)) = (speed, event.timestamp()) {
+            if let Some(gap) = previous.and_then(|previous| (timestamp - previous).to_std().ok()) {
+                tokio::time::sleep(gap.div_f64(multiplier)).await;
+            }
+            previous = Some(timestamp);
+        }
+
+        if event_sender.send(event).await.is_err() {
+            info!("{} stopping, market event channel closed", name);
+            return;
+        }
+    }
+    info!("{} replayed {} events", name, total);
+}
+
+#[async_trait]
+impl DataSource for ReplaySource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn source_type(&self) -> &DataSourceType {
+        &self.source_type
+    }
+
+    /// Read the recording and start replaying it in the background. Fails
+    /// without sending anything if the file cannot be read or any line is not
+    /// a market event.
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        if self.task.as_ref().is_some_and(|task| !task.is_finished()) {
+            return Err(TradingError::Conflict(format!("{} is already connected", self.name)));
+        }
+        if let ReplaySpeed::Scaled(multiplier) = self.speed {
+            if !(multiplier.is_finite() && multiplier > 0.0) {
+                return Err(TradingError::Validation(format!("{} replay speed must be positive, got {}", self.name, multiplier)));
+            }
+        }
+
+        let contents = tokio::fs::read_to_string(&self.path).await
+            .map_err(|e| TradingError::Unavailable(format!("Failed to read replay file {}: {}", self.path.display(), e)))?;
+        let events = parse_replay_events(&contents).map_err(TradingError::Validation)?;
+
+        info!("Replaying {} events from {} at {:?}", events.len(), self.path.display(), self.speed);
+        self.task = Some(tokio::spawn(replay(self.name.clone(), events, self.speed, self.event_sender.clone())));
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> Result<(), String> {
+        self.stop_task();
+        info!("Disconnected {}", self.name);
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.task.as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    // The recording decides what is replayed, so subscriptions change nothing
+    async fn subscribe(&mut self, _symbols: &[String]) -> Result<(), String> {
+        Ok(())
+    }
+
+    async fn unsubscribe(&mut self, _symbols: &[String]) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl Drop for ReplaySource {
+    fn drop(&mut self) {
+        self.stop_task();
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use chrono::{DateTime, Duration, Utc};
+
+use super::MarketEvent;
+
+/// How long sentiment observations are kept by default
+pub const DEFAULT_SENTIMENT_WINDOW_SECS: i64 = 15 * 60;
+
+/// A sentiment score attached to a symbol by a news item or social media post
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentimentObservation {
+    pub score: f64, // -1.0 to 1.0
+    pub source: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Rolling per-symbol sentiment fed from the market data pipeline. Clones share
+/// the same observations, so strategies can hold a handle to the buffer the
+/// `MarketDataManager` writes into. Uses a std lock because strategies read it
+/// from the synchronous `Strategy::evaluate`.
+#[derive(Debug, Clone)]
+pub struct SentimentBuffer {
+    observations: Arc<RwLock<HashMap<String, VecDeque<SentimentObservation>>>>,
+    window: Duration,
+}
+
+impl Default for SentimentBuffer {
+    fn default() -> Self {
+        Self::new(Duration::seconds(DEFAULT_SENTIMENT_WINDOW_SECS))
+    }
+}
+
+impl SentimentBuffer {
+    pub fn new(window: Duration) -> Self {
+        SentimentBuffer {
+            observations: Arc::new(RwLock::new(HashMap::new())),
+            window,
+        }
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    pub fn record(&self, symbol: &str, score: f64, source: &str, timestamp: DateTime<Utc>) {
+        let mut observations = self.observations.write().unwrap();
+        let entries = observations.entry(symbol.to_string()).or_default();
+
+        entries.push_back(SentimentObservation {
+            score: score.clamp(-1.0, 1.0),
+            source: source.to_string(),
+            timestamp,
+        });
+
+        // Drop observations that have fallen out of the window
+        let cutoff = timestamp - self.window;
+        while entries.front().map(|o| o.timestamp < cutoff).unwrap_or(false) {
+            entries.pop_front();
+        }
+    }
+
+    /// Record the sentiment carried by a news item or social media post for each
+    /// symbol it mentions. Returns false for events without a sentiment score.
+    pub fn record_event(&self, event: &MarketEvent) -> bool {
+        let (symbols, source, sentiment, timestamp) = match event {
+            MarketEvent::NewsItem { symbols, source, sentiment, timestamp, .. } => (symbols, source, sentiment, timestamp),
+            MarketEvent::SocialMediaPost { symbols, source, sentiment, timestamp, .. } => (symbols, source, sentiment, timestamp),
+            _ => return false,
+        };
+
+        match sentiment {
+            Some(score) => {
+                for symbol in symbols {
+                    self.record(symbol, *score, source, *timestamp);
+                }
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Observations for `symbol` within the window ending at `now`
+    pub fn observations(&self, symbol: &str, now: DateTime<Utc>) -> Vec<SentimentObservation> {
+        let observations = self.observations.read().unwrap();
+        let cutoff = now - self.window;
+
+        observations.get(symbol)
+            .map(|entries| entries.iter()
+                .filter(|o| o.timestamp >= cutoff && o.timestamp <= now)
+                .cloned()
+                .collect())
+            .unwrap_or_default()
+    }
+
+    pub fn symbols(&self) -> Vec<String> {
+        let observations = self.observations.read().unwrap();
+        observations.iter()
+            .filter(|(_, entries)| !entries.is_empty())
+            .map(|(symbol, _)| symbol.clone())
+            .collect()
+    }
+}
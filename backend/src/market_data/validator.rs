@@ -0,0 +1,172 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use serde::{Serialize, Deserialize};
+use tracing::warn;
+use utoipa::ToSchema;
+
+/// Prices further than this many standard deviations from the rolling mean are dropped
+pub const DEFAULT_MAX_STD_DEVS: f64 = 5.0;
+
+/// Accepted prices kept per symbol for the rolling mean and deviation
+pub const DEFAULT_PRICE_WINDOW: usize = 100;
+
+/// Accepted prices needed before the outlier check applies
+pub const MIN_OUTLIER_OBSERVATIONS: usize = 10;
+
+/// Consecutive outliers in a symbol taken as a move to a new price level
+/// rather than bad ticks. The window restarts from them and the last is accepted.
+pub const OUTLIERS_BEFORE_REGIME_SHIFT: usize = 5;
+
+// Names of the checks, as reported in rejection reasons
+pub const CHECK_POSITIVE_PRICE: &str = "positive_price";
+pub const CHECK_POSITIVE_BID: &str = "positive_bid";
+pub const CHECK_ASK_NOT_BELOW_BID: &str = "ask_not_below_bid";
+pub const CHECK_PRICE_OUTLIER: &str = "price_outlier";
+pub const CHECK_NON_NEGATIVE_VOLUME: &str = "non_negative_volume";
+
+/// Counts of price updates seen and dropped by the validator
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DataQualityStats {
+    pub events_received: u64,
+    pub events_rejected: u64,
+    pub rejection_reasons: HashMap<String, u64>, // Check name -> events it rejected
+}
+
+#[derive(Debug)]
+struct ValidatorState {
+    max_std_devs: f64,
+    window: usize,
+    prices: HashMap<String, VecDeque<f64>>, // Recent accepted prices by symbol, oldest first
+    outlier_runs: HashMap<String, Vec<f64>>, // Outliers rejected since the symbol's last accepted price
+    stats: DataQualityStats,
+}
+
+/// Screens price updates for erroneous ticks before they reach the current
+/// market data. Clones share the same state, so the handle held by the
+/// `MarketDataManager` reports what its event processor has seen.
+#[derive(Debug, Clone)]
+pub struct DataQualityValidator {
+    state: Arc<Mutex<ValidatorState>>,
+}
+
+impl Default for DataQualityValidator {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_STD_DEVS, DEFAULT_PRICE_WINDOW)
+    }
+}
+
+impl DataQualityValidator {
+    pub fn new(max_std_devs: f64, window: usize) -> Self {
+        DataQualityValidator {
+            state: Arc::new(Mutex::new(ValidatorState {
+                max_std_devs,
+                window: window.max(MIN_OUTLIER_OBSERVATIONS),
+                prices: HashMap::new(),
+                outlier_runs: HashMap::new(),
+                stats: DataQualityStats::default(),
+            })),
+        }
+    }
+
+    pub fn max_std_devs(&self) -> f64 {
+        self.state.lock().unwrap().max_std_devs
+    }
+
+    pub fn set_max_std_devs(&self, max_std_devs: f64) -> Result<(), String> {
+        if max_std_devs <= 0.0 {
+            return Err("Outlier threshold must be a positive number of standard deviations".to_string());
+        }
+        self.state.lock().unwrap().max_std_devs = max_std_devs;
+        Ok(())
+    }
+
+    /// Check a price update. Returns the name of the first failed check, in
+    /// which case the update should be dropped. Accepted prices join the
+    /// symbol's rolling window; rejected ones never do, so a spike cannot
+    /// widen the band that lets the next one through. A run of
+    /// `OUTLIERS_BEFORE_REGIME_SHIFT` outliers is taken as a new price level:
+    /// the window restarts from the run and its last price is accepted.
+    pub fn check_price_update(
+        &self,
+        symbol: &str,
+        price: f64,
+        volume: Option<f64>,
+        bid: Option<f64>,
+        ask: Option<f64>,
+    ) -> Result<(), &'static str> {
+        let mut state = self.state.lock().unwrap();
+        state.stats.events_received += 1;
+
+        let failure = if price.is_nan() || price <= 0.0 {
+            Some((CHECK_POSITIVE_PRICE, price))
+        } else if let Some(bid) = bid.filter(|bid| bid.is_nan() || *bid <= 0.0) {
+            Some((CHECK_POSITIVE_BID, bid))
+        } else if let Some(ask) = ask.filter(|ask| bid.map(|bid| *ask < bid).unwrap_or(false)) {
+            Some((CHECK_ASK_NOT_BELOW_BID, ask))
+        } else if let Some(volume) = volume.filter(|volume| volume.is_nan() || *volume < 0.0) {
+            Some((CHECK_NON_NEGATIVE_VOLUME, volume))
+        } else if state.is_outlier(symbol, price) {
+            Some((CHECK_PRICE_OUTLIER, price))
+        } else {
+            None
+        };
+        let failure = match failure {
+            Some((CHECK_PRICE_OUTLIER, _)) if state.is_regime_shift(symbol, price) => None,
+            failure => failure,
+        };
+
+        match failure {
+            Some((check, value)) => {
+                warn!("Dropping price update for {}: {} failed check {}", symbol, value, check);
+                state.stats.events_rejected += 1;
+                *state.stats.rejection_reasons.entry(check.to_string()).or_insert(0) += 1;
+                Err(check)
+            },
+            None => {
+                state.outlier_runs.remove(symbol);
+                let window = state.window;
+                let prices = state.prices.entry(symbol.to_string()).or_default();
+                prices.push_back(price);
+                while prices.len() > window {
+                    prices.pop_front();
+                }
+                Ok(())
+            },
+        }
+    }
+
+    pub fn stats(&self) -> DataQualityStats {
+        self.state.lock().unwrap().stats.clone()
+    }
+}
+
+impl ValidatorState {
+    // Outside the band around the rolling mean. Skipped until enough prices are
+    // seen, and while they have not varied, since there is nothing to size the band.
+    fn is_outlier(&self, symbol: &str, price: f64) -> bool {
+        let prices = match self.prices.get(symbol) {
+            Some(prices) if prices.len() >= MIN_OUTLIER_OBSERVATIONS => prices,
+            _ => return false,
+        };
+
+        let n = prices.len() as f64;
+        let mean = prices.iter().sum::<f64>() / n;
+        let std_dev = (prices.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+        std_dev > 0.0 && (price - mean).abs() > self.max_std_devs * std_dev
+    }
+
+    // Add an outlier to the symbol's run. Once the run is long enough, the
+    // window is replaced by the earlier outliers, ready to take this one.
+    fn is_regime_shift(&mut self, symbol: &str, price: f64) -> bool {
+        let run = self.outlier_runs.entry(symbol.to_string()).or_default();
+        if run.len() + 1 < OUTLIERS_BEFORE_REGIME_SHIFT {
+            run.push(price);
+            return false;
+        }
+
+        let run = std::mem::take(run);
+        warn!("{} consecutive outliers in {}, restarting its price window at {}", run.len() + 1, symbol, price);
+        self.prices.insert(symbol.to_string(), run.into());
+        true
+    }
+}
@@ -0,0 +1,409 @@
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{info, debug, warn, error};
+use uuid::Uuid;
+
+use super::{DataSource, DataSourceType, MarketEvent, Tick};
+use crate::channel::EventSender;
+use crate::error::TradingError;
+
+/// Reconnection attempts made before a source is given up on by default
+pub const DEFAULT_MAX_RECONNECT_ATTEMPTS: u32 = 10;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WsConnectionState {
+    Disconnected,
+    Connecting,
+    Connected { session_id: String },
+    Reconnecting { attempt: u32, next_retry: Instant },
+    PermanentlyFailed,
+}
+
+/// Opens WebSocket connections. Abstracted so the reconnection logic can run
+/// against scripted connections.
+#[async_trait]
+pub trait WsTransport: Send + Sync {
+    async fn connect(&self, url: &str) -> Result<Box<dyn WsStream>, String>;
+}
+
+/// An open WebSocket connection carrying text messages
+#[async_trait]
+pub trait WsStream: Send {
+    async fn send(&mut self, message: String) -> Result<(), String>;
+    /// The next text message, or an error once the connection has dropped
+    async fn next_message(&mut self) -> Result<String, String>;
+}
+
+/// Transport backed by tokio-tungstenite
+pub struct TungsteniteTransport;
+
+#[async_trait]
+impl WsTransport for TungsteniteTransport {
+    async fn connect(&self, url: &str) -> Result<Box<dyn WsStream>, String> {
+        let (stream, _) = tokio_tungstenite::connect_async(url).await
+            .map_err(|e| format!("WebSocket connection to {} failed: {}", url, e))?;
+        Ok(Box::new(TungsteniteStream { inner: stream }))
+    }
+}
+
+struct TungsteniteStream {
+    inner: tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+}
+
+#[async_trait]
+impl WsStream for TungsteniteStream {
+    async fn send(&mut self, message: String) -> Result<(), String> {
+        self.inner.send(tokio_tungstenite::tungstenite::Message::Text(message)).await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn next_message(&mut self) -> Result<String, String> {
+        use tokio_tungstenite::tungstenite::Message;
+
+        loop {
+            match self.inner.next().await {
+                Some(Ok(Message::Text(text))) => return Ok(text),
+                Some(Ok(Message::Close(_))) | None => return Err("Connection closed".to_string()),
+                Some(Ok(_)) => continue, // Pings are answered by tungstenite
+                Some(Err(e)) => return Err(e.to_string()),
+            }
+        }
+    }
+}
+
+/// Backoff schedule for reconnecting after a dropped connection
+#[derive(Debug, Clone)]
+pub struct WsReconnectConfig {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub max_reconnect_attempts: u32,
+}
+
+impl Default for WsReconnectConfig {
+    fn default() -> Self {
+        WsReconnectConfig {
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            max_reconnect_attempts: DEFAULT_MAX_RECONNECT_ATTEMPTS,
+        }
+    }
+}
+
+impl WsReconnectConfig {
+    /// Delay before reconnection attempt `attempt` (1-based), doubling each time
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+type FailureCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Market data source streaming JSON price ticks over a WebSocket. Dropped
+/// connections are retried with exponential backoff, restoring every active
+/// subscription, until `max_reconnect_attempts` consecutive attempts fail.
+pub struct WebSocketDataSource {
+    name: String,
+    source_type: DataSourceType,
+    url: String,
+    transport: Arc<dyn WsTransport>,
+    reconnect: WsReconnectConfig,
+    state: Arc<Mutex<WsConnectionState>>, // Readable from the synchronous DataSource methods
+    subscriptions: Arc<Mutex<BTreeSet<String>>>,
+    event_sender: EventSender<MarketEvent>,
+    outgoing: Option<mpsc::UnboundedSender<String>>, // Messages for the connection task to send
+    on_failure: Vec<FailureCallback>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl WebSocketDataSource {
+    pub fn new(name: &str, source_type: DataSourceType, url: &str, event_sender: EventSender<MarketEvent>) -> Self {
+        Self::with_transport(name, source_type, url, event_sender, Arc::new(TungsteniteTransport))
+    }
+
+    pub fn with_transport(
+        name: &str,
+        source_type: DataSourceType,
+        url: &str,
+        event_sender: EventSender<MarketEvent>,
+        transport: Arc<dyn WsTransport>,
+    ) -> Self {
+        WebSocketDataSource {
+            name: name.to_string(),
+            source_type,
+            url: url.to_string(),
+            transport,
+            reconnect: WsReconnectConfig::default(),
+            state: Arc::new(Mutex::new(WsConnectionState::Disconnected)),
+            subscriptions: Arc::new(Mutex::new(BTreeSet::new())),
+            event_sender,
+            outgoing: None,
+            on_failure: Vec::new(),
+            task: None,
+        }
+    }
+
+    pub fn set_reconnect_config(&mut self, config: WsReconnectConfig) {
+        self.reconnect = config;
+    }
+
+    /// Register a callback fired with the source name once reconnection is abandoned
+    pub fn on_permanent_failure<F>(&mut self, callback: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_failure.push(Arc::new(callback));
+    }
+
+    pub fn state(&self) -> WsConnectionState {
+        self.state.lock().unwrap().clone()
+    }
+
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions.lock().unwrap().iter().cloned().collect()
+    }
+
+    fn subscription_message(action: &str, symbols: &[String]) -> String {
+        serde_json::json!({ "type": action, "symbols": symbols }).to_string()
+    }
+
+    /// Turn a feed message into a price update, or `None` for anything else
+    pub fn parse_message(&self, message: &str) -> Option<MarketEvent> {
+        parse_price_tick(&self.name, message)
+    }
+
+    fn stop_task(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+        self.outgoing = None;
+    }
+}
+
+// Everything the connection task needs, detached from the source
+struct ConnectionTask {
+    name: String,
+    url: String,
+    transport: Arc<dyn WsTransport>,
+    reconnect: WsReconnectConfig,
+    state: Arc<Mutex<WsConnectionState>>,
+    subscriptions: Arc<Mutex<BTreeSet<String>>>,
+    event_sender: EventSender<MarketEvent>,
+    on_failure: Vec<FailureCallback>,
+}
+
+fn parse_price_tick(source_name: &str, message: &str) -> Option<MarketEvent> {
+    let tick: Tick = serde_json::from_str(message).ok()?;
+    Some(tick.into_event(source_name, Utc::now()))
+}
+
+impl ConnectionTask {
+    fn set_state(&self, state: WsConnectionState) {
+        debug!("{} connection state: {:?}", self.name, state);
+        *self.state.lock().unwrap() = state;
+    }
+
+    async fn run(self, mut outgoing: mpsc::UnboundedReceiver<String>) {
+        let mut attempt = 0u32;
+        let mut has_connected = false;
+
+        loop {
+            let mut stream = match self.transport.connect(&self.url).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    attempt += 1;
+                    warn!("{} connection attempt {} failed: {}", self.name, attempt, e);
+                    if !self.wait_to_retry(attempt).await {
+                        return;
+                    }
+                    continue;
+                },
+            };
+
+            // Mark connected before restoring subscriptions, so symbols subscribed
+            // from here on are sent by the pump rather than missed
+            let session_id = Uuid::new_v4().to_string();
+            self.set_state(WsConnectionState::Connected { session_id: session_id.clone() });
+
+            // Requests queued for the old connection are covered by the restore
+            while outgoing.try_recv().is_ok() {}
+
+            // Restore every subscription on the new connection
+            let symbols: Vec<String> = self.subscriptions.lock().unwrap().iter().cloned().collect();
+            if !symbols.is_empty() {
+                if let Err(e) = stream.send(WebSocketDataSource::subscription_message("subscribe", &symbols)).await {
+                    attempt += 1;
+                    warn!("{} failed to restore subscriptions: {}", self.name, e);
+                    if !self.wait_to_retry(attempt).await {
+                        return;
+                    }
+                    continue;
+                }
+            }
+
+            info!("{} connected (session {}, {} subscriptions)", self.name, session_id, symbols.len());
+            attempt = 0;
+
+            if has_connected {
+                // Let strategies know there may be a gap in the data
+                let event = MarketEvent::SourceReconnected { source_name: self.name.clone() };
+                if self.event_sender.send(event).await.is_err() {
+                    warn!("{} could not report reconnection, event channel closed", self.name);
+                }
+            }
+            has_connected = true;
+
+            let error = self.pump(stream.as_mut(), &mut outgoing).await;
+            attempt += 1;
+            warn!("{} connection lost: {}", self.name, error);
+            if !self.wait_to_retry(attempt).await {
+                return;
+            }
+        }
+    }
+
+    // Relay messages both ways until the connection fails, returning the error
+    async fn pump(&self, stream: &mut dyn WsStream, outgoing: &mut mpsc::UnboundedReceiver<String>) -> String {
+        loop {
+            tokio::select! {
+                message = stream.next_message() => match message {
+                    Ok(text) => {
+                        if let Some(event) = parse_price_tick(&self.name, &text) {
+                            if self.event_sender.send(event).await.is_err() {
+                                return "Event channel closed".to_string();
+                            }
+                        } else {
+                            debug!("{} ignoring message: {}", self.name, text);
+                        }
+                    },
+                    Err(e) => return e,
+                },
+                Some(message) = outgoing.recv() => {
+                    if let Err(e) = stream.send(message).await {
+                        return e;
+                    }
+                },
+            }
+        }
+    }
+
+    // Back off before the next attempt, or give up for good. Returns false once
+    // the attempts are exhausted.
+    async fn wait_to_retry(&self, attempt: u32) -> bool {
+        if attempt > self.reconnect.max_reconnect_attempts {
+            error!("{} failed to reconnect after {} attempts, giving up", self.name, attempt - 1);
+            self.set_state(WsConnectionState::PermanentlyFailed);
+            for callback in &self.on_failure {
+                callback(&self.name);
+            }
+            return false;
+        }
+
+        let delay = self.reconnect.backoff(attempt);
+        self.set_state(WsConnectionState::Reconnecting {
+            attempt,
+            next_retry: Instant::now() + delay,
+        });
+        tokio::time::sleep(delay).await;
+        true
+    }
+}
+
+#[async_trait]
+impl DataSource for WebSocketDataSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn source_type(&self) -> &DataSourceType {
+        &self.source_type
+    }
+
+    /// Start the connection task; the connection itself is established in
+    /// the background
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        if self.task.is_some() {
+            return Err(TradingError::Conflict(format!("{} is already connecting or connected", self.name)));
+        }
+
+        let (outgoing_tx, outgoing_rx) = mpsc::unbounded_channel();
+        let task = ConnectionTask {
+            name: self.name.clone(),
+            url: self.url.clone(),
+            transport: self.transport.clone(),
+            reconnect: self.reconnect.clone(),
+            state: self.state.clone(),
+            subscriptions: self.subscriptions.clone(),
+            event_sender: self.event_sender.clone(),
+            on_failure: self.on_failure.clone(),
+        };
+
+        info!("Connecting {} to {}", self.name, self.url);
+        *self.state.lock().unwrap() = WsConnectionState::Connecting;
+        self.outgoing = Some(outgoing_tx);
+        self.task = Some(tokio::spawn(task.run(outgoing_rx)));
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> Result<(), String> {
+        self.stop_task();
+        *self.state.lock().unwrap() = WsConnectionState::Disconnected;
+        info!("Disconnected {}", self.name);
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        matches!(*self.state.lock().unwrap(), WsConnectionState::Connected { .. })
+    }
+
+    async fn subscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        self.subscriptions.lock().unwrap().extend(symbols.iter().cloned());
+
+        // Sent now if connected; otherwise restored when the connection comes up
+        if self.is_connected() {
+            if let Some(outgoing) = &self.outgoing {
+                outgoing.send(Self::subscription_message("subscribe", symbols))
+                    .map_err(|_| format!("{} connection task has stopped", self.name))?;
+            }
+        }
+        Ok(())
+    }
+
+    // The connection task restores the subscription set on every connect, so
+    // the symbols only need recording
+    async fn resubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        self.subscriptions.lock().unwrap().extend(symbols.iter().cloned());
+        Ok(())
+    }
+
+    async fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        {
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            for symbol in symbols {
+                subscriptions.remove(symbol);
+            }
+        }
+
+        if self.is_connected() {
+            if let Some(outgoing) = &self.outgoing {
+                outgoing.send(Self::subscription_message("unsubscribe", symbols))
+                    .map_err(|_| format!("{} connection task has stopped", self.name))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for WebSocketDataSource {
+    fn drop(&mut self) {
+        self.stop_task();
+    }
+}
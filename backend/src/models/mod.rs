@@ -0,0 +1,8 @@
+// Portfolio-level models built on top of positions and market data
+pub mod portfolio;
+pub mod price;
+pub mod symbol;
+
+pub use portfolio::{CorrelationEntry, CorrelationMatrix, PortfolioManager};
+pub use price::{Price, DEFAULT_PRICE_SCALE};
+pub use symbol::SymbolNormalizer;
@@ -0,0 +1,336 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+use tracing::warn;
+use utoipa::ToSchema;
+
+use crate::exchange::Position;
+use crate::market_data::{split_symbol, FxRateProvider};
+use crate::position::MAX_RETURN_HISTORY;
+use crate::risk::MIN_VAR_OBSERVATIONS;
+
+/// z-score of the one-sided 95% confidence level
+pub const VAR_95_Z_SCORE: f64 = 1.645;
+
+/// Default time between recorded prices: one a day, so returns are daily
+pub const DEFAULT_PRICE_SAMPLE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Correlation used for pairs with neither a configured nor an estimated value.
+/// Assuming perfect correlation never understates risk.
+pub const DEFAULT_CORRELATION: f64 = 1.0;
+
+/// Pairwise correlations keyed by symbol pair, with the symbols in sorted order
+pub type CorrelationMatrix = HashMap<(String, String), f64>;
+
+/// One entry of the correlation matrix
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CorrelationEntry {
+    pub symbol_a: String,
+    pub symbol_b: String,
+    pub correlation: f64,
+}
+
+// Portfolio Manager measures risk across all open positions together, so that
+// correlated holdings count for more than the sum of their per-asset limits
+pub struct PortfolioManager {
+    positions: Arc<RwLock<HashMap<String, Position>>>,
+    correlations: Arc<RwLock<CorrelationMatrix>>, // Configured values, preferred over estimates
+    volatilities: Arc<RwLock<HashMap<String, f64>>>, // Configured daily volatilities
+    price_history: Arc<RwLock<HashMap<String, VecDeque<f64>>>>, // Prices per symbol, one per sample interval
+    sample_interval: std::sync::RwLock<Duration>,
+    fx: std::sync::RwLock<Option<(Arc<dyn FxRateProvider>, String)>>, // Rates into the base currency, if any
+}
+
+impl Default for PortfolioManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PortfolioManager {
+    pub fn new() -> Self {
+        PortfolioManager {
+            positions: Arc::new(RwLock::new(HashMap::new())),
+            correlations: Arc::new(RwLock::new(HashMap::new())),
+            volatilities: Arc::new(RwLock::new(HashMap::new())),
+            price_history: Arc::new(RwLock::new(HashMap::new())),
+            sample_interval: std::sync::RwLock::new(DEFAULT_PRICE_SAMPLE_INTERVAL),
+            fx: std::sync::RwLock::new(None),
+        }
+    }
+
+    /// Weight positions quoted in other currencies by their value in
+    /// `base_currency`. Without a provider, quote currencies are taken as is.
+    pub fn set_fx_provider(&self, provider: Arc<dyn FxRateProvider>, base_currency: &str) {
+        *self.fx.write().unwrap() = Some((provider, base_currency.to_string()));
+    }
+
+    pub async fn update_position(&self, position: Position) {
+        let mut positions = self.positions.write().await;
+        if position.quantity == 0.0 {
+            positions.remove(&position.symbol);
+        } else {
+            positions.insert(position.symbol.clone(), position);
+        }
+    }
+
+    /// Replace every held position, e.g. with a snapshot from the position manager
+    pub async fn set_positions(&self, new_positions: Vec<Position>) {
+        let mut positions = self.positions.write().await;
+        positions.clear();
+        for position in new_positions.into_iter().filter(|p| p.quantity != 0.0) {
+            positions.insert(position.symbol.clone(), position);
+        }
+    }
+
+    pub async fn get_positions(&self) -> Vec<Position> {
+        let positions = self.positions.read().await;
+        positions.values().cloned().collect()
+    }
+
+    /// Time between the prices `record_price` is given, daily by default.
+    /// Volatility estimates are scaled from it to daily volatility.
+    pub fn set_sample_interval(&self, interval: Duration) -> Result<(), String> {
+        if interval.is_zero() {
+            return Err("Price sample interval must be positive".to_string());
+        }
+        *self.sample_interval.write().unwrap() = interval;
+        Ok(())
+    }
+
+    pub fn sample_interval(&self) -> Duration {
+        *self.sample_interval.read().unwrap()
+    }
+
+    /// Record the price of `symbol` at the end of one sample interval.
+    /// Volatilities and correlations are estimated from these, so all symbols
+    /// should be recorded at the same times.
+    pub async fn record_price(&self, symbol: &str, price: f64) {
+        let mut history = self.price_history.write().await;
+        let prices = history.entry(symbol.to_string()).or_default();
+        prices.push_back(price);
+        while prices.len() > MAX_RETURN_HISTORY + 1 {
+            prices.pop_front();
+        }
+    }
+
+    pub async fn last_price(&self, symbol: &str) -> Option<f64> {
+        let history = self.price_history.read().await;
+        history.get(symbol).and_then(|prices| prices.back().copied())
+    }
+
+    /// Set the daily return volatility of `symbol`, overriding the estimate
+    pub async fn set_volatility(&self, symbol: &str, volatility: f64) -> Result<(), String> {
+        if !volatility.is_finite() || volatility < 0.0 {
+            return Err(format!("Volatility for {} must be non-negative, got {}", symbol, volatility));
+        }
+
+        let mut volatilities = self.volatilities.write().await;
+        volatilities.insert(symbol.to_string(), volatility);
+        Ok(())
+    }
+
+    /// Daily return volatility of `symbol`: the configured value, or the sample
+    /// standard deviation of its recorded returns scaled to a day
+    pub async fn volatility(&self, symbol: &str) -> Option<f64> {
+        if let Some(volatility) = self.volatilities.read().await.get(symbol) {
+            return Some(*volatility);
+        }
+
+        let history = self.price_history.read().await;
+        let samples_per_day = DEFAULT_PRICE_SAMPLE_INTERVAL.as_secs_f64() / self.sample_interval().as_secs_f64();
+        estimate_volatility(&returns(history.get(symbol)?)).map(|volatility| volatility * samples_per_day.sqrt())
+    }
+
+    pub async fn set_correlation(&self, symbol_a: &str, symbol_b: &str, correlation: f64) -> Result<(), String> {
+        self.set_correlations(vec![CorrelationEntry {
+            symbol_a: symbol_a.to_string(),
+            symbol_b: symbol_b.to_string(),
+            correlation,
+        }]).await
+    }
+
+    /// Configure several correlations at once. Nothing is applied if any entry is invalid.
+    pub async fn set_correlations(&self, entries: Vec<CorrelationEntry>) -> Result<(), String> {
+        for entry in &entries {
+            if entry.symbol_a.is_empty() || entry.symbol_b.is_empty() {
+                return Err("Correlation symbols cannot be empty".to_string());
+            }
+            if entry.symbol_a == entry.symbol_b {
+                return Err(format!("Cannot set the correlation of {} with itself", entry.symbol_a));
+            }
+            if !(-1.0..=1.0).contains(&entry.correlation) {
+                return Err(format!(
+                    "Correlation between {} and {} must be between -1 and 1, got {}",
+                    entry.symbol_a, entry.symbol_b, entry.correlation
+                ));
+            }
+        }
+
+        let mut correlations = self.correlations.write().await;
+        for entry in entries {
+            correlations.insert(pair_key(&entry.symbol_a, &entry.symbol_b), entry.correlation);
+        }
+        Ok(())
+    }
+
+    /// Configured correlations, sorted by symbol pair
+    pub async fn get_correlations(&self) -> Vec<CorrelationEntry> {
+        let correlations = self.correlations.read().await;
+        let mut entries: Vec<CorrelationEntry> = correlations.iter()
+            .map(|((a, b), correlation)| CorrelationEntry {
+                symbol_a: a.clone(),
+                symbol_b: b.clone(),
+                correlation: *correlation,
+            })
+            .collect();
+        entries.sort_by(|x, y| (&x.symbol_a, &x.symbol_b).cmp(&(&y.symbol_a, &y.symbol_b)));
+        entries
+    }
+
+    /// Correlation between the daily returns of two symbols: the configured
+    /// value, else an estimate from recorded prices, else `DEFAULT_CORRELATION`
+    pub async fn correlation(&self, symbol_a: &str, symbol_b: &str) -> f64 {
+        if symbol_a == symbol_b {
+            return 1.0;
+        }
+        if let Some(correlation) = self.correlations.read().await.get(&pair_key(symbol_a, symbol_b)) {
+            return *correlation;
+        }
+
+        let history = self.price_history.read().await;
+        match (history.get(symbol_a), history.get(symbol_b)) {
+            (Some(a), Some(b)) => estimate_correlation(&returns(a), &returns(b)).unwrap_or(DEFAULT_CORRELATION),
+            _ => DEFAULT_CORRELATION,
+        }
+    }
+
+    /// 95% one-day Value at Risk of the held positions, in base currency:
+    /// `sqrt(wᵀΣw) * 1.645`, where `w` holds the signed market value of each
+    /// position and `Σ` is the covariance matrix of daily returns. Positions
+    /// whose volatility or exchange rate is unknown are left out.
+    pub async fn total_var_exposure(&self) -> f64 {
+        let exposures = self.exposures().await;
+        self.var_of(&exposures).await
+    }
+
+    /// Portfolio VaR as `total_var_exposure` would report it after trading
+    /// `signed_quantity` of `symbol` at `price` (negative quantities sell)
+    pub async fn var_exposure_with_trade(&self, symbol: &str, signed_quantity: f64, price: f64) -> f64 {
+        let mut exposures = self.exposures().await;
+        if let Some(value) = self.to_base_currency(symbol, signed_quantity * price).await {
+            *exposures.entry(symbol.to_string()).or_insert(0.0) += value;
+        }
+        self.var_of(&exposures).await
+    }
+
+    // Signed market value held in each symbol, in base currency
+    async fn exposures(&self) -> HashMap<String, f64> {
+        let positions = self.get_positions().await;
+        let mut exposures = HashMap::new();
+        for position in positions {
+            if let Some(value) = self.to_base_currency(&position.symbol, position.quantity * position.current_price).await {
+                exposures.insert(position.symbol, value);
+            }
+        }
+        exposures
+    }
+
+    // Convert an amount in the quote currency of `symbol` into base currency
+    async fn to_base_currency(&self, symbol: &str, amount: f64) -> Option<f64> {
+        let fx = self.fx.read().unwrap().clone();
+        let ((provider, base), (_, quote)) = match (fx, split_symbol(symbol)) {
+            (Some(fx), Some(pair)) => (fx, pair),
+            _ => return Some(amount),
+        };
+
+        match provider.get_rate(quote, &base).await {
+            Ok(rate) => Some(amount * rate),
+            Err(e) => {
+                warn!("Leaving {} out of portfolio VaR: {}", symbol, e);
+                None
+            },
+        }
+    }
+
+    async fn var_of(&self, exposures: &HashMap<String, f64>) -> f64 {
+        // Sorted so the result does not depend on map order
+        let mut weighted = Vec::new();
+        let mut symbols: Vec<&String> = exposures.keys().collect();
+        symbols.sort();
+        for symbol in symbols {
+            let exposure = exposures[symbol];
+            if exposure == 0.0 {
+                continue;
+            }
+            match self.volatility(symbol).await {
+                Some(volatility) => weighted.push((symbol.as_str(), exposure * volatility)),
+                None => warn!("No volatility for {}, leaving it out of portfolio VaR", symbol),
+            }
+        }
+
+        let mut variance = 0.0;
+        for (i, (symbol_i, risk_i)) in weighted.iter().enumerate() {
+            variance += risk_i * risk_i;
+            for (symbol_j, risk_j) in &weighted[i + 1..] {
+                variance += 2.0 * risk_i * risk_j * self.correlation(symbol_i, symbol_j).await;
+            }
+        }
+
+        variance.max(0.0).sqrt() * VAR_95_Z_SCORE
+    }
+}
+
+fn pair_key(symbol_a: &str, symbol_b: &str) -> (String, String) {
+    if symbol_a <= symbol_b {
+        (symbol_a.to_string(), symbol_b.to_string())
+    } else {
+        (symbol_b.to_string(), symbol_a.to_string())
+    }
+}
+
+fn returns(prices: &VecDeque<f64>) -> Vec<f64> {
+    prices.iter()
+        .zip(prices.iter().skip(1))
+        .filter(|(previous, _)| **previous != 0.0)
+        .map(|(previous, current)| current / previous - 1.0)
+        .collect()
+}
+
+fn estimate_volatility(returns: &[f64]) -> Option<f64> {
+    if returns.len() < MIN_VAR_OBSERVATIONS {
+        return None;
+    }
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    Some(variance.sqrt())
+}
+
+// Pearson correlation over the most recent returns the two series share
+fn estimate_correlation(a: &[f64], b: &[f64]) -> Option<f64> {
+    let n = a.len().min(b.len());
+    if n < MIN_VAR_OBSERVATIONS {
+        return None;
+    }
+    let a = &a[a.len() - n..];
+    let b = &b[b.len() - n..];
+
+    let mean_a = a.iter().sum::<f64>() / n as f64;
+    let mean_b = b.iter().sum::<f64>() / n as f64;
+    let mut covariance = 0.0;
+    let mut variance_a = 0.0;
+    let mut variance_b = 0.0;
+    for (x, y) in a.iter().zip(b) {
+        covariance += (x - mean_a) * (y - mean_b);
+        variance_a += (x - mean_a).powi(2);
+        variance_b += (y - mean_b).powi(2);
+    }
+
+    if variance_a == 0.0 || variance_b == 0.0 {
+        return None;
+    }
+    Some((covariance / (variance_a * variance_b).sqrt()).clamp(-1.0, 1.0))
+}
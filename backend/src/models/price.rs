@@ -0,0 +1,235 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Schema as JsonSchemaObject, SchemaObject};
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+/// Decimal places kept when a price is converted from an `f64` without a tick size
+pub const DEFAULT_PRICE_SCALE: u32 = 8;
+
+/// A price held as a decimal, so sums and differences of prices are exact
+/// where `f64` would pick up rounding error. Serialized as a decimal string;
+/// JSON numbers are also accepted when deserializing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Price(Decimal);
+
+impl Price {
+    pub const ZERO: Price = Price(Decimal::ZERO);
+
+    pub fn new(value: Decimal) -> Self {
+        Price(value)
+    }
+
+    /// Convert `value`, rounded to the nearest multiple of `tick_size` when one
+    /// is given, or else to `DEFAULT_PRICE_SCALE` decimal places
+    pub fn from_f64_with_tick(value: f64, tick_size: Option<Price>) -> Self {
+        match tick_size {
+            Some(tick_size) => Price::from(value).round_to_tick(tick_size),
+            None => Price::from(value),
+        }
+    }
+
+    pub fn value(&self) -> Decimal {
+        self.0
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0.to_f64().unwrap_or_default()
+    }
+
+    /// The nearest multiple of `tick_size`, halves rounding away from zero.
+    /// A tick size that is not positive leaves the price as it is.
+    pub fn round_to_tick(self, tick_size: Price) -> Self {
+        if tick_size.0 <= Decimal::ZERO {
+            return self;
+        }
+        let ticks = (self.0 / tick_size.0).round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero);
+        Price((ticks * tick_size.0).normalize())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn is_sign_positive(&self) -> bool {
+        self.0 > Decimal::ZERO
+    }
+}
+
+/// Rounds to `DEFAULT_PRICE_SCALE` decimal places. NaN converts to zero and
+/// values beyond the decimal range saturate.
+impl From<f64> for Price {
+    fn from(value: f64) -> Self {
+        let decimal = match Decimal::from_f64(value) {
+            Some(decimal) => decimal.round_dp(DEFAULT_PRICE_SCALE).normalize(),
+            None if value.is_nan() => Decimal::ZERO,
+            None if value > 0.0 => Decimal::MAX,
+            None => Decimal::MIN,
+        };
+        Price(decimal)
+    }
+}
+
+impl From<Decimal> for Price {
+    fn from(value: Decimal) -> Self {
+        Price(value)
+    }
+}
+
+impl From<Price> for f64 {
+    fn from(price: Price) -> Self {
+        price.to_f64()
+    }
+}
+
+impl Add for Price {
+    type Output = Price;
+
+    fn add(self, rhs: Price) -> Price {
+        Price(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Price {
+    fn add_assign(&mut self, rhs: Price) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Price {
+    type Output = Price;
+
+    fn sub(self, rhs: Price) -> Price {
+        Price(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Price {
+    fn sub_assign(&mut self, rhs: Price) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Price {
+    type Output = Price;
+
+    fn neg(self) -> Price {
+        Price(-self.0)
+    }
+}
+
+impl Mul<Decimal> for Price {
+    type Output = Price;
+
+    fn mul(self, rhs: Decimal) -> Price {
+        Price(self.0 * rhs)
+    }
+}
+
+/// Panics when dividing by zero, as `Decimal` does
+impl Div<Decimal> for Price {
+    type Output = Price;
+
+    fn div(self, rhs: Decimal) -> Price {
+        Price(self.0 / rhs)
+    }
+}
+
+/// The ratio of two prices. Panics when dividing by zero, as `Decimal` does.
+impl Div for Price {
+    type Output = Decimal;
+
+    fn div(self, rhs: Price) -> Decimal {
+        self.0 / rhs.0
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0.normalize(), f)
+    }
+}
+
+impl FromStr for Price {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(s.trim())
+            .map(Price)
+            .map_err(|e| format!("Invalid price {}: {}", s, e))
+    }
+}
+
+impl Serialize for Price {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Price {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(PriceVisitor)
+    }
+}
+
+struct PriceVisitor;
+
+impl de::Visitor<'_> for PriceVisitor {
+    type Value = Price;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a decimal string or a number")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Price, E> {
+        value.parse().map_err(de::Error::custom)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Price, E> {
+        if !value.is_finite() {
+            return Err(de::Error::custom(format!("Invalid price {}", value)));
+        }
+        Ok(Price::from(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Price, E> {
+        Ok(Price(Decimal::from(value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Price, E> {
+        Ok(Price(Decimal::from(value)))
+    }
+}
+
+impl PartialSchema for Price {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::Custom("decimal".to_string())))
+            .description(Some("Decimal price, e.g. \"35000.25\""))
+            .into()
+    }
+}
+
+impl ToSchema for Price {}
+
+impl JsonSchema for Price {
+    fn schema_name() -> String {
+        "Price".to_string()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> JsonSchemaObject {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some("decimal".to_string()),
+            ..Default::default()
+        }.into()
+    }
+}
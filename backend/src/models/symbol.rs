@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+// Symbols of one exchange, in both directions
+#[derive(Debug, Default)]
+struct ExchangeSymbols {
+    by_canonical: HashMap<String, String>, // Canonical symbol -> the exchange's own
+    by_exchange: HashMap<String, String>, // The exchange's own symbol -> canonical
+}
+
+/// Translates between the platform's canonical symbols, such as `BTC/USD`, and
+/// the formats each exchange uses, such as `BTCUSDT` or `BTC-USD`. Symbols with
+/// no mapping are the same on both sides. Lookups take a std lock, so the
+/// normalizer can be shared through an `Arc` and used from synchronous code.
+#[derive(Debug, Default)]
+pub struct SymbolNormalizer {
+    exchanges: RwLock<HashMap<String, ExchangeSymbols>>,
+}
+
+impl SymbolNormalizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `canonical` to `exchange_specific` on `exchange`, replacing any
+    /// mapping either symbol had there
+    pub fn register_mapping(&self, exchange: &str, canonical: &str, exchange_specific: &str) {
+        let mut exchanges = self.exchanges.write().unwrap();
+        let symbols = exchanges.entry(exchange.to_string()).or_default();
+        if let Some(previous) = symbols.by_canonical.insert(canonical.to_string(), exchange_specific.to_string()) {
+            symbols.by_exchange.remove(&previous);
+        }
+        if let Some(previous) = symbols.by_exchange.insert(exchange_specific.to_string(), canonical.to_string()) {
+            if previous != canonical {
+                symbols.by_canonical.remove(&previous);
+            }
+        }
+    }
+
+    /// Register every mapping in a comma-separated list of
+    /// `canonical=exchange_specific` pairs, as in `BTC/USD=BTCUSDT,ETH/USD=ETHUSDT`.
+    /// Nothing is registered if any pair is malformed.
+    pub fn register_mappings(&self, exchange: &str, mappings: &str) -> Result<(), String> {
+        let pairs = mappings.split(',')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair.split_once('=') {
+                Some((canonical, specific)) if !canonical.trim().is_empty() && !specific.trim().is_empty() => {
+                    Ok((canonical.trim(), specific.trim()))
+                },
+                _ => Err(format!("Invalid symbol mapping '{}' for {}, expected canonical=exchange_symbol", pair, exchange)),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        for (canonical, specific) in pairs {
+            self.register_mapping(exchange, canonical, specific);
+        }
+        Ok(())
+    }
+
+    /// The canonical form of a symbol as `exchange` writes it, the symbol
+    /// itself when unmapped; `None` for an empty symbol
+    pub fn to_canonical(&self, exchange: &str, symbol: &str) -> Option<String> {
+        if symbol.is_empty() {
+            return None;
+        }
+        let exchanges = self.exchanges.read().unwrap();
+        let mapped = exchanges.get(exchange).and_then(|symbols| symbols.by_exchange.get(symbol));
+        Some(mapped.map_or(symbol, String::as_str).to_string())
+    }
+
+    /// How `exchange` writes a canonical symbol, the symbol itself when
+    /// unmapped; `None` for an empty symbol
+    pub fn from_canonical(&self, exchange: &str, canonical: &str) -> Option<String> {
+        if canonical.is_empty() {
+            return None;
+        }
+        let exchanges = self.exchanges.read().unwrap();
+        let mapped = exchanges.get(exchange).and_then(|symbols| symbols.by_canonical.get(canonical));
+        Some(mapped.map_or(canonical, String::as_str).to_string())
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tracing::{info, warn, error};
+use utoipa::ToSchema;
+
+/// How long a webhook may take to answer before delivery counts as failed
+pub const DEFAULT_WEBHOOK_TIMEOUT_MS: u64 = 5000;
+
+/// Severity of a notification, from least to most urgent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationLevel {
+    Info,
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Notification {
+    pub level: NotificationLevel,
+    pub title: String,
+    pub body: String,
+    pub timestamp: DateTime<Utc>,
+    pub metadata: HashMap<String, String>,
+}
+
+impl Notification {
+    pub fn new(level: NotificationLevel, title: &str, body: &str) -> Self {
+        Notification {
+            level,
+            title: title.to_string(),
+            body: body.to_string(),
+            timestamp: Utc::now(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    pub fn with_metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+/// A channel notifications are delivered through
+#[async_trait]
+pub trait NotificationHandler: Send + Sync {
+    fn name(&self) -> &str;
+    async fn send(&self, notification: Notification) -> Result<(), String>;
+}
+
+// Notification Manager fans notifications out to every registered handler
+pub struct NotificationManager {
+    handlers: Vec<Box<dyn NotificationHandler>>,
+    min_level: NotificationLevel, // Notifications below this level are dropped
+}
+
+impl Default for NotificationManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationManager {
+    pub fn new() -> Self {
+        NotificationManager {
+            handlers: Vec::new(),
+            min_level: NotificationLevel::Info,
+        }
+    }
+
+    pub fn register_handler(&mut self, handler: Box<dyn NotificationHandler>) {
+        info!("Registering notification handler: {}", handler.name());
+        self.handlers.push(handler);
+    }
+
+    pub fn handler_names(&self) -> Vec<String> {
+        self.handlers.iter().map(|h| h.name().to_string()).collect()
+    }
+
+    /// Only deliver notifications at or above `level`
+    pub fn set_min_level(&mut self, level: NotificationLevel) {
+        self.min_level = level;
+    }
+
+    pub fn min_level(&self) -> NotificationLevel {
+        self.min_level
+    }
+
+    /// Deliver `notification` to every handler, returning each handler's
+    /// result in registration order. Failures are also logged. Nothing is
+    /// sent, and the result is empty, when the level is below the threshold.
+    pub async fn notify(&self, notification: Notification) -> Vec<Result<(), String>> {
+        if notification.level < self.min_level {
+            return Vec::new();
+        }
+
+        let mut results = Vec::with_capacity(self.handlers.len());
+        for handler in &self.handlers {
+            let result = handler.send(notification.clone()).await;
+            if let Err(e) = &result {
+                warn!("Notification handler {} failed to send \"{}\": {}", handler.name(), notification.title, e);
+            }
+            results.push(result);
+        }
+        results
+    }
+
+    /// Deliver `notification` on a background task so slow channels don't hold
+    /// up the caller. Does nothing outside a tokio runtime.
+    pub fn notify_in_background(self: &Arc<Self>, notification: Notification) {
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                let manager = self.clone();
+                runtime.spawn(async move {
+                    manager.notify(notification).await;
+                });
+            },
+            Err(_) => warn!("No runtime to deliver notification \"{}\"", notification.title),
+        }
+    }
+}
+
+/// Writes notifications to the log at a level matching their severity
+pub struct LogNotificationHandler;
+
+impl Default for LogNotificationHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogNotificationHandler {
+    pub fn new() -> Self {
+        LogNotificationHandler
+    }
+}
+
+#[async_trait]
+impl NotificationHandler for LogNotificationHandler {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    async fn send(&self, notification: Notification) -> Result<(), String> {
+        match notification.level {
+            NotificationLevel::Info => info!("{}: {} {:?}", notification.title, notification.body, notification.metadata),
+            NotificationLevel::Warning => warn!("{}: {} {:?}", notification.title, notification.body, notification.metadata),
+            NotificationLevel::Critical => error!("{}: {} {:?}", notification.title, notification.body, notification.metadata),
+        }
+        Ok(())
+    }
+}
+
+/// POSTs each notification as JSON to a fixed URL
+pub struct WebhookNotificationHandler {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookNotificationHandler {
+    pub fn new(url: &str) -> Self {
+        Self::with_timeout(url, Duration::from_millis(DEFAULT_WEBHOOK_TIMEOUT_MS))
+    }
+
+    pub fn with_timeout(url: &str, timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+
+        WebhookNotificationHandler {
+            url: url.to_string(),
+            client,
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+#[async_trait]
+impl NotificationHandler for WebhookNotificationHandler {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    async fn send(&self, notification: Notification) -> Result<(), String> {
+        let response = self.client.post(&self.url)
+            .json(&notification)
+            .send()
+            .await
+            .map_err(|e| format!("Webhook request to {} failed: {}", self.url, e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Webhook {} returned {}", self.url, response.status()));
+        }
+        Ok(())
+    }
+}
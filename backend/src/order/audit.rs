@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use tracing::{info, error};
+use uuid::Uuid;
+
+use super::OrderStatus;
+
+/// A single recorded order status transition
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub order_id: Uuid,
+    pub from_status: Option<OrderStatus>, // None when the order is first created
+    pub to_status: OrderStatus,
+    pub reason: String,
+}
+
+// Interface for audit trail storage backends. Stores are append-only:
+// entries can be added and read back, never modified or removed.
+#[async_trait]
+pub trait AuditStore: Send + Sync {
+    async fn append(&self, entry: AuditEntry) -> Result<(), String>;
+    async fn entries_for_order(&self, order_id: Uuid) -> Result<Vec<AuditEntry>, String>;
+}
+
+/// Audit store that keeps entries in memory for the lifetime of the process
+#[derive(Default)]
+pub struct InMemoryAuditStore {
+    entries: RwLock<HashMap<Uuid, Vec<AuditEntry>>>,
+}
+
+impl InMemoryAuditStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AuditStore for InMemoryAuditStore {
+    async fn append(&self, entry: AuditEntry) -> Result<(), String> {
+        let mut entries = self.entries.write().await;
+        entries.entry(entry.order_id).or_default().push(entry);
+        Ok(())
+    }
+
+    async fn entries_for_order(&self, order_id: Uuid) -> Result<Vec<AuditEntry>, String> {
+        let entries = self.entries.read().await;
+        Ok(entries.get(&order_id).cloned().unwrap_or_default())
+    }
+}
+
+/// Records order status transitions to the configured store
+#[derive(Clone)]
+pub struct AuditLog {
+    store: Arc<dyn AuditStore>,
+}
+
+impl AuditLog {
+    pub fn new(store: Arc<dyn AuditStore>) -> Self {
+        AuditLog { store }
+    }
+
+    /// Append a transition. Failures are logged rather than propagated so that
+    /// a misbehaving store never blocks order processing.
+    pub async fn record(&self, order_id: Uuid, from_status: Option<OrderStatus>, to_status: OrderStatus, reason: &str) {
+        info!("Order {} transition: {:?} -> {:?} ({})", order_id, from_status, to_status, reason);
+
+        let entry = AuditEntry {
+            timestamp: Utc::now(),
+            order_id,
+            from_status,
+            to_status,
+            reason: reason.to_string(),
+        };
+
+        if let Err(e) = self.store.append(entry).await {
+            error!("Failed to record audit entry for order {}: {}", order_id, e);
+        }
+    }
+
+    pub async fn get_trail(&self, order_id: Uuid) -> Vec<AuditEntry> {
+        match self.store.entries_for_order(order_id).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                error!("Failed to read audit trail for order {}: {}", order_id, e);
+                Vec::new()
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Produces monotonically increasing client order IDs of the form `PREFIX-000000000001`.
+/// The counter is zero-padded so the IDs also sort lexicographically, which some
+/// exchanges require. Safe to share between concurrent `place_order` calls.
+#[derive(Debug)]
+pub struct ClientOrderIdGenerator {
+    prefix: String,
+    counter: AtomicU64,
+}
+
+impl ClientOrderIdGenerator {
+    pub fn new(prefix: &str) -> Self {
+        Self::starting_at(prefix, 1)
+    }
+
+    /// Start the sequence at `first`, e.g. to continue past IDs used earlier
+    pub fn starting_at(prefix: &str, first: u64) -> Self {
+        ClientOrderIdGenerator {
+            prefix: prefix.to_string(),
+            counter: AtomicU64::new(first),
+        }
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn next_id(&self) -> String {
+        let sequence = self.counter.fetch_add(1, Ordering::SeqCst);
+        format!("{}-{:012}", self.prefix, sequence)
+    }
+}
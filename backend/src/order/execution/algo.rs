@@ -0,0 +1,123 @@
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::order::{Order, OrderStatus};
+use super::twap::{MAX_TWAP_SLICES, TWAP_CHILD_TAG};
+
+/// Tag carried by every child order of a VWAP execution
+pub const VWAP_CHILD_TAG: &str = "vwap-child";
+
+/// How `OrderManager::place_algo_order` works a parent order through child orders
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecAlgo {
+    /// `slices` equal children, one every `interval`, the first straight away
+    Twap { slices: usize, interval: Duration },
+    /// One child per `interval` bucket of a historical volume profile, the
+    /// first straight away, each sized by its bucket's share of the total
+    /// volume. Buckets without volume are skipped.
+    Vwap { volume_profile: Vec<f64>, interval: Duration },
+}
+
+/// A child order and when to submit it, relative to the start of the execution
+#[derive(Debug, Clone)]
+pub struct ScheduledChild {
+    pub offset: Duration,
+    pub order: Order,
+}
+
+impl ExecAlgo {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExecAlgo::Twap { .. } => "TWAP",
+            ExecAlgo::Vwap { .. } => "VWAP",
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let (buckets, interval) = match self {
+            ExecAlgo::Twap { slices, interval } => (*slices, interval),
+            ExecAlgo::Vwap { volume_profile, interval } => {
+                if volume_profile.iter().any(|volume| !volume.is_finite() || *volume < 0.0) {
+                    return Err("VWAP volume profile must be non-negative".to_string());
+                }
+                if volume_profile.iter().sum::<f64>() <= 0.0 {
+                    return Err("VWAP volume profile has no volume".to_string());
+                }
+                (volume_profile.len(), interval)
+            },
+        };
+
+        if buckets == 0 || buckets > MAX_TWAP_SLICES {
+            return Err(format!("{} slices must be between 1 and {}", self.name(), MAX_TWAP_SLICES));
+        }
+        if interval.is_zero() && buckets > 1 {
+            return Err(format!("{} interval must be positive", self.name()));
+        }
+        Ok(())
+    }
+
+    /// Share of the parent quantity in each time bucket, in schedule order
+    pub fn slice_weights(&self) -> Vec<f64> {
+        match self {
+            ExecAlgo::Twap { slices, .. } => vec![1.0 / *slices as f64; *slices],
+            ExecAlgo::Vwap { volume_profile, .. } => {
+                let total: f64 = volume_profile.iter().sum();
+                volume_profile.iter().map(|volume| volume / total).collect()
+            },
+        }
+    }
+
+    /// Child orders for `parent`, in submission order. Each takes its bucket's
+    /// share of the quantity, the parent's strategy and tags, and the
+    /// algorithm's child tag. Call `validate` first.
+    pub fn schedule(&self, parent: &Order, parent_id: Uuid) -> Vec<ScheduledChild> {
+        let (interval, tag) = match self {
+            ExecAlgo::Twap { interval, .. } => (*interval, TWAP_CHILD_TAG),
+            ExecAlgo::Vwap { interval, .. } => (*interval, VWAP_CHILD_TAG),
+        };
+        let mut tags = parent.tags.clone();
+        if !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag.to_string());
+        }
+
+        let weights = self.slice_weights();
+        let buckets = weights.len();
+        weights.into_iter().enumerate()
+            .filter(|(_, weight)| *weight > 0.0)
+            .map(|(index, weight)| ScheduledChild {
+                offset: interval * index as u32,
+                order: Order {
+                    id: Uuid::new_v4(),
+                    client_order_id: format!("{}-{}", parent.client_order_id, index + 1),
+                    quantity: parent.quantity * weight,
+                    filled_quantity: 0.0,
+                    status: OrderStatus::Created,
+                    filled_at: None,
+                    average_fill_price: None,
+                    unfilled_quantity: None,
+                    notes: Some(format!("{} slice {}/{} of {}", self.name(), index + 1, buckets, parent_id)),
+                    tags: tags.clone(),
+                    amendment_history: Vec::new(),
+                    ..parent.clone()
+                },
+            })
+            .collect()
+    }
+}
+
+/// State of a parent order being worked by an execution algorithm
+#[derive(Debug, Clone)]
+pub struct AlgoExecution {
+    pub parent_id: Uuid,
+    pub algo: ExecAlgo,
+    pub child_ids: Vec<Uuid>, // Every scheduled child, in schedule order
+    pub submitted: Vec<Uuid>, // Children released to the router so far
+    pub cancelled: bool, // Set when the parent is cancelled; no further children go out
+}
+
+impl AlgoExecution {
+    /// Whether no more children will be submitted
+    pub fn is_schedule_complete(&self) -> bool {
+        self.cancelled || self.submitted.len() == self.child_ids.len()
+    }
+}
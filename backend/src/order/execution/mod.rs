@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::strategy::TradeDirection;
+
+pub mod algo;
+pub mod twap;
+
+/// A single fill reported for an order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Execution {
+    pub order_id: Uuid,
+    pub fill_qty: f64,
+    pub fill_price: f64,
+    pub timestamp: DateTime<Utc>,
+    pub exchange: String,
+}
+
+/// A fill with the details of its order, as a row of the trade journal
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalEntry {
+    pub order_id: Uuid,
+    pub symbol: String,
+    pub side: TradeDirection,
+    pub quantity: f64,
+    pub price: f64,
+    pub fee: Option<f64>, // None until exchanges report fees per fill
+    pub timestamp: DateTime<Utc>,
+}
+
+impl JournalEntry {
+    /// First line of the CSV journal
+    pub const CSV_HEADER: &'static str = "order_id,symbol,side,quantity,price,fee,timestamp\n";
+    
+    /// The entry as a CSV line, with an empty fee when none was reported
+    pub fn to_csv_row(&self) -> String {
+        format!("{},{},{},{},{},{},{}\n",
+            self.order_id,
+            csv_field(&self.symbol),
+            self.side.to_string().to_lowercase(),
+            self.quantity,
+            self.price,
+            self.fee.map(|fee| fee.to_string()).unwrap_or_default(),
+            self.timestamp.to_rfc3339())
+    }
+}
+
+// Quote a CSV field that contains a separator, quote or line break
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Price of the latest fill, recovered from the cumulative average fill price
+/// before and after it. Falls back to `new_avg` when there was no earlier
+/// average to difference against.
+pub(crate) fn marginal_fill_price(prev_qty: f64, prev_avg: Option<f64>, new_qty: f64, new_avg: f64) -> f64 {
+    let fill_qty = new_qty - prev_qty;
+    match prev_avg {
+        Some(prev_avg) if prev_qty > 0.0 && fill_qty > 0.0 => (new_avg * new_qty - prev_avg * prev_qty) / fill_qty,
+        _ => new_avg,
+    }
+}
@@ -0,0 +1,161 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use schemars::JsonSchema;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::order::{Order, OrderManager};
+use super::algo::ExecAlgo;
+
+/// Tag carried by every child order of a TWAP execution
+pub const TWAP_CHILD_TAG: &str = "twap-child";
+
+/// Most slices a single TWAP execution may be split into
+pub const MAX_TWAP_SLICES: usize = 1000;
+
+/// How far a TWAP execution has got
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct TwapProgress {
+    pub total_slices: usize,
+    pub slices_submitted: usize,
+    pub slices_failed: usize,
+    pub total_quantity: f64,
+    pub quantity_submitted: f64,
+    pub errors: Vec<String>, // Why each failed slice was refused
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>, // Set once every slice has been attempted
+}
+
+impl TwapProgress {
+    fn new(total_slices: usize, total_quantity: f64) -> Self {
+        TwapProgress {
+            total_slices,
+            slices_submitted: 0,
+            slices_failed: 0,
+            total_quantity,
+            quantity_submitted: 0.0,
+            errors: Vec::new(),
+            started_at: Utc::now(),
+            completed_at: None,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.completed_at.is_some()
+    }
+}
+
+/// Handle on a running TWAP execution. The child ids are assigned up front, so
+/// they are known before the children are submitted; `progress` is updated as
+/// each slice goes out.
+#[derive(Debug, Clone)]
+pub struct TwapExecution {
+    pub parent_id: Uuid,
+    pub child_ids: Vec<Uuid>,
+    pub progress: Arc<Mutex<TwapProgress>>,
+}
+
+impl TwapExecution {
+    pub fn progress_snapshot(&self) -> TwapProgress {
+        self.progress.lock().unwrap().clone()
+    }
+}
+
+/// Splits a parent order into equal child orders submitted at evenly spaced
+/// intervals over `duration`, the first straight away, to limit market impact.
+/// The parent order itself is never submitted.
+#[derive(Debug, Clone)]
+pub struct TwapExecutor {
+    duration: Duration,
+    num_slices: usize,
+}
+
+impl TwapExecutor {
+    pub fn new(duration: Duration, num_slices: usize) -> Result<Self, String> {
+        if num_slices == 0 || num_slices > MAX_TWAP_SLICES {
+            return Err(format!("TWAP slices must be between 1 and {}", MAX_TWAP_SLICES));
+        }
+        if duration.is_zero() {
+            return Err("TWAP duration must be positive".to_string());
+        }
+        Ok(TwapExecutor { duration, num_slices })
+    }
+
+    /// Time between child order submissions
+    pub fn slice_interval(&self) -> Duration {
+        self.duration / self.num_slices as u32
+    }
+
+    /// The schedule this executor works through, as an execution algorithm
+    pub fn algo(&self) -> ExecAlgo {
+        ExecAlgo::Twap { slices: self.num_slices, interval: self.slice_interval() }
+    }
+
+    /// Start executing `order` in the background and return straight away. The
+    /// execution is registered with the order manager so it can be looked up by
+    /// the parent id.
+    pub async fn execute(&self, order: Order, order_manager: Arc<RwLock<OrderManager>>) -> Result<TwapExecution, String> {
+        if order.quantity <= 0.0 {
+            return Err("Quantity must be positive".to_string());
+        }
+
+        let parent_id = if order.id == Uuid::nil() { Uuid::new_v4() } else { order.id };
+        let children: Vec<Order> = self.algo().schedule(&order, parent_id).into_iter()
+            .map(|child| child.order)
+            .collect();
+        let execution = TwapExecution {
+            parent_id,
+            child_ids: children.iter().map(|child| child.id).collect(),
+            progress: Arc::new(Mutex::new(TwapProgress::new(self.num_slices, order.quantity))),
+        };
+        order_manager.read().await.track_twap_execution(execution.clone()).await;
+
+        info!("Starting TWAP {} for {} {}: {} slices every {:?}", parent_id, order.quantity, order.symbol, self.num_slices, self.slice_interval());
+        let progress = execution.progress.clone();
+        let interval = self.slice_interval();
+        tokio::spawn(async move {
+            submit_slices(parent_id, children, interval, order_manager, progress).await;
+        });
+
+        Ok(execution)
+    }
+}
+
+// Submit one child per tick. A refused child is recorded and the rest still go out.
+async fn submit_slices(
+    parent_id: Uuid,
+    children: Vec<Order>,
+    interval: Duration,
+    order_manager: Arc<RwLock<OrderManager>>,
+    progress: Arc<Mutex<TwapProgress>>,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    let total = children.len();
+
+    for (index, child) in children.into_iter().enumerate() {
+        ticker.tick().await;
+        let quantity = child.quantity;
+        let result = order_manager.read().await.place_order(child).await;
+
+        let mut progress = progress.lock().unwrap();
+        match result {
+            Ok(_) => {
+                progress.slices_submitted += 1;
+                progress.quantity_submitted += quantity;
+            },
+            Err(e) => {
+                warn!("TWAP {} slice {}/{} was refused: {}", parent_id, index + 1, total, e);
+                progress.slices_failed += 1;
+                progress.errors.push(format!("Slice {}: {}", index + 1, e));
+            },
+        }
+    }
+
+    let mut progress = progress.lock().unwrap();
+    progress.completed_at = Some(Utc::now());
+    info!("TWAP {} complete: {} of {} slices submitted", parent_id, progress.slices_submitted, total);
+}
@@ -1,21 +1,54 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use std::time::Duration;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use tokio::sync::{broadcast, RwLock};
 use uuid::Uuid;
-use tracing::{info, warn, error};
+use tracing::{debug, info, info_span, warn, error, Instrument, Span};
 use chrono::{DateTime, Utc};
+use proptest_derive::Arbitrary;
+use schemars::JsonSchema;
+use utoipa::ToSchema;
 
-use crate::strategy::{TradeDirection, TimeInForce};
+use crate::strategy::{PrioritizedSignal, TradeDirection, TradeSignal, TimeInForce};
+use crate::clock::{Clock, SystemClock};
+use crate::channel::{event_channel, BackpressurePolicy, ChannelConfig, ChannelStats, EventReceiver, EventSender};
+use crate::error::TradingError;
+use crate::exchange::rejection_reason;
+use crate::position::PositionManager;
+use crate::risk::{CircuitBreaker, CircuitBreakerStatus};
+use crate::models::{PortfolioManager, Price, SymbolNormalizer};
+use crate::notifications::{Notification, NotificationLevel, NotificationManager};
+use crate::market_data::{MarketDataFxProvider, OrderBooks, PriceConverter, TradeSide, split_symbol, DEFAULT_BASE_CURRENCY};
+use crate::utils::text::{name_key, to_snake_case};
 
 mod router;
+mod audit;
+mod statistics;
+mod client_id;
+mod execution;
+mod rules;
+mod webhook;
 // Comment out missing modules
-// mod execution;
 // mod risk_check;
 
-pub use router::OrderRouter;
+pub use router::{OrderRouter, RoutingPolicy, poll_until_terminal, STATUS_POLL_INTERVAL};
+pub use audit::{AuditEntry, AuditLog, AuditStore, InMemoryAuditStore};
+pub use statistics::{OrderHistoryFilter, OrderStatistics};
+pub use client_id::ClientOrderIdGenerator;
+pub use execution::{Execution, JournalEntry};
+pub use rules::SymbolTradingRules;
+use webhook::OrderWebhook;
+pub use webhook::{OrderWebhookPayload, WebhookConfig, WebhookEventKind, WebhookStats, DEFAULT_WEBHOOK_MAX_ATTEMPTS, DEFAULT_WEBHOOK_QUEUE_CAPACITY};
+pub use execution::algo::{AlgoExecution, ExecAlgo, ScheduledChild, VWAP_CHILD_TAG};
+pub use execution::twap::{TwapExecution, TwapExecutor, TwapProgress, MAX_TWAP_SLICES, TWAP_CHILD_TAG};
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Arbitrary, ToSchema, JsonSchema)]
+#[schema(rename_all = "snake_case")]
+#[schemars(rename_all = "snake_case")]
 pub enum OrderStatus {
     Created,
     PendingSubmission,
@@ -28,14 +61,30 @@ pub enum OrderStatus {
 }
 
 impl OrderStatus {
+    /// Number of statuses an order can be in
+    pub const VARIANT_COUNT: usize = 8;
+    
+    /// Every status, in lifecycle order
+    pub const ALL: [OrderStatus; Self::VARIANT_COUNT] = [
+        OrderStatus::Created,
+        OrderStatus::PendingSubmission,
+        OrderStatus::Submitted,
+        OrderStatus::PartiallyFilled,
+        OrderStatus::Filled,
+        OrderStatus::Cancelled,
+        OrderStatus::Rejected,
+        OrderStatus::Failed,
+    ];
+    
     /// Check if the current state can transition to the given state
-    #[allow(dead_code)]
     pub fn can_transition_to(&self, next: &OrderStatus) -> bool {
         use OrderStatus::*;
         
         match (self, next) {
             // Valid transitions
             (Created, PendingSubmission) => true,
+            (Created, Submitted) => true, // Algo parents start working without being sent
+            (Created, Cancelled) => true, // Cancelled before it was routed
             (PendingSubmission, Submitted) => true,
             (PendingSubmission, Rejected) => true,
             (PendingSubmission, Failed) => true,
@@ -55,9 +104,74 @@ impl OrderStatus {
             _ => false,
         }
     }
+    
+    /// Whether the order can no longer change
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, OrderStatus::Filled | OrderStatus::Cancelled | OrderStatus::Rejected | OrderStatus::Failed)
+    }
+    
+    /// Whether the order is still working, the opposite of `is_terminal`
+    pub fn is_active(&self) -> bool {
+        matches!(self, OrderStatus::Created | OrderStatus::PendingSubmission | OrderStatus::Submitted | OrderStatus::PartiallyFilled)
+    }
+    
+    /// Whether the order is resting at an exchange and can still fill
+    pub fn is_fillable(&self) -> bool {
+        matches!(self, OrderStatus::Submitted | OrderStatus::PartiallyFilled)
+    }
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OrderStatus::Created => "Created",
+            OrderStatus::PendingSubmission => "PendingSubmission",
+            OrderStatus::Submitted => "Submitted",
+            OrderStatus::PartiallyFilled => "PartiallyFilled",
+            OrderStatus::Filled => "Filled",
+            OrderStatus::Cancelled => "Cancelled",
+            OrderStatus::Rejected => "Rejected",
+            OrderStatus::Failed => "Failed",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for OrderStatus {
+    type Err = String;
+
+    /// Accepts the `Display` name in any case, with or without underscores
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name_key(name).as_str() {
+            "created" => Ok(OrderStatus::Created),
+            "pendingsubmission" => Ok(OrderStatus::PendingSubmission),
+            "submitted" => Ok(OrderStatus::Submitted),
+            "partiallyfilled" => Ok(OrderStatus::PartiallyFilled),
+            "filled" => Ok(OrderStatus::Filled),
+            "cancelled" | "canceled" => Ok(OrderStatus::Cancelled),
+            "rejected" => Ok(OrderStatus::Rejected),
+            "failed" => Ok(OrderStatus::Failed),
+            _ => Err(format!("Unknown order status: {}", name)),
+        }
+    }
+}
+
+// Serialized as the snake_case form of the `Display` name, e.g. `partially_filled`
+impl Serialize for OrderStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_snake_case(&self.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, ToSchema, JsonSchema)]
+#[schema(rename_all = "snake_case")]
+#[schemars(rename_all = "snake_case")]
 pub enum OrderType {
     Market,
     Limit,
@@ -66,6 +180,64 @@ pub enum OrderType {
     TrailingStop,
 }
 
+impl OrderType {
+    /// Every order type, as supported by exchanges that do not restrict them
+    pub const ALL: [OrderType; 5] = [
+        OrderType::Market,
+        OrderType::Limit,
+        OrderType::StopLoss,
+        OrderType::StopLimit,
+        OrderType::TrailingStop,
+    ];
+    
+    /// Whether orders of this type are triggered by a stop price
+    pub fn is_stop(&self) -> bool {
+        matches!(self, OrderType::StopLoss | OrderType::StopLimit | OrderType::TrailingStop)
+    }
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OrderType::Market => "Market",
+            OrderType::Limit => "Limit",
+            OrderType::StopLoss => "StopLoss",
+            OrderType::StopLimit => "StopLimit",
+            OrderType::TrailingStop => "TrailingStop",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for OrderType {
+    type Err = String;
+
+    /// Accepts the `Display` name in any case, with or without underscores,
+    /// and `stop` for `StopLoss`
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name_key(name).as_str() {
+            "market" => Ok(OrderType::Market),
+            "limit" => Ok(OrderType::Limit),
+            "stop" | "stoploss" => Ok(OrderType::StopLoss),
+            "stoplimit" => Ok(OrderType::StopLimit),
+            "trailingstop" => Ok(OrderType::TrailingStop),
+            _ => Err(format!("Unknown order type: {}", name)),
+        }
+    }
+}
+
+impl Serialize for OrderType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_snake_case(&self.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Order {
     pub id: Uuid,
@@ -75,22 +247,135 @@ pub struct Order {
     pub order_type: OrderType,
     pub quantity: f64,
     pub filled_quantity: f64,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Price>,
+    pub stop_price: Option<Price>,
     pub time_in_force: TimeInForce,
     pub status: OrderStatus,
     pub exchange: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub filled_at: Option<DateTime<Utc>>,
-    pub average_fill_price: Option<f64>,
+    pub average_fill_price: Option<Price>,
+    pub unfilled_quantity: Option<f64>, // Quantity left unfilled when the order was cancelled
     pub strategy_id: Option<String>,
     pub notes: Option<String>,
+    pub tags: Vec<String>, // Free-form labels for filtering, e.g. "hedging"
+    pub post_only: bool, // Maker only: rejected rather than filled as a taker if it would cross the spread
+    pub amendment_history: Vec<OrderAmendment>, // Every change to the order after it was placed, oldest first
+}
+
+/// One change to an order after it was placed: a status transition or an
+/// amendment such as a reduced quantity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct OrderAmendment {
+    pub timestamp: DateTime<Utc>,
+    pub field: String,
+    pub old_value: serde_json::Value,
+    pub new_value: serde_json::Value,
+    pub reason: String,
+}
+
+impl Order {
+    /// Whether the order can no longer change
+    pub fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+    
+    /// Whether the order is still working
+    pub fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
+    
+    /// Whether the order is resting at an exchange and can still fill
+    pub fn is_fillable(&self) -> bool {
+        self.status.is_fillable()
+    }
+    
+    /// Move the order to `next` and stamp `updated_at`, if its status allows
+    /// it, recording the change with `reason` in the amendment history. Moving
+    /// to the status it already has is a no-op. A refused transition is logged
+    /// and leaves the order untouched. Returns whether the order is now in
+    /// `next`.
+    pub fn transition_to(&mut self, next: OrderStatus, reason: &str) -> bool {
+        if self.status == next {
+            return true;
+        }
+        if !self.status.can_transition_to(&next) {
+            warn!("Ignoring invalid status transition {} -> {} for order {}", self.status, next, self.id);
+            return false;
+        }
+        let previous = std::mem::replace(&mut self.status, next);
+        self.updated_at = Utc::now();
+        let next = self.status.clone();
+        self.record_amendment("status", previous, next, reason);
+        true
+    }
+    
+    /// Append a change to `field` to the amendment history, stamped with the
+    /// order's `updated_at`
+    pub fn record_amendment(&mut self, field: &str, old_value: impl Serialize, new_value: impl Serialize, reason: &str) {
+        self.amendment_history.push(OrderAmendment {
+            timestamp: self.updated_at,
+            field: field.to_string(),
+            old_value: serde_json::to_value(old_value).unwrap_or_default(),
+            new_value: serde_json::to_value(new_value).unwrap_or_default(),
+            reason: reason.to_string(),
+        });
+    }
+    
+    /// An order carrying out a strategy's signal, routed to the symbol's
+    /// primary exchange. Its type follows from the prices the signal sets.
+    pub fn from_signal(signal: &TradeSignal, strategy_id: &str) -> Self {
+        let order_type = match (signal.limit_price, signal.stop_price) {
+            (Some(_), Some(_)) => OrderType::StopLimit,
+            (Some(_), None) => OrderType::Limit,
+            (None, Some(_)) => OrderType::StopLoss,
+            (None, None) => OrderType::Market,
+        };
+        let now = Utc::now();
+        
+        Order {
+            id: Uuid::new_v4(),
+            client_order_id: format!("SIG-{}", Uuid::new_v4().as_simple()),
+            symbol: signal.asset.clone(),
+            direction: signal.direction,
+            order_type,
+            quantity: signal.quantity,
+            filled_quantity: 0.0,
+            price: signal.limit_price.map(Price::from),
+            stop_price: signal.stop_price.map(Price::from),
+            time_in_force: signal.time_in_force,
+            status: OrderStatus::Created,
+            exchange: String::new(),
+            created_at: now,
+            updated_at: now,
+            filled_at: None,
+            average_fill_price: None,
+            unfilled_quantity: None,
+            strategy_id: Some(strategy_id.to_string()),
+            notes: None,
+            tags: Vec::new(),
+            post_only: false,
+            amendment_history: Vec::new(),
+        }
+    }
+    
+    /// Whether the order would trade against the book as quoted rather than
+    /// rest on it: a buy at or above the ask, or a sell at or below the bid.
+    /// Orders without a limit price always take liquidity.
+    pub fn crosses_spread(&self, bid: Price, ask: Price) -> bool {
+        match (self.price, self.direction) {
+            (None, _) => true,
+            (Some(price), TradeDirection::Buy) => price >= ask,
+            (Some(price), TradeDirection::Sell) => price <= bid,
+        }
+    }
 }
 
 #[allow(dead_code)]
+#[derive(Debug, Clone)]
 pub enum OrderEvent {
-    New(Order),
+    New(Box<Order>),
     Update {
         order_id: Uuid,
         status: Option<OrderStatus>,
@@ -111,36 +396,142 @@ pub enum OrderEvent {
     },
 }
 
+impl OrderEvent {
+    /// The order the event is about, if any
+    pub fn order_id(&self) -> Option<Uuid> {
+        match self {
+            OrderEvent::New(order) => Some(order.id),
+            OrderEvent::Update { order_id, .. } | OrderEvent::Cancel { order_id, .. } | OrderEvent::Reject { order_id, .. } => Some(*order_id),
+            OrderEvent::Error { order_id, .. } => *order_id,
+        }
+    }
+}
+
+/// Order events buffered by default before the backpressure policy applies
+pub const DEFAULT_ORDER_EVENT_CAPACITY: usize = 100;
+
+/// Pause between orders placed from a batch of signals by default
+pub const DEFAULT_SIGNAL_SUBMISSION_DELAY: Duration = Duration::from_millis(5);
+
+/// Processed order events a subscriber may fall behind by before it starts
+/// missing them
+pub const ORDER_EVENT_BROADCAST_CAPACITY: usize = 1024;
+
 // Order Manager handles the lifecycle of orders
 #[allow(dead_code)]
 pub struct OrderManager {
     orders: Arc<RwLock<HashMap<Uuid, Order>>>,
     active_orders: Arc<RwLock<HashMap<Uuid, Order>>>,
+    executions: Arc<RwLock<HashMap<Uuid, Vec<Execution>>>>, // Fills per order, oldest first
+    tag_index: Arc<RwLock<HashMap<String, HashSet<Uuid>>>>, // Non-terminal orders carrying each tag
+    twap_executions: RwLock<HashMap<Uuid, TwapExecution>>, // By parent order id
+    algo_executions: Arc<RwLock<HashMap<Uuid, AlgoExecution>>>, // By parent order id
+    algo_parents: Arc<RwLock<HashMap<Uuid, Uuid>>>, // Child order id to its algo parent
     order_router: OrderRouter,
-    event_sender: mpsc::Sender<OrderEvent>,
-    event_receiver: Option<mpsc::Receiver<OrderEvent>>,
+    audit_log: AuditLog,
+    position_manager: Arc<PositionManager>,
+    portfolio_manager: Arc<PortfolioManager>,
+    max_portfolio_var: Option<f64>, // Orders may not push 95% one-day portfolio VaR above this
+    max_total_exposure: Option<f64>, // Cap on gross position value, in base currency
+    max_slippage_bps: Option<f64>, // Cap on the estimated market impact of an order
+    order_books: Option<OrderBooks>, // Books the market impact of orders is estimated against
+    price_converter: Option<PriceConverter>, // Without one, every quote currency counts as base currency
+    base_currency: String,
+    notification_manager: Option<Arc<NotificationManager>>, // Alerted on risk breaches, rejections and failures
+    circuit_breaker: Option<RwLock<CircuitBreaker>>, // Halts new orders on rapid intraday drawdown
+    client_id_generator: Option<Arc<ClientOrderIdGenerator>>, // Used for all orders unless a strategy has its own
+    strategy_client_id_generators: HashMap<String, Arc<ClientOrderIdGenerator>>,
+    allow_short: bool, // When false, sells are limited to the net long position
+    disabled_symbols: RwLock<BTreeSet<String>>, // Symbols halted for new orders
+    symbol_rules: HashMap<String, SymbolTradingRules>, // Desk size and open-order limits, by symbol
+    signal_submission_delay: Duration, // Pause between orders placed from signals
+    post_stop_cooldown: Duration, // Strategy entries held off after a stop fills; zero for none
+    stop_fills: Arc<RwLock<HashMap<String, DateTime<Utc>>>>, // When a stop order last filled, by symbol
+    clock: Arc<dyn Clock>, // Time for new orders, expiry and the circuit breaker
+    event_sender: EventSender<OrderEvent>,
+    event_receiver: Option<EventReceiver<OrderEvent>>,
+    event_broadcast: broadcast::Sender<OrderEvent>, // Every event, once processed, for subscribers
+    webhooks: RwLock<Vec<OrderWebhook>>, // Pushed matching events, fed from the broadcast
     shutdown_signal: Option<tokio::sync::oneshot::Sender<()>>,
 }
 
+impl Default for OrderManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl OrderManager {
     pub fn new() -> Self {
-        let (event_sender, event_receiver) = mpsc::channel(100);
+        Self::with_audit_store(Arc::new(InMemoryAuditStore::new()))
+    }
+    
+    /// Create an order manager that records status transitions to the given store
+    pub fn with_audit_store(audit_store: Arc<dyn AuditStore>) -> Self {
+        Self::with_audit_store_and_capacity(audit_store, DEFAULT_ORDER_EVENT_CAPACITY)
+    }
+    
+    /// Create an order manager whose event channel holds `capacity` events.
+    /// Senders wait while it is full: a dropped order event would be a lost
+    /// status update, so order events never use a dropping policy.
+    pub fn with_event_capacity(capacity: usize) -> Self {
+        Self::with_audit_store_and_capacity(Arc::new(InMemoryAuditStore::new()), capacity)
+    }
+    
+    fn with_audit_store_and_capacity(audit_store: Arc<dyn AuditStore>, capacity: usize) -> Self {
+        let (event_sender, event_receiver) = event_channel(ChannelConfig::new(capacity, BackpressurePolicy::Block));
         let orders = Arc::new(RwLock::new(HashMap::new()));
         let active_orders = Arc::new(RwLock::new(HashMap::new()));
         let order_router = OrderRouter::new();
+        let audit_log = AuditLog::new(audit_store);
         
         let mut manager = OrderManager {
             orders,
             active_orders,
+            executions: Arc::new(RwLock::new(HashMap::new())),
+            tag_index: Arc::new(RwLock::new(HashMap::new())),
+            twap_executions: RwLock::new(HashMap::new()),
+            algo_executions: Arc::new(RwLock::new(HashMap::new())),
+            algo_parents: Arc::new(RwLock::new(HashMap::new())),
             order_router,
+            audit_log,
+            position_manager: Arc::new(PositionManager::new()),
+            portfolio_manager: Arc::new(PortfolioManager::new()),
+            max_portfolio_var: None,
+            max_total_exposure: None,
+            max_slippage_bps: None,
+            order_books: None,
+            price_converter: None,
+            base_currency: DEFAULT_BASE_CURRENCY.to_string(),
+            notification_manager: None,
+            circuit_breaker: None,
+            client_id_generator: None,
+            strategy_client_id_generators: HashMap::new(),
+            allow_short: true,
+            disabled_symbols: RwLock::new(BTreeSet::new()),
+            symbol_rules: HashMap::new(),
+            signal_submission_delay: DEFAULT_SIGNAL_SUBMISSION_DELAY,
+            post_stop_cooldown: Duration::ZERO,
+            stop_fills: Arc::new(RwLock::new(HashMap::new())),
+            clock: Arc::new(SystemClock),
             event_sender,
             event_receiver: Some(event_receiver),
+            event_broadcast: broadcast::channel(ORDER_EVENT_BROADCAST_CAPACITY).0,
+            webhooks: RwLock::new(Vec::new()),
             shutdown_signal: None,
         };
         
         // Start event processing in a separate function
         let orders_clone = manager.orders.clone();
         let active_orders_clone = manager.active_orders.clone();
+        let executions_clone = manager.executions.clone();
+        let tag_index_clone = manager.tag_index.clone();
+        let audit_log_clone = manager.audit_log.clone();
+        let position_manager_clone = manager.position_manager.clone();
+        let stop_fills_clone = manager.stop_fills.clone();
+        let algo_executions_clone = manager.algo_executions.clone();
+        let algo_parents_clone = manager.algo_parents.clone();
+        let event_broadcast = manager.event_broadcast.clone();
         let mut event_receiver = manager.event_receiver.take().unwrap();
         
         tokio::spawn(async move {
@@ -148,9 +539,23 @@ impl OrderManager {
             
             loop {
                 tokio::select! {
-                    // Process new order events
-                    Some(event) = event_receiver.recv() => {
-                        Self::process_order_event(event, orders_clone.clone(), active_orders_clone.clone()).await;
+                    // Process new order events, traced as children of the span that sent them
+                    Some((event, sent_from)) = event_receiver.recv_with_span() => {
+                        let order_id = event.order_id();
+                        let published = (event_broadcast.receiver_count() > 0).then(|| event.clone());
+                        let span = info_span!(parent: &sent_from, "process_order_event", order_id = ?order_id);
+                        Self::process_order_event(event, orders_clone.clone(), active_orders_clone.clone(), &executions_clone, &tag_index_clone, &audit_log_clone, &position_manager_clone, &stop_fills_clone)
+                            .instrument(span)
+                            .await;
+                        if let Some(order_id) = order_id {
+                            Self::sync_algo_parent(order_id, &algo_parents_clone, &algo_executions_clone, &orders_clone, &active_orders_clone, &tag_index_clone, &audit_log_clone).await;
+                        }
+                        
+                        // Published once applied, so subscribers see the order in its new state.
+                        // Sending never waits: a subscriber that falls behind misses events instead.
+                        if let Some(event) = published {
+                            let _ = event_broadcast.send(event);
+                        }
                     }
                     
                     // Exit after 1 hour of inactivity (for tests)
@@ -167,121 +572,260 @@ impl OrderManager {
         manager
     }
     
-    pub async fn place_order(&self, mut order: Order) -> Result<Uuid, String> {
+    #[tracing::instrument(skip(self, order), fields(symbol = %order.symbol, qty = order.quantity, order_id = %order.id))]
+    pub async fn place_order(&self, order: Order) -> Result<Uuid, TradingError> {
+        let order = self.prepare_order(order).await?;
+        let order_id = order.id;
+        // Orders placed without an id are given one while being prepared
+        Span::current().record("order_id", tracing::field::display(order_id));
+        self.submitter().submit(order).await;
+        
+        Ok(order_id)
+    }
+    
+    // Assign ids and timestamps, then run the order past validation and risk checks
+    async fn prepare_order(&self, mut order: Order) -> Result<Order, TradingError> {
         // Generate a unique ID if not provided
         if order.id == Uuid::nil() {
             order.id = Uuid::new_v4();
         }
         
+        // Assign a sequential client order ID if a generator applies to this order
+        if let Some(generator) = self.client_id_generator_for(order.strategy_id.as_deref()) {
+            order.client_order_id = generator.next_id();
+        }
+        
         // Set created timestamp
-        order.created_at = Utc::now();
+        order.created_at = self.clock.now();
         order.updated_at = order.created_at;
         
-        // Update status
+        // Update status; history starts from placement
         order.status = OrderStatus::Created;
+        order.amendment_history.clear();
+        
+        // Nothing new goes out while the circuit breaker is tripped
+        self.check_circuit_breaker().await?;
+        self.check_symbol_enabled(&order.symbol).await?;
+        self.check_post_stop_cooldown(&order).await?;
         
         // Validate the order
-        self.validate_order(&order)?;
+        self.validate_order(&order).await?;
+        self.order_router.check_order_type(&order).await?;
+        self.check_post_only(&order).await?;
+        self.check_margin(&order).await?;
+        self.check_short_selling(&order).await?;
+        if let Err(e) = self.check_risk_limits(&order).await {
+            self.notify(Notification::new(NotificationLevel::Warning, "Order blocked by risk limit", &e.to_string())
+                .with_metadata("order_id", &order.id.to_string())
+                .with_metadata("symbol", &order.symbol));
+            return Err(e);
+        }
+        
+        Ok(order)
+    }
+    
+    /// Work a parent order through child orders on the algorithm's schedule.
+    /// The parent is checked against risk limits as a whole and is never sent
+    /// to an exchange itself; its filled quantity, average price and status
+    /// follow its children's fills. Cancelling the parent stops the schedule
+    /// and cancels the children still resting. Returns the parent's id.
+    pub async fn place_algo_order(&self, parent: Order, algo: ExecAlgo) -> Result<Uuid, TradingError> {
+        algo.validate().map_err(TradingError::Validation)?;
+        let parent = self.prepare_order(parent).await?;
+        let parent_id = parent.id;
+        let schedule = algo.schedule(&parent, parent_id);
+        
+        {
+            let mut algo_parents = self.algo_parents.write().await;
+            for child in &schedule {
+                algo_parents.insert(child.order.id, parent_id);
+            }
+        }
+        self.algo_executions.write().await.insert(parent_id, AlgoExecution {
+            parent_id,
+            algo: algo.clone(),
+            child_ids: schedule.iter().map(|child| child.order.id).collect(),
+            submitted: Vec::new(),
+            cancelled: false,
+        });
         
-        // Store the order
         {
             let mut orders = self.orders.write().await;
             let mut active_orders = self.active_orders.write().await;
-            
-            orders.insert(order.id, order.clone());
-            active_orders.insert(order.id, order.clone());
+            orders.insert(parent_id, parent.clone());
+            active_orders.insert(parent_id, parent.clone());
         }
+        self.audit_log.record(parent_id, None, OrderStatus::Created, "Order placed").await;
+        self.emit_event(OrderEvent::New(Box::new(parent.clone()))).await;
+        let reason = format!("{} execution started", algo.name());
+        Self::update_order_status_internal(self.orders.clone(), &self.audit_log, parent_id, OrderStatus::Submitted, &reason).await;
         
-        // Emit new order event
-        self.emit_event(OrderEvent::New(order.clone())).await;
+        info!("Starting {} {} for {} {}: {} children", algo.name(), parent_id, parent.quantity, parent.symbol, schedule.len());
+        tokio::spawn(Self::release_algo_children(self.submitter(), self.algo_executions.clone(), parent_id, schedule));
         
-        // Submit the order to the router for execution
-        let order_id = order.id;
-        tokio::spawn({
-            let order_router = self.order_router.clone();
-            let event_sender = self.event_sender.clone();
-            let orders = self.orders.clone();
-            let active_orders = self.active_orders.clone();
+        Ok(parent_id)
+    }
+    
+    pub async fn get_algo_execution(&self, parent_id: Uuid) -> Option<AlgoExecution> {
+        self.algo_executions.read().await.get(&parent_id).cloned()
+    }
+    
+    // Submit each child at its scheduled offset until the schedule is done or the parent is cancelled
+    async fn release_algo_children(
+        submitter: OrderSubmitter,
+        algo_executions: Arc<RwLock<HashMap<Uuid, AlgoExecution>>>,
+        parent_id: Uuid,
+        schedule: Vec<ScheduledChild>,
+    ) {
+        let start = tokio::time::Instant::now();
+        for child in schedule {
+            tokio::time::sleep_until(start + child.offset).await;
             
-            async move {
-                // Update order status to pending submission
-                Self::update_order_status_internal(orders.clone(), order_id, OrderStatus::PendingSubmission).await;
-                
-                // Submit to router
-                match order_router.submit_order(order.clone()).await {
-                    Ok(()) => {
-                        // Update status to submitted
-                        Self::update_order_status_internal(orders.clone(), order_id, OrderStatus::Submitted).await;
-                        
-                        // Emit update event
-                        let event = OrderEvent::Update {
-                            order_id,
-                            status: Some(OrderStatus::Submitted),
-                            filled_qty: None,
-                            avg_fill_price: None,
-                        };
-                        
-                        if let Err(e) = event_sender.send(event).await {
-                            error!("Failed to emit order update event: {}", e);
-                        }
-                    },
-                    Err(e) => {
-                        error!("Failed to submit order {}: {}", order_id, e);
-                        
-                        // Update status to failed
-                        Self::update_order_status_internal(orders.clone(), order_id, OrderStatus::Failed).await;
-                        
-                        // Remove from active orders
-                        {
-                            let mut active = active_orders.write().await;
-                            active.remove(&order_id);
-                        }
-                        
-                        // Emit error event
-                        let event = OrderEvent::Error {
-                            order_id: Some(order_id),
-                            message: e.to_string(),
-                        };
-                        
-                        if let Err(e) = event_sender.send(event).await {
-                            error!("Failed to emit order error event: {}", e);
-                        }
-                    }
+            // Held while the child is stored, so a cancellation either sees it or stops it
+            let mut algo_executions = algo_executions.write().await;
+            match algo_executions.get_mut(&parent_id) {
+                Some(execution) if !execution.cancelled => execution.submitted.push(child.order.id),
+                _ => {
+                    info!("Algo order {} cancelled, remaining children not submitted", parent_id);
+                    return;
                 }
             }
-        });
-        
-        Ok(order_id)
+            submitter.submit(child.order).await;
+        }
     }
     
-    #[allow(dead_code)]
-    pub async fn update_order_status(&self, order_id: Uuid, status: OrderStatus) {
-        Self::update_order_status_internal(self.orders.clone(), order_id, status).await;
+    fn submitter(&self) -> OrderSubmitter {
+        OrderSubmitter {
+            orders: self.orders.clone(),
+            active_orders: self.active_orders.clone(),
+            order_router: self.order_router.clone(),
+            event_sender: self.event_sender.clone(),
+            audit_log: self.audit_log.clone(),
+            notification_manager: self.notification_manager.clone(),
+        }
+    }
+    
+    /// Move an order to `status`, returning false when the order is unknown
+    /// or its current status cannot move there
+    pub async fn update_order_status(&self, order_id: Uuid, status: OrderStatus) -> bool {
+        Self::update_order_status_internal(self.orders.clone(), &self.audit_log, order_id, status, "Manual status update").await
+    }
+    
+    /// Place several orders concurrently. Each order succeeds or fails on its
+    /// own, and results come back in input order.
+    pub async fn place_orders(&self, orders: Vec<Order>) -> Vec<Result<Uuid, TradingError>> {
+        futures::future::join_all(orders.into_iter().map(|order| self.place_order(order))).await
+    }
+    
+    /// Place an order for each signal, highest priority first, pausing for the
+    /// signal submission delay between them so urgent signals are not held up
+    /// behind a large batch. Results come back in submission order.
+    pub async fn place_signals(&self, mut signals: BinaryHeap<PrioritizedSignal>) -> Vec<Result<Uuid, TradingError>> {
+        let mut results = Vec::with_capacity(signals.len());
+        while let Some(queued) = signals.pop() {
+            if !results.is_empty() && !self.signal_submission_delay.is_zero() {
+                tokio::time::sleep(self.signal_submission_delay).await;
+            }
+            
+            let result = self.place_order(Order::from_signal(&queued.signal, &queued.strategy)).await;
+            if let Err(e) = &result {
+                warn!("Could not place {} signal from {} (priority {}): {}", queued.signal.asset, queued.strategy, queued.priority(), e);
+            }
+            results.push(result);
+        }
+        results
+    }
+    
+    #[tracing::instrument(skip(self), fields(order_id = %order_id))]
+    pub async fn cancel_order(&self, order_id: Uuid, reason: String) -> Result<(), TradingError> {
+        if self.algo_executions.read().await.contains_key(&order_id) {
+            return self.cancel_algo_order(order_id, reason).await;
+        }
+        self.cancel_single_order(order_id, reason).await
+    }
+    
+    // Stop releasing children, cancel those still resting, then cancel the parent itself
+    async fn cancel_algo_order(&self, parent_id: Uuid, reason: String) -> Result<(), TradingError> {
+        let parent = self.get_order(parent_id).await
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found or not active", parent_id)))?;
+        if parent.is_terminal() {
+            return Err(TradingError::Conflict(format!("Order {} cannot be cancelled in status {}", parent_id, parent.status)));
+        }
+        
+        let released = {
+            let mut algo_executions = self.algo_executions.write().await;
+            match algo_executions.get_mut(&parent_id) {
+                Some(execution) => {
+                    execution.cancelled = true;
+                    execution.submitted.clone()
+                },
+                None => Vec::new(),
+            }
+        };
+        
+        for child_id in released {
+            let resting = self.get_order(child_id).await.is_some_and(|child| child.is_active());
+            if resting {
+                if let Err(e) = self.cancel_single_order(child_id, reason.clone()).await {
+                    warn!("Unable to cancel child {} of order {}: {}", child_id, parent_id, e);
+                }
+            }
+        }
+        
+        if let Some(parent) = self.orders.write().await.get_mut(&parent_id) {
+            parent.unfilled_quantity = Some(parent.quantity - parent.filled_quantity);
+        }
+        Self::update_order_status_internal(self.orders.clone(), &self.audit_log, parent_id, OrderStatus::Cancelled, &reason).await;
+        self.active_orders.write().await.remove(&parent_id);
+        self.emit_event(OrderEvent::Cancel { order_id: parent_id, reason }).await;
+        
+        Ok(())
     }
     
-    pub async fn cancel_order(&self, order_id: Uuid, reason: String) -> Result<(), String> {
-        // Check if order exists and is active
+    async fn cancel_single_order(&self, order_id: Uuid, reason: String) -> Result<(), TradingError> {
+        // Check if order exists and is active. The active map only tracks membership;
+        // the current status lives in the orders map.
         let order = {
             let active_orders = self.active_orders.read().await;
-            active_orders.get(&order_id).cloned()
+            if active_orders.contains_key(&order_id) {
+                let orders = self.orders.read().await;
+                orders.get(&order_id).cloned()
+            } else {
+                None
+            }
         };
         
         match order {
             Some(order) => {
-                // Only certain statuses can be cancelled
+                // Only orders not yet sent, or resting at an exchange, can be cancelled
                 match order.status {
-                    OrderStatus::Created | OrderStatus::Submitted | OrderStatus::PartiallyFilled => {
-                        // If the order is only Created (not yet sent to exchange), we can cancel locally
-                        if order.status == OrderStatus::Created {
-                            // Update status directly
-                            Self::update_order_status_internal(self.orders.clone(), order_id, OrderStatus::Cancelled).await;
+                    _ if order.status == OrderStatus::Created || order.is_fillable() => {
+                        // If the order is only Created (not yet sent to exchange), we can cancel locally.
+                        // Otherwise the exchange reports what was left unfilled.
+                        let exchange_cancellation = if order.status == OrderStatus::Created {
+                            None
                         } else {
                             // Submit cancel request to the router
-                            let router_result = self.order_router.cancel_order(order_id).await;
-                            // If router fails (e.g., no exchanges), still update status locally
-                            if router_result.is_err() {
-                                Self::update_order_status_internal(self.orders.clone(), order_id, OrderStatus::Cancelled).await;
+                            match self.order_router.cancel_order(order_id).await {
+                                Ok(result) => Some(result),
+                                Err(e) => {
+                                    // If router fails (e.g., no exchanges), still cancel locally
+                                    warn!("Cancelling order {} locally: {}", order_id, e);
+                                    None
+                                }
                             }
+                        };
+                        
+                        let unfilled_quantity = exchange_cancellation.as_ref()
+                            .map(|result| result.unfilled_quantity)
+                            .unwrap_or(order.quantity - order.filled_quantity);
+                        if let Some(order) = self.orders.write().await.get_mut(&order_id) {
+                            order.unfilled_quantity = Some(unfilled_quantity);
+                        }
+                        
+                        // Exchange cancellations complete when the cancel event is processed
+                        if exchange_cancellation.is_none() {
+                            Self::update_order_status_internal(self.orders.clone(), &self.audit_log, order_id, OrderStatus::Cancelled, &reason).await;
                         }
                         
                         // Remove from active orders
@@ -298,11 +842,83 @@ impl OrderManager {
                         
                         Ok(())
                     },
-                    _ => Err(format!("Order {} cannot be cancelled in status {:?}", order_id, order.status)),
+                    _ => Err(TradingError::Conflict(format!("Order {} cannot be cancelled in status {}", order_id, order.status))),
                 }
             },
-            None => Err(format!("Order {} not found or not active", order_id)),
+            // Orders that are no longer active have finished, so cancelling them clashes with their status
+            None => match self.orders.read().await.get(&order_id) {
+                Some(order) => Err(TradingError::Conflict(format!("Order {} cannot be cancelled in status {}", order_id, order.status))),
+                None => Err(TradingError::NotFound(format!("Order {} not found", order_id))),
+            },
+        }
+    }
+    
+    /// Shrink an order resting at an exchange to `new_quantity` rather than
+    /// cancel it. The new quantity must be below the current one and above what
+    /// has already filled. The exchange amends the order first; if it refuses,
+    /// the order is left as it was.
+    #[tracing::instrument(skip(self), fields(order_id = %order_id))]
+    pub async fn reduce_order(&self, order_id: Uuid, new_quantity: f64) -> Result<(), TradingError> {
+        let order = self.get_order(order_id).await
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))?;
+        if self.algo_executions.read().await.contains_key(&order_id) {
+            return Err(TradingError::Conflict(format!("Order {} is an algorithmic parent and cannot be reduced", order_id)));
+        }
+        // A cancelled order leaves the active set before its status catches up
+        let active = self.active_orders.read().await.contains_key(&order_id);
+        if !active || !order.is_fillable() {
+            return Err(TradingError::Conflict(format!("Order {} cannot be reduced in status {}", order_id, order.status)));
+        }
+        if !new_quantity.is_finite() || new_quantity <= order.filled_quantity || new_quantity >= order.quantity {
+            return Err(TradingError::Validation(format!(
+                "Reduced quantity {} must be above the {} already filled and below the current {}",
+                new_quantity, order.filled_quantity, order.quantity
+            )));
+        }
+        
+        self.order_router.amend_order(order_id, new_quantity).await?;
+        
+        info!("Reduced order {} from {} to {}", order_id, order.quantity, new_quantity);
+        let updated_at = Utc::now();
+        let reduce = |order: &mut Order| {
+            let previous = std::mem::replace(&mut order.quantity, new_quantity);
+            order.updated_at = updated_at;
+            order.record_amendment("quantity", previous, new_quantity, "Quantity reduced");
+        };
+        if let Some(order) = self.orders.write().await.get_mut(&order_id) {
+            reduce(order);
+        }
+        if let Some(active) = self.active_orders.write().await.get_mut(&order_id) {
+            reduce(active);
+        }
+        Ok(())
+    }
+    
+    /// Ask the owning exchange for the order's status and apply its report, for
+    /// when an update event was missed. The report is processed straight away
+    /// rather than queued, so the returned status is the one now stored.
+    pub async fn refresh_order_from_exchange(&self, order_id: Uuid) -> Result<OrderStatus, TradingError> {
+        if self.get_order(order_id).await.is_none() {
+            return Err(TradingError::NotFound(format!("Order {} not found", order_id)));
         }
+        
+        let response = self.order_router.get_order_status(order_id).await?;
+        info!("Refreshed order {} from exchange: status={:?}, filled={}", order_id, response.status, response.filled_quantity);
+        Self::process_order_event(
+            response.to_order_event(),
+            self.orders.clone(),
+            self.active_orders.clone(),
+            &self.executions,
+            &self.tag_index,
+            &self.audit_log,
+            &self.position_manager,
+            &self.stop_fills,
+        ).await;
+        Self::sync_algo_parent(order_id, &self.algo_parents, &self.algo_executions, &self.orders, &self.active_orders, &self.tag_index, &self.audit_log).await;
+        
+        self.get_order(order_id).await
+            .map(|order| order.status)
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))
     }
     
     pub async fn get_order(&self, order_id: Uuid) -> Option<Order> {
@@ -310,102 +926,825 @@ impl OrderManager {
         orders.get(&order_id).cloned()
     }
     
-    pub async fn get_active_orders(&self) -> Vec<Order> {
-        let active_orders = self.active_orders.read().await;
-        active_orders.values().cloned().collect()
+    /// Fills reported for an order, oldest first
+    pub async fn get_executions(&self, order_id: Uuid) -> Vec<Execution> {
+        let executions = self.executions.read().await;
+        executions.get(&order_id).cloned().unwrap_or_default()
     }
     
-    async fn emit_event(&self, event: OrderEvent) {
-        if let Err(e) = self.event_sender.send(event).await {
-            error!("Failed to emit order event: {}", e);
-        }
+    /// Every fill from `from` up to but excluding `to`, oldest first, with the
+    /// symbol and side of its order
+    pub async fn get_journal(&self, from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> Vec<JournalEntry> {
+        let orders = self.orders.read().await;
+        let executions = self.executions.read().await;
+        let mut entries: Vec<JournalEntry> = executions.iter()
+            .filter_map(|(order_id, fills)| orders.get(order_id).map(|order| (order, fills)))
+            .flat_map(|(order, fills)| fills.iter().map(move |fill| JournalEntry {
+                order_id: order.id,
+                symbol: order.symbol.clone(),
+                side: order.direction,
+                quantity: fill.fill_qty,
+                price: fill.fill_price,
+                fee: None,
+                timestamp: fill.timestamp,
+            }))
+            .filter(|entry| from.is_none_or(|from| entry.timestamp >= from) && to.is_none_or(|to| entry.timestamp < to))
+            .collect();
+        entries.sort_by_key(|entry| entry.timestamp);
+        entries
     }
     
-    fn validate_order(&self, order: &Order) -> Result<(), String> {
-        // Basic validation checks
-        if order.symbol.is_empty() {
-            return Err("Order symbol cannot be empty".to_string());
-        }
-        
-        if order.quantity <= 0.0 {
-            return Err("Order quantity must be positive".to_string());
-        }
-        
-        // Validate price for limit orders
-        if order.order_type == OrderType::Limit && order.price.is_none() {
-            return Err("Limit orders must specify a price".to_string());
-        }
+    /// Every order carrying `tag`, including finished ones, oldest first
+    pub async fn get_orders_by_tag(&self, tag: &str) -> Vec<Order> {
+        let orders = self.orders.read().await;
+        let mut tagged: Vec<Order> = orders.values()
+            .filter(|order| order.tags.iter().any(|t| t == tag))
+            .cloned()
+            .collect();
+        tagged.sort_by_key(|order| order.created_at);
+        tagged
+    }
+    
+    /// Ids of the orders carrying `tag` that have not reached a terminal status
+    pub async fn get_open_order_ids_by_tag(&self, tag: &str) -> Vec<Uuid> {
+        let tag_index = self.tag_index.read().await;
+        tag_index.get(tag).map(|ids| ids.iter().copied().collect()).unwrap_or_default()
+    }
+    
+    /// Orders placed by `strategy_id` that have not reached a terminal status, oldest first
+    pub async fn get_open_orders_by_strategy(&self, strategy_id: &str) -> Vec<Order> {
+        let orders = self.orders.read().await;
+        let mut open: Vec<Order> = orders.values()
+            .filter(|order| order.strategy_id.as_deref() == Some(strategy_id) && order.is_active())
+            .cloned()
+            .collect();
+        open.sort_by_key(|order| order.created_at);
+        open
+    }
+    
+    /// Cancel every active order placed by `strategy_id`. Algo children are
+    /// cancelled through their parent. Returns the ids cancelled, or every
+    /// failure if any order could not be cancelled.
+    pub async fn cancel_all_orders_for_strategy(&self, strategy_id: &str, reason: &str) -> Result<Vec<Uuid>, TradingError> {
+        let cancelled = self.cancel_active_orders(reason, |order| order.strategy_id.as_deref() == Some(strategy_id)).await?;
+        info!("Cancelled {} orders of strategy {}", cancelled.len(), strategy_id);
+        Ok(cancelled)
+    }
+    
+    /// Cancel every active order, as `cancel_all_orders_for_strategy` does for
+    /// one strategy's
+    pub async fn cancel_all_orders(&self, reason: &str) -> Result<Vec<Uuid>, TradingError> {
+        let cancelled = self.cancel_active_orders(reason, |_| true).await?;
+        info!("Cancelled {} active orders: {}", cancelled.len(), reason);
+        Ok(cancelled)
+    }
+    
+    // Cancel the active orders matching `filter`, leaving algo children to their parents
+    async fn cancel_active_orders(&self, reason: &str, filter: impl Fn(&Order) -> bool) -> Result<Vec<Uuid>, TradingError> {
+        let order_ids: Vec<Uuid> = {
+            let active_orders = self.active_orders.read().await;
+            let algo_parents = self.algo_parents.read().await;
+            active_orders.values()
+                .filter(|order| filter(order) && !algo_parents.contains_key(&order.id))
+                .map(|order| order.id)
+                .collect()
+        };
         
-        // Validate market orders shouldn't have a price
-        if order.order_type == OrderType::Market && order.price.is_some() {
-            return Err("Market orders should not specify a price".to_string());
+        let mut cancelled = Vec::new();
+        let mut errors = Vec::new();
+        for order_id in order_ids {
+            match self.cancel_order(order_id, reason.to_string()).await {
+                Ok(()) => cancelled.push(order_id),
+                Err(e) => errors.push(e),
+            }
         }
         
-        // Validate stop price for stop orders
-        if (order.order_type == OrderType::StopLoss || order.order_type == OrderType::StopLimit) 
-            && order.stop_price.is_none() {
-            return Err("Stop orders must specify a stop price".to_string());
+        match errors.len() {
+            0 => Ok(cancelled),
+            1 => Err(errors.remove(0)),
+            _ => {
+                let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                Err(TradingError::Conflict(errors.join("; ")))
+            },
         }
-        
-        // Additional validations could be added here
-        
-        Ok(())
     }
     
-    async fn process_order_event(
-        event: OrderEvent,
-        orders: Arc<RwLock<HashMap<Uuid, Order>>>,
-        active_orders: Arc<RwLock<HashMap<Uuid, Order>>>
-    ) {
-        match event {
-            OrderEvent::Update { order_id, status, filled_qty, avg_fill_price } => {
-                info!("Processing order update event for order {}: status={:?}, filled={:?}, avg_price={:?}", 
-                      order_id, status, filled_qty, avg_fill_price);
-                
-                // Update the order status
-                let mut orders_lock = orders.write().await;
-                if let Some(order) = orders_lock.get_mut(&order_id) {
-                    if let Some(new_status) = status {
-                        order.status = new_status;
-                    }
-                    
-                    if let Some(qty) = filled_qty {
-                        order.filled_quantity = qty;
-                    }
-                    
-                    if let Some(price) = avg_fill_price {
-                        order.average_fill_price = Some(price);
-                    }
-                    
-                    order.updated_at = Utc::now();
-                    
-                    // If the order is filled or canceled, remove it from active orders
-                    if order.status == OrderStatus::Filled || 
-                       order.status == OrderStatus::Cancelled || 
-                       order.status == OrderStatus::Rejected {
-                        let mut active_orders_lock = active_orders.write().await;
-                        active_orders_lock.remove(&order_id);
-                    }
-                } else {
-                    warn!("Received update for unknown order: {}", order_id);
+    /// Keep a TWAP execution so its progress can be looked up by parent id
+    pub async fn track_twap_execution(&self, execution: TwapExecution) {
+        self.twap_executions.write().await.insert(execution.parent_id, execution);
+    }
+    
+    pub async fn get_twap_execution(&self, parent_id: Uuid) -> Option<TwapExecution> {
+        self.twap_executions.read().await.get(&parent_id).cloned()
+    }
+    
+    pub async fn get_active_orders(&self) -> Vec<Order> {
+        let active_orders = self.active_orders.read().await;
+        active_orders.values().cloned().collect()
+    }
+    
+    /// Get every recorded status transition for an order, oldest first
+    pub async fn get_audit_trail(&self, order_id: Uuid) -> Vec<AuditEntry> {
+        self.audit_log.get_trail(order_id).await
+    }
+    
+    /// Execution quality statistics over every order matching the filter
+    pub async fn get_order_statistics(&self, filter: OrderHistoryFilter) -> OrderStatistics {
+        let orders = self.orders.read().await;
+        OrderStatistics::from_orders(orders.values().filter(|order| filter.matches(order)))
+    }
+    
+    /// Get a handle to the router used to submit orders, e.g. to register exchanges
+    pub fn get_order_router(&self) -> OrderRouter {
+        self.order_router.clone()
+    }
+    
+    /// Submit orders under each exchange's own symbols, as mapped by `normalizer`
+    pub fn set_symbol_normalizer(&mut self, normalizer: Arc<SymbolNormalizer>) {
+        self.order_router.set_symbol_normalizer(normalizer);
+    }
+    
+    /// Give every order a sequential client order ID with the given prefix
+    pub fn set_client_id_prefix(&mut self, prefix: &str) {
+        self.client_id_generator = Some(Arc::new(ClientOrderIdGenerator::new(prefix)));
+    }
+    
+    /// Give orders from `strategy_id` their own sequence, overriding the global one
+    pub fn set_strategy_client_id_prefix(&mut self, strategy_id: &str, prefix: &str) {
+        self.strategy_client_id_generators.insert(
+            strategy_id.to_string(),
+            Arc::new(ClientOrderIdGenerator::new(prefix)),
+        );
+    }
+    
+    fn client_id_generator_for(&self, strategy_id: Option<&str>) -> Option<&Arc<ClientOrderIdGenerator>> {
+        strategy_id
+            .and_then(|id| self.strategy_client_id_generators.get(id))
+            .or(self.client_id_generator.as_ref())
+    }
+    
+    pub fn get_position_manager(&self) -> Arc<PositionManager> {
+        self.position_manager.clone()
+    }
+    
+    pub fn get_portfolio_manager(&self) -> Arc<PortfolioManager> {
+        self.portfolio_manager.clone()
+    }
+    
+    /// Limit the 95% one-day portfolio VaR orders may lead to; `None` disables the check
+    pub fn set_max_portfolio_var(&mut self, max_var: Option<f64>) {
+        self.max_portfolio_var = max_var;
+    }
+    
+    pub fn max_portfolio_var(&self) -> Option<f64> {
+        self.max_portfolio_var
+    }
+    
+    /// Record the latest market price of every symbol with portfolio VaR, as
+    /// one sample for its volatility and correlation estimates. Returns how
+    /// many were recorded; none without a price converter.
+    pub async fn record_portfolio_prices(&self) -> usize {
+        let Some(converter) = &self.price_converter else {
+            return 0;
+        };
+        let prices = converter.prices().await;
+        for (symbol, price) in &prices {
+            self.portfolio_manager.record_price(symbol, *price).await;
+        }
+        prices.len()
+    }
+    
+    /// 95% one-day VaR of the positions currently held, as the VaR limit measures it
+    pub async fn portfolio_var_exposure(&self) -> f64 {
+        self.portfolio_manager.set_positions(self.position_manager.get_positions().await).await;
+        self.portfolio_manager.total_var_exposure().await
+    }
+    
+    /// Limit the gross value of all positions, in base currency; `None` disables the check
+    pub fn set_max_total_exposure(&mut self, max_exposure: Option<f64>) {
+        self.max_total_exposure = max_exposure;
+    }
+    
+    pub fn max_total_exposure(&self) -> Option<f64> {
+        self.max_total_exposure
+    }
+    
+    /// Limit how far, in basis points from the best price, an order may be
+    /// estimated to walk the book; `None` disables the check
+    pub fn set_max_slippage_bps(&mut self, max_slippage_bps: Option<f64>) {
+        self.max_slippage_bps = max_slippage_bps;
+    }
+    
+    pub fn max_slippage_bps(&self) -> Option<f64> {
+        self.max_slippage_bps
+    }
+    
+    /// Order books to estimate market impact against, as kept by the market
+    /// data manager. Orders in symbols with no book are not checked.
+    pub fn set_order_books(&mut self, order_books: OrderBooks) {
+        self.order_books = Some(order_books);
+    }
+    
+    /// Value positions quoted in other currencies in `base_currency` using
+    /// `converter`, for both the exposure limit and portfolio VaR
+    pub fn set_price_converter(&mut self, converter: PriceConverter, base_currency: &str) {
+        self.portfolio_manager.set_fx_provider(Arc::new(MarketDataFxProvider::from(converter.clone())), base_currency);
+        self.price_converter = Some(converter);
+        self.base_currency = base_currency.to_string();
+    }
+    
+    pub fn base_currency(&self) -> &str {
+        &self.base_currency
+    }
+    
+    /// Gross value of all open positions in base currency, converting each from
+    /// its quote currency. Fails when a quote currency has no rate to base.
+    pub async fn total_exposure(&self) -> Result<f64, TradingError> {
+        let mut total = 0.0;
+        for position in self.position_manager.get_positions().await {
+            total += self.to_base_currency(&position.symbol, (position.quantity * position.current_price).abs()).await?;
+        }
+        Ok(total)
+    }
+    
+    // Convert an amount in the quote currency of `symbol` to base currency
+    async fn to_base_currency(&self, symbol: &str, amount: f64) -> Result<f64, TradingError> {
+        let (quote, converter) = match (split_symbol(symbol), &self.price_converter) {
+            (Some((_, quote)), Some(converter)) => (quote, converter),
+            _ => return Ok(amount),
+        };
+        
+        converter.convert(amount, quote, &self.base_currency).await
+            .ok_or_else(|| TradingError::Unavailable(format!("No {}/{} rate to value {} exposure", quote, self.base_currency, symbol)))
+    }
+    
+    /// Send alerts for risk limit breaches, exchange rejections and submission failures
+    pub fn set_notification_manager(&mut self, notification_manager: Arc<NotificationManager>) {
+        self.notification_manager = Some(notification_manager);
+    }
+    
+    /// Halt new orders when intraday drawdown exceeds the breaker's limit.
+    /// Cancellations are still accepted while halted.
+    pub fn set_circuit_breaker(&mut self, circuit_breaker: Option<CircuitBreaker>) {
+        self.circuit_breaker = circuit_breaker.map(RwLock::new);
+    }
+    
+    /// Breaker state as of its last P&L update; None when no breaker is set.
+    /// Reading it never trips the breaker.
+    pub async fn circuit_breaker_status(&self) -> Option<CircuitBreakerStatus> {
+        match &self.circuit_breaker {
+            Some(circuit_breaker) => Some(circuit_breaker.read().await.status()),
+            None => None,
+        }
+    }
+    
+    /// Resume accepting orders after the circuit breaker has tripped
+    pub async fn reset_circuit_breaker(&self) {
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.write().await.reset();
+        }
+    }
+    
+    /// Feed the latest P&L to the breaker, alerting if this trips it
+    pub async fn refresh_circuit_breaker(&self) {
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            let pnl = self.position_manager.total_pnl().await;
+            let mut circuit_breaker = circuit_breaker.write().await;
+            if circuit_breaker.record_pnl(pnl, self.clock.now()) {
+                let status = circuit_breaker.status();
+                self.notify(Notification::new(
+                        NotificationLevel::Critical,
+                        "Trading halted by circuit breaker",
+                        &format!("Intraday drawdown of {:.2}% exceeded {:.2}%; new orders are rejected until the breaker is reset",
+                                 status.intraday_drawdown * 100.0, status.max_intraday_drawdown * 100.0))
+                    .with_metadata("equity", &status.current_equity.to_string()));
+            }
+        }
+    }
+    
+    async fn check_circuit_breaker(&self) -> Result<(), TradingError> {
+        self.refresh_circuit_breaker().await;
+        match &self.circuit_breaker {
+            Some(circuit_breaker) if circuit_breaker.read().await.is_halted() => {
+                Err(TradingError::RiskViolation("Trading halted by circuit breaker; reset it to resume".to_string()))
+            },
+            _ => Ok(()),
+        }
+    }
+    
+    fn notify(&self, notification: Notification) {
+        if let Some(notification_manager) = &self.notification_manager {
+            notification_manager.notify_in_background(notification);
+        }
+    }
+    
+    /// Take the time for new orders, expiry and the circuit breaker from
+    /// `clock`. Updates applied by the event loop still use the system clock.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+    
+    /// Cancel open `Day` orders placed on an earlier UTC day than the clock's
+    /// current one. Returns the ids of the orders cancelled.
+    pub async fn expire_day_orders(&self) -> Vec<Uuid> {
+        let today = self.clock.now().date_naive();
+        let expired: Vec<Uuid> = {
+            let active_orders = self.active_orders.read().await;
+            let orders = self.orders.read().await;
+            let algo_parents = self.algo_parents.read().await;
+            active_orders.keys()
+                .filter_map(|order_id| orders.get(order_id))
+                // Algo children go when their parent does
+                .filter(|order| !algo_parents.contains_key(&order.id))
+                .filter(|order| order.time_in_force == TimeInForce::Day && order.created_at.date_naive() < today)
+                .map(|order| order.id)
+                .collect()
+        };
+        
+        let mut cancelled = Vec::new();
+        for order_id in expired {
+            match self.cancel_order(order_id, "Day order expired".to_string()).await {
+                Ok(()) => cancelled.push(order_id),
+                Err(e) => warn!("Could not expire order {}: {}", order_id, e),
+            }
+        }
+        if !cancelled.is_empty() {
+            info!("Expired {} day orders", cancelled.len());
+        }
+        cancelled
+    }
+    
+    /// Halt or resume new orders in `symbol`, e.g. while it is suspended.
+    /// Cancellations and orders already working are unaffected.
+    pub async fn set_symbol_enabled(&self, symbol: &str, enabled: bool) {
+        let mut disabled_symbols = self.disabled_symbols.write().await;
+        let changed = if enabled {
+            disabled_symbols.remove(symbol)
+        } else {
+            disabled_symbols.insert(symbol.to_string())
+        };
+        if changed {
+            info!("Trading in {} {}", symbol, if enabled { "enabled" } else { "disabled" });
+        }
+    }
+    
+    pub async fn is_symbol_enabled(&self, symbol: &str) -> bool {
+        !self.disabled_symbols.read().await.contains(symbol)
+    }
+    
+    /// Symbols currently halted, in name order
+    pub async fn disabled_symbols(&self) -> Vec<String> {
+        self.disabled_symbols.read().await.iter().cloned().collect()
+    }
+    
+    async fn check_symbol_enabled(&self, symbol: &str) -> Result<(), TradingError> {
+        if self.is_symbol_enabled(symbol).await {
+            Ok(())
+        } else {
+            Err(TradingError::RiskViolation(format!("Trading in {} is disabled", symbol)))
+        }
+    }
+    
+    /// Limit order sizes and open orders in `symbol`, replacing any rules it had
+    pub fn set_symbol_rules(&mut self, symbol: &str, rules: SymbolTradingRules) -> Result<(), TradingError> {
+        rules.validate().map_err(TradingError::Validation)?;
+        self.symbol_rules.insert(symbol.to_string(), rules);
+        Ok(())
+    }
+    
+    pub fn remove_symbol_rules(&mut self, symbol: &str) -> Option<SymbolTradingRules> {
+        self.symbol_rules.remove(symbol)
+    }
+    
+    pub fn get_symbol_rules(&self, symbol: &str) -> Option<&SymbolTradingRules> {
+        self.symbol_rules.get(symbol)
+    }
+    
+    // Orders working in `symbol`. An algo order counts once, through its parent.
+    async fn open_order_count(&self, symbol: &str) -> usize {
+        let active_orders = self.active_orders.read().await;
+        let algo_parents = self.algo_parents.read().await;
+        active_orders.values()
+            .filter(|order| order.symbol == symbol && !algo_parents.contains_key(&order.id))
+            .count()
+    }
+    
+    /// Pause between the orders `place_signals` places; zero submits them back to back
+    pub fn set_signal_submission_delay(&mut self, delay: Duration) {
+        self.signal_submission_delay = delay;
+    }
+    
+    pub fn signal_submission_delay(&self) -> Duration {
+        self.signal_submission_delay
+    }
+    
+    /// Hold off strategy orders that would add to a symbol's position for
+    /// `cooldown` after a stop order in it fills, so a strategy does not buy
+    /// straight back into a move it was just stopped out of. Orders that
+    /// reduce the position, and manual orders, still go through. Zero, the
+    /// default, turns the cooldown off.
+    pub fn set_post_stop_cooldown(&mut self, cooldown: Duration) {
+        self.post_stop_cooldown = cooldown;
+    }
+    
+    pub fn post_stop_cooldown(&self) -> Duration {
+        self.post_stop_cooldown
+    }
+    
+    /// Time left before strategies may enter `symbol` again after a stop fill.
+    /// Fills are timed by the system clock, like other exchange updates.
+    pub async fn post_stop_cooldown_remaining(&self, symbol: &str) -> Option<Duration> {
+        let stopped_at = *self.stop_fills.read().await.get(symbol)?;
+        let elapsed = (Utc::now() - stopped_at).to_std().unwrap_or(Duration::ZERO);
+        self.post_stop_cooldown.checked_sub(elapsed).filter(|remaining| !remaining.is_zero())
+    }
+    
+    /// Allow or forbid sells that would take a position short
+    pub fn set_allow_short(&mut self, allow_short: bool) {
+        self.allow_short = allow_short;
+    }
+    
+    pub fn allow_short(&self) -> bool {
+        self.allow_short
+    }
+    
+    // Reject post-only orders that would cross the spread on the exchange
+    // they are routed to, going by its latest market snapshot
+    async fn check_post_only(&self, order: &Order) -> Result<(), TradingError> {
+        if !order.post_only {
+            return Ok(());
+        }
+        if order.order_type != OrderType::Limit {
+            return Err(TradingError::Validation("Post-only orders must be limit orders".to_string()));
+        }
+        
+        let snapshot = self.order_router.get_market_data(order).await
+            .map_err(|e| TradingError::Exchange(format!("Cannot check post-only order for {}: {}", order.symbol, e)))?;
+        if order.crosses_spread(snapshot.bid, snapshot.ask) {
+            return Err(TradingError::Validation(format!(
+                "Post-only {} of {} at {} would cross the spread (bid {}, ask {})",
+                order.direction, order.symbol, order.price.unwrap_or_default(), snapshot.bid, snapshot.ask
+            )));
+        }
+        
+        Ok(())
+    }
+    
+    // Reject orders needing more margin than the exchange they are routed to
+    // has available. Exchanges that report no margin are not checked, nor are
+    // orders whose exchange cannot be resolved; submission reports those itself.
+    // An exchange that fails to report its margin fails the order.
+    async fn check_margin(&self, order: &Order) -> Result<(), TradingError> {
+        let Some(margin) = self.order_router.get_margin_info(order).await? else {
+            return Ok(());
+        };
+        let price = match order.price {
+            Some(price) => price.to_f64(),
+            None => self.order_router.get_market_data(order).await
+                .map_err(|e| TradingError::Exchange(format!("Cannot check margin for {}: {}", order.symbol, e)))?
+                .price
+                .to_f64(),
+        };
+        
+        let required = margin.required_margin(order.quantity, price);
+        if required > margin.available_margin {
+            return Err(TradingError::InsufficientMargin { required, available: margin.available_margin });
+        }
+        
+        Ok(())
+    }
+    
+    // Reject strategy entries into a symbol still cooling down after a stop fill
+    async fn check_post_stop_cooldown(&self, order: &Order) -> Result<(), TradingError> {
+        if order.strategy_id.is_none() {
+            return Ok(());
+        }
+        let Some(remaining) = self.post_stop_cooldown_remaining(&order.symbol).await else {
+            return Ok(());
+        };
+        
+        let held = self.position_manager.net_quantity(&order.symbol).await;
+        let reduces = match order.direction {
+            TradeDirection::Buy => held < 0.0,
+            TradeDirection::Sell => held > 0.0,
+        };
+        if reduces {
+            return Ok(());
+        }
+        Err(TradingError::RiskViolation(format!(
+            "{} was stopped out; strategy entries resume in {:.1}s",
+            order.symbol, remaining.as_secs_f64()
+        )))
+    }
+    
+    // With shorting disabled, a sell may not exceed the net long position less
+    // the quantity already committed to other open sells in the symbol
+    async fn check_short_selling(&self, order: &Order) -> Result<(), TradingError> {
+        if self.allow_short || order.direction != TradeDirection::Sell {
+            return Ok(());
+        }
+        
+        let held = self.position_manager.net_quantity(&order.symbol).await.max(0.0);
+        let pending_sells: f64 = {
+            let active_orders = self.active_orders.read().await;
+            let orders = self.orders.read().await;
+            // Algo children are already counted in their parent's remaining quantity
+            let algo_parents = self.algo_parents.read().await;
+            active_orders.keys()
+                .filter(|id| !algo_parents.contains_key(id))
+                .filter_map(|id| orders.get(id))
+                .filter(|o| o.symbol == order.symbol && o.direction == TradeDirection::Sell)
+                .map(|o| o.quantity - o.filled_quantity)
+                .sum()
+        };
+        
+        let available = held - pending_sells;
+        if order.quantity > available {
+            return Err(TradingError::RiskViolation(format!(
+                "Sell of {} {} exceeds the {} available to sell (held {}, pending sells {}) and short selling is disabled",
+                order.quantity, order.symbol, available.max(0.0), held, pending_sells
+            )));
+        }
+        
+        Ok(())
+    }
+    
+    async fn check_risk_limits(&self, order: &Order) -> Result<(), TradingError> {
+        self.check_market_impact(order)?;
+        self.check_portfolio_var(order).await?;
+        self.check_total_exposure(order).await
+    }
+    
+    // Reject orders estimated to fill further from the best price than the
+    // slippage limit allows, walking the symbol's book as a market order would
+    fn check_market_impact(&self, order: &Order) -> Result<(), TradingError> {
+        let (Some(limit_bps), Some(order_books)) = (self.max_slippage_bps, &self.order_books) else {
+            return Ok(());
+        };
+        let Some(book) = order_books.read().unwrap().get(&order.symbol).cloned() else {
+            return Ok(());
+        };
+        
+        let side = match order.direction {
+            TradeDirection::Buy => TradeSide::Buy,
+            TradeDirection::Sell => TradeSide::Sell,
+        };
+        let estimate = book.read().unwrap().estimate_market_impact(side, order.quantity - order.filled_quantity);
+        if estimate.slippage_bps > limit_bps {
+            return Err(TradingError::ExcessiveMarketImpact { estimated_bps: estimate.slippage_bps, limit_bps });
+        }
+        
+        Ok(())
+    }
+    
+    // Reject orders that would leave portfolio VaR above the limit. Orders that
+    // reduce VaR pass even when it is already over, so risk can be unwound.
+    // Orders with no price to value them at, or in symbols with no known
+    // volatility, are not checked.
+    async fn check_portfolio_var(&self, order: &Order) -> Result<(), TradingError> {
+        let max_var = match self.max_portfolio_var {
+            Some(max_var) => max_var,
+            None => return Ok(()),
+        };
+        
+        self.portfolio_manager.set_positions(self.position_manager.get_positions().await).await;
+        
+        let price = match order.price {
+            Some(price) => Some(price.to_f64()),
+            None => match self.portfolio_manager.last_price(&order.symbol).await {
+                Some(price) => Some(price),
+                None => self.position_manager.get_position(&order.symbol).await.map(|p| p.current_price),
+            },
+        };
+        let Some(price) = price else {
+            debug!("Not checking portfolio VaR for {}: no price available", order.symbol);
+            return Ok(());
+        };
+        
+        let signed_quantity = match order.direction {
+            TradeDirection::Buy => order.quantity,
+            TradeDirection::Sell => -order.quantity,
+        };
+        
+        let current_var = self.portfolio_manager.total_var_exposure().await;
+        let projected_var = self.portfolio_manager.var_exposure_with_trade(&order.symbol, signed_quantity, price).await;
+        if projected_var > max_var && projected_var > current_var {
+            return Err(TradingError::RiskViolation(format!(
+                "Order would raise portfolio VaR to {:.2}, above the limit of {:.2}",
+                projected_var, max_var
+            )));
+        }
+        
+        Ok(())
+    }
+    
+    // Reject orders that would take gross exposure above the limit. As with the
+    // VaR check, orders that reduce exposure always pass.
+    async fn check_total_exposure(&self, order: &Order) -> Result<(), TradingError> {
+        let max_exposure = match self.max_total_exposure {
+            Some(max_exposure) => max_exposure,
+            None => return Ok(()),
+        };
+        
+        let position = self.position_manager.get_position(&order.symbol).await;
+        let price = match order.price.map(Price::to_f64).or_else(|| position.as_ref().map(|p| p.current_price)) {
+            Some(price) => price,
+            None => {
+                let rate = match (split_symbol(&order.symbol), &self.price_converter) {
+                    (Some((asset, quote)), Some(converter)) => converter.rate(asset, quote).await,
+                    _ => None,
+                };
+                rate.ok_or_else(|| TradingError::RiskViolation(format!("Cannot assess exposure for {}: no price available", order.symbol)))?
+            }
+        };
+        
+        let held = position.as_ref().map(|p| p.quantity).unwrap_or(0.0);
+        let signed_quantity = match order.direction {
+            TradeDirection::Buy => order.quantity,
+            TradeDirection::Sell => -order.quantity,
+        };
+        let held_value = match &position {
+            Some(p) => self.to_base_currency(&order.symbol, (p.quantity * p.current_price).abs()).await?,
+            None => 0.0,
+        };
+        
+        let current = self.total_exposure().await?;
+        let projected = current - held_value
+            + self.to_base_currency(&order.symbol, ((held + signed_quantity) * price).abs()).await?;
+        if projected > max_exposure && projected > current {
+            return Err(TradingError::RiskViolation(format!(
+                "Order would raise total exposure to {:.2} {}, above the limit of {:.2}",
+                projected, self.base_currency, max_exposure
+            )));
+        }
+        
+        Ok(())
+    }
+    
+    async fn emit_event(&self, event: OrderEvent) {
+        if let Err(e) = self.event_sender.send(event).await {
+            error!("Failed to emit order event: {}", e);
+        }
+    }
+    
+    async fn validate_order(&self, order: &Order) -> Result<(), TradingError> {
+        // Basic validation checks
+        if order.symbol.is_empty() {
+            return Err(TradingError::Validation("Order symbol cannot be empty".to_string()));
+        }
+        
+        if order.quantity <= 0.0 {
+            return Err(TradingError::Validation("Order quantity must be positive".to_string()));
+        }
+        
+        // Validate price for limit orders
+        if order.order_type == OrderType::Limit && order.price.is_none() {
+            return Err(TradingError::Validation("Limit orders must specify a price".to_string()));
+        }
+        
+        // Validate market orders shouldn't have a price
+        if order.order_type == OrderType::Market && order.price.is_some() {
+            return Err(TradingError::Validation("Market orders should not specify a price".to_string()));
+        }
+        
+        // Validate stop price for stop orders
+        if (order.order_type == OrderType::StopLoss || order.order_type == OrderType::StopLimit) 
+            && order.stop_price.is_none() {
+            return Err(TradingError::Validation("Stop orders must specify a stop price".to_string()));
+        }
+        
+        // Desk limits for the symbol
+        if let Some(rules) = self.symbol_rules.get(&order.symbol) {
+            let open_orders = match rules.max_orders_open {
+                Some(_) => self.open_order_count(&order.symbol).await,
+                None => 0,
+            };
+            rules.check_order(&order.symbol, order.quantity, open_orders).map_err(TradingError::RiskViolation)?;
+        }
+        
+        Ok(())
+    }
+    
+    #[allow(clippy::too_many_arguments)]
+    async fn process_order_event(
+        event: OrderEvent,
+        orders: Arc<RwLock<HashMap<Uuid, Order>>>,
+        active_orders: Arc<RwLock<HashMap<Uuid, Order>>>,
+        executions: &RwLock<HashMap<Uuid, Vec<Execution>>>,
+        tag_index: &RwLock<HashMap<String, HashSet<Uuid>>>,
+        audit_log: &AuditLog,
+        position_manager: &PositionManager,
+        stop_fills: &RwLock<HashMap<String, DateTime<Utc>>>,
+    ) {
+        match event {
+            OrderEvent::Update { order_id, status, filled_qty, avg_fill_price } => {
+                info!("Processing order update event for order {}: status={:?}, filled={:?}, avg_price={:?}", 
+                      order_id, status, filled_qty, avg_fill_price);
+                
+                // Update the order status
+                let mut orders_lock = orders.write().await;
+                if let Some(order) = orders_lock.get_mut(&order_id) {
+                    // A refused status still leaves the fill to be recorded
+                    let previous_status = order.status.clone();
+                    if let Some(new_status) = status {
+                        order.transition_to(new_status, "Exchange status update");
+                    }
+                    
+                    // Quantity filled since the last report moves the position
+                    let previous_filled = order.filled_quantity;
+                    let previous_avg = order.average_fill_price.map(Price::to_f64);
+                    let new_fill = filled_qty
+                        .map(|qty| qty - previous_filled)
+                        .filter(|qty| *qty > 0.0);
+                    
+                    if let Some(qty) = filled_qty {
+                        order.filled_quantity = qty;
+                    }
+                    
+                    if let Some(price) = avg_fill_price {
+                        order.average_fill_price = Some(Price::from(price));
+                    }
+                    
+                    if let Some(fill_qty) = new_fill {
+                        // Reports carry cumulative averages, so the fill's own price is
+                        // recovered from the change in average
+                        let fill_price = match avg_fill_price {
+                            Some(avg) => Some(execution::marginal_fill_price(previous_filled, previous_avg, order.filled_quantity, avg)),
+                            None => order.average_fill_price.or(order.price).map(Price::to_f64),
+                        };
+                        
+                        match fill_price {
+                            Some(price) => {
+                                position_manager.apply_strategy_fill(&order.symbol, order.strategy_id.as_deref(), order.direction, fill_qty, price).await;
+                                executions.write().await.entry(order_id).or_default().push(Execution {
+                                    order_id,
+                                    fill_qty,
+                                    fill_price: price,
+                                    timestamp: Utc::now(),
+                                    exchange: order.exchange.clone(),
+                                });
+                            },
+                            None => warn!("Fill of {} on order {} has no price, position not updated", fill_qty, order_id),
+                        }
+                    }
+                    
+                    order.updated_at = Utc::now();
+                    
+                    if order.status == OrderStatus::Filled && order.filled_at.is_none() {
+                        order.filled_at = Some(order.updated_at);
+                        // Starts the symbol's post-stop cooldown
+                        if order.order_type.is_stop() {
+                            stop_fills.write().await.insert(order.symbol.clone(), order.updated_at);
+                        }
+                    }
+                    
+                    // Finished orders leave the active orders
+                    if order.is_terminal() {
+                        active_orders.write().await.remove(&order_id);
+                        Self::untag_order(tag_index, order_id, &order.tags).await;
+                    }
+                    
+                    if order.status != previous_status {
+                        let new_status = order.status.clone();
+                        drop(orders_lock);
+                        audit_log.record(order_id, Some(previous_status), new_status, "Exchange status update").await;
+                    }
+                } else {
+                    warn!("Received update for unknown order: {}", order_id);
                 }
             },
             OrderEvent::New(order) => {
                 info!("Processing new order event for order {}", order.id);
                 // New orders are already added to the orders map during place_order
+                if order.is_active() {
+                    let mut tag_index = tag_index.write().await;
+                    for tag in &order.tags {
+                        tag_index.entry(tag.clone()).or_default().insert(order.id);
+                    }
+                }
             },
             OrderEvent::Cancel { order_id, reason } => {
                 info!("Processing cancel order event for order {}: {}", order_id, reason);
                 
                 let mut orders_lock = orders.write().await;
                 if let Some(order) = orders_lock.get_mut(&order_id) {
-                    order.status = OrderStatus::Cancelled;
+                    let previous_status = order.status.clone();
+                    if !order.transition_to(OrderStatus::Cancelled, &reason) {
+                        return;
+                    }
                     order.notes = Some(reason.clone());
                     order.updated_at = Utc::now();
+                    Self::untag_order(tag_index, order_id, &order.tags).await;
                     
                     // Remove from active orders
                     let mut active_orders_lock = active_orders.write().await;
                     active_orders_lock.remove(&order_id);
+                    drop(active_orders_lock);
+                    drop(orders_lock);
+                    
+                    if previous_status != OrderStatus::Cancelled {
+                        audit_log.record(order_id, Some(previous_status), OrderStatus::Cancelled, &reason).await;
+                    }
                 } else {
                     warn!("Received cancel for unknown order: {}", order_id);
                 }
@@ -415,13 +1754,23 @@ impl OrderManager {
                 
                 let mut orders_lock = orders.write().await;
                 if let Some(order) = orders_lock.get_mut(&order_id) {
-                    order.status = OrderStatus::Rejected;
+                    let previous_status = order.status.clone();
+                    if !order.transition_to(OrderStatus::Rejected, &reason) {
+                        return;
+                    }
                     order.notes = Some(reason.clone());
                     order.updated_at = Utc::now();
+                    Self::untag_order(tag_index, order_id, &order.tags).await;
                     
                     // Remove from active orders
                     let mut active_orders_lock = active_orders.write().await;
                     active_orders_lock.remove(&order_id);
+                    drop(active_orders_lock);
+                    drop(orders_lock);
+                    
+                    if previous_status != OrderStatus::Rejected {
+                        audit_log.record(order_id, Some(previous_status), OrderStatus::Rejected, &reason).await;
+                    }
                 } else {
                     warn!("Received reject for unknown order: {}", order_id);
                 }
@@ -432,26 +1781,157 @@ impl OrderManager {
                 if let Some(id) = order_id {
                     let mut orders_lock = orders.write().await;
                     if let Some(order) = orders_lock.get_mut(&id) {
-                        order.status = OrderStatus::Failed;
+                        let previous_status = order.status.clone();
+                        if !order.transition_to(OrderStatus::Failed, &message) {
+                            return;
+                        }
                         order.notes = Some(message.clone());
                         order.updated_at = Utc::now();
+                        Self::untag_order(tag_index, id, &order.tags).await;
                         
                         // Remove from active orders
                         let mut active_orders_lock = active_orders.write().await;
                         active_orders_lock.remove(&id);
+                        drop(active_orders_lock);
+                        drop(orders_lock);
+                        
+                        if previous_status != OrderStatus::Failed {
+                            audit_log.record(id, Some(previous_status), OrderStatus::Failed, &message).await;
+                        }
                     }
                 }
             }
         }
     }
     
+    // Roll an algo child's fills up into its parent. The parent is Filled once
+    // its children have filled its quantity and PartiallyFilled while some has
+    // filled. When every child has been released and finished short of that,
+    // the parent is Cancelled if anything filled and Rejected otherwise.
+    async fn sync_algo_parent(
+        child_id: Uuid,
+        algo_parents: &RwLock<HashMap<Uuid, Uuid>>,
+        algo_executions: &RwLock<HashMap<Uuid, AlgoExecution>>,
+        orders: &RwLock<HashMap<Uuid, Order>>,
+        active_orders: &RwLock<HashMap<Uuid, Order>>,
+        tag_index: &RwLock<HashMap<String, HashSet<Uuid>>>,
+        audit_log: &AuditLog,
+    ) {
+        let Some(parent_id) = algo_parents.read().await.get(&child_id).copied() else {
+            return;
+        };
+        let (released, schedule_complete) = match algo_executions.read().await.get(&parent_id) {
+            Some(execution) => (execution.submitted.clone(), execution.is_schedule_complete()),
+            None => return,
+        };
+        
+        let mut orders_lock = orders.write().await;
+        let children: Vec<&Order> = released.iter().filter_map(|id| orders_lock.get(id)).collect();
+        let filled: f64 = children.iter().map(|child| child.filled_quantity).sum();
+        let notional: f64 = children.iter()
+            .filter_map(|child| child.average_fill_price.map(|price| price.to_f64() * child.filled_quantity))
+            .sum();
+        let children_finished = children.iter().all(|child| child.is_terminal());
+        
+        let Some(parent) = orders_lock.get_mut(&parent_id) else {
+            return;
+        };
+        parent.filled_quantity = filled;
+        parent.average_fill_price = if filled > 0.0 { Some(Price::from(notional / filled)) } else { None };
+        parent.updated_at = Utc::now();
+        
+        let previous_status = parent.status.clone();
+        if previous_status != OrderStatus::Cancelled {
+            parent.transition_to(if filled >= parent.quantity - 1e-9 {
+                OrderStatus::Filled
+            } else if schedule_complete && children_finished {
+                if filled > 0.0 { OrderStatus::Cancelled } else { OrderStatus::Rejected }
+            } else if filled > 0.0 {
+                OrderStatus::PartiallyFilled
+            } else {
+                previous_status.clone()
+            }, "Child order update");
+        }
+        if parent.status == OrderStatus::Filled && parent.filled_at.is_none() {
+            parent.filled_at = Some(parent.updated_at);
+        }
+        
+        let status = parent.status.clone();
+        let tags = parent.tags.clone();
+        drop(orders_lock);
+        
+        if status != previous_status {
+            if status.is_terminal() {
+                active_orders.write().await.remove(&parent_id);
+                Self::untag_order(tag_index, parent_id, &tags).await;
+            }
+            audit_log.record(parent_id, Some(previous_status), status, "Child order update").await;
+        }
+    }
+    
+    // Drop a finished order from the tag index, along with tags no open order carries
+    async fn untag_order(tag_index: &RwLock<HashMap<String, HashSet<Uuid>>>, order_id: Uuid, tags: &[String]) {
+        let mut tag_index = tag_index.write().await;
+        for tag in tags {
+            if let Some(ids) = tag_index.get_mut(tag) {
+                ids.remove(&order_id);
+                if ids.is_empty() {
+                    tag_index.remove(tag);
+                }
+            }
+        }
+    }
+    
     #[allow(dead_code)]
-    pub fn get_event_sender(&self) -> mpsc::Sender<OrderEvent> {
+    pub fn get_event_sender(&self) -> EventSender<OrderEvent> {
         self.event_sender.clone()
     }
     
-    #[allow(dead_code)]
-    pub async fn shutdown(&mut self) -> Result<(), String> {
+    /// Receive every order event from now on, in the order processed. A
+    /// subscriber more than `ORDER_EVENT_BROADCAST_CAPACITY` events behind gets
+    /// `RecvError::Lagged` with the number it missed, then carries on from the
+    /// oldest event still held.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<OrderEvent> {
+        self.event_broadcast.subscribe()
+    }
+    
+    /// POST a JSON payload to `url` for each processed event of the given
+    /// kinds, or of every kind when `events` is empty. Returns the webhook's id.
+    pub async fn register_webhook(&self, url: String, events: Vec<WebhookEventKind>) -> Result<Uuid, TradingError> {
+        self.register_webhook_with_config(url, events, WebhookConfig::default()).await
+    }
+    
+    /// `register_webhook` with a custom queue size, retry schedule and timeout.
+    /// Delivery runs in the background: failures are logged and counted, and
+    /// payloads are dropped once the queue is full, without delaying orders.
+    pub async fn register_webhook_with_config(&self, url: String, events: Vec<WebhookEventKind>, config: WebhookConfig) -> Result<Uuid, TradingError> {
+        let webhook = OrderWebhook::start(&url, events, config, self.event_broadcast.subscribe(), self.orders.clone())
+            .map_err(TradingError::Validation)?;
+        let id = webhook.id();
+        self.webhooks.write().await.push(webhook);
+        Ok(id)
+    }
+    
+    /// Stop delivering to a webhook. Payloads still queued are discarded.
+    pub async fn remove_webhook(&self, webhook_id: Uuid) -> bool {
+        let mut webhooks = self.webhooks.write().await;
+        let before = webhooks.len();
+        webhooks.retain(|webhook| webhook.id() != webhook_id);
+        webhooks.len() < before
+    }
+    
+    pub async fn webhook_stats(&self, webhook_id: Uuid) -> Option<WebhookStats> {
+        self.webhooks.read().await.iter()
+            .find(|webhook| webhook.id() == webhook_id)
+            .map(|webhook| webhook.stats())
+    }
+    
+    /// Queue depth and events dropped by the backpressure policy
+    pub fn event_channel_stats(&self) -> ChannelStats {
+        self.event_sender.stats()
+    }
+    
+    pub async fn shutdown(&mut self) -> Result<(), TradingError> {
         info!("Shutting down order manager");
         
         // Send shutdown signal to event processor
@@ -464,11 +1944,165 @@ impl OrderManager {
         Ok(())
     }
 
-    async fn update_order_status_internal(orders: Arc<RwLock<HashMap<Uuid, Order>>>, order_id: Uuid, status: OrderStatus) {
-        let mut orders_lock = orders.write().await;
-        if let Some(order) = orders_lock.get_mut(&order_id) {
-            order.status = status;
-            order.updated_at = Utc::now();
+    async fn update_order_status_internal(
+        orders: Arc<RwLock<HashMap<Uuid, Order>>>,
+        audit_log: &AuditLog,
+        order_id: Uuid,
+        status: OrderStatus,
+        reason: &str,
+    ) -> bool {
+        let previous_status = {
+            let mut orders_lock = orders.write().await;
+            match orders_lock.get_mut(&order_id) {
+                Some(order) => {
+                    let previous_status = order.status.clone();
+                    if !order.transition_to(status.clone(), reason) {
+                        return false;
+                    }
+                    previous_status
+                },
+                None => return false,
+            }
+        };
+        
+        if previous_status != status {
+            audit_log.record(order_id, Some(previous_status), status, reason).await;
+        }
+        true
+    }
+} 
+/// Everything needed to store an accepted order and route it to its exchange,
+/// so orders can also be released from background tasks
+#[derive(Clone)]
+struct OrderSubmitter {
+    orders: Arc<RwLock<HashMap<Uuid, Order>>>,
+    active_orders: Arc<RwLock<HashMap<Uuid, Order>>>,
+    order_router: OrderRouter,
+    event_sender: EventSender<OrderEvent>,
+    audit_log: AuditLog,
+    notification_manager: Option<Arc<NotificationManager>>,
+}
+
+impl OrderSubmitter {
+    /// Store an order that has passed validation and risk checks, then submit
+    /// it to the router in the background
+    async fn submit(&self, order: Order) {
+        {
+            let mut orders = self.orders.write().await;
+            let mut active_orders = self.active_orders.write().await;
+            
+            orders.insert(order.id, order.clone());
+            active_orders.insert(order.id, order.clone());
+        }
+        
+        self.audit_log.record(order.id, None, OrderStatus::Created, "Order placed").await;
+        
+        // Emit new order event
+        if let Err(e) = self.event_sender.send(OrderEvent::New(Box::new(order.clone()))).await {
+            error!("Failed to emit order event: {}", e);
+        }
+        
+        // Routing carries on after the caller returns; keep it in the caller's trace
+        tokio::spawn(self.clone().route(order).instrument(Span::current()));
+    }
+    
+    #[tracing::instrument(skip_all, fields(order_id = %order.id, exchange = %order.exchange))]
+    async fn route(self, order: Order) {
+        let OrderSubmitter { orders, active_orders, order_router, event_sender, audit_log, notification_manager } = self;
+        let order_id = order.id;
+        
+        // Update order status to pending submission
+        OrderManager::update_order_status_internal(orders.clone(), &audit_log, order_id, OrderStatus::PendingSubmission, "Submitting to router").await;
+        
+        // Submit to router
+        match order_router.submit_order(order.clone()).await {
+            Ok(exchange) => {
+                // A fallback exchange may have taken the order
+                if exchange != order.exchange {
+                    if let Some(stored) = orders.write().await.get_mut(&order_id) {
+                        stored.exchange = exchange.clone();
+                    }
+                    if let Some(active) = active_orders.write().await.get_mut(&order_id) {
+                        active.exchange = exchange;
+                    }
+                }
+                
+                // Update status to submitted
+                OrderManager::update_order_status_internal(orders.clone(), &audit_log, order_id, OrderStatus::Submitted, "Accepted by exchange").await;
+                
+                // Emit update event
+                let event = OrderEvent::Update {
+                    order_id,
+                    status: Some(OrderStatus::Submitted),
+                    filled_qty: None,
+                    avg_fill_price: None,
+                };
+                
+                if let Err(e) = event_sender.send(event).await {
+                    error!("Failed to emit order update event: {}", e);
+                }
+                
+                // Track fills reported by the exchange until the order completes
+                if let Err(e) = order_router.spawn_status_poller(order_id, event_sender.clone(), STATUS_POLL_INTERVAL).await {
+                    warn!("Unable to poll status for order {}: {}", order_id, e);
+                }
+            },
+            Err(e) => {
+                // Rejections carry the exchange's reason and mark the order Rejected
+                if let Some(reason) = rejection_reason(&e) {
+                    warn!("Order {} rejected: {}", order_id, reason);
+                    
+                    if let Some(notification_manager) = &notification_manager {
+                        notification_manager.notify_in_background(
+                            Notification::new(NotificationLevel::Warning, "Order rejected", reason)
+                                .with_metadata("order_id", &order_id.to_string())
+                                .with_metadata("exchange", &order.exchange));
+                    }
+                    
+                    {
+                        let mut active = active_orders.write().await;
+                        active.remove(&order_id);
+                    }
+                    
+                    let event = OrderEvent::Reject {
+                        order_id,
+                        reason: reason.to_string(),
+                    };
+                    
+                    if let Err(e) = event_sender.send(event).await {
+                        error!("Failed to emit order reject event: {}", e);
+                    }
+                    return;
+                }
+                
+                error!("Failed to submit order {}: {}", order_id, e);
+                
+                if let Some(notification_manager) = &notification_manager {
+                    notification_manager.notify_in_background(
+                        Notification::new(NotificationLevel::Critical, "Order submission failed", &e.to_string())
+                            .with_metadata("order_id", &order_id.to_string())
+                            .with_metadata("exchange", &order.exchange));
+                }
+                
+                // Update status to failed
+                OrderManager::update_order_status_internal(orders.clone(), &audit_log, order_id, OrderStatus::Failed, &e.to_string()).await;
+                
+                // Remove from active orders
+                {
+                    let mut active = active_orders.write().await;
+                    active.remove(&order_id);
+                }
+                
+                // Emit error event
+                let event = OrderEvent::Error {
+                    order_id: Some(order_id),
+                    message: e.to_string(),
+                };
+                
+                if let Err(e) = event_sender.send(event).await {
+                    error!("Failed to emit order error event: {}", e);
+                }
+            }
         }
     }
-} 
\ No newline at end of file
+}
@@ -1,18 +1,74 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
-use tracing::info;
+use tokio::task::JoinHandle;
+use tracing::{info, debug, warn};
 use uuid::Uuid;
 
-use super::Order;
-use crate::exchange::Exchange;
-use crate::exchange::crypto::CryptoExchange;
+use super::{Order, OrderEvent, OrderStatus};
+use crate::exchange::{Exchange, CancellationResult, MarginInfo, MarketSnapshot, OrderStatusResponse, rejection_reason};
+use crate::exchange::circuit_breaker::{CircuitBreakerConfig, CircuitState, ExchangeCircuitBreaker};
+use crate::clock::{Clock, SystemClock};
+use crate::channel::EventSender;
+use crate::error::TradingError;
+use crate::models::SymbolNormalizer;
+use crate::strategy::TradeDirection;
+use crate::utils::text::name_key;
+
+/// Interval between exchange status polls for submitted orders
+pub const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Number of consecutive failed polls after which polling gives up
+const MAX_CONSECUTIVE_POLL_FAILURES: u32 = 5;
+
+/// How orders that name no exchange are routed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoutingPolicy {
+    /// The symbol's primary exchange, or else the default exchange
+    #[default]
+    Primary,
+    /// The exchange quoting the best price: the lowest ask for a buy, the
+    /// highest bid for a sell
+    BestPrice,
+    /// As `BestPrice`, comparing prices once each exchange's fee on the order
+    /// is paid
+    BestPriceAfterFees,
+}
+
+impl FromStr for RoutingPolicy {
+    type Err = String;
+
+    /// Accepts the variant name in any case, with or without underscores
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name_key(name).as_str() {
+            "primary" => Ok(RoutingPolicy::Primary),
+            "bestprice" => Ok(RoutingPolicy::BestPrice),
+            "bestpriceafterfees" => Ok(RoutingPolicy::BestPriceAfterFees),
+            _ => Err(format!("Unknown routing policy: {}", name)),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct OrderRouter {
-    // Since we only have CryptoExchange implemented for now, use concrete types
-    exchanges: Arc<RwLock<HashMap<String, CryptoExchange>>>,
+    exchanges: Arc<RwLock<HashMap<String, Arc<dyn Exchange>>>>,
     primary_exchange_map: Arc<RwLock<HashMap<String, String>>>, // Maps asset to primary exchange
+    fallback_chains: Arc<RwLock<HashMap<String, Vec<String>>>>, // Exchanges tried in turn, by asset, when the first choice is unavailable
+    default_exchange: Arc<RwLock<Option<String>>>, // For orders whose symbol has no primary exchange
+    order_exchanges: Arc<RwLock<HashMap<Uuid, String>>>, // Maps submitted order to its exchange
+    circuit_breakers: Arc<RwLock<HashMap<String, ExchangeCircuitBreaker>>>, // By exchange, tripped by failed submissions
+    circuit_breaker_config: Arc<RwLock<CircuitBreakerConfig>>,
+    clock: Arc<std::sync::RwLock<Arc<dyn Clock>>>, // Times how long circuit breakers stay open
+    symbol_normalizer: Arc<std::sync::RwLock<Arc<SymbolNormalizer>>>, // Turns canonical symbols into each exchange's own
+    routing_policy: Arc<RwLock<RoutingPolicy>>,
+}
+
+impl Default for OrderRouter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[allow(dead_code, unused_variables)]
@@ -21,24 +77,78 @@ impl OrderRouter {
         OrderRouter {
             exchanges: Arc::new(RwLock::new(HashMap::new())),
             primary_exchange_map: Arc::new(RwLock::new(HashMap::new())),
+            fallback_chains: Arc::new(RwLock::new(HashMap::new())),
+            default_exchange: Arc::new(RwLock::new(None)),
+            order_exchanges: Arc::new(RwLock::new(HashMap::new())),
+            circuit_breakers: Arc::new(RwLock::new(HashMap::new())),
+            circuit_breaker_config: Arc::new(RwLock::new(CircuitBreakerConfig::default())),
+            clock: Arc::new(std::sync::RwLock::new(Arc::new(SystemClock))),
+            symbol_normalizer: Arc::new(std::sync::RwLock::new(Arc::new(SymbolNormalizer::new()))),
+            routing_policy: Arc::new(RwLock::new(RoutingPolicy::default())),
         }
     }
     
-    // Register exchange with concrete type
-    pub async fn register_exchange(&self, exchange: CryptoExchange) -> Result<(), String> {
+    // Register any exchange implementation, including a ConnectionPool
+    pub async fn register_exchange(&self, exchange: Box<dyn Exchange>) -> Result<(), TradingError> {
+        self.register_shared_exchange(Arc::from(exchange)).await
+    }
+    
+    /// Register an exchange also held elsewhere, such as by the `ExchangeManager`
+    pub async fn register_shared_exchange(&self, exchange: Arc<dyn Exchange>) -> Result<(), TradingError> {
         let name = exchange.name().to_string();
         info!("Registering exchange: {}", name);
         
         let mut exchanges = self.exchanges.write().await;
         if exchanges.contains_key(&name) {
-            return Err(format!("Exchange {} already registered", name));
+            return Err(TradingError::Conflict(format!("Exchange {} already registered", name)));
         }
         
+        let config = *self.circuit_breaker_config.read().await;
+        self.circuit_breakers.write().await.insert(name.clone(), self.circuit_breaker(config));
         exchanges.insert(name, exchange);
         Ok(())
     }
     
-    pub async fn set_primary_exchange(&self, asset: &str, exchange: &str) -> Result<(), String> {
+    /// Thresholds for every exchange's circuit breaker. Breakers start over,
+    /// closed, under the new config.
+    pub async fn set_circuit_breaker_config(&self, config: CircuitBreakerConfig) {
+        *self.circuit_breaker_config.write().await = config;
+        let mut circuit_breakers = self.circuit_breakers.write().await;
+        for breaker in circuit_breakers.values_mut() {
+            *breaker = self.circuit_breaker(config);
+        }
+    }
+    
+    /// Time circuit breakers on `clock`. Breakers start over, closed, on it.
+    pub async fn set_clock(&self, clock: Arc<dyn Clock>) {
+        *self.clock.write().unwrap() = clock;
+        let config = *self.circuit_breaker_config.read().await;
+        self.set_circuit_breaker_config(config).await;
+    }
+    
+    fn circuit_breaker(&self, config: CircuitBreakerConfig) -> ExchangeCircuitBreaker {
+        ExchangeCircuitBreaker::with_clock(config, self.clock.read().unwrap().clone())
+    }
+    
+    /// Normalizer translating order symbols into the form each exchange expects
+    pub fn set_symbol_normalizer(&self, normalizer: Arc<SymbolNormalizer>) {
+        *self.symbol_normalizer.write().unwrap() = normalizer;
+    }
+    
+    // How `exchange_name` writes a canonical symbol
+    fn exchange_symbol(&self, exchange_name: &str, symbol: &str) -> String {
+        self.symbol_normalizer.read().unwrap()
+            .from_canonical(exchange_name, symbol)
+            .unwrap_or_else(|| symbol.to_string())
+    }
+    
+    /// State of a registered exchange's circuit breaker
+    pub async fn circuit_state(&self, exchange: &str) -> Option<CircuitState> {
+        let mut circuit_breakers = self.circuit_breakers.write().await;
+        circuit_breakers.get_mut(exchange).map(ExchangeCircuitBreaker::state)
+    }
+    
+    pub async fn set_primary_exchange(&self, asset: &str, exchange: &str) -> Result<(), TradingError> {
         let mut primary_map = self.primary_exchange_map.write().await;
         primary_map.insert(asset.to_string(), exchange.to_string());
         
@@ -46,52 +156,353 @@ impl OrderRouter {
         Ok(())
     }
     
-    pub async fn submit_order(&self, order: Order) -> Result<(), String> {
-        // Determine the exchange to use
-        let exchange_name = if !order.exchange.is_empty() {
-            // Use specified exchange
-            order.exchange.clone()
+    /// Exchange for orders that name none and whose symbol has no primary
+    /// exchange, provided it lists the symbol among its supported assets
+    pub async fn set_default_exchange(&self, exchange: &str) -> Result<(), TradingError> {
+        if !self.exchanges.read().await.contains_key(exchange) {
+            return Err(TradingError::NotFound(format!("Exchange {} not found", exchange)));
+        }
+        
+        *self.default_exchange.write().await = Some(exchange.to_string());
+        info!("Set default exchange: {}", exchange);
+        Ok(())
+    }
+    
+    pub async fn get_default_exchange(&self) -> Option<String> {
+        self.default_exchange.read().await.clone()
+    }
+    
+    /// How orders that name no exchange pick one. Under a best-price policy,
+    /// primary and default exchanges only apply when no exchange quotes the
+    /// symbol.
+    pub async fn set_routing_policy(&self, policy: RoutingPolicy) {
+        *self.routing_policy.write().await = policy;
+        info!("Set routing policy: {:?}", policy);
+    }
+    
+    pub async fn routing_policy(&self) -> RoutingPolicy {
+        *self.routing_policy.read().await
+    }
+    
+    /// Exchanges to try in order for `asset` when the exchange an order names,
+    /// or the asset's primary, is missing, disconnected or fails to take it.
+    /// An empty chain removes fallback for the asset.
+    pub async fn set_fallback_chain(&self, asset: &str, exchanges: Vec<String>) -> Result<(), TradingError> {
+        if let Some(duplicate) = exchanges.iter().enumerate().find_map(|(i, name)| exchanges[..i].contains(name).then_some(name)) {
+            return Err(TradingError::Validation(format!("Exchange {} appears twice in the fallback chain for {}", duplicate, asset)));
+        }
+        
+        let mut fallback_chains = self.fallback_chains.write().await;
+        if exchanges.is_empty() {
+            fallback_chains.remove(asset);
+            info!("Cleared fallback chain for {}", asset);
         } else {
-            // Use primary exchange for this asset
-            let primary_map = self.primary_exchange_map.read().await;
-            match primary_map.get(&order.symbol) {
-                Some(name) => name.clone(),
-                None => return Err(format!("No primary exchange defined for {}", order.symbol)),
+            info!("Set fallback chain for {}: {}", asset, exchanges.join(" -> "));
+            fallback_chains.insert(asset.to_string(), exchanges);
+        }
+        Ok(())
+    }
+    
+    pub async fn get_fallback_chain(&self, asset: &str) -> Vec<String> {
+        let fallback_chains = self.fallback_chains.read().await;
+        fallback_chains.get(asset).cloned().unwrap_or_default()
+    }
+    
+    /// Submit an order and return the name of the exchange that took it. When
+    /// the first choice is unavailable, including when its circuit breaker is
+    /// open, the asset's fallback chain is tried in turn; an exchange
+    /// rejecting the order ends routing, since the order itself is at fault.
+    pub async fn submit_order(&self, order: Order) -> Result<String, TradingError> {
+        let candidates = self.candidate_exchanges(&order).await?;
+        
+        let order_id = order.id;
+        let mut failures = Vec::new();
+        let mut open_circuits = Vec::new();
+        for (attempt, exchange_name) in candidates.iter().enumerate() {
+            if attempt > 0 {
+                info!("Routing order {} to fallback exchange {} (attempt {} of {})", order_id, exchange_name, attempt + 1, candidates.len());
+            }
+            
+            match self.submit_to(exchange_name, order.clone()).await {
+                Ok(()) => {
+                    // Remember where the order went so its status can be polled
+                    let mut order_exchanges = self.order_exchanges.write().await;
+                    order_exchanges.insert(order_id, exchange_name.to_string());
+                    return Ok(exchange_name.to_string());
+                },
+                Err(e) if rejection_reason(&e).is_some() => return Err(e),
+                Err(e) => {
+                    warn!("Could not route order {} to {}: {}", order_id, exchange_name, e);
+                    if e == circuit_open_error(exchange_name) {
+                        open_circuits.push(exchange_name.as_str());
+                    }
+                    failures.push(e);
+                },
             }
+        }
+        
+        if open_circuits.len() == candidates.len() {
+            Err(TradingError::Unavailable(format!("All exchanges for {} are unavailable: circuit breaker open for {}", order.symbol, open_circuits.join(", "))))
+        } else if failures.len() == 1 {
+            Err(failures.remove(0))
+        } else {
+            let failures: Vec<String> = failures.iter().map(ToString::to_string).collect();
+            Err(TradingError::Unavailable(format!("No exchange took order {}: {}", order_id, failures.join("; "))))
+        }
+    }
+    
+    // The exchange the order is routed to first, followed by the symbol's
+    // fallback chain. Fails when there is none of either.
+    async fn candidate_exchanges(&self, order: &Order) -> Result<Vec<String>, TradingError> {
+        let first_choice = self.first_choice(order).await;
+        let fallback_chain = self.get_fallback_chain(&order.symbol).await;
+        let mut candidates = match first_choice {
+            Ok(exchange_name) => vec![exchange_name],
+            Err(e) if fallback_chain.is_empty() => return Err(e),
+            Err(_) => Vec::new(),
         };
+        for fallback in fallback_chain {
+            if !candidates.contains(&fallback) {
+                candidates.push(fallback);
+            }
+        }
+        Ok(candidates)
+    }
+    
+    // The exchange the order names, or else its symbol's primary, or else the
+    // default exchange if it supports the symbol
+    async fn first_choice(&self, order: &Order) -> Result<String, TradingError> {
+        if !order.exchange.is_empty() {
+            return Ok(order.exchange.clone());
+        }
+        let policy = self.routing_policy().await;
+        if policy != RoutingPolicy::Primary {
+            if let Some(best) = self.best_priced_exchange(order, policy == RoutingPolicy::BestPriceAfterFees).await {
+                return Ok(best);
+            }
+        }
+        if let Some(primary) = self.get_exchange_for_asset(&order.symbol).await {
+            return Ok(primary);
+        }
         
-        // Get the exchange
-        let exchanges = self.exchanges.read().await;
-        let exchange = exchanges.get(&exchange_name)
-            .ok_or_else(|| format!("Exchange {} not found", exchange_name))?;
+        let Some(default_exchange) = self.get_default_exchange().await else {
+            return Err(TradingError::Validation(format!("No primary exchange defined for {}", order.symbol)));
+        };
+        if self.exchange_supports(&default_exchange, &order.symbol).await? {
+            Ok(default_exchange)
+        } else {
+            Err(TradingError::Validation(format!("No primary exchange defined for {} and default exchange {} does not support it", order.symbol, default_exchange)))
+        }
+    }
+    
+    // The connected exchange listing the order's symbol whose quote, after
+    // fees when asked, is best for the order. Exchanges with their circuit
+    // breaker open or that cannot quote the symbol are passed over; ties go
+    // to the exchange first by name.
+    async fn best_priced_exchange(&self, order: &Order, include_fees: bool) -> Option<String> {
+        let mut exchanges: Vec<(String, Arc<dyn Exchange>)> = self.exchanges.read().await
+            .iter()
+            .filter(|(_, exchange)| exchange.is_connected())
+            .map(|(name, exchange)| (name.clone(), exchange.clone()))
+            .collect();
+        exchanges.sort_by(|a, b| a.0.cmp(&b.0));
         
-        // Submit the order
-        exchange.submit_order(order).await
+        let mut best: Option<(String, f64)> = None;
+        for (name, exchange) in exchanges {
+            if matches!(self.circuit_state(&name).await, Some(CircuitState::Open { .. })) {
+                continue;
+            }
+            if !self.exchange_supports(&name, &order.symbol).await.unwrap_or(false) {
+                continue;
+            }
+            let snapshot = match exchange.get_market_data(&self.exchange_symbol(&name, &order.symbol)).await {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    debug!("No quote for {} from {}: {}", order.symbol, name, e);
+                    continue;
+                }
+            };
+            let quoted = match order.direction {
+                TradeDirection::Buy => snapshot.ask.to_f64(),
+                TradeDirection::Sell => snapshot.bid.to_f64(),
+            };
+            if !(quoted.is_finite() && quoted > 0.0) {
+                continue;
+            }
+            let price = if include_fees {
+                match exchange.get_fee_schedule().await {
+                    Ok(fees) => fees.price_after_fees(order, quoted),
+                    Err(e) => {
+                        debug!("No fee schedule from {}: {}", name, e);
+                        continue;
+                    }
+                }
+            } else {
+                quoted
+            };
+            
+            let better = best.as_ref().is_none_or(|(_, best_price)| match order.direction {
+                TradeDirection::Buy => price < *best_price,
+                TradeDirection::Sell => price > *best_price,
+            });
+            if better {
+                best = Some((name, price));
+            }
+        }
+        
+        best.map(|(name, price)| {
+            debug!("Best price for {:?} {} is {} on {}", order.direction, order.symbol, price, name);
+            name
+        })
     }
     
-    pub async fn cancel_order(&self, order_id: Uuid) -> Result<(), String> {
-        // We need to try all exchanges since we don't know which one has the order
+    /// Fail when no registered exchange the order could be routed to supports
+    /// its type. Exchanges that are not registered are left for submission
+    /// to report, so an order with none registered passes.
+    pub async fn check_order_type(&self, order: &Order) -> Result<(), TradingError> {
+        let candidates = self.candidate_exchanges(order).await.unwrap_or_default();
         let exchanges = self.exchanges.read().await;
-        if exchanges.is_empty() {
-            return Err("No exchanges registered for cancellation".to_string());
+        
+        let mut unsupported = Vec::new();
+        for exchange in candidates.iter().filter_map(|name| exchanges.get(name)) {
+            let supported = exchange.supported_order_types();
+            if supported.contains(&order.order_type) {
+                return Ok(());
+            }
+            let supported: Vec<String> = supported.iter().map(ToString::to_string).collect();
+            unsupported.push(format!("{} (supports {})", exchange.name(), supported.join(", ")));
         }
         
-        // Try each exchange
-        for (name, exchange) in exchanges.iter() {
-            match exchange.cancel_order(order_id).await {
-                Ok(_) => {
-                    info!("Order {} cancelled on {}", order_id, name);
-                    return Ok(());
-                }
-                Err(_) => {
-                    // This exchange doesn't have the order, try the next one
-                    continue;
-                }
+        if unsupported.is_empty() {
+            Ok(())
+        } else {
+            Err(TradingError::Validation(format!("{} orders are not supported by {}", order.order_type, unsupported.join(" or "))))
+        }
+    }
+    
+    // Hand an order to one exchange, failing if it is missing, disconnected,
+    // does not support the order's type or has its circuit breaker open. The
+    // outcome of the submission itself feeds the breaker; a rejection counts
+    // as a success, as the exchange answered. The order goes out under the
+    // exchange's own symbol.
+    async fn submit_to(&self, exchange_name: &str, mut order: Order) -> Result<(), TradingError> {
+        let exchange = {
+            let exchanges = self.exchanges.read().await;
+            exchanges.get(exchange_name).cloned()
+                .ok_or_else(|| TradingError::NotFound(format!("Exchange {} not found", exchange_name)))?
+        };
+        if !exchange.is_connected() {
+            return Err(TradingError::NotConnected(exchange_name.to_string()));
+        }
+        if !exchange.supported_order_types().contains(&order.order_type) {
+            return Err(TradingError::Validation(format!("Exchange {} does not support {} orders", exchange_name, order.order_type)));
+        }
+        
+        let allowed = self.circuit_breakers.write().await.get_mut(exchange_name)
+            .map(ExchangeCircuitBreaker::allow_request)
+            .unwrap_or(true);
+        if !allowed {
+            return Err(circuit_open_error(exchange_name));
+        }
+        
+        order.symbol = self.exchange_symbol(exchange_name, &order.symbol);
+        let result = exchange.submit_order(order).await;
+        if let Some(breaker) = self.circuit_breakers.write().await.get_mut(exchange_name) {
+            match &result {
+                Err(e) if rejection_reason(e).is_none() => breaker.record_failure(),
+                _ => breaker.record_success(),
             }
         }
+        result
+    }
+    
+    /// Ask the exchange an order was submitted to for its current status
+    pub async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
+        self.exchange_for_order(order_id).await?.get_order_status(order_id).await
+    }
+    
+    /// Ask the exchange an order was submitted to to lower its quantity
+    pub async fn amend_order(&self, order_id: Uuid, new_quantity: f64) -> Result<(), TradingError> {
+        self.exchange_for_order(order_id).await?.amend_order(order_id, new_quantity).await
+    }
+    
+    /// Spawn a task that polls the owning exchange for the order's status and emits
+    /// an update event each time the status or filled quantity changes, until the
+    /// order reaches a terminal state.
+    pub async fn spawn_status_poller(
+        &self,
+        order_id: Uuid,
+        event_sender: EventSender<OrderEvent>,
+        interval: Duration,
+    ) -> Result<JoinHandle<()>, TradingError> {
+        let exchange = self.exchange_for_order(order_id).await?;
         
-        // If we get here, no exchange could cancel the order
-        Err(format!("Order {} not found on any registered exchange", order_id))
+        Ok(tokio::spawn(async move {
+            poll_until_terminal(exchange.as_ref(), order_id, event_sender, interval).await;
+        }))
+    }
+    
+    // The exchange an order was submitted to
+    async fn exchange_for_order(&self, order_id: Uuid) -> Result<Arc<dyn Exchange>, TradingError> {
+        let exchange_name = {
+            let order_exchanges = self.order_exchanges.read().await;
+            order_exchanges.get(&order_id).cloned()
+                .ok_or_else(|| TradingError::NotFound(format!("Order {} was not submitted through this router", order_id)))?
+        };
+        
+        let exchanges = self.exchanges.read().await;
+        exchanges.get(&exchange_name).cloned()
+            .ok_or_else(|| TradingError::NotFound(format!("Exchange {} not found", exchange_name)))
+    }
+    
+    /// Ask the exchange an order was submitted to to cancel it, passing up
+    /// that exchange's error unchanged
+    pub async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError> {
+        let exchange = self.exchange_for_order(order_id).await?;
+        let result = exchange.cancel_order(order_id).await?;
+        info!("Order {} cancelled on {}, {} left unfilled", order_id, exchange.name(), result.unfilled_quantity);
+        Ok(result)
+    }
+    
+    /// Latest market snapshot for the order's symbol from the exchange it
+    /// names, or else the symbol's primary exchange
+    pub async fn get_market_data(&self, order: &Order) -> Result<MarketSnapshot, TradingError> {
+        let exchange = self.routed_exchange(order).await?;
+        let symbol = self.exchange_symbol(exchange.name(), &order.symbol);
+        exchange.get_market_data(&symbol).await
+    }
+    
+    /// Margin for the order's symbol on the exchange it would be routed to,
+    /// or `None` when that exchange does not trade on margin or the order has
+    /// no exchange to route to. Errors from the exchange itself are passed up.
+    pub async fn get_margin_info(&self, order: &Order) -> Result<Option<MarginInfo>, TradingError> {
+        let exchange = match self.routed_exchange(order).await {
+            Ok(exchange) => exchange,
+            Err(e) => {
+                debug!("No exchange to check {} margin on: {}", order.symbol, e);
+                return Ok(None);
+            },
+        };
+        let symbol = self.exchange_symbol(exchange.name(), &order.symbol);
+        exchange.get_margin_info(&symbol).await
+    }
+    
+    // The exchange the order names, or else the one the routing policy picks
+    async fn routed_exchange(&self, order: &Order) -> Result<Arc<dyn Exchange>, TradingError> {
+        let exchange_name = self.first_choice(order).await?;
+        let exchanges = self.exchanges.read().await;
+        exchanges.get(&exchange_name).cloned()
+            .ok_or_else(|| TradingError::NotFound(format!("Exchange {} not found", exchange_name)))
+    }
+    
+    /// Whether the registered exchange lists `symbol`, under its canonical
+    /// name or the exchange's own
+    pub async fn exchange_supports(&self, exchange_name: &str, symbol: &str) -> Result<bool, TradingError> {
+        let exchange = self.exchanges.read().await.get(exchange_name).cloned()
+            .ok_or_else(|| TradingError::NotFound(format!("Exchange {} not found", exchange_name)))?;
+        let assets = exchange.get_supported_assets().await?;
+        let exchange_symbol = self.exchange_symbol(exchange_name, symbol);
+        Ok(assets.iter().any(|asset| asset == symbol || *asset == exchange_symbol))
     }
     
     pub async fn get_exchange_for_asset(&self, symbol: &str) -> Option<String> {
@@ -120,4 +531,62 @@ impl OrderRouter {
         
         assets
     }
-} 
\ No newline at end of file
+} 
+
+fn circuit_open_error(exchange_name: &str) -> TradingError {
+    TradingError::Unavailable(format!("Circuit breaker for {} is open", exchange_name))
+}
+
+/// Poll `exchange` for the status of `order_id` every `interval`, emitting an
+/// `OrderEvent::Update` whenever the derived status or cumulative filled quantity
+/// changes. Returns once the order is terminal, the receiver is dropped, or the
+/// exchange fails to answer `MAX_CONSECUTIVE_POLL_FAILURES` times in a row.
+pub async fn poll_until_terminal<E: Exchange + ?Sized>(
+    exchange: &E,
+    order_id: Uuid,
+    event_sender: EventSender<OrderEvent>,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    let mut last_reported: Option<(Option<OrderStatus>, f64)> = None;
+    let mut consecutive_failures = 0;
+    
+    loop {
+        ticker.tick().await;
+        
+        let response = match exchange.get_order_status(order_id).await {
+            Ok(response) => {
+                consecutive_failures = 0;
+                response
+            },
+            Err(e) => {
+                consecutive_failures += 1;
+                warn!("Failed to poll status for order {} on {}: {}", order_id, exchange.name(), e);
+                if consecutive_failures >= MAX_CONSECUTIVE_POLL_FAILURES {
+                    warn!("Giving up polling order {} after {} failures", order_id, consecutive_failures);
+                    return;
+                }
+                continue;
+            }
+        };
+        
+        let status = response.derived_status();
+        let current = (status.clone(), response.filled_quantity);
+        if last_reported.as_ref() == Some(&current) {
+            continue;
+        }
+        
+        debug!("Order {} on {}: status={:?}, filled={}, remaining={}",
+            order_id, exchange.name(), status, response.filled_quantity, response.remaining_quantity);
+        
+        if event_sender.send(response.to_order_event()).await.is_err() {
+            debug!("Order event receiver dropped, stopping status polling for {}", order_id);
+            return;
+        }
+        last_reported = Some(current);
+        
+        if status.as_ref().is_some_and(OrderStatus::is_terminal) {
+            return;
+        }
+    }
+}
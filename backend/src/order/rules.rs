@@ -0,0 +1,58 @@
+/// Desk limits on orders in one symbol, on top of the exchange's own lot
+/// sizes. Each limit is off when unset.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolTradingRules {
+    pub min_qty: Option<f64>, // Smallest quantity a single order may have
+    pub max_qty: Option<f64>, // Largest quantity a single order may have
+    pub max_orders_open: Option<usize>, // Open orders allowed at once; new ones are refused at the cap
+}
+
+impl SymbolTradingRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_min_qty(mut self, min_qty: f64) -> Self {
+        self.min_qty = Some(min_qty);
+        self
+    }
+
+    pub fn with_max_qty(mut self, max_qty: f64) -> Self {
+        self.max_qty = Some(max_qty);
+        self
+    }
+
+    pub fn with_max_orders_open(mut self, max_orders_open: usize) -> Self {
+        self.max_orders_open = Some(max_orders_open);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, limit) in [("min_qty", self.min_qty), ("max_qty", self.max_qty)] {
+            if limit.is_some_and(|qty| !qty.is_finite() || qty <= 0.0) {
+                return Err(format!("{} must be positive", name));
+            }
+        }
+        if let (Some(min_qty), Some(max_qty)) = (self.min_qty, self.max_qty) {
+            if min_qty > max_qty {
+                return Err(format!("min_qty ({}) must not exceed max_qty ({})", min_qty, max_qty));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check an order's size, and that `open_orders` already working in the
+    /// symbol leave room for another
+    pub fn check_order(&self, symbol: &str, quantity: f64, open_orders: usize) -> Result<(), String> {
+        if let Some(min_qty) = self.min_qty.filter(|min_qty| quantity < *min_qty) {
+            return Err(format!("Order quantity {} is below the {} minimum of {}", quantity, symbol, min_qty));
+        }
+        if let Some(max_qty) = self.max_qty.filter(|max_qty| quantity > *max_qty) {
+            return Err(format!("Order quantity {} is above the {} maximum of {}", quantity, symbol, max_qty));
+        }
+        if let Some(max_orders_open) = self.max_orders_open.filter(|max_orders_open| open_orders >= *max_orders_open) {
+            return Err(format!("{} already has {} open orders, the most allowed", symbol, max_orders_open));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+
+use super::{Order, OrderStatus, OrderType};
+
+/// Selects which orders from the history are included in a query
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrderHistoryFilter {
+    pub start: Option<DateTime<Utc>>, // Inclusive, compared against created_at
+    pub end: Option<DateTime<Utc>>,   // Inclusive, compared against created_at
+    pub symbols: Vec<String>,         // Empty means all symbols
+}
+
+impl OrderHistoryFilter {
+    pub fn matches(&self, order: &Order) -> bool {
+        if self.start.map(|start| order.created_at < start).unwrap_or(false) {
+            return false;
+        }
+        if self.end.map(|end| order.created_at > end).unwrap_or(false) {
+            return false;
+        }
+        self.symbols.is_empty() || self.symbols.iter().any(|s| s == &order.symbol)
+    }
+}
+
+/// Execution quality metrics over a set of orders
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct OrderStatistics {
+    pub total_orders: usize,
+    pub fill_rate: f64,           // Filled orders / orders that were not cancelled
+    pub avg_fill_latency_ms: f64, // Mean time from creation to fill
+    pub avg_slippage_bps: f64,    // Mean fill price deviation from the limit price, limit orders only
+    pub partial_fill_rate: f64,   // Orders left partially filled / total orders
+    pub rejection_rate: f64,      // Rejected orders / total orders
+}
+
+impl OrderStatistics {
+    pub fn from_orders<'a>(orders: impl IntoIterator<Item = &'a Order>) -> Self {
+        let mut total = 0usize;
+        let mut filled = 0usize;
+        let mut cancelled = 0usize;
+        let mut partially_filled = 0usize;
+        let mut rejected = 0usize;
+        let mut latencies_ms = Vec::new();
+        let mut slippages_bps = Vec::new();
+
+        for order in orders {
+            total += 1;
+
+            match order.status {
+                OrderStatus::Filled => filled += 1,
+                OrderStatus::Cancelled => cancelled += 1,
+                OrderStatus::Rejected => rejected += 1,
+                _ => {}
+            }
+
+            if order.filled_quantity > 0.0 && order.filled_quantity < order.quantity {
+                partially_filled += 1;
+            }
+
+            if let Some(filled_at) = order.filled_at {
+                latencies_ms.push((filled_at - order.created_at).num_milliseconds() as f64);
+            }
+
+            if order.order_type == OrderType::Limit {
+                if let (Some(price), Some(fill_price)) = (order.price, order.average_fill_price) {
+                    if price.is_sign_positive() {
+                        slippages_bps.push((fill_price - price).to_f64().abs() / price.to_f64() * 10000.0);
+                    }
+                }
+            }
+        }
+
+        OrderStatistics {
+            total_orders: total,
+            fill_rate: ratio(filled, total - cancelled),
+            avg_fill_latency_ms: mean(&latencies_ms),
+            avg_slippage_bps: mean(&slippages_bps),
+            partial_fill_rate: ratio(partially_filled, total),
+            rejection_rate: ratio(rejected, total),
+        }
+    }
+}
+
+fn ratio(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
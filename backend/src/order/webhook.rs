@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use super::{Order, OrderEvent, OrderStatus};
+use crate::models::Price;
+use crate::strategy::TradeDirection;
+
+/// Payloads waiting for delivery to one webhook by default, beyond which new ones are dropped
+pub const DEFAULT_WEBHOOK_QUEUE_CAPACITY: usize = 256;
+/// Delivery attempts per payload by default, including the first
+pub const DEFAULT_WEBHOOK_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_WEBHOOK_INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+const DEFAULT_WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Kinds of order event a webhook can be registered for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventKind {
+    Placed,
+    Fill, // A status update reporting a partial or complete fill
+    StatusUpdate, // Any other status update
+    Cancelled,
+    Rejected,
+    Error,
+}
+
+impl WebhookEventKind {
+    pub fn of(event: &OrderEvent) -> Self {
+        match event {
+            OrderEvent::New(_) => WebhookEventKind::Placed,
+            OrderEvent::Update { status: Some(OrderStatus::PartiallyFilled | OrderStatus::Filled), .. } => WebhookEventKind::Fill,
+            OrderEvent::Update { .. } => WebhookEventKind::StatusUpdate,
+            OrderEvent::Cancel { .. } => WebhookEventKind::Cancelled,
+            OrderEvent::Reject { .. } => WebhookEventKind::Rejected,
+            OrderEvent::Error { .. } => WebhookEventKind::Error,
+        }
+    }
+}
+
+/// JSON body POSTed to a webhook for each matching order event. Order fields
+/// describe the order once the event has been applied.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OrderWebhookPayload {
+    pub event: WebhookEventKind,
+    pub order_id: Option<Uuid>,
+    pub client_order_id: Option<String>,
+    pub symbol: Option<String>,
+    pub direction: Option<TradeDirection>,
+    pub status: Option<OrderStatus>,
+    pub quantity: Option<f64>,
+    pub filled_quantity: Option<f64>,
+    pub average_fill_price: Option<Price>,
+    pub strategy_id: Option<String>,
+    pub reason: Option<String>, // Why the order was cancelled or rejected, or the error message
+    pub timestamp: DateTime<Utc>,
+}
+
+impl OrderWebhookPayload {
+    pub fn new(event: &OrderEvent, order: Option<&Order>) -> Self {
+        let reason = match event {
+            OrderEvent::Cancel { reason, .. } | OrderEvent::Reject { reason, .. } => Some(reason.clone()),
+            OrderEvent::Error { message, .. } => Some(message.clone()),
+            _ => None,
+        };
+
+        OrderWebhookPayload {
+            event: WebhookEventKind::of(event),
+            order_id: event.order_id(),
+            client_order_id: order.map(|order| order.client_order_id.clone()),
+            symbol: order.map(|order| order.symbol.clone()),
+            direction: order.map(|order| order.direction),
+            status: order.map(|order| order.status.clone()),
+            quantity: order.map(|order| order.quantity),
+            filled_quantity: order.map(|order| order.filled_quantity),
+            average_fill_price: order.and_then(|order| order.average_fill_price),
+            strategy_id: order.and_then(|order| order.strategy_id.clone()),
+            reason,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Queue size, retry schedule and request timeout of a webhook
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    pub queue_capacity: usize,
+    pub max_attempts: u32,
+    pub initial_backoff: Duration, // Doubles after each failed attempt
+    pub timeout: Duration,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        WebhookConfig {
+            queue_capacity: DEFAULT_WEBHOOK_QUEUE_CAPACITY,
+            max_attempts: DEFAULT_WEBHOOK_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_WEBHOOK_INITIAL_BACKOFF,
+            timeout: DEFAULT_WEBHOOK_TIMEOUT,
+        }
+    }
+}
+
+/// Delivery counts of a webhook since it was registered
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebhookStats {
+    pub delivered: u64,
+    pub failed: u64, // Payloads given up on after every attempt failed
+    pub dropped: u64, // Payloads discarded because the queue was full
+}
+
+#[derive(Default)]
+struct WebhookCounters {
+    delivered: AtomicU64,
+    failed: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl WebhookCounters {
+    fn snapshot(&self) -> WebhookStats {
+        WebhookStats {
+            delivered: self.delivered.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A registered webhook. Events are picked up from the order event broadcast
+/// and queued for a separate delivery task, so a slow endpoint only ever
+/// costs its own payloads and never holds up order processing.
+pub struct OrderWebhook {
+    id: Uuid,
+    counters: Arc<WebhookCounters>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl OrderWebhook {
+    /// Start forwarding matching events from `events_rx` to `url`. Requires a Tokio runtime.
+    pub(crate) fn start(
+        url: &str,
+        events: Vec<WebhookEventKind>,
+        config: WebhookConfig,
+        events_rx: broadcast::Receiver<OrderEvent>,
+        orders: Arc<RwLock<HashMap<Uuid, Order>>>,
+    ) -> Result<Self, String> {
+        let parsed = reqwest::Url::parse(url).map_err(|e| format!("Invalid webhook URL {}: {}", url, e))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(format!("Webhook URL {} must use http or https", url));
+        }
+        if config.max_attempts == 0 {
+            return Err("Webhook max_attempts must be at least 1".to_string());
+        }
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| format!("Could not create webhook client: {}", e))?;
+
+        let counters = Arc::new(WebhookCounters::default());
+        let (queue, pending) = mpsc::channel(config.queue_capacity.max(1));
+        info!("Registered order webhook {} for {:?}", url, events);
+        let collector = tokio::spawn(collect_events(url.to_string(), events, events_rx, orders, queue, counters.clone()));
+        let deliverer = tokio::spawn(deliver_payloads(url.to_string(), client, config, pending, counters.clone()));
+        Ok(OrderWebhook {
+            id: Uuid::new_v4(),
+            counters,
+            tasks: vec![collector, deliverer],
+        })
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn stats(&self) -> WebhookStats {
+        self.counters.snapshot()
+    }
+}
+
+impl Drop for OrderWebhook {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+// Turn matching events into payloads and queue them, dropping what does not fit
+async fn collect_events(
+    url: String,
+    kinds: Vec<WebhookEventKind>,
+    mut events_rx: broadcast::Receiver<OrderEvent>,
+    orders: Arc<RwLock<HashMap<Uuid, Order>>>,
+    queue: mpsc::Sender<OrderWebhookPayload>,
+    counters: Arc<WebhookCounters>,
+) {
+    loop {
+        let event = match events_rx.recv().await {
+            Ok(event) => event,
+            Err(RecvError::Lagged(missed)) => {
+                warn!("Webhook {} fell behind and missed {} order events", url, missed);
+                counters.dropped.fetch_add(missed, Ordering::Relaxed);
+                continue;
+            },
+            Err(RecvError::Closed) => return,
+        };
+        if !kinds.is_empty() && !kinds.contains(&WebhookEventKind::of(&event)) {
+            continue;
+        }
+
+        let order = match event.order_id() {
+            Some(order_id) => orders.read().await.get(&order_id).cloned(),
+            None => None,
+        };
+        let payload = OrderWebhookPayload::new(&event, order.as_ref());
+        if queue.try_send(payload).is_err() {
+            let dropped = counters.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+            warn!("Webhook {} queue full, {} payloads dropped so far", url, dropped);
+        }
+    }
+}
+
+// POST each queued payload, retrying with backoff, and count the outcome
+async fn deliver_payloads(
+    url: String,
+    client: reqwest::Client,
+    config: WebhookConfig,
+    mut pending: mpsc::Receiver<OrderWebhookPayload>,
+    counters: Arc<WebhookCounters>,
+) {
+    while let Some(payload) = pending.recv().await {
+        let mut backoff = config.initial_backoff;
+        for attempt in 1..=config.max_attempts {
+            match post(&client, &url, &payload).await {
+                Ok(()) => {
+                    debug!("Delivered {:?} webhook for order {:?} to {}", payload.event, payload.order_id, url);
+                    counters.delivered.fetch_add(1, Ordering::Relaxed);
+                    break;
+                },
+                Err(e) if attempt < config.max_attempts => {
+                    warn!("Webhook attempt {} of {} failed: {}", attempt, config.max_attempts, e);
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                },
+                Err(e) => {
+                    warn!("Giving up on {:?} webhook for order {:?}: {}", payload.event, payload.order_id, e);
+                    counters.failed.fetch_add(1, Ordering::Relaxed);
+                },
+            }
+        }
+    }
+}
+
+async fn post(client: &reqwest::Client, url: &str, payload: &OrderWebhookPayload) -> Result<(), String> {
+    let response = client.post(url)
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| format!("Webhook request to {} failed: {}", url, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Webhook {} returned {}", url, response.status()));
+    }
+    Ok(())
+}
@@ -0,0 +1,368 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+use tracing::info;
+use utoipa::ToSchema;
+
+use crate::exchange::Position;
+use crate::risk::{VarCalculator, VarMethod};
+use crate::strategy::{MarketData, TradeDirection};
+
+/// Number of daily returns kept for risk calculations (about four trading years)
+pub const MAX_RETURN_HISTORY: usize = 1000;
+
+/// Number of closed positions kept for inspection. P&L attribution keeps
+/// running totals, so it still covers positions dropped from this history.
+pub const MAX_CLOSED_POSITIONS: usize = 1000;
+
+/// P&L attributed to one strategy, or to all of them combined
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct StrategyPnl {
+    pub realized_pnl: f64, // Closed positions, plus reductions of positions still open
+    pub unrealized_pnl: f64, // Open positions at their last price
+    pub trade_count: usize, // Positions taken from flat back to flat
+    pub win_count: usize, // Closed positions that made money
+    #[serde(rename = "avg_holding_duration_secs", with = "duration_secs")]
+    #[schema(value_type = f64)]
+    pub avg_holding_duration: Duration, // Of closed positions whose opening time is known
+}
+
+impl StrategyPnl {
+    // Attribution over closed position totals and the given open positions
+    fn from_positions<'a>(closed: &ClosedTotals, open: impl IntoIterator<Item = &'a Position>) -> Self {
+        let mut pnl = StrategyPnl {
+            realized_pnl: closed.realized_pnl,
+            trade_count: closed.trade_count,
+            win_count: closed.win_count,
+            ..StrategyPnl::default()
+        };
+        for position in open {
+            pnl.realized_pnl += position.realized_pnl;
+            pnl.unrealized_pnl += position.unrealized_pnl;
+        }
+        if closed.timed > 0 {
+            pnl.avg_holding_duration = closed.held / closed.timed;
+        }
+        pnl
+    }
+}
+
+// Running totals over closed positions, kept as they close
+#[derive(Debug, Clone, Default)]
+struct ClosedTotals {
+    realized_pnl: f64,
+    trade_count: usize,
+    win_count: usize,
+    held: Duration, // Summed holding time of those whose opening time is known
+    timed: u32,
+}
+
+impl ClosedTotals {
+    fn record(&mut self, position: &Position) {
+        self.realized_pnl += position.realized_pnl;
+        self.trade_count += 1;
+        if position.realized_pnl > 0.0 {
+            self.win_count += 1;
+        }
+        if let Some(holding) = position.opened_at.and_then(|opened_at| (position.timestamp - opened_at).to_std().ok()) {
+            self.held += holding;
+            self.timed += 1;
+        }
+    }
+}
+
+// Positions closed out by fills: the most recent as they stood when flat,
+// and totals over all of them
+#[derive(Debug, Default)]
+struct ClosedPositions {
+    recent: VecDeque<Position>, // Oldest first, at most MAX_CLOSED_POSITIONS
+    closes: usize, // Positions closed since the manager was created
+    combined: ClosedTotals,
+    by_strategy: HashMap<String, ClosedTotals>,
+}
+
+impl ClosedPositions {
+    fn push(&mut self, position: Position) {
+        self.combined.record(&position);
+        if let Some(strategy_id) = &position.strategy_id {
+            self.by_strategy.entry(strategy_id.clone()).or_default().record(&position);
+        }
+        self.closes += 1;
+        self.recent.push_back(position);
+        while self.recent.len() > MAX_CLOSED_POSITIONS {
+            self.recent.pop_front();
+        }
+    }
+}
+
+/// Realized and unrealized P&L over every position, closed and open
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AccountPnl {
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+    pub total_pnl: f64,
+    pub stale_symbols: Vec<String>, // Open positions still carrying their last mark, sorted
+}
+
+// Durations as fractional seconds on the wire
+mod duration_secs {
+    use std::time::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let secs = f64::deserialize(deserializer)?;
+        Duration::try_from_secs_f64(secs).map_err(serde::de::Error::custom)
+    }
+}
+
+// Position Manager tracks open positions and portfolio return history
+#[allow(dead_code)]
+pub struct PositionManager {
+    positions: Arc<RwLock<HashMap<String, Position>>>,
+    daily_returns: Arc<RwLock<VecDeque<f64>>>, // Daily portfolio P&L as a fraction of portfolio value
+    closed_positions: Arc<RwLock<ClosedPositions>>,
+    stale_marks: Arc<RwLock<HashSet<String>>>, // Open positions the last mark to market had no price for
+}
+
+impl Default for PositionManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PositionManager {
+    pub fn new() -> Self {
+        PositionManager {
+            positions: Arc::new(RwLock::new(HashMap::new())),
+            daily_returns: Arc::new(RwLock::new(VecDeque::new())),
+            closed_positions: Arc::new(RwLock::new(ClosedPositions::default())),
+            stale_marks: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+    
+    pub async fn update_position(&self, position: Position) {
+        self.stale_marks.write().await.remove(&position.symbol);
+        let mut positions = self.positions.write().await;
+        if position.quantity == 0.0 {
+            positions.remove(&position.symbol);
+        } else {
+            positions.insert(position.symbol.clone(), position);
+        }
+    }
+    
+    /// Apply an execution to the position in `symbol`. Fills in the direction of
+    /// the position average into its price; fills against it realize P&L, and a
+    /// fill past flat opens the other side at the fill price.
+    pub async fn apply_fill(&self, symbol: &str, direction: TradeDirection, quantity: f64, price: f64) {
+        self.apply_strategy_fill(symbol, None, direction, quantity, price).await;
+    }
+    
+    /// `apply_fill` for an order placed by `strategy_id`. A fill that opens a
+    /// position from flat attributes the position to its strategy until it is
+    /// closed; later fills in the symbol move that position whoever placed them.
+    pub async fn apply_strategy_fill(&self, symbol: &str, strategy_id: Option<&str>, direction: TradeDirection, quantity: f64, price: f64) {
+        let mut positions = self.positions.write().await;
+        let mut position = positions.remove(symbol).unwrap_or_else(|| Position {
+            symbol: symbol.to_string(),
+            quantity: 0.0,
+            avg_price: price,
+            current_price: price,
+            unrealized_pnl: 0.0,
+            realized_pnl: 0.0,
+            timestamp: Utc::now(),
+            strategy_id: strategy_id.map(str::to_string),
+            opened_at: Some(Utc::now()),
+        });
+        
+        let signed_quantity = match direction {
+            TradeDirection::Buy => quantity,
+            TradeDirection::Sell => -quantity,
+        };
+        
+        if position.quantity == 0.0 || position.quantity.signum() == signed_quantity.signum() {
+            let total = position.quantity.abs() + quantity;
+            position.avg_price = (position.quantity.abs() * position.avg_price + quantity * price) / total;
+        } else {
+            let closed = quantity.min(position.quantity.abs());
+            position.realized_pnl += closed * (price - position.avg_price) * position.quantity.signum();
+            if quantity > position.quantity.abs() {
+                position.avg_price = price;
+            }
+        }
+        
+        position.quantity += signed_quantity;
+        position.current_price = price;
+        position.unrealized_pnl = position.quantity * (price - position.avg_price);
+        position.timestamp = Utc::now();
+        
+        // The fill price is a fresh mark
+        self.stale_marks.write().await.remove(symbol);
+        
+        info!("Position {} now {} @ {:.4} (realized {:.2})", symbol, position.quantity, position.avg_price, position.realized_pnl);
+        if position.quantity != 0.0 {
+            positions.insert(symbol.to_string(), position);
+        } else {
+            self.closed_positions.write().await.push(position);
+        }
+    }
+    
+    /// Reprice every open position at its symbol's price in `market_data`,
+    /// recomputing its unrealized P&L. Positions without a price there keep
+    /// their last mark and are flagged stale until a later mark or fill
+    /// prices them. Returns the stale symbols, sorted.
+    pub async fn mark_to_market(&self, market_data: &MarketData) -> Vec<String> {
+        let mut positions = self.positions.write().await;
+        let mut stale = HashSet::new();
+        for position in positions.values_mut() {
+            match market_data.asset_data.get(&position.symbol).filter(|asset| asset.price.is_sign_positive()) {
+                Some(asset) => {
+                    position.current_price = asset.price.to_f64();
+                    position.unrealized_pnl = position.quantity * (position.current_price - position.avg_price);
+                },
+                None => {
+                    stale.insert(position.symbol.clone());
+                },
+            }
+        }
+        
+        let mut stale_symbols: Vec<String> = stale.iter().cloned().collect();
+        stale_symbols.sort();
+        *self.stale_marks.write().await = stale;
+        stale_symbols
+    }
+    
+    /// Open positions the last mark to market had no price for, sorted
+    pub async fn stale_symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self.stale_marks.read().await.iter().cloned().collect();
+        symbols.sort();
+        symbols
+    }
+    
+    /// Realized P&L of closed and open positions, and unrealized P&L of open
+    /// ones at their last mark
+    pub async fn get_account_pnl(&self) -> AccountPnl {
+        let combined = self.get_combined_pnl().await;
+        AccountPnl {
+            realized_pnl: combined.realized_pnl,
+            unrealized_pnl: combined.unrealized_pnl,
+            total_pnl: combined.realized_pnl + combined.unrealized_pnl,
+            stale_symbols: self.stale_symbols().await,
+        }
+    }
+    
+    /// Positions closed out by fills, oldest first
+    /// The last `MAX_CLOSED_POSITIONS` positions closed, oldest first
+    pub async fn get_closed_positions(&self) -> Vec<Position> {
+        self.closed_positions.read().await.recent.iter().cloned().collect()
+    }
+    
+    /// Positions closed after the first `cursor` closes, with the cursor to
+    /// pass next time to pick up where these leave off. Closes already
+    /// dropped from the history are skipped.
+    pub async fn closed_positions_since(&self, cursor: usize) -> (Vec<Position>, usize) {
+        let closed = self.closed_positions.read().await;
+        let oldest = closed.closes - closed.recent.len();
+        let start = cursor.max(oldest) - oldest;
+        (closed.recent.iter().skip(start).cloned().collect(), closed.closes)
+    }
+    
+    /// P&L of the positions `strategy_id` opened, closed and still open
+    pub async fn get_strategy_pnl(&self, strategy_id: &str) -> StrategyPnl {
+        let positions = self.positions.read().await;
+        let closed = self.closed_positions.read().await;
+        let totals = closed.by_strategy.get(strategy_id).cloned().unwrap_or_default();
+        let open = positions.values().filter(|position| position.strategy_id.as_deref() == Some(strategy_id));
+        StrategyPnl::from_positions(&totals, open)
+    }
+    
+    /// P&L of every strategy with a closed position, by strategy id
+    pub async fn get_pnl_by_strategy(&self) -> HashMap<String, StrategyPnl> {
+        let strategy_ids: Vec<String> = self.closed_positions.read().await.by_strategy.keys().cloned().collect();
+        
+        let mut by_strategy = HashMap::new();
+        for strategy_id in strategy_ids {
+            let pnl = self.get_strategy_pnl(&strategy_id).await;
+            by_strategy.insert(strategy_id, pnl);
+        }
+        by_strategy
+    }
+    
+    /// P&L of all positions, closed and open, whichever strategy placed them
+    pub async fn get_combined_pnl(&self) -> StrategyPnl {
+        let positions = self.positions.read().await;
+        let closed = self.closed_positions.read().await;
+        StrategyPnl::from_positions(&closed.combined, positions.values())
+    }
+    
+    /// Signed quantity held in `symbol`; negative when short
+    pub async fn net_quantity(&self, symbol: &str) -> f64 {
+        let positions = self.positions.read().await;
+        positions.get(symbol).map(|p| p.quantity).unwrap_or(0.0)
+    }
+    
+    pub async fn get_position(&self, symbol: &str) -> Option<Position> {
+        let positions = self.positions.read().await;
+        positions.get(symbol).cloned()
+    }
+    
+    pub async fn get_positions(&self) -> Vec<Position> {
+        let positions = self.positions.read().await;
+        positions.values().cloned().collect()
+    }
+    
+    /// Gross market value of all open positions
+    pub async fn portfolio_value(&self) -> f64 {
+        let positions = self.positions.read().await;
+        positions.values().map(|p| (p.quantity * p.current_price).abs()).sum()
+    }
+    
+    /// Realized plus unrealized P&L, including positions already closed out
+    pub async fn total_pnl(&self) -> f64 {
+        let positions = self.positions.read().await;
+        let open: f64 = positions.values().map(|p| p.realized_pnl + p.unrealized_pnl).sum();
+        open + self.closed_positions.read().await.combined.realized_pnl
+    }
+    
+    pub async fn record_daily_return(&self, daily_return: f64) {
+        let mut returns = self.daily_returns.write().await;
+        returns.push_back(daily_return);
+        while returns.len() > MAX_RETURN_HISTORY {
+            returns.pop_front();
+        }
+    }
+    
+    pub async fn get_daily_returns(&self) -> Vec<f64> {
+        let returns = self.daily_returns.read().await;
+        returns.iter().copied().collect()
+    }
+    
+    /// One-day Value at Risk of the current portfolio, in account currency
+    pub async fn current_portfolio_var(&self, confidence: f64, method: VarMethod) -> Option<f64> {
+        let returns = self.get_daily_returns().await;
+        let value = self.portfolio_value().await;
+        
+        let var = match method {
+            VarMethod::Historical => VarCalculator::historical_var(&returns, confidence).map(|loss| loss * value),
+            VarMethod::Parametric => VarCalculator::parametric_var(&returns, confidence, value),
+        };
+        
+        if let Some(var) = var {
+            info!("Portfolio VaR ({:?}, {:.1}%): {:.2} on {:.2}", method, confidence * 100.0, var, value);
+        }
+        var
+    }
+    
+    /// One-day expected shortfall of the current portfolio, in account currency
+    pub async fn current_portfolio_cvar(&self, confidence: f64) -> Option<f64> {
+        let returns = self.get_daily_returns().await;
+        let value = self.portfolio_value().await;
+        VarCalculator::conditional_var(&returns, confidence).map(|loss| loss * value)
+    }
+}
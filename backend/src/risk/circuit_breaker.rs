@@ -0,0 +1,120 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Serialize, Deserialize};
+use tracing::{error, info, warn};
+use schemars::JsonSchema;
+use utoipa::ToSchema;
+
+/// Point-in-time view of the circuit breaker, for reporting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
+pub struct CircuitBreakerStatus {
+    pub halted: bool,
+    pub intraday_drawdown: f64, // Fraction of today's peak equity, 0.0 to 1.0
+    pub max_intraday_drawdown: f64,
+    pub peak_equity: f64,
+    pub current_equity: f64,
+    pub tripped_at: Option<DateTime<Utc>>,
+    pub last_update: Option<DateTime<Utc>>,
+}
+
+/// Halts trading when equity falls too far below its intraday peak. Equity is
+/// the starting capital plus realized and unrealized P&L. The peak starts at
+/// the capital and restarts at the first update of each later UTC day. Once
+/// tripped the breaker stays halted, across days, until it is reset by hand.
+pub struct CircuitBreaker {
+    max_intraday_drawdown: f64,
+    capital: f64,
+    peak_equity: f64,
+    current_equity: f64,
+    trading_day: Option<NaiveDate>,
+    halted: bool,
+    tripped_at: Option<DateTime<Utc>>,
+    last_update: Option<DateTime<Utc>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(max_intraday_drawdown: f64, capital: f64) -> Result<Self, String> {
+        if max_intraday_drawdown <= 0.0 || max_intraday_drawdown > 1.0 {
+            return Err("Circuit breaker drawdown must be between 0 (exclusive) and 1".to_string());
+        }
+        if capital <= 0.0 {
+            return Err("Circuit breaker capital must be positive".to_string());
+        }
+        Ok(CircuitBreaker {
+            max_intraday_drawdown,
+            capital,
+            peak_equity: capital,
+            current_equity: capital,
+            trading_day: None,
+            halted: false,
+            tripped_at: None,
+            last_update: None,
+        })
+    }
+
+    /// Feed the total P&L across all positions. Returns true when this update
+    /// trips the breaker.
+    pub fn record_pnl(&mut self, pnl: f64, timestamp: DateTime<Utc>) -> bool {
+        if self.last_update.map(|last| timestamp < last).unwrap_or(false) {
+            warn!("Ignoring out-of-order P&L update at {}", timestamp);
+            return false;
+        }
+
+        let equity = self.capital + pnl;
+        // The first day starts from the capital; later days from their first update
+        let day = timestamp.date_naive();
+        if self.trading_day.map(|current| current != day).unwrap_or(false) {
+            self.peak_equity = equity;
+        }
+        self.trading_day = Some(day);
+        self.current_equity = equity;
+        self.peak_equity = self.peak_equity.max(equity);
+        self.last_update = Some(timestamp);
+
+        let drawdown = self.intraday_drawdown();
+        if !self.halted && drawdown > self.max_intraday_drawdown {
+            self.halted = true;
+            self.tripped_at = Some(timestamp);
+            error!("Circuit breaker tripped: intraday drawdown {:.2}% exceeds {:.2}%", drawdown * 100.0, self.max_intraday_drawdown * 100.0);
+            return true;
+        }
+        false
+    }
+
+    /// Resume trading. The current equity becomes the new intraday peak, so
+    /// losses already taken do not trip the breaker again.
+    pub fn reset(&mut self) {
+        if self.halted {
+            info!("Circuit breaker reset at equity {:.2}", self.current_equity);
+        }
+        self.halted = false;
+        self.tripped_at = None;
+        self.peak_equity = self.current_equity;
+    }
+
+    pub fn intraday_drawdown(&self) -> f64 {
+        if self.peak_equity <= 0.0 {
+            return 0.0;
+        }
+        ((self.peak_equity - self.current_equity) / self.peak_equity).max(0.0)
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn max_intraday_drawdown(&self) -> f64 {
+        self.max_intraday_drawdown
+    }
+
+    pub fn status(&self) -> CircuitBreakerStatus {
+        CircuitBreakerStatus {
+            halted: self.halted,
+            intraday_drawdown: self.intraday_drawdown(),
+            max_intraday_drawdown: self.max_intraday_drawdown,
+            peak_equity: self.peak_equity,
+            current_equity: self.current_equity,
+            tripped_at: self.tripped_at,
+            last_update: self.last_update,
+        }
+    }
+}
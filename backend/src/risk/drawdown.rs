@@ -0,0 +1,129 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use tracing::warn;
+use utoipa::ToSchema;
+
+/// Drawdown from the high-water mark above which strategies are paused
+pub const DEFAULT_MAX_DRAWDOWN: f64 = 0.2;
+
+type DrawdownCallback = Box<dyn Fn(f64) + Send + Sync>;
+
+/// Point-in-time view of the drawdown monitor, for reporting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DrawdownSnapshot {
+    pub current_drawdown: f64,  // Fraction of the high-water mark, 0.0 to 1.0
+    pub high_water_mark: f64,
+    pub current_equity: f64,
+    pub max_drawdown_ever: f64,
+    pub threshold: f64,
+    pub last_update: Option<DateTime<Utc>>,
+}
+
+/// Tracks equity against its high-water mark and notifies registered callbacks
+/// when the drawdown exceeds the threshold. Callbacks fire once when a breach
+/// starts and are re-armed once the drawdown recovers to the threshold.
+pub struct DrawdownMonitor {
+    threshold: f64,
+    high_water_mark: f64,
+    current_equity: f64,
+    max_drawdown_ever: f64,
+    last_update: Option<DateTime<Utc>>,
+    in_breach: bool,
+    callbacks: Vec<DrawdownCallback>,
+}
+
+impl Default for DrawdownMonitor {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_DRAWDOWN)
+    }
+}
+
+impl DrawdownMonitor {
+    pub fn new(threshold: f64) -> Self {
+        DrawdownMonitor {
+            threshold,
+            high_water_mark: 0.0,
+            current_equity: 0.0,
+            max_drawdown_ever: 0.0,
+            last_update: None,
+            in_breach: false,
+            callbacks: Vec::new(),
+        }
+    }
+
+    /// Register a callback invoked with the drawdown when a breach starts
+    pub fn on_drawdown(&mut self, callback: impl Fn(f64) + Send + Sync + 'static) {
+        self.callbacks.push(Box::new(callback));
+    }
+
+    pub fn record_equity(&mut self, value: f64, timestamp: DateTime<Utc>) {
+        if self.last_update.map(|last| timestamp < last).unwrap_or(false) {
+            warn!("Ignoring out-of-order equity update at {}", timestamp);
+            return;
+        }
+
+        self.current_equity = value;
+        self.high_water_mark = self.high_water_mark.max(value);
+        self.last_update = Some(timestamp);
+
+        let drawdown = self.current_drawdown();
+        self.max_drawdown_ever = self.max_drawdown_ever.max(drawdown);
+
+        if drawdown > self.threshold {
+            if !self.in_breach {
+                self.in_breach = true;
+                for callback in &self.callbacks {
+                    callback(drawdown);
+                }
+            }
+        } else {
+            self.in_breach = false;
+        }
+    }
+
+    pub fn current_drawdown(&self) -> f64 {
+        if self.high_water_mark <= 0.0 {
+            return 0.0;
+        }
+        ((self.high_water_mark - self.current_equity) / self.high_water_mark).max(0.0)
+    }
+
+    pub fn high_water_mark(&self) -> f64 {
+        self.high_water_mark
+    }
+
+    pub fn current_equity(&self) -> f64 {
+        self.current_equity
+    }
+
+    pub fn max_drawdown_ever(&self) -> f64 {
+        self.max_drawdown_ever
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    pub fn set_threshold(&mut self, threshold: f64) -> Result<(), String> {
+        if threshold <= 0.0 || threshold > 1.0 {
+            return Err("Drawdown threshold must be between 0 (exclusive) and 1".to_string());
+        }
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    pub fn is_in_breach(&self) -> bool {
+        self.in_breach
+    }
+
+    pub fn snapshot(&self) -> DrawdownSnapshot {
+        DrawdownSnapshot {
+            current_drawdown: self.current_drawdown(),
+            high_water_mark: self.high_water_mark,
+            current_equity: self.current_equity,
+            max_drawdown_ever: self.max_drawdown_ever,
+            threshold: self.threshold,
+            last_update: self.last_update,
+        }
+    }
+}
@@ -0,0 +1,179 @@
+// Hedging of open positions with opposing orders on another exchange
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::error::TradingError;
+use crate::market_data::MarketDataManager;
+use crate::order::{Order, OrderManager};
+use crate::strategy::{TimeInForce, TradeDirection, TradeSignal};
+
+/// Strategy id given to the orders a `PositionHedger` places
+pub const HEDGER_STRATEGY_ID: &str = "Position Hedger";
+
+/// When and how a `PositionHedger` hedges on its own
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoHedgeConfig {
+    pub var_threshold: f64, // Portfolio VaR exposure above which positions are hedged
+    pub hedge_ratio: f64,
+    pub hedge_exchange: String,
+}
+
+impl AutoHedgeConfig {
+    pub fn new(var_threshold: f64, hedge_ratio: f64, hedge_exchange: &str) -> Result<Self, String> {
+        if !(var_threshold.is_finite() && var_threshold >= 0.0) {
+            return Err(format!("Auto-hedge VaR threshold must be non-negative, got {}", var_threshold));
+        }
+        if !(hedge_ratio.is_finite() && hedge_ratio > 0.0) {
+            return Err(format!("Auto-hedge ratio must be positive, got {}", hedge_ratio));
+        }
+        if hedge_exchange.is_empty() {
+            return Err("Auto-hedge exchange cannot be empty".to_string());
+        }
+
+        Ok(AutoHedgeConfig {
+            var_threshold,
+            hedge_ratio,
+            hedge_exchange: hedge_exchange.to_string(),
+        })
+    }
+}
+
+/// Places market orders against the net position in a symbol on a chosen
+/// exchange, e.g. shorting BTC on one exchange to offset a long held through
+/// another. Hedge orders go through `OrderManager::place_order`, so they are
+/// subject to the same risk checks as any other order.
+pub struct PositionHedger {
+    order_manager: Arc<RwLock<OrderManager>>,
+    market_data_manager: Arc<RwLock<MarketDataManager>>,
+    auto_hedge: Option<AutoHedgeConfig>,
+    hedge_orders: Mutex<HashMap<String, Uuid>>, // Latest hedge order by symbol
+}
+
+impl PositionHedger {
+    pub fn new(order_manager: Arc<RwLock<OrderManager>>, market_data_manager: Arc<RwLock<MarketDataManager>>) -> Self {
+        PositionHedger {
+            order_manager,
+            market_data_manager,
+            auto_hedge: None,
+            hedge_orders: Mutex::default(),
+        }
+    }
+
+    pub fn with_auto_hedge(mut self, config: AutoHedgeConfig) -> Self {
+        self.auto_hedge = Some(config);
+        self
+    }
+
+    pub fn auto_hedge_config(&self) -> Option<&AutoHedgeConfig> {
+        self.auto_hedge.as_ref()
+    }
+
+    /// Hedge `position_usd * hedge_ratio` worth of `symbol` at its current
+    /// price with a market order on `hedge_exchange`, opposite to the net
+    /// position held. Fails without placing anything when there is no net
+    /// position or price, or the exchange does not list the symbol.
+    pub async fn hedge_position(&self, symbol: &str, position_usd: f64, hedge_ratio: f64, hedge_exchange: &str) -> Result<Uuid, TradingError> {
+        if !(position_usd.is_finite() && position_usd > 0.0) {
+            return Err(TradingError::Validation(format!("Position value to hedge must be positive, got {}", position_usd)));
+        }
+        if !(hedge_ratio.is_finite() && hedge_ratio > 0.0) {
+            return Err(TradingError::Validation(format!("Hedge ratio must be positive, got {}", hedge_ratio)));
+        }
+
+        let order_manager = self.order_manager.read().await;
+        if !order_manager.get_order_router().exchange_supports(hedge_exchange, symbol).await? {
+            return Err(TradingError::Validation(format!("Exchange {} does not support {}", hedge_exchange, symbol)));
+        }
+
+        let position_manager = order_manager.get_position_manager();
+        let net_quantity = position_manager.net_quantity(symbol).await;
+        let direction = if net_quantity > 0.0 {
+            TradeDirection::Sell
+        } else if net_quantity < 0.0 {
+            TradeDirection::Buy
+        } else {
+            return Err(TradingError::Validation(format!("No open position in {} to hedge", symbol)));
+        };
+
+        let price = match self.current_price(symbol).await {
+            Some(price) => price,
+            None => position_manager.get_position(symbol).await
+                .map(|p| p.current_price)
+                .filter(|price| *price > 0.0)
+                .ok_or_else(|| TradingError::Unavailable(format!("No price available for {}", symbol)))?,
+        };
+
+        let signal = TradeSignal {
+            asset: symbol.to_string(),
+            direction,
+            quantity: position_usd * hedge_ratio / price,
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            priority: TimeInForce::Day.default_signal_priority(),
+        };
+        let mut order = Order::from_signal(&signal, HEDGER_STRATEGY_ID);
+        order.exchange = hedge_exchange.to_string();
+
+        let order_id = order_manager.place_order(order).await?;
+        info!("Hedging {:.2} of {} {} with {:?} {} on {} (order {})",
+            position_usd * hedge_ratio, net_quantity, symbol, direction, signal.quantity, hedge_exchange, order_id);
+        self.hedge_orders.lock().unwrap().insert(symbol.to_string(), order_id);
+        Ok(order_id)
+    }
+
+    /// With auto-hedging configured and the portfolio VaR exposure above its
+    /// threshold, hedge every open position at the configured ratio. Symbols
+    /// whose last hedge order is still working are left alone until it is done.
+    /// Returns the outcome for each symbol hedged.
+    pub async fn auto_hedge(&self) -> Vec<(String, Result<Uuid, TradingError>)> {
+        let Some(config) = &self.auto_hedge else {
+            return Vec::new();
+        };
+
+        let (exposure, mut positions) = {
+            let order_manager = self.order_manager.read().await;
+            let exposure = order_manager.portfolio_var_exposure().await;
+            (exposure, order_manager.get_position_manager().get_positions().await)
+        };
+        if exposure <= config.var_threshold {
+            return Vec::new();
+        }
+        warn!("Portfolio VaR exposure {:.2} is above the auto-hedge threshold of {:.2}", exposure, config.var_threshold);
+
+        positions.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        let mut results = Vec::new();
+        for position in positions.into_iter().filter(|p| p.quantity != 0.0) {
+            if self.hedge_in_progress(&position.symbol).await {
+                continue;
+            }
+            let position_usd = (position.quantity * position.current_price).abs();
+            let result = self.hedge_position(&position.symbol, position_usd, config.hedge_ratio, &config.hedge_exchange).await;
+            if let Err(e) = &result {
+                warn!("Could not auto-hedge {}: {}", position.symbol, e);
+            }
+            results.push((position.symbol, result));
+        }
+        results
+    }
+
+    // Whether the last hedge order for the symbol is still working
+    async fn hedge_in_progress(&self, symbol: &str) -> bool {
+        let Some(order_id) = self.hedge_orders.lock().unwrap().get(symbol).copied() else {
+            return false;
+        };
+        let order = self.order_manager.read().await.get_order(order_id).await;
+        order.is_some_and(|order| order.is_active())
+    }
+
+    async fn current_price(&self, symbol: &str) -> Option<f64> {
+        let current_data = self.market_data_manager.read().await.get_current_data();
+        let market_data = current_data.read().await;
+        market_data.asset_data.get(symbol)
+            .map(|asset| asset.price.to_f64())
+            .filter(|price| *price > 0.0)
+    }
+}
@@ -0,0 +1,12 @@
+// Risk management: loss limits and exposure monitoring
+pub mod circuit_breaker;
+pub mod drawdown;
+pub mod hedger;
+pub mod position_sizer;
+pub mod var;
+
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerStatus};
+pub use drawdown::{DrawdownMonitor, DrawdownSnapshot, DEFAULT_MAX_DRAWDOWN};
+pub use hedger::{AutoHedgeConfig, PositionHedger, HEDGER_STRATEGY_ID};
+pub use position_sizer::{FixedFractionalSizer, KellySizer, PositionSizer, TradeStats, DEFAULT_MAX_POSITION_PCT};
+pub use var::{VarCalculator, VarMethod, MIN_VAR_OBSERVATIONS};
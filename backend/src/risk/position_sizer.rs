@@ -0,0 +1,109 @@
+use tracing::warn;
+
+use crate::strategy::TradeSignal;
+
+/// Largest share of the portfolio the Kelly sizer puts into one position
+pub const DEFAULT_MAX_POSITION_PCT: f64 = 0.25;
+
+/// Decides how many dollars to commit to a signal given the strategy's track record
+pub trait PositionSizer: Send + Sync {
+    /// Dollar size of the position. `win_rate` is the fraction of trades won,
+    /// `avg_win` and `avg_loss` the mean profit and loss per trade, both positive.
+    fn compute_size(&self, signal: &TradeSignal, portfolio_value: f64, win_rate: f64, avg_win: f64, avg_loss: f64) -> f64;
+}
+
+/// Sizes positions by the Kelly criterion, scaled down by `fraction` (1.0 for
+/// full Kelly, 0.5 for half Kelly) and capped at `max_position_pct` of the
+/// portfolio. Strategies without a positive edge get no position.
+#[derive(Debug, Clone)]
+pub struct KellySizer {
+    pub fraction: f64,
+    pub max_position_pct: f64,
+}
+
+impl KellySizer {
+    pub fn new(fraction: f64) -> Self {
+        KellySizer {
+            fraction,
+            max_position_pct: DEFAULT_MAX_POSITION_PCT,
+        }
+    }
+
+    /// Full Kelly fraction of equity: (p * W - (1 - p) * L) / W. Returns 0.0
+    /// for invalid inputs.
+    pub fn kelly_fraction(win_rate: f64, avg_win: f64, avg_loss: f64) -> f64 {
+        if !(0.0..=1.0).contains(&win_rate) || avg_win.is_nan() || avg_win <= 0.0 || avg_loss.is_nan() || avg_loss < 0.0 {
+            warn!("Invalid Kelly inputs: win rate {}, average win {}, average loss {}", win_rate, avg_win, avg_loss);
+            return 0.0;
+        }
+        (win_rate * avg_win - (1.0 - win_rate) * avg_loss) / avg_win
+    }
+}
+
+impl PositionSizer for KellySizer {
+    fn compute_size(&self, _signal: &TradeSignal, portfolio_value: f64, win_rate: f64, avg_win: f64, avg_loss: f64) -> f64 {
+        let kelly = Self::kelly_fraction(win_rate, avg_win, avg_loss);
+        // Never more than the whole portfolio, whatever the cap is set to
+        let cap = self.max_position_pct.clamp(0.0, 1.0);
+        (self.fraction * kelly).clamp(0.0, cap) * portfolio_value.max(0.0)
+    }
+}
+
+/// Puts the same fraction of the portfolio into every position, regardless of track record
+#[derive(Debug, Clone)]
+pub struct FixedFractionalSizer {
+    pub fraction: f64,
+}
+
+impl PositionSizer for FixedFractionalSizer {
+    fn compute_size(&self, _signal: &TradeSignal, portfolio_value: f64, _win_rate: f64, _avg_win: f64, _avg_loss: f64) -> f64 {
+        self.fraction.clamp(0.0, 1.0) * portfolio_value.max(0.0)
+    }
+}
+
+/// Running win/loss record of a strategy's closed trades
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TradeStats {
+    pub wins: u64,
+    pub losses: u64,
+    pub total_win: f64,
+    pub total_loss: f64, // Sum of losses, as a positive number
+}
+
+impl TradeStats {
+    /// Add a closed trade's P&L. Breakeven trades count as losses of zero.
+    pub fn record(&mut self, pnl: f64) {
+        if pnl > 0.0 {
+            self.wins += 1;
+            self.total_win += pnl;
+        } else {
+            self.losses += 1;
+            self.total_loss -= pnl;
+        }
+    }
+
+    pub fn trades(&self) -> u64 {
+        self.wins + self.losses
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        match self.trades() {
+            0 => 0.0,
+            trades => self.wins as f64 / trades as f64,
+        }
+    }
+
+    pub fn avg_win(&self) -> f64 {
+        match self.wins {
+            0 => 0.0,
+            wins => self.total_win / wins as f64,
+        }
+    }
+
+    pub fn avg_loss(&self) -> f64 {
+        match self.losses {
+            0 => 0.0,
+            losses => self.total_loss / losses as f64,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use serde::{Serialize, Deserialize};
+use tracing::warn;
+use schemars::JsonSchema;
+use utoipa::ToSchema;
+
+/// Fewest return observations for which VaR is considered meaningful
+pub const MIN_VAR_OBSERVATIONS: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum VarMethod {
+    Historical,
+    Parametric,
+}
+
+/// Value at Risk calculations over a series of periodic returns (fractions, e.g. -0.02
+/// for a 2% loss). Every method reports the loss as a positive number and returns
+/// `None` when there are fewer than `MIN_VAR_OBSERVATIONS` returns or the confidence
+/// is outside (0, 1).
+pub struct VarCalculator;
+
+impl VarCalculator {
+    /// Loss at the `(1 - confidence)` quantile of the observed returns, as a fraction
+    pub fn historical_var(returns: &[f64], confidence: f64) -> Option<f64> {
+        let sorted = sorted_returns(returns, confidence)?;
+        let index = tail_index(sorted.len(), confidence);
+        Some((-sorted[index]).max(0.0))
+    }
+
+    /// Loss on `position_value` assuming normally distributed returns
+    pub fn parametric_var(returns: &[f64], confidence: f64, position_value: f64) -> Option<f64> {
+        if !has_enough_data(returns, confidence) {
+            return None;
+        }
+
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        let z = inverse_normal_cdf(confidence);
+
+        Some((position_value * (z * variance.sqrt() - mean)).max(0.0))
+    }
+
+    /// Expected shortfall: mean loss over the returns at or beyond the historical VaR, as a fraction
+    pub fn conditional_var(returns: &[f64], confidence: f64) -> Option<f64> {
+        let sorted = sorted_returns(returns, confidence)?;
+        let tail = &sorted[..=tail_index(sorted.len(), confidence)];
+        let mean_tail = tail.iter().sum::<f64>() / tail.len() as f64;
+        Some((-mean_tail).max(0.0))
+    }
+}
+
+fn has_enough_data(returns: &[f64], confidence: f64) -> bool {
+    if confidence <= 0.0 || confidence >= 1.0 {
+        warn!("VaR confidence must be between 0 and 1, got {}", confidence);
+        return false;
+    }
+    if returns.len() < MIN_VAR_OBSERVATIONS {
+        warn!("VaR needs at least {} returns, only {} available", MIN_VAR_OBSERVATIONS, returns.len());
+        return false;
+    }
+    true
+}
+
+fn sorted_returns(returns: &[f64], confidence: f64) -> Option<Vec<f64>> {
+    if !has_enough_data(returns, confidence) {
+        return None;
+    }
+    let mut sorted = returns.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    Some(sorted)
+}
+
+// Index of the (1 - confidence) quantile in an ascending series of `len` returns
+fn tail_index(len: usize, confidence: f64) -> usize {
+    (((1.0 - confidence) * len as f64).floor() as usize).min(len - 1)
+}
+
+// Inverse of the standard normal CDF (Acklam's rational approximation,
+// relative error below 1.2e-9)
+fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [-3.969683028665376e1, 2.209460984245205e2, -2.759285104469687e2,
+        1.38357751867269e2, -3.066479806614716e1, 2.506628277459239];
+    const B: [f64; 5] = [-5.447609879822406e1, 1.615858368580409e2, -1.556989798598866e2,
+        6.680131188771972e1, -1.328068155288572e1];
+    const C: [f64; 6] = [-7.784894002430293e-3, -3.223964580411365e-1, -2.400758277161838,
+        -2.549732539343734, 4.374664141464968, 2.938163982698783];
+    const D: [f64; 4] = [7.784695709041462e-3, 3.224671290700398e-1, 2.445134137142996,
+        3.754408661907416];
+    const P_LOW: f64 = 0.02425;
+
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -inverse_normal_cdf(1.0 - p)
+    }
+}
@@ -0,0 +1,161 @@
+// Orderly shutdown on SIGTERM or Ctrl-C: stop trading, cancel what is open at
+// the exchanges and wait for the cancels to land before the process exits
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::market_data::MarketDataManager;
+use crate::order::OrderManager;
+use crate::strategy::StrategyEvaluationScheduler;
+
+/// Time open orders are given to finish by default
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often active orders are checked while draining
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Reason recorded on orders cancelled at shutdown
+pub const SHUTDOWN_CANCEL_REASON: &str = "graceful shutdown";
+
+/// Drains in-flight orders before the process exits. Once a shutdown signal
+/// arrives, scheduled evaluation stops so no new orders are placed, every
+/// active order is cancelled, and the coordinator waits up to the drain
+/// timeout for them all to finish before shutting the managers down.
+pub struct ShutdownCoordinator {
+    order_manager: Arc<RwLock<OrderManager>>,
+    market_data_manager: Arc<RwLock<MarketDataManager>>,
+    scheduler: Option<StrategyEvaluationScheduler>,
+    drain_timeout: Duration,
+}
+
+impl ShutdownCoordinator {
+    pub fn new(order_manager: Arc<RwLock<OrderManager>>, market_data_manager: Arc<RwLock<MarketDataManager>>) -> Self {
+        ShutdownCoordinator {
+            order_manager,
+            market_data_manager,
+            scheduler: None,
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
+        }
+    }
+
+    /// Stop this scheduler before anything is cancelled
+    pub fn with_scheduler(mut self, scheduler: StrategyEvaluationScheduler) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    pub fn with_drain_timeout(mut self, drain_timeout: Duration) -> Self {
+        self.drain_timeout = drain_timeout;
+        self
+    }
+
+    pub fn drain_timeout(&self) -> Duration {
+        self.drain_timeout
+    }
+
+    /// Wait for SIGTERM or Ctrl-C, then shut down. Returns the orders still
+    /// active when the drain timeout ran out.
+    pub async fn run(self) -> Vec<Uuid> {
+        wait_for_signal().await;
+        self.shutdown().await
+    }
+
+    /// Shut down now. Returns the orders still active when the drain timeout
+    /// ran out, each of which is logged as an error.
+    pub async fn shutdown(mut self) -> Vec<Uuid> {
+        info!("Shutting down, draining open orders for up to {:?}", self.drain_timeout);
+
+        if let Some(mut scheduler) = self.scheduler.take() {
+            if let Err(e) = scheduler.stop().await {
+                warn!("Strategy evaluation did not stop cleanly: {}", e);
+            }
+        }
+
+        let draining: Vec<Uuid> = {
+            let order_manager = self.order_manager.read().await;
+            let draining = order_manager.get_active_orders().await.into_iter().map(|order| order.id).collect();
+            if let Err(e) = order_manager.cancel_all_orders(SHUTDOWN_CANCEL_REASON).await {
+                warn!("Not every order could be cancelled at shutdown: {}", e);
+            }
+            draining
+        };
+
+        let outstanding = self.drain(draining).await;
+        for order_id in &outstanding {
+            error!("Order {} still active at shutdown", order_id);
+        }
+
+        if let Err(e) = self.market_data_manager.write().await.shutdown().await {
+            warn!("Market data manager did not shut down cleanly: {}", e);
+        }
+        if let Err(e) = self.order_manager.write().await.shutdown().await {
+            warn!("Order manager did not shut down cleanly: {}", e);
+        }
+
+        info!("Shutdown complete");
+        outstanding
+    }
+
+    // Wait for the orders being drained, and any still active, to finish,
+    // returning those left when time runs out. Exchange cancels only finish
+    // once the exchange confirms them, and orders still being submitted can
+    // only be cancelled once they are accepted, so cancels are retried.
+    async fn drain(&self, draining: Vec<Uuid>) -> Vec<Uuid> {
+        let deadline = tokio::time::Instant::now() + self.drain_timeout;
+        loop {
+            let order_manager = self.order_manager.read().await;
+            let mut outstanding: Vec<Uuid> = order_manager.get_active_orders().await.into_iter().map(|order| order.id).collect();
+            let retry_cancel = !outstanding.is_empty();
+            for order_id in &draining {
+                let active = order_manager.get_order(*order_id).await.is_some_and(|order| order.is_active());
+                if active && !outstanding.contains(order_id) {
+                    outstanding.push(*order_id);
+                }
+            }
+
+            if outstanding.is_empty() || tokio::time::Instant::now() >= deadline {
+                return outstanding;
+            }
+            if retry_cancel {
+                if let Err(e) = order_manager.cancel_all_orders(SHUTDOWN_CANCEL_REASON).await {
+                    debug!("Retrying shutdown cancels: {}", e);
+                }
+            }
+            drop(order_manager);
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// Resolve on the first Ctrl-C or, on Unix, SIGTERM
+pub async fn wait_for_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            },
+            Err(e) => {
+                error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            },
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!("Received Ctrl-C"),
+        _ = terminate => info!("Received SIGTERM"),
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::debug;
+
+use super::{AssetData, AssetType, MarketData, StrategyResult};
+
+/// Liquidity a symbol needs before signals on it are let through
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiquidityThresholds {
+    pub max_spread_bps: f64, // Widest bid-ask spread, in basis points of the mid price
+    pub min_volume_threshold: f64, // Least volume, in the feed's units
+}
+
+impl LiquidityThresholds {
+    pub fn new(max_spread_bps: f64, min_volume_threshold: f64) -> Self {
+        LiquidityThresholds { max_spread_bps, min_volume_threshold }
+    }
+}
+
+/// Liquidity thresholds by asset type. Asset types without thresholds of
+/// their own use the default ones, and are not filtered when there are none.
+#[derive(Debug, Clone, Default)]
+pub struct LiquidityConfig {
+    default: Option<LiquidityThresholds>,
+    by_asset_type: HashMap<AssetType, LiquidityThresholds>,
+}
+
+impl LiquidityConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_default(mut self, thresholds: LiquidityThresholds) -> Self {
+        self.default = Some(thresholds);
+        self
+    }
+
+    pub fn with_asset_type(mut self, asset_type: AssetType, thresholds: LiquidityThresholds) -> Self {
+        self.by_asset_type.insert(asset_type, thresholds);
+        self
+    }
+
+    pub fn thresholds_for(&self, asset_type: AssetType) -> Option<LiquidityThresholds> {
+        self.by_asset_type.get(&asset_type).copied().or(self.default)
+    }
+}
+
+/// Why a symbol was found too illiquid to trade
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LiquidityRejection {
+    SpreadTooWide { spread_bps: f64, max_spread_bps: f64 },
+    VolumeTooLow { volume: f64, min_volume_threshold: f64 },
+}
+
+impl fmt::Display for LiquidityRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiquidityRejection::SpreadTooWide { spread_bps, max_spread_bps } =>
+                write!(f, "spread too wide ({:.1} bps, max {:.1})", spread_bps, max_spread_bps),
+            LiquidityRejection::VolumeTooLow { volume, min_volume_threshold } =>
+                write!(f, "volume too low ({}, min {})", volume, min_volume_threshold),
+        }
+    }
+}
+
+/// Drops signals on symbols whose spread or volume is outside the configured
+/// thresholds, counting each one dropped
+#[derive(Debug, Default)]
+pub struct LiquidityFilter {
+    config: LiquidityConfig,
+    filtered: AtomicU64,
+}
+
+impl LiquidityFilter {
+    pub fn new(config: LiquidityConfig) -> Self {
+        LiquidityFilter { config, filtered: AtomicU64::new(0) }
+    }
+
+    pub fn config(&self) -> &LiquidityConfig {
+        &self.config
+    }
+
+    /// Replace the thresholds, keeping the count of signals filtered so far
+    pub fn set_config(&mut self, config: LiquidityConfig) {
+        self.config = config;
+    }
+
+    /// Signals dropped since the filter was created
+    pub fn signals_filtered(&self) -> u64 {
+        self.filtered.load(Ordering::Relaxed)
+    }
+
+    /// Check a symbol against the thresholds for its asset type. A spread is
+    /// only judged when both sides are quoted.
+    pub fn check(&self, asset: &AssetData) -> Result<(), LiquidityRejection> {
+        let Some(thresholds) = self.config.thresholds_for(asset.asset_type) else {
+            return Ok(());
+        };
+
+        if asset.bid.is_sign_positive() && asset.ask.is_sign_positive() {
+            let (bid, ask) = (asset.bid.to_f64(), asset.ask.to_f64());
+            let spread_bps = (ask - bid) / ((bid + ask) / 2.0) * 10_000.0;
+            if spread_bps > thresholds.max_spread_bps {
+                return Err(LiquidityRejection::SpreadTooWide { spread_bps, max_spread_bps: thresholds.max_spread_bps });
+            }
+        }
+        if asset.volume < thresholds.min_volume_threshold {
+            return Err(LiquidityRejection::VolumeTooLow { volume: asset.volume, min_volume_threshold: thresholds.min_volume_threshold });
+        }
+        Ok(())
+    }
+
+    /// Drop the signals on symbols that fail `check`. Signals on symbols
+    /// missing from the market data are kept, as there is nothing to judge.
+    pub fn apply(&self, strategy: &str, mut result: StrategyResult, market_data: &MarketData) -> StrategyResult {
+        result.signals.retain(|signal| {
+            let Some(asset) = market_data.asset_data.get(&signal.asset) else {
+                return true;
+            };
+            match self.check(asset) {
+                Ok(()) => true,
+                Err(rejection) => {
+                    debug!("Dropping {} {} signal from {}: {}", signal.asset, signal.direction, strategy, rejection);
+                    self.filtered.fetch_add(1, Ordering::Relaxed);
+                    false
+                },
+            }
+        });
+        result
+    }
+}
@@ -0,0 +1,144 @@
+use std::time::Duration;
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+use tracing::info;
+use utoipa::ToSchema;
+
+use crate::error::TradingError;
+use crate::order::OrderManager;
+use super::{StrategyManager, StrategyState};
+
+/// How long `WaitForFill` waits for the outgoing strategy's orders by default
+pub const DEFAULT_HOT_SWAP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How often `WaitForFill` checks the outgoing strategy's orders
+pub const HOT_SWAP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// What happens to the outgoing strategy's open orders when the active strategy changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HotSwapTransition {
+    /// Cancel them before switching
+    CancelExistingOrders,
+    /// Leave them working under the old strategy's id
+    #[default]
+    KeepExistingOrders,
+    /// Pause the old strategy and switch once they have all filled, been
+    /// cancelled or been rejected
+    WaitForFill,
+}
+
+impl StrategyManager {
+    /// Make `new_name` the active strategy, dealing with the open orders of the
+    /// one it replaces as `transition` says. If the orders do not finish within
+    /// the hot swap timeout, the old strategy stays active and is resumed.
+    pub async fn hot_swap_strategy(
+        &mut self,
+        new_name: &str,
+        transition: HotSwapTransition,
+        order_manager: &OrderManager,
+    ) -> Result<(), TradingError> {
+        let Some(old_name) = self.outgoing_strategy(new_name)? else {
+            return self.set_active_strategy(new_name);
+        };
+
+        match transition {
+            HotSwapTransition::KeepExistingOrders => {},
+            HotSwapTransition::CancelExistingOrders => {
+                let reason = format!("Strategy {} replaced by {}", old_name, new_name);
+                order_manager.cancel_all_orders_for_strategy(&old_name, &reason).await?;
+            },
+            HotSwapTransition::WaitForFill => {
+                let was_running = self.pause_outgoing(&old_name)?;
+                let waited = wait_for_open_orders(order_manager, &old_name, self.hot_swap_timeout).await;
+                return self.finish_wait_for_fill(&old_name, new_name, was_running, waited);
+            },
+        }
+
+        info!("Hot swapping active strategy {} -> {} ({:?})", old_name, new_name, transition);
+        self.set_active_strategy(new_name)
+    }
+
+    // The active strategy `new_name` would replace, or `None` when there is
+    // none or it is already active
+    fn outgoing_strategy(&self, new_name: &str) -> Result<Option<String>, TradingError> {
+        if !self.strategies.contains_key(new_name) {
+            return Err(TradingError::NotFound(format!("Strategy not found: {}", new_name)));
+        }
+        Ok(self.active_strategy.clone().filter(|old_name| old_name != new_name))
+    }
+
+    // Pause the outgoing strategy so it places nothing new while its orders
+    // work, returning whether it was running
+    fn pause_outgoing(&mut self, old_name: &str) -> Result<bool, TradingError> {
+        let was_running = self.get_strategy_state(old_name) == Some(StrategyState::Running);
+        if was_running {
+            self.pause_strategy(old_name)?;
+        }
+        Ok(was_running)
+    }
+
+    // Switch once the wait is over, or resume the old strategy if it failed.
+    // Nothing switches if the active strategy changed in the meantime.
+    fn finish_wait_for_fill(
+        &mut self,
+        old_name: &str,
+        new_name: &str,
+        was_running: bool,
+        waited: Result<(), TradingError>,
+    ) -> Result<(), TradingError> {
+        if let Err(e) = waited {
+            if was_running && self.is_paused(old_name) {
+                self.start_strategy(old_name)?;
+            }
+            return Err(e);
+        }
+        if self.active_strategy() != Some(old_name) {
+            return Err(TradingError::Conflict(format!("Active strategy changed from {} while waiting for its orders", old_name)));
+        }
+
+        info!("Hot swapping active strategy {} -> {} ({:?})", old_name, new_name, HotSwapTransition::WaitForFill);
+        self.set_active_strategy(new_name)
+    }
+}
+
+/// `StrategyManager::hot_swap_strategy` on a shared manager. A `WaitForFill`
+/// swap holds the lock only to pause the old strategy and to switch, so
+/// evaluations and other strategy requests carry on while its orders finish.
+pub async fn hot_swap_shared_strategy(
+    strategy_manager: &RwLock<StrategyManager>,
+    new_name: &str,
+    transition: HotSwapTransition,
+    order_manager: &OrderManager,
+) -> Result<(), TradingError> {
+    if transition != HotSwapTransition::WaitForFill {
+        return strategy_manager.write().await.hot_swap_strategy(new_name, transition, order_manager).await;
+    }
+
+    let (old_name, was_running, timeout) = {
+        let mut manager = strategy_manager.write().await;
+        let Some(old_name) = manager.outgoing_strategy(new_name)? else {
+            return manager.set_active_strategy(new_name);
+        };
+        let was_running = manager.pause_outgoing(&old_name)?;
+        (old_name, was_running, manager.hot_swap_timeout)
+    };
+
+    let waited = wait_for_open_orders(order_manager, &old_name, timeout).await;
+    strategy_manager.write().await.finish_wait_for_fill(&old_name, new_name, was_running, waited)
+}
+
+// Poll until `strategy_id` has no open orders, or fail once `timeout` has passed
+async fn wait_for_open_orders(order_manager: &OrderManager, strategy_id: &str, timeout: Duration) -> Result<(), TradingError> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        let open = order_manager.get_open_orders_by_strategy(strategy_id).await;
+        if open.is_empty() {
+            return Ok(());
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return Err(TradingError::Conflict(format!("{} orders of {} still open after {:?}", open.len(), strategy_id, timeout)));
+        }
+        tokio::time::sleep(HOT_SWAP_POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,179 @@
+use tracing::debug;
+use super::{
+    Strategy, AssetType, MarketData, StrategyResult,
+    TradeSignal, TradeDirection, TimeInForce, StrategyParams
+};
+use crate::error::TradingError;
+use super::params::{validate_params, ParamSpec, ParamType};
+use crate::market_data::{SentimentBuffer, SentimentObservation};
+
+pub struct InformationArbitrageStrategy {
+    name: String,
+    description: String,
+    supported_assets: Vec<AssetType>,
+    // Strategy parameters
+    sentiment_threshold: f64,
+    half_life_secs: f64, // Age at which an observation counts half as much as a fresh one
+    max_position_size: f64,
+    news_sources: Vec<String>, // Sources to trust; empty means all sources
+    sentiment: SentimentBuffer,
+}
+
+impl InformationArbitrageStrategy {
+    pub fn new(sentiment: SentimentBuffer) -> Self {
+        InformationArbitrageStrategy {
+            name: "Information Arbitrage".to_string(),
+            description: "Trades ahead of price moves implied by news and social media sentiment".to_string(),
+            supported_assets: vec![
+                AssetType::Stock,
+                AssetType::ETF,
+                AssetType::Crypto,
+            ],
+            sentiment_threshold: 0.5,
+            half_life_secs: 300.0,
+            max_position_size: 100000.0,
+            news_sources: Vec::new(),
+            sentiment,
+        }
+    }
+
+    fn accepts_source(&self, source: &str) -> bool {
+        self.news_sources.is_empty() || self.news_sources.iter().any(|s| s.eq_ignore_ascii_case(source))
+    }
+
+    // Recency-weighted mean sentiment, decaying each observation by its age
+    fn smoothed_sentiment(&self, observations: &[SentimentObservation], now: chrono::DateTime<chrono::Utc>) -> f64 {
+        let mut weighted_sum = 0.0;
+        let mut total_weight = 0.0;
+
+        for observation in observations {
+            let age_secs = (now - observation.timestamp).num_milliseconds().max(0) as f64 / 1000.0;
+            let weight = 0.5f64.powf(age_secs / self.half_life_secs);
+            weighted_sum += observation.score * weight;
+            total_weight += weight;
+        }
+
+        if total_weight == 0.0 {
+            0.0
+        } else {
+            weighted_sum / total_weight
+        }
+    }
+
+    // Confidence grows with sentiment strength and the number of items that agree with it
+    fn calculate_confidence(smoothed: f64, corroborating: usize) -> f64 {
+        let n = corroborating as f64;
+        (smoothed.abs() * n / (n + 2.0)).min(0.95)
+    }
+}
+
+impl Strategy for InformationArbitrageStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn asset_types(&self) -> Vec<AssetType> {
+        self.supported_assets.clone()
+    }
+
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        let mut signals = Vec::new();
+        let timestamp = market_data.timestamp;
+        let mut confidence: f64 = 0.0;
+        let mut expected_profit = 0.0;
+
+        debug!("Evaluating information arbitrage strategy");
+
+        for symbol in self.sentiment.symbols() {
+            let price = match market_data.asset_data.get(&symbol) {
+                Some(asset) if asset.price.is_sign_positive() => asset.price.to_f64(),
+                _ => continue,
+            };
+
+            let observations: Vec<SentimentObservation> = self.sentiment.observations(&symbol, timestamp)
+                .into_iter()
+                .filter(|o| self.accepts_source(&o.source))
+                .collect();
+            if observations.is_empty() {
+                continue;
+            }
+
+            let smoothed = self.smoothed_sentiment(&observations, timestamp);
+            let direction = if smoothed > self.sentiment_threshold {
+                TradeDirection::Buy
+            } else if smoothed < -self.sentiment_threshold {
+                TradeDirection::Sell
+            } else {
+                continue;
+            };
+
+            let corroborating = observations.iter()
+                .filter(|o| o.score.signum() == smoothed.signum())
+                .count();
+            let signal_confidence = Self::calculate_confidence(smoothed, corroborating);
+
+            debug!("{}: smoothed sentiment {:.3} from {} items ({} corroborating)",
+                symbol, smoothed, observations.len(), corroborating);
+
+            let limit_price = match direction {
+                TradeDirection::Buy => price * 1.001, // Small buffer
+                TradeDirection::Sell => price * 0.999,
+            };
+
+            signals.push(TradeSignal {
+                asset: symbol.clone(),
+                direction,
+                quantity: self.max_position_size / price,
+                limit_price: Some(limit_price),
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                priority: TimeInForce::Day.default_signal_priority(),
+            });
+
+            confidence = confidence.max(signal_confidence);
+            expected_profit += self.max_position_size * 0.01 * signal_confidence;
+        }
+
+        StrategyResult {
+            signals,
+            confidence,
+            expected_profit,
+            timestamp,
+            warming_up: false,
+        }
+    }
+
+    fn param_schema(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec::new("sentiment_threshold", ParamType::Number).positive().at_most(1.0),
+            ParamSpec::new("half_life_secs", ParamType::Number).positive(),
+            ParamSpec::new("max_position_size", ParamType::Number).positive(),
+            ParamSpec::new("news_sources", ParamType::StringList),
+        ]
+    }
+
+    fn update_params(&mut self, params: StrategyParams) -> Result<(), TradingError> {
+        validate_params(&self.param_schema(), &params)?;
+
+        // Types and ranges were checked above
+        for (key, value) in params.params {
+            match key.as_str() {
+                "sentiment_threshold" => self.sentiment_threshold = value.as_f64().unwrap_or(self.sentiment_threshold),
+                "half_life_secs" => self.half_life_secs = value.as_f64().unwrap_or(self.half_life_secs),
+                "max_position_size" => self.max_position_size = value.as_f64().unwrap_or(self.max_position_size),
+                "news_sources" => {
+                    self.news_sources = value.as_array().into_iter().flatten()
+                        .filter_map(|s| s.as_str().map(|s| s.to_string()))
+                        .collect();
+                },
+                _ => {},
+            }
+        }
+
+        Ok(())
+    }
+}
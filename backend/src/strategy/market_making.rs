@@ -0,0 +1,158 @@
+use tracing::debug;
+use super::{
+    Strategy, AssetType, MarketData, StrategyResult,
+    TradeSignal, TradeDirection, TimeInForce, StrategyParams
+};
+use crate::error::TradingError;
+use super::params::{validate_params, ParamSpec, ParamType};
+use crate::market_data::{OrderBook, OrderBooks};
+
+pub struct MarketMakingStrategy {
+    name: String,
+    description: String,
+    supported_assets: Vec<AssetType>,
+    // Strategy parameters
+    quote_size: f64,
+    min_spread_bps: f64, // Narrowest spread we quote, however tight the book
+    depth_levels: usize, // Levels on each side used to measure book imbalance
+    skew_factor: f64,    // Share of the half-spread quotes shift towards the heavier side
+    symbols: Vec<String>, // Symbols to quote; empty means every book
+    order_books: OrderBooks,
+}
+
+impl MarketMakingStrategy {
+    pub fn new(order_books: OrderBooks) -> Self {
+        MarketMakingStrategy {
+            name: "Market Making".to_string(),
+            description: "Quotes both sides of the order book, skewed by book imbalance".to_string(),
+            supported_assets: vec![
+                AssetType::Stock,
+                AssetType::Crypto,
+                AssetType::Forex,
+            ],
+            quote_size: 1.0,
+            min_spread_bps: 10.0,
+            depth_levels: 5,
+            skew_factor: 0.5,
+            symbols: Vec::new(),
+            order_books,
+        }
+    }
+
+    fn books_to_quote(&self) -> Vec<OrderBook> {
+        let books = self.order_books.read().unwrap();
+        books.iter()
+            .filter(|(symbol, _)| self.symbols.is_empty() || self.symbols.contains(symbol))
+            .map(|(_, book)| book.read().unwrap().clone())
+            .collect()
+    }
+
+    // Quote around the book mid, at least as wide as the book itself. Buying
+    // pressure (more bid depth) shifts both quotes up, selling pressure down.
+    fn quotes(&self, book: &OrderBook) -> Option<(f64, f64, f64)> {
+        let mid = book.mid_price()?;
+        let spread = book.spread()?;
+        if mid <= 0.0 {
+            return None;
+        }
+
+        let half_spread = (spread / 2.0).max(mid * self.min_spread_bps / 20000.0);
+        let (bid_volume, ask_volume) = book.volume_at_depth(self.depth_levels);
+        let imbalance = if bid_volume + ask_volume > 0.0 {
+            (bid_volume - ask_volume) / (bid_volume + ask_volume)
+        } else {
+            0.0
+        };
+        let skew = imbalance * self.skew_factor * half_spread;
+
+        Some((mid - half_spread + skew, mid + half_spread + skew, imbalance))
+    }
+}
+
+impl Strategy for MarketMakingStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn asset_types(&self) -> Vec<AssetType> {
+        self.supported_assets.clone()
+    }
+
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        let mut signals = Vec::new();
+        let timestamp = market_data.timestamp;
+        let mut confidence: f64 = 0.0;
+        let mut expected_profit = 0.0;
+
+        debug!("Evaluating market making strategy");
+
+        for book in self.books_to_quote() {
+            let (bid_price, ask_price, imbalance) = match self.quotes(&book) {
+                Some(quotes) => quotes,
+                None => continue,
+            };
+
+            debug!("{}: quoting {:.4} / {:.4} (imbalance {:.2})", book.symbol, bid_price, ask_price, imbalance);
+
+            for (direction, price) in [(TradeDirection::Buy, bid_price), (TradeDirection::Sell, ask_price)] {
+                signals.push(TradeSignal {
+                    asset: book.symbol.clone(),
+                    direction,
+                    quantity: self.quote_size,
+                    limit_price: Some(price),
+                    stop_price: None,
+                    time_in_force: TimeInForce::GoodTilCancelled,
+                    priority: TimeInForce::GoodTilCancelled.default_signal_priority(),
+                });
+            }
+
+            // Assume half the round trips complete; balanced books are the safest to quote
+            confidence = confidence.max(0.8 - 0.3 * imbalance.abs());
+            expected_profit += self.quote_size * (ask_price - bid_price) * 0.5;
+        }
+
+        StrategyResult {
+            signals,
+            confidence,
+            expected_profit,
+            timestamp,
+            warming_up: false,
+        }
+    }
+
+    fn param_schema(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec::new("quote_size", ParamType::Number).positive(),
+            ParamSpec::new("min_spread_bps", ParamType::Number).non_negative(),
+            ParamSpec::new("depth_levels", ParamType::Integer).positive(),
+            ParamSpec::new("skew_factor", ParamType::Number).non_negative().at_most(1.0),
+            ParamSpec::new("symbols", ParamType::StringList),
+        ]
+    }
+
+    fn update_params(&mut self, params: StrategyParams) -> Result<(), TradingError> {
+        validate_params(&self.param_schema(), &params)?;
+
+        // Types and ranges were checked above
+        for (key, value) in params.params {
+            match key.as_str() {
+                "quote_size" => self.quote_size = value.as_f64().unwrap_or(self.quote_size),
+                "min_spread_bps" => self.min_spread_bps = value.as_f64().unwrap_or(self.min_spread_bps),
+                "depth_levels" => self.depth_levels = value.as_u64().map(|v| v as usize).unwrap_or(self.depth_levels),
+                "skew_factor" => self.skew_factor = value.as_f64().unwrap_or(self.skew_factor),
+                "symbols" => {
+                    self.symbols = value.as_array().into_iter().flatten()
+                        .filter_map(|s| s.as_str().map(|s| s.to_string()))
+                        .collect();
+                },
+                _ => {},
+            }
+        }
+
+        Ok(())
+    }
+}
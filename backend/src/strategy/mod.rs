@@ -1,11 +1,48 @@
-use std::collections::HashMap;
-use serde::{Serialize, Deserialize};
-use tracing::{info, error};
+use std::borrow::Cow;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
+use chrono::{DateTime, Duration, Utc};
+use serde::{de, Serialize, Deserialize, Deserializer, Serializer};
+use tracing::{info, debug, warn, error};
+use schemars::JsonSchema;
+use utoipa::ToSchema;
+
+use crate::error::TradingError;
+use crate::exchange::Position;
+use crate::models::Price;
+use crate::risk::{DrawdownMonitor, PositionSizer, TradeStats};
+use crate::notifications::{Notification, NotificationLevel, NotificationManager};
+use crate::utils::text::{name_key, to_snake_case};
+use crate::utils::math::round_down_to_lot;
+
+pub mod filter;
+pub mod hot_swap;
+pub mod information_arbitrage;
+pub mod market_making;
+pub mod momentum;
+pub mod params;
+pub mod priority;
+pub mod regime;
+pub mod scheduler;
+pub mod selection;
+pub mod statistical_arbitrage;
+
+pub use filter::{LiquidityConfig, LiquidityFilter, LiquidityRejection, LiquidityThresholds};
+pub use hot_swap::{hot_swap_shared_strategy, HotSwapTransition, DEFAULT_HOT_SWAP_TIMEOUT};
+pub use information_arbitrage::InformationArbitrageStrategy;
+pub use market_making::MarketMakingStrategy;
+pub use momentum::MomentumStrategy;
+pub use params::{validate_params, ParamError, ParamSpec, ParamType};
+pub use priority::PrioritizedSignal;
+pub use regime::{MarketRegime, MarketReturnTracker, RegimeDetector, MIN_REGIME_OBSERVATIONS};
+pub use scheduler::{SchedulerMetrics, StrategyEvaluationScheduler, DEFAULT_EVALUATION_INTERVAL};
+pub use selection::{SelectionMode, SelectionObjective, StrategySelector};
+pub use statistical_arbitrage::StatisticalArbitrageStrategy;
 
 // Comment out missing modules
 // mod event_arbitrage;
-// mod statistical_arbitrage;
-// mod information_arbitrage;
 // mod latency_arbitrage;
 // mod day_trading;
 
@@ -16,10 +53,33 @@ pub trait Strategy: Send + Sync {
     fn description(&self) -> &str;
     fn asset_types(&self) -> Vec<AssetType>;
     fn evaluate(&self, market_data: &MarketData) -> StrategyResult;
-    fn update_params(&mut self, params: StrategyParams) -> Result<(), String>;
+    fn update_params(&mut self, params: StrategyParams) -> Result<(), TradingError>;
+    
+    /// Parameters `update_params` accepts. Strategies that leave this empty
+    /// validate their parameters themselves.
+    fn param_schema(&self) -> Vec<ParamSpec> {
+        vec![]
+    }
+    
+    /// Regimes the strategy should trade in, matched by kind. Empty means all regimes.
+    fn suitable_regimes(&self) -> Vec<MarketRegime> {
+        vec![]
+    }
+
+    /// Called when one of the strategy's signals fills at `fill_price`, for
+    /// strategies that track what they hold. Does nothing by default.
+    fn on_fill(&mut self, _signal: &TradeSignal, _fill_price: f64) {}
+    
+    /// Whether the strategy has seen enough market data for its signals to
+    /// mean something. Until it has, the manager still evaluates it, so it can
+    /// build up history, but holds back its signals. Ready from the start by
+    /// default.
+    fn is_warmed_up(&self) -> bool {
+        true
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, JsonSchema)]
 pub enum AssetType {
     Stock,
     Bond,
@@ -38,27 +98,78 @@ pub struct MarketData {
     pub asset_data: HashMap<String, AssetData>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl MarketData {
+    /// Symbols whose last update is more than `max_age` before `now`
+    pub fn stale_symbols(&self, max_age: Duration, now: DateTime<Utc>) -> Vec<String> {
+        self.asset_data.values()
+            .filter(|asset| now - asset.last_update > max_age)
+            .map(|asset| asset.symbol.clone())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, JsonSchema)]
 pub struct AssetData {
     pub symbol: String,
     pub asset_type: AssetType,
-    pub price: f64,
+    pub price: Price,
     pub volume: f64,
-    pub bid: f64,
-    pub ask: f64,
+    pub bid: Price,
+    pub ask: Price,
+    #[serde(default)]
+    pub tick_size: Option<Price>, // Smallest price increment; feed prices are rounded to it
+    #[serde(default)]
+    pub lot_size: Option<f64>, // Smallest quantity increment; signal quantities are rounded down to it
     pub exchange: String,
+    pub last_update: DateTime<Utc>, // When the feed last updated this asset
     // Additional fields will be added based on asset type
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct StrategyResult {
     pub signals: Vec<TradeSignal>,
     pub confidence: f64, // 0.0 to 1.0
     pub expected_profit: f64,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub warming_up: bool, // Signals held back because the strategy lacks the history it needs
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl StrategyResult {
+    /// Capital the signals tie up: the sum of their notionals at their limit or
+    /// stop prices, or at the latest market price for signals with neither.
+    /// Signals priced no way at all add nothing.
+    pub fn required_capital(&self, market_data: &MarketData) -> f64 {
+        self.signals.iter()
+            .map(|signal| {
+                let price = signal.limit_price
+                    .or(signal.stop_price)
+                    .or_else(|| market_data.asset_data.get(&signal.asset).map(|asset| asset.price.to_f64()))
+                    .unwrap_or(0.0);
+                (signal.quantity * price).abs()
+            })
+            .sum()
+    }
+    
+    /// A result without signals from a strategy still warming up
+    pub fn warming_up(timestamp: DateTime<Utc>) -> Self {
+        StrategyResult {
+            signals: Vec::new(),
+            confidence: 0.0,
+            expected_profit: 0.0,
+            timestamp,
+            warming_up: true,
+        }
+    }
+}
+
+/// Priority of signals that must reach the market straight away
+pub const URGENT_SIGNAL_PRIORITY: u8 = 255;
+
+/// Priority of signals that can rest on the book
+pub const DEFAULT_SIGNAL_PRIORITY: u8 = 128;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TradeSignal {
     pub asset: String,
     pub direction: TradeDirection,
@@ -66,9 +177,17 @@ pub struct TradeSignal {
     pub limit_price: Option<f64>,
     pub stop_price: Option<f64>,
     pub time_in_force: TimeInForce,
+    #[serde(default = "default_signal_priority")]
+    pub priority: u8, // 0 lowest to 255 highest; higher priority signals are submitted first
+}
+
+fn default_signal_priority() -> u8 {
+    DEFAULT_SIGNAL_PRIORITY
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ToSchema, JsonSchema)]
+#[schema(rename_all = "snake_case")]
+#[schemars(rename_all = "snake_case")]
 pub enum TradeDirection {
     Buy,
     Sell,
@@ -84,7 +203,44 @@ impl TradeDirection {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl fmt::Display for TradeDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TradeDirection::Buy => "Buy",
+            TradeDirection::Sell => "Sell",
+        })
+    }
+}
+
+impl FromStr for TradeDirection {
+    type Err = String;
+
+    /// Accepts `buy` or `sell` in any case
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name_key(name).as_str() {
+            "buy" => Ok(TradeDirection::Buy),
+            "sell" => Ok(TradeDirection::Sell),
+            _ => Err(format!("Unknown trade direction: {}", name)),
+        }
+    }
+}
+
+// Serialized as the snake_case form of the `Display` name, e.g. `buy`
+impl Serialize for TradeDirection {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_snake_case(&self.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for TradeDirection {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ToSchema, JsonSchema)]
+#[schema(rename_all = "snake_case")]
+#[schemars(rename_all = "snake_case")]
 pub enum TimeInForce {
     Day,
     GoodTilCancelled,
@@ -92,6 +248,56 @@ pub enum TimeInForce {
     ImmediateOrCancel,
 }
 
+impl fmt::Display for TimeInForce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TimeInForce::Day => "Day",
+            TimeInForce::GoodTilCancelled => "GoodTilCancelled",
+            TimeInForce::FillOrKill => "FillOrKill",
+            TimeInForce::ImmediateOrCancel => "ImmediateOrCancel",
+        })
+    }
+}
+
+impl TimeInForce {
+    /// Priority for signals with this time in force: immediate orders are
+    /// urgent, as they are worthless once the price has moved
+    pub fn default_signal_priority(&self) -> u8 {
+        match self {
+            TimeInForce::ImmediateOrCancel | TimeInForce::FillOrKill => URGENT_SIGNAL_PRIORITY,
+            TimeInForce::Day | TimeInForce::GoodTilCancelled => DEFAULT_SIGNAL_PRIORITY,
+        }
+    }
+}
+
+impl FromStr for TimeInForce {
+    type Err = String;
+
+    /// Accepts the `Display` name in any case, with or without underscores,
+    /// and the usual abbreviations `gtc`, `fok` and `ioc`
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name_key(name).as_str() {
+            "day" => Ok(TimeInForce::Day),
+            "gtc" | "goodtilcancelled" | "goodtilcanceled" => Ok(TimeInForce::GoodTilCancelled),
+            "fok" | "fillorkill" => Ok(TimeInForce::FillOrKill),
+            "ioc" | "immediateorcancel" => Ok(TimeInForce::ImmediateOrCancel),
+            _ => Err(format!("Unknown time in force: {}", name)),
+        }
+    }
+}
+
+impl Serialize for TimeInForce {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_snake_case(&self.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeInForce {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategyParams {
     pub params: HashMap<String, serde_json::Value>,
@@ -102,24 +308,130 @@ pub struct StrategyParams {
 pub struct StrategyManager {
     strategies: HashMap<String, Box<dyn Strategy>>,
     active_strategy: Option<String>,
+    states: Arc<RwLock<HashMap<String, StrategyState>>>, // Shared with the drawdown callback
+    drawdown_monitor: DrawdownMonitor,
+    selector: StrategySelector,
+    regime_detector: RegimeDetector,
+    market_returns: Mutex<MarketReturnTracker>, // Fed by evaluate_strategies, which only borrows self
+    current_regime: RwLock<Option<MarketRegime>>,
+    stale_data_threshold: Option<Duration>, // Symbols older than this are hidden from strategies
+    position_sizer: Option<Box<dyn PositionSizer>>,
+    trade_stats: HashMap<String, TradeStats>, // Closed trade record by strategy name
+    hot_swap_timeout: std::time::Duration, // Longest a WaitForFill hot swap waits for open orders
+    liquidity_filter: LiquidityFilter, // Drops signals on symbols with too wide a spread or too little volume
+}
+
+/// Default age past which a symbol's data is considered stale
+pub const DEFAULT_STALE_DATA_THRESHOLD_SECS: i64 = 60;
+
+impl Default for StrategyManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[allow(dead_code, unused_variables)]
 impl StrategyManager {
     pub fn new() -> Self {
+        let states = Arc::new(RwLock::new(HashMap::new()));
+        
+        // Stop trading when losses run past the drawdown limit
+        let mut drawdown_monitor = DrawdownMonitor::default();
+        let callback_states = states.clone();
+        drawdown_monitor.on_drawdown(move |drawdown| {
+            let paused = pause_running_strategies(&callback_states);
+            error!("Drawdown of {:.2}% exceeded limit, paused strategies: {:?}", drawdown * 100.0, paused);
+        });
+        
         StrategyManager {
             strategies: HashMap::new(),
             active_strategy: None,
+            states,
+            drawdown_monitor,
+            selector: StrategySelector::default(),
+            regime_detector: RegimeDetector::default(),
+            market_returns: Mutex::new(MarketReturnTracker::new()),
+            current_regime: RwLock::new(None),
+            stale_data_threshold: Some(Duration::seconds(DEFAULT_STALE_DATA_THRESHOLD_SECS)),
+            position_sizer: None,
+            trade_stats: HashMap::new(),
+            hot_swap_timeout: DEFAULT_HOT_SWAP_TIMEOUT,
+            liquidity_filter: LiquidityFilter::default(),
         }
     }
 
     pub fn register_strategy(&mut self, strategy: Box<dyn Strategy>) {
         let name = strategy.name().to_string();
         info!("Registering strategy: {}", name);
+        self.states.write().unwrap().insert(name.clone(), StrategyState::Ready);
         self.strategies.insert(name, strategy);
     }
+    
+    pub fn get_strategy_state(&self, name: &str) -> Option<StrategyState> {
+        self.states.read().unwrap().get(name).cloned()
+    }
+    
+    pub fn start_strategy(&mut self, name: &str) -> Result<(), TradingError> {
+        self.transition_strategy(name, StrategyState::Running)
+    }
+    
+    pub fn pause_strategy(&mut self, name: &str) -> Result<(), TradingError> {
+        self.transition_strategy(name, StrategyState::Paused)
+    }
+    
+    fn is_paused(&self, name: &str) -> bool {
+        self.get_strategy_state(name) == Some(StrategyState::Paused)
+    }
+    
+    pub fn running_strategies(&self) -> Vec<String> {
+        self.states.read().unwrap().iter()
+            .filter(|(_, state)| **state == StrategyState::Running)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+    
+    fn transition_strategy(&mut self, name: &str, next: StrategyState) -> Result<(), TradingError> {
+        let mut states = self.states.write().unwrap();
+        let state = states.get_mut(name)
+            .ok_or_else(|| TradingError::NotFound(format!("Strategy not found: {}", name)))?;
+        
+        if !state.can_transition_to(&next) {
+            return Err(TradingError::Conflict(format!("Strategy {} cannot move from {:?} to {:?}", name, state, next)));
+        }
+        
+        info!("Strategy {} state: {:?} -> {:?}", name, state, next);
+        *state = next;
+        Ok(())
+    }
+    
+    /// Feed the latest portfolio equity to the drawdown monitor. Running strategies
+    /// are paused when the drawdown crosses the monitor's threshold.
+    pub fn record_equity(&mut self, value: f64, timestamp: DateTime<Utc>) {
+        self.drawdown_monitor.record_equity(value, timestamp);
+    }
+    
+    /// Raise a critical notification whenever a drawdown breach pauses trading
+    pub fn set_notification_manager(&mut self, notification_manager: Arc<NotificationManager>) {
+        self.drawdown_monitor.on_drawdown(move |drawdown| {
+            notification_manager.notify_in_background(
+                Notification::new(
+                    NotificationLevel::Critical,
+                    "Strategies paused on drawdown",
+                    &format!("Drawdown of {:.2}% exceeded the limit; running strategies were paused", drawdown * 100.0),
+                )
+                .with_metadata("drawdown", &drawdown.to_string()));
+        });
+    }
+    
+    pub fn get_drawdown_monitor(&self) -> &DrawdownMonitor {
+        &self.drawdown_monitor
+    }
+    
+    pub fn get_drawdown_monitor_mut(&mut self) -> &mut DrawdownMonitor {
+        &mut self.drawdown_monitor
+    }
 
-    pub fn set_active_strategy(&mut self, name: &str) -> Result<(), String> {
+    pub fn set_active_strategy(&mut self, name: &str) -> Result<(), TradingError> {
         if self.strategies.contains_key(name) {
             info!("Setting active strategy to: {}", name);
             self.active_strategy = Some(name.to_string());
@@ -127,17 +439,69 @@ impl StrategyManager {
         } else {
             let error_msg = format!("Strategy not found: {}", name);
             error!("{}", error_msg);
-            Err(error_msg)
+            Err(TradingError::NotFound(error_msg))
+        }
+    }
+
+    pub fn active_strategy(&self) -> Option<&str> {
+        self.active_strategy.as_deref()
+    }
+    
+    /// Longest a `WaitForFill` hot swap waits for the outgoing strategy's orders
+    pub fn set_hot_swap_timeout(&mut self, timeout: std::time::Duration) {
+        self.hot_swap_timeout = timeout;
+    }
+
+    /// Set the age past which symbols are excluded from evaluation, or `None` to
+    /// evaluate on data of any age
+    pub fn set_stale_data_threshold(&mut self, threshold: Option<Duration>) {
+        self.stale_data_threshold = threshold;
+    }
+    
+    pub fn stale_data_threshold(&self) -> Option<Duration> {
+        self.stale_data_threshold
+    }
+    
+    // Market data without the symbols that have gone stale, so frozen feeds
+    // don't produce signals
+    fn fresh_market_data<'a>(&self, market_data: &'a MarketData) -> Cow<'a, MarketData> {
+        let stale = match self.stale_data_threshold {
+            Some(threshold) => market_data.stale_symbols(threshold, Utc::now()),
+            None => return Cow::Borrowed(market_data),
+        };
+        if stale.is_empty() {
+            return Cow::Borrowed(market_data);
         }
+        
+        warn!("Excluding stale market data from evaluation: {:?}", stale);
+        let mut fresh = market_data.clone();
+        fresh.asset_data.retain(|symbol, _| !stale.contains(symbol));
+        Cow::Owned(fresh)
     }
 
     pub fn evaluate_strategies(&self, market_data: &MarketData) -> HashMap<String, StrategyResult> {
         let mut results = HashMap::new();
+        let market_data = self.fresh_market_data(market_data);
+        let market_data = market_data.as_ref();
+        let regime = self.update_regime(market_data);
         
         for (name, strategy) in &self.strategies {
+            if self.is_paused(name) {
+                info!("Skipping paused strategy: {}", name);
+                continue;
+            }
+            
+            if let Some(regime) = &regime {
+                let suitable = strategy.suitable_regimes();
+                if !suitable.is_empty() && !suitable.iter().any(|r| r.same_kind(regime)) {
+                    info!("Skipping strategy {} unsuited to {:?} regime", name, regime);
+                    continue;
+                }
+            }
+            
             info!("Evaluating strategy: {}", name);
             
-            let result = strategy.evaluate(market_data);
+            let result = self.run_strategy(name, strategy.as_ref(), market_data);
             
             info!("Strategy {} evaluation complete, confidence: {}", name, result.confidence);
             
@@ -147,34 +511,247 @@ impl StrategyManager {
         results
     }
 
+    /// Evaluate every strategy and queue their signals by priority, highest
+    /// first. Signals of equal priority keep their order, strategies taken by name.
+    pub fn get_aggregated_signals(&self, market_data: &MarketData) -> BinaryHeap<PrioritizedSignal> {
+        let mut results: Vec<(String, StrategyResult)> = self.evaluate_strategies(market_data).into_iter().collect();
+        results.sort_by(|(a, _), (b, _)| a.cmp(b));
+        
+        let mut queue = BinaryHeap::new();
+        for (name, result) in results {
+            for signal in result.signals {
+                let sequence = queue.len() as u64;
+                queue.push(PrioritizedSignal::new(&name, signal, sequence));
+            }
+        }
+        queue
+    }
+
+    pub fn set_regime_detector(&mut self, detector: RegimeDetector) {
+        self.regime_detector = detector;
+    }
+    
+    /// Add a market return to the series used for regime detection, for feeds
+    /// that track returns themselves
+    pub fn record_market_return(&self, value: f64) {
+        self.market_returns.lock().unwrap().record_return(value);
+    }
+    
+    /// Regime detected by the last evaluation, or `None` until enough returns have been seen
+    pub fn current_regime(&self) -> Option<MarketRegime> {
+        *self.current_regime.read().unwrap()
+    }
+    
+    // Extend the return series with the snapshot and re-detect the regime
+    fn update_regime(&self, market_data: &MarketData) -> Option<MarketRegime> {
+        let returns = {
+            let mut tracker = self.market_returns.lock().unwrap();
+            tracker.record_snapshot(market_data);
+            tracker.returns()
+        };
+        
+        let regime = if returns.len() >= MIN_REGIME_OBSERVATIONS {
+            Some(self.regime_detector.detect(&returns))
+        } else {
+            None
+        };
+        
+        debug!("Detected market regime: {:?}", regime);
+        *self.current_regime.write().unwrap() = regime;
+        regime
+    }
+
+    /// Evaluate a single strategy, or `None` if no strategy is registered under `name`
+    pub fn evaluate_one(&self, name: &str, market_data: &MarketData) -> Option<StrategyResult> {
+        let strategy = self.strategies.get(name)?;
+        
+        info!("Evaluating strategy: {}", name);
+        let market_data = self.fresh_market_data(market_data);
+        let result = self.run_strategy(name, strategy.as_ref(), &market_data);
+        info!("Strategy {} evaluation complete, confidence: {}", name, result.confidence);
+        
+        Some(result)
+    }
+
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        info!("Setting strategy selection mode to: {:?}", mode);
+        self.selector.set_mode(mode);
+    }
+    
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selector.mode()
+    }
+
+    /// Highest scoring strategy under the current selection mode
     pub fn get_best_strategy(&self, results: &HashMap<String, StrategyResult>) -> Option<String> {
-        results.iter()
-            .max_by(|a, b| {
-                a.1.expected_profit.partial_cmp(&b.1.expected_profit).unwrap_or(std::cmp::Ordering::Equal)
-            })
-            .map(|(name, _)| name.clone())
+        self.selector.select_best(results)
+    }
+    
+    /// Best strategy for `objective`; for ROI, its score per unit of the capital
+    /// its signals require at `market_data` prices
+    pub fn get_best_strategy_for(&self, results: &HashMap<String, StrategyResult>, market_data: &MarketData, objective: SelectionObjective) -> Option<String> {
+        match objective {
+            SelectionObjective::Profit => self.selector.select_best(results),
+            SelectionObjective::Roi => {
+                let capital = results.iter()
+                    .map(|(name, result)| (name.clone(), result.required_capital(market_data)))
+                    .collect();
+                self.selector.select_best_per_capital(results, &capital)
+            },
+        }
+    }
+    
+    /// Signals of every strategy at or above `confidence_floor`, weighted by score
+    pub fn get_blended_signals(&self, results: &HashMap<String, StrategyResult>, confidence_floor: f64) -> Option<StrategyResult> {
+        self.selector.blend(results, confidence_floor)
     }
 
     pub fn get_active_strategy_signals(&self, market_data: &MarketData) -> Option<StrategyResult> {
         match &self.active_strategy {
-            Some(name) => {
-                if let Some(strategy) = self.strategies.get(name) {
-                    Some(strategy.evaluate(market_data))
-                } else {
-                    None
-                }
-            }
+            Some(name) if self.is_paused(name) => None,
+            Some(name) => self.strategies.get(name).map(|strategy| {
+                let market_data = self.fresh_market_data(market_data);
+                self.run_strategy(name, strategy.as_ref(), &market_data)
+            }),
             None => None,
         }
     }
 
-    pub fn update_strategy_params(&mut self, name: &str, params: StrategyParams) -> Result<(), String> {
+    /// Spread and volume thresholds, by asset type, that symbols must meet for
+    /// signals on them to be kept. The default config filters nothing.
+    pub fn set_liquidity_config(&mut self, config: LiquidityConfig) {
+        self.liquidity_filter.set_config(config);
+    }
+    
+    /// Signals dropped by the liquidity filter since the manager was created
+    pub fn signals_filtered_by_liquidity(&self) -> u64 {
+        self.liquidity_filter.signals_filtered()
+    }
+
+    /// Size signal quantities from each strategy's trade record and the equity
+    /// last passed to `record_equity`, or `None` to keep the strategies' own quantities
+    pub fn set_position_sizer(&mut self, sizer: Option<Box<dyn PositionSizer>>) {
+        self.position_sizer = sizer;
+    }
+    
+    /// Add the P&L of a trade the strategy closed to its record
+    pub fn record_trade_result(&mut self, name: &str, pnl: f64) {
+        self.trade_stats.entry(name.to_string()).or_default().record(pnl);
+    }
+    
+    /// Record the realized P&L of closed positions to the strategies that opened them
+    pub fn record_closed_positions(&mut self, positions: &[Position]) {
+        for position in positions {
+            if let Some(strategy_id) = &position.strategy_id {
+                self.record_trade_result(strategy_id, position.realized_pnl);
+            }
+        }
+    }
+    
+    pub fn get_trade_stats(&self, name: &str) -> Option<TradeStats> {
+        self.trade_stats.get(name).cloned()
+    }
+    
+    // Evaluate the strategy, then size, round and filter its signals. A strategy
+    // that is not warmed up once it has seen the data gets an empty result instead.
+    fn run_strategy(&self, name: &str, strategy: &dyn Strategy, market_data: &MarketData) -> StrategyResult {
+        let result = strategy.evaluate(market_data);
+        if !strategy.is_warmed_up() {
+            debug!("Strategy {} is warming up, holding back {} signals", name, result.signals.len());
+            return StrategyResult::warming_up(result.timestamp);
+        }
+        let result = self.size_signals(name, result, market_data);
+        let result = round_to_lot_sizes(name, result, market_data);
+        self.liquidity_filter.apply(name, result, market_data)
+    }
+    
+    // Replace signal quantities with the sizer's dollar size at the signal's
+    // price. Left alone without a sizer, equity or trade record to size from;
+    // signals sized to nothing are dropped.
+    fn size_signals(&self, name: &str, mut result: StrategyResult, market_data: &MarketData) -> StrategyResult {
+        let sizer = match &self.position_sizer {
+            Some(sizer) => sizer,
+            None => return result,
+        };
+        let stats = match self.trade_stats.get(name) {
+            Some(stats) if stats.trades() > 0 => stats,
+            _ => {
+                debug!("No trade record for strategy {}, keeping its signal quantities", name);
+                return result;
+            },
+        };
+        let portfolio_value = self.drawdown_monitor.current_equity();
+        if portfolio_value <= 0.0 {
+            return result;
+        }
+        
+        for signal in result.signals.iter_mut() {
+            let price = signal.limit_price
+                .or_else(|| market_data.asset_data.get(&signal.asset).map(|asset| asset.price.to_f64()))
+                .filter(|price| *price > 0.0);
+            match price {
+                Some(price) => {
+                    let size = sizer.compute_size(signal, portfolio_value, stats.win_rate(), stats.avg_win(), stats.avg_loss());
+                    signal.quantity = size / price;
+                },
+                None => warn!("No price to size {} signal from strategy {}", signal.asset, name),
+            }
+        }
+        result.signals.retain(|signal| signal.quantity > 0.0);
+        result
+    }
+
+    /// Check parameters against a strategy's schema without applying them.
+    /// Strategies without a schema are only checked when updated.
+    pub fn validate_params(&self, name: &str, params: &StrategyParams) -> Result<(), ParamError> {
+        let strategy = self.strategies.get(name)
+            .ok_or_else(|| ParamError::UnknownStrategy(name.to_string()))?;
+        let schema = strategy.param_schema();
+        if schema.is_empty() {
+            return Ok(());
+        }
+        validate_params(&schema, params)
+    }
+    
+    pub fn update_strategy_params(&mut self, name: &str, params: StrategyParams) -> Result<(), TradingError> {
         if let Some(strategy) = self.strategies.get_mut(name) {
             strategy.update_params(params)
         } else {
-            Err(format!("Strategy not found: {}", name))
+            Err(TradingError::NotFound(format!("Strategy not found: {}", name)))
+        }
+    }
+}
+
+// Pause every running strategy, returning the names of those paused
+// Round each signal's quantity down to its asset's lot size, so what the
+// strategy emits can be traded as it is. Signals that round to nothing are dropped.
+fn round_to_lot_sizes(name: &str, mut result: StrategyResult, market_data: &MarketData) -> StrategyResult {
+    result.signals.retain_mut(|signal| {
+        let Some(lot_size) = market_data.asset_data.get(&signal.asset).and_then(|asset| asset.lot_size) else {
+            return true;
+        };
+        signal.quantity = round_down_to_lot(signal.quantity, lot_size);
+        if signal.quantity <= 0.0 {
+            info!("Dropping {} signal on {} below its lot size of {}", name, signal.asset, lot_size);
+            return false;
+        }
+        true
+    });
+    result
+}
+
+fn pause_running_strategies(states: &RwLock<HashMap<String, StrategyState>>) -> Vec<String> {
+    let mut states = states.write().unwrap();
+    let mut paused = Vec::new();
+    
+    for (name, state) in states.iter_mut() {
+        if *state == StrategyState::Running {
+            *state = StrategyState::Paused;
+            paused.push(name.clone());
         }
     }
+    
+    paused
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
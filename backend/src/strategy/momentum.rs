@@ -0,0 +1,185 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use tracing::debug;
+use super::{
+    Strategy, AssetType, MarketData, StrategyResult,
+    TradeSignal, TradeDirection, TimeInForce, StrategyParams, MarketRegime
+};
+use crate::error::TradingError;
+use super::params::{validate_params, ParamSpec, ParamType};
+use crate::utils::math::ema_series;
+
+/// Prices kept per asset, as a multiple of the slow period. Older prices
+/// barely move the averages.
+const HISTORY_PERIODS: usize = 10;
+
+/// Gap between the averages, as a fraction of the slow one, that earns full confidence
+const FULL_CONFIDENCE_SPREAD: f64 = 0.05;
+
+/// Trades moving average crossovers. A Buy is signalled when the fast EMA
+/// crosses above the slow EMA and a Sell when it crosses below, provided the
+/// MACD line (fast minus slow) is on the same side of its own `signal_period`
+/// EMA, which filters out crossovers that immediately reverse.
+pub struct MomentumStrategy {
+    name: String,
+    description: String,
+    supported_assets: Vec<AssetType>,
+    // Strategy parameters
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    max_position_usd: f64, // Notional of each signal
+    price_history: Mutex<HashMap<String, VecDeque<f64>>>, // Oldest first, per asset
+}
+
+impl Default for MomentumStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MomentumStrategy {
+    pub fn new() -> Self {
+        MomentumStrategy {
+            name: "Momentum".to_string(),
+            description: "Follows trends on moving average crossovers".to_string(),
+            supported_assets: vec![
+                AssetType::Stock,
+                AssetType::ETF,
+                AssetType::Crypto,
+                AssetType::Forex,
+                AssetType::Commodity,
+            ],
+            fast_period: 12,
+            slow_period: 26,
+            signal_period: 9,
+            max_position_usd: 10000.0,
+            price_history: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Direction of a crossover on the latest price, and the gap between the
+    // averages as a fraction of the slow one
+    fn crossover(&self, prices: &[f64]) -> Option<(TradeDirection, f64)> {
+        if prices.len() <= self.slow_period {
+            return None;
+        }
+
+        let fast = ema_series(prices, self.fast_period);
+        let slow = ema_series(prices, self.slow_period);
+        let macd: Vec<f64> = fast.iter().zip(&slow).map(|(fast, slow)| fast - slow).collect();
+        let signal = ema_series(&macd, self.signal_period);
+
+        let last = macd.len() - 1;
+        let (previous, current) = (macd[last - 1], macd[last]);
+        let direction = if previous <= 0.0 && current > 0.0 && current > signal[last] {
+            TradeDirection::Buy
+        } else if previous >= 0.0 && current < 0.0 && current < signal[last] {
+            TradeDirection::Sell
+        } else {
+            return None;
+        };
+
+        let spread = if slow[last] != 0.0 { (current / slow[last]).abs() } else { 0.0 };
+        Some((direction, spread))
+    }
+}
+
+impl Strategy for MomentumStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn asset_types(&self) -> Vec<AssetType> {
+        self.supported_assets.clone()
+    }
+
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        let mut signals = Vec::new();
+        let mut confidence: f64 = 0.0;
+        let history_limit = self.slow_period * HISTORY_PERIODS + self.signal_period;
+
+        debug!("Evaluating momentum strategy");
+
+        let mut symbols: Vec<&String> = market_data.asset_data.keys().collect();
+        symbols.sort();
+
+        let mut price_history = self.price_history.lock().unwrap();
+        for symbol in symbols {
+            let price = market_data.asset_data[symbol].price.to_f64();
+            if price <= 0.0 {
+                continue;
+            }
+
+            let history = price_history.entry(symbol.clone()).or_default();
+            history.push_back(price);
+            while history.len() > history_limit {
+                history.pop_front();
+            }
+
+            let Some((direction, spread)) = self.crossover(history.make_contiguous()) else {
+                continue;
+            };
+            debug!("{}: {:?} crossover, averages {:.2}% apart", symbol, direction, spread * 100.0);
+
+            signals.push(TradeSignal {
+                asset: symbol.clone(),
+                direction,
+                quantity: self.max_position_usd / price,
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                priority: TimeInForce::Day.default_signal_priority(),
+            });
+            confidence = confidence.max((spread / FULL_CONFIDENCE_SPREAD).min(1.0));
+        }
+
+        StrategyResult {
+            signals,
+            confidence,
+            expected_profit: 0.0, // Trend following sets no price target
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+
+    fn param_schema(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec::new("fast_period", ParamType::Integer).positive(),
+            ParamSpec::new("slow_period", ParamType::Integer).positive(),
+            ParamSpec::new("signal_period", ParamType::Integer).positive(),
+            ParamSpec::new("max_position_usd", ParamType::Number).positive(),
+        ]
+    }
+
+    fn update_params(&mut self, params: StrategyParams) -> Result<(), TradingError> {
+        validate_params(&self.param_schema(), &params)?;
+
+        let period = |key: &str, current: usize| params.params.get(key)
+            .and_then(|value| value.as_u64())
+            .map(|value| value as usize)
+            .unwrap_or(current);
+        let fast_period = period("fast_period", self.fast_period);
+        let slow_period = period("slow_period", self.slow_period);
+        if fast_period >= slow_period {
+            return Err(TradingError::Validation(format!("fast_period ({}) must be shorter than slow_period ({})", fast_period, slow_period)));
+        }
+
+        self.fast_period = fast_period;
+        self.slow_period = slow_period;
+        self.signal_period = period("signal_period", self.signal_period);
+        if let Some(max_position_usd) = params.params.get("max_position_usd").and_then(|value| value.as_f64()) {
+            self.max_position_usd = max_position_usd;
+        }
+
+        Ok(())
+    }
+
+    fn suitable_regimes(&self) -> Vec<MarketRegime> {
+        vec![MarketRegime::Trending { strength: 0.0 }]
+    }
+}
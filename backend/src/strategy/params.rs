@@ -0,0 +1,184 @@
+use std::fmt;
+use serde_json::Value;
+
+use super::StrategyParams;
+use crate::error::TradingError;
+
+/// JSON shape a strategy parameter must take
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamType {
+    Number,
+    Integer,
+    StringList,
+    StringPairList, // Array of [string, string] pairs
+}
+
+impl ParamType {
+    fn describe(&self) -> &'static str {
+        match self {
+            ParamType::Number => "number",
+            ParamType::Integer => "integer",
+            ParamType::StringList => "array of strings",
+            ParamType::StringPairList => "array of [string, string] pairs",
+        }
+    }
+
+    fn matches(&self, value: &Value) -> bool {
+        match self {
+            ParamType::Number => value.is_number(),
+            ParamType::Integer => value.is_i64() || value.is_u64(),
+            ParamType::StringList => value.as_array()
+                .map(|items| items.iter().all(Value::is_string))
+                .unwrap_or(false),
+            ParamType::StringPairList => value.as_array()
+                .map(|items| items.iter().all(|item| match item.as_array() {
+                    Some(pair) => pair.len() == 2 && pair.iter().all(Value::is_string),
+                    None => false,
+                }))
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// One parameter a strategy accepts, with the range numeric values must fall in
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamSpec {
+    pub name: &'static str,
+    pub param_type: ParamType,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub min_exclusive: bool, // Whether `min` itself is out of range
+}
+
+impl ParamSpec {
+    pub fn new(name: &'static str, param_type: ParamType) -> Self {
+        ParamSpec {
+            name,
+            param_type,
+            min: None,
+            max: None,
+            min_exclusive: false,
+        }
+    }
+
+    /// Greater than zero
+    pub fn positive(mut self) -> Self {
+        self.min = Some(0.0);
+        self.min_exclusive = true;
+        self
+    }
+
+    /// Zero or more
+    pub fn non_negative(mut self) -> Self {
+        self.min = Some(0.0);
+        self.min_exclusive = false;
+        self
+    }
+
+    pub fn at_most(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    fn in_range(&self, value: f64) -> bool {
+        let above_min = match self.min {
+            Some(min) if self.min_exclusive => value > min,
+            Some(min) => value >= min,
+            None => true,
+        };
+        above_min && self.max.map(|max| value <= max).unwrap_or(true)
+    }
+
+    fn describe_range(&self) -> String {
+        let exclusive = if self.min_exclusive { " (exclusive)" } else { "" };
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => format!("between {}{} and {}", min, exclusive, max),
+            (Some(min), None) if min != 0.0 && self.min_exclusive => format!("greater than {}", min),
+            (Some(min), None) if min != 0.0 => format!("at least {}", min),
+            (Some(_), None) if self.min_exclusive => "positive".to_string(),
+            (Some(_), None) => "non-negative".to_string(),
+            (None, Some(max)) => format!("at most {}", max),
+            (None, None) => "any value".to_string(),
+        }
+    }
+}
+
+/// Why a set of strategy parameters was refused
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamError {
+    UnknownStrategy(String),
+    UnknownParameter(String),
+    WrongType { name: String, expected: &'static str, got: &'static str },
+    OutOfRange { name: String, value: f64, range: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::UnknownStrategy(name) => write!(f, "Strategy not found: {}", name),
+            ParamError::UnknownParameter(name) => write!(f, "Unknown parameter: {}", name),
+            ParamError::WrongType { name, expected, got } => write!(f, "{} expected {}, got {}", name, expected, got),
+            ParamError::OutOfRange { name, value, range } => write!(f, "{} must be {}, got {}", name, range, value),
+        }
+    }
+}
+
+impl From<ParamError> for String {
+    fn from(error: ParamError) -> String {
+        error.to_string()
+    }
+}
+
+impl From<ParamError> for TradingError {
+    fn from(error: ParamError) -> TradingError {
+        match error {
+            ParamError::UnknownStrategy(_) => TradingError::NotFound(error.to_string()),
+            _ => TradingError::Validation(error.to_string()),
+        }
+    }
+}
+
+/// Check every parameter against the schema, in name order so the same input
+/// always reports the same error. Nothing should be applied unless this passes.
+pub fn validate_params(schema: &[ParamSpec], params: &StrategyParams) -> Result<(), ParamError> {
+    let mut names: Vec<&String> = params.params.keys().collect();
+    names.sort();
+
+    for name in names {
+        let value = &params.params[name];
+        let spec = schema.iter()
+            .find(|spec| spec.name == name)
+            .ok_or_else(|| ParamError::UnknownParameter(name.clone()))?;
+
+        if !spec.param_type.matches(value) {
+            return Err(ParamError::WrongType {
+                name: name.clone(),
+                expected: spec.param_type.describe(),
+                got: json_type_name(value),
+            });
+        }
+
+        if let Some(number) = value.as_f64() {
+            if !spec.in_range(number) {
+                return Err(ParamError::OutOfRange {
+                    name: name.clone(),
+                    value: number,
+                    range: spec.describe_range(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
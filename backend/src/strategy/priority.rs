@@ -0,0 +1,45 @@
+use std::cmp::Ordering;
+
+use super::TradeSignal;
+
+/// A signal queued for submission, with the strategy that produced it.
+/// Ordered by the signal's priority, then by `sequence` so that among signals
+/// of equal priority the earliest queued comes out of a `BinaryHeap` first.
+#[derive(Debug, Clone)]
+pub struct PrioritizedSignal {
+    pub strategy: String,
+    pub signal: TradeSignal,
+    pub sequence: u64, // Order in which the signal was queued
+}
+
+impl PrioritizedSignal {
+    pub fn new(strategy: &str, signal: TradeSignal, sequence: u64) -> Self {
+        PrioritizedSignal { strategy: strategy.to_string(), signal, sequence }
+    }
+
+    pub fn priority(&self) -> u8 {
+        self.signal.priority
+    }
+}
+
+impl Ord for PrioritizedSignal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority().cmp(&other.priority())
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl PartialOrd for PrioritizedSignal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equal when neither goes first, to agree with `Ord`
+impl PartialEq for PrioritizedSignal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PrioritizedSignal {}
@@ -0,0 +1,222 @@
+use std::collections::{HashMap, VecDeque};
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+use super::MarketData;
+
+/// Fewest returns the rescaled range analysis needs for two window sizes
+pub const MIN_REGIME_OBSERVATIONS: usize = 32;
+
+/// Per-bar return volatility above which the market counts as volatile by default
+pub const DEFAULT_VOLATILITY_THRESHOLD: f64 = 0.05;
+
+/// Most returns kept for regime detection
+const MAX_RETURN_HISTORY: usize = 512;
+
+/// Smallest window used in the rescaled range analysis
+const MIN_RS_WINDOW: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MarketRegime {
+    Trending { strength: f64 },         // 0.0 (random walk) to 1.0 (persistent trend)
+    MeanReverting { half_life_bars: f64 },
+    Volatile,
+}
+
+impl MarketRegime {
+    /// Whether both regimes are the same kind, ignoring their measurements
+    pub fn same_kind(&self, other: &MarketRegime) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// Classifies the market from a series of returns. Volatility above the
+/// threshold wins outright; otherwise the Hurst exponent separates trending
+/// (H >= 0.5) from mean-reverting (H < 0.5) markets.
+#[derive(Debug, Clone)]
+pub struct RegimeDetector {
+    volatility_threshold: f64,
+}
+
+impl Default for RegimeDetector {
+    fn default() -> Self {
+        Self::new(DEFAULT_VOLATILITY_THRESHOLD)
+    }
+}
+
+impl RegimeDetector {
+    pub fn new(volatility_threshold: f64) -> Self {
+        RegimeDetector { volatility_threshold }
+    }
+
+    pub fn volatility_threshold(&self) -> f64 {
+        self.volatility_threshold
+    }
+
+    /// Detect the regime of `returns`. Callers should supply at least
+    /// `MIN_REGIME_OBSERVATIONS` returns; shorter series are treated as a random walk.
+    pub fn detect(&self, returns: &[f64]) -> MarketRegime {
+        if std_dev(returns) > self.volatility_threshold {
+            return MarketRegime::Volatile;
+        }
+
+        let hurst = hurst_exponent(returns).unwrap_or(0.5);
+        if hurst >= 0.5 {
+            MarketRegime::Trending {
+                strength: ((hurst - 0.5) * 2.0).min(1.0),
+            }
+        } else {
+            MarketRegime::MeanReverting {
+                half_life_bars: mean_reversion_half_life(returns),
+            }
+        }
+    }
+}
+
+/// Estimate the Hurst exponent by rescaled range analysis: the slope of
+/// log(R/S) against log(window size). `None` when there is too little data.
+pub fn hurst_exponent(returns: &[f64]) -> Option<f64> {
+    let mut points = Vec::new();
+    let mut window = MIN_RS_WINDOW;
+
+    while window * 2 <= returns.len() {
+        if let Some(rs) = mean_rescaled_range(returns, window) {
+            points.push(((window as f64).ln(), rs.ln()));
+        }
+        window *= 2;
+    }
+
+    if points.len() < 2 {
+        return None;
+    }
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let covariance: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    Some(covariance / variance)
+}
+
+// Average R/S over the non-overlapping windows of `size`, skipping flat windows
+fn mean_rescaled_range(returns: &[f64], size: usize) -> Option<f64> {
+    let ratios: Vec<f64> = returns.chunks_exact(size)
+        .filter_map(|chunk| {
+            let mean = chunk.iter().sum::<f64>() / size as f64;
+            let mut cumulative = 0.0;
+            let mut max = f64::MIN;
+            let mut min = f64::MAX;
+
+            for r in chunk {
+                cumulative += r - mean;
+                max = max.max(cumulative);
+                min = min.min(cumulative);
+            }
+
+            let s = std_dev(chunk);
+            if s > 0.0 {
+                Some((max - min) / s)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if ratios.is_empty() {
+        None
+    } else {
+        Some(ratios.iter().sum::<f64>() / ratios.len() as f64)
+    }
+}
+
+// Half-life of an Ornstein-Uhlenbeck fit to the cumulative return level,
+// from regressing each change on the previous level. A level that does not
+// pull back is given the length of the series.
+fn mean_reversion_half_life(returns: &[f64]) -> f64 {
+    let mut level = 0.0;
+    let mut levels = Vec::with_capacity(returns.len());
+    for r in returns {
+        levels.push(level);
+        level += r;
+    }
+
+    let n = levels.len() as f64;
+    let mean_level = levels.iter().sum::<f64>() / n;
+    let mean_change = returns.iter().sum::<f64>() / n;
+    let covariance: f64 = levels.iter().zip(returns)
+        .map(|(l, r)| (l - mean_level) * (r - mean_change))
+        .sum();
+    let variance: f64 = levels.iter().map(|l| (l - mean_level).powi(2)).sum();
+
+    let lambda = if variance > 0.0 { covariance / variance } else { 0.0 };
+    if lambda < 0.0 {
+        std::f64::consts::LN_2 / -lambda
+    } else {
+        returns.len() as f64
+    }
+}
+
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+}
+
+/// Builds a market-wide return series from successive market data snapshots:
+/// each snapshot contributes the mean return of the symbols priced in both it
+/// and the previous one.
+#[derive(Debug, Default)]
+pub struct MarketReturnTracker {
+    last_prices: HashMap<String, f64>,
+    last_timestamp: Option<DateTime<Utc>>,
+    returns: VecDeque<f64>,
+}
+
+impl MarketReturnTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a snapshot. Snapshots no newer than the last one are ignored so
+    /// re-evaluating the same data doesn't add flat returns.
+    pub fn record_snapshot(&mut self, market_data: &MarketData) {
+        if self.last_timestamp.map(|last| market_data.timestamp <= last).unwrap_or(false) {
+            return;
+        }
+
+        let changes: Vec<f64> = market_data.asset_data.iter()
+            .filter_map(|(symbol, asset)| {
+                let previous = *self.last_prices.get(symbol)?;
+                let price = asset.price.to_f64();
+                if previous > 0.0 && price > 0.0 {
+                    Some(price / previous - 1.0)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if !changes.is_empty() {
+            self.record_return(changes.iter().sum::<f64>() / changes.len() as f64);
+        }
+
+        self.last_prices = market_data.asset_data.iter()
+            .map(|(symbol, asset)| (symbol.clone(), asset.price.to_f64()))
+            .collect();
+        self.last_timestamp = Some(market_data.timestamp);
+    }
+
+    pub fn record_return(&mut self, value: f64) {
+        self.returns.push_back(value);
+        while self.returns.len() > MAX_RETURN_HISTORY {
+            self.returns.pop_front();
+        }
+    }
+
+    pub fn returns(&self) -> Vec<f64> {
+        self.returns.iter().copied().collect()
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use chrono::Utc;
+use serde::Serialize;
+use tokio::sync::{oneshot, RwLock};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, warn};
+
+use super::{MarketData, PrioritizedSignal, StrategyManager};
+use crate::market_data::MarketDataManager;
+use crate::order::OrderManager;
+
+/// Time between scheduled evaluations by default
+pub const DEFAULT_EVALUATION_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Evaluation counts since the scheduler was started, with the rates they
+/// average out to over that time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct SchedulerMetrics {
+    pub evaluations: u64,
+    pub signals_generated: u64, // Signals from the active strategy, before the confidence check
+    pub signals_placed: u64, // Signals placed successfully as orders
+    pub evaluations_per_second: f64,
+    pub signals_generated_per_minute: f64,
+}
+
+#[derive(Default)]
+struct SchedulerCounters {
+    evaluations: u64,
+    signals_generated: u64,
+    signals_placed: u64,
+    started_at: Option<Instant>,
+}
+
+// Handles an evaluation needs, shared by `evaluate_once` and the scheduled loop
+#[derive(Clone)]
+struct EvaluationContext {
+    strategy_manager: Arc<RwLock<StrategyManager>>,
+    market_data_manager: Arc<RwLock<MarketDataManager>>,
+    order_manager: Arc<RwLock<OrderManager>>,
+    min_confidence: f64,
+    capital: Option<f64>,
+    counters: Arc<Mutex<SchedulerCounters>>,
+    closed_positions_seen: Arc<tokio::sync::Mutex<usize>>, // Cursor into the position manager's closed positions
+}
+
+impl EvaluationContext {
+    // Evaluate the active strategy on the current market data and place its
+    // signals when it is confident enough. Returns the number placed.
+    async fn evaluate(&self) -> usize {
+        self.record_closed_trades().await;
+        let current_data = self.market_data_manager.read().await.get_current_data();
+        let market_data = current_data.read().await.clone();
+        self.record_equity(&market_data).await;
+
+        // Liquidity filtering happens within the strategy manager
+        let (strategy, result) = {
+            let strategy_manager = self.strategy_manager.read().await;
+            let Some(strategy) = strategy_manager.active_strategy().map(str::to_string) else {
+                self.record(0, 0);
+                return 0;
+            };
+            (strategy, strategy_manager.get_active_strategy_signals(&market_data))
+        };
+        let Some(result) = result else {
+            self.record(0, 0);
+            return 0;
+        };
+
+        let generated = result.signals.len();
+        if generated == 0 || result.confidence < self.min_confidence {
+            if generated > 0 {
+                debug!("Skipping {} signals from {}: confidence {:.2} below {:.2}", generated, strategy, result.confidence, self.min_confidence);
+            }
+            self.record(generated, 0);
+            return 0;
+        }
+
+        let mut queue = BinaryHeap::new();
+        for signal in result.signals {
+            let sequence = queue.len() as u64;
+            queue.push(PrioritizedSignal::new(&strategy, signal, sequence));
+        }
+        let placed = self.order_manager.read().await.place_signals(queue).await
+            .iter()
+            .filter(|result| result.is_ok())
+            .count();
+        debug!("Placed {} of {} signals from {}", placed, generated, strategy);
+
+        self.record(generated, placed);
+        placed
+    }
+
+    // Give strategies the P&L of positions they opened that have closed since
+    // the last evaluation, so the position sizer sizes from their record
+    async fn record_closed_trades(&self) {
+        let mut seen = self.closed_positions_seen.lock().await;
+        let positions = self.order_manager.read().await.get_position_manager();
+        let (closed, cursor) = positions.closed_positions_since(*seen).await;
+        *seen = cursor;
+        if !closed.is_empty() {
+            self.strategy_manager.write().await.record_closed_positions(&closed);
+        }
+    }
+
+    // Mark positions to the market data and feed capital plus total P&L to
+    // the drawdown monitor, which pauses running strategies on a breach
+    async fn record_equity(&self, market_data: &MarketData) {
+        let Some(capital) = self.capital else {
+            return;
+        };
+        let positions = self.order_manager.read().await.get_position_manager();
+        positions.mark_to_market(market_data).await;
+        let equity = capital + positions.total_pnl().await;
+        self.strategy_manager.write().await.record_equity(equity, Utc::now());
+    }
+
+    fn record(&self, generated: usize, placed: usize) {
+        let mut counters = self.counters.lock().unwrap();
+        counters.evaluations += 1;
+        counters.signals_generated += generated as u64;
+        counters.signals_placed += placed as u64;
+    }
+}
+
+/// Evaluates the active strategy on a fixed interval and places the signals of
+/// confident results as orders, so trading does not wait on the evaluate endpoint
+pub struct StrategyEvaluationScheduler {
+    context: EvaluationContext,
+    evaluation_interval: Duration,
+    shutdown_signal: Option<oneshot::Sender<()>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl StrategyEvaluationScheduler {
+    pub fn new(
+        strategy_manager: Arc<RwLock<StrategyManager>>,
+        market_data_manager: Arc<RwLock<MarketDataManager>>,
+        order_manager: Arc<RwLock<OrderManager>>,
+        evaluation_interval: Duration,
+    ) -> Self {
+        StrategyEvaluationScheduler {
+            context: EvaluationContext {
+                strategy_manager,
+                market_data_manager,
+                order_manager,
+                min_confidence: 0.0,
+                capital: None,
+                counters: Arc::default(),
+                closed_positions_seen: Arc::default(),
+            },
+            evaluation_interval,
+            shutdown_signal: None,
+            task: None,
+        }
+    }
+
+    /// Least confidence a result needs for its signals to be placed
+    pub fn with_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.context.min_confidence = min_confidence;
+        self
+    }
+
+    /// Capital the strategies trade. With it set, each evaluation first marks
+    /// positions to market and records capital plus total P&L as equity, for
+    /// the drawdown monitor and the position sizer.
+    pub fn with_capital(mut self, capital: f64) -> Self {
+        self.context.capital = Some(capital);
+        self
+    }
+
+    pub fn evaluation_interval(&self) -> Duration {
+        self.evaluation_interval
+    }
+
+    pub fn min_confidence(&self) -> f64 {
+        self.context.min_confidence
+    }
+
+    pub fn capital(&self) -> Option<f64> {
+        self.context.capital
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.task.as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    /// Start evaluating every interval, the first time straight away. An
+    /// evaluation that overruns the interval skips the ticks it missed.
+    pub fn start(&mut self) -> Result<(), String> {
+        if self.is_running() {
+            return Err("Strategy evaluation scheduler is already running".to_string());
+        }
+        if self.evaluation_interval.is_zero() {
+            return Err("Evaluation interval must be positive".to_string());
+        }
+
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+        self.shutdown_signal = Some(shutdown_tx);
+        self.context.counters.lock().unwrap().started_at = Some(Instant::now());
+
+        let context = self.context.clone();
+        let evaluation_interval = self.evaluation_interval;
+        self.task = Some(tokio::spawn(async move {
+            info!("Starting strategy evaluation every {:?}", evaluation_interval);
+            let mut ticker = tokio::time::interval(evaluation_interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {
+                        context.evaluate().await;
+                    }
+
+                    _ = &mut shutdown_rx => {
+                        break;
+                    }
+                }
+            }
+
+            info!("Strategy evaluation stopped");
+        }));
+        Ok(())
+    }
+
+    /// Signal the evaluation loop to stop and wait for it to finish. An
+    /// evaluation in progress completes first.
+    pub async fn stop(&mut self) -> Result<(), String> {
+        if let Some(shutdown_signal) = self.shutdown_signal.take() {
+            if shutdown_signal.send(()).is_err() {
+                warn!("Strategy evaluation loop had already stopped");
+            }
+        }
+        if let Some(task) = self.task.take() {
+            task.await.map_err(|e| format!("Strategy evaluation task failed: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Run one evaluation now, outside the schedule. Returns the number of signals placed.
+    pub async fn evaluate_once(&self) -> usize {
+        self.context.evaluate().await
+    }
+
+    pub fn metrics(&self) -> SchedulerMetrics {
+        let counters = self.context.counters.lock().unwrap();
+        let elapsed = counters.started_at.map(|started| started.elapsed().as_secs_f64()).unwrap_or(0.0);
+        let per_second = |count: u64| if elapsed > 0.0 { count as f64 / elapsed } else { 0.0 };
+
+        SchedulerMetrics {
+            evaluations: counters.evaluations,
+            signals_generated: counters.signals_generated,
+            signals_placed: counters.signals_placed,
+            evaluations_per_second: per_second(counters.evaluations),
+            signals_generated_per_minute: per_second(counters.signals_generated) * 60.0,
+        }
+    }
+}
+
+impl Drop for StrategyEvaluationScheduler {
+    fn drop(&mut self) {
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
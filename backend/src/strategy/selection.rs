@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+use utoipa::ToSchema;
+
+use super::StrategyResult;
+
+/// How strategy results are scored against each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionMode {
+    /// Raw expected profit, ignoring confidence
+    #[default]
+    ExpectedProfit,
+    /// Expected profit scaled by confidence
+    ConfidenceWeighted,
+    /// Expected value of winning the expected profit with probability `confidence`
+    /// and losing the same amount otherwise. Results below 50% confidence score
+    /// negative however large their profit.
+    RiskAdjusted,
+}
+
+/// What the best strategy is picked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectionObjective {
+    /// The highest score, whatever capital its signals tie up
+    #[default]
+    Profit,
+    /// The highest score per unit of capital its signals require, for when
+    /// capital is limited
+    Roi,
+}
+
+/// Scores strategy results and picks or blends them
+#[derive(Debug, Clone, Default)]
+pub struct StrategySelector {
+    mode: SelectionMode,
+}
+
+impl StrategySelector {
+    pub fn new(mode: SelectionMode) -> Self {
+        StrategySelector { mode }
+    }
+
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: SelectionMode) {
+        self.mode = mode;
+    }
+
+    pub fn score(&self, result: &StrategyResult) -> f64 {
+        match self.mode {
+            SelectionMode::ExpectedProfit => result.expected_profit,
+            SelectionMode::ConfidenceWeighted => result.expected_profit * result.confidence,
+            SelectionMode::RiskAdjusted => result.expected_profit * (2.0 * result.confidence - 1.0),
+        }
+    }
+
+    /// Name of the highest scoring strategy
+    pub fn select_best(&self, results: &HashMap<String, StrategyResult>) -> Option<String> {
+        results.iter()
+            .map(|(name, result)| (name, self.score(result)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(name, _)| name.clone())
+    }
+
+    /// Name of the strategy scoring highest per unit of required capital, by
+    /// name in `capital`. Strategies needing no capital are passed over.
+    pub fn select_best_per_capital(&self, results: &HashMap<String, StrategyResult>, capital: &HashMap<String, f64>) -> Option<String> {
+        results.iter()
+            .filter_map(|(name, result)| match capital.get(name) {
+                Some(&required) if required > 0.0 => Some((name, self.score(result) / required)),
+                _ => None,
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(name, _)| name.clone())
+    }
+
+    /// Combine the signals of every strategy at or above `confidence_floor` with a
+    /// positive score. Each strategy's signal quantities are scaled by its share of
+    /// the total score, so the blend commits about as much as a single strategy.
+    /// Returns `None` when no strategy qualifies.
+    pub fn blend(&self, results: &HashMap<String, StrategyResult>, confidence_floor: f64) -> Option<StrategyResult> {
+        let mut qualifying: Vec<(&String, &StrategyResult, f64)> = results.iter()
+            .filter(|(_, result)| result.confidence >= confidence_floor)
+            .map(|(name, result)| (name, result, self.score(result)))
+            .filter(|(_, _, score)| *score > 0.0)
+            .collect();
+        if qualifying.is_empty() {
+            return None;
+        }
+
+        // Keep the blended signal order stable across calls
+        qualifying.sort_by(|a, b| a.0.cmp(b.0));
+
+        let total_score: f64 = qualifying.iter().map(|(_, _, score)| score).sum();
+        let mut signals = Vec::new();
+        let mut confidence = 0.0;
+        let mut expected_profit = 0.0;
+        let mut timestamp = qualifying[0].1.timestamp;
+
+        for (_, result, score) in &qualifying {
+            let weight = score / total_score;
+
+            signals.extend(result.signals.iter().cloned().map(|mut signal| {
+                signal.quantity *= weight;
+                signal
+            }));
+            confidence += result.confidence * weight;
+            expected_profit += result.expected_profit * weight;
+            timestamp = timestamp.max(result.timestamp);
+        }
+
+        Some(StrategyResult {
+            signals,
+            confidence,
+            expected_profit,
+            timestamp,
+            warming_up: false,
+        })
+    }
+}
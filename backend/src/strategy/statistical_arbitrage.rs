@@ -1,10 +1,14 @@
-use std::collections::HashMap;
-use tracing::{info, debug, error};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use tracing::debug;
 use super::{
     Strategy, AssetType, MarketData, StrategyResult, 
-    TradeSignal, TradeDirection, TimeInForce, StrategyParams
+    TradeSignal, TradeDirection, TimeInForce, StrategyParams, MarketRegime
 };
+use crate::error::TradingError;
+use super::params::{validate_params, ParamSpec, ParamType};
 
+#[allow(dead_code)]
 pub struct StatisticalArbitrageStrategy {
     name: String,
     description: String,
@@ -15,6 +19,13 @@ pub struct StatisticalArbitrageStrategy {
     lookback_period: usize,
     max_position_size: f64,
     pairs: Vec<(String, String)>, // Pairs of correlated assets to monitor
+    spread_history: Mutex<HashMap<(String, String), VecDeque<f64>>>, // Last `lookback_period` spreads per pair, oldest first
+}
+
+impl Default for StatisticalArbitrageStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl StatisticalArbitrageStrategy {
@@ -33,6 +44,7 @@ impl StatisticalArbitrageStrategy {
             lookback_period: 100,
             max_position_size: 100000.0,
             pairs: Vec::new(),
+            spread_history: Mutex::new(HashMap::new()),
         }
     }
 
@@ -60,7 +72,7 @@ impl StatisticalArbitrageStrategy {
     }
 
     // Find pairs of correlated assets
-    fn identify_pairs(&self, market_data: &MarketData) -> Vec<(String, String)> {
+    fn identify_pairs(&self, _market_data: &MarketData) -> Vec<(String, String)> {
         // In a real implementation, this would analyze historical price data
         // to find pairs with high correlation
         // For now, we'll return some predefined pairs
@@ -89,26 +101,29 @@ impl Strategy for StatisticalArbitrageStrategy {
 
         debug!("Evaluating statistical arbitrage strategy");
         
-        // In a real implementation, we would:
-        // 1. Retrieve historical data for the pairs we're monitoring
-        // 2. Calculate current spread between paired assets
-        // 3. Calculate z-score to determine if the spread is statistically significant
-        // 4. Generate trade signals for pairs with z-scores exceeding our threshold
-        
-        // For the sake of this example, let's generate a simple signal
+        let mut spread_history = self.spread_history.lock().unwrap();
         for (asset1, asset2) in self.identify_pairs(market_data) {
             if let (Some(data1), Some(data2)) = (
                 market_data.asset_data.get(&asset1),
                 market_data.asset_data.get(&asset2)
             ) {
+                if data2.price.to_f64() <= 0.0 {
+                    continue;
+                }
                 // Calculate the spread (in a real implementation, this might be more complex)
-                let spread = data1.price / data2.price;
+                let spread = data1.price.to_f64() / data2.price.to_f64();
                 
-                // Assume we have historical spread data (in a real implementation, this would be stored/retrieved)
-                let historical_spreads = vec![spread * 0.98, spread * 0.99, spread * 1.01, spread * 1.02];
-                
-                // Calculate z-score
-                let z_score = self.calculate_z_score(&historical_spreads, spread);
+                // The spread is judged against the window before it, once the window is full
+                let history = spread_history.entry((asset1.clone(), asset2.clone())).or_default();
+                let z_score = if history.len() >= self.lookback_period {
+                    self.calculate_z_score(history.make_contiguous(), spread)
+                } else {
+                    0.0
+                };
+                history.push_back(spread);
+                while history.len() > self.lookback_period {
+                    history.pop_front();
+                }
                 
                 // If z-score exceeds threshold, generate signals
                 if z_score.abs() > self.z_score_threshold {
@@ -122,25 +137,29 @@ impl Strategy for StatisticalArbitrageStrategy {
                     
                     // Calculate position size (simplified)
                     let position_size = self.max_position_size / 2.0;
+                    let buy_price = market_data.asset_data[&buy_asset].price.to_f64();
+                    let sell_price = market_data.asset_data[&sell_asset].price.to_f64();
                     
                     // Generate buy signal
                     signals.push(TradeSignal {
                         asset: buy_asset,
                         direction: TradeDirection::Buy,
-                        quantity: position_size / market_data.asset_data[&buy_asset].price,
-                        limit_price: Some(market_data.asset_data[&buy_asset].price * 1.001), // Small buffer
+                        quantity: position_size / buy_price,
+                        limit_price: Some(buy_price * 1.001), // Small buffer
                         stop_price: None,
-                        time_in_force: TimeInForce::DayOnly,
+                        time_in_force: TimeInForce::Day,
+                        priority: TimeInForce::Day.default_signal_priority(),
                     });
                     
                     // Generate sell signal
                     signals.push(TradeSignal {
                         asset: sell_asset,
                         direction: TradeDirection::Sell,
-                        quantity: position_size / market_data.asset_data[&sell_asset].price,
-                        limit_price: Some(market_data.asset_data[&sell_asset].price * 0.999), // Small buffer
+                        quantity: position_size / sell_price,
+                        limit_price: Some(sell_price * 0.999), // Small buffer
                         stop_price: None,
-                        time_in_force: TimeInForce::DayOnly,
+                        time_in_force: TimeInForce::Day,
+                        priority: TimeInForce::Day.default_signal_priority(),
                     });
                     
                     // Update confidence and expected profit
@@ -158,69 +177,55 @@ impl Strategy for StatisticalArbitrageStrategy {
             confidence,
             expected_profit,
             timestamp,
+            warming_up: false,
         }
     }
 
-    fn update_params(&mut self, params: StrategyParams) -> Result<(), String> {
+    fn param_schema(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec::new("correlation_threshold", ParamType::Number).non_negative().at_most(1.0),
+            ParamSpec::new("z_score_threshold", ParamType::Number).positive(),
+            ParamSpec::new("lookback_period", ParamType::Integer).positive(),
+            ParamSpec::new("max_position_size", ParamType::Number).positive(),
+            ParamSpec::new("pairs", ParamType::StringPairList),
+        ]
+    }
+
+    fn update_params(&mut self, params: StrategyParams) -> Result<(), TradingError> {
+        validate_params(&self.param_schema(), &params)?;
+        
+        // Types and ranges were checked above
         for (key, value) in params.params {
             match key.as_str() {
-                "correlation_threshold" => {
-                    if let Some(v) = value.as_f64() {
-                        if (0.0..=1.0).contains(&v) {
-                            self.correlation_threshold = v;
-                        } else {
-                            return Err("correlation_threshold must be between 0 and 1".to_string());
-                        }
-                    }
-                },
-                "z_score_threshold" => {
-                    if let Some(v) = value.as_f64() {
-                        if v > 0.0 {
-                            self.z_score_threshold = v;
-                        } else {
-                            return Err("z_score_threshold must be positive".to_string());
-                        }
-                    }
-                },
-                "lookback_period" => {
-                    if let Some(v) = value.as_u64() {
-                        if v > 0 {
-                            self.lookback_period = v as usize;
-                        } else {
-                            return Err("lookback_period must be positive".to_string());
-                        }
-                    }
-                },
-                "max_position_size" => {
-                    if let Some(v) = value.as_f64() {
-                        if v > 0.0 {
-                            self.max_position_size = v;
-                        } else {
-                            return Err("max_position_size must be positive".to_string());
-                        }
-                    }
-                },
+                "correlation_threshold" => self.correlation_threshold = value.as_f64().unwrap_or(self.correlation_threshold),
+                "z_score_threshold" => self.z_score_threshold = value.as_f64().unwrap_or(self.z_score_threshold),
+                "lookback_period" => self.lookback_period = value.as_u64().map(|v| v as usize).unwrap_or(self.lookback_period),
+                "max_position_size" => self.max_position_size = value.as_f64().unwrap_or(self.max_position_size),
                 "pairs" => {
-                    if let Some(pairs_array) = value.as_array() {
-                        let mut new_pairs = Vec::new();
-                        for pair in pairs_array {
-                            if let Some(pair_array) = pair.as_array() {
-                                if pair_array.len() == 2 {
-                                    if let (Some(asset1), Some(asset2)) = (pair_array[0].as_str(), pair_array[1].as_str()) {
-                                        new_pairs.push((asset1.to_string(), asset2.to_string()));
-                                    }
-                                }
-                            }
-                        }
-                        self.pairs = new_pairs;
-                    }
+                    self.pairs = value.as_array().into_iter().flatten()
+                        .filter_map(|pair| match pair.as_array().map(Vec::as_slice) {
+                            Some([asset1, asset2]) => Some((asset1.as_str()?.to_string(), asset2.as_str()?.to_string())),
+                            _ => None,
+                        })
+                        .collect();
+                    self.spread_history.get_mut().unwrap().retain(|pair, _| self.pairs.contains(pair));
                 },
-                _ => {
-                    return Err(format!("Unknown parameter: {}", key));
-                }
+                _ => {},
             }
         }
         
         Ok(())
     }
+
+    // Ready once every pair has a full window of spreads
+    fn is_warmed_up(&self) -> bool {
+        let spread_history = self.spread_history.lock().unwrap();
+        self.pairs.iter()
+            .all(|pair| spread_history.get(pair).is_some_and(|history| history.len() >= self.lookback_period))
+    }
+
+    // Spreads only revert reliably in mean-reverting markets
+    fn suitable_regimes(&self) -> Vec<MarketRegime> {
+        vec![MarketRegime::MeanReverting { half_life_bars: 0.0 }]
+    }
 } 
\ No newline at end of file
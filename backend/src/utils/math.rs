@@ -0,0 +1,40 @@
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+/// Exponential moving average of `values` at each point, oldest first:
+/// `EMA[t] = value[t] * k + EMA[t - 1] * (1 - k)` with `k = 2 / (period + 1)`,
+/// seeded with the first value. Empty for an empty series or a zero period.
+pub fn ema_series(values: &[f64], period: usize) -> Vec<f64> {
+    if period == 0 {
+        return Vec::new();
+    }
+
+    let k = 2.0 / (period as f64 + 1.0);
+    let mut series = Vec::with_capacity(values.len());
+    for value in values {
+        let next = match series.last() {
+            Some(previous) => value * k + previous * (1.0 - k),
+            None => *value,
+        };
+        series.push(next);
+    }
+    series
+}
+
+/// Latest exponential moving average of `values`; see `ema_series`
+pub fn ema(values: &[f64], period: usize) -> Option<f64> {
+    ema_series(values, period).last().copied()
+}
+
+/// The largest multiple of `lot_size` not above `quantity`, worked out in
+/// decimal so multiples like 0.3 come out exact. A lot size that is not
+/// positive leaves the quantity as it is.
+pub fn round_down_to_lot(quantity: f64, lot_size: f64) -> f64 {
+    let (Some(quantity_dec), Some(lot)) = (Decimal::from_f64(quantity), Decimal::from_f64(lot_size)) else {
+        return quantity;
+    };
+    if lot <= Decimal::ZERO {
+        return quantity;
+    }
+    ((quantity_dec / lot).floor() * lot).to_f64().unwrap_or(quantity)
+}
@@ -0,0 +1,6 @@
+// Shared numeric and text helpers used across strategies, analytics and the API
+pub mod math;
+pub mod text;
+
+pub use math::{ema, ema_series};
+pub use text::{name_key, to_snake_case};
@@ -0,0 +1,25 @@
+/// `PascalCase` name in `snake_case`, e.g. `PartiallyFilled` to `partially_filled`
+pub fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// Lower-case `name` without separators, so `PartiallyFilled`,
+/// `partially_filled` and `partially-filled` compare equal when parsing
+pub fn name_key(name: &str) -> String {
+    name.trim()
+        .chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
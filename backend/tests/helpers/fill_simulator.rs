@@ -0,0 +1,130 @@
+use arb_platform::channel::EventSender;
+use arb_platform::order::{Order, OrderEvent, OrderManager, OrderStatus};
+use arb_platform::strategy::TradeDirection;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// Price the simulator fills orders at
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillPriceMode {
+    /// The order's limit price; orders without one are left unfilled
+    AtLimit,
+    /// The same price for every order
+    AtMarketPrice(f64),
+    /// The limit price moved against the order by a slippage drawn from the
+    /// range, in basis points: buys pay more and sells receive less
+    Random { min_slippage_bps: i32, max_slippage_bps: i32 },
+}
+
+/// A fill the simulator reported
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedFill {
+    pub order_id: Uuid,
+    pub quantity: f64,
+    pub price: f64,
+}
+
+/// Fills every order an `OrderManager` places in full, after a delay, by
+/// sending it the update an exchange would, so positions follow the fills
+/// without any exchange registered. The manager still routes each order and
+/// fails it for want of an exchange, so a fill arriving after that records the
+/// filled quantity while the order stays Failed. Filling stops when the
+/// simulator is dropped.
+pub struct TestOrderFillSimulator {
+    fills: Arc<Mutex<Vec<SimulatedFill>>>,
+    task: JoinHandle<()>,
+}
+
+impl TestOrderFillSimulator {
+    /// Fill orders placed with `manager` at their limit price after 10ms
+    pub fn attach(manager: &OrderManager) -> Self {
+        Self::start(manager.get_event_sender(), manager.subscribe_events(), 10, FillPriceMode::AtLimit, 0)
+    }
+
+    /// Fill each order announced on `events` by sending its fill through
+    /// `event_sender` `fill_delay_ms` later. `seed` drives random slippage.
+    pub fn start(
+        event_sender: EventSender<OrderEvent>,
+        mut events: broadcast::Receiver<OrderEvent>,
+        fill_delay_ms: u64,
+        fill_price: FillPriceMode,
+        seed: u64,
+    ) -> Self {
+        let fills = Arc::new(Mutex::new(Vec::new()));
+        let recorded = fills.clone();
+        let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(seed)));
+
+        let task = tokio::spawn(async move {
+            loop {
+                let order = match events.recv().await {
+                    Ok(OrderEvent::New(order)) => order,
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                let Some(price) = fill_price_for(&order, fill_price, &rng) else {
+                    continue;
+                };
+
+                let event_sender = event_sender.clone();
+                let recorded = recorded.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(fill_delay_ms)).await;
+                    let fill = OrderEvent::Update {
+                        order_id: order.id,
+                        status: Some(OrderStatus::Filled),
+                        filled_qty: Some(order.quantity),
+                        avg_fill_price: Some(price),
+                    };
+                    if event_sender.send(fill).await.is_ok() {
+                        recorded.lock().unwrap().push(SimulatedFill { order_id: order.id, quantity: order.quantity, price });
+                    }
+                });
+            }
+        });
+
+        TestOrderFillSimulator { fills, task }
+    }
+
+    pub fn fills(&self) -> Vec<SimulatedFill> {
+        self.fills.lock().unwrap().clone()
+    }
+
+    /// Wait up to a second for `count` fills
+    pub async fn wait_for_fills(&self, count: usize) -> Vec<SimulatedFill> {
+        for _ in 0..100 {
+            if self.fills.lock().unwrap().len() >= count {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        self.fills()
+    }
+}
+
+impl Drop for TestOrderFillSimulator {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn fill_price_for(order: &Order, mode: FillPriceMode, rng: &Mutex<StdRng>) -> Option<f64> {
+    let limit = order.price.map(|price| price.to_f64());
+    match mode {
+        FillPriceMode::AtLimit => limit,
+        FillPriceMode::AtMarketPrice(price) => Some(price),
+        FillPriceMode::Random { min_slippage_bps, max_slippage_bps } => {
+            let bps = rng.lock().unwrap().gen_range(min_slippage_bps..=max_slippage_bps);
+            let slippage = f64::from(bps) / 10_000.0;
+            limit.map(|price| match order.direction {
+                TradeDirection::Buy => price * (1.0 + slippage),
+                TradeDirection::Sell => price * (1.0 - slippage),
+            })
+        },
+    }
+}
@@ -0,0 +1,117 @@
+use arb_platform::backtest::StrategyFactory;
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    AssetData, AssetType, MarketData, Strategy, StrategyParams, StrategyResult, TimeInForce, TradeDirection,
+    TradeSignal,
+};
+use arb_platform::models::Price;
+
+use chrono::{Duration, TimeZone, Utc};
+use std::collections::HashMap;
+
+/// Strategy that always holds `quantity` of BTC/USD on one side. Parameters:
+/// `side` ("buy" or "sell") and `quantity` (positive).
+pub struct FixedSideStrategy {
+    direction: TradeDirection,
+    quantity: f64,
+    confidence: f64,
+}
+
+impl Default for FixedSideStrategy {
+    fn default() -> Self {
+        FixedSideStrategy { direction: TradeDirection::Buy, quantity: 1.0, confidence: 1.0 }
+    }
+}
+
+impl FixedSideStrategy {
+    /// Long one unit, reporting `confidence` with its signal
+    pub fn with_confidence(confidence: f64) -> Self {
+        FixedSideStrategy { confidence, ..Self::default() }
+    }
+
+    pub fn factory() -> StrategyFactory {
+        Box::new(|| Box::new(FixedSideStrategy::default()))
+    }
+}
+
+impl Strategy for FixedSideStrategy {
+    fn name(&self) -> &str {
+        "Fixed Side"
+    }
+
+    fn description(&self) -> &str {
+        "Always holds one side of BTC/USD"
+    }
+
+    fn asset_types(&self) -> Vec<AssetType> {
+        vec![AssetType::Crypto]
+    }
+
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        StrategyResult {
+            signals: vec![TradeSignal {
+                asset: "BTC/USD".to_string(),
+                direction: self.direction,
+                quantity: self.quantity,
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                priority: TimeInForce::Day.default_signal_priority(),
+            }],
+            confidence: self.confidence,
+            expected_profit: 0.0,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+
+    fn update_params(&mut self, params: StrategyParams) -> Result<(), TradingError> {
+        for (key, value) in params.params {
+            match key.as_str() {
+                "side" => match value.as_str() {
+                    Some("buy") => self.direction = TradeDirection::Buy,
+                    Some("sell") => self.direction = TradeDirection::Sell,
+                    _ => return Err(TradingError::Validation("side must be 'buy' or 'sell'".to_string())),
+                },
+                "quantity" => match value.as_f64() {
+                    Some(v) if v > 0.0 => self.quantity = v,
+                    _ => return Err(TradingError::Validation("quantity must be positive".to_string())),
+                },
+                _ => return Err(TradingError::Validation(format!("Unknown parameter: {}", key))),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Daily BTC/USD snapshots at the given prices, starting 2024-01-01
+pub fn daily_history(prices: &[f64]) -> Vec<MarketData> {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    prices.iter().enumerate().map(|(day, price)| {
+        let timestamp = start + Duration::days(day as i64);
+        let mut asset_data = HashMap::new();
+        asset_data.insert("BTC/USD".to_string(), AssetData {
+            symbol: "BTC/USD".to_string(),
+            asset_type: AssetType::Crypto,
+            price: Price::from(*price),
+            volume: 0.0,
+            bid: Price::from(*price),
+            ask: Price::from(*price),
+            tick_size: None,
+            lot_size: None,
+            exchange: "Historical".to_string(),
+            last_update: timestamp,
+        });
+        MarketData { timestamp, asset_data }
+    }).collect()
+}
+
+/// Prices that rise every day, by 1 and 3 alternately
+pub fn rising_prices(days: usize) -> Vec<f64> {
+    let mut price = 100.0;
+    (0..days).map(|day| {
+        let current = price;
+        price += if day % 2 == 0 { 1.0 } else { 3.0 };
+        current
+    }).collect()
+}
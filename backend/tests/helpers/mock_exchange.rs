@@ -0,0 +1,337 @@
+use arb_platform::exchange::{
+    Exchange, ExchangeType, MarketSnapshot, OrderStatusResponse, AccountBalance, Position, CancellationResult, MarginInfo,
+    AccountTransaction, FeeSchedule, OrderStatus as ExchangeOrderStatus,
+};
+use arb_platform::order::{Order, OrderType};
+use arb_platform::models::Price;
+
+use arb_platform::error::TradingError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// A call made to the mock, with its arguments
+#[derive(Debug, Clone)]
+pub enum ExchangeCall {
+    Connect,
+    Disconnect,
+    GetSupportedAssets,
+    GetMarketData { symbol: String },
+    SubmitOrder(Box<Order>),
+    CancelOrder(Uuid),
+    AmendOrder { order_id: Uuid, new_quantity: f64 },
+    GetOrderStatus(Uuid),
+    GetAccountBalance,
+    GetPositions,
+    GetMarginInfo { symbol: String },
+    GetFeeSchedule,
+    GetAccountHistory { from: DateTime<Utc>, to: DateTime<Utc> },
+}
+
+type SubmitResponse = Arc<dyn Fn(&Order) -> Result<(), TradingError> + Send + Sync>;
+
+/// Deterministic exchange that records every call for later assertions.
+/// Clones share the same call log and state, so a test can keep a handle
+/// after boxing the mock into an `OrderRouter`.
+#[derive(Clone)]
+pub struct MockExchange {
+    name: String,
+    connected: bool,
+    calls: Arc<Mutex<Vec<ExchangeCall>>>,
+    orders: Arc<Mutex<HashMap<Uuid, Order>>>, // Accepted orders still open on the mock
+    submit_response: Arc<Mutex<Option<SubmitResponse>>>,
+    submit_delay: Arc<Mutex<Duration>>, // How long each submission takes to be answered
+    order_statuses: Arc<Mutex<HashMap<Uuid, OrderStatusResponse>>>, // Reported instead of Open when set
+    balance: Arc<Mutex<AccountBalance>>,
+    positions: Arc<Mutex<Vec<Position>>>,
+    margin: Arc<Mutex<Option<MarginInfo>>>, // None until set, as for an exchange without margin trading
+    order_types: Arc<Mutex<Vec<OrderType>>>,
+    history: Arc<Mutex<Vec<AccountTransaction>>>, // Reported by range, in the order set
+    quote: Arc<Mutex<(f64, f64)>>, // Bid and ask for every symbol
+    fees: Arc<Mutex<FeeSchedule>>,
+    cancel_error: Arc<Mutex<Option<TradingError>>>, // Returned by every cancel_order call when set
+    margin_error: Arc<Mutex<Option<TradingError>>>, // Returned by every get_margin_info call when set
+}
+
+impl MockExchange {
+    /// A connected mock that accepts every order
+    pub fn new(name: &str) -> Self {
+        MockExchange {
+            name: name.to_string(),
+            connected: true,
+            calls: Arc::new(Mutex::new(Vec::new())),
+            orders: Arc::new(Mutex::new(HashMap::new())),
+            submit_response: Arc::new(Mutex::new(None)),
+            submit_delay: Arc::new(Mutex::new(Duration::ZERO)),
+            order_statuses: Arc::new(Mutex::new(HashMap::new())),
+            balance: Arc::new(Mutex::new(AccountBalance {
+                total: 100000.0,
+                available: 100000.0,
+                currency: "USD".to_string(),
+                additional_balances: Vec::new(),
+                timestamp: Utc::now(),
+            })),
+            positions: Arc::new(Mutex::new(Vec::new())),
+            margin: Arc::new(Mutex::new(None)),
+            order_types: Arc::new(Mutex::new(OrderType::ALL.to_vec())),
+            history: Arc::new(Mutex::new(Vec::new())),
+            quote: Arc::new(Mutex::new((99.95, 100.05))),
+            fees: Arc::new(Mutex::new(FeeSchedule::default())),
+            cancel_error: Arc::new(Mutex::new(None)),
+            margin_error: Arc::new(Mutex::new(None)),
+        }
+    }
+    
+    /// A mock that reports itself disconnected
+    pub fn disconnected(name: &str) -> Self {
+        MockExchange { connected: false, ..Self::new(name) }
+    }
+    
+    /// Decide the outcome of each `submit_order` call
+    pub fn set_submit_order_response(&self, f: impl Fn(&Order) -> Result<(), TradingError> + Send + Sync + 'static) {
+        *self.submit_response.lock().unwrap() = Some(Arc::new(f));
+    }
+    
+    /// Answer each `submit_order` call only after `delay`, leaving orders
+    /// pending submission in the meantime
+    pub fn set_submit_delay(&self, delay: Duration) {
+        *self.submit_delay.lock().unwrap() = delay;
+    }
+    
+    /// Move an accepted order on the mock's side without emitting any event, as
+    /// if the exchange's update had been missed. `get_order_status` reports it.
+    pub fn set_order_status(&self, order_id: Uuid, status: ExchangeOrderStatus, filled_quantity: f64, average_price: Option<f64>) {
+        let quantity = self.orders.lock().unwrap().get(&order_id)
+            .map(|order| order.quantity)
+            .unwrap_or(filled_quantity);
+        self.order_statuses.lock().unwrap().insert(order_id, OrderStatusResponse {
+            order_id,
+            exchange_order_id: Some(format!("MOCK-{}", order_id.simple())),
+            status,
+            filled_quantity,
+            remaining_quantity: (quantity - filled_quantity).max(0.0),
+            average_price,
+            last_update: Utc::now(),
+        });
+    }
+    
+    pub fn set_account_balance(&self, balance: AccountBalance) {
+        *self.balance.lock().unwrap() = balance;
+    }
+    
+    pub fn set_positions(&self, positions: Vec<Position>) {
+        *self.positions.lock().unwrap() = positions;
+    }
+    
+    pub fn set_margin_info(&self, margin: MarginInfo) {
+        *self.margin.lock().unwrap() = Some(margin);
+    }
+    
+    pub fn set_account_history(&self, transactions: Vec<AccountTransaction>) {
+        *self.history.lock().unwrap() = transactions;
+    }
+    
+    /// Quote every symbol at `bid` and `ask`, priced at the midpoint; the
+    /// default is 99.95 / 100.05
+    pub fn set_quote(&self, bid: f64, ask: f64) {
+        *self.quote.lock().unwrap() = (bid, ask);
+    }
+    
+    pub fn set_fee_schedule(&self, fees: FeeSchedule) {
+        *self.fees.lock().unwrap() = fees;
+    }
+    
+    /// Fail every `get_margin_info` call with `error`
+    pub fn fail_margin_info(&self, error: TradingError) {
+        *self.margin_error.lock().unwrap() = Some(error);
+    }
+    
+    /// Fail every `cancel_order` call with `error`
+    pub fn fail_cancellations(&self, error: TradingError) {
+        *self.cancel_error.lock().unwrap() = Some(error);
+    }
+    
+    /// Restrict the order types the mock reports supporting; it supports all by default
+    pub fn set_supported_order_types(&self, order_types: Vec<OrderType>) {
+        *self.order_types.lock().unwrap() = order_types;
+    }
+    
+    pub fn calls(&self) -> Vec<ExchangeCall> {
+        self.calls.lock().unwrap().clone()
+    }
+    
+    pub fn submitted_orders(&self) -> Vec<Order> {
+        self.calls().into_iter()
+            .filter_map(|call| match call {
+                ExchangeCall::SubmitOrder(order) => Some(*order),
+                _ => None,
+            })
+            .collect()
+    }
+    
+    pub fn assert_order_submitted(&self, order_id: Uuid) {
+        assert!(
+            self.submitted_orders().iter().any(|order| order.id == order_id),
+            "Order {} was not submitted to {}; calls: {:?}", order_id, self.name, self.calls()
+        );
+    }
+    
+    pub fn assert_order_cancelled(&self, order_id: Uuid) {
+        assert!(
+            self.calls().iter().any(|call| matches!(call, ExchangeCall::CancelOrder(id) if *id == order_id)),
+            "Order {} was not cancelled on {}; calls: {:?}", order_id, self.name, self.calls()
+        );
+    }
+    
+    fn record(&self, call: ExchangeCall) {
+        self.calls.lock().unwrap().push(call);
+    }
+}
+
+#[async_trait]
+impl Exchange for MockExchange {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn exchange_type(&self) -> ExchangeType {
+        ExchangeType::Crypto
+    }
+    
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+    
+    fn supported_order_types(&self) -> Vec<OrderType> {
+        self.order_types.lock().unwrap().clone()
+    }
+    
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        self.record(ExchangeCall::Connect);
+        self.connected = true;
+        Ok(())
+    }
+    
+    async fn disconnect(&mut self) -> Result<(), TradingError> {
+        self.record(ExchangeCall::Disconnect);
+        self.connected = false;
+        Ok(())
+    }
+    
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError> {
+        self.record(ExchangeCall::GetSupportedAssets);
+        Ok(vec!["BTC/USD".to_string(), "ETH/USD".to_string(), "SOL/USD".to_string()])
+    }
+    
+    async fn get_market_data(&self, symbol: &str) -> Result<MarketSnapshot, TradingError> {
+        self.record(ExchangeCall::GetMarketData { symbol: symbol.to_string() });
+        let (bid, ask) = *self.quote.lock().unwrap();
+        Ok(MarketSnapshot {
+            symbol: symbol.to_string(),
+            price: Price::from((bid + ask) / 2.0),
+            bid: Price::from(bid),
+            ask: Price::from(ask),
+            bid_size: 1.0,
+            ask_size: 1.0,
+            volume: 1000.0,
+            timestamp: Utc::now(),
+            bids: Vec::new(),
+            asks: Vec::new(),
+        })
+    }
+    
+    async fn submit_order(&self, order: Order) -> Result<(), TradingError> {
+        self.record(ExchangeCall::SubmitOrder(Box::new(order.clone())));
+        
+        let delay = *self.submit_delay.lock().unwrap();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        
+        let response = self.submit_response.lock().unwrap().clone();
+        if let Some(response) = response {
+            response(&order)?;
+        }
+        
+        self.orders.lock().unwrap().insert(order.id, order);
+        Ok(())
+    }
+    
+    async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError> {
+        self.record(ExchangeCall::CancelOrder(order_id));
+        if let Some(error) = self.cancel_error.lock().unwrap().clone() {
+            return Err(error);
+        }
+        self.orders.lock().unwrap().remove(&order_id)
+            .map(|order| CancellationResult {
+                exchange_order_id: Some(format!("MOCK-{}", order_id.simple())),
+                cancelled_at: Utc::now(),
+                unfilled_quantity: order.quantity - order.filled_quantity,
+            })
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))
+    }
+    
+    async fn amend_order(&self, order_id: Uuid, new_quantity: f64) -> Result<(), TradingError> {
+        self.record(ExchangeCall::AmendOrder { order_id, new_quantity });
+        let mut orders = self.orders.lock().unwrap();
+        let order = orders.get_mut(&order_id)
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))?;
+        order.quantity = new_quantity;
+        Ok(())
+    }
+    
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
+        self.record(ExchangeCall::GetOrderStatus(order_id));
+        if let Some(response) = self.order_statuses.lock().unwrap().get(&order_id) {
+            return Ok(response.clone());
+        }
+        
+        let orders = self.orders.lock().unwrap();
+        let order = orders.get(&order_id)
+            .ok_or_else(|| TradingError::NotFound(format!("Order {} not found", order_id)))?;
+        
+        Ok(OrderStatusResponse {
+            order_id,
+            exchange_order_id: Some(format!("MOCK-{}", order_id.simple())),
+            status: ExchangeOrderStatus::Open,
+            filled_quantity: 0.0,
+            remaining_quantity: order.quantity,
+            average_price: None,
+            last_update: Utc::now(),
+        })
+    }
+    
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError> {
+        self.record(ExchangeCall::GetAccountBalance);
+        Ok(self.balance.lock().unwrap().clone())
+    }
+    
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError> {
+        self.record(ExchangeCall::GetPositions);
+        Ok(self.positions.lock().unwrap().clone())
+    }
+    
+    async fn get_margin_info(&self, symbol: &str) -> Result<Option<MarginInfo>, TradingError> {
+        self.record(ExchangeCall::GetMarginInfo { symbol: symbol.to_string() });
+        if let Some(error) = self.margin_error.lock().unwrap().clone() {
+            return Err(error);
+        }
+        Ok(self.margin.lock().unwrap().clone())
+    }
+    
+    async fn get_fee_schedule(&self) -> Result<FeeSchedule, TradingError> {
+        self.record(ExchangeCall::GetFeeSchedule);
+        Ok(self.fees.lock().unwrap().clone())
+    }
+    
+    async fn get_account_history(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<AccountTransaction>, TradingError> {
+        self.record(ExchangeCall::GetAccountHistory { from, to });
+        Ok(self.history.lock().unwrap().iter()
+            .filter(|transaction| transaction.timestamp >= from && transaction.timestamp < to)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,7 @@
+// Shared test helpers
+pub mod mock_exchange;
+pub mod recording_notifier;
+pub mod fixed_side_strategy;
+pub mod strategy_harness;
+pub mod fill_simulator;
+pub mod orders;
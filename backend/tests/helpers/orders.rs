@@ -0,0 +1,38 @@
+use arb_platform::order::{Order, OrderStatus, OrderType};
+use arb_platform::strategy::{TradeDirection, TimeInForce};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+/// A new limit order to buy 1 BTC/USD at 100, good until cancelled, naming
+/// no exchange. Tests override what they care about with struct update
+/// syntax, so a new `Order` field only needs adding here:
+///
+/// `Order { symbol: "ETH/USD".to_string(), ..test_order() }`
+pub fn test_order() -> Order {
+    Order {
+        id: Uuid::new_v4(),
+        client_order_id: format!("test-{}", Uuid::new_v4().simple()),
+        symbol: "BTC/USD".to_string(),
+        direction: TradeDirection::Buy,
+        order_type: OrderType::Limit,
+        quantity: 1.0,
+        filled_quantity: 0.0,
+        price: Some(Price::from(100.0)),
+        stop_price: None,
+        time_in_force: TimeInForce::GoodTilCancelled,
+        status: OrderStatus::Created,
+        exchange: String::new(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        filled_at: None,
+        average_fill_price: None,
+        unfilled_quantity: None,
+        strategy_id: None,
+        notes: None,
+        tags: Vec::new(),
+        post_only: false,
+        amendment_history: Vec::new(),
+    }
+}
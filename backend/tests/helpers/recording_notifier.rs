@@ -0,0 +1,56 @@
+use arb_platform::notifications::{Notification, NotificationHandler};
+
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+/// Notification handler that keeps everything it is sent. Clones share the
+/// same record, so a test can keep a handle after registering the handler.
+/// When `failing`, every send is recorded and then reported as an error.
+#[derive(Clone, Default)]
+pub struct RecordingNotifier {
+    sent: Arc<Mutex<Vec<Notification>>>,
+    failing: bool,
+}
+
+impl RecordingNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn failing() -> Self {
+        RecordingNotifier {
+            sent: Arc::new(Mutex::new(Vec::new())),
+            failing: true,
+        }
+    }
+
+    pub fn sent(&self) -> Vec<Notification> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    /// Wait up to a second for `count` notifications, for senders that deliver in the background
+    pub async fn wait_for(&self, count: usize) -> Vec<Notification> {
+        for _ in 0..100 {
+            if self.sent.lock().unwrap().len() >= count {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        self.sent()
+    }
+}
+
+#[async_trait]
+impl NotificationHandler for RecordingNotifier {
+    fn name(&self) -> &str {
+        if self.failing { "failing" } else { "recording" }
+    }
+
+    async fn send(&self, notification: Notification) -> Result<(), String> {
+        self.sent.lock().unwrap().push(notification);
+        if self.failing {
+            return Err("delivery failed".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use arb_platform::strategy::{AssetData, AssetType, MarketData, Strategy, StrategyResult, TradeDirection, TradeSignal};
+use arb_platform::models::Price;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::collections::HashMap;
+
+/// Runs a strategy over deterministic price sequences, one snapshot per tick,
+/// and collects its signals. Every signal is treated as filled straight away,
+/// at its limit price or else the tick's price, and fed back through `on_fill`.
+pub struct StrategyTestHarness {
+    strategy: Box<dyn Strategy>,
+    symbols: Vec<(String, f64)>, // In the order added, with their initial prices
+    sequences: HashMap<String, Vec<f64>>,
+    start: DateTime<Utc>,
+    tick_interval: Duration,
+}
+
+impl StrategyTestHarness {
+    pub fn new(strategy: impl Strategy + 'static) -> Self {
+        StrategyTestHarness {
+            strategy: Box::new(strategy),
+            symbols: Vec::new(),
+            sequences: HashMap::new(),
+            start: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            tick_interval: Duration::minutes(1),
+        }
+    }
+
+    /// Quote `symbol` at `initial_price` on every tick its price sequence does not cover
+    pub fn add_symbol(mut self, symbol: &str, initial_price: f64) -> Self {
+        match self.symbols.iter_mut().find(|(existing, _)| existing == symbol) {
+            Some(entry) => entry.1 = initial_price,
+            None => self.symbols.push((symbol.to_string(), initial_price)),
+        }
+        self
+    }
+
+    /// Prices of `symbol` from the first tick on. A symbol not added yet starts
+    /// at the first price; after its last price it stays there.
+    pub fn add_price_sequence(mut self, symbol: &str, prices: Vec<f64>) -> Self {
+        if !self.symbols.iter().any(|(existing, _)| existing == symbol) {
+            let initial_price = prices.first().copied().unwrap_or_default();
+            self = self.add_symbol(symbol, initial_price);
+        }
+        self.sequences.insert(symbol.to_string(), prices);
+        self
+    }
+
+    /// Time between ticks, one minute by default
+    pub fn with_tick_interval(mut self, tick_interval: Duration) -> Self {
+        self.tick_interval = tick_interval;
+        self
+    }
+
+    /// Evaluate the strategy once per tick, as many ticks as the longest price sequence
+    pub fn run(mut self) -> TestRun {
+        let ticks = self.sequences.values().map(Vec::len).max().unwrap_or(0);
+        let asset_type = self.strategy.asset_types().first().copied().unwrap_or(AssetType::Crypto);
+        let mut prices: HashMap<String, f64> = self.symbols.iter().cloned().collect();
+        let mut results = Vec::with_capacity(ticks);
+        let mut fills = Vec::new();
+
+        for tick in 0..ticks {
+            for (symbol, sequence) in &self.sequences {
+                if let Some(price) = sequence.get(tick) {
+                    prices.insert(symbol.clone(), *price);
+                }
+            }
+            let timestamp = self.start + self.tick_interval * tick as i32;
+            let market_data = market_data(&prices, asset_type, timestamp);
+
+            let result = self.strategy.evaluate(&market_data);
+            for signal in &result.signals {
+                let Some(fill_price) = signal.limit_price.or_else(|| prices.get(&signal.asset).copied()) else {
+                    continue;
+                };
+                self.strategy.on_fill(signal, fill_price);
+                fills.push((tick, signal.clone(), fill_price));
+            }
+            results.push(result);
+        }
+
+        TestRun { results, fills }
+    }
+}
+
+fn market_data(prices: &HashMap<String, f64>, asset_type: AssetType, timestamp: DateTime<Utc>) -> MarketData {
+    let asset_data = prices.iter().map(|(symbol, price)| {
+        (symbol.clone(), AssetData {
+            symbol: symbol.clone(),
+            asset_type,
+            price: Price::from(*price),
+            volume: 0.0,
+            bid: Price::from(*price),
+            ask: Price::from(*price),
+            tick_size: None,
+            lot_size: None,
+            exchange: "Harness".to_string(),
+            last_update: timestamp,
+        })
+    }).collect();
+    MarketData { timestamp, asset_data }
+}
+
+/// What a strategy produced on each tick of a harness run
+pub struct TestRun {
+    results: Vec<StrategyResult>,
+    fills: Vec<(usize, TradeSignal, f64)>, // Tick, signal and the price fed to `on_fill`
+}
+
+impl TestRun {
+    pub fn ticks(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn result_at(&self, tick: usize) -> Option<&StrategyResult> {
+        self.results.get(tick)
+    }
+
+    /// Signals from the given tick; none for ticks past the end of the run
+    pub fn signals_at(&self, tick: usize) -> &[TradeSignal] {
+        self.results.get(tick).map(|result| result.signals.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn total_signals(&self) -> usize {
+        self.results.iter().map(|result| result.signals.len()).sum()
+    }
+
+    pub fn first_buy_signal_for(&self, symbol: &str) -> Option<&TradeSignal> {
+        self.results.iter()
+            .flat_map(|result| &result.signals)
+            .find(|signal| signal.asset == symbol && signal.direction == TradeDirection::Buy)
+    }
+
+    pub fn fills(&self) -> &[(usize, TradeSignal, f64)] {
+        &self.fills
+    }
+}
@@ -0,0 +1,85 @@
+use arb_platform::order::{Order, OrderManager, OrderType};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
+
+use crate::helpers::fill_simulator::{FillPriceMode, TestOrderFillSimulator};
+use crate::helpers::orders::test_order;
+
+use std::time::Duration;
+use uuid::Uuid;
+
+fn create_order(direction: TradeDirection, quantity: f64, price: Option<f64>) -> Order {
+    Order {
+        direction,
+        order_type: if price.is_some() { OrderType::Limit } else { OrderType::Market },
+        quantity,
+        price: price.map(Price::from),
+        exchange: "Simulated".to_string(),
+        strategy_id: Some("sim_strategy".to_string()),
+        ..test_order()
+    }
+}
+
+async fn wait_for_filled(manager: &OrderManager, order_id: Uuid, quantity: f64) {
+    for _ in 0..100 {
+        if manager.get_order(order_id).await.is_some_and(|order| order.filled_quantity == quantity) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("order {} never filled {}", order_id, quantity);
+}
+
+#[tokio::test]
+async fn test_simulated_fills_at_limit_build_positions() {
+    let manager = OrderManager::new();
+    let simulator = TestOrderFillSimulator::attach(&manager);
+    
+    let buy = manager.place_order(create_order(TradeDirection::Buy, 2.0, Some(100.0))).await.unwrap();
+    let sell = manager.place_order(create_order(TradeDirection::Sell, 0.5, Some(110.0))).await.unwrap();
+    assert_eq!(simulator.wait_for_fills(2).await.len(), 2);
+    wait_for_filled(&manager, buy, 2.0).await;
+    wait_for_filled(&manager, sell, 0.5).await;
+    
+    let position = manager.get_position_manager().get_position("BTC/USD").await.unwrap();
+    assert_eq!(position.quantity, 1.5);
+    assert_eq!(position.avg_price, 100.0);
+    assert_eq!(position.realized_pnl, 5.0);
+    
+    let order = manager.get_order(buy).await.unwrap();
+    assert_eq!(order.filled_quantity, 2.0);
+    assert_eq!(order.average_fill_price, Some(Price::from(100.0)));
+}
+
+#[tokio::test]
+async fn test_simulated_fills_at_a_fixed_market_price() {
+    let manager = OrderManager::new();
+    let simulator = TestOrderFillSimulator::start(
+        manager.get_event_sender(), manager.subscribe_events(), 5, FillPriceMode::AtMarketPrice(250.0), 0);
+    
+    let order_id = manager.place_order(create_order(TradeDirection::Buy, 1.0, None)).await.unwrap();
+    let fills = simulator.wait_for_fills(1).await;
+    assert_eq!(fills[0].order_id, order_id);
+    assert_eq!(fills[0].price, 250.0);
+    wait_for_filled(&manager, order_id, 1.0).await;
+    
+    let position = manager.get_position_manager().get_position("BTC/USD").await.unwrap();
+    assert_eq!(position.quantity, 1.0);
+    assert_eq!(position.avg_price, 250.0);
+}
+
+#[tokio::test]
+async fn test_simulated_slippage_moves_fills_against_the_order() {
+    let manager = OrderManager::new();
+    let mode = FillPriceMode::Random { min_slippage_bps: 10, max_slippage_bps: 50 };
+    let simulator = TestOrderFillSimulator::start(manager.get_event_sender(), manager.subscribe_events(), 5, mode, 42);
+    
+    let buy = manager.place_order(create_order(TradeDirection::Buy, 1.0, Some(1000.0))).await.unwrap();
+    let sell = manager.place_order(create_order(TradeDirection::Sell, 1.0, Some(1000.0))).await.unwrap();
+    let fills = simulator.wait_for_fills(2).await;
+    
+    let price_of = |order_id| fills.iter().find(|fill| fill.order_id == order_id).unwrap().price;
+    assert!((1001.0..=1005.0).contains(&price_of(buy)), "buy filled at {}", price_of(buy));
+    assert!((995.0..=999.0).contains(&price_of(sell)), "sell filled at {}", price_of(sell));
+    assert_eq!(simulator.fills().len(), 2);
+}
@@ -1,2 +1,5 @@
 // Integration tests
 pub mod exchange_order_workflow;
+pub mod order_tracing;
+pub mod fill_simulation;
+pub mod websocket_codec;
@@ -0,0 +1,127 @@
+use arb_platform::exchange::{ExchangeConfig, ExchangeType, Exchange};
+use arb_platform::exchange::crypto::CryptoExchange;
+use arb_platform::order::{Order, OrderManager, OrderStatus};
+use arb_platform::models::Price;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use uuid::Uuid;
+
+use crate::helpers::orders::test_order;
+
+const EXCHANGE_NAME: &str = "Traced Crypto Exchange";
+
+/// A span as it was opened: its name, its parent's name and its order id field
+#[derive(Debug, Clone, PartialEq)]
+struct RecordedSpan {
+    name: &'static str,
+    parent: Option<&'static str>,
+    order_id: Option<String>,
+}
+
+/// Layer recording every span opened, standing in for a trace exporter
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<RecordedSpan>>>,
+}
+
+impl SpanRecorder {
+    fn spans(&self) -> Vec<RecordedSpan> {
+        self.spans.lock().unwrap().clone()
+    }
+
+    fn children_of(&self, parent: &str, order_id: Uuid) -> Vec<&'static str> {
+        self.spans().into_iter()
+            .filter(|span| span.parent == Some(parent) && span.order_id.as_deref() == Some(order_id.to_string().as_str()))
+            .map(|span| span.name)
+            .collect()
+    }
+}
+
+struct OrderIdVisitor(Option<String>);
+
+impl Visit for OrderIdVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "order_id" {
+            // Debug-formatted Option<Uuid> from the event span, Display elsewhere
+            let value = format!("{:?}", value);
+            let value = value.strip_prefix("Some(").and_then(|v| v.strip_suffix(')')).map(str::to_string).unwrap_or(value);
+            self.0 = Some(value);
+        }
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanRecorder {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = OrderIdVisitor(None);
+        attrs.record(&mut visitor);
+        let parent = ctx.span(id).and_then(|span| span.parent()).map(|parent| parent.name());
+        self.spans.lock().unwrap().push(RecordedSpan { name: attrs.metadata().name(), parent, order_id: visitor.0 });
+    }
+}
+
+fn create_order() -> Order {
+    Order {
+        client_order_id: format!("trace-{}", Uuid::new_v4().simple()),
+        price: Some(Price::from(35000.0)),
+        exchange: EXCHANGE_NAME.to_string(),
+        ..test_order()
+    }
+}
+
+async fn manager_with_crypto_exchange() -> OrderManager {
+    let mut exchange = CryptoExchange::new(ExchangeConfig {
+        name: EXCHANGE_NAME.to_string(),
+        exchange_type: ExchangeType::Crypto,
+        api_url: "https://api.example.com".to_string(),
+        api_key: Some("test_key".to_string()),
+        api_secret: Some("test_secret".to_string()),
+        additional_params: HashMap::new(),
+    });
+    exchange.connect().await.unwrap();
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(exchange)).await.unwrap();
+    manager
+}
+
+async fn wait_for_status(manager: &OrderManager, order_id: Uuid, status: OrderStatus) {
+    for _ in 0..200 {
+        if manager.get_order(order_id).await.is_some_and(|order| order.status == status) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("Order {} never reached {:?}", order_id, status);
+}
+
+// The current-thread test runtime keeps every spawned task under the thread's default subscriber
+#[tokio::test]
+async fn test_order_flow_spans_form_one_tree() {
+    let recorder = SpanRecorder::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let manager = manager_with_crypto_exchange().await;
+    let order_id = manager.place_order(create_order()).await.unwrap();
+    wait_for_status(&manager, order_id, OrderStatus::Submitted).await;
+
+    let place = recorder.spans().into_iter().find(|span| span.name == "place_order").unwrap();
+    assert_eq!(place.parent, None);
+    assert_eq!(place.order_id, Some(order_id.to_string()));
+
+    // Routing runs in a spawned task but stays under place_order, with the exchange call below it
+    let placed = recorder.children_of("place_order", order_id);
+    assert!(placed.contains(&"route"), "{:?}", recorder.spans());
+    assert!(placed.contains(&"process_order_event"), "{:?}", recorder.spans());
+    assert_eq!(recorder.children_of("route", order_id), vec!["submit_order", "process_order_event"]);
+
+    manager.cancel_order(order_id, "Done".to_string()).await.unwrap();
+    wait_for_status(&manager, order_id, OrderStatus::Cancelled).await;
+    assert!(recorder.children_of("cancel_order", order_id).contains(&"process_order_event"), "{:?}", recorder.spans());
+}
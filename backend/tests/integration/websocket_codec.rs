@@ -0,0 +1,162 @@
+use arb_platform::api::websocket::{ws_index, WsCodec, WsFrame, WsMessage, MARKET_DATA_FEED, WS_FORMAT_HEADER};
+use arb_platform::api::{configure_routes, AppState};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::OrderManager;
+use arb_platform::strategy::{AssetData, AssetType, StrategyManager};
+use arb_platform::models::Price;
+
+use actix_web::{web, App, HttpServer};
+use chrono::{TimeZone, Utc};
+use futures::{SinkExt, StreamExt};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::RwLock;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+async fn create_state() -> AppState {
+    let market_data_manager = MarketDataManager::new();
+    market_data_manager.get_current_data().write().await.asset_data.insert("BTC/USD".to_string(), AssetData {
+        symbol: "BTC/USD".to_string(),
+        asset_type: AssetType::Crypto,
+        price: Price::from(50000.0),
+        volume: 12.5,
+        bid: Price::from(49995.0),
+        ask: Price::from(50005.0),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Simulated".to_string(),
+        last_update: Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap(),
+    });
+
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(StrategyManager::new())),
+        market_data_manager: Arc::new(RwLock::new(market_data_manager)),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(ExchangeManager::new())),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+// Serve the API and WebSocket on a free local port
+async fn start_server() -> SocketAddr {
+    let state = web::Data::new(create_state().await);
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(state.clone())
+            .configure(configure_routes)
+            .route("/ws", web::get().to(ws_index))
+    })
+    .workers(1)
+    .bind(("127.0.0.1", 0))
+    .unwrap();
+    let addr = server.addrs()[0];
+    actix_web::rt::spawn(server.run());
+    addr
+}
+
+async fn connect(addr: SocketAddr, format: Option<&str>) -> Client {
+    let mut request = format!("ws://{}/ws", addr).into_client_request().unwrap();
+    if let Some(format) = format {
+        request.headers_mut().insert(WS_FORMAT_HEADER, format.parse().unwrap());
+    }
+    let (client, _) = connect_async(request).await.unwrap();
+    client
+}
+
+async fn send(client: &mut Client, codec: WsCodec, message: &WsMessage) {
+    let frame = match codec {
+        WsCodec::Json => Message::Text(serde_json::to_string(message).unwrap()),
+        WsCodec::Msgpack => Message::Binary(rmp_serde::to_vec_named(message).unwrap()),
+    };
+    client.send(frame).await.unwrap();
+}
+
+// Next message, checking it came in the frame type the codec sends
+async fn receive(client: &mut Client, codec: WsCodec) -> WsMessage {
+    let frame = tokio::time::timeout(Duration::from_secs(5), client.next()).await.unwrap().unwrap().unwrap();
+    match (codec, frame) {
+        (WsCodec::Json, Message::Text(text)) => serde_json::from_str(&text).unwrap(),
+        (WsCodec::Msgpack, Message::Binary(bytes)) => rmp_serde::from_slice(&bytes).unwrap(),
+        (codec, frame) => panic!("Unexpected {:?} frame for {:?}", frame, codec),
+    }
+}
+
+// Subscribe to BTC/USD market data and return the first update
+async fn subscribe(addr: SocketAddr, format: Option<&str>, codec: WsCodec) -> WsMessage {
+    let mut client = connect(addr, format).await;
+    assert!(matches!(receive(&mut client, codec).await, WsMessage::Connect { .. }));
+
+    let subscribe = WsMessage::Subscribe { feed: MARKET_DATA_FEED.to_string(), symbols: Some(vec!["BTC/USD".to_string()]) };
+    send(&mut client, codec, &subscribe).await;
+    receive(&mut client, codec).await
+}
+
+#[actix_web::test]
+async fn test_msgpack_session_matches_json_session() {
+    let addr = start_server().await;
+
+    let msgpack = subscribe(addr, Some("msgpack"), WsCodec::Msgpack).await;
+    let json = subscribe(addr, None, WsCodec::Json).await;
+
+    assert_eq!(msgpack, WsMessage::MarketData {
+        symbol: "BTC/USD".to_string(),
+        price: 50000.0,
+        bid: 49995.0,
+        ask: 50005.0,
+        volume: 12.5,
+        timestamp: "2024-03-01T12:00:00+00:00".to_string(),
+    });
+    assert_eq!(msgpack, json);
+}
+
+#[actix_web::test]
+async fn test_invalid_frames_get_an_error_message() {
+    let addr = start_server().await;
+    let mut client = connect(addr, Some("MsgPack")).await;
+    receive(&mut client, WsCodec::Msgpack).await;
+
+    // JSON is not MessagePack
+    client.send(Message::Text(r#"{"type":"Heartbeat"}"#.to_string())).await.unwrap();
+    assert!(matches!(receive(&mut client, WsCodec::Msgpack).await, WsMessage::Error { code, .. } if code == "invalid_message"));
+
+    let subscribe = WsMessage::Subscribe { feed: "news".to_string(), symbols: None };
+    send(&mut client, WsCodec::Msgpack, &subscribe).await;
+    assert!(matches!(receive(&mut client, WsCodec::Msgpack).await, WsMessage::Error { code, .. } if code == "unknown_feed"));
+}
+
+#[test]
+fn test_codecs_round_trip_every_message() {
+    let messages = vec![
+        WsMessage::Heartbeat,
+        WsMessage::Connect { client_id: "client-1".to_string() },
+        WsMessage::Unsubscribe { feed: MARKET_DATA_FEED.to_string(), symbols: None },
+        WsMessage::OrderUpdate {
+            order_id: "order-1".to_string(),
+            status: "filled".to_string(),
+            filled_quantity: 2.0,
+            average_price: Some(101.5),
+            timestamp: "2024-03-01T12:00:00+00:00".to_string(),
+        },
+    ];
+    for codec in [WsCodec::Json, WsCodec::Msgpack] {
+        for message in &messages {
+            let decoded = match codec.encode(message).unwrap() {
+                WsFrame::Text(text) => codec.decode(text.as_bytes()).unwrap(),
+                WsFrame::Binary(bytes) => codec.decode(&bytes).unwrap(),
+            };
+            assert_eq!(&decoded, message);
+        }
+    }
+}
@@ -1,3 +1,6 @@
+// Shared test helpers
+pub mod helpers;
+
 // Unit tests
 pub mod unit;
 
@@ -0,0 +1,171 @@
+// Handler responses checked against the JSON Schema of the type each one documents
+use arb_platform::api::{
+    configure_routes, AccountBalanceResponse, AccountHistoryPage, AppState, BatchOrderResult, CancelOrderResponse,
+    EventChannelMetrics, HealthResponse, MessageResponse, OrderAmendmentPage, OrderRefreshResponse, OrderResponse,
+    OrderSummaryResponse, PlaceOrderResponse, StrategyEvaluationsResponse, TestNotificationResponse, TwapExecutionResponse,
+};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::exchange::OrderStatus as ExchangeOrderStatus;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::OrderManager;
+use arb_platform::strategy::{MomentumStrategy, StrategyManager};
+
+use actix_web::{test, web, App};
+use jsonschema::JSONSchema;
+use schemars::{schema_for, JsonSchema};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[allow(dead_code)]
+#[path = "helpers/mock_exchange.rs"]
+mod mock_exchange;
+
+use mock_exchange::MockExchange;
+
+async fn create_state(exchange: &MockExchange) -> AppState {
+    let mut strategy_manager = StrategyManager::new();
+    strategy_manager.register_strategy(Box::new(MomentumStrategy::new()));
+    let mut exchange_manager = ExchangeManager::new();
+    exchange_manager.add_exchange(Box::new(exchange.clone())).unwrap();
+
+    let order_manager = OrderManager::new();
+    let router = order_manager.get_order_router();
+    router.register_exchange(Box::new(exchange.clone())).await.unwrap();
+    router.set_primary_exchange("BTC/USD", "Mock").await.unwrap();
+
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(strategy_manager)),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(order_manager)),
+        exchange_manager: Arc::new(RwLock::new(exchange_manager)),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+/// Panic with every violation unless `instance` matches the schema of `T`
+fn assert_matches_schema<T: JsonSchema>(endpoint: &str, instance: &Value) {
+    let schema = serde_json::to_value(schema_for!(T)).unwrap();
+    let compiled = JSONSchema::compile(&schema).expect("Generated schema should compile");
+    let result = compiled.validate(instance)
+        .map_err(|errors| errors.map(|e| format!("{} at {}", e, e.instance_path)).collect::<Vec<_>>());
+    if let Err(errors) = result {
+        panic!("{} does not match {}: {:?}\n{}", endpoint, T::schema_name(), errors, instance);
+    }
+}
+
+/// The `data` of a successful response envelope
+fn envelope_data(body: Value) -> Value {
+    assert!(body.get("data").is_some(), "Expected a data envelope: {}", body);
+    body["data"].clone()
+}
+
+#[actix_web::test]
+async fn test_handler_responses_match_their_schemas() {
+    let exchange = MockExchange::new("Mock");
+    let state = create_state(&exchange).await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+
+    // The health check is the one response without an envelope
+    let body: Value = test::call_and_read_body_json(&app, test::TestRequest::get().uri("/api/health").to_request()).await;
+    assert_matches_schema::<HealthResponse>("GET /api/health", &body);
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::get().uri("/api/metrics").to_request()).await);
+    assert_matches_schema::<EventChannelMetrics>("GET /api/metrics", &data);
+
+    let req = test::TestRequest::put()
+        .uri("/api/strategy/active")
+        .set_json(json!({"name": "Momentum"}))
+        .to_request();
+    let data = envelope_data(test::call_and_read_body_json(&app, req).await);
+    assert_matches_schema::<MessageResponse>("PUT /api/strategy/active", &data);
+
+    let req = test::TestRequest::put()
+        .uri("/api/strategy/Momentum/params")
+        .set_json(json!({}))
+        .to_request();
+    let data = envelope_data(test::call_and_read_body_json(&app, req).await);
+    assert_matches_schema::<MessageResponse>("PUT /api/strategy/{name}/params", &data);
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::post().uri("/api/strategy/evaluate?objective=roi").to_request()).await);
+    assert_matches_schema::<StrategyEvaluationsResponse>("POST /api/strategy/evaluate", &data);
+
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 2.0, "price": 100.0}))
+        .to_request();
+    let data = envelope_data(test::call_and_read_body_json(&app, req).await);
+    assert_matches_schema::<PlaceOrderResponse>("POST /api/order", &data);
+    let order_id: Uuid = data["order_id"].as_str().unwrap().parse().unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/order/batch")
+        .set_json(json!([
+            {"symbol": "BTC/USD", "direction": "sell", "order_type": "limit", "quantity": 1.0, "price": 120.0},
+            {"symbol": "BTC/USD", "direction": "sideways", "order_type": "market", "quantity": 1.0},
+        ]))
+        .to_request();
+    let data = envelope_data(test::call_and_read_body_json(&app, req).await);
+    assert_matches_schema::<Vec<BatchOrderResult>>("POST /api/order/batch", &data);
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::get().uri("/api/order").to_request()).await);
+    assert_matches_schema::<Vec<OrderSummaryResponse>>("GET /api/order", &data);
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::get().uri(&format!("/api/order/{}", order_id)).to_request()).await);
+    assert_matches_schema::<OrderResponse>("GET /api/order/{id}", &data);
+
+    exchange.set_order_status(order_id, ExchangeOrderStatus::PartiallyFilled, 0.5, Some(99.5));
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::post().uri(&format!("/api/order/{}/refresh", order_id)).to_request()).await);
+    assert_matches_schema::<OrderRefreshResponse>("POST /api/order/{id}/refresh", &data);
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::get().uri(&format!("/api/order/{}/history", order_id)).to_request()).await);
+    assert_matches_schema::<OrderAmendmentPage>("GET /api/order/{id}/history", &data);
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/order/{}/cancel", order_id))
+        .set_json(json!({"reason": "schema check"}))
+        .to_request();
+    let data = envelope_data(test::call_and_read_body_json(&app, req).await);
+    assert_matches_schema::<CancelOrderResponse>("POST /api/order/{id}/cancel", &data);
+
+    let req = test::TestRequest::post()
+        .uri("/api/order/twap")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 4.0, "price": 100.0, "duration_secs": 60, "num_slices": 4}))
+        .to_request();
+    let data = envelope_data(test::call_and_read_body_json(&app, req).await);
+    assert_matches_schema::<TwapExecutionResponse>("POST /api/order/twap", &data);
+    let parent_id = data["parent_id"].as_str().unwrap().to_string();
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::get().uri(&format!("/api/order/twap/{}", parent_id)).to_request()).await);
+    assert_matches_schema::<TwapExecutionResponse>("GET /api/order/twap/{parent_id}", &data);
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::get().uri("/api/account/balance").to_request()).await);
+    assert_matches_schema::<AccountBalanceResponse>("GET /api/account/balance", &data);
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::get().uri("/api/account/history").to_request()).await);
+    assert_matches_schema::<AccountHistoryPage>("GET /api/account/history", &data);
+
+    let data = envelope_data(test::call_and_read_body_json(&app, test::TestRequest::post().uri("/api/notifications/test").to_request()).await);
+    assert_matches_schema::<TestNotificationResponse>("POST /api/notifications/test", &data);
+}
+
+#[actix_web::test]
+async fn test_schema_rejects_mismatched_response() {
+    let schema = serde_json::to_value(schema_for!(PlaceOrderResponse)).unwrap();
+    let compiled = JSONSchema::compile(&schema).unwrap();
+
+    assert!(compiled.is_valid(&json!({"order_id": Uuid::nil(), "status": "created"})));
+    assert!(!compiled.is_valid(&json!({"order_id": Uuid::nil(), "status": "shipped"})));
+    assert!(!compiled.is_valid(&json!({"order_id": Uuid::nil()})));
+}
@@ -1,10 +1,14 @@
 use arb_platform::exchange::{Exchange, ExchangeConfig, ExchangeType};
 use arb_platform::exchange::crypto::CryptoExchange;
 use arb_platform::order::{Order, OrderStatus, OrderType};
-use arb_platform::strategy::{TradeDirection, TimeInForce};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
 use std::collections::HashMap;
-use chrono::Utc;
-use uuid::Uuid;
+
+#[path = "helpers/orders.rs"]
+mod orders;
+
+use orders::test_order;
 
 #[test]
 fn test_exchange_config() {
@@ -24,24 +28,11 @@ fn test_exchange_config() {
 #[test]
 fn test_order_creation() {
     let order = Order {
-        id: Uuid::new_v4(),
         client_order_id: "test_order".to_string(),
-        symbol: "BTC/USD".to_string(),
-        direction: TradeDirection::Buy,
-        order_type: OrderType::Limit,
-        quantity: 1.0,
-        filled_quantity: 0.0,
-        price: Some(35000.0),
-        stop_price: None,
-        time_in_force: TimeInForce::GoodTilCancelled,
-        status: OrderStatus::Created,
+        price: Some(Price::from(35000.0)),
         exchange: "Test Exchange".to_string(),
-        created_at: Utc::now(),
-        updated_at: Utc::now(),
-        filled_at: None,
-        average_fill_price: None,
         strategy_id: Some("test_strategy".to_string()),
-        notes: None,
+        ..test_order()
     };
     
     assert_eq!(order.symbol, "BTC/USD");
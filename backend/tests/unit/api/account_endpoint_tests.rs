@@ -0,0 +1,349 @@
+use arb_platform::api::{configure_routes, AppState};
+use arb_platform::exchange::{AccountBalance, AccountTransaction, MarginInfo, Position, TransactionType};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::{OrderEvent, OrderManager};
+use arb_platform::strategy::{AssetData, AssetType, StrategyManager, TradeDirection};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+
+use actix_web::{test, web, App};
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+fn create_state(exchange_manager: ExchangeManager) -> AppState {
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(StrategyManager::new())),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(exchange_manager)),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+fn usd_exchange(name: &str, total: f64, symbol: &str) -> MockExchange {
+    let exchange = MockExchange::new(name);
+    exchange.set_account_balance(AccountBalance {
+        total,
+        available: total / 2.0,
+        currency: "USD".to_string(),
+        additional_balances: vec![("BTC".to_string(), 1.0)],
+        timestamp: Utc::now(),
+    });
+    exchange.set_positions(vec![Position {
+        symbol: symbol.to_string(),
+        quantity: 2.0,
+        avg_price: 100.0,
+        current_price: 105.0,
+        unrealized_pnl: 10.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }]);
+    exchange
+}
+
+#[actix_web::test]
+async fn test_account_endpoints_aggregate_exchanges() {
+    let mut exchange_manager = ExchangeManager::new();
+    exchange_manager.add_exchange(Box::new(usd_exchange("Alpha", 1000.0, "BTC/USD"))).unwrap();
+    exchange_manager.add_exchange(Box::new(usd_exchange("Beta", 3000.0, "ETH/USD"))).unwrap();
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state(exchange_manager)))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/account/balance").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["currency"], "USD");
+    assert_eq!(body["data"]["total"], 4000.0);
+    assert_eq!(body["data"]["available"], 2000.0);
+    assert_eq!(body["data"]["additional_balances"], serde_json::json!([["BTC", 2.0]]));
+    // No BTC/USD price yet, so the dollar value is unknown
+    assert_eq!(body["data"]["total_usd_equivalent"], serde_json::Value::Null);
+    
+    let req = test::TestRequest::get().uri("/api/account/positions").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let symbols: Vec<&str> = body["data"].as_array().unwrap().iter()
+        .map(|p| p["symbol"].as_str().unwrap())
+        .collect();
+    assert_eq!(symbols, vec!["BTC/USD", "ETH/USD"]);
+}
+
+#[actix_web::test]
+async fn test_margin_endpoint_aggregates_exchanges() {
+    let alpha = usd_exchange("Alpha", 1000.0, "BTC/USD");
+    alpha.set_margin_info(MarginInfo {
+        available_margin: 800.0,
+        used_margin: 200.0,
+        margin_level: 500.0,
+        max_leverage: 3.0,
+        liquidation_price: Some(70.0),
+    });
+    let mut exchange_manager = ExchangeManager::new();
+    exchange_manager.add_exchange(Box::new(alpha.clone())).unwrap();
+    exchange_manager.add_exchange(Box::new(usd_exchange("Beta", 3000.0, "ETH/USD"))).unwrap();
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state(exchange_manager)))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/account/margin?symbol=BTC/USD").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["total_available_margin"], 800.0);
+    assert_eq!(body["data"]["total_used_margin"], 200.0);
+    assert_eq!(body["data"]["exchanges"]["Alpha"]["liquidation_price"], 70.0);
+    assert!(body["data"]["exchanges"]["Beta"].is_null());
+    
+    let req = test::TestRequest::get().uri("/api/account/margin?symbol=BTC%20USD").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[actix_web::test]
+async fn test_balance_includes_usd_equivalent() {
+    let mut exchange_manager = ExchangeManager::new();
+    exchange_manager.add_exchange(Box::new(usd_exchange("Alpha", 1000.0, "BTC/USD"))).unwrap();
+    let state = create_state(exchange_manager);
+    {
+        let market_data_manager = state.market_data_manager.read().await;
+        let current_data = market_data_manager.get_current_data();
+        current_data.write().await.asset_data.insert("BTC/USD".to_string(), AssetData {
+            symbol: "BTC/USD".to_string(),
+            asset_type: AssetType::Crypto,
+            price: Price::from(50000.0),
+            volume: 0.0,
+            bid: Price::from(50000.0),
+            ask: Price::from(50000.0),
+            tick_size: None,
+            lot_size: None,
+            exchange: "Simulated".to_string(),
+            last_update: Utc::now(),
+        });
+    }
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/account/balance").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["total"], 1000.0);
+    assert_eq!(body["data"]["total_usd_equivalent"], 51000.0);
+}
+
+#[actix_web::test]
+async fn test_balance_endpoint_without_exchanges() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state(ExchangeManager::new())))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/account/balance").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::SERVICE_UNAVAILABLE);
+    
+    let req = test::TestRequest::get().uri("/api/account/positions").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"], serde_json::json!([]));
+}
+
+#[actix_web::test]
+async fn test_pnl_by_strategy_endpoint() {
+    let state = create_state(ExchangeManager::new());
+    {
+        let positions = state.order_manager.read().await.get_position_manager();
+        positions.apply_strategy_fill("BTC/USD", Some("Momentum"), TradeDirection::Buy, 1.0, 100.0).await;
+        positions.apply_strategy_fill("BTC/USD", Some("Momentum"), TradeDirection::Sell, 1.0, 125.0).await;
+        positions.apply_fill("ETH/USD", TradeDirection::Buy, 2.0, 100.0).await;
+        positions.apply_fill("ETH/USD", TradeDirection::Sell, 1.0, 90.0).await;
+    }
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/account/pnl/by-strategy").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let momentum = &body["data"]["Momentum"];
+    assert_eq!(momentum["realized_pnl"], 25.0);
+    assert_eq!(momentum["trade_count"], 1);
+    assert_eq!(momentum["win_count"], 1);
+    assert!(momentum["avg_holding_duration_secs"].as_f64().unwrap() >= 0.0);
+    
+    // The manual ETH trade counts only towards the total
+    let combined = &body["data"]["combined"];
+    assert_eq!(combined["realized_pnl"], 15.0);
+    assert_eq!(combined["unrealized_pnl"], -10.0);
+    assert_eq!(combined["trade_count"], 1);
+    assert_eq!(body["data"].as_object().unwrap().len(), 2);
+}
+
+#[actix_web::test]
+async fn test_account_pnl_marks_positions_to_market() {
+    let state = create_state(ExchangeManager::new());
+    {
+        let positions = state.order_manager.read().await.get_position_manager();
+        positions.apply_fill("BTC/USD", TradeDirection::Buy, 2.0, 100.0).await;
+        positions.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 110.0).await;
+        positions.apply_fill("ETH/USD", TradeDirection::Buy, 1.0, 50.0).await;
+        
+        let market_data_manager = state.market_data_manager.read().await;
+        market_data_manager.get_current_data().write().await.asset_data.insert("BTC/USD".to_string(), AssetData {
+            symbol: "BTC/USD".to_string(),
+            asset_type: AssetType::Crypto,
+            price: Price::from(130.0),
+            volume: 0.0,
+            bid: Price::from(130.0),
+            ask: Price::from(130.0),
+            tick_size: None,
+            lot_size: None,
+            exchange: "Simulated".to_string(),
+            last_update: Utc::now(),
+        });
+    }
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/account/pnl").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["realized_pnl"], 10.0);
+    assert_eq!(body["data"]["unrealized_pnl"], 30.0);
+    assert_eq!(body["data"]["total_pnl"], 40.0);
+    assert_eq!(body["data"]["stale_symbols"], serde_json::json!(["ETH/USD"]));
+}
+
+#[actix_web::test]
+async fn test_journal_endpoint_exports_fills_as_csv() {
+    let state = create_state(ExchangeManager::new());
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let mut order_ids = Vec::new();
+    for (symbol, direction) in [("BTC/USD", "buy"), ("ETH/USD", "sell")] {
+        let req = test::TestRequest::post()
+            .uri("/api/order")
+            .set_json(serde_json::json!({"symbol": symbol, "direction": direction, "order_type": "limit", "quantity": 1.0, "price": 100.0}))
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        order_ids.push(body["data"]["order_id"].as_str().unwrap().parse::<uuid::Uuid>().unwrap());
+    }
+    
+    // Two fills of the first order either side of one of the second
+    let sender = state.order_manager.read().await.get_event_sender();
+    for (order_id, filled, avg_price) in [(order_ids[0], 0.25, 99.0), (order_ids[1], 1.0, 101.0), (order_ids[0], 0.5, 99.5)] {
+        sender.send(OrderEvent::Update { order_id, status: None, filled_qty: Some(filled), avg_fill_price: Some(avg_price) }).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    
+    let req = test::TestRequest::get().uri("/api/account/journal?format=csv").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    assert_eq!(resp.headers().get("content-type").unwrap(), "text/csv");
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(lines[0], "order_id,symbol,side,quantity,price,fee,timestamp");
+    assert_eq!(lines.len(), 4);
+    
+    let rows: Vec<Vec<&str>> = lines[1..].iter().map(|line| line.split(',').collect()).collect();
+    let expected = [
+        (order_ids[0], "BTC/USD", "buy", "0.25", "99"),
+        (order_ids[1], "ETH/USD", "sell", "1", "101"),
+        (order_ids[0], "BTC/USD", "buy", "0.25", "100"),
+    ];
+    for (row, (order_id, symbol, side, quantity, price)) in rows.iter().zip(expected) {
+        assert_eq!(row[..6], [order_id.to_string().as_str(), symbol, side, quantity, price, ""]);
+    }
+    let timestamps: Vec<chrono::DateTime<Utc>> = rows.iter().map(|row| row[6].parse().unwrap()).collect();
+    assert!(timestamps.windows(2).all(|pair| pair[0] <= pair[1]));
+    
+    // Only fills before `to`, and only CSV
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/account/journal?to={}", query_value(rows[1][6])))
+        .to_request();
+    let body = String::from_utf8(test::call_and_read_body(&app, req).await.to_vec()).unwrap();
+    assert_eq!(body.lines().count(), 2);
+    
+    let req = test::TestRequest::get().uri("/api/account/journal?format=xlsx").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+// RFC 3339 timestamps carry a '+' that would otherwise decode as a space
+fn query_value(value: &str) -> String {
+    value.replace('+', "%2B")
+}
+
+#[actix_web::test]
+async fn test_account_history_endpoint_pages_by_date_range() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let exchange = MockExchange::new("Alpha");
+    exchange.set_account_history((0..5).map(|day| AccountTransaction {
+        id: format!("tx{}", day),
+        transaction_type: TransactionType::Trade,
+        currency: "USD".to_string(),
+        amount: 100.0 * day as f64,
+        fee: 0.1,
+        timestamp: start + chrono::Duration::days(day) + chrono::Duration::hours(12),
+        description: None,
+    }).collect());
+    let mut exchange_manager = ExchangeManager::new();
+    exchange_manager.add_exchange(Box::new(exchange)).unwrap();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state(exchange_manager)))
+            .configure(configure_routes)
+    ).await;
+    
+    // A plain to date includes that whole day
+    let req = test::TestRequest::get().uri("/api/account/history?from=2024-01-01&to=2024-01-04&limit=2&offset=1").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["total"], 4);
+    assert_eq!(body["data"]["limit"], 2);
+    assert_eq!(body["data"]["offset"], 1);
+    let ids: Vec<&str> = body["data"]["transactions"].as_array().unwrap().iter()
+        .map(|transaction| transaction["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["tx1", "tx2"]);
+    assert_eq!(body["data"]["transactions"][0]["transaction_type"], "Trade");
+    
+    let req = test::TestRequest::get().uri("/api/account/history?from=2024-01-02T12:00:00Z&to=2024-01-31").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["total"], 4);
+    assert_eq!(body["data"]["limit"], 100);
+    
+    for (uri, status) in [
+        ("/api/account/history?from=yesterday", actix_web::http::StatusCode::BAD_REQUEST),
+        ("/api/account/history?from=2024-02-01&to=2024-01-01", actix_web::http::StatusCode::BAD_REQUEST),
+        ("/api/account/history?from=2023-01-01&to=2024-12-31", actix_web::http::StatusCode::BAD_REQUEST),
+        ("/api/account/history?limit=0", actix_web::http::StatusCode::UNPROCESSABLE_ENTITY),
+    ] {
+        let resp = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+        assert_eq!(resp.status(), status, "{}", uri);
+    }
+}
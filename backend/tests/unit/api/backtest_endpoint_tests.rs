@@ -0,0 +1,284 @@
+use arb_platform::api::{configure_routes, AppState};
+use arb_platform::backtest::{BacktestEngine, BacktestRunner, BacktestSpec};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::OrderManager;
+use arb_platform::strategy::{StrategyManager, StrategyParams};
+
+use crate::helpers::fixed_side_strategy::{daily_history, FixedSideStrategy};
+
+use actix_web::{test, web, App};
+use chrono::{TimeZone, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+fn create_state() -> AppState {
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(StrategyManager::new())),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(ExchangeManager::new())),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+const PRICES: [f64; 5] = [100.0, 101.0, 104.0, 102.0, 103.0];
+
+fn state_with_runner(runner: BacktestRunner) -> AppState {
+    AppState { backtest_runner: Arc::new(runner), ..create_state() }
+}
+
+fn spec() -> BacktestSpec {
+    BacktestSpec {
+        strategy: "Fixed Side".to_string(),
+        symbols: vec!["BTC/USD".to_string()],
+        start: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap(),
+        initial_capital: 1000.0,
+        params: StrategyParams { params: HashMap::new() },
+    }
+}
+
+fn backtest_request(strategy: &str) -> serde_json::Value {
+    serde_json::json!({
+        "strategy": strategy,
+        "start_date": "2024-01-01",
+        "end_date": "2024-01-05",
+        "symbols": ["BTC/USD"],
+        "initial_capital": 1000.0,
+        "parameters": {"quantity": 2.0},
+    })
+}
+
+#[actix_web::test]
+async fn test_backtest_result_is_stored_and_fetched_by_id() {
+    let runner = BacktestRunner::new()
+        .with_strategy("Fixed Side", FixedSideStrategy::factory())
+        .with_history_loader(Box::new(|_, _, _| Ok(daily_history(&PRICES))));
+    let state = state_with_runner(runner);
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri("/api/backtest").set_json(backtest_request("Fixed Side")).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["status"], "running");
+    assert_eq!(body["data"]["strategy"], "Fixed Side");
+    let backtest_id = body["data"]["id"].as_str().unwrap().to_string();
+    
+    let uri = format!("/api/backtest/{}", backtest_id);
+    let mut run = serde_json::Value::Null;
+    for _ in 0..100 {
+        let req = test::TestRequest::get().uri(&uri).to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        run = body["data"].clone();
+        if run["status"] != "running" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert_eq!(run["status"], "completed");
+    assert_eq!(run["id"], backtest_id);
+    
+    // The fetched result is exactly the stored one
+    let stored = state.backtests.result(Uuid::parse_str(&backtest_id).unwrap()).await.unwrap();
+    assert_eq!(run["result"], serde_json::to_value(&stored).unwrap());
+    
+    // And the stored one is what the engine produces for the same run
+    let mut params = HashMap::new();
+    params.insert("quantity".to_string(), serde_json::json!(2.0));
+    let engine = BacktestEngine::new(daily_history(&PRICES), FixedSideStrategy::factory(), 1000.0);
+    let expected = engine.backtest(&StrategyParams { params }, 0, 4).unwrap();
+    assert_eq!(stored, expected);
+    assert_eq!(run["result"]["total_return"].as_f64().unwrap(), expected.total_return);
+    assert_eq!(run["result"]["sharpe_ratio"].as_f64().unwrap(), expected.sharpe_ratio);
+    assert_eq!(run["result"]["max_drawdown"].as_f64().unwrap(), expected.max_drawdown);
+    assert_eq!(run["result"]["trades"], expected.trades);
+}
+
+#[actix_web::test]
+async fn test_backtest_failure_is_reported() {
+    let runner = BacktestRunner::new()
+        .with_strategy("Fixed Side", FixedSideStrategy::factory())
+        .with_history_loader(Box::new(|_, _, _| Err("Archive offline".to_string())));
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state_with_runner(runner)))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri("/api/backtest").set_json(backtest_request("Fixed Side")).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let backtest_id = body["data"]["id"].as_str().unwrap().to_string();
+    
+    let uri = format!("/api/backtest/{}", backtest_id);
+    let mut run = serde_json::Value::Null;
+    for _ in 0..100 {
+        let req = test::TestRequest::get().uri(&uri).to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        run = body["data"].clone();
+        if run["status"] != "running" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert_eq!(run["status"], "failed");
+    assert_eq!(run["error"], "Archive offline");
+    assert!(run["result"].is_null());
+}
+
+#[actix_web::test]
+async fn test_backtest_rejects_unknown_strategy_bad_dates_and_unknown_id() {
+    let runner = BacktestRunner::new().with_strategy("Fixed Side", FixedSideStrategy::factory());
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state_with_runner(runner)))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri("/api/backtest").set_json(backtest_request("Nonexistent")).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    
+    let mut reversed = backtest_request("Fixed Side");
+    reversed["start_date"] = serde_json::json!("2024-02-01");
+    let req = test::TestRequest::post().uri("/api/backtest").set_json(reversed).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    
+    let mut malformed = backtest_request("Fixed Side");
+    malformed["end_date"] = serde_json::json!("next week");
+    let req = test::TestRequest::post().uri("/api/backtest").set_json(malformed).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    
+    let req = test::TestRequest::get().uri(&format!("/api/backtest/{}", Uuid::new_v4())).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_monte_carlo_job_runs_in_background() {
+    let state = create_state();
+    let engine = BacktestEngine::new(daily_history(&[100.0, 101.0, 104.0, 102.0, 103.0]), FixedSideStrategy::factory(), 1000.0);
+    let result = engine.backtest(&StrategyParams { params: HashMap::new() }, 0, 4).unwrap();
+    let backtest_id = state.backtests.insert_result(&spec(), result).await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri(&format!("/api/backtest/{}/monte-carlo?iterations=500", backtest_id)).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["iterations"], 500);
+    let job_id = body["data"]["job_id"].as_str().unwrap().to_string();
+    
+    let uri = format!("/api/backtest/{}/monte-carlo/{}", backtest_id, job_id);
+    let mut job = serde_json::Value::Null;
+    for _ in 0..100 {
+        let req = test::TestRequest::get().uri(&uri).to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        job = body["data"].clone();
+        if job["status"] == "completed" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert_eq!(job["status"], "completed");
+    assert_eq!(job["result"]["simulations"].as_array().unwrap().len(), 500);
+    assert!(job["result"]["p5_total_return"].as_f64().unwrap() <= job["result"]["p95_total_return"].as_f64().unwrap());
+    
+    // The job belongs to its backtest
+    let req = test::TestRequest::get().uri(&format!("/api/backtest/{}/monte-carlo/{}", Uuid::new_v4(), job_id)).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_monte_carlo_rejects_unknown_backtest_and_bad_iterations() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri(&format!("/api/backtest/{}/monte-carlo", Uuid::new_v4())).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    
+    let req = test::TestRequest::post().uri(&format!("/api/backtest/{}/monte-carlo?iterations=0", Uuid::new_v4())).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+    
+    let req = test::TestRequest::post().uri("/api/backtest/not-a-uuid/monte-carlo").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_compare_endpoint_diffs_two_backtests() {
+    let state = create_state();
+    let engine = BacktestEngine::new(daily_history(&PRICES), FixedSideStrategy::factory(), 1000.0);
+    let cheap = {
+        let mut spec = spec();
+        spec.params.params.insert("commission_rate".to_string(), serde_json::json!(0.001));
+        spec
+    };
+    let dear = {
+        let mut spec = spec();
+        spec.params.params.insert("commission_rate".to_string(), serde_json::json!(0.005));
+        spec
+    };
+    let cheap_result = engine.backtest(&StrategyParams { params: HashMap::new() }, 0, 4).unwrap();
+    let mut dear_result = cheap_result.clone();
+    dear_result.final_capital -= 20.0;
+    dear_result.total_return = (dear_result.final_capital - 1000.0) / 1000.0;
+    let a = state.backtests.insert_result(&cheap, cheap_result).await;
+    let b = state.backtests.insert_result(&dear, dear_result).await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri(&format!("/api/backtest/compare?a={}&b={}", a, b)).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let comparison = &body["data"];
+    assert_eq!(comparison["a_id"], a.to_string());
+    assert_eq!(comparison["b_id"], b.to_string());
+    assert!((comparison["return_delta"].as_f64().unwrap() + 0.02).abs() < 1e-12);
+    assert_eq!(comparison["trade_count_delta"], 0);
+    assert_eq!(comparison["parameter_differences"], serde_json::json!({
+        "parameters.commission_rate": [0.001, 0.005],
+    }));
+    
+    // The missing run is named
+    let req = test::TestRequest::get().uri(&format!("/api/backtest/compare?a={}&b={}", a, Uuid::new_v4())).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["error"].as_str().unwrap().contains("Backtest b"), "{}", body);
+    
+    let running = arb_platform::backtest::BacktestRun::new(&spec());
+    let running_id = running.id;
+    state.backtests.insert(running).await;
+    let req = test::TestRequest::get().uri(&format!("/api/backtest/compare?a={}&b={}", running_id, b)).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert!(body["error"].as_str().unwrap().contains("Backtest a"), "{}", body);
+    
+    let req = test::TestRequest::get().uri(&format!("/api/backtest/compare?a=not-a-uuid&b={}", b)).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,165 @@
+use arb_platform::api::{configure_routes, AppState};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::market_data::{MarketDataManager, MarketEvent};
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::OrderManager;
+use arb_platform::strategy::{AssetData, AssetType, StrategyManager};
+use arb_platform::models::Price;
+
+use actix_web::{test, web, App};
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+fn create_state() -> AppState {
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(StrategyManager::new())),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(ExchangeManager::new())),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+#[actix_web::test]
+async fn test_order_book_endpoint_unknown_symbol() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/market/orderbook/AAPL").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_order_book_endpoint_returns_depth() {
+    let state = create_state();
+    let mut market_data = state.market_data_manager.write().await;
+    market_data.start_processing().await.unwrap();
+    market_data.get_event_sender().send(MarketEvent::OrderBookUpdate {
+        symbol: "AAPL".to_string(),
+        bids: vec![(199.9, 100.0), (199.8, 200.0), (199.7, 300.0)],
+        asks: vec![(200.1, 150.0), (200.2, 250.0)],
+        exchange: "NASDAQ".to_string(),
+        timestamp: Utc::now(),
+    }).await.unwrap();
+    for _ in 0..100 {
+        if market_data.get_order_book("AAPL").is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    drop(market_data);
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/market/orderbook/AAPL?depth=2").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    
+    let data = &body["data"];
+    assert_eq!(data["symbol"], "AAPL");
+    assert_eq!(data["bids"].as_array().unwrap().len(), 2);
+    assert_eq!(data["bids"][0]["price"], 199.9);
+    assert_eq!(data["asks"][0]["price"], 200.1);
+    assert_eq!(data["asks"][1]["quantity"], 250.0);
+}
+
+#[actix_web::test]
+async fn test_data_quality_endpoint_reports_rejections() {
+    let state = create_state();
+    let validator = state.market_data_manager.read().await.get_data_quality_validator();
+    validator.check_price_update("AAPL", 200.0, None, None, None).unwrap();
+    validator.check_price_update("AAPL", -200.0, None, None, None).unwrap_err();
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/market/data-quality").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    
+    let data = &body["data"];
+    assert_eq!(data["events_received"], 2);
+    assert_eq!(data["events_rejected"], 1);
+    assert_eq!(data["rejection_reasons"]["positive_price"], 1);
+}
+
+#[actix_web::test]
+async fn test_funding_rate_endpoint_counts_down_to_next_payment() {
+    let state = create_state();
+    let monitor = state.market_data_manager.read().await.get_funding_monitor();
+    monitor.update("BTC-PERP", 0.0001, Utc::now() + chrono::Duration::hours(2));
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/market/funding/BTC-PERP").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    
+    let data = &body["data"];
+    assert_eq!(data["symbol"], "BTC-PERP");
+    assert_eq!(data["rate"], 0.0001);
+    assert!((data["annualized_rate"].as_f64().unwrap() - 0.1095).abs() < 1e-12);
+    let countdown = data["seconds_to_next_funding"].as_i64().unwrap();
+    assert!((7190..=7200).contains(&countdown));
+    
+    let req = test::TestRequest::get().uri("/api/market/funding/ETH-PERP").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_quotes_endpoint_lists_missing_symbols() {
+    let state = create_state();
+    {
+        let current_data = state.market_data_manager.read().await.get_current_data();
+        let mut data = current_data.write().await;
+        for (symbol, price) in [("BTC/USD", 50000.0), ("ETH/USD", 3000.0)] {
+            data.asset_data.insert(symbol.to_string(), AssetData {
+                symbol: symbol.to_string(),
+                asset_type: AssetType::Crypto,
+                price: Price::from(price),
+                volume: 10.0,
+                bid: Price::from(price - 1.0),
+                ask: Price::from(price + 1.0),
+                tick_size: None,
+                lot_size: None,
+                exchange: "Simulated".to_string(),
+                last_update: Utc::now(),
+            });
+        }
+    }
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/market/quotes?symbols=BTC/USD,ETH/USD,SOL/USD").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let quotes = body["data"]["quotes"].as_object().unwrap();
+    assert_eq!(quotes.len(), 2);
+    assert_eq!(quotes["BTC/USD"]["price"], "50000");
+    assert_eq!(quotes["ETH/USD"]["symbol"], "ETH/USD");
+    assert_eq!(body["data"]["missing"], serde_json::json!(["SOL/USD"]));
+    
+    let req = test::TestRequest::get().uri("/api/market/quotes?symbols=").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+}
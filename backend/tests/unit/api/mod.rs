@@ -0,0 +1,11 @@
+// API module tests
+pub mod openapi_tests;
+pub mod strategy_endpoint_tests;
+pub mod risk_endpoint_tests;
+pub mod market_endpoint_tests;
+pub mod order_endpoint_tests;
+pub mod account_endpoint_tests;
+pub mod notification_endpoint_tests;
+pub mod validation_tests;
+pub mod backtest_endpoint_tests;
+pub mod response_tests;
@@ -0,0 +1,53 @@
+use arb_platform::api::{configure_routes, AppState};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::notifications::{NotificationLevel, NotificationManager};
+use arb_platform::order::OrderManager;
+use arb_platform::strategy::StrategyManager;
+
+use crate::helpers::recording_notifier::RecordingNotifier;
+
+use actix_web::{test, web, App};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+fn create_state(notification_manager: NotificationManager) -> AppState {
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(StrategyManager::new())),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(ExchangeManager::new())),
+        notification_manager: Arc::new(notification_manager),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+#[actix_web::test]
+async fn test_notification_test_endpoint_reaches_every_handler() {
+    let recording = RecordingNotifier::new();
+    let mut notifier = NotificationManager::new();
+    notifier.register_handler(Box::new(recording.clone()));
+    notifier.register_handler(Box::new(RecordingNotifier::failing()));
+    notifier.set_min_level(NotificationLevel::Critical);
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state(notifier)))
+            .configure(configure_routes)
+    ).await;
+
+    let req = test::TestRequest::post().uri("/api/notifications/test").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["handlers"], serde_json::json!(["recording", "failing"]));
+    assert_eq!(body["data"]["delivered"], 1);
+    assert_eq!(body["data"]["errors"].as_array().unwrap().len(), 1);
+
+    // Sent at the threshold so it is not filtered out
+    let sent = recording.sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].level, NotificationLevel::Critical);
+    assert_eq!(sent[0].title, "Test notification");
+}
@@ -0,0 +1,99 @@
+use arb_platform::api::{configure_routes, ApiDoc};
+
+use actix_web::App;
+use utoipa::OpenApi;
+
+#[test]
+fn test_openapi_spec_is_valid_yaml() {
+    let yaml = ApiDoc::openapi().to_yaml().expect("Failed to render spec as YAML");
+    
+    // The rendered spec must parse back into a generic YAML document
+    let document: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("Spec is not valid YAML");
+    let version = document["openapi"].as_str().expect("Missing openapi version");
+    assert!(version.starts_with("3."), "Unexpected OpenAPI version: {}", version);
+    
+    // Required top-level OpenAPI objects
+    assert_eq!(document["info"]["title"].as_str(), Some("ARB Platform API"));
+    assert!(document["info"]["version"].is_string());
+    assert!(document["paths"].is_mapping());
+    assert!(document["components"]["schemas"].is_mapping());
+}
+
+#[test]
+fn test_openapi_spec_contains_all_paths() {
+    let spec = ApiDoc::openapi();
+    
+    let expected_paths = [
+        "/api/health",
+        "/api/metrics",
+        "/api/market/data/{symbol}",
+        "/api/market/symbols",
+        "/api/market/quotes",
+        "/api/market/orderbook/{symbol}",
+        "/api/market/funding/{symbol}",
+        "/api/market/data-quality",
+        "/api/strategy",
+        "/api/strategy/active",
+        "/api/strategy/{name}/params",
+        "/api/strategy/evaluate",
+        "/api/strategy/{name}/evaluate",
+        "/api/order",
+        "/api/order/batch",
+        "/api/order/twap",
+        "/api/order/twap/{parent_id}",
+        "/api/order/{id}",
+        "/api/order/{id}/fills",
+        "/api/order/statistics",
+        "/api/order/{id}/cancel",
+        "/api/order/{id}/refresh",
+        "/api/account/balance",
+        "/api/account/positions",
+        "/api/account/pnl",
+        "/api/account/pnl/by-strategy",
+        "/api/account/margin",
+        "/api/account/journal",
+        "/api/account/history",
+        "/api/backtest",
+        "/api/backtest/{id}",
+        "/api/backtest/{id}/monte-carlo",
+        "/api/backtest/{id}/monte-carlo/{job_id}",
+        "/api/risk/drawdown",
+        "/api/risk/var",
+        "/api/risk/correlations",
+        "/api/risk/hedge",
+        "/api/notifications/test",
+    ];
+    
+    for path in expected_paths {
+        assert!(spec.paths.paths.contains_key(path), "Missing path: {}", path);
+    }
+}
+
+#[test]
+fn test_openapi_spec_contains_request_schemas() {
+    let spec = ApiDoc::openapi();
+    let components = spec.components.expect("Spec has no components");
+    
+    for schema in ["PlaceOrderRequest", "BatchOrderResult", "CancelOrderRequest", "SetActiveStrategyRequest", "BacktestRequest", "UpdateCorrelationsRequest", "ErrorResponse", "WsMessage", "Price", "BacktestRun"] {
+        assert!(components.schemas.contains_key(schema), "Missing schema: {}", schema);
+    }
+}
+
+#[actix_web::test]
+async fn test_openapi_spec_is_served() {
+    let app = actix_web::test::init_service(App::new().configure(configure_routes)).await;
+    
+    let req = actix_web::test::TestRequest::get().uri("/api/openapi.json").to_request();
+    let spec: serde_json::Value = actix_web::test::call_and_read_body_json(&app, req).await;
+    
+    let place_order = &spec["paths"]["/api/order"]["post"];
+    assert_eq!(
+        place_order["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/PlaceOrderRequest"
+    );
+    let request_schema = &spec["components"]["schemas"]["PlaceOrderRequest"];
+    for field in ["symbol", "direction", "order_type", "quantity"] {
+        assert!(request_schema["properties"][field].is_object(), "PlaceOrderRequest is missing {}", field);
+    }
+    assert!(request_schema["required"].as_array().unwrap().contains(&serde_json::json!("symbol")));
+}
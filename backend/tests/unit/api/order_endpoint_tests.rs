@@ -0,0 +1,337 @@
+use arb_platform::api::{configure_routes, AppState};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::exchange::OrderStatus as ExchangeOrderStatus;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::{OrderEvent, OrderManager};
+use arb_platform::strategy::StrategyManager;
+
+use crate::helpers::mock_exchange::MockExchange;
+
+use actix_web::{test, web, App};
+use serde_json::json;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+fn create_state() -> AppState {
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(StrategyManager::new())),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(ExchangeManager::new())),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+#[actix_web::test]
+async fn test_batch_endpoint_reports_results_in_order() {
+    let state = create_state();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order/batch")
+        .set_json(json!([
+            {"symbol": "BTC/USD", "direction": "buy", "order_type": "market", "quantity": 1.0},
+            {"symbol": "ETH/USD", "direction": "sideways", "order_type": "market", "quantity": 1.0},
+            {"symbol": "SOL/USD", "direction": "sell", "order_type": "limit", "quantity": 2.0, "price": 150.0},
+            {"symbol": "ADA/USD", "direction": "buy", "order_type": "market", "quantity": 1.0, "price": 0.5},
+        ]))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let results = body["data"].as_array().unwrap();
+    assert_eq!(results.len(), 4);
+    for (index, result) in results.iter().enumerate() {
+        assert_eq!(result["index"], index);
+    }
+    
+    // Request errors and order manager validation errors both stay at their index
+    assert!(results[0]["order_id"].is_string());
+    assert_eq!(results[1]["error"], "Invalid direction: must be 'buy' or 'sell'");
+    assert!(results[1]["order_id"].is_null());
+    assert!(results[2]["order_id"].is_string());
+    assert_eq!(results[3]["error"], "Market orders should not specify a price");
+    
+    let order_manager = state.order_manager.read().await;
+    let order_id = results[2]["order_id"].as_str().unwrap().parse().unwrap();
+    assert_eq!(order_manager.get_order(order_id).await.unwrap().symbol, "SOL/USD");
+}
+
+#[actix_web::test]
+async fn test_batch_endpoint_rejects_empty_batch() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri("/api/order/batch").set_json(json!([])).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_fills_endpoint_lists_executions() {
+    let state = create_state();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 1.0, "price": 100.0}))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let order_id: uuid::Uuid = body["data"]["order_id"].as_str().unwrap().parse().unwrap();
+    
+    let sender = state.order_manager.read().await.get_event_sender();
+    for (filled, avg_price) in [(0.25, 99.0), (0.5, 99.5)] {
+        sender.send(OrderEvent::Update {
+            order_id,
+            status: None,
+            filled_qty: Some(filled),
+            avg_fill_price: Some(avg_price),
+        }).await.unwrap();
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    
+    let req = test::TestRequest::get().uri(&format!("/api/order/{}/fills", order_id)).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let fills = body["data"].as_array().unwrap();
+    assert_eq!(fills.len(), 2);
+    assert_eq!(fills[0]["fill_qty"], 0.25);
+    assert_eq!(fills[0]["fill_price"], 99.0);
+    assert_eq!(fills[1]["fill_qty"], 0.25);
+    assert_eq!(fills[1]["fill_price"], 100.0);
+    
+    let req = test::TestRequest::get().uri(&format!("/api/order/{}/fills", uuid::Uuid::new_v4())).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    
+    let req = test::TestRequest::get().uri("/api/order/not-a-uuid/fills").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_orders_endpoint_filters_by_tag() {
+    let state = create_state();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    for tags in [json!(["hedging"]), json!(["momentum"])] {
+        let req = test::TestRequest::post()
+            .uri("/api/order")
+            .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 1.0, "price": 100.0, "tags": tags}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    
+    // Tagged lookups include orders that have already failed routing
+    let req = test::TestRequest::get().uri("/api/order?tag=hedging").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let orders = body["data"].as_array().unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0]["tags"], json!(["hedging"]));
+    
+    let req = test::TestRequest::get().uri("/api/order?tag=unknown").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert!(body["data"].as_array().unwrap().is_empty());
+}
+
+#[actix_web::test]
+async fn test_twap_endpoint_starts_execution_and_reports_progress() {
+    let state = create_state();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order/twap")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 4.0, "price": 100.0, "duration_secs": 60, "num_slices": 4}))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let parent_id = body["data"]["parent_id"].as_str().unwrap().to_string();
+    assert_eq!(body["data"]["child_ids"].as_array().unwrap().len(), 4);
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    
+    // Only the first of four slices, 15 seconds apart, has gone out
+    let req = test::TestRequest::get().uri(&format!("/api/order/twap/{}", parent_id)).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["progress"]["total_slices"], 4);
+    assert_eq!(body["data"]["progress"]["slices_submitted"], 1);
+    assert_eq!(body["data"]["progress"]["quantity_submitted"], 1.0);
+    assert_eq!(body["data"]["progress"]["completed_at"], serde_json::Value::Null);
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order/twap")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "market", "quantity": 4.0, "duration_secs": 60, "num_slices": 0}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    
+    let req = test::TestRequest::get().uri(&format!("/api/order/twap/{}", uuid::Uuid::new_v4())).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_refresh_endpoint_applies_exchange_status() {
+    let state = create_state();
+    let exchange = MockExchange::new("Mock");
+    let router = state.order_manager.read().await.get_order_router();
+    router.register_exchange(Box::new(exchange.clone())).await.unwrap();
+    router.set_primary_exchange("BTC/USD", "Mock").await.unwrap();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 2.0, "price": 100.0}))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let order_id: uuid::Uuid = body["data"]["order_id"].as_str().unwrap().parse().unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    
+    exchange.set_order_status(order_id, ExchangeOrderStatus::PartiallyFilled, 0.5, Some(99.5));
+    let req = test::TestRequest::post().uri(&format!("/api/order/{}/refresh", order_id)).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["status"], "partially_filled");
+    assert_eq!(body["data"]["filled_quantity"], 0.5);
+    assert_eq!(body["data"]["average_fill_price"], "99.5");
+    
+    let req = test::TestRequest::post().uri(&format!("/api/order/{}/refresh", uuid::Uuid::new_v4())).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    
+    let req = test::TestRequest::post().uri("/api/order/not-a-uuid/refresh").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_history_endpoint_pages_order_amendments() {
+    let state = create_state();
+    let exchange = MockExchange::new("Mock");
+    let router = state.order_manager.read().await.get_order_router();
+    router.register_exchange(Box::new(exchange.clone())).await.unwrap();
+    router.set_primary_exchange("BTC/USD", "Mock").await.unwrap();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 2.0, "price": 100.0}))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let order_id: uuid::Uuid = body["data"]["order_id"].as_str().unwrap().parse().unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    state.order_manager.read().await.reduce_order(order_id, 1.5).await.unwrap();
+    
+    // The order itself carries the whole history
+    let req = test::TestRequest::get().uri(&format!("/api/order/{}", order_id)).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let history = body["data"]["amendment_history"].as_array().unwrap();
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[1]["new_value"], "submitted");
+    assert_eq!(history[2]["field"], "quantity");
+    
+    let req = test::TestRequest::get().uri(&format!("/api/order/{}/history?limit=2&offset=1", order_id)).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["total"], 3);
+    assert_eq!(body["data"]["limit"], 2);
+    assert_eq!(body["data"]["offset"], 1);
+    let amendments = body["data"]["amendments"].as_array().unwrap();
+    assert_eq!(amendments.len(), 2);
+    assert_eq!(amendments[0]["reason"], "Accepted by exchange");
+    assert_eq!(amendments[1]["old_value"], 2.0);
+    assert_eq!(amendments[1]["new_value"], 1.5);
+    
+    let req = test::TestRequest::get().uri(&format!("/api/order/{}/history?limit=0", order_id)).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+    
+    let req = test::TestRequest::get().uri(&format!("/api/order/{}/history", uuid::Uuid::new_v4())).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    
+    let req = test::TestRequest::get().uri("/api/order/not-a-uuid/history").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_order_errors_map_to_statuses_by_kind() {
+    let state = create_state();
+    let exchange = MockExchange::new("Mock");
+    let router = state.order_manager.read().await.get_order_router();
+    router.register_exchange(Box::new(exchange.clone())).await.unwrap();
+    router.set_primary_exchange("BTC/USD", "Mock").await.unwrap();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    // Validation failure from the order manager
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "market", "quantity": 1.0, "price": 100.0}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["error"], "Market orders should not specify a price");
+    
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/order/{}/cancel", uuid::Uuid::new_v4()))
+        .set_json(json!({}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    
+    // A filled order can no longer be cancelled
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 1.0, "price": 100.0}))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let order_id: uuid::Uuid = body["data"]["order_id"].as_str().unwrap().parse().unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    exchange.set_order_status(order_id, ExchangeOrderStatus::Filled, 1.0, Some(100.0));
+    let req = test::TestRequest::post().uri(&format!("/api/order/{}/refresh", order_id)).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/order/{}/cancel", order_id))
+        .set_json(json!({}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::CONFLICT);
+}
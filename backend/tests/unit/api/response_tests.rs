@@ -0,0 +1,280 @@
+// Every API response type survives a JSON round trip unchanged, over random values
+use arb_platform::api::{
+    AccountBalanceResponse, AccountHistoryPage, BatchOrderResult, CancelOrderResponse, EventChannelMetrics,
+    FundingRateStatus, HealthResponse, MessageResponse, OrderAmendmentPage, OrderRefreshResponse, OrderResponse,
+    OrderSummaryResponse, PlaceOrderResponse, QuotesResponse, StrategyEvaluation, StrategyEvaluationsResponse,
+    TestNotificationResponse,
+    TwapExecutionResponse, VarResponse,
+};
+use arb_platform::channel::{BackpressurePolicy, ChannelStats};
+use arb_platform::exchange::{AccountBalance, AccountTransaction, TransactionType};
+use arb_platform::market_data::FundingRate;
+use arb_platform::models::Price;
+use arb_platform::order::{OrderAmendment, OrderStatus, OrderType, TwapProgress};
+use arb_platform::risk::{CircuitBreakerStatus, VarMethod};
+use arb_platform::strategy::{AssetData, AssetType, SelectionObjective, TimeInForce, TradeDirection};
+
+use chrono::{DateTime, TimeZone, Utc};
+use proptest::collection::{hash_map, vec};
+use proptest::option;
+use proptest::prelude::*;
+use proptest::sample::select;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) -> Result<(), TestCaseError> {
+    let json = serde_json::to_string(value).unwrap();
+    let parsed: T = serde_json::from_str(&json).map_err(|e| TestCaseError::fail(format!("{}: {}", e, json)))?;
+    prop_assert_eq!(&parsed, value);
+    Ok(())
+}
+
+// Quarter steps, which JSON writes and reads back exactly
+fn amount() -> impl Strategy<Value = f64> {
+    (-4_000_000i64..4_000_000).prop_map(|n| n as f64 / 4.0)
+}
+
+fn fraction() -> impl Strategy<Value = f64> {
+    (0u32..=100).prop_map(|n| f64::from(n) / 100.0)
+}
+
+fn price() -> impl Strategy<Value = Price> {
+    (1i64..10_000_000).prop_map(|n| Price::from(n as f64 / 100.0))
+}
+
+fn timestamp() -> impl Strategy<Value = DateTime<Utc>> {
+    (0i64..4_000_000_000, 0u32..1_000_000_000).prop_map(|(secs, nanos)| Utc.timestamp_opt(secs, nanos).unwrap())
+}
+
+fn uuid() -> impl Strategy<Value = Uuid> {
+    any::<u128>().prop_map(Uuid::from_u128)
+}
+
+fn text() -> impl Strategy<Value = String> {
+    "\\PC{0,16}"
+}
+
+fn order_status() -> impl Strategy<Value = OrderStatus> {
+    select(OrderStatus::ALL.to_vec())
+}
+
+fn order_type() -> impl Strategy<Value = OrderType> {
+    select(OrderType::ALL.to_vec())
+}
+
+fn direction() -> impl Strategy<Value = TradeDirection> {
+    select(vec![TradeDirection::Buy, TradeDirection::Sell])
+}
+
+fn time_in_force() -> impl Strategy<Value = TimeInForce> {
+    select(vec![TimeInForce::Day, TimeInForce::GoodTilCancelled, TimeInForce::FillOrKill, TimeInForce::ImmediateOrCancel])
+}
+
+fn circuit_breaker() -> impl Strategy<Value = CircuitBreakerStatus> {
+    (any::<bool>(), fraction(), fraction(), amount(), amount(), option::of(timestamp()), option::of(timestamp()))
+        .prop_map(|(halted, intraday_drawdown, max_intraday_drawdown, peak_equity, current_equity, tripped_at, last_update)| {
+            CircuitBreakerStatus { halted, intraday_drawdown, max_intraday_drawdown, peak_equity, current_equity, tripped_at, last_update }
+        })
+}
+
+fn channel_stats() -> impl Strategy<Value = ChannelStats> {
+    let policy = select(vec![BackpressurePolicy::Block, BackpressurePolicy::DropOldest, BackpressurePolicy::DropNewestWithCounter]);
+    (any::<usize>(), policy, any::<usize>(), any::<u64>())
+        .prop_map(|(capacity, policy, queued, dropped_events)| ChannelStats { capacity, policy, queued, dropped_events })
+}
+
+fn asset_data() -> impl Strategy<Value = AssetData> {
+    let asset_type = select(vec![AssetType::Stock, AssetType::Crypto, AssetType::Forex, AssetType::Future, AssetType::ETF]);
+    (text(), asset_type, price(), amount(), price(), price(), option::of(price()), option::of(fraction()), text(), timestamp())
+        .prop_map(|(symbol, asset_type, price, volume, bid, ask, tick_size, lot_size, exchange, last_update)| {
+            AssetData { symbol, asset_type, price, volume, bid, ask, tick_size, lot_size, exchange, last_update }
+        })
+}
+
+fn account_balance() -> impl Strategy<Value = AccountBalance> {
+    (amount(), amount(), text(), vec((text(), amount()), 0..3), timestamp())
+        .prop_map(|(total, available, currency, additional_balances, timestamp)| {
+            AccountBalance { total, available, currency, additional_balances, timestamp }
+        })
+}
+
+fn transaction() -> impl Strategy<Value = AccountTransaction> {
+    let transaction_type = select(vec![
+        TransactionType::Trade, TransactionType::Deposit, TransactionType::Withdrawal,
+        TransactionType::Fee, TransactionType::Interest, TransactionType::Funding,
+    ]);
+    (text(), transaction_type, text(), amount(), amount(), timestamp(), option::of(text()))
+        .prop_map(|(id, transaction_type, currency, amount, fee, timestamp, description)| {
+            AccountTransaction { id, transaction_type, currency, amount, fee, timestamp, description }
+        })
+}
+
+fn twap_progress() -> impl Strategy<Value = TwapProgress> {
+    (any::<usize>(), any::<usize>(), any::<usize>(), amount(), amount(), vec(text(), 0..3), timestamp(), option::of(timestamp()))
+        .prop_map(|(total_slices, slices_submitted, slices_failed, total_quantity, quantity_submitted, errors, started_at, completed_at)| {
+            TwapProgress { total_slices, slices_submitted, slices_failed, total_quantity, quantity_submitted, errors, started_at, completed_at }
+        })
+}
+
+fn strategy_evaluation() -> impl Strategy<Value = StrategyEvaluation> {
+    (text(), fraction(), amount(), amount(), any::<usize>(), any::<bool>())
+        .prop_map(|(strategy, confidence, expected_profit, required_capital, signals, is_best)| {
+            StrategyEvaluation { strategy, confidence, expected_profit, required_capital, signals, is_best }
+        })
+}
+
+fn order_summary() -> impl Strategy<Value = OrderSummaryResponse> {
+    (
+        (uuid(), text(), direction(), order_type(), amount(), amount()),
+        (option::of(price()), option::of(price()), order_status(), vec(text(), 0..3), timestamp(), timestamp()),
+    ).prop_map(|((id, symbol, direction, order_type, quantity, filled_quantity), (price, stop_price, status, tags, created_at, updated_at))| {
+        OrderSummaryResponse { id, symbol, direction, order_type, quantity, filled_quantity, price, stop_price, status, tags, created_at, updated_at }
+    })
+}
+
+fn amendment() -> impl Strategy<Value = OrderAmendment> {
+    let status_change = (order_status(), order_status()).prop_map(|(old, new)| ("status".to_string(), json!(old), json!(new)));
+    let quantity_change = (amount(), amount()).prop_map(|(old, new)| ("quantity".to_string(), json!(old), json!(new)));
+    (timestamp(), prop_oneof![status_change, quantity_change], text())
+        .prop_map(|(timestamp, (field, old_value, new_value), reason)| OrderAmendment { timestamp, field, old_value, new_value, reason })
+}
+
+fn order() -> impl Strategy<Value = OrderResponse> {
+    (
+        (uuid(), text(), text(), direction(), order_type(), amount(), amount()),
+        (option::of(price()), option::of(price()), time_in_force(), order_status(), text(), timestamp(), timestamp()),
+        (option::of(timestamp()), option::of(price()), option::of(amount()), option::of(text()), option::of(text()), vec(text(), 0..3), any::<bool>()),
+        vec(amendment(), 0..3),
+    ).prop_map(|(
+        (id, client_order_id, symbol, direction, order_type, quantity, filled_quantity),
+        (price, stop_price, time_in_force, status, exchange, created_at, updated_at),
+        (filled_at, average_fill_price, unfilled_quantity, strategy_id, notes, tags, post_only),
+        amendment_history,
+    )| OrderResponse {
+        id, client_order_id, symbol, direction, order_type, quantity, filled_quantity, price, stop_price, time_in_force,
+        status, exchange, created_at, updated_at, filled_at, average_fill_price, unfilled_quantity, strategy_id, notes, tags, post_only,
+        amendment_history,
+    })
+}
+
+proptest! {
+    #[test]
+    fn health_response_round_trips(
+        status in text(), timestamp in timestamp(), trading_halted in any::<bool>(),
+        circuit_breaker in option::of(circuit_breaker()), disabled_symbols in vec(text(), 0..3),
+    ) {
+        round_trip(&HealthResponse { status, timestamp, trading_halted, circuit_breaker, disabled_symbols })?;
+    }
+
+    #[test]
+    fn event_channel_metrics_round_trip(order_events in channel_stats(), market_events in channel_stats()) {
+        round_trip(&EventChannelMetrics { order_events, market_events })?;
+    }
+
+    #[test]
+    fn quotes_response_round_trips(quotes in hash_map(text(), asset_data(), 0..3), missing in vec(text(), 0..3)) {
+        round_trip(&QuotesResponse { quotes, missing })?;
+    }
+
+    #[test]
+    fn funding_rate_status_round_trips(
+        symbol in text(), rate in fraction(), next_funding in timestamp(), annualized_rate in amount(),
+        seconds_to_next_funding in any::<i64>(),
+    ) {
+        let funding_rate = FundingRate { symbol, rate, next_funding, annualized_rate };
+        round_trip(&FundingRateStatus { funding_rate, seconds_to_next_funding })?;
+    }
+
+    #[test]
+    fn message_response_round_trips(success in any::<bool>(), message in text()) {
+        round_trip(&MessageResponse { success, message })?;
+    }
+
+    #[test]
+    fn strategy_evaluation_round_trips(evaluation in strategy_evaluation()) {
+        round_trip(&evaluation)?;
+    }
+
+    #[test]
+    fn strategy_evaluations_response_round_trips(
+        timestamp in timestamp(), objective in select(vec![SelectionObjective::Profit, SelectionObjective::Roi]),
+        results in vec(strategy_evaluation(), 0..3), best_strategy in option::of(text()),
+    ) {
+        round_trip(&StrategyEvaluationsResponse { timestamp, objective, results, best_strategy })?;
+    }
+
+    #[test]
+    fn place_order_response_round_trips(order_id in uuid(), status in order_status()) {
+        round_trip(&PlaceOrderResponse { order_id, status })?;
+    }
+
+    #[test]
+    fn batch_order_result_round_trips(index in any::<usize>(), order_id in option::of(text()), error in option::of(text())) {
+        round_trip(&BatchOrderResult { index, order_id, error })?;
+    }
+
+    #[test]
+    fn twap_execution_response_round_trips(
+        parent_id in uuid(), child_ids in vec(uuid(), 0..4), progress in twap_progress(), filled_quantity in option::of(amount()),
+    ) {
+        round_trip(&TwapExecutionResponse { parent_id, child_ids, progress, filled_quantity })?;
+    }
+
+    #[test]
+    fn order_summary_response_round_trips(summary in order_summary()) {
+        round_trip(&summary)?;
+    }
+
+    #[test]
+    fn order_response_round_trips(order in order()) {
+        round_trip(&order)?;
+    }
+
+    #[test]
+    fn cancel_order_response_round_trips(order_id in uuid(), status in order_status(), reason in text()) {
+        round_trip(&CancelOrderResponse { order_id, status, reason })?;
+    }
+
+    #[test]
+    fn order_refresh_response_round_trips(
+        order_id in uuid(), status in order_status(), filled_quantity in amount(),
+        average_fill_price in option::of(price()), updated_at in timestamp(),
+    ) {
+        round_trip(&OrderRefreshResponse { order_id, status, filled_quantity, average_fill_price, updated_at })?;
+    }
+
+    #[test]
+    fn account_balance_response_round_trips(balance in account_balance(), total_usd_equivalent in option::of(amount())) {
+        round_trip(&AccountBalanceResponse { balance, total_usd_equivalent })?;
+    }
+
+    #[test]
+    fn account_history_page_round_trips(
+        transactions in vec(transaction(), 0..3), total in any::<usize>(), limit in any::<usize>(), offset in any::<usize>(),
+    ) {
+        round_trip(&AccountHistoryPage { transactions, total, limit, offset })?;
+    }
+
+    #[test]
+    fn order_amendment_page_round_trips(
+        amendments in vec(amendment(), 0..3), total in any::<usize>(), limit in any::<usize>(), offset in any::<usize>(),
+    ) {
+        round_trip(&OrderAmendmentPage { amendments, total, limit, offset })?;
+    }
+
+    #[test]
+    fn var_response_round_trips(
+        confidence in fraction(), method in select(vec![VarMethod::Historical, VarMethod::Parametric]),
+        var in amount(), cvar in amount(), portfolio_value in amount(),
+    ) {
+        round_trip(&VarResponse { confidence, method, var, cvar, portfolio_value })?;
+    }
+
+    #[test]
+    fn test_notification_response_round_trips(handlers in vec(text(), 0..3), delivered in any::<usize>(), errors in vec(text(), 0..3)) {
+        round_trip(&TestNotificationResponse { handlers, delivered, errors })?;
+    }
+}
@@ -0,0 +1,235 @@
+use arb_platform::api::{configure_routes, AppState};
+use arb_platform::channel::{BackpressurePolicy, ChannelConfig};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::exchange::Position;
+use arb_platform::market_data::{MarketDataManager, MarketEvent};
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::OrderManager;
+use arb_platform::risk::CircuitBreaker;
+use arb_platform::strategy::{StrategyManager, TradeDirection};
+
+use actix_web::{test, web, App};
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::helpers::mock_exchange::MockExchange;
+
+fn create_state() -> AppState {
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(StrategyManager::new())),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(ExchangeManager::new())),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+#[actix_web::test]
+async fn test_var_endpoint_requires_return_history() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/risk/var?confidence=0.95&method=historical").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    
+    let req = test::TestRequest::get().uri("/api/risk/var?confidence=1.5").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_var_endpoint_returns_var_and_cvar() {
+    let state = create_state();
+    let position_manager = state.order_manager.read().await.get_position_manager();
+    position_manager.update_position(Position {
+        symbol: "ETH/USD".to_string(),
+        quantity: 10.0,
+        avg_price: 2000.0,
+        current_price: 2000.0,
+        unrealized_pnl: 0.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }).await;
+    for i in 0..100 {
+        position_manager.record_daily_return((i as f64 - 50.0) / 1000.0).await;
+    }
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/risk/var?confidence=0.95&method=historical").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    let data = &body["data"];
+    assert_eq!(data["method"], "historical");
+    assert!((data["var"].as_f64().unwrap() - 900.0).abs() < 1e-6);
+    assert!((data["cvar"].as_f64().unwrap() - 950.0).abs() < 1e-6);
+    assert_eq!(data["portfolio_value"], 20000.0);
+    
+    let req = test::TestRequest::get().uri("/api/risk/var?method=parametric").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["method"], "parametric");
+    assert!(body["data"]["var"].as_f64().unwrap() > 0.0);
+}
+
+#[actix_web::test]
+async fn test_update_correlations_endpoint() {
+    let state = create_state();
+    let portfolio_manager = state.order_manager.read().await.get_portfolio_manager();
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::put()
+        .uri("/api/risk/correlations")
+        .set_json(serde_json::json!({
+            "correlations": [
+                { "symbol_a": "ETH/USD", "symbol_b": "BTC/USD", "correlation": 0.8 }
+            ]
+        }))
+        .to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"][0]["symbol_a"], "BTC/USD");
+    assert_eq!(body["data"][0]["symbol_b"], "ETH/USD");
+    assert_eq!(body["data"][0]["correlation"], 0.8);
+    assert_eq!(portfolio_manager.correlation("BTC/USD", "ETH/USD").await, 0.8);
+    
+    let req = test::TestRequest::put()
+        .uri("/api/risk/correlations")
+        .set_json(serde_json::json!({
+            "correlations": [
+                { "symbol_a": "ETH/USD", "symbol_b": "BTC/USD", "correlation": -1.2 }
+            ]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    assert_eq!(portfolio_manager.correlation("BTC/USD", "ETH/USD").await, 0.8);
+}
+
+#[actix_web::test]
+async fn test_hedge_endpoint_places_opposing_order_on_hedge_exchange() {
+    let state = create_state();
+    {
+        let order_manager = state.order_manager.read().await;
+        let router = order_manager.get_order_router();
+        router.register_exchange(Box::new(MockExchange::new("Hedge"))).await.unwrap();
+        order_manager.get_position_manager().apply_fill("BTC/USD", TradeDirection::Buy, 2.0, 50000.0).await;
+    }
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post()
+        .uri("/api/risk/hedge")
+        .set_json(serde_json::json!({"symbol": "BTC/USD", "position_usd": 100000.0, "hedge_ratio": 0.5, "hedge_exchange": "Hedge"}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::OK);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["direction"], "sell");
+    assert_eq!(body["data"]["quantity"], 1.0);
+    assert_eq!(body["data"]["exchange"], "Hedge");
+    let order_id = body["data"]["order_id"].as_str().unwrap().parse().unwrap();
+    assert!(state.order_manager.read().await.get_order(order_id).await.is_some());
+    
+    // Nothing to hedge, an exchange without the symbol, and an unknown exchange
+    let cases = [
+        (serde_json::json!({"symbol": "ETH/USD", "position_usd": 1000.0, "hedge_exchange": "Hedge"}), actix_web::http::StatusCode::BAD_REQUEST),
+        (serde_json::json!({"symbol": "BTC/EUR", "position_usd": 1000.0, "hedge_exchange": "Hedge"}), actix_web::http::StatusCode::BAD_REQUEST),
+        (serde_json::json!({"symbol": "BTC/USD", "position_usd": 1000.0, "hedge_exchange": "Elsewhere"}), actix_web::http::StatusCode::NOT_FOUND),
+        (serde_json::json!({"symbol": "BTC/USD", "position_usd": -1000.0, "hedge_exchange": "Hedge"}), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY),
+        (serde_json::json!({"symbol": "BTC/USD", "position_usd": 1000.0, "hedge_ratio": 0.0, "hedge_exchange": "Hedge"}), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY),
+    ];
+    for (request, status) in cases {
+        let req = test::TestRequest::post().uri("/api/risk/hedge").set_json(&request).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), status, "{}", request);
+    }
+}
+
+#[actix_web::test]
+async fn test_health_endpoint_reports_circuit_breaker() {
+    let state = create_state();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    // No breaker configured
+    let req = test::TestRequest::get().uri("/api/health").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["trading_halted"], false);
+    assert!(body["circuit_breaker"].is_null());
+    assert_eq!(body["disabled_symbols"], serde_json::json!([]));
+    
+    state.order_manager.read().await.set_symbol_enabled("ETH/USD", false).await;
+    
+    state.order_manager.write().await
+        .set_circuit_breaker(Some(CircuitBreaker::new(0.05, 1000.0).unwrap()));
+    {
+        let order_manager = state.order_manager.read().await;
+        let positions = order_manager.get_position_manager();
+        positions.apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+        positions.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 40.0).await;
+    }
+    
+    // Health checks only report the breaker; they never trip it
+    let req = test::TestRequest::get().uri("/api/health").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["trading_halted"], false);
+    assert_eq!(body["circuit_breaker"]["halted"], false);
+    
+    state.order_manager.read().await.refresh_circuit_breaker().await;
+    let req = test::TestRequest::get().uri("/api/health").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["trading_halted"], true);
+    assert_eq!(body["circuit_breaker"]["halted"], true);
+    assert_eq!(body["circuit_breaker"]["current_equity"], 940.0);
+    assert_eq!(body["disabled_symbols"], serde_json::json!(["ETH/USD"]));
+}
+
+#[actix_web::test]
+async fn test_metrics_endpoint_reports_dropped_events() {
+    let mut state = create_state();
+    state.market_data_manager = Arc::new(RwLock::new(
+        MarketDataManager::with_channel_config(ChannelConfig::new(2, BackpressurePolicy::DropNewestWithCounter))));
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let sender = state.market_data_manager.read().await.get_event_sender();
+    for _ in 0..5 {
+        sender.send(MarketEvent::SourceReconnected { source_name: "Test".to_string() }).await.unwrap();
+    }
+    
+    let req = test::TestRequest::get().uri("/api/metrics").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"]["market_events"]["policy"], "drop_newest_with_counter");
+    assert_eq!(body["data"]["market_events"]["capacity"], 2);
+    assert_eq!(body["data"]["market_events"]["dropped_events"], 3);
+    assert_eq!(body["data"]["order_events"]["policy"], "block");
+    assert_eq!(body["data"]["order_events"]["dropped_events"], 0);
+}
@@ -0,0 +1,274 @@
+use arb_platform::api::{configure_routes, AppState};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::{Order, OrderManager, OrderStatus};
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    AssetType, MarketData, MomentumStrategy, Strategy, StrategyManager, StrategyParams, StrategyResult,
+    TimeInForce, TradeDirection, TradeSignal
+};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use actix_web::{test, web, App};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+struct SignalStrategy;
+
+impl Strategy for SignalStrategy {
+    fn name(&self) -> &str { "Signal Strategy" }
+    fn description(&self) -> &str { "Always emits the same signals" }
+    fn asset_types(&self) -> Vec<AssetType> { vec![AssetType::Crypto] }
+    
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        StrategyResult {
+            signals: vec![
+                TradeSignal {
+                    asset: "BTC/USD".to_string(),
+                    direction: TradeDirection::Buy,
+                    quantity: 0.5,
+                    limit_price: Some(35000.0),
+                    stop_price: None,
+                    time_in_force: TimeInForce::Day,
+                    priority: TimeInForce::Day.default_signal_priority(),
+                },
+                TradeSignal {
+                    asset: "ETH/USD".to_string(),
+                    direction: TradeDirection::Sell,
+                    quantity: 2.0,
+                    limit_price: None,
+                    stop_price: Some(1800.0),
+                    time_in_force: TimeInForce::ImmediateOrCancel,
+                    priority: TimeInForce::ImmediateOrCancel.default_signal_priority(),
+                },
+            ],
+            confidence: 0.7,
+            expected_profit: 120.0,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+    
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+}
+
+fn create_state() -> AppState {
+    let mut strategy_manager = StrategyManager::new();
+    strategy_manager.register_strategy(Box::new(SignalStrategy));
+    strategy_manager.register_strategy(Box::new(MomentumStrategy::new()));
+    
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(strategy_manager)),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(ExchangeManager::new())),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+#[actix_web::test]
+async fn test_evaluate_one_returns_strategy_result() {
+    let state = create_state();
+    let manager = state.strategy_manager.read().await;
+    let market_data = MarketData { timestamp: chrono::Utc::now(), asset_data: HashMap::new() };
+    
+    let result = manager.evaluate_one("Signal Strategy", &market_data).unwrap();
+    assert_eq!(result.signals.len(), 2);
+    assert!(manager.evaluate_one("Missing", &market_data).is_none());
+}
+
+#[actix_web::test]
+async fn test_evaluate_single_strategy_endpoint() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/strategy/Signal%20Strategy/evaluate").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    
+    let data = &body["data"];
+    assert_eq!(data["confidence"], 0.7);
+    assert_eq!(data["expected_profit"], 120.0);
+    
+    let signals = data["signals"].as_array().expect("Signals should be serialized in full");
+    assert_eq!(signals.len(), 2);
+    assert_eq!(signals[0]["asset"], "BTC/USD");
+    assert_eq!(signals[0]["direction"], "buy");
+    assert_eq!(signals[0]["limit_price"], 35000.0);
+    assert_eq!(signals[1]["asset"], "ETH/USD");
+    assert_eq!(signals[1]["stop_price"], 1800.0);
+}
+
+#[actix_web::test]
+async fn test_evaluate_unknown_strategy_returns_404() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::get().uri("/api/strategy/Unknown/evaluate").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["error"], "Strategy not found: Unknown");
+}
+
+#[actix_web::test]
+async fn test_hot_swap_cancels_outgoing_strategy_orders() {
+    let state = create_state();
+    state.strategy_manager.write().await.set_active_strategy("Signal Strategy").unwrap();
+    state.order_manager.read().await.get_order_router()
+        .register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    let order_id = state.order_manager.read().await.place_order(Order {
+        client_order_id: "signal-1".to_string(),
+        price: Some(Price::from(35000.0)),
+        exchange: "Mock".to_string(),
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+        strategy_id: Some("Signal Strategy".to_string()),
+        ..test_order()
+    }).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::put()
+        .uri("/api/strategy/active")
+        .set_json(serde_json::json!({"name": "Momentum", "transition": "cancel_existing_orders"}))
+        .to_request();
+    assert!(test::call_service(&app, req).await.status().is_success());
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    
+    let req = test::TestRequest::get().uri("/api/strategy/active").to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["data"], "Momentum");
+    assert!(state.order_manager.read().await.get_open_orders_by_strategy("Signal Strategy").await.is_empty());
+    assert_eq!(state.order_manager.read().await.get_order(order_id).await.unwrap().status, OrderStatus::Cancelled);
+    
+    let req = test::TestRequest::put()
+        .uri("/api/strategy/active")
+        .set_json(serde_json::json!({"name": "Momentum", "transition": "sideways"}))
+        .to_request();
+    assert!(test::call_service(&app, req).await.status().is_client_error());
+}
+
+// Emits one buy of `quantity` at `price` expecting `expected_profit`
+struct FixedStrategy {
+    name: &'static str,
+    quantity: f64,
+    price: f64,
+    expected_profit: f64,
+}
+
+impl Strategy for FixedStrategy {
+    fn name(&self) -> &str { self.name }
+    fn description(&self) -> &str { "Always emits one buy" }
+    fn asset_types(&self) -> Vec<AssetType> { vec![AssetType::Crypto] }
+    
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        StrategyResult {
+            signals: vec![TradeSignal {
+                asset: "BTC/USD".to_string(),
+                direction: TradeDirection::Buy,
+                quantity: self.quantity,
+                limit_price: Some(self.price),
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                priority: TimeInForce::Day.default_signal_priority(),
+            }],
+            confidence: 0.8,
+            expected_profit: self.expected_profit,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+    
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+}
+
+// "Big" makes the most, 100 on 10,000 of capital; "Lean" makes 50 on 500
+fn create_capital_state() -> AppState {
+    let state = create_state();
+    let mut strategy_manager = StrategyManager::new();
+    strategy_manager.register_strategy(Box::new(FixedStrategy { name: "Big", quantity: 1.0, price: 10000.0, expected_profit: 100.0 }));
+    strategy_manager.register_strategy(Box::new(FixedStrategy { name: "Lean", quantity: 0.05, price: 10000.0, expected_profit: 50.0 }));
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(strategy_manager)),
+        ..state
+    }
+}
+
+async fn evaluate_all(uri: &str) -> serde_json::Value {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_capital_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri(uri).to_request();
+    let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+    body["data"].clone()
+}
+
+fn result_for<'a>(data: &'a serde_json::Value, strategy: &str) -> &'a serde_json::Value {
+    data["results"].as_array().unwrap().iter()
+        .find(|result| result["strategy"] == strategy)
+        .unwrap()
+}
+
+#[actix_web::test]
+async fn test_evaluate_all_picks_the_most_profitable_by_default() {
+    for uri in ["/api/strategy/evaluate", "/api/strategy/evaluate?objective=profit"] {
+        let data = evaluate_all(uri).await;
+        
+        assert_eq!(data["objective"], "profit");
+        assert_eq!(data["best_strategy"], "Big");
+        assert_eq!(result_for(&data, "Big")["is_best"], true);
+        assert_eq!(result_for(&data, "Big")["required_capital"], 10000.0);
+        assert_eq!(result_for(&data, "Lean")["required_capital"], 500.0);
+    }
+}
+
+#[actix_web::test]
+async fn test_evaluate_all_picks_the_best_return_on_capital() {
+    let data = evaluate_all("/api/strategy/evaluate?objective=roi").await;
+    
+    assert_eq!(data["objective"], "roi");
+    assert_eq!(data["best_strategy"], "Lean");
+    assert_eq!(result_for(&data, "Lean")["is_best"], true);
+    assert_eq!(result_for(&data, "Big")["is_best"], false);
+}
+
+#[actix_web::test]
+async fn test_evaluate_all_rejects_unknown_objectives() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_capital_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri("/api/strategy/evaluate?objective=sharpe").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
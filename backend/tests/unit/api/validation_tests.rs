@@ -0,0 +1,142 @@
+use arb_platform::api::{configure_routes, AppState, MAX_PAYLOAD_BYTES};
+use arb_platform::exchange::manager::ExchangeManager;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::notifications::NotificationManager;
+use arb_platform::order::OrderManager;
+use arb_platform::strategy::StrategyManager;
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+fn create_state() -> AppState {
+    AppState {
+        strategy_manager: Arc::new(RwLock::new(StrategyManager::new())),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(OrderManager::new())),
+        exchange_manager: Arc::new(RwLock::new(ExchangeManager::new())),
+        notification_manager: Arc::new(NotificationManager::new()),
+        backtests: Arc::default(),
+        backtest_runner: Arc::default(),
+        monte_carlo_jobs: Arc::default(),
+        symbol_normalizer: Arc::default(),
+    }
+}
+
+fn order(overrides: Value) -> Value {
+    let mut order = json!({"symbol": "BTC/USD", "direction": "buy", "order_type": "limit", "quantity": 1.0, "price": 100.0});
+    for (key, value) in overrides.as_object().unwrap() {
+        order[key] = value.clone();
+    }
+    order
+}
+
+#[actix_web::test]
+async fn test_invalid_order_fields_are_reported_with_422() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post().uri("/api/order").set_json(order(json!({}))).to_request();
+    assert!(test::call_service(&app, req).await.status().is_success());
+    
+    let tags: Vec<String> = (0..51).map(|i| format!("tag-{}", i)).collect();
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(order(json!({"symbol": "BTC/USD'; --", "quantity": 0.0, "tags": tags})))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    
+    let body: Value = test::read_body_json(resp).await;
+    let fields = body["fields"].as_object().unwrap();
+    assert_eq!(fields.keys().collect::<Vec<_>>(), vec!["quantity", "symbol", "tags"]);
+    assert_eq!(body["fields"]["quantity"], json!(["must be at least 0.000001"]));
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(order(json!({"symbol": "A".repeat(21)})))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["fields"]["symbol"], json!(["must be 1 to 20 characters"]));
+}
+
+#[actix_web::test]
+async fn test_other_requests_are_validated() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::put()
+        .uri("/api/strategy/active")
+        .set_json(json!({"name": "x".repeat(101)}))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    
+    let req = test::TestRequest::post()
+        .uri("/api/backtest")
+        .set_json(json!({
+            "strategy": "Statistical Arbitrage",
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "symbols": ["BTC/USD", "<script>"],
+            "initial_capital": 100000.0,
+            "parameters": {},
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(body["fields"]["symbols"].is_array());
+    
+    // Fields of the flattened parent order are reported under "order"
+    let mut twap = order(json!({"symbol": "BTC USD"}));
+    twap["duration_secs"] = json!(60);
+    twap["num_slices"] = json!(4);
+    let req = test::TestRequest::post().uri("/api/order/twap").set_json(twap).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(body["fields"]["order.symbol"].is_array());
+    
+    let req = test::TestRequest::get().uri(&format!("/api/order?tag={}", "t".repeat(101))).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[actix_web::test]
+async fn test_batch_reports_invalid_orders_at_their_index() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order/batch")
+        .set_json(json!([order(json!({})), order(json!({"quantity": -1.0}))]))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(body["data"][0]["order_id"].is_string());
+    assert_eq!(body["data"][1]["error"], "quantity: must be at least 0.000001");
+}
+
+#[actix_web::test]
+async fn test_oversized_payloads_are_rejected() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(create_state()))
+            .configure(configure_routes)
+    ).await;
+    
+    let req = test::TestRequest::post()
+        .uri("/api/order")
+        .set_json(order(json!({"notes": "x".repeat(MAX_PAYLOAD_BYTES)})))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
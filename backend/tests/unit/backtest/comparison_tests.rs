@@ -0,0 +1,88 @@
+use arb_platform::backtest::{BacktestComparison, BacktestEngine, BacktestRun, BacktestSpec};
+use arb_platform::strategy::StrategyParams;
+
+use crate::helpers::fixed_side_strategy::{daily_history, FixedSideStrategy};
+
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn spec(commission_rate: f64) -> BacktestSpec {
+    BacktestSpec {
+        strategy: "Fixed Side".to_string(),
+        symbols: vec!["BTC/USD".to_string()],
+        start: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap(),
+        initial_capital: 1000.0,
+        params: StrategyParams {
+            params: HashMap::from([
+                ("quantity".to_string(), json!(1.0)),
+                ("commission_rate".to_string(), json!(commission_rate)),
+            ]),
+        },
+    }
+}
+
+// A completed run of `spec` over the given prices
+fn completed_run(spec: &BacktestSpec, prices: &[f64]) -> BacktestRun {
+    let engine = BacktestEngine::new(daily_history(prices), FixedSideStrategy::factory(), spec.initial_capital);
+    let mut run = BacktestRun::new(spec);
+    run.result = Some(engine.backtest(&StrategyParams { params: HashMap::new() }, 0, prices.len() - 1).unwrap());
+    run
+}
+
+#[test]
+fn test_win_rate_counts_profitable_positions() {
+    let a = completed_run(&spec(0.001), &[100.0, 101.0, 104.0, 102.0, 103.0]);
+    assert_eq!(a.result.unwrap().win_rate(), 0.75);
+
+    let flat = completed_run(&spec(0.001), &[100.0, 100.0]);
+    assert_eq!(flat.result.unwrap().win_rate(), 0.0);
+}
+
+#[test]
+fn test_comparison_reports_deltas_and_only_differing_parameters() {
+    let a = completed_run(&spec(0.001), &[100.0, 101.0, 104.0, 102.0, 103.0]);
+    let b = completed_run(&spec(0.002), &[100.0, 99.0, 101.0, 100.0, 102.0]);
+    let (a_result, b_result) = (a.result.clone().unwrap(), b.result.clone().unwrap());
+
+    let comparison = BacktestComparison::new(&a, &b).unwrap();
+    assert_eq!((comparison.a_id, comparison.b_id), (a.id, b.id));
+    assert!((comparison.return_delta - (0.002 - 0.003)).abs() < 1e-12);
+    assert_eq!(comparison.sharpe_delta, b_result.sharpe_ratio - a_result.sharpe_ratio);
+    assert_eq!(comparison.max_drawdown_delta, b_result.max_drawdown - a_result.max_drawdown);
+    assert_eq!(comparison.win_rate_delta, 0.5 - 0.75);
+    assert_eq!(comparison.trade_count_delta, 0);
+    assert_eq!(comparison.parameter_differences, HashMap::from([
+        ("parameters.commission_rate".to_string(), (json!(0.001), json!(0.002))),
+    ]));
+
+    // A run compared with itself differs in nothing
+    let same = BacktestComparison::new(&a, &a).unwrap();
+    assert_eq!(same.return_delta, 0.0);
+    assert!(same.parameter_differences.is_empty());
+}
+
+#[test]
+fn test_parameter_set_on_one_run_only_is_null_on_the_other() {
+    let a = completed_run(&spec(0.001), &[100.0, 101.0]);
+    let mut b_spec = spec(0.001);
+    b_spec.params.params.remove("commission_rate");
+    b_spec.initial_capital = 2000.0;
+    let b = completed_run(&b_spec, &[100.0, 101.0]);
+
+    let comparison = BacktestComparison::new(&a, &b).unwrap();
+    assert_eq!(comparison.parameter_differences, HashMap::from([
+        ("parameters.commission_rate".to_string(), (json!(0.001), serde_json::Value::Null)),
+        ("initial_capital".to_string(), (json!(1000.0), json!(2000.0))),
+    ]));
+}
+
+#[test]
+fn test_comparison_needs_both_results() {
+    let a = completed_run(&spec(0.001), &[100.0, 101.0]);
+    let running = BacktestRun::new(&spec(0.002));
+
+    assert_eq!(BacktestComparison::new(&a, &running), Err(running.id));
+    assert_eq!(BacktestComparison::new(&running, &a), Err(running.id));
+}
@@ -0,0 +1,6 @@
+// Backtest module tests
+pub mod mod_tests;
+pub mod walk_forward_tests;
+pub mod monte_carlo_tests;
+pub mod store_tests;
+pub mod comparison_tests;
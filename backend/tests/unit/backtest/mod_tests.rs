@@ -0,0 +1,60 @@
+use arb_platform::backtest::BacktestEngine;
+use arb_platform::strategy::StrategyParams;
+
+use crate::helpers::fixed_side_strategy::{daily_history, rising_prices, FixedSideStrategy};
+
+use serde_json::json;
+use std::collections::HashMap;
+
+fn params(pairs: &[(&str, serde_json::Value)]) -> StrategyParams {
+    StrategyParams {
+        params: pairs.iter().map(|(k, v)| (k.to_string(), v.clone())).collect(),
+    }
+}
+
+#[test]
+fn test_backtest_marks_positions_to_next_snapshot() {
+    let engine = BacktestEngine::new(daily_history(&[100.0, 101.0, 104.0, 105.0]), FixedSideStrategy::factory(), 1000.0);
+    assert_eq!(engine.periods(), 3);
+    
+    let result = engine.backtest(&params(&[("side", json!("buy"))]), 0, 3).unwrap();
+    assert_eq!(result.periods, 3);
+    assert_eq!(result.trades, 3);
+    assert!((result.final_capital - 1005.0).abs() < 1e-9);
+    assert!((result.total_return - 0.005).abs() < 1e-12);
+    assert!((result.returns[1] - 3.0 / 1001.0).abs() < 1e-12);
+    assert_eq!(result.max_drawdown, 0.0);
+    assert!(result.sharpe_ratio > 0.0);
+    assert_eq!(result.start, engine.period_start(0).unwrap());
+    assert_eq!(result.end, engine.period_start(3).unwrap());
+}
+
+#[test]
+fn test_losing_side_has_drawdown_and_negative_sharpe() {
+    let engine = BacktestEngine::new(daily_history(&rising_prices(11)), FixedSideStrategy::factory(), 1000.0);
+    
+    let result = engine.backtest(&params(&[("side", json!("sell")), ("quantity", json!(2.0))]), 0, 10).unwrap();
+    assert!(result.total_return < 0.0);
+    assert!(result.sharpe_ratio < 0.0);
+    assert!(result.max_drawdown > 0.0);
+}
+
+#[test]
+fn test_backtest_subrange() {
+    let engine = BacktestEngine::new(daily_history(&[100.0, 101.0, 104.0, 105.0]), FixedSideStrategy::factory(), 1000.0);
+    
+    let result = engine.backtest(&StrategyParams { params: HashMap::new() }, 1, 2).unwrap();
+    assert_eq!(result.periods, 1);
+    assert!((result.final_capital - 1003.0).abs() < 1e-9);
+    assert_eq!(result.sharpe_ratio, 0.0);
+}
+
+#[test]
+fn test_backtest_rejects_bad_range_and_params() {
+    let engine = BacktestEngine::new(daily_history(&[100.0, 101.0, 104.0]), FixedSideStrategy::factory(), 1000.0);
+    let empty = StrategyParams { params: HashMap::new() };
+    
+    assert!(engine.backtest(&empty, 0, 3).is_err());
+    assert!(engine.backtest(&empty, 1, 1).is_err());
+    assert!(engine.backtest(&params(&[("leverage", json!(3))]), 0, 2).is_err());
+}
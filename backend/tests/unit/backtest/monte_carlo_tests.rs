@@ -0,0 +1,61 @@
+use arb_platform::backtest::{BacktestEngine, BacktestResult};
+use arb_platform::strategy::StrategyParams;
+
+use crate::helpers::fixed_side_strategy::{daily_history, FixedSideStrategy};
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+
+// Long one unit over prices moving +1, +3, -2, +1: trades of 1, 3, -2 and 1 on 1000 of capital
+fn backtest_result() -> BacktestResult {
+    let engine = BacktestEngine::new(daily_history(&[100.0, 101.0, 104.0, 102.0, 103.0]), FixedSideStrategy::factory(), 1000.0);
+    engine.backtest(&StrategyParams { params: HashMap::new() }, 0, 4).unwrap()
+}
+
+#[test]
+fn test_backtest_records_trade_log() {
+    let result = backtest_result();
+    
+    let pnls: Vec<f64> = result.trade_log.iter().map(|trade| trade.pnl).collect();
+    assert_eq!(pnls, vec![1.0, 3.0, -2.0, 1.0]);
+    assert_eq!(result.trade_log[2].entry_price, 104.0);
+    assert_eq!(result.trade_log[2].exit_price, 102.0);
+    assert_eq!(result.trade_log[2].quantity, 1.0);
+}
+
+#[test]
+fn test_monte_carlo_distribution() {
+    let result = backtest_result();
+    let monte_carlo = result.monte_carlo_with_rng(2000, &mut StdRng::seed_from_u64(7));
+    
+    assert_eq!(monte_carlo.simulations.len(), 2000);
+    // Four draws from {1, 3, -2, 1} total between -8 and 12 on 1000 of capital
+    assert!(monte_carlo.simulations.iter().all(|total_return| (-0.008..=0.012).contains(total_return)));
+    assert!(monte_carlo.p5_total_return <= monte_carlo.p50_total_return);
+    assert!(monte_carlo.p50_total_return <= monte_carlo.p95_total_return);
+    assert!(monte_carlo.p5_total_return < result.total_return && result.total_return < monte_carlo.p95_total_return);
+    
+    // Only sequences with two or more -2 draws can lose, about 14% of them
+    let expected_losses = monte_carlo.simulations.iter().filter(|total_return| **total_return < 0.0).count();
+    assert_eq!(monte_carlo.probability_of_loss, expected_losses as f64 / 2000.0);
+    assert!(monte_carlo.probability_of_loss > 0.0 && monte_carlo.probability_of_loss < 0.2);
+    
+    // The same seed reproduces the run
+    assert_eq!(result.monte_carlo_with_rng(2000, &mut StdRng::seed_from_u64(7)), monte_carlo);
+}
+
+#[test]
+fn test_monte_carlo_without_trades() {
+    let mut result = backtest_result();
+    result.trade_log.clear();
+    
+    let monte_carlo = result.monte_carlo(10);
+    assert_eq!(monte_carlo.simulations, vec![0.0; 10]);
+    assert_eq!(monte_carlo.p95_total_return, 0.0);
+    assert_eq!(monte_carlo.probability_of_loss, 0.0);
+    
+    let monte_carlo = backtest_result().monte_carlo(0);
+    assert!(monte_carlo.simulations.is_empty());
+    assert_eq!(monte_carlo.p50_total_return, 0.0);
+}
@@ -0,0 +1,86 @@
+use arb_platform::backtest::{BacktestEngine, BacktestRun, BacktestRunner, BacktestSpec, BacktestStatus, BacktestStore};
+use arb_platform::strategy::StrategyParams;
+
+use crate::helpers::fixed_side_strategy::{daily_history, FixedSideStrategy};
+
+use chrono::{TimeZone, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const PRICES: [f64; 4] = [100.0, 101.0, 104.0, 105.0];
+
+fn spec(strategy: &str) -> BacktestSpec {
+    BacktestSpec {
+        strategy: strategy.to_string(),
+        symbols: vec!["BTC/USD".to_string()],
+        start: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2024, 1, 4, 0, 0, 0).unwrap(),
+        initial_capital: 1000.0,
+        params: StrategyParams { params: HashMap::new() },
+    }
+}
+
+fn runner() -> BacktestRunner {
+    BacktestRunner::new()
+        .with_strategy("Fixed Side", FixedSideStrategy::factory())
+        .with_history_loader(Box::new(|_, _, _| Ok(daily_history(&PRICES))))
+}
+
+#[test]
+fn test_runner_matches_engine() {
+    let result = runner().run(&spec("Fixed Side")).unwrap();
+    let engine = BacktestEngine::new(daily_history(&PRICES), FixedSideStrategy::factory(), 1000.0);
+    assert_eq!(result, engine.backtest(&StrategyParams { params: HashMap::new() }, 0, 3).unwrap());
+    assert!((result.final_capital - 1005.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_runner_errors() {
+    assert!(runner().run(&spec("Nonexistent")).unwrap_err().contains("Unknown strategy"));
+
+    let no_history = BacktestRunner::new().with_strategy("Fixed Side", FixedSideStrategy::factory());
+    assert!(no_history.has_strategy("Fixed Side"));
+    assert!(no_history.run(&spec("Fixed Side")).is_err());
+
+    let mut reversed = spec("Fixed Side");
+    std::mem::swap(&mut reversed.start, &mut reversed.end);
+    assert!(runner().run(&reversed).is_err());
+}
+
+#[tokio::test]
+async fn test_store_keeps_runs_until_completed() {
+    let store = BacktestStore::new();
+    let run = BacktestRun::new(&spec("Fixed Side"));
+    let id = run.id;
+    store.insert(run).await;
+    assert_eq!(store.get(id).await.unwrap().status, BacktestStatus::Running);
+    assert!(store.result(id).await.is_none());
+
+    let result = runner().run(&spec("Fixed Side")).unwrap();
+    assert!(store.complete(id, Ok(result.clone())).await);
+    let run = store.get(id).await.unwrap();
+    assert_eq!(run.status, BacktestStatus::Completed);
+    assert!(run.completed_at.is_some());
+    assert_eq!(store.result(id).await, Some(result.clone()));
+
+    let stored_id = store.insert_result(&spec("Fixed Side"), result.clone()).await;
+    assert_ne!(stored_id, id);
+    assert_eq!(store.result(stored_id).await, Some(result));
+    assert_eq!(store.len().await, 2);
+}
+
+#[tokio::test]
+async fn test_store_records_failures() {
+    let store = BacktestStore::new();
+    assert!(store.is_empty().await);
+    assert!(!store.complete(Uuid::new_v4(), Err("lost".to_string())).await);
+
+    let run = BacktestRun::new(&spec("Fixed Side"));
+    let id = run.id;
+    store.insert(run).await;
+    assert!(store.complete(id, Err("Archive offline".to_string())).await);
+    let run = store.get(id).await.unwrap();
+    assert_eq!(run.status, BacktestStatus::Failed);
+    assert_eq!(run.error.as_deref(), Some("Archive offline"));
+    assert!(store.result(id).await.is_none());
+}
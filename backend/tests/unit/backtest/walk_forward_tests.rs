@@ -0,0 +1,76 @@
+use arb_platform::backtest::{BacktestEngine, WalkForwardConfig};
+
+use crate::helpers::fixed_side_strategy::{daily_history, rising_prices, FixedSideStrategy};
+
+use chrono::Duration;
+use serde_json::json;
+use std::collections::HashMap;
+
+fn config(train: usize, test: usize, step: usize, grid: &[(&str, Vec<serde_json::Value>)]) -> WalkForwardConfig {
+    WalkForwardConfig {
+        train_periods: train,
+        test_periods: test,
+        step_periods: step,
+        param_grid: grid.iter().map(|(k, v)| (k.to_string(), v.clone())).collect(),
+    }
+}
+
+#[test]
+fn test_walk_forward_period_count() {
+    // 21 snapshots give 20 periods; windows of 8 + 4 stepping by 4 start at 0, 4 and 8
+    let engine = BacktestEngine::new(daily_history(&rising_prices(21)), FixedSideStrategy::factory(), 10000.0);
+    let grid = [("side", vec![json!("sell"), json!("buy")])];
+    
+    assert_eq!(engine.walk_forward(config(8, 4, 4, &grid)).len(), 3);
+    assert_eq!(engine.walk_forward(config(8, 4, 1, &grid)).len(), 9);
+    assert_eq!(engine.walk_forward(config(10, 10, 5, &grid)).len(), 1);
+    assert!(engine.walk_forward(config(15, 6, 1, &grid)).is_empty());
+}
+
+#[test]
+fn test_walk_forward_selects_best_params_per_window() {
+    let engine = BacktestEngine::new(daily_history(&rising_prices(21)), FixedSideStrategy::factory(), 10000.0);
+    let grid = [("side", vec![json!("sell"), json!("buy")])];
+    
+    let periods = engine.walk_forward(config(8, 4, 4, &grid));
+    for (i, period) in periods.iter().enumerate() {
+        let start = engine.period_start(i * 4).unwrap();
+        assert_eq!(period.train_start, start);
+        assert_eq!(period.train_end, start + Duration::days(8));
+        assert_eq!(period.test_start, period.train_end);
+        assert_eq!(period.test_end, start + Duration::days(12));
+        
+        // Prices only rise, so holding long wins every training window
+        assert_eq!(period.best_params.params.get("side"), Some(&json!("buy")));
+        assert!(period.test_return > 0.0);
+        assert!(period.test_sharpe > 0.0);
+    }
+}
+
+#[test]
+fn test_walk_forward_skips_rejected_params() {
+    let engine = BacktestEngine::new(daily_history(&rising_prices(13)), FixedSideStrategy::factory(), 10000.0);
+    let grid = [("quantity", vec![json!(-1.0), json!(2.0)])];
+    
+    let periods = engine.walk_forward(config(8, 4, 4, &grid));
+    assert_eq!(periods.len(), 1);
+    assert_eq!(periods[0].best_params.params.get("quantity"), Some(&json!(2.0)));
+}
+
+#[test]
+fn test_walk_forward_rejects_empty_windows() {
+    let engine = BacktestEngine::new(daily_history(&rising_prices(21)), FixedSideStrategy::factory(), 10000.0);
+    
+    assert!(engine.walk_forward(config(8, 4, 0, &[])).is_empty());
+    assert!(engine.walk_forward(config(0, 4, 4, &[])).is_empty());
+    
+    // An empty grid runs the strategy's defaults
+    let periods = engine.walk_forward(WalkForwardConfig {
+        train_periods: 8,
+        test_periods: 4,
+        step_periods: 4,
+        param_grid: HashMap::new(),
+    });
+    assert_eq!(periods.len(), 3);
+    assert!(periods[0].best_params.params.is_empty());
+}
@@ -0,0 +1,2 @@
+// Channel module tests
+pub mod mod_tests;
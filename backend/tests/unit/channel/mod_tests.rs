@@ -0,0 +1,129 @@
+use arb_platform::channel::{event_channel, BackpressurePolicy, ChannelConfig};
+use arb_platform::market_data::{MarketDataManager, MarketEvent};
+use arb_platform::order::OrderManager;
+
+use chrono::Utc;
+use std::time::Duration;
+
+fn drain(receiver: &mut arb_platform::channel::EventReceiver<u32>) -> Vec<u32> {
+    let mut events = Vec::new();
+    while let Some(event) = receiver.try_recv() {
+        events.push(event);
+    }
+    events
+}
+
+#[tokio::test]
+async fn test_block_waits_for_room() {
+    let (sender, mut receiver) = event_channel(ChannelConfig::new(2, BackpressurePolicy::Block));
+    sender.send(1).await.unwrap();
+    sender.send(2).await.unwrap();
+    
+    // A third send waits until the receiver takes an event
+    let blocked = tokio::time::timeout(Duration::from_millis(50), sender.send(3)).await;
+    assert!(blocked.is_err());
+    
+    let producer = tokio::spawn({
+        let sender = sender.clone();
+        async move { sender.send(3).await }
+    });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!producer.is_finished());
+    
+    assert_eq!(receiver.recv().await, Some(1));
+    producer.await.unwrap().unwrap();
+    assert_eq!(drain(&mut receiver), vec![2, 3]);
+    assert_eq!(sender.stats().dropped_events, 0);
+}
+
+#[tokio::test]
+async fn test_drop_oldest_keeps_the_latest_events() {
+    let (sender, mut receiver) = event_channel(ChannelConfig::new(3, BackpressurePolicy::DropOldest));
+    for event in 1..=10 {
+        sender.send(event).await.unwrap();
+    }
+    
+    let stats = sender.stats();
+    assert_eq!(stats.queued, 3);
+    assert_eq!(stats.dropped_events, 7);
+    assert_eq!(drain(&mut receiver), vec![8, 9, 10]);
+}
+
+#[tokio::test]
+async fn test_drop_newest_keeps_the_earliest_events() {
+    let (sender, mut receiver) = event_channel(ChannelConfig::new(3, BackpressurePolicy::DropNewestWithCounter));
+    for event in 1..=10 {
+        sender.send(event).await.unwrap();
+    }
+    
+    assert_eq!(sender.stats().dropped_events, 7);
+    assert_eq!(drain(&mut receiver), vec![1, 2, 3]);
+    
+    // Room frees up once the receiver catches up
+    sender.send(11).await.unwrap();
+    assert_eq!(drain(&mut receiver), vec![11]);
+    assert_eq!(sender.stats().dropped_events, 7);
+}
+
+#[tokio::test]
+async fn test_channel_closes_with_its_ends() {
+    let (sender, receiver) = event_channel::<u32>(ChannelConfig::new(1, BackpressurePolicy::Block));
+    sender.send(1).await.unwrap();
+    
+    // A blocked sender is released when the receiver goes away
+    let blocked = tokio::spawn({
+        let sender = sender.clone();
+        async move { sender.send(2).await }
+    });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    drop(receiver);
+    assert!(blocked.await.unwrap().is_err());
+    
+    let (sender, mut receiver) = event_channel(ChannelConfig::new(4, BackpressurePolicy::Block));
+    sender.send(1).await.unwrap();
+    drop(sender);
+    assert_eq!(receiver.recv().await, Some(1));
+    assert_eq!(receiver.recv().await, None);
+}
+
+#[test]
+fn test_policy_names_parse() {
+    assert_eq!(BackpressurePolicy::parse("block"), Ok(BackpressurePolicy::Block));
+    assert_eq!(BackpressurePolicy::parse("Drop_Oldest"), Ok(BackpressurePolicy::DropOldest));
+    assert_eq!(BackpressurePolicy::parse("drop_newest"), Ok(BackpressurePolicy::DropNewestWithCounter));
+    assert!(BackpressurePolicy::parse("spill").is_err());
+}
+
+#[tokio::test]
+async fn test_market_data_flood_drops_without_blocking() {
+    // Nothing is processing the manager's events, so the channel fills up
+    let manager = MarketDataManager::with_channel_config(ChannelConfig::new(5, BackpressurePolicy::DropOldest));
+    let sender = manager.get_event_sender();
+    
+    let flood = async {
+        for i in 0..100 {
+            sender.send(MarketEvent::PriceUpdate {
+                symbol: "BTC/USD".to_string(),
+                price: 50000.0 + i as f64,
+                volume: None,
+                bid: None,
+                ask: None,
+                timestamp: Utc::now(),
+                exchange: "Test".to_string(),
+            }).await.unwrap();
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(1), flood).await.unwrap();
+    
+    let stats = manager.event_channel_stats();
+    assert_eq!(stats.queued, 5);
+    assert_eq!(stats.dropped_events, 95);
+}
+
+#[tokio::test]
+async fn test_order_events_always_block() {
+    let manager = OrderManager::with_event_capacity(3);
+    let stats = manager.event_channel_stats();
+    assert_eq!(stats.capacity, 3);
+    assert_eq!(stats.policy, BackpressurePolicy::Block);
+}
@@ -0,0 +1,2 @@
+// Clock module tests
+pub mod mod_tests;
@@ -0,0 +1,24 @@
+use arb_platform::clock::{Clock, MockClock, SystemClock};
+
+use chrono::{Duration, TimeZone, Utc};
+
+#[test]
+fn test_mock_clock_moves_only_when_told() {
+    let start = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let clock = MockClock::new(start);
+    assert_eq!(clock.now(), start);
+    assert_eq!(clock.now(), start);
+
+    clock.advance(Duration::seconds(90));
+    assert_eq!(clock.now(), start + Duration::seconds(90));
+
+    clock.set(start);
+    assert_eq!(clock.now(), start);
+}
+
+#[test]
+fn test_system_clock_follows_wall_time() {
+    let before = Utc::now();
+    let now = SystemClock.now();
+    assert!(now >= before && now <= Utc::now());
+}
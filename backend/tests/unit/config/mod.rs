@@ -0,0 +1,2 @@
+// Config module tests
+pub mod mod_tests;
@@ -0,0 +1,127 @@
+use arb_platform::config::{ConfigError, TradingConfig};
+use arb_platform::exchange::{ExchangeConfig, ExchangeType};
+
+use serde_json::json;
+use std::sync::{Mutex, MutexGuard};
+
+// Tests share the process environment, so each holds this lock while its variables are set
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+/// Environment variables set for the life of the guard, and removed when it drops
+struct EnvGuard {
+    names: Vec<String>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl EnvGuard {
+    fn set(vars: &[(&str, &str)]) -> Self {
+        let lock = ENV_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        for (name, value) in vars {
+            std::env::set_var(name, value);
+        }
+        EnvGuard { names: vars.iter().map(|(name, _)| name.to_string()).collect(), _lock: lock }
+    }
+}
+
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        for name in &self.names {
+            std::env::remove_var(name);
+        }
+    }
+}
+
+#[test]
+fn test_exchange_config_from_env() {
+    let _env = EnvGuard::set(&[
+        ("TEST_VENUE_NAME", "Kraken"),
+        ("TEST_VENUE_API_URL", "https://api.kraken.com"),
+        ("TEST_VENUE_EXCHANGE_TYPE", "crypto"),
+        ("TEST_VENUE_API_KEY", "key"),
+        ("TEST_VENUE_API_SECRET", "secret"),
+        ("TEST_VENUE_PARAM_FILL_SCHEDULE", "instant"),
+        ("TEST_VENUE_PARAM_SIMULATION_SEED", "7"),
+    ]);
+    
+    let config = ExchangeConfig::from_env("TEST_VENUE").unwrap();
+    assert_eq!(config.name, "Kraken");
+    assert_eq!(config.api_url, "https://api.kraken.com");
+    assert_eq!(config.exchange_type, ExchangeType::Crypto);
+    assert_eq!(config.api_key.as_deref(), Some("key"));
+    assert_eq!(config.api_secret.as_deref(), Some("secret"));
+    assert_eq!(config.additional_params.len(), 2);
+    assert_eq!(config.additional_params["fill_schedule"], "instant");
+    assert_eq!(config.additional_params["simulation_seed"], "7");
+}
+
+#[test]
+fn test_exchange_config_from_env_requires_name_url_and_type() {
+    let _env = EnvGuard::set(&[
+        ("PARTIAL_VENUE_NAME", "Kraken"),
+        ("PARTIAL_VENUE_EXCHANGE_TYPE", "crypto"),
+        ("BAD_TYPE_VENUE_NAME", "Kraken"),
+        ("BAD_TYPE_VENUE_API_URL", "https://api.kraken.com"),
+        ("BAD_TYPE_VENUE_EXCHANGE_TYPE", "spaceship"),
+    ]);
+    
+    assert_eq!(ExchangeConfig::from_env("PARTIAL_VENUE").unwrap_err(), ConfigError::MissingVar("PARTIAL_VENUE_API_URL".to_string()));
+    let error = ExchangeConfig::from_env("BAD_TYPE_VENUE").unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidVar { name, .. } if name == "BAD_TYPE_VENUE_EXCHANGE_TYPE"), "{}", error);
+}
+
+#[test]
+fn test_environment_overrides_the_config_file() {
+    let path = std::env::temp_dir().join(format!("exchanges-{}.json", uuid::Uuid::new_v4()));
+    std::fs::write(&path, json!([{
+        "name": "Binance",
+        "exchange_type": "Crypto",
+        "api_url": "https://api.binance.com",
+        "api_key": "file-key",
+        "api_secret": "file-secret",
+        "additional_params": {"fill_schedule": "never", "simulation_seed": "1"}
+    }]).to_string()).unwrap();
+    
+    let _env = EnvGuard::set(&[
+        ("EXCHANGE_1_NAME", "Binance"),
+        ("EXCHANGE_1_API_KEY", "env-key"),
+        ("EXCHANGE_1_PARAM_FILL_SCHEDULE", "instant"),
+        ("EXCHANGE_3_NAME", "Coinbase"),
+        ("EXCHANGE_3_API_URL", "https://api.coinbase.com"),
+        ("EXCHANGE_3_EXCHANGE_TYPE", "Crypto"),
+    ]);
+    let config = TradingConfig::load(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+    let config = config.unwrap();
+    
+    let names: Vec<&str> = config.exchanges.iter().map(|exchange| exchange.name.as_str()).collect();
+    assert_eq!(names, ["Binance", "Coinbase"]);
+    let binance = &config.exchanges[0];
+    assert_eq!(binance.api_key.as_deref(), Some("env-key"));
+    assert_eq!(binance.api_secret.as_deref(), Some("file-secret"));
+    assert_eq!(binance.api_url, "https://api.binance.com");
+    assert_eq!(binance.additional_params["fill_schedule"], "instant");
+    assert_eq!(binance.additional_params["simulation_seed"], "1");
+    
+    // Only Coinbase is fully configured by its variables
+    assert!(TradingConfig::from_env().is_err());
+}
+
+#[test]
+fn test_trading_config_from_env_scans_numbered_exchanges() {
+    let _env = EnvGuard::set(&[
+        ("EXCHANGE_2_NAME", "Second"),
+        ("EXCHANGE_2_API_URL", "https://second.example.com"),
+        ("EXCHANGE_2_EXCHANGE_TYPE", "crypto"),
+        ("EXCHANGE_10_NAME", "Tenth"),
+        ("EXCHANGE_10_API_URL", "https://tenth.example.com"),
+        ("EXCHANGE_10_EXCHANGE_TYPE", "stock"),
+    ]);
+    
+    let config = TradingConfig::from_env().unwrap();
+    let names: Vec<&str> = config.exchanges.iter().map(|exchange| exchange.name.as_str()).collect();
+    assert_eq!(names, ["Second", "Tenth"]);
+    assert_eq!(config.exchanges[1].exchange_type, ExchangeType::Stock);
+    
+    let missing = TradingConfig::load("/nonexistent/exchanges.json").unwrap();
+    assert_eq!(missing.exchanges.len(), 2);
+}
@@ -0,0 +1,2 @@
+// Error module tests
+pub mod mod_tests;
@@ -0,0 +1,25 @@
+use arb_platform::error::TradingError;
+use arb_platform::exchange::{rejection_error, rejection_reason};
+
+#[test]
+fn test_display_shows_the_message() {
+    assert_eq!(TradingError::NotConnected("Binance".to_string()).to_string(), "Exchange Binance is not connected");
+    assert_eq!(TradingError::Rejected("insufficient margin".to_string()).to_string(), "Rejected: insufficient margin");
+    assert_eq!(TradingError::NotFound("Order 42 not found".to_string()).to_string(), "Order 42 not found");
+    assert_eq!(TradingError::Unavailable("No healthy connections".to_string()).to_string(), "No healthy connections");
+}
+
+#[test]
+fn test_is_a_std_error() {
+    let error: Box<dyn std::error::Error> = Box::new(TradingError::Validation("Order quantity must be positive".to_string()));
+    assert_eq!(error.to_string(), "Order quantity must be positive");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_only_rejections_carry_a_rejection_reason() {
+    let error = rejection_error("price outside band");
+    assert_eq!(error, TradingError::Rejected("price outside band".to_string()));
+    assert_eq!(rejection_reason(&error), Some("price outside band"));
+    assert_eq!(rejection_reason(&TradingError::Exchange("Rejected: timed out".to_string())), None);
+}
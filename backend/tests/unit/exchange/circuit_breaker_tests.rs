@@ -0,0 +1,90 @@
+use arb_platform::clock::MockClock;
+use arb_platform::exchange::circuit_breaker::{CircuitBreakerConfig, CircuitState, ExchangeCircuitBreaker};
+
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn config(open_duration: Duration) -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+        failure_threshold: 5,
+        success_threshold: 2,
+        open_duration,
+    }
+}
+
+// A breaker opened by five failures at a mock time, with the clock to move it on
+fn opened_breaker() -> (ExchangeCircuitBreaker, Arc<MockClock>) {
+    let clock = Arc::new(MockClock::new(Utc::now()));
+    let mut breaker = ExchangeCircuitBreaker::with_clock(config(Duration::from_secs(30)), clock.clone());
+    for _ in 0..5 {
+        breaker.record_failure();
+    }
+    (breaker, clock)
+}
+
+#[test]
+fn test_breaker_opens_after_consecutive_failures() {
+    let mut breaker = ExchangeCircuitBreaker::new(config(Duration::from_secs(60)));
+    for _ in 0..4 {
+        breaker.record_failure();
+    }
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    // A success starts the count over
+    breaker.record_success();
+    for _ in 0..4 {
+        breaker.record_failure();
+    }
+    assert!(breaker.allow_request());
+
+    breaker.record_failure();
+    assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+    assert!(!breaker.allow_request());
+    assert_eq!(breaker.consecutive_failures(), 5);
+}
+
+#[test]
+fn test_half_open_breaker_admits_one_probe_at_a_time() {
+    let (mut breaker, clock) = opened_breaker();
+    clock.advance(chrono::Duration::seconds(29));
+    assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+    clock.advance(chrono::Duration::seconds(1));
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+    assert!(breaker.allow_request());
+    assert!(!breaker.allow_request());
+    breaker.record_success();
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+    // The second successful probe closes it
+    assert!(breaker.allow_request());
+    breaker.record_success();
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    assert!(breaker.allow_request());
+}
+
+#[test]
+fn test_failed_probe_reopens_the_breaker() {
+    let (mut breaker, clock) = opened_breaker();
+    clock.advance(chrono::Duration::seconds(30));
+
+    assert!(breaker.allow_request());
+    breaker.record_failure();
+    assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+    assert!(!breaker.allow_request());
+
+    // Open for the full duration again, from the failed probe
+    clock.advance(chrono::Duration::seconds(29));
+    assert!(!breaker.allow_request());
+    clock.advance(chrono::Duration::seconds(1));
+    assert!(breaker.allow_request());
+}
+
+#[test]
+fn test_thresholds_are_at_least_one() {
+    let breaker = ExchangeCircuitBreaker::new(CircuitBreakerConfig { failure_threshold: 0, success_threshold: 0, open_duration: Duration::ZERO });
+    assert_eq!(breaker.config().failure_threshold, 1);
+    assert_eq!(breaker.config().success_threshold, 1);
+    assert_eq!(ExchangeCircuitBreaker::default().config(), &CircuitBreakerConfig::default());
+}
@@ -4,6 +4,7 @@ use arb_platform::exchange::{
 use arb_platform::exchange::crypto::CryptoExchange;
 use arb_platform::order::{Order, OrderType, OrderStatus as OrderOrderStatus};
 use arb_platform::strategy::{TradeDirection, TimeInForce};
+use arb_platform::models::Price;
 
 use chrono::Utc;
 use std::collections::HashMap;
@@ -31,7 +32,7 @@ fn create_test_order() -> Order {
         order_type: OrderType::Limit,
         quantity: 1.0,
         filled_quantity: 0.0,
-        price: Some(35000.0),
+        price: Some(Price::from(35000.0)),
         stop_price: None,
         time_in_force: TimeInForce::GoodTilCancelled,
         status: OrderOrderStatus::Created,
@@ -40,8 +41,12 @@ fn create_test_order() -> Order {
         updated_at: Utc::now(),
         filled_at: None,
         average_fill_price: None,
+        unfilled_quantity: None,
         strategy_id: Some("test_strategy".to_string()),
         notes: None,
+        tags: Vec::new(),
+        post_only: false,
+        amendment_history: Vec::new(),
     }
 }
 
@@ -1,14 +1,24 @@
+use arb_platform::clock::MockClock;
+use arb_platform::error::TradingError;
 use arb_platform::exchange::{
-    ExchangeType, ExchangeConfig, Exchange
+    ExchangeType, ExchangeConfig, ExchangeFactory, Exchange, FeeSchedule, TransactionType, rejection_reason
 };
-use arb_platform::exchange::crypto::CryptoExchange;
-use arb_platform::order::{Order, OrderType, OrderStatus as OrderOrderStatus};
-use arb_platform::strategy::{TradeDirection, TimeInForce};
+use arb_platform::exchange::crypto::{ConnectionConfig, CryptoExchange, SimulationSettings};
+use arb_platform::exchange::fill_schedule::FillSchedule;
+use arb_platform::exchange::OrderStatus as ExchangeOrderStatus;
+use arb_platform::order::{Order, OrderType};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
 
-use chrono::Utc;
+use chrono::{Duration, TimeZone, Utc};
 use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
 use uuid::Uuid;
 
+use crate::helpers::orders::test_order;
+
 fn create_test_config() -> ExchangeConfig {
     ExchangeConfig {
         name: "Test Crypto Exchange".to_string(),
@@ -22,24 +32,11 @@ fn create_test_config() -> ExchangeConfig {
 
 fn create_test_order() -> Order {
     Order {
-        id: Uuid::new_v4(),
         client_order_id: "test_client_id".to_string(),
-        symbol: "BTC/USD".to_string(),
-        direction: TradeDirection::Buy,
-        order_type: OrderType::Limit,
-        quantity: 1.0,
-        filled_quantity: 0.0,
-        price: Some(35000.0),
-        stop_price: None,
-        time_in_force: TimeInForce::GoodTilCancelled,
-        status: OrderOrderStatus::Created,
+        price: Some(Price::from(35000.0)),
         exchange: "Test Crypto Exchange".to_string(),
-        created_at: Utc::now(),
-        updated_at: Utc::now(),
-        filled_at: None,
-        average_fill_price: None,
         strategy_id: Some("test_strategy".to_string()),
-        notes: None,
+        ..test_order()
     }
 }
 
@@ -161,9 +158,9 @@ async fn test_get_market_data_when_connected() {
     
     let snapshot = result.unwrap();
     assert_eq!(snapshot.symbol, "BTC/USD");
-    assert!(snapshot.price > 0.0);
-    assert!(snapshot.bid > 0.0);
-    assert!(snapshot.ask > 0.0);
+    assert!(snapshot.price > Price::ZERO);
+    assert!(snapshot.bid > Price::ZERO);
+    assert!(snapshot.ask > Price::ZERO);
     assert!(snapshot.volume > 0.0);
 }
 
@@ -226,9 +223,10 @@ async fn test_submit_and_cancel_order() {
     let submit_result = exchange.submit_order(order.clone()).await;
     assert!(submit_result.is_ok());
     
-    // Cancel the order
-    let cancel_result = exchange.cancel_order(order.id).await;
-    assert!(cancel_result.is_ok());
+    // Cancel the order; nothing has filled yet
+    let cancellation = exchange.cancel_order(order.id).await.unwrap();
+    assert!(cancellation.exchange_order_id.unwrap().starts_with("EX-"));
+    assert_eq!(cancellation.unfilled_quantity, order.quantity);
 }
 
 #[tokio::test]
@@ -276,6 +274,27 @@ async fn test_submit_and_get_order_status() {
     assert!(status_response.exchange_order_id.is_some());
 }
 
+#[tokio::test]
+async fn test_amend_order_lowers_resting_quantity() {
+    let mut exchange = CryptoExchange::new(create_test_config());
+    let order = create_test_order();
+    exchange.connect().await.unwrap();
+    exchange.submit_order(order.clone()).await.unwrap();
+    
+    exchange.amend_order(order.id, 0.4).await.unwrap();
+    let status = exchange.get_order_status(order.id).await.unwrap();
+    assert_eq!(status.filled_quantity + status.remaining_quantity, 0.4);
+    assert!(exchange.fillable_quantity(order.id).unwrap() <= 0.4);
+    
+    // Only decreases
+    let error = exchange.amend_order(order.id, 0.4).await.unwrap_err();
+    assert!(rejection_reason(&error).is_some());
+    assert!(exchange.amend_order(Uuid::new_v4(), 0.1).await.is_err());
+    
+    exchange.cancel_order(order.id).await.unwrap();
+    assert!(exchange.amend_order(order.id, 0.2).await.is_err());
+}
+
 #[tokio::test]
 async fn test_get_account_balance_when_not_connected() {
     let config = create_test_config();
@@ -323,6 +342,33 @@ async fn test_get_positions_when_connected() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_get_margin_info() {
+    let mut exchange = CryptoExchange::new(create_test_config());
+    assert!(exchange.get_margin_info("BTC/USD").await.is_err());
+    exchange.connect().await.unwrap();
+    
+    let margin = exchange.get_margin_info("BTC/USD").await.unwrap().unwrap();
+    assert_eq!(margin.available_margin, 75000.0);
+    assert_eq!(margin.used_margin, 25000.0);
+    assert_eq!(margin.margin_level, 400.0);
+    assert_eq!(margin.max_leverage, 5.0);
+    // Long 1.5 BTC from 34500, liquidated after a fifth of that is lost
+    assert_eq!(margin.liquidation_price, Some(27600.0));
+    
+    // No position held
+    let margin = exchange.get_margin_info("XRP/USD").await.unwrap().unwrap();
+    assert_eq!(margin.liquidation_price, None);
+}
+
+#[tokio::test]
+async fn test_max_leverage_from_params() {
+    let leveraged = CryptoExchange::new(create_simulated_config(&[("max_leverage", "10")]));
+    assert_eq!(leveraged.simulation_settings().max_leverage, 10.0);
+    let unleveraged = CryptoExchange::new(create_simulated_config(&[("max_leverage", "0.5")]));
+    assert_eq!(unleveraged.simulation_settings().max_leverage, 1.0); // Clamped
+}
+
 #[tokio::test]
 async fn test_multiple_market_data_requests() {
     let config = create_test_config();
@@ -340,7 +386,7 @@ async fn test_multiple_market_data_requests() {
         
         let snapshot = result.unwrap();
         assert_eq!(snapshot.symbol, symbol);
-        assert!(snapshot.price > 0.0);
+        assert!(snapshot.price > Price::ZERO);
     }
 }
 
@@ -363,4 +409,452 @@ async fn test_reconnect_after_disconnect() {
     let connect_result2 = exchange.connect().await;
     assert!(connect_result2.is_ok());
     assert!(exchange.is_connected());
-} 
\ No newline at end of file
+} 
+fn create_simulated_config(params: &[(&str, &str)]) -> ExchangeConfig {
+    let mut config = create_test_config();
+    for (key, value) in params {
+        config.additional_params.insert(key.to_string(), value.to_string());
+    }
+    config
+}
+
+#[tokio::test]
+async fn test_simulation_settings_from_params() {
+    let config = create_simulated_config(&[
+        ("simulated_latency_ms", "5"),
+        ("reject_probability", "0.25"),
+        ("fail_probability", "2.0"),
+        ("simulation_seed", "not-a-number"),
+    ]);
+    let exchange = CryptoExchange::new(config);
+    let settings = exchange.simulation_settings();
+    
+    assert_eq!(settings.submit_latency, std::time::Duration::from_millis(5));
+    assert_eq!(settings.reject_probability, 0.25);
+    assert_eq!(settings.fail_probability, 1.0); // Clamped
+    assert_eq!(settings.seed, None); // Invalid values are ignored
+    
+    let defaults = CryptoExchange::new(create_test_config());
+    assert_eq!(*defaults.simulation_settings(), SimulationSettings::default());
+}
+
+#[tokio::test]
+async fn test_simulated_rejection() {
+    let config = create_simulated_config(&[("simulated_latency_ms", "0"), ("reject_probability", "1.0")]);
+    let mut exchange = CryptoExchange::new(config);
+    exchange.connect().await.unwrap();
+    
+    let order = create_test_order();
+    let order_id = order.id;
+    let error = exchange.submit_order(order).await.unwrap_err();
+    
+    assert_eq!(rejection_reason(&error), Some("Simulated rejection by Test Crypto Exchange"));
+    assert!(exchange.get_order_status(order_id).await.is_err());
+}
+
+#[tokio::test]
+async fn test_simulated_failure_is_not_a_rejection() {
+    let config = create_simulated_config(&[("simulated_latency_ms", "0"), ("fail_probability", "1.0")]);
+    let mut exchange = CryptoExchange::new(config);
+    exchange.connect().await.unwrap();
+    
+    let error = exchange.submit_order(create_test_order()).await.unwrap_err();
+    assert!(rejection_reason(&error).is_none());
+    assert!(matches!(error, TradingError::Exchange(_)));
+    assert!(error.to_string().contains("Simulated failure"));
+}
+
+#[tokio::test]
+async fn test_seeded_simulation_is_deterministic() {
+    async fn outcomes(seed: &str) -> Vec<Option<TradingError>> {
+        let config = create_simulated_config(&[
+            ("simulated_latency_ms", "0"),
+            ("reject_probability", "0.3"),
+            ("fail_probability", "0.3"),
+            ("simulation_seed", seed),
+        ]);
+        let mut exchange = CryptoExchange::new(config);
+        exchange.connect().await.unwrap();
+        
+        let mut results = Vec::new();
+        for _ in 0..20 {
+            results.push(exchange.submit_order(create_test_order()).await.err());
+        }
+        results
+    }
+    
+    let first = outcomes("42").await;
+    assert_eq!(first, outcomes("42").await);
+    assert!(first.iter().any(|r| r.is_none()));
+    assert!(first.iter().any(|r| r.is_some()));
+}
+
+#[tokio::test]
+async fn test_fill_model_sets_fill_price_on_submission() {
+    let config = create_simulated_config(&[
+        ("simulated_latency_ms", "0"),
+        ("fill_model", "constant_slippage"),
+        ("slippage_bps", "1000"),
+    ]);
+    let mut exchange = CryptoExchange::new(config);
+    exchange.connect().await.unwrap();
+    
+    let mut order = create_test_order();
+    order.order_type = OrderType::Market;
+    order.price = None;
+    let order_id = order.id;
+    exchange.submit_order(order).await.unwrap();
+    
+    // Simulated tickers trade between 35000 and 36000, so 10% slippage lands above that
+    let fill_price = exchange.fill_price(order_id).unwrap();
+    assert!((35000.0 * 1.1..=36000.0 * 1.1).contains(&fill_price));
+    assert_eq!(exchange.commission(order_id), Some(0.0)); // Nothing filled yet
+}
+
+#[tokio::test]
+async fn test_book_depth_settings_from_params() {
+    let config = create_simulated_config(&[("book_depth_levels", "3"), ("book_level_spacing_bps", "20")]);
+    let mut exchange = CryptoExchange::new(config);
+    assert_eq!(exchange.simulation_settings().book_depth_levels, 3);
+    assert_eq!(exchange.simulation_settings().book_level_spacing_bps, 20.0);
+    
+    exchange.connect().await.unwrap();
+    let snapshot = exchange.get_market_data("BTC/USD").await.unwrap();
+    assert_eq!(snapshot.asks.len(), 3);
+    assert_eq!(snapshot.bids.len(), 3);
+    assert_eq!(Price::from(snapshot.asks[0].price), snapshot.ask);
+    assert_eq!(Price::from(snapshot.bids[0].price), snapshot.bid);
+    
+    // Levels move away from the top, 20 bps apart
+    assert!((snapshot.asks[2].price - snapshot.ask.to_f64() * 1.004).abs() < 1e-6);
+    assert!((snapshot.bids[2].price - snapshot.bid.to_f64() * 0.996).abs() < 1e-6);
+}
+
+// Exchanges with the same seed quote the same prices, so orders on separate
+// instances see the same book
+async fn market_order_fill(direction: TradeDirection, quantity: f64) -> (f64, f64) {
+    let config = create_simulated_config(&[("simulated_latency_ms", "0"), ("simulation_seed", "7")]);
+    let mut exchange = CryptoExchange::new(config);
+    exchange.connect().await.unwrap();
+    
+    let mut order = create_test_order();
+    order.direction = direction;
+    order.order_type = OrderType::Market;
+    order.price = None;
+    order.quantity = quantity;
+    let order_id = order.id;
+    exchange.submit_order(order).await.unwrap();
+    (exchange.fill_price(order_id).unwrap(), exchange.fillable_quantity(order_id).unwrap())
+}
+
+#[tokio::test]
+async fn test_large_market_order_walks_the_book() {
+    // Asks hold 1.2 per level, 5 bps apart
+    let (small_price, small_fillable) = market_order_fill(TradeDirection::Buy, 1.0).await;
+    let (large_price, large_fillable) = market_order_fill(TradeDirection::Buy, 6.0).await;
+    assert_eq!(small_fillable, 1.0);
+    assert_eq!(large_fillable, 6.0);
+    
+    // Six units take five full levels, averaging two level gaps above the top
+    let impact = large_price - small_price;
+    assert!((2.0 * 35000.0 * 0.0005..=2.0 * 36100.0 * 0.0005).contains(&impact), "impact {}", impact);
+    
+    let (small_sell, _) = market_order_fill(TradeDirection::Sell, 1.0).await;
+    let (large_sell, _) = market_order_fill(TradeDirection::Sell, 6.0).await;
+    assert!(large_sell < small_sell);
+}
+
+#[tokio::test]
+async fn test_market_order_beyond_depth_partially_fills() {
+    // Ten levels of 1.2 hold 12 units
+    let (price, fillable) = market_order_fill(TradeDirection::Buy, 20.0).await;
+    assert!((fillable - 12.0).abs() < 1e-9);
+    
+    let (top_price, _) = market_order_fill(TradeDirection::Buy, 1.0).await;
+    let impact = price - top_price;
+    assert!((4.5 * 35000.0 * 0.0005..=4.5 * 36100.0 * 0.0005).contains(&impact), "impact {}", impact);
+}
+
+#[tokio::test]
+async fn test_limit_orders_ignore_book_depth() {
+    let config = create_simulated_config(&[("simulated_latency_ms", "0")]);
+    let mut exchange = CryptoExchange::new(config);
+    exchange.connect().await.unwrap();
+    
+    let mut order = create_test_order();
+    order.quantity = 50.0;
+    let order_id = order.id;
+    exchange.submit_order(order).await.unwrap();
+    assert_eq!(exchange.fillable_quantity(order_id), Some(50.0));
+}
+
+#[tokio::test]
+async fn test_post_only_order_crossing_the_spread_is_rejected() {
+    let config = create_simulated_config(&[("simulated_latency_ms", "0")]);
+    let mut exchange = CryptoExchange::new(config);
+    exchange.connect().await.unwrap();
+    
+    // Simulated prices stay between 35000 and 36000
+    let mut order = create_test_order();
+    order.post_only = true;
+    order.price = Some(Price::from(40000.0));
+    let order_id = order.id;
+    let error = exchange.submit_order(order).await.unwrap_err();
+    
+    assert!(rejection_reason(&error).unwrap().starts_with("Post-only order"), "{}", error);
+    assert!(exchange.get_order_status(order_id).await.is_err());
+}
+
+#[tokio::test]
+async fn test_resting_post_only_order_is_accepted() {
+    let config = create_simulated_config(&[("simulated_latency_ms", "0")]);
+    let mut exchange = CryptoExchange::new(config);
+    exchange.connect().await.unwrap();
+    
+    let mut order = create_test_order();
+    order.post_only = true;
+    order.price = Some(Price::from(30000.0));
+    let order_id = order.id;
+    exchange.submit_order(order).await.unwrap();
+    assert!(exchange.get_order_status(order_id).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_fill_progression_follows_clock() {
+    let clock = Arc::new(MockClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()));
+    let mut exchange = CryptoExchange::new(create_simulated_config(&[("simulated_latency_ms", "0")]))
+        .with_clock(clock.clone());
+    exchange.connect().await.unwrap();
+    let order = create_test_order();
+    exchange.submit_order(order.clone()).await.unwrap();
+    
+    // Nothing happens until the clock moves, however often the status is checked
+    for _ in 0..3 {
+        assert_eq!(exchange.get_order_status(order.id).await.unwrap().status, ExchangeOrderStatus::Pending);
+    }
+    
+    clock.advance(Duration::seconds(3));
+    assert_eq!(exchange.get_order_status(order.id).await.unwrap().status, ExchangeOrderStatus::Open);
+    
+    clock.advance(Duration::seconds(3));
+    let status = exchange.get_order_status(order.id).await.unwrap();
+    assert_eq!(status.status, ExchangeOrderStatus::PartiallyFilled);
+    assert_eq!(status.filled_quantity, 0.5);
+    
+    clock.advance(Duration::seconds(5));
+    let status = exchange.get_order_status(order.id).await.unwrap();
+    assert_eq!(status.status, ExchangeOrderStatus::Filled);
+    assert_eq!(status.filled_quantity, 1.0);
+}
+
+#[tokio::test]
+async fn test_instant_fill_schedule() {
+    let clock = Arc::new(MockClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()));
+    let config = create_simulated_config(&[("simulated_latency_ms", "0"), ("fill_schedule", "instant")]);
+    let mut exchange = CryptoExchange::new(config).with_clock(clock);
+    exchange.connect().await.unwrap();
+    let order = create_test_order();
+    exchange.submit_order(order.clone()).await.unwrap();
+    
+    let status = exchange.get_order_status(order.id).await.unwrap();
+    assert_eq!(status.status, ExchangeOrderStatus::Filled);
+    assert_eq!(status.filled_quantity, 1.0);
+    assert_eq!(status.remaining_quantity, 0.0);
+}
+
+#[tokio::test]
+async fn test_never_fill_schedule() {
+    let clock = Arc::new(MockClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()));
+    let mut exchange = CryptoExchange::new(create_simulated_config(&[("simulated_latency_ms", "0")]))
+        .with_clock(clock.clone())
+        .with_fill_schedule(FillSchedule::never());
+    exchange.connect().await.unwrap();
+    let order = create_test_order();
+    exchange.submit_order(order.clone()).await.unwrap();
+    
+    assert_eq!(exchange.get_order_status(order.id).await.unwrap().status, ExchangeOrderStatus::Open);
+    clock.advance(Duration::days(1));
+    let status = exchange.get_order_status(order.id).await.unwrap();
+    assert_eq!(status.status, ExchangeOrderStatus::Open);
+    assert_eq!(status.filled_quantity, 0.0);
+}
+
+#[tokio::test]
+async fn test_fill_schedule_is_not_applied_to_cancelled_orders() {
+    let clock = Arc::new(MockClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()));
+    let schedule: FillSchedule = "1:0,2:0.25,4:1".parse().unwrap();
+    let mut exchange = CryptoExchange::new(create_simulated_config(&[("simulated_latency_ms", "0")]))
+        .with_clock(clock.clone())
+        .with_fill_schedule(schedule);
+    exchange.connect().await.unwrap();
+    let order = create_test_order();
+    exchange.submit_order(order.clone()).await.unwrap();
+    
+    // Polling late jumps straight to the step reached
+    clock.advance(Duration::seconds(3));
+    let status = exchange.get_order_status(order.id).await.unwrap();
+    assert_eq!(status.status, ExchangeOrderStatus::PartiallyFilled);
+    assert_eq!(status.filled_quantity, 0.25);
+    
+    exchange.cancel_order(order.id).await.unwrap();
+    clock.advance(Duration::seconds(10));
+    let status = exchange.get_order_status(order.id).await.unwrap();
+    assert_eq!(status.status, ExchangeOrderStatus::Cancelled);
+    assert_eq!(status.filled_quantity, 0.25);
+}
+
+#[test]
+fn test_factory_rejects_invalid_fill_schedule() {
+    let config = create_simulated_config(&[("fill_schedule", "5:1,2:0")]);
+    let error = ExchangeFactory::create_crypto_exchange(config).err().unwrap();
+    assert!(error.to_string().contains("Invalid fill schedule"));
+    
+    // Constructing directly falls back to the default schedule
+    let exchange = CryptoExchange::new(create_simulated_config(&[("fill_schedule", "5:1,2:0")]));
+    assert_eq!(exchange.simulation_settings().fill_schedule, FillSchedule::default());
+}
+
+#[tokio::test]
+async fn test_account_history_is_deterministic_by_day() {
+    let mut exchange = CryptoExchange::new(create_test_config());
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(); // A Monday
+    let to = from + Duration::days(7);
+    assert!(exchange.get_account_history(from, to).await.is_err());
+    exchange.connect().await.unwrap();
+    
+    let week = exchange.get_account_history(from, to).await.unwrap();
+    assert_eq!(week, exchange.get_account_history(from, to).await.unwrap());
+    assert!(week.windows(2).all(|pair| pair[0].timestamp <= pair[1].timestamp));
+    assert!(week.iter().all(|transaction| transaction.timestamp >= from && transaction.timestamp < to));
+    
+    let of_type = |transaction_type| week.iter().filter(|transaction| transaction.transaction_type == transaction_type).count();
+    assert_eq!(of_type(TransactionType::Deposit), 1);
+    assert_eq!(of_type(TransactionType::Withdrawal), 1); // Friday the 5th
+    assert_eq!(of_type(TransactionType::Interest), 1);
+    assert_eq!(of_type(TransactionType::Fee), 1);
+    assert_eq!(of_type(TransactionType::Funding), 7);
+    assert!((7..=21).contains(&of_type(TransactionType::Trade)));
+    
+    // A day's transactions do not depend on the range asked for
+    let (day_start, day_end) = (from + Duration::days(2), from + Duration::days(3));
+    let day = exchange.get_account_history(day_start, day_end).await.unwrap();
+    let from_week: Vec<_> = week.into_iter()
+        .filter(|transaction| transaction.timestamp >= day_start && transaction.timestamp < day_end)
+        .collect();
+    assert_eq!(day, from_week);
+    assert!(day[0].id.starts_with("Test Crypto Exchange-20240103-"));
+    
+    assert!(exchange.get_account_history(to, from).await.unwrap().is_empty());
+}
+
+// Server answering every request after `delay`, and the URL to reach it
+async fn slow_server(delay: std::time::Duration) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                tokio::time::sleep(delay).await;
+                let _ = socket.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n").await;
+            });
+        }
+    });
+    url
+}
+
+#[tokio::test]
+async fn test_connection_config_from_params() {
+    let config = create_simulated_config(&[
+        ("request_timeout_ms", "250"),
+        ("connect_timeout_ms", "0"),
+        ("max_idle_connections", "4"),
+        ("verify_connection", "true"),
+    ]);
+    let exchange = CryptoExchange::new(config);
+    let connection = exchange.connection_config();
+
+    assert_eq!(connection.request_timeout_ms, 250);
+    assert_eq!(connection.connect_timeout_ms, ConnectionConfig::default().connect_timeout_ms); // Zero is ignored
+    assert_eq!(connection.max_idle_connections, 4);
+    assert!(connection.verify_connection);
+
+    let defaults = CryptoExchange::new(create_test_config());
+    assert_eq!(*defaults.connection_config(), ConnectionConfig::default());
+}
+
+#[tokio::test]
+async fn test_slow_exchange_times_out() {
+    let mut config = create_simulated_config(&[("request_timeout_ms", "100"), ("verify_connection", "true")]);
+    config.api_url = slow_server(std::time::Duration::from_secs(5)).await;
+    let mut exchange = CryptoExchange::new(config);
+
+    let error = exchange.connect().await.unwrap_err();
+    assert!(!exchange.is_connected());
+    match error {
+        TradingError::ExchangeTimeout { exchange, operation, elapsed_ms } => {
+            assert_eq!(exchange, "Test Crypto Exchange");
+            assert_eq!(operation, "connect");
+            assert!((100..5000).contains(&elapsed_ms), "elapsed {} ms", elapsed_ms);
+        },
+        other => panic!("Expected a timeout, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_exchange_answering_in_time_does_not_time_out() {
+    let mut config = create_test_config();
+    config.api_url = slow_server(std::time::Duration::from_millis(50)).await;
+    let verified = ConnectionConfig { request_timeout_ms: 2000, verify_connection: true, ..ConnectionConfig::default() };
+    let mut exchange = CryptoExchange::new(config).with_connection_config(verified);
+
+    exchange.connect().await.unwrap();
+    assert!(exchange.is_connected());
+
+    // Nothing listening is a failure, but not a timeout
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut config = create_test_config();
+    config.api_url = format!("http://{}/", listener.local_addr().unwrap());
+    drop(listener);
+    let error = CryptoExchange::new(config).with_connection_config(verified).connect().await.unwrap_err();
+    assert!(matches!(error, TradingError::Exchange(_)), "{:?}", error);
+}
+
+#[tokio::test]
+async fn test_fee_schedule_from_params() {
+    let config = create_simulated_config(&[
+        ("maker_fee_bps", "2"),
+        ("taker_fee_bps", "7.5"),
+        ("withdrawal_fees", "BTC=0.0005, ETH=0.005"),
+    ]);
+    // Known before connecting
+    let fees = CryptoExchange::new(config).get_fee_schedule().await.unwrap();
+    assert_eq!(fees.maker_bps, 2.0);
+    assert_eq!(fees.taker_bps, 7.5);
+    assert_eq!(fees.withdrawal_fees.get("BTC"), Some(&0.0005));
+    assert_eq!(fees.withdrawal_fees.get("ETH"), Some(&0.005));
+
+    let defaults = CryptoExchange::new(create_test_config()).get_fee_schedule().await.unwrap();
+    assert_eq!(defaults, FeeSchedule::default());
+
+    // A buy costs more and a sell brings in less once the fee is paid
+    let market = Order { order_type: OrderType::Market, price: None, ..create_test_order() };
+    assert_eq!(fees.fee_bps(&market), 7.5);
+    assert_eq!(fees.fee_bps(&create_test_order()), 2.0);
+    assert!((fees.price_after_fees(&market, 1000.0) - 1000.75).abs() < 1e-9);
+    let sell = Order { direction: TradeDirection::Sell, ..market };
+    assert!((fees.price_after_fees(&sell, 1000.0) - 999.25).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_factory_rejects_invalid_withdrawal_fees() {
+    for withdrawal_fees in ["BTC", "BTC=-1", "=0.1", "BTC=lots"] {
+        let config = create_simulated_config(&[("withdrawal_fees", withdrawal_fees)]);
+        let error = ExchangeFactory::create_crypto_exchange(config).err().unwrap();
+        assert!(error.to_string().contains("Invalid withdrawal fees"), "{}: {}", withdrawal_fees, error);
+
+        // Constructing directly leaves them out
+        let exchange = CryptoExchange::new(create_simulated_config(&[("withdrawal_fees", withdrawal_fees)]));
+        assert!(exchange.get_fee_schedule().await.unwrap().withdrawal_fees.is_empty());
+    }
+}
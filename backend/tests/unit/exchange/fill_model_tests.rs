@@ -0,0 +1,163 @@
+use arb_platform::exchange::{ExchangeConfig, ExchangeFactory, ExchangeType, MarketSnapshot};
+use arb_platform::exchange::fill_model::{
+    FillModel, ConstantSlippageModel, LinearImpactModel, TakerMakerModel, fill_model_from_params, walk_book
+};
+use arb_platform::market_data::PriceLevel;
+use arb_platform::order::{Order, OrderType};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
+
+use chrono::Utc;
+use std::collections::HashMap;
+
+use crate::helpers::orders::test_order;
+
+fn create_order(direction: TradeDirection, order_type: OrderType, quantity: f64, price: Option<f64>) -> Order {
+    Order {
+        client_order_id: "test_client_id".to_string(),
+        direction,
+        order_type,
+        quantity,
+        price: price.map(Price::from),
+        exchange: "Test Crypto Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+fn create_snapshot() -> MarketSnapshot {
+    MarketSnapshot {
+        symbol: "BTC/USD".to_string(),
+        price: Price::from(100.0),
+        bid: Price::from(99.5),
+        ask: Price::from(100.5),
+        bid_size: 10.0,
+        ask_size: 10.0,
+        volume: 1000.0,
+        timestamp: Utc::now(),
+        bids: Vec::new(),
+        asks: Vec::new(),
+    }
+}
+
+fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn test_constant_slippage_moves_price_against_order() {
+    let model = ConstantSlippageModel { slippage_bps: 50.0 };
+    let market = create_snapshot();
+    
+    let buy = create_order(TradeDirection::Buy, OrderType::Market, 1.0, None);
+    let sell = create_order(TradeDirection::Sell, OrderType::Market, 1.0, None);
+    
+    assert!((model.compute_fill_price(&buy, &market) - 100.5).abs() < 1e-9);
+    assert!((model.compute_fill_price(&sell, &market) - 99.5).abs() < 1e-9);
+    assert_eq!(model.compute_commission(&buy, 100.5, 1.0), 0.0);
+}
+
+#[test]
+fn test_slippage_never_crosses_limit_price() {
+    let model = ConstantSlippageModel { slippage_bps: 500.0 };
+    let market = create_snapshot();
+    
+    let buy = create_order(TradeDirection::Buy, OrderType::Limit, 1.0, Some(101.0));
+    let sell = create_order(TradeDirection::Sell, OrderType::Limit, 1.0, Some(98.0));
+    
+    assert_eq!(model.compute_fill_price(&buy, &market), 101.0);
+    assert_eq!(model.compute_fill_price(&sell, &market), 98.0);
+}
+
+#[test]
+fn test_linear_impact_scales_with_participation() {
+    let model = LinearImpactModel { impact_factor: 0.1 };
+    let market = create_snapshot();
+    
+    // 10% of volume with a 0.1 factor is 1% slippage
+    let small = create_order(TradeDirection::Buy, OrderType::Market, 100.0, None);
+    let large = create_order(TradeDirection::Buy, OrderType::Market, 500.0, None);
+    
+    assert!((model.compute_fill_price(&small, &market) - 101.0).abs() < 1e-9);
+    assert!((model.compute_fill_price(&large, &market) - 105.0).abs() < 1e-9);
+    
+    let mut no_volume = create_snapshot();
+    no_volume.volume = 0.0;
+    assert_eq!(model.compute_fill_price(&large, &no_volume), 100.0);
+}
+
+#[test]
+fn test_taker_maker_distinguishes_order_types() {
+    let model = TakerMakerModel { taker_fee_bps: 10.0, maker_fee_bps: 2.0 };
+    let market = create_snapshot();
+    
+    let taker = create_order(TradeDirection::Buy, OrderType::Market, 2.0, None);
+    let maker = create_order(TradeDirection::Sell, OrderType::Limit, 2.0, Some(100.25));
+    
+    // Takers cross the spread, makers fill at their limit
+    assert_eq!(model.compute_fill_price(&taker, &market), 100.5);
+    assert_eq!(model.compute_fill_price(&maker, &market), 100.25);
+    
+    assert!((model.compute_commission(&taker, 100.5, 2.0) - 0.201).abs() < 1e-9);
+    assert!((model.compute_commission(&maker, 100.25, 2.0) - 0.0401).abs() < 1e-9);
+}
+
+#[test]
+fn test_fill_model_from_params() {
+    assert!(fill_model_from_params(&HashMap::new()).unwrap().is_none());
+    
+    let model = fill_model_from_params(&params(&[
+        ("fill_model", "taker_maker"),
+        ("taker_fee_bps", "10"),
+        ("maker_fee_bps", "2"),
+    ])).unwrap().unwrap();
+    let order = create_order(TradeDirection::Buy, OrderType::Market, 1.0, None);
+    assert_eq!(model.compute_fill_price(&order, &create_snapshot()), 100.5);
+    
+    let model = fill_model_from_params(&params(&[("fill_model", "constant_slippage")])).unwrap().unwrap();
+    assert_eq!(model.compute_fill_price(&order, &create_snapshot()), 100.0); // Missing settings default to zero
+    
+    assert!(fill_model_from_params(&params(&[("fill_model", "magic")])).is_err());
+    assert!(fill_model_from_params(&params(&[
+        ("fill_model", "linear_impact"),
+        ("impact_factor", "-1"),
+    ])).is_err());
+}
+
+#[test]
+fn test_factory_rejects_invalid_fill_model() {
+    let config = ExchangeConfig {
+        name: "Test Crypto Exchange".to_string(),
+        exchange_type: ExchangeType::Crypto,
+        api_url: "https://api.example.com".to_string(),
+        api_key: None,
+        api_secret: None,
+        additional_params: params(&[("fill_model", "constant_slippage"), ("slippage_bps", "lots")]),
+    };
+    
+    let error = ExchangeFactory::create_crypto_exchange(config).err().unwrap();
+    assert!(error.contains("slippage_bps"));
+}
+
+#[test]
+fn test_walk_book_blends_levels() {
+    let levels = [
+        PriceLevel { price: 100.0, quantity: 1.0 },
+        PriceLevel { price: 101.0, quantity: 2.0 },
+        PriceLevel { price: 103.0, quantity: 1.0 },
+    ];
+    
+    // Within the top level
+    assert_eq!(walk_book(&levels, 0.5), Some((0.5, 100.0)));
+    
+    // 1 @ 100 + 2 @ 101 + 0.5 @ 103
+    let (filled, average) = walk_book(&levels, 3.5).unwrap();
+    assert_eq!(filled, 3.5);
+    assert!((average - 353.5 / 3.5).abs() < 1e-9);
+    
+    // More than the book holds fills only its depth
+    let (filled, average) = walk_book(&levels, 10.0).unwrap();
+    assert_eq!(filled, 4.0);
+    assert!((average - 405.0 / 4.0).abs() < 1e-9);
+    
+    assert_eq!(walk_book(&[], 1.0), None);
+}
@@ -0,0 +1,41 @@
+use arb_platform::exchange::fill_schedule::{FillSchedule, FillStep};
+
+use std::time::Duration;
+
+#[test]
+fn test_default_schedule_opens_then_fills_in_halves() {
+    let schedule = FillSchedule::default();
+    assert_eq!(schedule.filled_fraction(Duration::from_secs(1)), None);
+    assert_eq!(schedule.filled_fraction(Duration::from_secs(2)), Some(0.0));
+    assert_eq!(schedule.filled_fraction(Duration::from_secs(7)), Some(0.5));
+    assert_eq!(schedule.filled_fraction(Duration::from_secs(60)), Some(1.0));
+    assert_eq!("2:0,5:0.5,10:1".parse::<FillSchedule>().unwrap(), schedule);
+}
+
+#[test]
+fn test_instant_and_never_schedules() {
+    assert_eq!(FillSchedule::instant().filled_fraction(Duration::ZERO), Some(1.0));
+    assert_eq!(FillSchedule::never().filled_fraction(Duration::ZERO), Some(0.0));
+    assert_eq!(FillSchedule::never().filled_fraction(Duration::from_secs(86400)), Some(0.0));
+    assert_eq!("instant".parse::<FillSchedule>().unwrap(), FillSchedule::instant());
+    assert_eq!(" never ".parse::<FillSchedule>().unwrap(), FillSchedule::never());
+}
+
+#[test]
+fn test_parses_fractional_seconds() {
+    let schedule: FillSchedule = "0.5:0.25, 1.5:1".parse().unwrap();
+    assert_eq!(schedule.steps(), &[
+        FillStep::new(Duration::from_millis(500), 0.25),
+        FillStep::new(Duration::from_millis(1500), 1.0),
+    ]);
+    assert_eq!(schedule.filled_fraction(Duration::from_millis(499)), None);
+    assert_eq!(schedule.filled_fraction(Duration::from_secs(1)), Some(0.25));
+}
+
+#[test]
+fn test_invalid_schedules_are_rejected() {
+    for invalid in ["", "soon", "2", "2:half", "-1:0.5", "2:1.5", "5:0.5,2:1", "2:0.5,5:0.25"] {
+        assert!(invalid.parse::<FillSchedule>().is_err(), "{} should be rejected", invalid);
+    }
+    assert!(FillSchedule::new(Vec::new()).is_err());
+}
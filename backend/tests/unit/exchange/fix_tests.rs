@@ -0,0 +1,266 @@
+use arb_platform::exchange::{
+    ExchangeType, ExchangeConfig, Exchange, OrderStatus, rejection_reason
+};
+use arb_platform::exchange::fix::{
+    FixExchange, FixMessage, FixMsgType, FixSessionConfig, SOH,
+    TAG_CL_ORD_ID, TAG_CUM_QTY, TAG_LEAVES_QTY, TAG_AVG_PX, TAG_ORDER_ID,
+    TAG_ORD_STATUS, TAG_ORD_TYPE, TAG_ORDER_QTY, TAG_ORIG_CL_ORD_ID, TAG_TEXT,
+    TAG_TIME_IN_FORCE, TAG_HANDL_INST,
+};
+use arb_platform::order::{Order, OrderType};
+use arb_platform::strategy::{TradeDirection, TimeInForce};
+use arb_platform::models::Price;
+
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::helpers::orders::test_order;
+
+fn create_test_order(symbol: &str) -> Order {
+    Order {
+        client_order_id: "test_client_id".to_string(),
+        symbol: symbol.to_string(),
+        direction: TradeDirection::Sell,
+        price: Some(Price::from(35000.5)),
+        exchange: "Test FIX Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+fn create_test_config(port: u16) -> ExchangeConfig {
+    let mut additional_params = HashMap::new();
+    additional_params.insert("sender_comp_id".to_string(), "ARB".to_string());
+    additional_params.insert("target_comp_id".to_string(), "BROKER".to_string());
+    additional_params.insert("response_timeout_ms".to_string(), "1000".to_string());
+
+    ExchangeConfig {
+        name: "Test FIX Exchange".to_string(),
+        exchange_type: ExchangeType::Stock,
+        api_url: format!("tcp://127.0.0.1:{}", port),
+        api_key: None,
+        api_secret: None,
+        additional_params,
+    }
+}
+
+#[test]
+fn test_parse_pipe_delimited_message() {
+    let raw = "8=FIX.4.2|9=65|35=A|49=SERVER|56=CLIENT|34=177|52=20090107-18:15:16|98=0|108=30|10=062|";
+    let message = FixMessage::parse(raw).unwrap();
+
+    assert_eq!(message.msg_type(), Some(FixMsgType::Logon));
+    assert_eq!(message.get(49), Some("SERVER"));
+    assert_eq!(message.get(108), Some("30"));
+    assert_eq!(message.seq_num(), Some(177));
+    // Derived fields are checked, not kept
+    assert_eq!(message.get(9), None);
+    assert_eq!(message.get(10), None);
+}
+
+#[test]
+fn test_parse_rejects_bad_checksum_and_body_length() {
+    let bad_checksum = "8=FIX.4.2|9=65|35=A|49=SERVER|56=CLIENT|34=177|52=20090107-18:15:16|98=0|108=30|10=063|";
+    assert!(FixMessage::parse(bad_checksum).unwrap_err().contains("CheckSum"));
+
+    let bad_length = "8=FIX.4.2|9=64|35=A|49=SERVER|56=CLIENT|34=177|52=20090107-18:15:16|98=0|108=30|10=062|";
+    assert!(FixMessage::parse(bad_length).unwrap_err().contains("BodyLength"));
+}
+
+#[test]
+fn test_parse_rejects_malformed_messages() {
+    assert!(FixMessage::parse("35=0|8=FIX.4.2|").is_err());
+    assert!(FixMessage::parse("8=FIX.4.2|49=X|").is_err());
+    assert!(FixMessage::parse("8=FIX.4.2|35=0|garbage|").is_err());
+    assert!(FixMessage::parse("8=FIX.4.2|abc=1|35=0|").is_err());
+}
+
+#[test]
+fn test_all_message_types_round_trip() {
+    for msg_type in FixMsgType::ALL {
+        let mut message = FixMessage::new(msg_type);
+        message
+            .set(49, "ARB")
+            .set(56, "BROKER")
+            .set(34, "12")
+            .set(TAG_CL_ORD_ID, "order-1")
+            .set(TAG_TEXT, "a=b");
+
+        let wire = message.to_wire();
+        assert!(wire.starts_with("8=FIX.4.2\x019="));
+        assert!(wire.contains(&format!("{}35={}{}", SOH, msg_type.code(), SOH)));
+        assert_eq!(FixMessage::parse(&wire).unwrap(), message, "{:?} over SOH", msg_type);
+
+        // The logged form parses back too
+        let logged = message.to_string();
+        assert!(!logged.contains(SOH));
+        assert_eq!(FixMessage::parse(&logged).unwrap(), message, "{:?} over pipes", msg_type);
+        assert_eq!(FixMessage::parse(&logged).unwrap().msg_type(), Some(msg_type));
+    }
+}
+
+#[test]
+fn test_new_order_single_fields() {
+    let mut order = create_test_order("AAPL");
+    order.order_type = OrderType::StopLimit;
+    order.stop_price = Some(Price::from(34000.0));
+    order.time_in_force = TimeInForce::ImmediateOrCancel;
+
+    let message = FixMessage::new_order_single(&order);
+    assert_eq!(message.msg_type(), Some(FixMsgType::NewOrderSingle));
+    assert_eq!(message.order_id(), Some(order.id.to_string().as_str()));
+    assert_eq!(message.symbol(), Some("AAPL"));
+    assert_eq!(message.side(), Some(TradeDirection::Sell));
+    assert_eq!(message.price(), Some(35000.5));
+    assert_eq!(message.get(99), Some("34000"));
+    assert_eq!(message.get(TAG_ORDER_QTY), Some("1"));
+    assert_eq!(message.get(TAG_ORD_TYPE), Some("4"));
+    assert_eq!(message.get(TAG_TIME_IN_FORCE), Some("3"));
+    assert_eq!(message.get(TAG_HANDL_INST), Some("1"));
+    assert!(message.get(60).is_some());
+
+    let parsed = FixMessage::parse(&message.to_wire()).unwrap();
+    assert_eq!(parsed, message);
+}
+
+#[test]
+fn test_session_config_from_exchange_config() {
+    let config = create_test_config(9878);
+    let session = FixSessionConfig::from_exchange_config(&config).unwrap();
+    assert_eq!(session.host, "127.0.0.1");
+    assert_eq!(session.port, 9878);
+    assert_eq!(session.sender_comp_id, "ARB");
+    assert_eq!(session.target_comp_id, "BROKER");
+    assert_eq!(session.response_timeout, Duration::from_millis(1000));
+
+    // Explicit params win over the URL
+    let mut config = create_test_config(9878);
+    config.additional_params.insert("fix_host".to_string(), "fix.example.com".to_string());
+    config.additional_params.insert("fix_port".to_string(), "4000".to_string());
+    let session = FixSessionConfig::from_exchange_config(&config).unwrap();
+    assert_eq!((session.host.as_str(), session.port), ("fix.example.com", 4000));
+
+    let mut config = create_test_config(9878);
+    config.api_url = "https://api.example.com".to_string();
+    assert!(FixSessionConfig::from_exchange_config(&config).is_err());
+
+    let mut config = create_test_config(9878);
+    config.additional_params.remove("sender_comp_id");
+    assert!(FixExchange::new(config).is_err());
+}
+
+// Read one message from a raw stream, as the counterparty
+async fn read_message(stream: &mut TcpStream, buffer: &mut Vec<u8>) -> Option<FixMessage> {
+    loop {
+        let text = String::from_utf8_lossy(buffer).to_string();
+        if let Some(start) = text.find("\x0110=") {
+            if let Some(end) = text[start + 4..].find(SOH) {
+                let end = start + 4 + end + 1;
+                let message = FixMessage::parse(&text[..end]).unwrap();
+                buffer.drain(..end);
+                return Some(message);
+            }
+        }
+
+        let mut chunk = [0u8; 1024];
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+}
+
+// A broker that accepts everything except symbols starting with "REJECT"
+async fn run_acceptor(listener: TcpListener) {
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let mut buffer = Vec::new();
+
+    while let Some(request) = read_message(&mut stream, &mut buffer).await {
+        let mut reply = match request.msg_type() {
+            Some(FixMsgType::Logon) => FixMessage::new(FixMsgType::Logon),
+            Some(FixMsgType::NewOrderSingle) => {
+                // Probe the client's test request handling before answering
+                let mut test_request = FixMessage::new(FixMsgType::TestRequest);
+                test_request.set(112, "probe");
+                stream.write_all(test_request.to_wire().as_bytes()).await.unwrap();
+                let heartbeat = read_message(&mut stream, &mut buffer).await.unwrap();
+                assert_eq!(heartbeat.msg_type(), Some(FixMsgType::Heartbeat));
+                assert_eq!(heartbeat.get(112), Some("probe"));
+
+                let mut report = FixMessage::new(FixMsgType::ExecutionReport);
+                report.set(TAG_CL_ORD_ID, request.order_id().unwrap());
+                if request.symbol().unwrap().starts_with("REJECT") {
+                    report.set(TAG_ORD_STATUS, "8").set(TAG_TEXT, "Unknown symbol");
+                } else {
+                    report.set(TAG_ORD_STATUS, "0").set(TAG_ORDER_ID, "BRK-1");
+                }
+                report
+            },
+            Some(FixMsgType::OrderCancelRequest) => {
+                let mut report = FixMessage::new(FixMsgType::ExecutionReport);
+                report
+                    .set(TAG_CL_ORD_ID, request.order_id().unwrap())
+                    .set(TAG_ORIG_CL_ORD_ID, request.get(TAG_ORIG_CL_ORD_ID).unwrap())
+                    .set(TAG_ORD_STATUS, "4");
+                report
+            },
+            Some(FixMsgType::OrderStatusRequest) => {
+                let mut report = FixMessage::new(FixMsgType::ExecutionReport);
+                report
+                    .set(TAG_CL_ORD_ID, request.order_id().unwrap())
+                    .set(TAG_ORDER_ID, "BRK-1")
+                    .set(TAG_ORD_STATUS, "1")
+                    .set(TAG_CUM_QTY, "0.4")
+                    .set(TAG_LEAVES_QTY, "0.6")
+                    .set(TAG_AVG_PX, "35001");
+                report
+            },
+            Some(FixMsgType::Logout) => break,
+            _ => continue,
+        };
+        reply.set(49, "BROKER").set(56, "ARB");
+        stream.write_all(reply.to_wire().as_bytes()).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_fix_exchange_order_lifecycle() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let acceptor = tokio::spawn(run_acceptor(listener));
+
+    let mut exchange = FixExchange::new(create_test_config(port)).unwrap();
+    exchange.connect().await.unwrap();
+    assert!(exchange.is_connected());
+
+    let order = create_test_order("AAPL");
+    exchange.submit_order(order.clone()).await.unwrap();
+
+    let status = exchange.get_order_status(order.id).await.unwrap();
+    assert_eq!(status.status, OrderStatus::PartiallyFilled);
+    assert_eq!(status.exchange_order_id.as_deref(), Some("BRK-1"));
+    assert_eq!(status.filled_quantity, 0.4);
+    assert_eq!(status.remaining_quantity, 0.6);
+    assert_eq!(status.average_price, Some(35001.0));
+
+    let cancellation = exchange.cancel_order(order.id).await.unwrap();
+    assert_eq!(cancellation.unfilled_quantity, order.quantity);
+
+    let rejected = create_test_order("REJECT/USD");
+    let err = exchange.submit_order(rejected.clone()).await.unwrap_err();
+    assert_eq!(rejection_reason(&err), Some("Unknown symbol"));
+    assert!(exchange.get_order_status(rejected.id).await.is_err());
+
+    exchange.disconnect().await.unwrap();
+    assert!(!exchange.is_connected());
+    tokio::time::timeout(Duration::from_secs(1), acceptor).await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_fix_exchange_requires_connection() {
+    let exchange = FixExchange::new(create_test_config(1)).unwrap();
+    assert!(exchange.submit_order(create_test_order("AAPL")).await.is_err());
+    assert!(exchange.get_market_data("AAPL").await.is_err());
+}
@@ -0,0 +1,151 @@
+use arb_platform::exchange::{
+    Exchange, ExchangeConfig, ExchangeFactory, ExchangeType, MarketSnapshot, OrderStatusResponse,
+    AccountBalance, Position, CancellationResult, MAX_CONCURRENT_REQUESTS_PARAM,
+};
+use arb_platform::exchange::limit::ConcurrencyLimitedExchange;
+use arb_platform::order::{Order, OrderType};
+
+use arb_platform::error::TradingError;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::helpers::orders::test_order;
+
+// Exchange taking a while over every request, tracking how many run at once
+#[derive(Default)]
+struct SlowExchange {
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+    completed: Arc<AtomicUsize>,
+}
+
+impl SlowExchange {
+    async fn respond(&self) {
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        self.completed.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl Exchange for SlowExchange {
+    fn name(&self) -> &str { "Slow Exchange" }
+    fn exchange_type(&self) -> ExchangeType { ExchangeType::Crypto }
+    fn is_connected(&self) -> bool { true }
+    
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        Ok(())
+    }
+    
+    async fn disconnect(&mut self) -> Result<(), TradingError> {
+        Ok(())
+    }
+    
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError> {
+        Ok(vec!["BTC/USD".to_string()])
+    }
+    
+    async fn get_market_data(&self, _symbol: &str) -> Result<MarketSnapshot, TradingError> {
+        self.respond().await;
+        Err(TradingError::Exchange("No market data".to_string()))
+    }
+    
+    async fn submit_order(&self, _order: Order) -> Result<(), TradingError> {
+        self.respond().await;
+        Ok(())
+    }
+    
+    async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError> {
+        Err(TradingError::NotFound(format!("Order {} not found", order_id)))
+    }
+    
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
+        self.respond().await;
+        Err(TradingError::NotFound(format!("Order {} not found", order_id)))
+    }
+    
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError> {
+        Err(TradingError::Exchange("Not supported".to_string()))
+    }
+    
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError> {
+        Ok(Vec::new())
+    }
+}
+
+fn create_order() -> Order {
+    Order {
+        client_order_id: "limit-1".to_string(),
+        order_type: OrderType::Market,
+        price: None,
+        exchange: "Slow Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+fn config(params: &[(&str, &str)]) -> ExchangeConfig {
+    ExchangeConfig {
+        name: "Limited Exchange".to_string(),
+        exchange_type: ExchangeType::Crypto,
+        api_url: "https://api.example.com".to_string(),
+        api_key: None,
+        api_secret: None,
+        additional_params: params.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<HashMap<_, _>>(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_in_flight_requests_never_exceed_the_limit() {
+    let slow = SlowExchange::default();
+    let max_in_flight = slow.max_in_flight.clone();
+    let completed = slow.completed.clone();
+    let exchange = Arc::new(ConcurrencyLimitedExchange::new(Box::new(slow), 3));
+    assert_eq!(exchange.limit(), 3);
+    
+    let mut handles = Vec::new();
+    for index in 0..12 {
+        let exchange = exchange.clone();
+        handles.push(tokio::spawn(async move {
+            match index % 3 {
+                0 => exchange.submit_order(create_order()).await.is_ok(),
+                1 => exchange.get_market_data("BTC/USD").await.is_err(),
+                _ => exchange.get_order_status(Uuid::new_v4()).await.is_err(),
+            }
+        }));
+    }
+    
+    // Excess calls queue rather than fail
+    for handle in handles {
+        assert!(handle.await.unwrap());
+    }
+    assert_eq!(completed.load(Ordering::SeqCst), 12);
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 3);
+    assert_eq!(exchange.in_flight(), 0);
+}
+
+#[tokio::test]
+async fn test_unlimited_calls_pass_straight_through() {
+    let exchange = ConcurrencyLimitedExchange::new(Box::new(SlowExchange::default()), 0);
+    assert_eq!(exchange.limit(), 1);
+    assert_eq!(exchange.name(), "Slow Exchange");
+    assert_eq!(exchange.get_supported_assets().await.unwrap(), vec!["BTC/USD"]);
+    assert!(exchange.cancel_order(Uuid::new_v4()).await.is_err());
+}
+
+#[test]
+fn test_limit_is_read_from_config() {
+    assert_eq!(config(&[]).max_concurrent_requests(), Ok(None));
+    assert_eq!(config(&[(MAX_CONCURRENT_REQUESTS_PARAM, " 8 ")]).max_concurrent_requests(), Ok(Some(8)));
+    assert!(config(&[(MAX_CONCURRENT_REQUESTS_PARAM, "0")]).max_concurrent_requests().is_err());
+    assert!(config(&[(MAX_CONCURRENT_REQUESTS_PARAM, "many")]).max_concurrent_requests().is_err());
+    
+    assert!(ExchangeFactory::create_exchange(config(&[(MAX_CONCURRENT_REQUESTS_PARAM, "0")])).is_err());
+    let exchange = ExchangeFactory::create_exchange(config(&[(MAX_CONCURRENT_REQUESTS_PARAM, "2")])).unwrap();
+    assert_eq!(exchange.name(), "Limited Exchange");
+}
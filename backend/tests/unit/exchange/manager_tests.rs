@@ -0,0 +1,298 @@
+use arb_platform::exchange::{AccountBalance, AccountTransaction, CurrencyHolding, Exchange, ExchangeConfig, ExchangeType, MarginInfo, Position, TransactionType};
+use arb_platform::error::TradingError;
+use arb_platform::exchange::manager::{ExchangeManager, load_exchange_configs};
+use arb_platform::order::OrderRouter;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::strategy::{AssetData, AssetType};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::collections::HashMap;
+
+fn balance(currency: &str, total: f64, available: f64, additional: &[(&str, f64)]) -> AccountBalance {
+    AccountBalance {
+        total,
+        available,
+        currency: currency.to_string(),
+        additional_balances: additional.iter().map(|(c, a)| (c.to_string(), *a)).collect(),
+        timestamp: Utc::now(),
+    }
+}
+
+fn position(symbol: &str, quantity: f64) -> Position {
+    Position {
+        symbol: symbol.to_string(),
+        quantity,
+        avg_price: 100.0,
+        current_price: 110.0,
+        unrealized_pnl: quantity * 10.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }
+}
+
+fn mock_with_balance(name: &str, balance: AccountBalance) -> MockExchange {
+    let exchange = MockExchange::new(name);
+    exchange.set_account_balance(balance);
+    exchange
+}
+
+#[tokio::test]
+async fn test_aggregate_balance_sums_usd_across_exchanges() {
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(mock_with_balance(
+        "Alpha", balance("USD", 1000.0, 800.0, &[("BTC", 1.5), ("ETH", 10.0)]),
+    ))).unwrap();
+    manager.add_exchange(Box::new(mock_with_balance(
+        "Beta", balance("USD", 500.0, 250.0, &[("BTC", 0.5), ("SOL", 20.0)]),
+    ))).unwrap();
+    
+    let aggregate = manager.get_aggregate_balance().await.unwrap();
+    assert_eq!(aggregate.currency, "USD");
+    assert_eq!(aggregate.total, 1500.0);
+    assert_eq!(aggregate.available, 1050.0);
+    assert_eq!(aggregate.additional_balances, vec![
+        ("BTC".to_string(), 2.0),
+        ("ETH".to_string(), 10.0),
+        ("SOL".to_string(), 20.0),
+    ]);
+}
+
+#[tokio::test]
+async fn test_aggregate_balance_consolidates_other_currencies() {
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(mock_with_balance("Alpha", balance("USD", 1000.0, 1000.0, &[])))).unwrap();
+    manager.add_exchange(Box::new(mock_with_balance("Beta", balance("EUR", 300.0, 300.0, &[("USD", 50.0)])))).unwrap();
+    manager.add_exchange(Box::new(mock_with_balance("Gamma", balance("EUR", 200.0, 100.0, &[])))).unwrap();
+    
+    let aggregate = manager.get_aggregate_balance().await.unwrap();
+    assert_eq!(aggregate.currency, "USD");
+    assert_eq!(aggregate.total, 1050.0);
+    assert_eq!(aggregate.available, 1000.0);
+    assert_eq!(aggregate.additional_balances, vec![("EUR".to_string(), 500.0)]);
+}
+
+#[tokio::test]
+async fn test_total_balance_converts_other_currencies() {
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(mock_with_balance(
+        "Alpha", balance("USD", 1000.0, 800.0, &[("BTC", 1.5), ("DOGE", 100.0)]),
+    ))).unwrap();
+    
+    // Simulated BTC price; DOGE has no market and is left out
+    let market_data = MarketDataManager::new();
+    market_data.get_current_data().write().await.asset_data.insert("BTC/USD".to_string(), AssetData {
+        symbol: "BTC/USD".to_string(),
+        asset_type: AssetType::Crypto,
+        price: Price::from(40000.0),
+        volume: 0.0,
+        bid: Price::from(40000.0),
+        ask: Price::from(40000.0),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Simulated".to_string(),
+        last_update: Utc::now(),
+    });
+    
+    let total = manager.get_total_balance_in(&market_data.get_price_converter(), "USD").await.unwrap();
+    assert_eq!(total, 61000.0);
+}
+
+#[tokio::test]
+async fn test_balance_in_quote_currency_converts_usd_and_eur() {
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(mock_with_balance("Alpha", balance("USD", 1000.0, 1000.0, &[("EUR", 100.0)])))).unwrap();
+    manager.add_exchange(Box::new(mock_with_balance("Beta", balance("EUR", 500.0, 500.0, &[("DOGE", 300.0)])))).unwrap();
+    
+    let market_data = MarketDataManager::new();
+    market_data.get_current_data().write().await.asset_data.insert("EUR/USD".to_string(), AssetData {
+        symbol: "EUR/USD".to_string(),
+        asset_type: AssetType::Forex,
+        price: Price::from(1.25),
+        volume: 0.0,
+        bid: Price::from(1.25),
+        ask: Price::from(1.25),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Simulated".to_string(),
+        last_update: Utc::now(),
+    });
+    let converter = market_data.get_price_converter();
+    
+    let usd = manager.get_balance_in(&converter, "USD").await.unwrap();
+    assert_eq!(usd.quote_currency, "USD");
+    assert_eq!(usd.holdings, vec![
+        CurrencyHolding { currency: "DOGE".to_string(), amount: 300.0, rate: None, value: None },
+        CurrencyHolding { currency: "EUR".to_string(), amount: 600.0, rate: Some(1.25), value: Some(750.0) },
+        CurrencyHolding { currency: "USD".to_string(), amount: 1000.0, rate: Some(1.0), value: Some(1000.0) },
+    ]);
+    assert_eq!(usd.total, 1750.0);
+    
+    // The missing DOGE rate is reported, not counted as zero
+    assert_eq!(usd.missing_rates, vec!["DOGE".to_string()]);
+    assert!(!usd.is_complete());
+    
+    let eur = manager.get_balance_in(&converter, "EUR").await.unwrap();
+    assert_eq!(eur.total, 1400.0);
+    assert_eq!(eur.missing_rates, vec!["DOGE".to_string()]);
+}
+
+#[tokio::test]
+async fn test_disconnected_exchanges_are_left_out() {
+    let mut offline = mock_with_balance("Alpha", balance("USD", 1000.0, 1000.0, &[]));
+    offline.set_positions(vec![position("BTC/USD", 1.0)]);
+    offline.disconnect().await.unwrap();
+    
+    let online = mock_with_balance("Beta", balance("USD", 250.0, 250.0, &[]));
+    online.set_positions(vec![position("ETH/USD", 2.0)]);
+    
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(offline)).unwrap();
+    manager.add_exchange(Box::new(online)).unwrap();
+    
+    assert_eq!(manager.get_aggregate_balance().await.unwrap().total, 250.0);
+    let positions = manager.get_aggregate_positions().await;
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].symbol, "ETH/USD");
+}
+
+#[tokio::test]
+async fn test_aggregate_positions_across_exchanges() {
+    let alpha = MockExchange::new("Alpha");
+    alpha.set_positions(vec![position("BTC/USD", 1.0), position("ETH/USD", 5.0)]);
+    let beta = MockExchange::new("Beta");
+    beta.set_positions(vec![position("BTC/USD", 0.5)]);
+    
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(alpha)).unwrap();
+    manager.add_exchange(Box::new(beta)).unwrap();
+    
+    let positions = manager.get_aggregate_positions().await;
+    let held: Vec<(&str, f64)> = positions.iter().map(|p| (p.symbol.as_str(), p.quantity)).collect();
+    assert_eq!(held, vec![("BTC/USD", 1.0), ("ETH/USD", 5.0), ("BTC/USD", 0.5)]);
+}
+
+#[tokio::test]
+async fn test_aggregate_margin_across_exchanges() {
+    let margin = |available_margin, used_margin| MarginInfo {
+        available_margin,
+        used_margin,
+        margin_level: 0.0,
+        max_leverage: 5.0,
+        liquidation_price: None,
+    };
+    let alpha = MockExchange::new("Alpha");
+    alpha.set_margin_info(margin(1000.0, 200.0));
+    let beta = MockExchange::new("Beta");
+    beta.set_margin_info(margin(500.0, 100.0));
+    
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(alpha)).unwrap();
+    manager.add_exchange(Box::new(beta)).unwrap();
+    // Trades without margin, so is left out
+    manager.add_exchange(Box::new(MockExchange::new("Gamma"))).unwrap();
+    
+    let aggregate = manager.get_aggregate_margin("BTC/USD").await;
+    assert_eq!(aggregate.total_available_margin, 1500.0);
+    assert_eq!(aggregate.total_used_margin, 300.0);
+    assert_eq!(aggregate.exchanges.keys().collect::<Vec<_>>(), vec!["Alpha", "Beta"]);
+    assert_eq!(aggregate.exchanges["Beta"], margin(500.0, 100.0));
+}
+
+fn deposit(id: &str, timestamp: DateTime<Utc>) -> AccountTransaction {
+    AccountTransaction {
+        id: id.to_string(),
+        transaction_type: TransactionType::Deposit,
+        currency: "USD".to_string(),
+        amount: 100.0,
+        fee: 0.0,
+        timestamp,
+        description: None,
+    }
+}
+
+#[tokio::test]
+async fn test_account_history_across_exchanges_oldest_first() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let alpha = MockExchange::new("Alpha");
+    alpha.set_account_history(vec![deposit("a1", start + Duration::hours(1)), deposit("a2", start + Duration::hours(5))]);
+    let beta = MockExchange::new("Beta");
+    beta.set_account_history(vec![deposit("b1", start + Duration::hours(3)), deposit("b2", start + Duration::days(2))]);
+    let gamma = MockExchange::disconnected("Gamma");
+    gamma.set_account_history(vec![deposit("g1", start + Duration::hours(2))]);
+    
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(alpha)).unwrap();
+    manager.add_exchange(Box::new(beta)).unwrap();
+    manager.add_exchange(Box::new(gamma)).unwrap();
+    
+    let history = manager.get_account_history(start, start + Duration::days(1)).await;
+    let ids: Vec<&str> = history.iter().map(|transaction| transaction.id.as_str()).collect();
+    assert_eq!(ids, vec!["a1", "b1", "a2"]);
+}
+
+#[tokio::test]
+async fn test_no_exchanges_has_no_balance() {
+    let manager = ExchangeManager::new();
+    assert!(manager.get_aggregate_balance().await.is_err());
+    assert!(manager.get_aggregate_positions().await.is_empty());
+}
+
+#[test]
+fn test_duplicate_exchange_rejected() {
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(MockExchange::new("Alpha"))).unwrap();
+    assert!(manager.add_exchange(Box::new(MockExchange::new("Alpha"))).is_err());
+    assert_eq!(manager.exchange_names(), vec!["Alpha".to_string()]);
+    assert!(manager.get_exchange("Alpha").is_some());
+}
+
+#[tokio::test]
+async fn test_from_configs_skips_unsupported_exchanges() {
+    let config = |name: &str, exchange_type| ExchangeConfig {
+        name: name.to_string(),
+        exchange_type,
+        api_url: "https://api.example.com".to_string(),
+        api_key: Some("key".to_string()),
+        api_secret: Some("secret".to_string()),
+        additional_params: HashMap::new(),
+    };
+    
+    let path = std::env::temp_dir().join(format!("exchanges-{}.json", uuid::Uuid::new_v4()));
+    let configs = vec![config("Crypto", ExchangeType::Crypto), config("Bonds", ExchangeType::Bond)];
+    std::fs::write(&path, serde_json::to_string(&configs).unwrap()).unwrap();
+    let loaded = load_exchange_configs(path.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    
+    let manager = ExchangeManager::from_configs(loaded).await;
+    assert_eq!(manager.exchange_names(), vec!["Crypto".to_string()]);
+    assert!(manager.get_exchange("Crypto").unwrap().is_connected());
+    
+    assert!(load_exchange_configs("/nonexistent/exchanges.json").is_err());
+}
+
+#[tokio::test]
+async fn test_managed_exchanges_can_be_shared_with_the_order_router() {
+    let mut manager = ExchangeManager::new();
+    manager.add_exchange(Box::new(MockExchange::new("Kraken"))).unwrap();
+    manager.add_exchange(Box::new(MockExchange::new("Binance"))).unwrap();
+    let exchanges = manager.exchanges();
+    assert_eq!(exchanges.iter().map(|exchange| exchange.name()).collect::<Vec<_>>(), vec!["Binance", "Kraken"]);
+
+    let router = OrderRouter::new();
+    for exchange in exchanges {
+        router.register_shared_exchange(exchange).await.unwrap();
+    }
+    router.set_default_exchange("Binance").await.unwrap();
+    let mut supported = router.get_supported_exchanges().await;
+    supported.sort();
+    assert_eq!(supported, manager.exchange_names());
+
+    // Each exchange is registered once
+    let shared = manager.get_exchange("Binance").unwrap();
+    assert!(matches!(router.register_shared_exchange(shared).await, Err(TradingError::Conflict(_))));
+}
@@ -1,3 +1,10 @@
 // Exchange module tests
 pub mod mod_tests;
 pub mod crypto_tests;
+pub mod fill_model_tests;
+pub mod pool_tests;
+pub mod limit_tests;
+pub mod fix_tests;
+pub mod manager_tests;
+pub mod circuit_breaker_tests;
+pub mod fill_schedule_tests;
@@ -1,7 +1,9 @@
 use arb_platform::exchange::{
     ExchangeType, MarketSnapshot, OrderStatusResponse, OrderStatus,
-    AccountBalance, Position, ExchangeConfig, ExchangeFactory, Exchange
+    AccountBalance, Position, ExchangeConfig, ExchangeFactory, Exchange, SUPPORTED_ORDER_TYPES_PARAM,
 };
+use arb_platform::order::OrderType;
+use arb_platform::models::Price;
 use chrono::Utc;
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -31,19 +33,21 @@ fn test_market_snapshot_creation() {
     let now = Utc::now();
     let snapshot = MarketSnapshot {
         symbol: "BTC/USD".to_string(),
-        price: 35000.0,
-        bid: 34990.0,
-        ask: 35010.0,
+        price: Price::from(35000.0),
+        bid: Price::from(34990.0),
+        ask: Price::from(35010.0),
         bid_size: 1.5,
         ask_size: 2.0,
         volume: 100.0,
         timestamp: now,
+        bids: Vec::new(),
+        asks: Vec::new(),
     };
     
     assert_eq!(snapshot.symbol, "BTC/USD");
-    assert_eq!(snapshot.price, 35000.0);
-    assert_eq!(snapshot.bid, 34990.0);
-    assert_eq!(snapshot.ask, 35010.0);
+    assert_eq!(snapshot.price, Price::from(35000.0));
+    assert_eq!(snapshot.bid, Price::from(34990.0));
+    assert_eq!(snapshot.ask, Price::from(35010.0));
     assert_eq!(snapshot.bid_size, 1.5);
     assert_eq!(snapshot.ask_size, 2.0);
     assert_eq!(snapshot.volume, 100.0);
@@ -127,6 +131,8 @@ fn test_position_creation() {
         unrealized_pnl: 1500.0,
         realized_pnl: 500.0,
         timestamp: now,
+        strategy_id: None,
+        opened_at: None,
     };
     
     assert_eq!(position.symbol, "BTC/USD");
@@ -178,4 +184,29 @@ fn test_exchange_factory() {
     assert_eq!(exchange.name(), config.name);
     assert_eq!(exchange.exchange_type(), config.exchange_type);
     assert!(!exchange.is_connected());
-} 
\ No newline at end of file
+} 
+
+#[test]
+fn test_supported_order_types_from_config() {
+    let config = |value: &str| ExchangeConfig {
+        name: "Restricted Exchange".to_string(),
+        exchange_type: ExchangeType::Crypto,
+        api_url: "https://api.example.com".to_string(),
+        api_key: None,
+        api_secret: None,
+        additional_params: HashMap::from([(SUPPORTED_ORDER_TYPES_PARAM.to_string(), value.to_string())]),
+    };
+    
+    assert_eq!(config("market, limit,stop_loss").supported_order_types(), Ok(Some(vec![OrderType::Market, OrderType::Limit, OrderType::StopLoss])));
+    assert!(config("market,iceberg").supported_order_types().is_err());
+    assert!(ExchangeFactory::create_exchange(config("market,iceberg")).is_err());
+    
+    let exchange = ExchangeFactory::create_exchange(config("limit")).unwrap();
+    assert_eq!(exchange.supported_order_types(), vec![OrderType::Limit]);
+    
+    // Unrestricted exchanges take every type
+    let mut unrestricted = config("limit");
+    unrestricted.additional_params.clear();
+    assert_eq!(unrestricted.supported_order_types(), Ok(None));
+    assert_eq!(ExchangeFactory::create_exchange(unrestricted).unwrap().supported_order_types(), OrderType::ALL.to_vec());
+}
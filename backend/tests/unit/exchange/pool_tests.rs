@@ -0,0 +1,248 @@
+use arb_platform::exchange::{
+    Exchange, ExchangeConfig, ExchangeType, MarketSnapshot, OrderStatusResponse,
+    AccountBalance, Position, CancellationResult, OrderStatus as ExchangeOrderStatus,
+};
+use arb_platform::exchange::pool::ConnectionPool;
+use arb_platform::order::{Order, OrderRouter, OrderType};
+use arb_platform::strategy::TimeInForce;
+
+use arb_platform::error::TradingError;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::helpers::orders::test_order;
+
+// Connection whose link can be dropped from the outside, recording which
+// connection handled each submission
+struct TestConnection {
+    id: usize,
+    connected: Arc<AtomicBool>,
+    connects: Arc<AtomicUsize>,
+    submissions: Arc<Mutex<Vec<(usize, Uuid)>>>,
+}
+
+#[async_trait]
+impl Exchange for TestConnection {
+    fn name(&self) -> &str { "Pooled Exchange" }
+    fn exchange_type(&self) -> ExchangeType { ExchangeType::Crypto }
+    fn is_connected(&self) -> bool { self.connected.load(Ordering::SeqCst) }
+    
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        self.connects.fetch_add(1, Ordering::SeqCst);
+        self.connected.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+    
+    async fn disconnect(&mut self) -> Result<(), TradingError> {
+        self.connected.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+    
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError> {
+        Ok(vec!["BTC/USD".to_string()])
+    }
+    
+    async fn get_market_data(&self, _symbol: &str) -> Result<MarketSnapshot, TradingError> {
+        Err(TradingError::Exchange("Not supported".to_string()))
+    }
+    
+    async fn submit_order(&self, order: Order) -> Result<(), TradingError> {
+        if !self.is_connected() {
+            return Err(TradingError::NotConnected(self.name().to_string()));
+        }
+        self.submissions.lock().unwrap().push((self.id, order.id));
+        Ok(())
+    }
+    
+    async fn cancel_order(&self, order_id: Uuid) -> Result<CancellationResult, TradingError> {
+        let submissions = self.submissions.lock().unwrap();
+        if submissions.contains(&(self.id, order_id)) {
+            Ok(CancellationResult {
+                exchange_order_id: Some(format!("POOL-{}", self.id)),
+                cancelled_at: Utc::now(),
+                unfilled_quantity: 1.0,
+            })
+        } else {
+            Err(TradingError::NotFound(format!("Order {} not found", order_id)))
+        }
+    }
+    
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
+        Ok(OrderStatusResponse {
+            order_id,
+            exchange_order_id: Some(format!("conn-{}", self.id)),
+            status: ExchangeOrderStatus::Open,
+            filled_quantity: 0.0,
+            remaining_quantity: 1.0,
+            average_price: None,
+            last_update: Utc::now(),
+        })
+    }
+    
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError> {
+        Err(TradingError::Exchange("Not supported".to_string()))
+    }
+    
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError> {
+        Ok(Vec::new())
+    }
+}
+
+struct TestPool {
+    pool: ConnectionPool<TestConnection>,
+    links: Vec<Arc<AtomicBool>>,
+    connects: Vec<Arc<AtomicUsize>>,
+    submissions: Arc<Mutex<Vec<(usize, Uuid)>>>,
+}
+
+fn create_pool(size: usize) -> TestPool {
+    let links: Vec<_> = (0..size).map(|_| Arc::new(AtomicBool::new(false))).collect();
+    let connects: Vec<_> = (0..size).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+    let submissions = Arc::new(Mutex::new(Vec::new()));
+    let next_id = AtomicUsize::new(0);
+    
+    let factory = {
+        let links = links.clone();
+        let connects = connects.clone();
+        let submissions = submissions.clone();
+        move || {
+            let id = next_id.fetch_add(1, Ordering::SeqCst);
+            TestConnection {
+                id,
+                connected: links[id].clone(),
+                connects: connects[id].clone(),
+                submissions: submissions.clone(),
+            }
+        }
+    };
+    
+    let config = ExchangeConfig {
+        name: "Pooled Exchange".to_string(),
+        exchange_type: ExchangeType::Crypto,
+        api_url: "https://api.example.com".to_string(),
+        api_key: Some("test_key".to_string()),
+        api_secret: Some("test_secret".to_string()),
+        additional_params: HashMap::new(),
+    };
+    
+    TestPool {
+        pool: ConnectionPool::new(factory, size, config),
+        links,
+        connects,
+        submissions,
+    }
+}
+
+fn create_test_order() -> Order {
+    Order {
+        order_type: OrderType::Market,
+        price: None,
+        time_in_force: TimeInForce::ImmediateOrCancel,
+        exchange: "Pooled Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+fn connections_used(submissions: &Mutex<Vec<(usize, Uuid)>>) -> Vec<usize> {
+    submissions.lock().unwrap().iter().map(|(id, _)| *id).collect()
+}
+
+#[tokio::test]
+async fn test_pool_connects_all_connections() {
+    let mut test_pool = create_pool(3);
+    assert_eq!(test_pool.pool.size(), 3);
+    assert!(!test_pool.pool.is_connected());
+    
+    test_pool.pool.connect().await.unwrap();
+    
+    assert!(test_pool.pool.is_connected());
+    assert_eq!(test_pool.pool.healthy_count(), 3);
+    assert!(test_pool.links.iter().all(|link| link.load(Ordering::SeqCst)));
+    assert_eq!(test_pool.pool.name(), "Pooled Exchange");
+}
+
+#[tokio::test]
+async fn test_submissions_round_robin_across_connections() {
+    let mut test_pool = create_pool(3);
+    test_pool.pool.connect().await.unwrap();
+    
+    for _ in 0..6 {
+        test_pool.pool.submit_order(create_test_order()).await.unwrap();
+    }
+    
+    assert_eq!(connections_used(&test_pool.submissions), vec![0, 1, 2, 0, 1, 2]);
+}
+
+#[tokio::test]
+async fn test_dropped_connection_is_skipped_until_reconnected() {
+    let mut test_pool = create_pool(2);
+    test_pool.pool.connect().await.unwrap();
+    
+    // Connection 0 drops; the next submission to it fails and marks it unhealthy
+    test_pool.links[0].store(false, Ordering::SeqCst);
+    assert!(test_pool.pool.submit_order(create_test_order()).await.is_err());
+    assert_eq!(test_pool.pool.healthy_count(), 1);
+    
+    for _ in 0..3 {
+        test_pool.pool.submit_order(create_test_order()).await.unwrap();
+    }
+    assert_eq!(connections_used(&test_pool.submissions), vec![1, 1, 1]);
+    
+    // A health check reconnects it and it rejoins the rotation
+    test_pool.pool.check_health().await;
+    assert_eq!(test_pool.pool.healthy_count(), 2);
+    assert_eq!(test_pool.connects[0].load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_background_health_check_reconnects() {
+    let mut test_pool = create_pool(2);
+    test_pool.pool = test_pool.pool.with_health_check_interval(Duration::from_millis(20));
+    test_pool.pool.connect().await.unwrap();
+    
+    test_pool.links[1].store(false, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    
+    assert!(test_pool.links[1].load(Ordering::SeqCst));
+    assert!(test_pool.connects[1].load(Ordering::SeqCst) >= 2);
+    
+    // Disconnecting stops the health check, so connections stay down
+    test_pool.pool.disconnect().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert!(!test_pool.pool.is_connected());
+    assert!(test_pool.links.iter().all(|link| !link.load(Ordering::SeqCst)));
+}
+
+#[tokio::test]
+async fn test_order_calls_go_to_submitting_connection() {
+    let mut test_pool = create_pool(3);
+    test_pool.pool.connect().await.unwrap();
+    
+    let order = create_test_order();
+    let order_id = order.id;
+    test_pool.pool.submit_order(create_test_order()).await.unwrap();
+    test_pool.pool.submit_order(order).await.unwrap();
+    
+    let status = test_pool.pool.get_order_status(order_id).await.unwrap();
+    assert_eq!(status.exchange_order_id.as_deref(), Some("conn-1"));
+    assert!(test_pool.pool.cancel_order(order_id).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_router_accepts_connection_pool() {
+    let mut test_pool = create_pool(2);
+    test_pool.pool.connect().await.unwrap();
+    
+    let router = OrderRouter::new();
+    router.register_exchange(Box::new(test_pool.pool)).await.unwrap();
+    assert_eq!(router.get_supported_exchanges().await, vec!["Pooled Exchange".to_string()]);
+    
+    router.submit_order(create_test_order()).await.unwrap();
+    router.submit_order(create_test_order()).await.unwrap();
+    assert_eq!(connections_used(&test_pool.submissions), vec![0, 1]);
+}
@@ -0,0 +1,199 @@
+use arb_platform::error::TradingError;
+use arb_platform::market_data::{DataSource, DataSourceType, MarketDataManager, DEFAULT_SOURCE_CONNECT_TIMEOUT};
+
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Source whose connect and subscribe calls fail on demand. Clones share the
+// connection flag so tests can observe a source after handing it to the manager.
+#[derive(Clone)]
+struct FlakySource {
+    name: String,
+    source_type: Arc<DataSourceType>,
+    connected: Arc<Mutex<bool>>,
+    connect_error: Option<String>,
+    subscribe_error: Option<String>,
+    connect_delay: Duration,
+}
+
+impl FlakySource {
+    fn healthy(name: &str) -> Self {
+        FlakySource {
+            name: name.to_string(),
+            source_type: Arc::new(DataSourceType::CryptoExchange(name.to_string())),
+            connected: Arc::new(Mutex::new(false)),
+            connect_error: None,
+            subscribe_error: None,
+            connect_delay: Duration::ZERO,
+        }
+    }
+    
+    fn unreachable(name: &str) -> Self {
+        FlakySource { connect_error: Some("connection refused".to_string()), ..Self::healthy(name) }
+    }
+    
+    fn rejecting_subscriptions(name: &str) -> Self {
+        FlakySource { subscribe_error: Some("subscription limit reached".to_string()), ..Self::healthy(name) }
+    }
+    
+    fn slow(name: &str, connect_delay: Duration) -> Self {
+        FlakySource { connect_delay, ..Self::healthy(name) }
+    }
+}
+
+#[async_trait]
+impl DataSource for FlakySource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn source_type(&self) -> &DataSourceType {
+        &self.source_type
+    }
+    
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        tokio::time::sleep(self.connect_delay).await;
+        if let Some(e) = &self.connect_error {
+            return Err(TradingError::Exchange(e.clone()));
+        }
+        *self.connected.lock().unwrap() = true;
+        Ok(())
+    }
+    
+    fn disconnect(&mut self) -> Result<(), String> {
+        *self.connected.lock().unwrap() = false;
+        Ok(())
+    }
+    
+    fn is_connected(&self) -> bool {
+        *self.connected.lock().unwrap()
+    }
+    
+    async fn subscribe(&mut self, _symbols: &[String]) -> Result<(), String> {
+        match &self.subscribe_error {
+            Some(e) if self.is_connected() => Err(e.clone()),
+            _ => Ok(()),
+        }
+    }
+    
+    async fn unsubscribe(&mut self, _symbols: &[String]) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+// A manager with a healthy Alpha, an unreachable Beta and a Gamma that
+// connects but rejects its saved BTC/USD subscription
+async fn mixed_manager() -> (MarketDataManager, [FlakySource; 3]) {
+    let sources = [
+        FlakySource::healthy("Alpha"),
+        FlakySource::unreachable("Beta"),
+        FlakySource::rejecting_subscriptions("Gamma"),
+    ];
+    let mut manager = MarketDataManager::new();
+    for source in &sources {
+        manager.add_data_source(Box::new(source.clone())).await.unwrap();
+    }
+    // Recorded while disconnected, so only restored on connect
+    manager.subscribe_to_symbols("Gamma", &["BTC/USD".to_string()]).await.unwrap();
+    (manager, sources)
+}
+
+#[tokio::test]
+async fn test_connect_all_sources_reports_each_source() {
+    let (mut manager, [alpha, beta, gamma]) = mixed_manager().await;
+    
+    let results = manager.connect_all_sources().await;
+    let names: Vec<&str> = results.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["Alpha", "Beta", "Gamma"]);
+    
+    let failed: Vec<&str> = results.iter()
+        .filter(|(_, result)| result.is_err())
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(failed, vec!["Beta", "Gamma"]);
+    assert_eq!(results[1].1, Err(TradingError::Exchange("connection refused".to_string())));
+    assert_eq!(results[2].1, Err(TradingError::Exchange("subscription limit reached".to_string())));
+    
+    // Failed sources are left disconnected, not half connected
+    assert!(alpha.is_connected());
+    assert!(!beta.is_connected());
+    assert!(!gamma.is_connected());
+}
+
+#[tokio::test]
+async fn test_strict_connect_lists_failures_and_rolls_back() {
+    let (mut manager, [alpha, beta, gamma]) = mixed_manager().await;
+    
+    let error = manager.connect_all_sources_strict().await.unwrap_err().to_string();
+    assert!(error.contains("Beta (connection refused)"), "{}", error);
+    assert!(error.contains("Gamma (subscription limit reached)"), "{}", error);
+    assert!(!error.contains("Alpha"), "{}", error);
+    
+    assert!(!alpha.is_connected());
+    assert!(!beta.is_connected());
+    assert!(!gamma.is_connected());
+}
+
+#[tokio::test]
+async fn test_strict_connect_succeeds_when_every_source_connects() {
+    let alpha = FlakySource::healthy("Alpha");
+    let beta = FlakySource::healthy("Beta");
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(alpha.clone())).await.unwrap();
+    manager.add_data_source(Box::new(beta.clone())).await.unwrap();
+    
+    assert!(manager.connect_all_sources_strict().await.is_ok());
+    assert!(alpha.is_connected());
+    assert!(beta.is_connected());
+}
+
+#[tokio::test]
+async fn test_subscribe_names_sources_that_fail() {
+    let healthy = FlakySource::healthy("Alpha");
+    let rejecting = FlakySource::rejecting_subscriptions("Gamma");
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(healthy)).await.unwrap();
+    manager.add_data_source(Box::new(rejecting)).await.unwrap();
+    manager.connect_all_sources().await;
+    
+    let error = manager.subscribe(&["BTC/USD".to_string()]).await.unwrap_err();
+    assert!(error.contains("Gamma (subscription limit reached)"), "{}", error);
+    assert!(!error.contains("Alpha"), "{}", error);
+    assert_eq!(manager.get_symbol_sources("BTC/USD"), vec!["Alpha".to_string()]);
+}
+
+#[tokio::test]
+async fn test_sources_connect_concurrently() {
+    let sources: Vec<FlakySource> = ["Alpha", "Beta", "Gamma"].iter()
+        .map(|name| FlakySource::slow(name, Duration::from_millis(200)))
+        .collect();
+    let mut manager = MarketDataManager::new();
+    for source in &sources {
+        manager.add_data_source(Box::new(source.clone())).await.unwrap();
+    }
+    
+    // Three 200ms connects side by side, not one after another
+    let started = Instant::now();
+    let results = manager.connect_all_sources().await;
+    assert!(started.elapsed() < Duration::from_millis(500), "{:?}", started.elapsed());
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+    assert!(sources.iter().all(|source| source.is_connected()));
+}
+
+#[tokio::test]
+async fn test_source_that_does_not_connect_in_time_fails() {
+    let prompt = FlakySource::healthy("Alpha");
+    let stuck = FlakySource::slow("Beta", Duration::from_secs(60));
+    let mut manager = MarketDataManager::new();
+    assert_eq!(manager.connect_timeout(), DEFAULT_SOURCE_CONNECT_TIMEOUT);
+    manager.set_connect_timeout(Duration::from_millis(50));
+    manager.add_data_source(Box::new(prompt.clone())).await.unwrap();
+    manager.add_data_source(Box::new(stuck.clone())).await.unwrap();
+    
+    let results = manager.connect_all_sources().await;
+    assert_eq!(results[0].1, Ok(()));
+    assert!(matches!(&results[1].1, Err(TradingError::Unavailable(e)) if e.contains("did not connect")), "{:?}", results[1].1);
+    assert!(prompt.is_connected());
+    assert!(!stuck.is_connected());
+}
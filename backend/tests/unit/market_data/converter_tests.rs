@@ -0,0 +1,61 @@
+use arb_platform::market_data::{split_symbol, MarketDataManager, PriceConverter};
+use arb_platform::strategy::{AssetData, AssetType};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+
+async fn converter_with_prices(prices: &[(&str, f64)]) -> PriceConverter {
+    let manager = MarketDataManager::new();
+    {
+        let current_data = manager.get_current_data();
+        let mut data = current_data.write().await;
+        for (symbol, price) in prices {
+            data.asset_data.insert(symbol.to_string(), AssetData {
+                symbol: symbol.to_string(),
+                asset_type: AssetType::Crypto,
+                price: Price::from(*price),
+                volume: 0.0,
+                bid: Price::from(*price),
+                ask: Price::from(*price),
+                tick_size: None,
+                lot_size: None,
+                exchange: "Simulated".to_string(),
+                last_update: Utc::now(),
+            });
+        }
+    }
+    manager.get_price_converter()
+}
+
+#[test]
+fn test_split_symbol() {
+    assert_eq!(split_symbol("BTC/USD"), Some(("BTC", "USD")));
+    assert_eq!(split_symbol("AAPL"), None);
+    assert_eq!(split_symbol("BTC/"), None);
+}
+
+#[tokio::test]
+async fn test_convert_btc_to_usd_at_market_price() {
+    let converter = converter_with_prices(&[("BTC/USD", 50000.0)]).await;
+
+    assert_eq!(converter.convert(1.5, "BTC", "USD").await, Some(75000.0));
+    assert_eq!(converter.convert(25000.0, "USD", "BTC").await, Some(0.5));
+    assert_eq!(converter.convert(42.0, "USD", "USD").await, Some(42.0));
+}
+
+#[tokio::test]
+async fn test_convert_through_shared_currency() {
+    let converter = converter_with_prices(&[("BTC/USD", 50000.0), ("ETH/USD", 2500.0)]).await;
+
+    let btc = converter.convert(10.0, "ETH", "BTC").await.unwrap();
+    assert!((btc - 0.5).abs() < 1e-12);
+}
+
+#[tokio::test]
+async fn test_convert_without_rate_is_none() {
+    let converter = converter_with_prices(&[("BTC/USD", 50000.0), ("SOL/EUR", 0.0)]).await;
+
+    assert_eq!(converter.convert(1.0, "DOGE", "USD").await, None);
+    assert_eq!(converter.convert(1.0, "SOL", "EUR").await, None);
+    assert_eq!(converter.rate("BTC", "EUR").await, None);
+}
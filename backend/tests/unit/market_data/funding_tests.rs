@@ -0,0 +1,198 @@
+use arb_platform::channel::{event_channel, BackpressurePolicy, ChannelConfig, EventReceiver};
+use arb_platform::market_data::{DataSource, FundingRate, FundingRateDataSource, FundingRateMonitor, MarketDataManager, MarketEvent, DEFAULT_FUNDING_POLL_INTERVAL};
+use arb_platform::market_data::funding::{parse_funding_rates, FundingRateFetcher, FUNDING_PERIODS_PER_YEAR};
+use arb_platform::strategy::TradeDirection;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+const RATES: &str = r#"[
+    {"symbol": "BTC-PERP", "rate": 0.0001, "next_funding": "2026-01-01T08:00:00Z"},
+    {"symbol": "ETH-PERP", "rate": -0.0002, "next_funding": "2026-01-01T08:00:00Z"}
+]"#;
+
+/// Fetcher returning the same body on every poll, counting the polls
+struct CannedFetcher {
+    body: Result<String, String>,
+    polls: AtomicUsize,
+}
+
+impl CannedFetcher {
+    fn new(body: Result<&str, &str>) -> Arc<Self> {
+        Arc::new(CannedFetcher {
+            body: body.map(str::to_string).map_err(str::to_string),
+            polls: AtomicUsize::new(0),
+        })
+    }
+}
+
+#[async_trait]
+impl FundingRateFetcher for CannedFetcher {
+    async fn fetch(&self, _url: &str) -> Result<String, String> {
+        self.polls.fetch_add(1, Ordering::SeqCst);
+        self.body.clone()
+    }
+}
+
+fn next_funding() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 8, 0, 0).unwrap()
+}
+
+fn create_source(fetcher: Arc<CannedFetcher>) -> (FundingRateDataSource, EventReceiver<MarketEvent>) {
+    let (sender, receiver) = event_channel(ChannelConfig::new(100, BackpressurePolicy::Block));
+    let source = FundingRateDataSource::with_fetcher("Perp Funding", "https://funding.example.com", sender, fetcher);
+    (source, receiver)
+}
+
+async fn next_symbol(events: &mut EventReceiver<MarketEvent>) -> String {
+    match tokio::time::timeout(Duration::from_secs(1), events.recv()).await {
+        Ok(Some(MarketEvent::FundingRate { symbol, .. })) => symbol,
+        other => panic!("expected a funding rate event, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_funding_rate_is_annualized_over_three_daily_payments() {
+    let rate = FundingRate::new("BTC-PERP", 0.0001, next_funding());
+
+    assert_eq!(FUNDING_PERIODS_PER_YEAR, 1095.0);
+    assert!((rate.annualized_rate - 0.1095).abs() < 1e-12);
+}
+
+#[test]
+fn test_funding_payment_follows_the_rate_sign() {
+    let monitor = FundingRateMonitor::new();
+    monitor.update("BTC-PERP", 0.0001, next_funding());
+    monitor.update("ETH-PERP", -0.0002, next_funding());
+
+    // Positive funding: longs pay shorts
+    assert!((monitor.funding_payment("BTC-PERP", TradeDirection::Buy, 10_000.0) + 1.0).abs() < 1e-9);
+    assert!((monitor.funding_payment("BTC-PERP", TradeDirection::Sell, 10_000.0) - 1.0).abs() < 1e-9);
+    // Negative funding: shorts pay longs
+    assert!((monitor.funding_payment("ETH-PERP", TradeDirection::Buy, 10_000.0) - 2.0).abs() < 1e-9);
+    assert!((monitor.funding_payment("ETH-PERP", TradeDirection::Sell, 10_000.0) + 2.0).abs() < 1e-9);
+    assert_eq!(monitor.funding_payment("SOL-PERP", TradeDirection::Buy, 10_000.0), 0.0);
+}
+
+#[test]
+fn test_time_to_next_funding_stops_at_zero() {
+    let rate = FundingRate::new("BTC-PERP", 0.0001, next_funding());
+
+    assert_eq!(rate.time_to_next_funding(next_funding() - ChronoDuration::minutes(90)), ChronoDuration::minutes(90));
+    assert_eq!(rate.time_to_next_funding(next_funding() + ChronoDuration::minutes(1)), ChronoDuration::zero());
+}
+
+#[test]
+fn test_monitor_keeps_latest_rate_per_symbol() {
+    let monitor = FundingRateMonitor::new();
+    let shared = monitor.clone();
+
+    assert!(monitor.record_event(&MarketEvent::FundingRate {
+        symbol: "BTC-PERP".to_string(),
+        rate: 0.0001,
+        next_funding: next_funding(),
+        exchange: "Feed".to_string(),
+    }));
+    monitor.update("BTC-PERP", 0.0003, next_funding() + ChronoDuration::hours(8));
+    assert!(!monitor.record_event(&MarketEvent::SourceReconnected { source_name: "Feed".to_string() }));
+
+    let rate = shared.get("BTC-PERP").unwrap();
+    assert_eq!(rate.rate, 0.0003);
+    assert_eq!(rate.next_funding, next_funding() + ChronoDuration::hours(8));
+    assert_eq!(shared.all().len(), 1);
+    assert!(shared.get("ETH-PERP").is_none());
+}
+
+#[test]
+fn test_parse_funding_rates() {
+    let events = parse_funding_rates("Feed", RATES).unwrap();
+
+    assert_eq!(events.len(), 2);
+    match &events[1] {
+        MarketEvent::FundingRate { symbol, rate, next_funding: at, exchange } => {
+            assert_eq!(symbol, "ETH-PERP");
+            assert_eq!(exchange, "Feed");
+            assert_eq!(*rate, -0.0002);
+            assert_eq!(*at, next_funding());
+        },
+        other => panic!("expected a funding rate event, got {:?}", other),
+    }
+    assert!(parse_funding_rates("Feed", "{\"symbol\": \"BTC-PERP\"}").is_err());
+}
+
+#[test]
+fn test_source_polls_hourly_by_default() {
+    let (source, _events) = create_source(CannedFetcher::new(Ok(RATES)));
+
+    assert_eq!(source.poll_interval(), DEFAULT_FUNDING_POLL_INTERVAL);
+    assert_eq!(DEFAULT_FUNDING_POLL_INTERVAL, Duration::from_secs(3600));
+    assert!(!source.is_connected());
+}
+
+#[tokio::test]
+async fn test_source_forwards_subscribed_symbols_each_poll() {
+    let fetcher = CannedFetcher::new(Ok(RATES));
+    let (mut source, mut events) = create_source(fetcher.clone());
+    source.set_poll_interval(Duration::from_millis(20));
+    source.subscribe(&["ETH-PERP".to_string()]).await.unwrap();
+
+    source.connect().await.unwrap();
+    assert!(source.is_connected());
+    assert!(source.connect().await.is_err());
+
+    assert_eq!(next_symbol(&mut events).await, "ETH-PERP");
+    assert_eq!(next_symbol(&mut events).await, "ETH-PERP");
+    assert!(fetcher.polls.load(Ordering::SeqCst) >= 2);
+
+    source.disconnect().unwrap();
+    assert!(!source.is_connected());
+}
+
+#[tokio::test]
+async fn test_source_forwards_every_symbol_without_subscriptions() {
+    let (mut source, mut events) = create_source(CannedFetcher::new(Ok(RATES)));
+    source.connect().await.unwrap();
+
+    assert_eq!(next_symbol(&mut events).await, "BTC-PERP");
+    assert_eq!(next_symbol(&mut events).await, "ETH-PERP");
+}
+
+#[tokio::test]
+async fn test_source_keeps_polling_after_a_failed_fetch() {
+    let fetcher = CannedFetcher::new(Err("503 Service Unavailable"));
+    let (mut source, mut events) = create_source(fetcher.clone());
+    source.set_poll_interval(Duration::from_millis(10));
+    source.connect().await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(fetcher.polls.load(Ordering::SeqCst) >= 2);
+    assert!(source.is_connected());
+    assert!(events.try_recv().is_none());
+}
+
+#[tokio::test]
+async fn test_manager_updates_funding_monitor_from_events() {
+    let mut manager = MarketDataManager::new();
+    let monitor = manager.get_funding_monitor();
+    manager.start_processing().await.unwrap();
+
+    manager.get_event_sender().send(MarketEvent::FundingRate {
+        symbol: "BTC-PERP".to_string(),
+        rate: 0.0001,
+        next_funding: next_funding(),
+        exchange: "Feed".to_string(),
+    }).await.unwrap();
+
+    for _ in 0..100 {
+        if monitor.get("BTC-PERP").is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let rate = monitor.get("BTC-PERP").expect("funding rate recorded");
+    assert_eq!(rate.rate, 0.0001);
+    assert!((rate.annualized_rate - 0.1095).abs() < 1e-12);
+}
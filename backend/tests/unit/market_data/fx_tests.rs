@@ -0,0 +1,74 @@
+use arb_platform::error::TradingError;
+use arb_platform::exchange::AccountBalance;
+use arb_platform::market_data::{FxRateProvider, MarketDataManager, StaticFxProvider};
+use arb_platform::strategy::{AssetData, AssetType};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+
+async fn manager_with_prices(prices: &[(&str, f64)]) -> MarketDataManager {
+    let manager = MarketDataManager::new();
+    {
+        let current_data = manager.get_current_data();
+        let mut data = current_data.write().await;
+        for (symbol, price) in prices {
+            data.asset_data.insert(symbol.to_string(), AssetData {
+                symbol: symbol.to_string(),
+                asset_type: AssetType::Forex,
+                price: Price::from(*price),
+                volume: 0.0,
+                bid: Price::from(*price),
+                ask: Price::from(*price),
+                tick_size: None,
+                lot_size: None,
+                exchange: "Simulated".to_string(),
+                last_update: Utc::now(),
+            });
+        }
+    }
+    manager
+}
+
+fn balance(total: f64, currency: &str, additional: &[(&str, f64)]) -> AccountBalance {
+    AccountBalance {
+        total,
+        available: total,
+        currency: currency.to_string(),
+        additional_balances: additional.iter().map(|(currency, amount)| (currency.to_string(), *amount)).collect(),
+        timestamp: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_static_rates_answer_both_directions() {
+    let fx = StaticFxProvider::new().with_rate("EUR", "USD", 1.25);
+
+    assert_eq!(fx.get_rate("EUR", "USD").await, Ok(1.25));
+    assert_eq!(fx.get_rate("USD", "EUR").await, Ok(0.8));
+    assert_eq!(fx.get_rate("GBP", "GBP").await, Ok(1.0));
+    assert!(matches!(fx.get_rate("GBP", "USD").await, Err(TradingError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_market_rates_from_direct_and_inverse_pairs() {
+    let manager = manager_with_prices(&[("EUR/USD", 1.25), ("USD/JPY", 150.0)]).await;
+    let fx = manager.get_fx_provider();
+
+    assert_eq!(fx.get_rate("EUR", "USD").await, Ok(1.25));
+    assert_eq!(fx.get_rate("JPY", "USD").await, Ok(1.0 / 150.0));
+    assert!(matches!(fx.get_rate("GBP", "USD").await, Err(TradingError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_balance_total_in_base_currency() {
+    let fx = StaticFxProvider::new()
+        .with_rate("EUR", "USD", 1.25)
+        .with_rate("BTC", "USD", 50000.0);
+
+    let eur = balance(1000.0, "EUR", &[("BTC", 0.5), ("USD", 100.0)]);
+    assert_eq!(eur.total_in_base_currency("USD", &fx).await, Ok(1250.0 + 25000.0 + 100.0));
+
+    // A single currency without a rate makes the total unknown
+    let unpriced = balance(1000.0, "USD", &[("GBP", 10.0)]);
+    assert!(matches!(unpriced.total_in_base_currency("USD", &fx).await, Err(TradingError::NotFound(_))));
+}
@@ -0,0 +1,226 @@
+use arb_platform::error::TradingError;
+use arb_platform::market_data::{
+    DataSource, DataSourceType, HealthCheckConfig, MarketDataManager, MarketEvent, SourceHealthMonitor,
+    DEFAULT_HEALTH_CHECK_INTERVAL, DEFAULT_MAX_SOURCE_SILENCE,
+};
+
+use async_trait::async_trait;
+use chrono::{Duration as ChronoDuration, TimeZone, Utc};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// Source that only sends what the test pushes through the manager's event
+// channel. Clones share state so tests can count connects after handing it over.
+#[derive(Clone)]
+struct QuietSource {
+    name: String,
+    source_type: Arc<DataSourceType>,
+    connected: Arc<Mutex<bool>>,
+    connects: Arc<AtomicU32>,
+    subscribed: Arc<Mutex<Vec<String>>>,
+}
+
+impl QuietSource {
+    fn new(name: &str) -> Self {
+        QuietSource {
+            name: name.to_string(),
+            source_type: Arc::new(DataSourceType::CryptoExchange(name.to_string())),
+            connected: Arc::new(Mutex::new(false)),
+            connects: Arc::new(AtomicU32::new(0)),
+            subscribed: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn connects(&self) -> u32 {
+        self.connects.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl DataSource for QuietSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn source_type(&self) -> &DataSourceType {
+        &self.source_type
+    }
+
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        self.connects.fetch_add(1, Ordering::SeqCst);
+        *self.connected.lock().unwrap() = true;
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> Result<(), String> {
+        *self.connected.lock().unwrap() = false;
+        self.subscribed.lock().unwrap().clear();
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        *self.connected.lock().unwrap()
+    }
+
+    async fn subscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        self.subscribed.lock().unwrap().extend(symbols.iter().cloned());
+        Ok(())
+    }
+
+    async fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        self.subscribed.lock().unwrap().retain(|symbol| !symbols.contains(symbol));
+        Ok(())
+    }
+}
+
+fn price_update(exchange: &str) -> MarketEvent {
+    MarketEvent::PriceUpdate {
+        symbol: "BTC-USD".to_string(),
+        price: 50000.0,
+        volume: None,
+        bid: None,
+        ask: None,
+        exchange: exchange.to_string(),
+        timestamp: Utc::now(),
+    }
+}
+
+// Poll until the condition holds, for up to two seconds
+async fn wait_for(mut condition: impl FnMut() -> bool) -> bool {
+    for _ in 0..200 {
+        if condition() {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    condition()
+}
+
+#[test]
+fn test_health_check_config_defaults() {
+    let config = HealthCheckConfig::default();
+
+    assert_eq!(config.check_interval, DEFAULT_HEALTH_CHECK_INTERVAL);
+    assert_eq!(config.max_silence, DEFAULT_MAX_SOURCE_SILENCE);
+    assert!(config.auto_reconnect);
+    assert!(!config.with_auto_reconnect(false).auto_reconnect);
+}
+
+#[test]
+fn test_events_name_their_source() {
+    assert_eq!(price_update("Binance").source(), Some("Binance"));
+    assert_eq!(MarketEvent::SourceReconnected { source_name: "Feed".to_string() }.source(), Some("Feed"));
+    assert_eq!(MarketEvent::FundingRate {
+        symbol: "BTC-PERP".to_string(),
+        rate: 0.0001,
+        next_funding: Utc::now(),
+        exchange: "Perps".to_string(),
+    }.source(), Some("Perps"));
+}
+
+#[test]
+fn test_monitor_flags_silent_sources_once_and_recovers_on_event() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let max_silence = ChronoDuration::seconds(30);
+    let monitor = SourceHealthMonitor::new();
+    let mut changes = monitor.subscribe();
+    monitor.watch("Quiet", start);
+    monitor.watch("Busy", start);
+
+    assert!(monitor.record_event("Busy", start + ChronoDuration::seconds(20)));
+    assert!(!monitor.record_event("Unknown", start));
+    assert!(monitor.silent_sources(start + ChronoDuration::seconds(29), max_silence).is_empty());
+    assert_eq!(monitor.silent_sources(start + ChronoDuration::seconds(30), max_silence), vec!["Quiet"]);
+    assert_eq!(monitor.silent_sources(start + ChronoDuration::seconds(31), max_silence), vec!["Quiet"]);
+
+    let change = changes.try_recv().unwrap();
+    assert_eq!(change.source_name, "Quiet");
+    assert!(!change.healthy);
+    assert!(changes.try_recv().is_err()); // Still silent is not a change
+
+    monitor.record_reconnect("Quiet", Some("refused".to_string()), start + ChronoDuration::seconds(31));
+    let health = monitor.snapshot()["Quiet"].clone();
+    assert!(!health.healthy);
+    assert_eq!(health.reconnect_attempts, 1);
+    assert_eq!(health.last_error.as_deref(), Some("refused"));
+    // Retried only once its silence runs out again
+    assert!(!monitor.silent_sources(start + ChronoDuration::seconds(40), max_silence).contains(&"Quiet".to_string()));
+
+    monitor.record_event("Quiet", start + ChronoDuration::seconds(45));
+    let health = monitor.snapshot()["Quiet"].clone();
+    assert!(health.healthy);
+    assert_eq!(health.reconnect_attempts, 0);
+    assert_eq!(health.last_error, None);
+    assert_eq!(health.last_event, Some(start + ChronoDuration::seconds(45)));
+    assert!(changes.try_recv().unwrap().healthy);
+
+    monitor.unwatch("Quiet");
+    assert!(!monitor.snapshot().contains_key("Quiet"));
+}
+
+#[tokio::test]
+async fn test_silent_source_is_flagged_and_reconnected() {
+    let mut manager = MarketDataManager::new();
+    let source = QuietSource::new("Quiet");
+    manager.add_data_source(Box::new(source.clone())).await.unwrap();
+    manager.subscribe(&["BTC-USD".to_string()]).await.unwrap();
+    manager.connect_source("Quiet").await.unwrap();
+    manager.start_processing().await.unwrap();
+    let mut changes = manager.subscribe_source_health();
+
+    assert!(manager.get_source_health()["Quiet"].healthy);
+    manager.start_health_monitoring(HealthCheckConfig::new(Duration::from_millis(20), Duration::from_millis(100))).unwrap();
+    assert!(manager.start_health_monitoring(HealthCheckConfig::default()).is_err());
+
+    assert!(wait_for(|| source.connects() >= 2).await, "silent source was not reconnected");
+    let health = manager.get_source_health()["Quiet"].clone();
+    assert!(!health.healthy);
+    assert!(health.reconnect_attempts >= 1);
+    assert_eq!(health.last_error, None);
+    assert_eq!(*source.subscribed.lock().unwrap(), vec!["BTC-USD".to_string()]);
+
+    let change = changes.recv().await.unwrap();
+    assert_eq!(change.source_name, "Quiet");
+    assert!(!change.healthy);
+
+    manager.get_event_sender().send(price_update("Quiet")).await.unwrap();
+    assert!(wait_for(|| manager.get_source_health()["Quiet"].healthy).await, "source did not recover");
+    assert_eq!(manager.get_source_health()["Quiet"].reconnect_attempts, 0);
+
+    manager.shutdown().await.unwrap();
+    assert!(manager.get_source_health().is_empty());
+}
+
+#[tokio::test]
+async fn test_silent_source_is_left_alone_without_auto_reconnect() {
+    let mut manager = MarketDataManager::new();
+    let source = QuietSource::new("Quiet");
+    manager.add_data_source(Box::new(source.clone())).await.unwrap();
+    manager.connect_source("Quiet").await.unwrap();
+
+    let config = HealthCheckConfig::new(Duration::from_millis(20), Duration::from_millis(50)).with_auto_reconnect(false);
+    manager.start_health_monitoring(config).unwrap();
+
+    assert!(wait_for(|| !manager.get_source_health()["Quiet"].healthy).await, "silent source was not flagged");
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(source.connects(), 1);
+    assert_eq!(manager.get_source_health()["Quiet"].reconnect_attempts, 0);
+    assert!(source.is_connected());
+}
+
+#[tokio::test]
+async fn test_only_connected_sources_are_watched() {
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(QuietSource::new("Idle"))).await.unwrap();
+    let mut connected = QuietSource::new("Live");
+    connected.connect().await.unwrap();
+    manager.add_data_source(Box::new(connected)).await.unwrap();
+
+    let health = manager.get_source_health();
+    assert!(health.contains_key("Live"));
+    assert!(!health.contains_key("Idle"));
+
+    manager.remove_data_source("Live").await.unwrap();
+    assert!(manager.get_source_health().is_empty());
+}
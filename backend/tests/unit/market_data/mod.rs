@@ -1,2 +1,13 @@
 // Market data module tests
 pub mod mod_tests;
+pub mod order_book_tests;
+pub mod websocket_tests;
+pub mod subscription_tests;
+pub mod connection_tests;
+pub mod converter_tests;
+pub mod fx_tests;
+pub mod validator_tests;
+pub mod funding_tests;
+pub mod health_tests;
+pub mod replay_tests;
+pub mod recorder_tests;
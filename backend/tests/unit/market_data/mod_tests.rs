@@ -1,9 +1,12 @@
 use arb_platform::market_data::{
     MarketDataManager, DataSourceType, MarketEvent, DataSource
 };
+use arb_platform::error::TradingError;
 use arb_platform::exchange::MarketSnapshot;
+use arb_platform::models::Price;
 
-use chrono::Utc;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
 use tokio::test;
 
 // Create a mock data source for testing
@@ -14,6 +17,7 @@ struct MockDataSource {
     is_connected: bool,
 }
 
+#[async_trait]
 impl DataSource for MockDataSource {
     fn name(&self) -> &str {
         &self.name
@@ -23,7 +27,7 @@ impl DataSource for MockDataSource {
         &self.source_type
     }
     
-    fn connect(&mut self) -> Result<(), String> {
+    async fn connect(&mut self) -> Result<(), TradingError> {
         self.is_connected = true;
         Ok(())
     }
@@ -37,7 +41,7 @@ impl DataSource for MockDataSource {
         self.is_connected
     }
     
-    fn subscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+    async fn subscribe(&mut self, symbols: &[String]) -> Result<(), String> {
         for symbol in symbols {
             if !self.symbols.contains(symbol) {
                 self.symbols.push(symbol.clone());
@@ -46,7 +50,7 @@ impl DataSource for MockDataSource {
         Ok(())
     }
     
-    fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+    async fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
         self.symbols.retain(|s| !symbols.contains(s));
         Ok(())
     }
@@ -78,13 +82,15 @@ async fn test_market_data_update_creation() {
     let now = Utc::now();
     let _snapshot = MarketSnapshot {
         symbol: "BTC/USD".to_string(),
-        price: 35000.0,
+        price: Price::from(35000.0),
         volume: 10.5,
-        bid: 34990.0,
-        ask: 35010.0,
+        bid: Price::from(34990.0),
+        ask: Price::from(35010.0),
         bid_size: 1.5,
         ask_size: 2.0,
         timestamp: now,
+        bids: Vec::new(),
+        asks: Vec::new(),
     };
     
     let event = MarketEvent::PriceUpdate {
@@ -126,7 +132,7 @@ async fn test_add_data_source() {
     let mut manager = MarketDataManager::new();
     let source = create_test_data_source();
     
-    let result = manager.add_data_source(source);
+    let result = manager.add_data_source(source).await;
     assert!(result.is_ok());
 }
 
@@ -136,10 +142,10 @@ async fn test_add_duplicate_data_source() {
     let source1 = create_test_data_source();
     let source2 = create_test_data_source(); // Same name
     
-    let result1 = manager.add_data_source(source1);
+    let result1 = manager.add_data_source(source1).await;
     assert!(result1.is_ok());
     
-    let result2 = manager.add_data_source(source2);
+    let result2 = manager.add_data_source(source2).await;
     assert!(result2.is_err());
 }
 
@@ -149,9 +155,44 @@ async fn test_remove_data_source() {
     let source = create_test_data_source();
     let name = source.name().to_string();
     
-    let result = manager.add_data_source(source);
+    let result = manager.add_data_source(source).await;
     assert!(result.is_ok());
     
-    let remove_result = manager.remove_data_source(&name);
+    let remove_result = manager.remove_data_source(&name).await;
     assert!(remove_result.is_ok());
-} 
\ No newline at end of file
+}
+
+fn price_update(symbol: &str, timestamp: chrono::DateTime<Utc>) -> MarketEvent {
+    MarketEvent::PriceUpdate {
+        symbol: symbol.to_string(),
+        price: 100.0,
+        volume: None,
+        bid: None,
+        ask: None,
+        exchange: "Test".to_string(),
+        timestamp,
+    }
+}
+
+#[test]
+async fn test_get_stale_symbols() {
+    let mut manager = MarketDataManager::new();
+    manager.start_processing().await.unwrap();
+    
+    let sender = manager.get_event_sender();
+    sender.send(price_update("FROZEN", Utc::now() - Duration::minutes(5))).await.unwrap();
+    sender.send(price_update("LIVE", Utc::now())).await.unwrap();
+    
+    // Wait for the processing task to apply both updates
+    for _ in 0..100 {
+        if manager.get_current_data().read().await.asset_data.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    
+    assert_eq!(manager.get_stale_symbols(Duration::minutes(1)).await, vec!["FROZEN".to_string()]);
+    assert!(manager.get_stale_symbols(Duration::minutes(10)).await.is_empty());
+    
+    manager.shutdown().await.unwrap();
+}
@@ -0,0 +1,141 @@
+use arb_platform::market_data::{ImpactEstimate, MarketDataManager, MarketEvent, OrderBook, PriceLevel, TradeSide};
+
+use chrono::Utc;
+
+fn level(price: f64, quantity: f64) -> PriceLevel {
+    PriceLevel { price, quantity }
+}
+
+fn book_update(bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> MarketEvent {
+    MarketEvent::OrderBookUpdate {
+        symbol: "BTC/USD".to_string(),
+        bids,
+        asks,
+        exchange: "Test".to_string(),
+        timestamp: Utc::now(),
+    }
+}
+
+#[test]
+fn test_levels_are_sorted_best_first() {
+    let mut book = OrderBook::new("BTC/USD", "Test");
+    book.apply_update(&[(99.0, 1.0), (100.0, 2.0), (98.0, 3.0)], &[(102.0, 1.0), (101.0, 4.0)], Utc::now());
+    
+    assert_eq!(book.best_bid(), Some(level(100.0, 2.0)));
+    assert_eq!(book.best_ask(), Some(level(101.0, 4.0)));
+    assert_eq!(book.mid_price(), Some(100.5));
+    assert_eq!(book.spread(), Some(1.0));
+    
+    let depth = book.depth(2);
+    assert_eq!(depth.bids, vec![level(100.0, 2.0), level(99.0, 1.0)]);
+    assert_eq!(depth.asks, vec![level(101.0, 4.0), level(102.0, 1.0)]);
+    assert_eq!(book.volume_at_depth(2), (3.0, 5.0));
+}
+
+#[test]
+fn test_updates_replace_and_remove_levels() {
+    let mut book = OrderBook::new("BTC/USD", "Test");
+    book.apply_update(&[(100.0, 2.0), (99.0, 1.0)], &[(101.0, 4.0)], Utc::now());
+    
+    // Replace one bid, remove the other, and ignore an unpriceable level
+    book.apply_update(&[(100.0, 5.0), (99.0, 0.0), (f64::NAN, 1.0)], &[], Utc::now());
+    
+    assert_eq!(book.depth(10).bids, vec![level(100.0, 5.0)]);
+    assert_eq!(book.depth(10).asks, vec![level(101.0, 4.0)]);
+}
+
+#[test]
+fn test_empty_side_has_no_mid() {
+    let mut book = OrderBook::new("BTC/USD", "Test");
+    book.apply_update(&[(100.0, 1.0)], &[], Utc::now());
+    
+    assert_eq!(book.best_ask(), None);
+    assert_eq!(book.mid_price(), None);
+    assert_eq!(book.spread(), None);
+}
+
+#[tokio::test]
+async fn test_manager_merges_order_book_events() {
+    let mut manager = MarketDataManager::new();
+    manager.start_processing().await.unwrap();
+    assert!(manager.get_order_book("BTC/USD").is_none());
+    
+    let sender = manager.get_event_sender();
+    sender.send(book_update(vec![(100.0, 1.0), (99.0, 2.0)], vec![(101.0, 1.0)])).await.unwrap();
+    sender.send(book_update(vec![(100.0, 0.0)], vec![(101.5, 3.0)])).await.unwrap();
+    
+    // Wait for the processing task to apply both updates
+    for _ in 0..100 {
+        let applied = manager.get_order_book("BTC/USD")
+            .map(|book| book.read().unwrap().asks.len() == 2)
+            .unwrap_or(false);
+        if applied {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    
+    let book = manager.get_order_book("BTC/USD").unwrap().read().unwrap().clone();
+    assert_eq!(book.best_bid(), Some(level(99.0, 2.0)));
+    assert_eq!(book.depth(10).asks, vec![level(101.0, 1.0), level(101.5, 3.0)]);
+    
+    manager.shutdown().await.unwrap();
+}
+
+// Asks at 100 (1.0), 101 (2.0) and 103 (1.0); bids at 99 (2.0) and 98 (3.0)
+fn impact_book() -> OrderBook {
+    let mut book = OrderBook::new("BTC/USD", "Test");
+    book.apply_update(&[(99.0, 2.0), (98.0, 3.0)], &[(100.0, 1.0), (101.0, 2.0), (103.0, 1.0)], Utc::now());
+    book
+}
+
+#[test]
+fn test_impact_of_an_order_within_the_best_level() {
+    let estimate = impact_book().estimate_market_impact(TradeSide::Buy, 0.5);
+    
+    assert_eq!(estimate, ImpactEstimate {
+        expected_fill_price: 100.0,
+        slippage_bps: 0.0,
+        book_levels_consumed: 1,
+        is_fully_fillable: true,
+    });
+}
+
+#[test]
+fn test_impact_of_an_order_walking_several_levels() {
+    let book = impact_book();
+    
+    // 1.0 at 100 and 1.5 at 101
+    let buy = book.estimate_market_impact(TradeSide::Buy, 2.5);
+    assert!((buy.expected_fill_price - 100.6).abs() < 1e-9, "{:?}", buy);
+    assert!((buy.slippage_bps - 60.0).abs() < 1e-6, "{:?}", buy);
+    assert_eq!(buy.book_levels_consumed, 2);
+    assert!(buy.is_fully_fillable);
+    
+    // 2.0 at 99 and 2.0 at 98, measured down from the best bid
+    let sell = book.estimate_market_impact(TradeSide::Sell, 4.0);
+    assert!((sell.expected_fill_price - 98.5).abs() < 1e-9, "{:?}", sell);
+    assert!((sell.slippage_bps - 0.5 / 99.0 * 10_000.0).abs() < 1e-6, "{:?}", sell);
+    assert_eq!(sell.book_levels_consumed, 2);
+    assert!(sell.is_fully_fillable);
+}
+
+#[test]
+fn test_impact_of_an_order_larger_than_the_book() {
+    let book = impact_book();
+    
+    // Only the 4.0 on offer fills: 100 + 202 + 103 over 4
+    let estimate = book.estimate_market_impact(TradeSide::Buy, 10.0);
+    assert!((estimate.expected_fill_price - 101.25).abs() < 1e-9, "{:?}", estimate);
+    assert!((estimate.slippage_bps - 125.0).abs() < 1e-6, "{:?}", estimate);
+    assert_eq!(estimate.book_levels_consumed, 3);
+    assert!(!estimate.is_fully_fillable);
+    
+    let empty = OrderBook::new("BTC/USD", "Test").estimate_market_impact(TradeSide::Sell, 1.0);
+    assert_eq!(empty, ImpactEstimate {
+        expected_fill_price: 0.0,
+        slippage_bps: 0.0,
+        book_levels_consumed: 0,
+        is_fully_fillable: false,
+    });
+}
@@ -0,0 +1,143 @@
+use arb_platform::market_data::{MarketDataManager, MarketEvent, MarketEventRecorder, RecorderConfig, RotationPolicy, TradeSide};
+use arb_platform::market_data::replay::parse_replay_events;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::path::PathBuf;
+use tokio::sync::broadcast;
+
+fn at(seconds: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, 14, 30, 0).unwrap() + Duration::seconds(seconds)
+}
+
+fn price_update(symbol: &str, price: f64, timestamp: DateTime<Utc>) -> MarketEvent {
+    MarketEvent::PriceUpdate {
+        symbol: symbol.to_string(),
+        price,
+        volume: Some(2.0),
+        bid: None,
+        ask: None,
+        exchange: "Live".to_string(),
+        timestamp,
+    }
+}
+
+fn recording_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("recording-{}", uuid::Uuid::new_v4()))
+}
+
+// Events compared through their JSON, as MarketEvent has no PartialEq
+fn as_json(events: &[MarketEvent]) -> Vec<serde_json::Value> {
+    events.iter().map(|event| serde_json::to_value(event).unwrap()).collect()
+}
+
+async fn wait_for_recorded(recorder: &MarketEventRecorder, count: u64) {
+    for _ in 0..100 {
+        if recorder.recorded_events() >= count {
+            return;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    panic!("recorded {} of {} events", recorder.recorded_events(), count);
+}
+
+#[tokio::test]
+async fn test_recorder_captures_the_event_stream_in_order() {
+    let mut manager = MarketDataManager::new();
+    manager.start_processing().await.unwrap();
+    let directory = recording_dir();
+    let mut recorder = MarketEventRecorder::new(RecorderConfig::new(&directory));
+    recorder.start(manager.subscribe_events()).await.unwrap();
+    assert!(recorder.is_recording());
+    
+    let events = vec![
+        price_update("BTC/USD", 40000.0, at(0)),
+        MarketEvent::TradeExecution {
+            symbol: "BTC/USD".to_string(),
+            price: 40001.0,
+            volume: 0.5,
+            side: TradeSide::Buy,
+            exchange: "Live".to_string(),
+            timestamp: at(1),
+        },
+        MarketEvent::FundingRate {
+            symbol: "BTC-PERP".to_string(),
+            rate: 0.0001,
+            next_funding: at(3600),
+            exchange: "Live".to_string(),
+        },
+        price_update("ETH/USD", 2500.0, at(2)),
+        MarketEvent::SourceReconnected { source_name: "Live".to_string() },
+    ];
+    let sender = manager.get_event_sender();
+    for event in &events {
+        sender.send(event.clone()).await.unwrap();
+    }
+    wait_for_recorded(&recorder, events.len() as u64).await;
+    recorder.stop().await.unwrap();
+    assert!(!recorder.is_recording());
+    
+    let files = recorder.files();
+    assert_eq!(files.len(), 1);
+    let contents = std::fs::read_to_string(&files[0]).unwrap();
+    let lines: Vec<serde_json::Value> = contents.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(lines, as_json(&events));
+    
+    // What was recorded replays as the same events
+    assert_eq!(as_json(&parse_replay_events(&contents).unwrap()), as_json(&events));
+    assert_eq!(recorder.missed_events(), 0);
+    
+    manager.shutdown().await.unwrap();
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[tokio::test]
+async fn test_recorder_rotates_files_by_size() {
+    let (sender, receiver) = broadcast::channel(64);
+    let directory = recording_dir();
+    let config = RecorderConfig::new(&directory)
+        .with_file_prefix("btc")
+        .with_rotation(RotationPolicy::MaxBytes(400));
+    let mut recorder = MarketEventRecorder::new(config);
+    recorder.start(receiver).await.unwrap();
+    
+    let events: Vec<MarketEvent> = (0..10).map(|i| price_update("BTC/USD", 40000.0 + i as f64, at(i))).collect();
+    for event in &events {
+        sender.send(event.clone()).unwrap();
+    }
+    recorder.stop().await.unwrap();
+    
+    // Stopping still writes what was sent before it
+    assert_eq!(recorder.recorded_events(), 10);
+    let files = recorder.files();
+    assert!(files.len() > 1, "{:?}", files);
+    let mut replayed = Vec::new();
+    for file in &files {
+        assert!(file.file_name().unwrap().to_str().unwrap().starts_with("btc-"));
+        let contents = std::fs::read_to_string(file).unwrap();
+        assert!(contents.len() <= 400, "{} is {} bytes", file.display(), contents.len());
+        replayed.extend(parse_replay_events(&contents).unwrap());
+    }
+    assert_eq!(as_json(&replayed), as_json(&events));
+    
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[tokio::test]
+async fn test_recorder_rejects_bad_settings_and_second_start() {
+    let directory = recording_dir();
+    let (sender, receiver) = broadcast::channel::<MarketEvent>(8);
+    
+    let mut empty_files = MarketEventRecorder::new(RecorderConfig::new(&directory).with_rotation(RotationPolicy::MaxBytes(0)));
+    assert!(empty_files.start(sender.subscribe()).await.is_err());
+    let mut constant = MarketEventRecorder::new(RecorderConfig::new(&directory).with_rotation(RotationPolicy::Interval(std::time::Duration::ZERO)));
+    assert!(constant.start(sender.subscribe()).await.is_err());
+    assert!(!directory.exists());
+    
+    let mut recorder = MarketEventRecorder::new(RecorderConfig::new(&directory));
+    recorder.start(receiver).await.unwrap();
+    assert!(recorder.start(sender.subscribe()).await.is_err());
+    recorder.stop().await.unwrap();
+    assert_eq!(recorder.files().len(), 1);
+    
+    std::fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,124 @@
+use arb_platform::channel::{event_channel, BackpressurePolicy, ChannelConfig};
+use arb_platform::error::TradingError;
+use arb_platform::market_data::{DataSource, MarketDataManager, MarketEvent, ReplaySource, ReplaySpeed};
+use arb_platform::market_data::replay::parse_replay_events;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::path::PathBuf;
+
+fn at(seconds: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, 14, 30, 0).unwrap() + Duration::seconds(seconds)
+}
+
+fn price_update(symbol: &str, price: f64, timestamp: DateTime<Utc>) -> MarketEvent {
+    MarketEvent::PriceUpdate {
+        symbol: symbol.to_string(),
+        price,
+        volume: Some(1.0),
+        bid: Some(price - 0.5),
+        ask: Some(price + 0.5),
+        exchange: "Recorded".to_string(),
+        timestamp,
+    }
+}
+
+/// Write the events to a fresh JSON lines file
+fn write_recording(events: &[MarketEvent]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("replay-{}.jsonl", uuid::Uuid::new_v4()));
+    let lines: Vec<String> = events.iter().map(|event| serde_json::to_string(event).unwrap()).collect();
+    std::fs::write(&path, lines.join("\n")).unwrap();
+    path
+}
+
+#[tokio::test]
+async fn test_replay_at_max_speed_ends_in_recorded_state() {
+    let path = write_recording(&[
+        price_update("BTC/USD", 40000.0, at(0)),
+        price_update("ETH/USD", 2500.0, at(1)),
+        price_update("BTC/USD", 40100.0, at(60)),
+        price_update("ETH/USD", 2510.0, at(61)),
+        price_update("BTC/USD", 40050.0, at(3600)),
+    ]);
+    
+    let mut manager = MarketDataManager::new();
+    manager.start_processing().await.unwrap();
+    let source = ReplaySource::new("Replay", &path, manager.get_event_sender());
+    manager.add_data_source(Box::new(source)).await.unwrap();
+    manager.connect_source("Replay").await.unwrap();
+    
+    // An hour of recording arrives at once
+    let current_data = manager.get_current_data();
+    for _ in 0..100 {
+        if current_data.read().await.asset_data.get("BTC/USD").is_some_and(|asset| asset.last_update == at(3600)) {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    
+    {
+        let data = current_data.read().await;
+        assert_eq!(data.asset_data.len(), 2);
+        let btc = &data.asset_data["BTC/USD"];
+        assert_eq!(btc.price.to_f64(), 40050.0);
+        assert_eq!(btc.bid.to_f64(), 40049.5);
+        assert_eq!(btc.last_update, at(3600));
+        let eth = &data.asset_data["ETH/USD"];
+        assert_eq!(eth.price.to_f64(), 2510.0);
+        assert_eq!(eth.last_update, at(61));
+    }
+    
+    manager.shutdown().await.unwrap();
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn test_scaled_replay_keeps_recorded_spacing() {
+    let path = write_recording(&[
+        price_update("BTC/USD", 40000.0, at(0)),
+        price_update("BTC/USD", 40001.0, at(1)),
+    ]);
+    let (sender, mut receiver) = event_channel(ChannelConfig::new(10, BackpressurePolicy::Block));
+    
+    // A second apart in the recording, a fifth of a second at five times speed
+    let mut source = ReplaySource::new("Replay", &path, sender).with_speed(ReplaySpeed::Scaled(5.0));
+    source.connect().await.unwrap();
+    receiver.recv().await.unwrap();
+    let started = tokio::time::Instant::now();
+    receiver.recv().await.unwrap();
+    let gap = started.elapsed();
+    assert!(gap >= std::time::Duration::from_millis(180), "{:?}", gap);
+    assert!(gap < std::time::Duration::from_millis(900), "{:?}", gap);
+    
+    // Finished replays report themselves disconnected
+    for _ in 0..100 {
+        if !source.is_connected() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert!(!source.is_connected());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn test_replay_rejects_bad_recordings_and_speeds() {
+    let path = std::env::temp_dir().join(format!("replay-{}.jsonl", uuid::Uuid::new_v4()));
+    let (sender, _receiver) = event_channel(ChannelConfig::new(10, BackpressurePolicy::Block));
+    
+    let mut missing = ReplaySource::new("Replay", &path, sender.clone());
+    assert!(missing.connect().await.unwrap_err().to_string().contains("Failed to read replay file"));
+    
+    let mut stalled = ReplaySource::new("Replay", &path, sender).with_speed(ReplaySpeed::Scaled(0.0));
+    assert!(matches!(stalled.connect().await, Err(TradingError::Validation(_))));
+    assert!(!stalled.is_connected());
+}
+
+#[test]
+fn test_parse_replay_events_reports_the_bad_line() {
+    let good = serde_json::to_string(&price_update("BTC/USD", 40000.0, at(0))).unwrap();
+    let contents = format!("{}\n\n{}\n{{\"type\": \"price_update\"}}\n", good, good);
+    
+    assert!(parse_replay_events(&format!("{}\n\n{}\n", good, good)).unwrap().len() == 2);
+    let error = parse_replay_events(&contents).unwrap_err();
+    assert!(error.starts_with("Invalid market event on line 4"), "{}", error);
+}
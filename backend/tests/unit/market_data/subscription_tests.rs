@@ -0,0 +1,254 @@
+use arb_platform::error::TradingError;
+use arb_platform::market_data::{DataSource, DataSourceType, MarketDataManager, MarketEvent};
+
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// Source that loses its subscriptions whenever its connection drops, as a
+// plain exchange feed does. Clones share state so tests can observe a source
+// after handing it to the manager.
+#[derive(Clone)]
+struct ForgetfulSource {
+    name: String,
+    source_type: Arc<DataSourceType>,
+    connected: Arc<Mutex<bool>>,
+    subscribed: Arc<Mutex<Vec<String>>>,
+    requests: Arc<Mutex<Vec<Vec<String>>>>, // Symbols of each subscribe call, in order
+}
+
+impl ForgetfulSource {
+    fn new(name: &str) -> Self {
+        ForgetfulSource {
+            name: name.to_string(),
+            source_type: Arc::new(DataSourceType::CryptoExchange(name.to_string())),
+            connected: Arc::new(Mutex::new(false)),
+            subscribed: Arc::new(Mutex::new(Vec::new())),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+    
+    fn connected(name: &str) -> Self {
+        let source = Self::new(name);
+        *source.connected.lock().unwrap() = true;
+        source
+    }
+    
+    // The link drops and comes back without the manager's involvement
+    fn drop_connection(&self) {
+        self.subscribed.lock().unwrap().clear();
+    }
+    
+    fn requests(&self) -> Vec<Vec<String>> {
+        self.requests.lock().unwrap().clone()
+    }
+    
+    fn subscribed(&self) -> Vec<String> {
+        let mut symbols = self.subscribed.lock().unwrap().clone();
+        symbols.sort();
+        symbols
+    }
+}
+
+#[async_trait]
+impl DataSource for ForgetfulSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn source_type(&self) -> &DataSourceType {
+        &self.source_type
+    }
+    
+    async fn connect(&mut self) -> Result<(), TradingError> {
+        *self.connected.lock().unwrap() = true;
+        Ok(())
+    }
+    
+    fn disconnect(&mut self) -> Result<(), String> {
+        *self.connected.lock().unwrap() = false;
+        self.subscribed.lock().unwrap().clear();
+        Ok(())
+    }
+    
+    fn is_connected(&self) -> bool {
+        *self.connected.lock().unwrap()
+    }
+    
+    async fn subscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        if !self.is_connected() {
+            return Err(format!("{} is not connected", self.name));
+        }
+        self.requests.lock().unwrap().push(symbols.to_vec());
+        let mut subscribed = self.subscribed.lock().unwrap();
+        for symbol in symbols {
+            if !subscribed.contains(symbol) {
+                subscribed.push(symbol.clone());
+            }
+        }
+        Ok(())
+    }
+    
+    async fn unsubscribe(&mut self, symbols: &[String]) -> Result<(), String> {
+        self.subscribed.lock().unwrap().retain(|s| !symbols.contains(s));
+        Ok(())
+    }
+}
+
+fn symbols(names: &[&str]) -> Vec<String> {
+    names.iter().map(|s| s.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_subscriptions_are_recorded_per_source() {
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(ForgetfulSource::new("Alpha"))).await.unwrap();
+    manager.add_data_source(Box::new(ForgetfulSource::new("Beta"))).await.unwrap();
+    manager.connect_all_sources().await;
+    
+    manager.subscribe_to_symbols("Alpha", &symbols(&["ETH/USD", "BTC/USD"])).await.unwrap();
+    manager.subscribe_to_symbols("Alpha", &symbols(&["SOL/USD", "BTC/USD"])).await.unwrap();
+    manager.subscribe_to_symbols("Beta", &symbols(&["AAPL"])).await.unwrap();
+    manager.unsubscribe_from_symbols("Alpha", &symbols(&["ETH/USD"])).await.unwrap();
+    
+    assert_eq!(manager.get_subscriptions("Alpha"), symbols(&["BTC/USD", "SOL/USD"]));
+    assert_eq!(manager.get_subscriptions("Beta"), symbols(&["AAPL"]));
+    assert!(manager.get_subscriptions("Gamma").is_empty());
+    assert!(manager.subscribe_to_symbols("Gamma", &symbols(&["AAPL"])).await.is_err());
+}
+
+#[tokio::test]
+async fn test_failed_subscription_is_not_recorded() {
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(ForgetfulSource::new("Alpha"))).await.unwrap();
+    
+    assert!(manager.subscribe_to_symbols("Alpha", &symbols(&["BTC/USD"])).await.is_err());
+    assert!(manager.get_subscriptions("Alpha").is_empty());
+}
+
+#[tokio::test]
+async fn test_reconnect_restores_subscriptions() {
+    let source = ForgetfulSource::new("Alpha");
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(source.clone())).await.unwrap();
+    manager.connect_source("Alpha").await.unwrap();
+    manager.subscribe_to_symbols("Alpha", &symbols(&["BTC/USD", "ETH/USD"])).await.unwrap();
+    
+    manager.disconnect_all_sources().await;
+    assert!(source.subscribed().is_empty());
+    
+    manager.connect_source("Alpha").await.unwrap();
+    assert_eq!(source.subscribed(), symbols(&["BTC/USD", "ETH/USD"]));
+    
+    // Reconnecting everything restores them too
+    manager.disconnect_all_sources().await;
+    assert!(manager.connect_all_sources().await.iter().all(|(_, r)| r.is_ok()));
+    assert_eq!(source.subscribed(), symbols(&["BTC/USD", "ETH/USD"]));
+}
+
+#[tokio::test]
+async fn test_source_reconnected_event_restores_subscriptions() {
+    let source = ForgetfulSource::new("Alpha");
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(source.clone())).await.unwrap();
+    manager.connect_source("Alpha").await.unwrap();
+    manager.subscribe_to_symbols("Alpha", &symbols(&["BTC/USD", "ETH/USD"])).await.unwrap();
+    manager.start_processing().await.unwrap();
+    
+    source.drop_connection();
+    manager.get_event_sender()
+        .send(MarketEvent::SourceReconnected { source_name: "Alpha".to_string() })
+        .await
+        .unwrap();
+    
+    for _ in 0..100 {
+        if !source.subscribed().is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(source.subscribed(), symbols(&["BTC/USD", "ETH/USD"]));
+}
+
+#[tokio::test]
+async fn test_removing_source_forgets_subscriptions() {
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(ForgetfulSource::new("Alpha"))).await.unwrap();
+    manager.connect_source("Alpha").await.unwrap();
+    manager.subscribe_to_symbols("Alpha", &symbols(&["BTC/USD"])).await.unwrap();
+    
+    manager.remove_data_source("Alpha").await.unwrap();
+    assert!(manager.get_subscriptions("Alpha").is_empty());
+    assert!(manager.connect_source("Alpha").await.is_err());
+}
+
+#[tokio::test]
+async fn test_subscribe_covers_every_source() {
+    let alpha = ForgetfulSource::connected("Alpha");
+    let beta = ForgetfulSource::connected("Beta");
+    let gamma = ForgetfulSource::new("Gamma");
+    let mut manager = MarketDataManager::new();
+    for source in [&alpha, &beta, &gamma] {
+        manager.add_data_source(Box::new(source.clone())).await.unwrap();
+    }
+    
+    manager.subscribe(&symbols(&["ETH/USD", "BTC/USD"])).await.unwrap();
+    assert_eq!(alpha.subscribed(), symbols(&["BTC/USD", "ETH/USD"]));
+    assert_eq!(beta.subscribed(), symbols(&["BTC/USD", "ETH/USD"]));
+    assert_eq!(manager.subscribed_symbols(), symbols(&["BTC/USD", "ETH/USD"]));
+    assert_eq!(manager.get_symbol_sources("BTC/USD"), symbols(&["Alpha", "Beta", "Gamma"]));
+    
+    // Gamma was disconnected, so it picks the symbols up when it connects
+    assert!(gamma.subscribed().is_empty());
+    manager.connect_source("Gamma").await.unwrap();
+    assert_eq!(gamma.subscribed(), symbols(&["BTC/USD", "ETH/USD"]));
+}
+
+#[tokio::test]
+async fn test_subscribe_skips_symbols_a_source_already_has() {
+    let alpha = ForgetfulSource::connected("Alpha");
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(alpha.clone())).await.unwrap();
+    
+    manager.subscribe(&symbols(&["BTC/USD"])).await.unwrap();
+    manager.subscribe(&symbols(&["BTC/USD", "ETH/USD", "ETH/USD"])).await.unwrap();
+    manager.subscribe(&symbols(&["ETH/USD"])).await.unwrap();
+    
+    assert_eq!(alpha.requests(), vec![symbols(&["BTC/USD"]), symbols(&["ETH/USD"])]);
+}
+
+#[tokio::test]
+async fn test_unsubscribe_covers_every_source() {
+    let alpha = ForgetfulSource::connected("Alpha");
+    let beta = ForgetfulSource::connected("Beta");
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(alpha.clone())).await.unwrap();
+    manager.add_data_source(Box::new(beta.clone())).await.unwrap();
+    manager.subscribe(&symbols(&["BTC/USD", "ETH/USD"])).await.unwrap();
+    manager.subscribe_to_symbols("Beta", &symbols(&["AAPL"])).await.unwrap();
+    
+    manager.unsubscribe(&symbols(&["BTC/USD", "AAPL"])).await.unwrap();
+    assert_eq!(alpha.subscribed(), symbols(&["ETH/USD"]));
+    assert_eq!(beta.subscribed(), symbols(&["ETH/USD"]));
+    assert_eq!(manager.subscribed_symbols(), symbols(&["ETH/USD"]));
+    assert!(manager.get_symbol_sources("BTC/USD").is_empty());
+}
+
+#[tokio::test]
+async fn test_added_source_gets_known_symbols() {
+    let mut manager = MarketDataManager::new();
+    manager.add_data_source(Box::new(ForgetfulSource::connected("Alpha"))).await.unwrap();
+    manager.subscribe(&symbols(&["BTC/USD", "ETH/USD"])).await.unwrap();
+    
+    // Connected when added: subscribed straight away
+    let beta = ForgetfulSource::connected("Beta");
+    manager.add_data_source(Box::new(beta.clone())).await.unwrap();
+    assert_eq!(beta.subscribed(), symbols(&["BTC/USD", "ETH/USD"]));
+    
+    // Not yet connected: subscribed once it connects
+    let gamma = ForgetfulSource::new("Gamma");
+    manager.add_data_source(Box::new(gamma.clone())).await.unwrap();
+    assert_eq!(manager.get_subscriptions("Gamma"), symbols(&["BTC/USD", "ETH/USD"]));
+    manager.connect_source("Gamma").await.unwrap();
+    assert_eq!(gamma.subscribed(), symbols(&["BTC/USD", "ETH/USD"]));
+}
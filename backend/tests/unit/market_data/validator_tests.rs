@@ -0,0 +1,134 @@
+use arb_platform::market_data::validator::{
+    CHECK_ASK_NOT_BELOW_BID, CHECK_NON_NEGATIVE_VOLUME, CHECK_POSITIVE_BID, CHECK_POSITIVE_PRICE,
+    CHECK_PRICE_OUTLIER, MIN_OUTLIER_OBSERVATIONS, OUTLIERS_BEFORE_REGIME_SHIFT,
+};
+use arb_platform::market_data::{DataQualityValidator, MarketDataManager, MarketEvent, DEFAULT_MAX_STD_DEVS};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+
+// Prices alternating around 100 with a standard deviation of about 1
+fn warmed_up_validator() -> DataQualityValidator {
+    let validator = DataQualityValidator::default();
+    for i in 0..MIN_OUTLIER_OBSERVATIONS * 2 {
+        let price = if i % 2 == 0 { 99.0 } else { 101.0 };
+        validator.check_price_update("BTC/USD", price, None, None, None).unwrap();
+    }
+    validator
+}
+
+#[test]
+fn test_basic_checks_name_the_failure() {
+    let validator = DataQualityValidator::default();
+    assert_eq!(validator.max_std_devs(), DEFAULT_MAX_STD_DEVS);
+    
+    assert_eq!(validator.check_price_update("BTC/USD", 0.0, None, None, None), Err(CHECK_POSITIVE_PRICE));
+    assert_eq!(validator.check_price_update("BTC/USD", -5.0, None, None, None), Err(CHECK_POSITIVE_PRICE));
+    assert_eq!(validator.check_price_update("BTC/USD", 100.0, None, Some(0.0), Some(100.1)), Err(CHECK_POSITIVE_BID));
+    assert_eq!(validator.check_price_update("BTC/USD", 100.0, None, Some(100.2), Some(100.1)), Err(CHECK_ASK_NOT_BELOW_BID));
+    assert_eq!(validator.check_price_update("BTC/USD", 100.0, Some(-1.0), None, None), Err(CHECK_NON_NEGATIVE_VOLUME));
+    
+    // A locked quote and zero volume are allowed
+    assert!(validator.check_price_update("BTC/USD", 100.0, Some(0.0), Some(100.0), Some(100.0)).is_ok());
+    
+    let stats = validator.stats();
+    assert_eq!(stats.events_received, 6);
+    assert_eq!(stats.events_rejected, 5);
+    assert_eq!(stats.rejection_reasons.get(CHECK_POSITIVE_PRICE), Some(&2));
+    assert_eq!(stats.rejection_reasons.get(CHECK_POSITIVE_BID), Some(&1));
+    assert_eq!(stats.rejection_reasons.get(CHECK_ASK_NOT_BELOW_BID), Some(&1));
+    assert_eq!(stats.rejection_reasons.get(CHECK_NON_NEGATIVE_VOLUME), Some(&1));
+}
+
+#[test]
+fn test_price_spike_is_rejected_as_outlier() {
+    let validator = warmed_up_validator();
+    
+    assert!(validator.check_price_update("BTC/USD", 104.0, None, None, None).is_ok());
+    assert_eq!(validator.check_price_update("BTC/USD", 150.0, None, None, None), Err(CHECK_PRICE_OUTLIER));
+    assert_eq!(validator.check_price_update("BTC/USD", 50.0, None, None, None), Err(CHECK_PRICE_OUTLIER));
+    
+    // Each symbol has its own window
+    assert!(validator.check_price_update("ETH/USD", 150.0, None, None, None).is_ok());
+    
+    // A tighter band rejects smaller moves
+    validator.set_max_std_devs(2.0).unwrap();
+    assert_eq!(validator.check_price_update("BTC/USD", 104.0, None, None, None), Err(CHECK_PRICE_OUTLIER));
+    assert!(validator.set_max_std_devs(0.0).is_err());
+}
+
+#[test]
+fn test_sustained_move_is_accepted_as_regime_shift() {
+    let validator = warmed_up_validator();
+    
+    for _ in 1..OUTLIERS_BEFORE_REGIME_SHIFT {
+        assert_eq!(validator.check_price_update("BTC/USD", 150.0, None, None, None), Err(CHECK_PRICE_OUTLIER));
+    }
+    assert!(validator.check_price_update("BTC/USD", 150.0, None, None, None).is_ok());
+    
+    // Prices at the new level are accepted from then on
+    for i in 0..MIN_OUTLIER_OBSERVATIONS * 2 {
+        let price = if i % 2 == 0 { 149.0 } else { 151.0 };
+        assert!(validator.check_price_update("BTC/USD", price, None, None, None).is_ok());
+    }
+    assert_eq!(validator.check_price_update("BTC/USD", 100.0, None, None, None), Err(CHECK_PRICE_OUTLIER));
+    assert_eq!(validator.stats().events_rejected, OUTLIERS_BEFORE_REGIME_SHIFT as u64);
+}
+
+#[test]
+fn test_accepted_price_ends_an_outlier_run() {
+    let validator = warmed_up_validator();
+    
+    for _ in 0..OUTLIERS_BEFORE_REGIME_SHIFT * 2 {
+        assert_eq!(validator.check_price_update("BTC/USD", 150.0, None, None, None), Err(CHECK_PRICE_OUTLIER));
+        assert!(validator.check_price_update("BTC/USD", 100.0, None, None, None).is_ok());
+    }
+}
+
+#[test]
+fn test_outlier_check_waits_for_history() {
+    let validator = DataQualityValidator::default();
+    for i in 0..MIN_OUTLIER_OBSERVATIONS - 1 {
+        let price = if i % 2 == 0 { 99.0 } else { 101.0 };
+        validator.check_price_update("BTC/USD", price, None, None, None).unwrap();
+    }
+    assert!(validator.check_price_update("BTC/USD", 150.0, None, None, None).is_ok());
+}
+
+#[tokio::test]
+async fn test_manager_drops_rejected_updates() {
+    let mut manager = MarketDataManager::new();
+    manager.start_processing().await.unwrap();
+    
+    let sender = manager.get_event_sender();
+    for (price, bid, ask) in [(100.0, 99.9, 100.1), (-1.0, 99.9, 100.1), (101.0, 101.2, 100.9)] {
+        sender.send(MarketEvent::PriceUpdate {
+            symbol: "BTC/USD".to_string(),
+            price,
+            volume: Some(10.0),
+            bid: Some(bid),
+            ask: Some(ask),
+            exchange: "Test".to_string(),
+            timestamp: Utc::now(),
+        }).await.unwrap();
+    }
+    
+    let validator = manager.get_data_quality_validator();
+    for _ in 0..100 {
+        if validator.stats().events_received == 3 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    
+    let stats = validator.stats();
+    assert_eq!(stats.events_received, 3);
+    assert_eq!(stats.events_rejected, 2);
+    
+    let data = manager.get_current_data();
+    let asset = data.read().await.asset_data.get("BTC/USD").cloned().unwrap();
+    assert_eq!(asset.price, Price::from(100.0));
+    assert_eq!(asset.bid, Price::from(99.9));
+    
+    manager.shutdown().await.unwrap();
+}
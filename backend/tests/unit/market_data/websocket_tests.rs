@@ -0,0 +1,212 @@
+use arb_platform::channel::{event_channel, BackpressurePolicy, ChannelConfig, EventReceiver};
+use arb_platform::error::TradingError;
+use arb_platform::market_data::{DataSource, DataSourceType, MarketEvent, WebSocketDataSource, WsConnectionState, WsReconnectConfig};
+use arb_platform::market_data::websocket::{WsStream, WsTransport};
+
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+// Incoming messages for one scripted connection; an Err drops the connection
+type Feed = mpsc::UnboundedSender<Result<String, String>>;
+
+/// Transport whose connection attempts succeed or fail as scripted, recording
+/// what each connection sends
+#[derive(Default)]
+struct ScriptedTransport {
+    outcomes: Mutex<VecDeque<bool>>, // Attempts past the script are refused
+    feeds: Mutex<Vec<Feed>>,
+    sent: Arc<Mutex<Vec<Vec<String>>>>,
+    attempts: AtomicUsize,
+}
+
+impl ScriptedTransport {
+    fn new(outcomes: &[bool]) -> Arc<Self> {
+        Arc::new(ScriptedTransport {
+            outcomes: Mutex::new(outcomes.iter().copied().collect()),
+            ..Default::default()
+        })
+    }
+    
+    fn feed(&self, connection: usize) -> Feed {
+        self.feeds.lock().unwrap()[connection].clone()
+    }
+    
+    fn sent(&self, connection: usize) -> Vec<String> {
+        self.sent.lock().unwrap().get(connection).cloned().unwrap_or_default()
+    }
+    
+    fn connections(&self) -> usize {
+        self.feeds.lock().unwrap().len()
+    }
+}
+
+struct ScriptedStream {
+    incoming: mpsc::UnboundedReceiver<Result<String, String>>,
+    sent: Arc<Mutex<Vec<Vec<String>>>>,
+    index: usize,
+}
+
+#[async_trait]
+impl WsTransport for ScriptedTransport {
+    async fn connect(&self, _url: &str) -> Result<Box<dyn WsStream>, String> {
+        self.attempts.fetch_add(1, Ordering::SeqCst);
+        if !self.outcomes.lock().unwrap().pop_front().unwrap_or(false) {
+            return Err("Connection refused".to_string());
+        }
+        
+        let (feed, incoming) = mpsc::unbounded_channel();
+        let mut feeds = self.feeds.lock().unwrap();
+        feeds.push(feed);
+        self.sent.lock().unwrap().push(Vec::new());
+        
+        Ok(Box::new(ScriptedStream { incoming, sent: self.sent.clone(), index: feeds.len() - 1 }))
+    }
+}
+
+#[async_trait]
+impl WsStream for ScriptedStream {
+    async fn send(&mut self, message: String) -> Result<(), String> {
+        self.sent.lock().unwrap()[self.index].push(message);
+        Ok(())
+    }
+    
+    async fn next_message(&mut self) -> Result<String, String> {
+        match self.incoming.recv().await {
+            Some(message) => message,
+            None => std::future::pending().await,
+        }
+    }
+}
+
+fn create_source(transport: Arc<ScriptedTransport>, max_attempts: u32) -> (WebSocketDataSource, EventReceiver<MarketEvent>) {
+    let (sender, receiver) = event_channel(ChannelConfig::new(100, BackpressurePolicy::Block));
+    let mut source = WebSocketDataSource::with_transport(
+        "Test Feed",
+        DataSourceType::CryptoExchange("Test".to_string()),
+        "wss://feed.example.com",
+        sender,
+        transport,
+    );
+    source.set_reconnect_config(WsReconnectConfig {
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+        max_reconnect_attempts: max_attempts,
+    });
+    (source, receiver)
+}
+
+async fn wait_until(condition: impl Fn() -> bool) {
+    for _ in 0..200 {
+        if condition() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("condition not met in time");
+}
+
+fn symbols(names: &[&str]) -> Vec<String> {
+    names.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_backoff_doubles_up_to_the_cap() {
+    let config = WsReconnectConfig {
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(500),
+        max_reconnect_attempts: 10,
+    };
+    
+    assert_eq!(config.backoff(1), Duration::from_millis(100));
+    assert_eq!(config.backoff(3), Duration::from_millis(400));
+    assert_eq!(config.backoff(4), Duration::from_millis(500));
+    assert_eq!(config.backoff(40), Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn test_second_connect_is_refused() {
+    let (mut source, _events) = create_source(ScriptedTransport::new(&[true]), 1);
+    assert_eq!(source.state(), WsConnectionState::Disconnected);
+    
+    source.connect().await.unwrap();
+    assert!(matches!(source.connect().await, Err(TradingError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_connects_subscribes_and_forwards_ticks() {
+    let transport = ScriptedTransport::new(&[true]);
+    let (mut source, mut events) = create_source(transport.clone(), 3);
+    source.subscribe(&symbols(&["BTC/USD"])).await.unwrap();
+    
+    source.connect().await.unwrap();
+    wait_until(|| source.is_connected()).await;
+    assert!(matches!(source.state(), WsConnectionState::Connected { .. }));
+    
+    // Subscriptions made while connected go straight out
+    source.subscribe(&symbols(&["ETH/USD"])).await.unwrap();
+    wait_until(|| transport.sent(0).len() == 2).await;
+    assert!(transport.sent(0)[0].contains("BTC/USD"));
+    assert!(transport.sent(0)[1].contains("ETH/USD"));
+    
+    transport.feed(0).send(Ok(r#"{"symbol":"BTC/USD","price":35000.0,"bid":34999.0}"#.to_string())).unwrap();
+    match events.recv().await.unwrap() {
+        MarketEvent::PriceUpdate { symbol, price, bid, exchange, .. } => {
+            assert_eq!(symbol, "BTC/USD");
+            assert_eq!(price, 35000.0);
+            assert_eq!(bid, Some(34999.0));
+            assert_eq!(exchange, "Test Feed");
+        },
+        other => panic!("expected a price update, got {:?}", other),
+    }
+    
+    source.disconnect().unwrap();
+    assert_eq!(source.state(), WsConnectionState::Disconnected);
+}
+
+#[tokio::test]
+async fn test_reconnects_and_restores_subscriptions() {
+    // The first retry is refused, the second succeeds
+    let transport = ScriptedTransport::new(&[true, false, true]);
+    let (mut source, mut events) = create_source(transport.clone(), 3);
+    source.subscribe(&symbols(&["BTC/USD", "ETH/USD"])).await.unwrap();
+    source.connect().await.unwrap();
+    wait_until(|| source.is_connected()).await;
+    
+    source.unsubscribe(&symbols(&["ETH/USD"])).await.unwrap();
+    transport.feed(0).send(Err("Connection reset".to_string())).unwrap();
+    
+    match events.recv().await.unwrap() {
+        MarketEvent::SourceReconnected { source_name } => assert_eq!(source_name, "Test Feed"),
+        other => panic!("expected a reconnection event, got {:?}", other),
+    }
+    assert!(source.is_connected());
+    assert_eq!(transport.connections(), 2);
+    assert_eq!(transport.attempts.load(Ordering::SeqCst), 3);
+    
+    // Only the subscriptions still active are restored
+    let restored = transport.sent(1);
+    assert_eq!(restored.len(), 1);
+    assert!(restored[0].contains("BTC/USD") && !restored[0].contains("ETH/USD"));
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() {
+    let transport = ScriptedTransport::new(&[true]);
+    let (mut source, _events) = create_source(transport.clone(), 2);
+    let alerts = Arc::new(Mutex::new(Vec::new()));
+    let alerts_clone = alerts.clone();
+    source.on_permanent_failure(move |name| alerts_clone.lock().unwrap().push(name.to_string()));
+    
+    source.connect().await.unwrap();
+    wait_until(|| source.is_connected()).await;
+    transport.feed(0).send(Err("Connection reset".to_string())).unwrap();
+    
+    wait_until(|| source.state() == WsConnectionState::PermanentlyFailed).await;
+    assert!(!source.is_connected());
+    assert_eq!(transport.attempts.load(Ordering::SeqCst), 3); // Initial connection plus two retries
+    assert_eq!(*alerts.lock().unwrap(), vec!["Test Feed".to_string()]);
+}
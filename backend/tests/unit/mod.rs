@@ -1,5 +1,16 @@
 // Unit test submodules
+pub mod api;
+pub mod backtest;
+pub mod channel;
+pub mod clock;
+pub mod config;
+pub mod error;
 pub mod exchange;
 pub mod order;
+pub mod risk;
+pub mod shutdown;
 pub mod market_data;
-pub mod strategy; 
\ No newline at end of file
+pub mod models;
+pub mod notifications;
+pub mod strategy; pub mod position;
+pub mod utils;
@@ -0,0 +1,4 @@
+// Models module tests
+pub mod portfolio_tests;
+pub mod price_tests;
+pub mod symbol_tests;
@@ -0,0 +1,167 @@
+use arb_platform::exchange::Position;
+use arb_platform::market_data::StaticFxProvider;
+use arb_platform::models::portfolio::{CorrelationEntry, PortfolioManager, DEFAULT_CORRELATION, VAR_95_Z_SCORE};
+
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn create_position(symbol: &str, quantity: f64, price: f64) -> Position {
+    Position {
+        symbol: symbol.to_string(),
+        quantity,
+        avg_price: price,
+        current_price: price,
+        unrealized_pnl: 0.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }
+}
+
+// 10,000 of BTC at 2% daily volatility and 5,000 of ETH at 4%
+async fn btc_eth_portfolio() -> PortfolioManager {
+    let portfolio = PortfolioManager::new();
+    portfolio.update_position(create_position("BTC/USD", 0.2, 50000.0)).await;
+    portfolio.update_position(create_position("ETH/USD", 2.5, 2000.0)).await;
+    portfolio.set_volatility("BTC/USD", 0.02).await.unwrap();
+    portfolio.set_volatility("ETH/USD", 0.04).await.unwrap();
+    portfolio
+}
+
+#[tokio::test]
+async fn test_var_of_single_position() {
+    let portfolio = PortfolioManager::new();
+    portfolio.update_position(create_position("BTC/USD", 0.2, 50000.0)).await;
+    portfolio.set_volatility("BTC/USD", 0.02).await.unwrap();
+
+    assert!((portfolio.total_var_exposure().await - 200.0 * VAR_95_Z_SCORE).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_var_reflects_correlation() {
+    let portfolio = btc_eth_portfolio().await;
+
+    // Each leg risks 200: w'Σw = 200² + 200² + 2·200·200·ρ
+    portfolio.set_correlation("BTC/USD", "ETH/USD", 0.5).await.unwrap();
+    let expected = 120000.0_f64.sqrt() * VAR_95_Z_SCORE;
+    assert!((portfolio.total_var_exposure().await - expected).abs() < 1e-9);
+
+    portfolio.set_correlation("ETH/USD", "BTC/USD", 0.0).await.unwrap();
+    let expected = 80000.0_f64.sqrt() * VAR_95_Z_SCORE;
+    assert!((portfolio.total_var_exposure().await - expected).abs() < 1e-9);
+
+    portfolio.set_correlation("BTC/USD", "ETH/USD", -1.0).await.unwrap();
+    assert!(portfolio.total_var_exposure().await.abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_unknown_correlation_is_conservative() {
+    let portfolio = btc_eth_portfolio().await;
+
+    assert_eq!(portfolio.correlation("BTC/USD", "ETH/USD").await, DEFAULT_CORRELATION);
+    assert!((portfolio.total_var_exposure().await - 400.0 * VAR_95_Z_SCORE).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_var_with_trade_projects_new_exposure() {
+    let portfolio = btc_eth_portfolio().await;
+    portfolio.set_correlation("BTC/USD", "ETH/USD", 0.0).await.unwrap();
+
+    // Selling all the ETH leaves only the BTC leg
+    let projected = portfolio.var_exposure_with_trade("ETH/USD", -2.5, 2000.0).await;
+    assert!((projected - 200.0 * VAR_95_Z_SCORE).abs() < 1e-9);
+
+    // The trade is only projected, not applied
+    assert_eq!(portfolio.get_positions().await.len(), 2);
+}
+
+#[tokio::test]
+async fn test_var_weights_positions_in_base_currency() {
+    let portfolio = PortfolioManager::new();
+    portfolio.set_fx_provider(Arc::new(StaticFxProvider::new().with_rate("EUR", "USD", 1.25)), "USD");
+    portfolio.update_position(create_position("SAP/EUR", 100.0, 80.0)).await;
+    portfolio.set_volatility("SAP/EUR", 0.02).await.unwrap();
+
+    // 8,000 EUR is 10,000 USD, risking 200 a day
+    assert!((portfolio.total_var_exposure().await - 200.0 * VAR_95_Z_SCORE).abs() < 1e-9);
+    let projected = portfolio.var_exposure_with_trade("SAP/EUR", 100.0, 80.0).await;
+    assert!((projected - 400.0 * VAR_95_Z_SCORE).abs() < 1e-9);
+
+    // Without a rate the position cannot be weighted
+    portfolio.update_position(create_position("BP/GBP", 100.0, 5.0)).await;
+    portfolio.set_volatility("BP/GBP", 0.02).await.unwrap();
+    assert!((portfolio.total_var_exposure().await - 200.0 * VAR_95_Z_SCORE).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_positions_without_volatility_are_left_out() {
+    let portfolio = PortfolioManager::new();
+    portfolio.update_position(create_position("BTC/USD", 1.0, 50000.0)).await;
+
+    assert_eq!(portfolio.volatility("BTC/USD").await, None);
+    assert_eq!(portfolio.total_var_exposure().await, 0.0);
+}
+
+#[tokio::test]
+async fn test_volatility_and_correlation_estimated_from_prices() {
+    let portfolio = PortfolioManager::new();
+    let mut btc = 50000.0;
+    let mut eth = 2000.0;
+    for i in 0..60 {
+        let change = if i % 2 == 0 { 0.01 } else { -0.01 };
+        btc *= 1.0 + change;
+        eth *= 1.0 - change;
+        portfolio.record_price("BTC/USD", btc).await;
+        portfolio.record_price("ETH/USD", eth).await;
+    }
+
+    let volatility = portfolio.volatility("BTC/USD").await.unwrap();
+    assert!((volatility - 0.01).abs() < 1e-3, "unexpected volatility {}", volatility);
+    assert!((portfolio.correlation("BTC/USD", "ETH/USD").await + 1.0).abs() < 1e-9);
+    assert_eq!(portfolio.last_price("BTC/USD").await, Some(btc));
+}
+
+#[tokio::test]
+async fn test_volatility_is_scaled_to_a_day_from_the_sample_interval() {
+    let portfolio = PortfolioManager::new();
+    assert!(portfolio.set_sample_interval(Duration::ZERO).is_err());
+    portfolio.set_sample_interval(Duration::from_secs(60 * 60)).unwrap();
+    let mut btc = 50000.0;
+    for i in 0..60 {
+        btc *= if i % 2 == 0 { 1.01 } else { 0.99 };
+        portfolio.record_price("BTC/USD", btc).await;
+    }
+
+    // 1% hourly is about 4.9% daily
+    let volatility = portfolio.volatility("BTC/USD").await.unwrap();
+    assert!((volatility - 0.01 * 24f64.sqrt()).abs() < 5e-3, "unexpected volatility {}", volatility);
+}
+
+#[tokio::test]
+async fn test_invalid_correlations_are_rejected() {
+    let portfolio = PortfolioManager::new();
+
+    let result = portfolio.set_correlations(vec![
+        CorrelationEntry { symbol_a: "BTC/USD".to_string(), symbol_b: "ETH/USD".to_string(), correlation: 0.8 },
+        CorrelationEntry { symbol_a: "BTC/USD".to_string(), symbol_b: "SOL/USD".to_string(), correlation: 1.5 },
+    ]).await;
+    assert!(result.is_err());
+    assert!(portfolio.get_correlations().await.is_empty());
+
+    assert!(portfolio.set_correlation("BTC/USD", "BTC/USD", 0.5).await.is_err());
+    assert!(portfolio.set_volatility("BTC/USD", -0.1).await.is_err());
+}
+
+#[tokio::test]
+async fn test_correlations_are_listed_by_sorted_pair() {
+    let portfolio = PortfolioManager::new();
+    portfolio.set_correlation("SOL/USD", "BTC/USD", 0.6).await.unwrap();
+    portfolio.set_correlation("ETH/USD", "BTC/USD", 0.8).await.unwrap();
+
+    let entries = portfolio.get_correlations().await;
+    assert_eq!(entries.len(), 2);
+    assert_eq!((entries[0].symbol_a.as_str(), entries[0].symbol_b.as_str()), ("BTC/USD", "ETH/USD"));
+    assert_eq!((entries[1].symbol_a.as_str(), entries[1].symbol_b.as_str()), ("BTC/USD", "SOL/USD"));
+}
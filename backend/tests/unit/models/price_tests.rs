@@ -0,0 +1,151 @@
+use arb_platform::market_data::{MarketDataManager, MarketEvent, Tick};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+fn dec(value: &str) -> Decimal {
+    Decimal::from_str(value).unwrap()
+}
+
+#[test]
+fn test_sums_are_exact() {
+    assert_ne!(0.1 + 0.2, 0.3);
+    assert_eq!(Price::from(0.1) + Price::from(0.2), Price::from(0.3));
+    assert_eq!(Price::from(0.3) - Price::from(0.1), Price::from(0.2));
+}
+
+#[test]
+fn test_conversion_from_f64_keeps_eight_places() {
+    assert_eq!(Price::from(35000.123456789), Price::from(35000.12345679));
+    assert_eq!(Price::from(35000.0).to_f64(), 35000.0);
+    assert_eq!(Price::from(f64::NAN), Price::ZERO);
+}
+
+#[test]
+fn test_rounding_to_tick_size() {
+    let tick = Price::from(0.05);
+    assert_eq!(Price::from(100.02).round_to_tick(tick), Price::from(100.0));
+    assert_eq!(Price::from(100.03).round_to_tick(tick), Price::from(100.05));
+    assert_eq!(Price::from(100.025).round_to_tick(tick), Price::from(100.05)); // Halves away from zero
+    assert_eq!(Price::from_f64_with_tick(35000.4, Some(Price::from(1.0))), Price::from(35000.0));
+    assert_eq!(Price::from_f64_with_tick(35000.4, None), Price::from(35000.4));
+    assert_eq!(Price::from(100.02).round_to_tick(Price::ZERO), Price::from(100.02));
+}
+
+#[test]
+fn test_arithmetic_and_ordering() {
+    let price = Price::from(100.0);
+    assert_eq!(price * dec("1.5"), Price::from(150.0));
+    assert_eq!(price / dec("4"), Price::from(25.0));
+    assert_eq!(price / Price::from(40.0), dec("2.5"));
+    assert_eq!(-price, Price::from(-100.0));
+    assert!(Price::from(99.99) < price);
+    assert_eq!(Price::from(100.01).max(price), Price::from(100.01));
+
+    let mut total = Price::ZERO;
+    total += price;
+    total -= Price::from(0.5);
+    assert_eq!(total, Price::from(99.5));
+}
+
+#[test]
+fn test_serializes_as_a_decimal_string() {
+    assert_eq!(serde_json::to_value(Price::from(35000.1)).unwrap(), serde_json::json!("35000.1"));
+    assert_eq!(Price::from(35000.0).to_string(), "35000");
+
+    let from_string: Price = serde_json::from_value(serde_json::json!("0.30000000000000004")).unwrap();
+    assert_eq!(from_string.value(), dec("0.30000000000000004"));
+    let from_number: Price = serde_json::from_value(serde_json::json!(35000.5)).unwrap();
+    assert_eq!(from_number, Price::from(35000.5));
+    let from_integer: Price = serde_json::from_value(serde_json::json!(35000)).unwrap();
+    assert_eq!(from_integer, Price::from(35000.0));
+    assert!(serde_json::from_value::<Price>(serde_json::json!("not a price")).is_err());
+}
+
+#[test]
+fn test_tick_parses_string_and_number_prices() {
+    let tick: Tick = serde_json::from_str(r#"{"symbol": "BTC/USD", "price": "35000.25", "volume": 2.0, "bid": 35000.2, "ask": null}"#).unwrap();
+    assert_eq!(tick.price, Price::from(35000.25));
+    assert_eq!(tick.bid, Some(Price::from(35000.2)));
+    assert_eq!(tick.ask, None);
+
+    match tick.into_event("Feed", Utc::now()) {
+        MarketEvent::PriceUpdate { symbol, price, bid, exchange, .. } => {
+            assert_eq!(symbol, "BTC/USD");
+            assert_eq!(price, 35000.25);
+            assert_eq!(bid, Some(35000.2));
+            assert_eq!(exchange, "Feed");
+        },
+        other => panic!("expected a price update, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_price_updates_are_rounded_to_the_tick_size() {
+    let mut manager = MarketDataManager::new();
+    assert!(manager.set_tick_size("BTC/USD", Price::ZERO).await.is_err());
+    manager.set_tick_size("BTC/USD", Price::from(0.5)).await.unwrap();
+    assert_eq!(manager.get_tick_size("BTC/USD"), Some(Price::from(0.5)));
+    manager.start_processing().await.unwrap();
+
+    manager.get_event_sender().send(MarketEvent::PriceUpdate {
+        symbol: "BTC/USD".to_string(),
+        price: 35000.3,
+        volume: Some(1.0),
+        bid: Some(34999.9),
+        ask: Some(35000.6),
+        exchange: "Test".to_string(),
+        timestamp: Utc::now(),
+    }).await.unwrap();
+
+    let data = manager.get_current_data();
+    for _ in 0..100 {
+        if data.read().await.asset_data.contains_key("BTC/USD") {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    let asset = data.read().await.asset_data.get("BTC/USD").cloned().unwrap();
+    assert_eq!(asset.tick_size, Some(Price::from(0.5)));
+    assert_eq!(asset.price, Price::from(35000.5));
+    assert_eq!(asset.bid, Price::from(35000.0));
+    assert_eq!(asset.ask, Price::from(35000.5));
+
+    manager.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_lot_size_is_stamped_on_asset_data() {
+    let mut manager = MarketDataManager::new();
+    assert!(manager.set_lot_size("BTC/USD", 0.0).await.is_err());
+    assert!(manager.set_lot_size("BTC/USD", f64::NAN).await.is_err());
+    manager.set_lot_size("BTC/USD", 0.01).await.unwrap();
+    assert_eq!(manager.get_lot_size("BTC/USD"), Some(0.01));
+    assert_eq!(manager.get_lot_size("ETH/USD"), None);
+    manager.start_processing().await.unwrap();
+
+    manager.get_event_sender().send(MarketEvent::PriceUpdate {
+        symbol: "BTC/USD".to_string(),
+        price: 35000.0,
+        volume: Some(1.0),
+        bid: None,
+        ask: None,
+        exchange: "Test".to_string(),
+        timestamp: Utc::now(),
+    }).await.unwrap();
+
+    let data = manager.get_current_data();
+    for _ in 0..100 {
+        if data.read().await.asset_data.contains_key("BTC/USD") {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert_eq!(data.read().await.asset_data["BTC/USD"].lot_size, Some(0.01));
+
+    // A change reaches data already held
+    manager.set_lot_size("BTC/USD", 0.001).await.unwrap();
+    assert_eq!(data.read().await.asset_data["BTC/USD"].lot_size, Some(0.001));
+}
@@ -0,0 +1,109 @@
+use arb_platform::exchange::{ExchangeConfig, ExchangeType, SYMBOL_MAP_PARAM};
+use arb_platform::market_data::{MarketDataManager, MarketEvent};
+use arb_platform::models::{Price, SymbolNormalizer};
+
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn test_mappings_translate_both_ways_per_exchange() {
+    let normalizer = SymbolNormalizer::new();
+    normalizer.register_mapping("Binance", "BTC/USD", "BTCUSDT");
+    normalizer.register_mapping("Coinbase", "BTC/USD", "BTC-USD");
+
+    assert_eq!(normalizer.from_canonical("Binance", "BTC/USD").as_deref(), Some("BTCUSDT"));
+    assert_eq!(normalizer.from_canonical("Coinbase", "BTC/USD").as_deref(), Some("BTC-USD"));
+    assert_eq!(normalizer.to_canonical("Binance", "BTCUSDT").as_deref(), Some("BTC/USD"));
+    assert_eq!(normalizer.to_canonical("Coinbase", "BTC-USD").as_deref(), Some("BTC/USD"));
+    // Another exchange's format means nothing on this one
+    assert_eq!(normalizer.to_canonical("Coinbase", "BTCUSDT").as_deref(), Some("BTCUSDT"));
+}
+
+#[test]
+fn test_unmapped_symbols_pass_through() {
+    let normalizer = SymbolNormalizer::new();
+
+    assert_eq!(normalizer.to_canonical("Kraken", "ETH/USD").as_deref(), Some("ETH/USD"));
+    assert_eq!(normalizer.from_canonical("Kraken", "ETH/USD").as_deref(), Some("ETH/USD"));
+    assert_eq!(normalizer.to_canonical("Kraken", ""), None);
+    assert_eq!(normalizer.from_canonical("Kraken", ""), None);
+}
+
+#[test]
+fn test_remapping_drops_the_stale_direction() {
+    let normalizer = SymbolNormalizer::new();
+    normalizer.register_mapping("Binance", "BTC/USD", "BTCUSD");
+    normalizer.register_mapping("Binance", "BTC/USD", "BTCUSDT");
+
+    assert_eq!(normalizer.from_canonical("Binance", "BTC/USD").as_deref(), Some("BTCUSDT"));
+    assert_eq!(normalizer.to_canonical("Binance", "BTCUSD").as_deref(), Some("BTCUSD"));
+
+    normalizer.register_mapping("Binance", "BTC/USDT", "BTCUSDT");
+    assert_eq!(normalizer.to_canonical("Binance", "BTCUSDT").as_deref(), Some("BTC/USDT"));
+    assert_eq!(normalizer.from_canonical("Binance", "BTC/USD").as_deref(), Some("BTC/USD"));
+}
+
+#[test]
+fn test_symbol_map_param_registers_every_pair() {
+    let mut config = ExchangeConfig {
+        name: "Binance".to_string(),
+        exchange_type: ExchangeType::Crypto,
+        api_url: "https://api.binance.com".to_string(),
+        api_key: None,
+        api_secret: None,
+        additional_params: HashMap::new(),
+    };
+    let normalizer = SymbolNormalizer::new();
+    config.register_symbol_map(&normalizer).unwrap();
+
+    config.additional_params.insert(SYMBOL_MAP_PARAM.to_string(), "BTC/USD=BTCUSDT, ETH/USD=ETHUSDT".to_string());
+    config.register_symbol_map(&normalizer).unwrap();
+    assert_eq!(normalizer.from_canonical("Binance", "ETH/USD").as_deref(), Some("ETHUSDT"));
+    assert_eq!(normalizer.to_canonical("Binance", "BTCUSDT").as_deref(), Some("BTC/USD"));
+
+    config.additional_params.insert(SYMBOL_MAP_PARAM.to_string(), "SOL/USD=SOLUSDT,XRPUSDT".to_string());
+    assert!(config.register_symbol_map(&normalizer).is_err());
+    assert_eq!(normalizer.from_canonical("Binance", "SOL/USD").as_deref(), Some("SOL/USD"));
+}
+
+#[tokio::test]
+async fn test_market_data_is_stored_under_canonical_symbols() {
+    let normalizer = Arc::new(SymbolNormalizer::new());
+    normalizer.register_mapping("Binance", "BTC/USD", "BTCUSDT");
+    let mut manager = MarketDataManager::new();
+    manager.set_symbol_normalizer(normalizer);
+    manager.start_processing().await.unwrap();
+
+    let sender = manager.get_event_sender();
+    sender.send(MarketEvent::OrderBookUpdate {
+        symbol: "BTCUSDT".to_string(),
+        bids: vec![(35000.0, 1.0)],
+        asks: vec![(35001.0, 2.0)],
+        exchange: "Binance".to_string(),
+        timestamp: Utc::now(),
+    }).await.unwrap();
+    sender.send(MarketEvent::PriceUpdate {
+        symbol: "BTCUSDT".to_string(),
+        price: 35000.5,
+        volume: None,
+        bid: None,
+        ask: None,
+        exchange: "Binance".to_string(),
+        timestamp: Utc::now(),
+    }).await.unwrap();
+
+    let data = manager.get_current_data();
+    for _ in 0..100 {
+        if data.read().await.asset_data.contains_key("BTC/USD") {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let data = data.read().await;
+    assert_eq!(data.asset_data.get("BTC/USD").unwrap().price, Price::from(35000.5));
+    assert!(!data.asset_data.contains_key("BTCUSDT"));
+    assert!(manager.get_order_book("BTC/USD").is_some());
+    assert!(manager.get_order_book("BTCUSDT").is_none());
+}
@@ -0,0 +1,2 @@
+// Notifications module tests
+pub mod mod_tests;
@@ -0,0 +1,134 @@
+use arb_platform::notifications::{
+    LogNotificationHandler, Notification, NotificationHandler, NotificationLevel, NotificationManager,
+    WebhookNotificationHandler,
+};
+
+use crate::helpers::recording_notifier::RecordingNotifier;
+
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+#[tokio::test]
+async fn test_notify_delivers_to_every_handler() {
+    let first = RecordingNotifier::new();
+    let second = RecordingNotifier::new();
+    let mut manager = NotificationManager::new();
+    manager.register_handler(Box::new(first.clone()));
+    manager.register_handler(Box::new(second.clone()));
+
+    let notification = Notification::new(NotificationLevel::Warning, "Order rejected", "Insufficient margin")
+        .with_metadata("symbol", "BTC/USD");
+    let results = manager.notify(notification).await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.is_ok()));
+    for handler in [&first, &second] {
+        let sent = handler.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].title, "Order rejected");
+        assert_eq!(sent[0].metadata.get("symbol").map(String::as_str), Some("BTC/USD"));
+    }
+}
+
+#[tokio::test]
+async fn test_failing_handler_does_not_stop_others() {
+    let failing = RecordingNotifier::failing();
+    let recording = RecordingNotifier::new();
+    let mut manager = NotificationManager::new();
+    manager.register_handler(Box::new(failing));
+    manager.register_handler(Box::new(recording.clone()));
+
+    let results = manager.notify(Notification::new(NotificationLevel::Critical, "Order failed", "Timeout")).await;
+    assert!(results[0].is_err());
+    assert!(results[1].is_ok());
+    assert_eq!(recording.sent().len(), 1);
+}
+
+#[tokio::test]
+async fn test_notifications_below_threshold_are_dropped() {
+    let recording = RecordingNotifier::new();
+    let mut manager = NotificationManager::new();
+    manager.register_handler(Box::new(recording.clone()));
+    manager.set_min_level(NotificationLevel::Warning);
+
+    assert!(manager.notify(Notification::new(NotificationLevel::Info, "Heartbeat", "")).await.is_empty());
+    assert_eq!(manager.notify(Notification::new(NotificationLevel::Critical, "Order failed", "")).await.len(), 1);
+    assert_eq!(recording.sent().len(), 1);
+    assert_eq!(recording.sent()[0].level, NotificationLevel::Critical);
+}
+
+#[tokio::test]
+async fn test_log_handler_accepts_every_level() {
+    let handler = LogNotificationHandler::new();
+    for level in [NotificationLevel::Info, NotificationLevel::Warning, NotificationLevel::Critical] {
+        assert!(handler.send(Notification::new(level, "Test", "Body")).await.is_ok());
+    }
+}
+
+// Accept one HTTP request, answer with `status_line` and return the raw request
+async fn serve_once(listener: TcpListener, status_line: &'static str) -> String {
+    let (mut socket, _) = listener.accept().await.unwrap();
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&request);
+        if let Some(header_end) = text.find("\r\n\r\n") {
+            let length = text.lines()
+                .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                .unwrap_or(0);
+            if request.len() >= header_end + 4 + length {
+                break;
+            }
+        }
+        if n == 0 {
+            break;
+        }
+    }
+    let response = format!("{}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status_line);
+    socket.write_all(response.as_bytes()).await.unwrap();
+    String::from_utf8_lossy(&request).to_string()
+}
+
+#[tokio::test]
+async fn test_webhook_posts_notification_json() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/alerts", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_once(listener, "HTTP/1.1 200 OK"));
+
+    let handler = WebhookNotificationHandler::new(&url);
+    let notification = Notification::new(NotificationLevel::Critical, "Strategies paused", "Drawdown limit hit");
+    assert!(handler.send(notification).await.is_ok());
+
+    let request = server.await.unwrap();
+    assert!(request.starts_with("POST /alerts"), "unexpected request: {}", request);
+    let body: serde_json::Value = serde_json::from_str(&request[request.find("\r\n\r\n").unwrap() + 4..]).unwrap();
+    assert_eq!(body["level"], "critical");
+    assert_eq!(body["title"], "Strategies paused");
+    assert_eq!(body["body"], "Drawdown limit hit");
+}
+
+#[tokio::test]
+async fn test_webhook_error_status_is_reported() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/alerts", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_once(listener, "HTTP/1.1 500 Internal Server Error"));
+
+    let handler = WebhookNotificationHandler::with_timeout(&url, Duration::from_secs(2));
+    let err = handler.send(Notification::new(NotificationLevel::Info, "Test", "")).await.unwrap_err();
+    assert!(err.contains("500"), "unexpected error: {}", err);
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_webhook_unreachable_url_is_an_error() {
+    // Bind then drop to get a port with nothing listening
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/alerts", listener.local_addr().unwrap());
+    drop(listener);
+
+    let handler = WebhookNotificationHandler::with_timeout(&url, Duration::from_secs(2));
+    assert!(handler.send(Notification::new(NotificationLevel::Info, "Test", "")).await.is_err());
+}
@@ -0,0 +1,132 @@
+use arb_platform::order::{ExecAlgo, Order, OrderEvent, OrderManager, OrderStatus, TWAP_CHILD_TAG, VWAP_CHILD_TAG};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::time::Duration;
+use uuid::Uuid;
+
+fn parent_order(quantity: f64) -> Order {
+    Order {
+        client_order_id: "algo-parent".to_string(),
+        quantity,
+        price: Some(Price::from(50000.0)),
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+async fn create_manager() -> (OrderManager, MockExchange) {
+    let manager = OrderManager::new();
+    let exchange = MockExchange::new("Mock");
+    manager.get_order_router().register_exchange(Box::new(exchange.clone())).await.unwrap();
+    (manager, exchange)
+}
+
+async fn fill(manager: &OrderManager, order_id: Uuid, quantity: f64, price: f64) {
+    manager.get_event_sender().send(OrderEvent::Update {
+        order_id,
+        status: Some(OrderStatus::Filled),
+        filled_qty: Some(quantity),
+        avg_fill_price: Some(price),
+    }).await.unwrap();
+}
+
+#[test]
+fn test_algo_validation() {
+    assert!(ExecAlgo::Twap { slices: 0, interval: Duration::from_secs(1) }.validate().is_err());
+    assert!(ExecAlgo::Twap { slices: 3, interval: Duration::ZERO }.validate().is_err());
+    assert!(ExecAlgo::Vwap { volume_profile: vec![0.0, 0.0], interval: Duration::from_secs(1) }.validate().is_err());
+    assert!(ExecAlgo::Vwap { volume_profile: vec![1.0, -1.0], interval: Duration::from_secs(1) }.validate().is_err());
+    assert!(ExecAlgo::Twap { slices: 1, interval: Duration::ZERO }.validate().is_ok());
+}
+
+#[test]
+fn test_vwap_slices_follow_volume_profile() {
+    let algo = ExecAlgo::Vwap { volume_profile: vec![100.0, 0.0, 300.0], interval: Duration::from_secs(60) };
+    let parent = parent_order(10.0);
+    let schedule = algo.schedule(&parent, parent.id);
+    
+    // The empty bucket is skipped but keeps its place in time
+    assert_eq!(schedule.len(), 2);
+    assert_eq!(schedule[0].order.quantity, 2.5);
+    assert_eq!(schedule[0].offset, Duration::ZERO);
+    assert_eq!(schedule[1].order.quantity, 7.5);
+    assert_eq!(schedule[1].offset, Duration::from_secs(120));
+    assert_eq!(schedule[1].order.client_order_id, "algo-parent-3");
+    assert!(schedule.iter().all(|child| child.order.tags == vec![VWAP_CHILD_TAG.to_string()]));
+}
+
+#[tokio::test]
+async fn test_twap_children_submit_on_schedule_and_fills_aggregate() {
+    let (manager, exchange) = create_manager().await;
+    let parent_id = manager.place_algo_order(parent_order(10.0), ExecAlgo::Twap { slices: 5, interval: Duration::from_millis(100) }).await.unwrap();
+    
+    // One child straight away, then one every 100ms
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(exchange.submitted_orders().len(), 1);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(exchange.submitted_orders().len(), 3);
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    
+    let submitted = exchange.submitted_orders();
+    assert_eq!(submitted.len(), 5);
+    assert!(submitted.iter().all(|child| child.quantity == 2.0 && child.tags.contains(&TWAP_CHILD_TAG.to_string())));
+    assert!(submitted.iter().all(|child| child.id != parent_id));
+    
+    let execution = manager.get_algo_execution(parent_id).await.unwrap();
+    assert_eq!(execution.submitted, execution.child_ids);
+    assert!(execution.is_schedule_complete());
+    assert_eq!(manager.get_order(parent_id).await.unwrap().status, OrderStatus::Submitted);
+    
+    // Fills on three children leave the parent part filled
+    for (child_id, price) in execution.child_ids.iter().zip([100.0, 101.0, 102.0]) {
+        fill(&manager, *child_id, 2.0, price).await;
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let parent = manager.get_order(parent_id).await.unwrap();
+    assert_eq!(parent.status, OrderStatus::PartiallyFilled);
+    assert_eq!(parent.filled_quantity, 6.0);
+    assert_eq!(parent.average_fill_price, Some(Price::from(101.0)));
+    
+    for (child_id, price) in execution.child_ids[3..].iter().zip([103.0, 104.0]) {
+        fill(&manager, *child_id, 2.0, price).await;
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let parent = manager.get_order(parent_id).await.unwrap();
+    assert_eq!(parent.status, OrderStatus::Filled);
+    assert_eq!(parent.filled_quantity, 10.0);
+    assert_eq!(parent.average_fill_price, Some(Price::from(102.0)));
+    assert!(parent.filled_at.is_some());
+    assert!(!manager.get_active_orders().await.iter().any(|order| order.id == parent_id));
+}
+
+#[tokio::test]
+async fn test_cancelling_parent_cancels_children() {
+    let (manager, exchange) = create_manager().await;
+    let parent_id = manager.place_algo_order(parent_order(10.0), ExecAlgo::Twap { slices: 5, interval: Duration::from_millis(100) }).await.unwrap();
+    
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let execution = manager.get_algo_execution(parent_id).await.unwrap();
+    assert_eq!(execution.submitted.len(), 2);
+    fill(&manager, execution.submitted[0], 2.0, 100.0).await;
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    
+    manager.cancel_order(parent_id, "Stop working".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    
+    // Nothing more goes out, and only the resting child is cancelled
+    assert_eq!(exchange.submitted_orders().len(), 2);
+    exchange.assert_order_cancelled(execution.submitted[1]);
+    assert_eq!(manager.get_order(execution.submitted[1]).await.unwrap().status, OrderStatus::Cancelled);
+    assert_eq!(manager.get_order(execution.submitted[0]).await.unwrap().status, OrderStatus::Filled);
+    assert!(manager.get_order(execution.child_ids[2]).await.is_none());
+    
+    let parent = manager.get_order(parent_id).await.unwrap();
+    assert_eq!(parent.status, OrderStatus::Cancelled);
+    assert_eq!(parent.filled_quantity, 2.0);
+    assert_eq!(parent.unfilled_quantity, Some(8.0));
+    assert!(manager.get_algo_execution(parent_id).await.unwrap().cancelled);
+    assert!(manager.cancel_order(parent_id, "Again".to_string()).await.is_err());
+}
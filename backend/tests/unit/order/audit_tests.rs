@@ -0,0 +1,130 @@
+use arb_platform::exchange::{Exchange, ExchangeConfig, ExchangeType};
+use arb_platform::exchange::crypto::CryptoExchange;
+use arb_platform::order::{
+    AuditEntry, AuditStore, InMemoryAuditStore, Order, OrderManager, OrderStatus
+};
+use arb_platform::models::Price;
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::test;
+use uuid::Uuid;
+
+use crate::helpers::orders::test_order;
+
+fn create_test_order(exchange: &str) -> Order {
+    Order {
+        price: Some(Price::from(35000.0)),
+        exchange: exchange.to_string(),
+        strategy_id: Some("test_strategy".to_string()),
+        ..test_order()
+    }
+}
+
+async fn create_connected_exchange() -> CryptoExchange {
+    let mut exchange = CryptoExchange::new(ExchangeConfig {
+        name: "Test Crypto Exchange".to_string(),
+        exchange_type: ExchangeType::Crypto,
+        api_url: "https://api.example.com".to_string(),
+        api_key: Some("test_key".to_string()),
+        api_secret: Some("test_secret".to_string()),
+        additional_params: HashMap::new(),
+    });
+    exchange.connect().await.expect("Failed to connect");
+    exchange
+}
+
+async fn wait_for_status(manager: &OrderManager, order_id: Uuid, status: OrderStatus) {
+    for _ in 0..100 {
+        if manager.get_order(order_id).await.map(|o| o.status) == Some(status.clone()) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("Order {} never reached {:?}", order_id, status);
+}
+
+fn transitions(trail: &[AuditEntry]) -> Vec<(Option<OrderStatus>, OrderStatus)> {
+    trail.iter().map(|e| (e.from_status.clone(), e.to_status.clone())).collect()
+}
+
+#[test]
+async fn test_audit_trail_for_placed_and_cancelled_order() {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(create_connected_exchange().await)).await.unwrap();
+    
+    let order_id = manager.place_order(create_test_order("Test Crypto Exchange")).await.unwrap();
+    wait_for_status(&manager, order_id, OrderStatus::Submitted).await;
+    
+    manager.cancel_order(order_id, "Operator request".to_string()).await.unwrap();
+    wait_for_status(&manager, order_id, OrderStatus::Cancelled).await;
+    
+    let trail = manager.get_audit_trail(order_id).await;
+    assert_eq!(transitions(&trail), vec![
+        (None, OrderStatus::Created),
+        (Some(OrderStatus::Created), OrderStatus::PendingSubmission),
+        (Some(OrderStatus::PendingSubmission), OrderStatus::Submitted),
+        (Some(OrderStatus::Submitted), OrderStatus::Cancelled),
+    ]);
+    assert_eq!(trail.last().unwrap().reason, "Operator request");
+    assert!(trail.iter().all(|e| e.order_id == order_id));
+    assert!(trail.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+}
+
+#[test]
+async fn test_audit_trail_records_failure_reason() {
+    // No exchanges registered, so submission fails
+    let manager = OrderManager::new();
+    let order_id = manager.place_order(create_test_order("Missing Exchange")).await.unwrap();
+    wait_for_status(&manager, order_id, OrderStatus::Failed).await;
+    
+    let trail = manager.get_audit_trail(order_id).await;
+    assert_eq!(transitions(&trail), vec![
+        (None, OrderStatus::Created),
+        (Some(OrderStatus::Created), OrderStatus::PendingSubmission),
+        (Some(OrderStatus::PendingSubmission), OrderStatus::Failed),
+    ]);
+    assert!(trail.last().unwrap().reason.contains("Missing Exchange"));
+}
+
+#[test]
+async fn test_audit_trail_for_unknown_order_is_empty() {
+    let manager = OrderManager::new();
+    assert!(manager.get_audit_trail(Uuid::new_v4()).await.is_empty());
+}
+
+// Store that counts appends while delegating to the in-memory store
+struct CountingStore {
+    inner: InMemoryAuditStore,
+    appends: AtomicUsize,
+}
+
+#[async_trait]
+impl AuditStore for CountingStore {
+    async fn append(&self, entry: AuditEntry) -> Result<(), String> {
+        self.appends.fetch_add(1, Ordering::SeqCst);
+        self.inner.append(entry).await
+    }
+    
+    async fn entries_for_order(&self, order_id: Uuid) -> Result<Vec<AuditEntry>, String> {
+        self.inner.entries_for_order(order_id).await
+    }
+}
+
+#[test]
+async fn test_custom_audit_store_receives_entries() {
+    let store = Arc::new(CountingStore {
+        inner: InMemoryAuditStore::new(),
+        appends: AtomicUsize::new(0),
+    });
+    let manager = OrderManager::with_audit_store(store.clone());
+    
+    let order_id = manager.place_order(create_test_order("Missing Exchange")).await.unwrap();
+    wait_for_status(&manager, order_id, OrderStatus::Failed).await;
+    
+    assert_eq!(store.appends.load(Ordering::SeqCst), 3);
+    assert_eq!(manager.get_audit_trail(order_id).await.len(), 3);
+}
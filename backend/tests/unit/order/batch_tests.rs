@@ -0,0 +1,51 @@
+use arb_platform::error::TradingError;
+use arb_platform::order::{Order, OrderManager, OrderType};
+use arb_platform::strategy::TimeInForce;
+
+use uuid::Uuid;
+
+use crate::helpers::orders::test_order;
+
+fn create_order(symbol: &str, quantity: f64) -> Order {
+    Order {
+        symbol: symbol.to_string(),
+        order_type: OrderType::Market,
+        quantity,
+        price: None,
+        time_in_force: TimeInForce::ImmediateOrCancel,
+        exchange: "Test Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+#[tokio::test]
+async fn test_batch_reports_invalid_order_at_its_index() {
+    let manager = OrderManager::new();
+    let orders = vec![
+        create_order("BTC/USD", 1.0),
+        create_order("ETH/USD", 2.0),
+        create_order("SOL/USD", -1.0),
+        create_order("ADA/USD", 3.0),
+    ];
+    let ids: Vec<Uuid> = orders.iter().map(|o| o.id).collect();
+    
+    let results = manager.place_orders(orders).await;
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[2], Err(TradingError::Validation("Order quantity must be positive".to_string())));
+    
+    // Valid orders succeed in input order despite the failure between them
+    for index in [0, 1, 3] {
+        assert_eq!(results[index], Ok(ids[index]));
+    }
+    for (index, symbol) in [(0, "BTC/USD"), (1, "ETH/USD"), (3, "ADA/USD")] {
+        assert_eq!(manager.get_order(ids[index]).await.unwrap().symbol, symbol);
+    }
+    assert!(manager.get_order(ids[2]).await.is_none());
+}
+
+#[tokio::test]
+async fn test_empty_batch_places_nothing() {
+    let manager = OrderManager::new();
+    assert!(manager.place_orders(Vec::new()).await.is_empty());
+    assert!(manager.get_active_orders().await.is_empty());
+}
@@ -0,0 +1,99 @@
+use arb_platform::exchange::FeeSchedule;
+use arb_platform::order::{Order, OrderRouter, OrderType, RoutingPolicy};
+use arb_platform::strategy::TradeDirection;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::collections::BTreeMap;
+
+fn create_order(symbol: &str, direction: TradeDirection) -> Order {
+    Order {
+        symbol: symbol.to_string(),
+        direction,
+        order_type: OrderType::Market,
+        price: None,
+        ..test_order()
+    }
+}
+
+fn fees(maker_bps: f64, taker_bps: f64) -> FeeSchedule {
+    FeeSchedule { maker_bps, taker_bps, withdrawal_fees: BTreeMap::new() }
+}
+
+// "Cheap" quotes the better prices but charges 50 bps to take, "Lean" quotes
+// 10 cents worse either side at 10 bps; "Primary" is BTC/USD's primary
+async fn create_router(policy: RoutingPolicy) -> (OrderRouter, MockExchange, MockExchange) {
+    let router = OrderRouter::new();
+    let cheap = MockExchange::new("Cheap");
+    cheap.set_quote(100.00, 100.00);
+    cheap.set_fee_schedule(fees(0.0, 50.0));
+    let lean = MockExchange::new("Lean");
+    lean.set_quote(99.90, 100.10);
+    lean.set_fee_schedule(fees(0.0, 10.0));
+    let primary = MockExchange::new("Primary");
+    primary.set_quote(99.00, 101.00);
+    
+    for exchange in [&cheap, &lean, &primary] {
+        router.register_exchange(Box::new(exchange.clone())).await.unwrap();
+    }
+    router.set_primary_exchange("BTC/USD", "Primary").await.unwrap();
+    router.set_routing_policy(policy).await;
+    (router, cheap, lean)
+}
+
+#[tokio::test]
+async fn test_primary_policy_ignores_prices() {
+    let (router, cheap, _) = create_router(RoutingPolicy::default()).await;
+    assert_eq!(router.routing_policy().await, RoutingPolicy::Primary);
+    assert_eq!(router.submit_order(create_order("BTC/USD", TradeDirection::Buy)).await.unwrap(), "Primary");
+    assert!(cheap.calls().is_empty());
+}
+
+#[tokio::test]
+async fn test_best_price_buys_at_the_lowest_ask_and_sells_at_the_highest_bid() {
+    let (router, cheap, _) = create_router(RoutingPolicy::BestPrice).await;
+    assert_eq!(router.submit_order(create_order("BTC/USD", TradeDirection::Buy)).await.unwrap(), "Cheap");
+    assert_eq!(router.submit_order(create_order("BTC/USD", TradeDirection::Sell)).await.unwrap(), "Cheap");
+    assert_eq!(cheap.submitted_orders().len(), 2);
+    
+    // An order naming its exchange still goes there
+    let order = Order { exchange: "Lean".to_string(), ..create_order("BTC/USD", TradeDirection::Buy) };
+    assert_eq!(router.submit_order(order).await.unwrap(), "Lean");
+}
+
+#[tokio::test]
+async fn test_fees_can_outweigh_a_better_price() {
+    // Buying: 100.00 + 50 bps is 100.50, against 100.10 + 10 bps at 100.2001
+    let (router, cheap, lean) = create_router(RoutingPolicy::BestPriceAfterFees).await;
+    assert_eq!(router.submit_order(create_order("BTC/USD", TradeDirection::Buy)).await.unwrap(), "Lean");
+    // Selling: 100.00 - 50 bps is 99.50, against 99.90 - 10 bps at 99.8001
+    assert_eq!(router.submit_order(create_order("BTC/USD", TradeDirection::Sell)).await.unwrap(), "Lean");
+    assert_eq!(lean.submitted_orders().len(), 2);
+    assert!(cheap.submitted_orders().is_empty());
+    
+    // Limit orders pay the maker fee, which neither charges
+    let order = Order { order_type: OrderType::Limit, price: Some(100.0.into()), ..create_order("BTC/USD", TradeDirection::Buy) };
+    assert_eq!(router.submit_order(order).await.unwrap(), "Cheap");
+}
+
+#[tokio::test]
+async fn test_best_price_passes_over_exchanges_that_cannot_take_the_order() {
+    let (router, _, _) = create_router(RoutingPolicy::BestPrice).await;
+    let offline = MockExchange::disconnected("Offline");
+    offline.set_quote(50.0, 50.0);
+    router.register_exchange(Box::new(offline)).await.unwrap();
+    assert_eq!(router.submit_order(create_order("BTC/USD", TradeDirection::Buy)).await.unwrap(), "Cheap");
+    
+    // Nobody lists DOGE, so routing falls back to its primary exchange
+    router.set_primary_exchange("DOGE/USD", "Primary").await.unwrap();
+    assert_eq!(router.get_market_data(&create_order("DOGE/USD", TradeDirection::Buy)).await.unwrap().ask.to_f64(), 101.0);
+}
+
+#[test]
+fn test_routing_policy_parses_from_its_name() {
+    assert_eq!("primary".parse::<RoutingPolicy>(), Ok(RoutingPolicy::Primary));
+    assert_eq!("BestPrice".parse::<RoutingPolicy>(), Ok(RoutingPolicy::BestPrice));
+    assert_eq!("best_price_after_fees".parse::<RoutingPolicy>(), Ok(RoutingPolicy::BestPriceAfterFees));
+    assert!("cheapest".parse::<RoutingPolicy>().is_err());
+}
@@ -0,0 +1,112 @@
+use arb_platform::notifications::{NotificationLevel, NotificationManager};
+use arb_platform::order::{Order, OrderManager};
+use arb_platform::risk::CircuitBreaker;
+use arb_platform::strategy::TradeDirection;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::recording_notifier::RecordingNotifier;
+use crate::helpers::orders::test_order;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+fn create_order() -> Order {
+    Order {
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+// 5% intraday limit on 1,000 of capital
+async fn manager_with_breaker() -> (OrderManager, RecordingNotifier) {
+    let recording = RecordingNotifier::new();
+    let mut notifier = NotificationManager::new();
+    notifier.register_handler(Box::new(recording.clone()));
+
+    let mut manager = OrderManager::new();
+    manager.set_circuit_breaker(Some(CircuitBreaker::new(0.05, 1000.0).unwrap()));
+    manager.set_notification_manager(Arc::new(notifier));
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    (manager, recording)
+}
+
+#[tokio::test]
+async fn test_losses_past_threshold_halt_new_orders() {
+    let (manager, recording) = manager_with_breaker().await;
+    let positions = manager.get_position_manager();
+
+    // A 30 loss is within the limit
+    positions.apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    positions.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 70.0).await;
+    assert!(manager.place_order(create_order()).await.is_ok());
+
+    // Another 30 takes equity to 940, 6% below its peak
+    positions.apply_fill("ETH/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    positions.apply_fill("ETH/USD", TradeDirection::Sell, 1.0, 70.0).await;
+    let err = manager.place_order(create_order()).await.unwrap_err();
+    assert!(err.to_string().contains("circuit breaker"), "unexpected error: {}", err);
+    assert!(manager.place_order(create_order()).await.is_err());
+
+    let status = manager.circuit_breaker_status().await.unwrap();
+    assert!(status.halted);
+    assert_eq!(status.current_equity, 940.0);
+
+    let sent = recording.wait_for(1).await;
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].level, NotificationLevel::Critical);
+    assert_eq!(sent[0].title, "Trading halted by circuit breaker");
+}
+
+#[tokio::test]
+async fn test_open_orders_can_be_cancelled_while_halted() {
+    let (manager, _recording) = manager_with_breaker().await;
+    let order_id = manager.place_order(create_order()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let positions = manager.get_position_manager();
+    positions.apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    positions.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 40.0).await;
+    assert!(manager.place_order(create_order()).await.is_err());
+
+    manager.cancel_order(order_id, "Halted".to_string()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_reset_resumes_trading() {
+    let (manager, _recording) = manager_with_breaker().await;
+    let positions = manager.get_position_manager();
+    positions.apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    positions.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 40.0).await;
+    assert!(manager.place_order(create_order()).await.is_err());
+
+    manager.reset_circuit_breaker().await;
+    assert!(manager.place_order(create_order()).await.is_ok());
+    assert!(!manager.circuit_breaker_status().await.unwrap().halted);
+}
+
+#[tokio::test]
+async fn test_status_does_not_trip_the_breaker() {
+    let (manager, recording) = manager_with_breaker().await;
+    let positions = manager.get_position_manager();
+    positions.apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    positions.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 40.0).await;
+
+    assert!(!manager.circuit_breaker_status().await.unwrap().halted);
+    assert!(recording.sent().is_empty());
+
+    manager.refresh_circuit_breaker().await;
+    let status = manager.circuit_breaker_status().await.unwrap();
+    assert!(status.halted);
+    assert_eq!(status.current_equity, 940.0);
+    assert_eq!(recording.wait_for(1).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_no_breaker_by_default() {
+    let manager = OrderManager::new();
+    manager.get_position_manager().apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    manager.get_position_manager().apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 1.0).await;
+
+    assert!(manager.circuit_breaker_status().await.is_none());
+    assert!(manager.place_order(create_order()).await.is_ok());
+}
@@ -0,0 +1,112 @@
+use arb_platform::order::{
+    ClientOrderIdGenerator, Order, OrderManager, OrderType
+};
+use arb_platform::strategy::TimeInForce;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::helpers::orders::test_order;
+
+fn create_order(strategy_id: Option<&str>) -> Order {
+    Order {
+        order_type: OrderType::Market,
+        price: None,
+        time_in_force: TimeInForce::ImmediateOrCancel,
+        exchange: "Test Exchange".to_string(),
+        strategy_id: strategy_id.map(|s| s.to_string()),
+        ..test_order()
+    }
+}
+
+fn sequence_number(client_order_id: &str) -> u64 {
+    client_order_id.rsplit('-').next().unwrap().parse().unwrap()
+}
+
+#[test]
+fn test_generator_produces_ordered_ids() {
+    let generator = ClientOrderIdGenerator::starting_at("ARB", 9);
+    let ids: Vec<String> = (0..3).map(|_| generator.next_id()).collect();
+    
+    assert_eq!(ids, vec!["ARB-000000000009", "ARB-000000000010", "ARB-000000000011"]);
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_generator_is_thread_safe() {
+    let generator = Arc::new(ClientOrderIdGenerator::new("T"));
+    
+    let handles: Vec<_> = (0..8).map(|_| {
+        let generator = generator.clone();
+        std::thread::spawn(move || (0..250).map(|_| generator.next_id()).collect::<Vec<_>>())
+    }).collect();
+    
+    let mut all = Vec::new();
+    for handle in handles {
+        let ids = handle.join().unwrap();
+        // Each thread observes its own IDs in strictly increasing order
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        all.extend(ids);
+    }
+    
+    let unique: HashSet<_> = all.iter().collect();
+    assert_eq!(unique.len(), 2000);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_placements_get_unique_increasing_ids() {
+    let mut manager = OrderManager::new();
+    manager.set_client_id_prefix("ARB");
+    let manager = Arc::new(manager);
+    
+    let handles: Vec<_> = (0..100).map(|_| {
+        let manager = manager.clone();
+        tokio::spawn(async move { manager.place_order(create_order(None)).await.unwrap() })
+    }).collect();
+    
+    let mut client_ids = Vec::new();
+    for handle in handles {
+        let order_id = handle.await.unwrap();
+        client_ids.push(manager.get_order(order_id).await.unwrap().client_order_id);
+    }
+    
+    let unique: HashSet<_> = client_ids.iter().collect();
+    assert_eq!(unique.len(), 100);
+    assert!(client_ids.iter().all(|id| id.starts_with("ARB-")));
+    
+    // The sequence has no gaps, and string order matches numeric order
+    client_ids.sort();
+    let sequence: Vec<u64> = client_ids.iter().map(|id| sequence_number(id)).collect();
+    assert_eq!(sequence, (1..=100).collect::<Vec<u64>>());
+}
+
+#[tokio::test]
+async fn test_strategy_prefix_overrides_global_sequence() {
+    let mut manager = OrderManager::new();
+    manager.set_client_id_prefix("GLOBAL");
+    manager.set_strategy_client_id_prefix("momentum", "MOM");
+    
+    let global_first = manager.place_order(create_order(Some("other"))).await.unwrap();
+    let momentum_first = manager.place_order(create_order(Some("momentum"))).await.unwrap();
+    let global_second = manager.place_order(create_order(None)).await.unwrap();
+    let momentum_second = manager.place_order(create_order(Some("momentum"))).await.unwrap();
+    
+    let client_id = |id| {
+        let manager = &manager;
+        async move { manager.get_order(id).await.unwrap().client_order_id }
+    };
+    assert_eq!(client_id(global_first).await, "GLOBAL-000000000001");
+    assert_eq!(client_id(global_second).await, "GLOBAL-000000000002");
+    assert_eq!(client_id(momentum_first).await, "MOM-000000000001");
+    assert_eq!(client_id(momentum_second).await, "MOM-000000000002");
+}
+
+#[tokio::test]
+async fn test_client_id_is_kept_without_generator() {
+    let manager = OrderManager::new();
+    let order = create_order(None);
+    let original = order.client_order_id.clone();
+    
+    let order_id = manager.place_order(order).await.unwrap();
+    assert_eq!(manager.get_order(order_id).await.unwrap().client_order_id, original);
+}
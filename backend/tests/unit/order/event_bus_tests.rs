@@ -0,0 +1,67 @@
+use arb_platform::order::{Order, OrderEvent, OrderManager, OrderStatus, ORDER_EVENT_BROADCAST_CAPACITY};
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+fn create_order() -> Order {
+    Order {
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+// Events already delivered to a subscriber, as Debug strings for comparison
+fn drain(receiver: &mut broadcast::Receiver<OrderEvent>) -> Vec<String> {
+    let mut events = Vec::new();
+    while let Ok(event) = receiver.try_recv() {
+        events.push(format!("{:?}", event));
+    }
+    events
+}
+
+#[tokio::test]
+async fn test_every_subscriber_sees_every_event() {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    let mut first = manager.subscribe_events();
+    let mut second = manager.subscribe_events();
+    
+    let order_id = manager.place_order(create_order()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    manager.cancel_order(order_id, "test".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    
+    let first_events = drain(&mut first);
+    assert_eq!(first_events, drain(&mut second));
+    assert!(first_events.first().unwrap().starts_with("New("), "{:?}", first_events);
+    assert!(first_events.iter().any(|event| event.starts_with("Update {") && event.contains("Submitted")), "{:?}", first_events);
+    assert!(first_events.last().unwrap().starts_with("Cancel {"), "{:?}", first_events);
+    
+    // Published after processing, so the order already reflects the last event
+    assert_eq!(manager.get_order(order_id).await.unwrap().status, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_lagging_subscriber_does_not_block_processing() {
+    let manager = OrderManager::new();
+    let mut idle = manager.subscribe_events();
+    let sender = manager.get_event_sender();
+    
+    let total = ORDER_EVENT_BROADCAST_CAPACITY + 10;
+    for index in 0..total {
+        sender.send(OrderEvent::Error { order_id: None, message: format!("event {}", index) }).await.unwrap();
+    }
+    // Processing gets through everything without the idle subscriber reading
+    let mut active = manager.subscribe_events();
+    sender.send(OrderEvent::Error { order_id: None, message: "after".to_string() }).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while !format!("{:?}", active.recv().await.unwrap()).contains("after") {}
+    }).await.unwrap();
+    
+    assert_eq!(idle.recv().await.unwrap_err(), RecvError::Lagged(11));
+    let event = idle.recv().await.unwrap();
+    assert!(format!("{:?}", event).contains("event 11"), "{:?}", event);
+}
@@ -0,0 +1,101 @@
+use arb_platform::order::{JournalEntry, Order, OrderEvent, OrderManager};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
+
+use chrono::Utc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::helpers::orders::test_order;
+
+fn create_order(quantity: f64) -> Order {
+    Order {
+        quantity,
+        price: Some(Price::from(105.0)),
+        exchange: "Test Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+async fn report_fill(manager: &OrderManager, order_id: Uuid, filled: f64, avg_price: Option<f64>) {
+    manager.get_event_sender().send(OrderEvent::Update {
+        order_id,
+        status: None,
+        filled_qty: Some(filled),
+        avg_fill_price: avg_price,
+    }).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_partial_fills_are_recorded_as_executions() {
+    let manager = OrderManager::new();
+    let order_id = manager.place_order(create_order(1.0)).await.unwrap();
+    
+    // Cumulative reports: 0.4 at 100, then 1.0 at an average of 102
+    report_fill(&manager, order_id, 0.4, Some(100.0)).await;
+    report_fill(&manager, order_id, 1.0, Some(102.0)).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    
+    let executions = manager.get_executions(order_id).await;
+    assert_eq!(executions.len(), 2);
+    assert!((executions[0].fill_qty - 0.4).abs() < 1e-9);
+    assert!((executions[0].fill_price - 100.0).abs() < 1e-9);
+    assert!((executions[1].fill_qty - 0.6).abs() < 1e-9);
+    assert!((executions[1].fill_price - 310.0 / 3.0).abs() < 1e-9);
+    assert!(executions.iter().all(|e| e.order_id == order_id && e.exchange == "Test Exchange"));
+    
+    let order = manager.get_order(order_id).await.unwrap();
+    let total: f64 = executions.iter().map(|e| e.fill_qty).sum();
+    assert!((total - order.filled_quantity).abs() < 1e-9);
+    
+    // Notional of the executions matches the reported average
+    let notional: f64 = executions.iter().map(|e| e.fill_qty * e.fill_price).sum();
+    assert!((notional / total - 102.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_repeated_report_adds_no_execution() {
+    let manager = OrderManager::new();
+    let order_id = manager.place_order(create_order(1.0)).await.unwrap();
+    
+    report_fill(&manager, order_id, 0.5, Some(100.0)).await;
+    report_fill(&manager, order_id, 0.5, Some(100.0)).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    
+    assert_eq!(manager.get_executions(order_id).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_fill_without_average_uses_order_price() {
+    let manager = OrderManager::new();
+    let order_id = manager.place_order(create_order(1.0)).await.unwrap();
+    
+    report_fill(&manager, order_id, 0.25, None).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    
+    let executions = manager.get_executions(order_id).await;
+    assert_eq!(executions.len(), 1);
+    assert_eq!(executions[0].fill_price, 105.0);
+}
+
+#[tokio::test]
+async fn test_unknown_order_has_no_executions() {
+    let manager = OrderManager::new();
+    assert!(manager.get_executions(Uuid::new_v4()).await.is_empty());
+}
+
+#[test]
+fn test_journal_row_quotes_fields_with_separators() {
+    let order_id = Uuid::new_v4();
+    let timestamp = Utc::now();
+    let entry = JournalEntry {
+        order_id,
+        symbol: "ODD,\"SYM\"".to_string(),
+        side: TradeDirection::Sell,
+        quantity: 0.5,
+        price: 101.25,
+        fee: Some(0.1),
+        timestamp,
+    };
+    assert_eq!(entry.to_csv_row(), format!("{},\"ODD,\"\"SYM\"\"\",sell,0.5,101.25,0.1,{}\n", order_id, timestamp.to_rfc3339()));
+}
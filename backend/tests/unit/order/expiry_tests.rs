@@ -0,0 +1,41 @@
+use arb_platform::clock::{Clock, MockClock};
+use arb_platform::order::{Order, OrderManager};
+use arb_platform::strategy::TimeInForce;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use chrono::{Duration, TimeZone, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn create_order(time_in_force: TimeInForce) -> Order {
+    Order {
+        time_in_force,
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+#[tokio::test]
+async fn test_day_orders_expire_when_the_day_ends() {
+    let clock = Arc::new(MockClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 15, 30, 0).unwrap()));
+    let mut manager = OrderManager::new();
+    manager.set_clock(clock.clone());
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    
+    let day_order = manager.place_order(create_order(TimeInForce::Day)).await.unwrap();
+    let gtc_order = manager.place_order(create_order(TimeInForce::GoodTilCancelled)).await.unwrap();
+    assert_eq!(manager.get_order(day_order).await.unwrap().created_at, clock.now());
+    
+    // Still the same trading day
+    clock.advance(Duration::hours(8));
+    assert!(manager.expire_day_orders().await.is_empty());
+    
+    clock.advance(Duration::minutes(31));
+    assert_eq!(manager.expire_day_orders().await, vec![day_order]);
+    
+    let active: Vec<Uuid> = manager.get_active_orders().await.iter().map(|order| order.id).collect();
+    assert_eq!(active, vec![gtc_order]);
+    assert!(manager.expire_day_orders().await.is_empty());
+}
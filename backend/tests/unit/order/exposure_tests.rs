@@ -0,0 +1,125 @@
+use arb_platform::exchange::Position;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::order::{Order, OrderManager, OrderType};
+use arb_platform::strategy::{AssetData, AssetType, TradeDirection};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+
+use crate::helpers::orders::test_order;
+
+fn create_order(symbol: &str, direction: TradeDirection, quantity: f64, price: Option<f64>) -> Order {
+    Order {
+        symbol: symbol.to_string(),
+        direction,
+        order_type: if price.is_some() { OrderType::Limit } else { OrderType::Market },
+        quantity,
+        price: price.map(Price::from),
+        exchange: "Test Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+fn create_position(symbol: &str, quantity: f64, price: f64) -> Position {
+    Position {
+        symbol: symbol.to_string(),
+        quantity,
+        avg_price: price,
+        current_price: price,
+        unrealized_pnl: 0.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }
+}
+
+async fn market_data_with_prices(prices: &[(&str, f64)]) -> MarketDataManager {
+    let manager = MarketDataManager::new();
+    {
+        let current_data = manager.get_current_data();
+        let mut data = current_data.write().await;
+        for (symbol, price) in prices {
+            data.asset_data.insert(symbol.to_string(), AssetData {
+                symbol: symbol.to_string(),
+                asset_type: AssetType::Crypto,
+                price: Price::from(*price),
+                volume: 0.0,
+                bid: Price::from(*price),
+                ask: Price::from(*price),
+                tick_size: None,
+                lot_size: None,
+                exchange: "Simulated".to_string(),
+                last_update: Utc::now(),
+            });
+        }
+    }
+    manager
+}
+
+// 10,000 USD of BTC plus 4 ETH quoted in BTC, worth 0.2 BTC = 10,000 USD
+async fn manager_with_positions(max_exposure: Option<f64>) -> OrderManager {
+    let market_data = market_data_with_prices(&[("BTC/USD", 50000.0), ("ETH/BTC", 0.05)]).await;
+    let mut manager = OrderManager::new();
+    manager.set_price_converter(market_data.get_price_converter(), "USD");
+    manager.set_max_total_exposure(max_exposure);
+
+    let position_manager = manager.get_position_manager();
+    position_manager.update_position(create_position("BTC/USD", 0.2, 50000.0)).await;
+    position_manager.update_position(create_position("ETH/BTC", 4.0, 0.05)).await;
+    manager
+}
+
+#[tokio::test]
+async fn test_total_exposure_converts_quote_currencies() {
+    let manager = manager_with_positions(None).await;
+    assert_eq!(manager.base_currency(), "USD");
+    assert!((manager.total_exposure().await.unwrap() - 20000.0).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn test_total_exposure_without_converter_uses_raw_values() {
+    let manager = OrderManager::new();
+    manager.get_position_manager().update_position(create_position("ETH/BTC", 4.0, 0.05)).await;
+    assert!((manager.total_exposure().await.unwrap() - 0.2).abs() < 1e-12);
+}
+
+#[tokio::test]
+async fn test_order_within_exposure_limit_is_accepted() {
+    let manager = manager_with_positions(Some(25000.0)).await;
+
+    // 1 ETH at 0.05 BTC adds 2,500 USD
+    let order = create_order("ETH/BTC", TradeDirection::Buy, 1.0, Some(0.05));
+    assert!(manager.place_order(order).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_order_breaching_exposure_limit_is_rejected() {
+    let manager = manager_with_positions(Some(25000.0)).await;
+
+    // 3 ETH at 0.05 BTC adds 7,500 USD
+    let order = create_order("ETH/BTC", TradeDirection::Buy, 3.0, Some(0.05));
+    let err = manager.place_order(order).await.unwrap_err();
+    assert!(err.to_string().contains("total exposure"), "unexpected error: {}", err);
+
+    // Market orders are priced from the held position
+    let order = create_order("BTC/USD", TradeDirection::Buy, 0.2, None);
+    assert!(manager.place_order(order).await.is_err());
+}
+
+#[tokio::test]
+async fn test_order_reducing_exposure_is_accepted_over_the_limit() {
+    let manager = manager_with_positions(Some(5000.0)).await;
+
+    let order = create_order("BTC/USD", TradeDirection::Sell, 0.1, Some(50000.0));
+    assert!(manager.place_order(order).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_order_in_unconvertible_currency_is_rejected() {
+    let manager = manager_with_positions(Some(1_000_000.0)).await;
+
+    let order = create_order("SOL/EUR", TradeDirection::Buy, 1.0, Some(100.0));
+    let err = manager.place_order(order).await.unwrap_err();
+    assert!(err.to_string().contains("No EUR/USD rate"), "unexpected error: {}", err);
+}
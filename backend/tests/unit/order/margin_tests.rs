@@ -0,0 +1,96 @@
+use arb_platform::error::TradingError;
+use arb_platform::exchange::MarginInfo;
+use arb_platform::order::{Order, OrderManager, OrderType};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::{ExchangeCall, MockExchange};
+use crate::helpers::orders::test_order;
+
+fn create_order(quantity: f64, price: Option<f64>) -> Order {
+    Order {
+        order_type: if price.is_some() { OrderType::Limit } else { OrderType::Market },
+        quantity,
+        price: price.map(Price::from),
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+fn margin(available_margin: f64, max_leverage: f64) -> MarginInfo {
+    MarginInfo {
+        available_margin,
+        used_margin: 0.0,
+        margin_level: 0.0,
+        max_leverage,
+        liquidation_price: None,
+    }
+}
+
+async fn manager_with_exchange(exchange: &MockExchange) -> OrderManager {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(exchange.clone())).await.unwrap();
+    manager
+}
+
+#[test]
+fn test_required_margin_uses_max_leverage() {
+    assert_eq!(margin(1000.0, 5.0).required_margin(2.0, 100.0), 40.0);
+    assert_eq!(margin(1000.0, 5.0).required_margin(-2.0, 100.0), 40.0);
+    // Leverage below 1 is treated as unleveraged
+    assert_eq!(margin(1000.0, 0.0).required_margin(2.0, 100.0), 200.0);
+}
+
+#[tokio::test]
+async fn test_order_beyond_available_margin_is_rejected() {
+    let exchange = MockExchange::new("Mock");
+    exchange.set_margin_info(margin(1000.0, 5.0));
+    let manager = manager_with_exchange(&exchange).await;
+
+    let error = manager.place_order(create_order(60.0, Some(100.0))).await.unwrap_err();
+    assert_eq!(error, TradingError::InsufficientMargin { required: 1200.0, available: 1000.0 });
+    assert_eq!(error.to_string(), "Insufficient margin: order requires 1200.00, 1000.00 available");
+    assert!(manager.get_active_orders().await.is_empty());
+    assert!(exchange.submitted_orders().is_empty());
+
+    assert!(manager.place_order(create_order(50.0, Some(100.0))).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_market_orders_are_checked_at_the_market_price() {
+    // MockExchange quotes 100 for every symbol
+    let exchange = MockExchange::new("Mock");
+    exchange.set_margin_info(margin(1000.0, 2.0));
+    let manager = manager_with_exchange(&exchange).await;
+
+    let error = manager.place_order(create_order(25.0, None)).await.unwrap_err();
+    assert_eq!(error, TradingError::InsufficientMargin { required: 1250.0, available: 1000.0 });
+    assert_eq!(error.to_string(), "Insufficient margin: order requires 1250.00, 1000.00 available");
+    assert!(manager.place_order(create_order(20.0, None)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_exchanges_without_margin_are_not_checked() {
+    let exchange = MockExchange::new("Mock");
+    let manager = manager_with_exchange(&exchange).await;
+
+    assert!(manager.place_order(create_order(1_000_000.0, Some(100.0))).await.is_ok());
+    assert!(exchange.calls().iter().any(|call| matches!(call, ExchangeCall::GetMarginInfo { symbol } if symbol == "BTC/USD")));
+}
+
+#[tokio::test]
+async fn test_margin_lookup_failure_rejects_the_order() {
+    let exchange = MockExchange::new("Mock");
+    exchange.fail_margin_info(TradingError::NotConnected("Mock".to_string()));
+    let manager = manager_with_exchange(&exchange).await;
+
+    let error = manager.place_order(create_order(1.0, Some(100.0))).await.unwrap_err();
+    assert_eq!(error, TradingError::NotConnected("Mock".to_string()));
+    assert!(exchange.submitted_orders().is_empty());
+}
+
+#[tokio::test]
+async fn test_orders_for_unknown_exchanges_skip_the_margin_check() {
+    let manager = OrderManager::new();
+    let order = Order { exchange: "Nowhere".to_string(), ..create_order(1.0, Some(100.0)) };
+    assert!(manager.place_order(order).await.is_ok());
+}
@@ -0,0 +1,74 @@
+use arb_platform::error::TradingError;
+use arb_platform::market_data::{MarketDataManager, MarketEvent};
+use arb_platform::order::{Order, OrderManager};
+use arb_platform::strategy::TradeDirection;
+
+use chrono::Utc;
+use std::time::Duration;
+
+use crate::helpers::orders::test_order;
+
+fn create_order(symbol: &str, direction: TradeDirection, quantity: f64) -> Order {
+    Order {
+        symbol: symbol.to_string(),
+        direction,
+        quantity,
+        exchange: "Test Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+// Market data holding a BTC/USD book with asks at 100 (1.0) and 102 (1.0)
+// and bids at 99 (5.0)
+async fn market_data_with_book() -> MarketDataManager {
+    let mut market_data = MarketDataManager::new();
+    market_data.start_processing().await.unwrap();
+    market_data.get_event_sender().send(MarketEvent::OrderBookUpdate {
+        symbol: "BTC/USD".to_string(),
+        bids: vec![(99.0, 5.0)],
+        asks: vec![(100.0, 1.0), (102.0, 1.0)],
+        exchange: "Test Exchange".to_string(),
+        timestamp: Utc::now(),
+    }).await.unwrap();
+    for _ in 0..100 {
+        if market_data.get_order_book("BTC/USD").is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    market_data
+}
+
+#[tokio::test]
+async fn test_orders_walking_the_book_past_the_limit_are_rejected() {
+    let mut market_data = market_data_with_book().await;
+    let mut manager = OrderManager::new();
+    manager.set_order_books(market_data.get_order_books());
+    manager.set_max_slippage_bps(Some(50.0));
+    assert_eq!(manager.max_slippage_bps(), Some(50.0));
+    
+    // Within the best ask, and a sell the bids absorb
+    assert!(manager.place_order(create_order("BTC/USD", TradeDirection::Buy, 1.0)).await.is_ok());
+    assert!(manager.place_order(create_order("BTC/USD", TradeDirection::Sell, 5.0)).await.is_ok());
+    
+    // Half at 100 and half at 102 averages 101, 100 bps from the best ask
+    let error = manager.place_order(create_order("BTC/USD", TradeDirection::Buy, 2.0)).await.unwrap_err();
+    assert_eq!(error, TradingError::ExcessiveMarketImpact { estimated_bps: 100.0, limit_bps: 50.0 });
+    assert_eq!(error.to_string(), "Estimated market impact of 100.0 bps exceeds the limit of 50.0 bps");
+    
+    // Symbols without a book are not checked
+    assert!(manager.place_order(create_order("ETH/USD", TradeDirection::Buy, 1000.0)).await.is_ok());
+    
+    market_data.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_market_impact_is_unchecked_without_a_limit() {
+    let mut market_data = market_data_with_book().await;
+    let mut manager = OrderManager::new();
+    manager.set_order_books(market_data.get_order_books());
+    
+    assert!(manager.place_order(create_order("BTC/USD", TradeDirection::Buy, 2.0)).await.is_ok());
+    
+    market_data.shutdown().await.unwrap();
+}
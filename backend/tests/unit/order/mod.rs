@@ -1,2 +1,31 @@
 // Order module tests
 pub mod mod_tests;
+pub mod status_poller_tests;
+pub mod audit_tests;
+pub mod statistics_tests;
+pub mod client_id_tests;
+pub mod short_selling_tests;
+pub mod batch_tests;
+pub mod portfolio_var_tests;
+pub mod exposure_tests;
+pub mod notification_tests;
+pub mod execution_tests;
+pub mod tag_tests;
+pub mod circuit_breaker_tests;
+pub mod twap_tests;
+pub mod algo_tests;
+pub mod symbol_halt_tests;
+pub mod expiry_tests;
+pub mod routing_tests;
+pub mod event_bus_tests;
+pub mod symbol_rules_tests;
+pub mod signal_tests;
+pub mod webhook_tests;
+pub mod post_only_tests;
+pub mod margin_tests;
+pub mod order_type_support_tests;
+pub mod reduce_tests;
+pub mod market_impact_tests;
+pub mod status_transition_tests;
+pub mod post_stop_cooldown_tests;
+pub mod best_price_routing_tests;
@@ -2,38 +2,31 @@ use arb_platform::order::{
     Order, OrderType, OrderStatus, OrderManager, OrderEvent
 };
 use arb_platform::strategy::{TradeDirection, TimeInForce};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
 
-use chrono::Utc;
 use std::time::Duration;
 use tokio::test;
 use uuid::Uuid;
 
 fn create_test_order(symbol: &str, direction: TradeDirection, order_type: OrderType) -> Order {
     Order {
-        id: Uuid::new_v4(),
-        client_order_id: format!("test-{}", Uuid::new_v4().simple()),
         symbol: symbol.to_string(),
         direction,
         order_type: order_type.clone(),
-        quantity: 1.0,
-        filled_quantity: 0.0,
         price: match order_type {
             OrderType::Market => None,
-            _ => Some(35000.0),
+            _ => Some(Price::from(35000.0)),
         },
         stop_price: match order_type {
-            OrderType::StopLoss | OrderType::StopLimit => Some(34500.0),
+            OrderType::StopLoss | OrderType::StopLimit => Some(Price::from(34500.0)),
             _ => None,
         },
-        time_in_force: TimeInForce::GoodTilCancelled,
-        status: OrderStatus::Created,
         exchange: "Test Exchange".to_string(),
-        created_at: Utc::now(),
-        updated_at: Utc::now(),
-        filled_at: None,
-        average_fill_price: None,
         strategy_id: Some("test_strategy".to_string()),
-        notes: None,
+        ..test_order()
     }
 }
 
@@ -121,7 +114,9 @@ async fn test_order_cancellation() {
     // Verify the order status is updated
     let retrieved_order = manager.get_order(order_id).await;
     assert!(retrieved_order.is_some());
-    assert_eq!(retrieved_order.unwrap().status, OrderStatus::Cancelled);
+    let retrieved_order = retrieved_order.unwrap();
+    assert_eq!(retrieved_order.status, OrderStatus::Cancelled);
+    assert_eq!(retrieved_order.unfilled_quantity, Some(order.quantity));
     
     // Verify it's no longer in active orders
     let active_orders = manager.get_active_orders().await;
@@ -173,7 +168,7 @@ async fn test_invalid_order_validation() {
     
     // Create an invalid market order with a price
     let mut invalid_market_order = create_test_order("BTC/USD", TradeDirection::Buy, OrderType::Market);
-    invalid_market_order.price = Some(35000.0); // Market orders shouldn't have a price
+    invalid_market_order.price = Some(Price::from(35000.0)); // Market orders shouldn't have a price
     
     // Attempt to place the order
     let result = manager.place_order(invalid_market_order).await;
@@ -199,6 +194,8 @@ async fn test_invalid_order_validation() {
 #[test]
 async fn test_order_event_emission() {
     let manager = OrderManager::new();
+    // The order has to rest at an exchange before it can fill
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Test Exchange"))).await.unwrap();
     let order = create_test_order("BTC/USD", TradeDirection::Buy, OrderType::Limit);
     
     // Place the order
@@ -235,7 +232,7 @@ async fn test_order_event_emission() {
     let updated_order = updated_order.unwrap();
     assert_eq!(updated_order.status, OrderStatus::PartiallyFilled);
     assert_eq!(updated_order.filled_quantity, 0.5);
-    assert_eq!(updated_order.average_fill_price, Some(35100.0));
+    assert_eq!(updated_order.average_fill_price, Some(Price::from(35100.0)));
 }
 
 #[test]
@@ -289,4 +286,76 @@ async fn test_time_in_force() {
     let mut ioc_order = create_test_order("ADA/USD", TradeDirection::Sell, OrderType::Limit);
     ioc_order.time_in_force = TimeInForce::ImmediateOrCancel;
     assert_eq!(ioc_order.time_in_force, TimeInForce::ImmediateOrCancel);
-} 
\ No newline at end of file
+} 
+#[test]
+async fn test_rejected_order_records_reason() {
+    use arb_platform::exchange::{Exchange, ExchangeConfig, ExchangeType};
+    use arb_platform::exchange::crypto::CryptoExchange;
+    use std::collections::HashMap;
+    
+    let mut additional_params = HashMap::new();
+    additional_params.insert("simulated_latency_ms".to_string(), "0".to_string());
+    additional_params.insert("reject_probability".to_string(), "1.0".to_string());
+    additional_params.insert("simulation_seed".to_string(), "7".to_string());
+    
+    let mut exchange = CryptoExchange::new(ExchangeConfig {
+        name: "Test Exchange".to_string(),
+        exchange_type: ExchangeType::Crypto,
+        api_url: "https://api.example.com".to_string(),
+        api_key: Some("test_key".to_string()),
+        api_secret: Some("test_secret".to_string()),
+        additional_params,
+    });
+    exchange.connect().await.unwrap();
+    
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(exchange)).await.unwrap();
+    
+    let order_id = manager.place_order(create_test_order("BTC/USD", TradeDirection::Buy, OrderType::Market)).await.unwrap();
+    
+    let mut order = None;
+    for _ in 0..50 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        order = manager.get_order(order_id).await;
+        if order.as_ref().map(|o| o.status == OrderStatus::Rejected).unwrap_or(false) {
+            break;
+        }
+    }
+    
+    let order = order.unwrap();
+    assert_eq!(order.status, OrderStatus::Rejected);
+    assert_eq!(order.notes.as_deref(), Some("Simulated rejection by Test Exchange"));
+    assert!(manager.get_active_orders().await.is_empty());
+    
+    let trail = manager.get_audit_trail(order_id).await;
+    assert_eq!(trail.last().unwrap().reason, "Simulated rejection by Test Exchange");
+}
+
+#[test]
+async fn test_order_enums_round_trip_through_strings() {
+    let statuses = [
+        OrderStatus::Created, OrderStatus::PendingSubmission, OrderStatus::Submitted, OrderStatus::PartiallyFilled,
+        OrderStatus::Filled, OrderStatus::Cancelled, OrderStatus::Rejected, OrderStatus::Failed,
+    ];
+    for status in statuses {
+        assert_eq!(status.to_string().parse::<OrderStatus>(), Ok(status.clone()));
+    }
+    let order_types = [OrderType::Market, OrderType::Limit, OrderType::StopLoss, OrderType::StopLimit, OrderType::TrailingStop];
+    for order_type in order_types {
+        assert_eq!(order_type.to_string().parse::<OrderType>(), Ok(order_type.clone()));
+    }
+    
+    assert_eq!(OrderStatus::PartiallyFilled.to_string(), "PartiallyFilled");
+    assert_eq!(OrderType::StopLimit.to_string(), "StopLimit");
+    assert_eq!("partially_filled".parse::<OrderStatus>(), Ok(OrderStatus::PartiallyFilled));
+    assert_eq!("stop".parse::<OrderType>(), Ok(OrderType::StopLoss));
+    assert!("sideways".parse::<OrderStatus>().is_err());
+}
+
+#[test]
+async fn test_order_enums_serialize_in_snake_case() {
+    assert_eq!(serde_json::to_value(OrderStatus::PartiallyFilled).unwrap(), "partially_filled");
+    assert_eq!(serde_json::to_value(OrderType::TrailingStop).unwrap(), "trailing_stop");
+    assert_eq!(serde_json::from_str::<OrderStatus>("\"pending_submission\"").unwrap(), OrderStatus::PendingSubmission);
+    assert!(serde_json::from_str::<OrderType>("\"iceberg\"").is_err());
+}
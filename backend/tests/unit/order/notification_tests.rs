@@ -0,0 +1,84 @@
+use arb_platform::exchange::{rejection_error, Position};
+use arb_platform::notifications::{NotificationLevel, NotificationManager};
+use arb_platform::order::{Order, OrderManager};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::recording_notifier::RecordingNotifier;
+use crate::helpers::orders::test_order;
+
+use chrono::Utc;
+use std::sync::Arc;
+
+fn create_order(quantity: f64) -> Order {
+    Order {
+        quantity,
+        price: Some(Price::from(50000.0)),
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+fn manager_with_notifier() -> (OrderManager, RecordingNotifier) {
+    let recording = RecordingNotifier::new();
+    let mut notifier = NotificationManager::new();
+    notifier.register_handler(Box::new(recording.clone()));
+
+    let mut manager = OrderManager::new();
+    manager.set_notification_manager(Arc::new(notifier));
+    (manager, recording)
+}
+
+#[tokio::test]
+async fn test_risk_limit_breach_sends_warning() {
+    let (mut manager, recording) = manager_with_notifier();
+    manager.set_max_total_exposure(Some(10000.0));
+    manager.get_position_manager().update_position(Position {
+        symbol: "BTC/USD".to_string(),
+        quantity: 0.1,
+        avg_price: 50000.0,
+        current_price: 50000.0,
+        unrealized_pnl: 0.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }).await;
+
+    assert!(manager.place_order(create_order(1.0)).await.is_err());
+
+    let sent = recording.wait_for(1).await;
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].level, NotificationLevel::Warning);
+    assert_eq!(sent[0].title, "Order blocked by risk limit");
+    assert_eq!(sent[0].metadata.get("symbol").map(String::as_str), Some("BTC/USD"));
+}
+
+#[tokio::test]
+async fn test_exchange_rejection_sends_warning() {
+    let (manager, recording) = manager_with_notifier();
+    let exchange = MockExchange::new("Mock");
+    exchange.set_submit_order_response(|_| Err(rejection_error("Insufficient margin")));
+    manager.get_order_router().register_exchange(Box::new(exchange)).await.unwrap();
+
+    let order_id = manager.place_order(create_order(1.0)).await.unwrap();
+
+    let sent = recording.wait_for(1).await;
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].level, NotificationLevel::Warning);
+    assert_eq!(sent[0].body, "Insufficient margin");
+    assert_eq!(sent[0].metadata.get("order_id"), Some(&order_id.to_string()));
+}
+
+#[tokio::test]
+async fn test_submission_failure_sends_critical() {
+    let (manager, recording) = manager_with_notifier();
+
+    // No exchange is registered, so routing fails
+    manager.place_order(create_order(1.0)).await.unwrap();
+
+    let sent = recording.wait_for(1).await;
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].level, NotificationLevel::Critical);
+    assert_eq!(sent[0].title, "Order submission failed");
+}
@@ -0,0 +1,72 @@
+use arb_platform::error::TradingError;
+use arb_platform::order::{Order, OrderManager, OrderRouter, OrderType};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+fn trailing_stop(exchange: &str) -> Order {
+    Order {
+        direction: TradeDirection::Sell,
+        order_type: OrderType::TrailingStop,
+        price: None,
+        stop_price: Some(Price::from(95.0)),
+        exchange: exchange.to_string(),
+        ..test_order()
+    }
+}
+
+// A mock supporting every order type but trailing stops
+fn without_trailing_stops(name: &str) -> MockExchange {
+    let exchange = MockExchange::new(name);
+    exchange.set_supported_order_types(vec![OrderType::Market, OrderType::Limit, OrderType::StopLoss, OrderType::StopLimit]);
+    exchange
+}
+
+#[tokio::test]
+async fn test_unsupported_order_type_is_rejected_before_submission() {
+    let exchange = without_trailing_stops("Mock");
+    let mut manager = OrderManager::new();
+    manager.set_allow_short(true);
+    manager.get_order_router().register_exchange(Box::new(exchange.clone())).await.unwrap();
+
+    let error = manager.place_order(trailing_stop("Mock")).await.unwrap_err();
+    assert_eq!(error, TradingError::Validation("TrailingStop orders are not supported by Mock (supports Market, Limit, StopLoss, StopLimit)".to_string()));
+    assert!(manager.get_active_orders().await.is_empty());
+    assert!(exchange.submitted_orders().is_empty());
+
+    let mut stop_loss = trailing_stop("Mock");
+    stop_loss.order_type = OrderType::StopLoss;
+    assert!(manager.place_order(stop_loss).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_order_type_supported_by_a_fallback_is_routed_there() {
+    let router = OrderRouter::new();
+    let primary = without_trailing_stops("Primary");
+    let secondary = MockExchange::new("Secondary");
+    router.register_exchange(Box::new(primary.clone())).await.unwrap();
+    router.register_exchange(Box::new(secondary.clone())).await.unwrap();
+    router.set_primary_exchange("BTC/USD", "Primary").await.unwrap();
+    router.set_fallback_chain("BTC/USD", vec!["Secondary".to_string()]).await.unwrap();
+
+    let order = trailing_stop("");
+    let order_id = order.id;
+    assert!(router.check_order_type(&order).await.is_ok());
+    assert_eq!(router.submit_order(order).await.unwrap(), "Secondary");
+    assert!(primary.submitted_orders().is_empty());
+    secondary.assert_order_submitted(order_id);
+
+    // With the fallback unable to take it either, both are named
+    secondary.set_supported_order_types(vec![OrderType::Market]);
+    let error = router.check_order_type(&trailing_stop("")).await.unwrap_err();
+    assert_eq!(error, TradingError::Validation("TrailingStop orders are not supported by Primary (supports Market, Limit, StopLoss, StopLimit) or Secondary (supports Market)".to_string()));
+}
+
+#[tokio::test]
+async fn test_orders_without_a_registered_exchange_pass_the_check() {
+    let router = OrderRouter::new();
+    assert!(router.check_order_type(&trailing_stop("Unknown")).await.is_ok());
+    assert!(router.submit_order(trailing_stop("Unknown")).await.is_err());
+}
@@ -0,0 +1,136 @@
+use arb_platform::exchange::Position;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::order::{Order, OrderManager, OrderType};
+use arb_platform::strategy::{AssetData, AssetType, TradeDirection};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+
+use crate::helpers::orders::test_order;
+
+fn create_order(symbol: &str, direction: TradeDirection, quantity: f64, price: Option<f64>) -> Order {
+    Order {
+        symbol: symbol.to_string(),
+        direction,
+        order_type: if price.is_some() { OrderType::Limit } else { OrderType::Market },
+        quantity,
+        price: price.map(Price::from),
+        exchange: "Test Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+// Holding 10,000 of BTC at 2% daily volatility (VaR about 329) with ETH at 4%
+// and perfectly correlated with it
+async fn manager_with_var_limit(max_var: f64) -> OrderManager {
+    let mut manager = OrderManager::new();
+    manager.set_max_portfolio_var(Some(max_var));
+    manager.get_position_manager().update_position(Position {
+        symbol: "BTC/USD".to_string(),
+        quantity: 0.2,
+        avg_price: 50000.0,
+        current_price: 50000.0,
+        unrealized_pnl: 0.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }).await;
+
+    let portfolio = manager.get_portfolio_manager();
+    portfolio.set_volatility("BTC/USD", 0.02).await.unwrap();
+    portfolio.set_volatility("ETH/USD", 0.04).await.unwrap();
+    portfolio.set_correlation("BTC/USD", "ETH/USD", 1.0).await.unwrap();
+    manager
+}
+
+#[tokio::test]
+async fn test_var_limit_disabled_by_default() {
+    assert_eq!(OrderManager::new().max_portfolio_var(), None);
+}
+
+#[tokio::test]
+async fn test_order_within_var_limit_is_accepted() {
+    let manager = manager_with_var_limit(1000.0).await;
+
+    // Adds 80 of risk: VaR 280 * 1.645 ≈ 461
+    let order = create_order("ETH/USD", TradeDirection::Buy, 1.0, Some(2000.0));
+    assert!(manager.place_order(order).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_order_breaching_var_limit_is_rejected() {
+    let manager = manager_with_var_limit(1000.0).await;
+
+    // Adds 480 of risk: VaR 680 * 1.645 ≈ 1,119
+    let order = create_order("ETH/USD", TradeDirection::Buy, 6.0, Some(2000.0));
+    let err = manager.place_order(order).await.unwrap_err();
+    assert!(err.to_string().contains("portfolio VaR"), "unexpected error: {}", err);
+    assert!(manager.get_active_orders().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_order_reducing_var_is_accepted_over_the_limit() {
+    let manager = manager_with_var_limit(100.0).await;
+
+    let order = create_order("BTC/USD", TradeDirection::Sell, 0.1, Some(50000.0));
+    assert!(manager.place_order(order).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_market_order_priced_from_position() {
+    let manager = manager_with_var_limit(500.0).await;
+
+    // Doubling the BTC position at its current price doubles VaR to about 658
+    let order = create_order("BTC/USD", TradeDirection::Buy, 0.2, None);
+    assert!(manager.place_order(order).await.is_err());
+
+    // Nothing to price an ETH market order with, so it is not checked
+    let order = create_order("ETH/USD", TradeDirection::Buy, 0.1, None);
+    assert!(manager.place_order(order).await.is_ok());
+}
+
+async fn set_price(market_data: &MarketDataManager, symbol: &str, price: f64) {
+    let current_data = market_data.get_current_data();
+    current_data.write().await.asset_data.insert(symbol.to_string(), AssetData {
+        symbol: symbol.to_string(),
+        asset_type: AssetType::Crypto,
+        price: Price::from(price),
+        volume: 0.0,
+        bid: Price::from(price),
+        ask: Price::from(price),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Test Exchange".to_string(),
+        last_update: Utc::now(),
+    });
+}
+
+#[tokio::test]
+async fn test_var_is_estimated_from_sampled_market_prices() {
+    let market_data = MarketDataManager::new();
+    let mut manager = OrderManager::new();
+    manager.set_price_converter(market_data.get_price_converter(), "USD");
+    manager.get_position_manager().apply_fill("BTC/USD", TradeDirection::Buy, 0.2, 50000.0).await;
+
+    // Nothing sampled yet, so no volatility and no VaR
+    assert_eq!(manager.record_portfolio_prices().await, 0);
+    assert_eq!(manager.portfolio_var_exposure().await, 0.0);
+
+    // BTC alternating 2% either side of 50,000, a daily sample at a time
+    for i in 0..40 {
+        let price = if i % 2 == 0 { 51000.0 } else { 49000.0 };
+        set_price(&market_data, "BTC/USD", price).await;
+        assert_eq!(manager.record_portfolio_prices().await, 1);
+    }
+    set_price(&market_data, "BTC/USD", 50000.0).await;
+
+    // 10,000 held at about 4% return volatility: VaR near 10,000 * 0.04 * 1.645
+    let var = manager.portfolio_var_exposure().await;
+    assert!((600.0..750.0).contains(&var), "unexpected VaR {}", var);
+
+    manager.set_max_portfolio_var(Some(800.0));
+    let err = manager.place_order(create_order("BTC/USD", TradeDirection::Buy, 0.1, Some(50000.0))).await.unwrap_err();
+    assert!(err.to_string().contains("portfolio VaR"), "unexpected error: {}", err);
+    assert!(manager.place_order(create_order("BTC/USD", TradeDirection::Buy, 0.01, Some(50000.0))).await.is_ok());
+}
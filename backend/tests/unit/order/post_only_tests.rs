@@ -0,0 +1,78 @@
+use arb_platform::error::TradingError;
+use arb_platform::order::{Order, OrderManager, OrderType};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+// MockExchange quotes 99.95 bid, 100.05 ask
+fn post_only_order(direction: TradeDirection, price: f64) -> Order {
+    Order {
+        direction,
+        price: Some(Price::from(price)),
+        exchange: "Mock".to_string(),
+        post_only: true,
+        ..test_order()
+    }
+}
+
+async fn manager_with_exchange() -> OrderManager {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    manager
+}
+
+#[test]
+fn test_crosses_spread() {
+    let (bid, ask) = (Price::from(99.95), Price::from(100.05));
+    assert!(post_only_order(TradeDirection::Buy, 100.05).crosses_spread(bid, ask));
+    assert!(!post_only_order(TradeDirection::Buy, 100.0).crosses_spread(bid, ask));
+    assert!(post_only_order(TradeDirection::Sell, 99.95).crosses_spread(bid, ask));
+    assert!(!post_only_order(TradeDirection::Sell, 100.0).crosses_spread(bid, ask));
+
+    let mut market = post_only_order(TradeDirection::Buy, 100.0);
+    market.price = None;
+    assert!(market.crosses_spread(bid, ask));
+}
+
+#[tokio::test]
+async fn test_crossing_post_only_orders_are_rejected() {
+    let manager = manager_with_exchange().await;
+
+    let error = manager.place_order(post_only_order(TradeDirection::Buy, 100.05)).await.unwrap_err();
+    assert!(error.to_string().contains("would cross the spread"), "{}", error);
+    let error = manager.place_order(post_only_order(TradeDirection::Sell, 99.9)).await.unwrap_err();
+    assert!(error.to_string().contains("would cross the spread"), "{}", error);
+    assert!(manager.get_active_orders().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_resting_post_only_orders_are_accepted() {
+    let manager = manager_with_exchange().await;
+
+    let buy_id = manager.place_order(post_only_order(TradeDirection::Buy, 100.0)).await.unwrap();
+    let sell_id = manager.place_order(post_only_order(TradeDirection::Sell, 100.04)).await.unwrap();
+    assert!(manager.get_order(buy_id).await.unwrap().post_only);
+    assert!(manager.get_order(sell_id).await.is_some());
+}
+
+#[tokio::test]
+async fn test_post_only_requires_a_limit_order() {
+    let manager = manager_with_exchange().await;
+
+    let mut order = post_only_order(TradeDirection::Buy, 100.0);
+    order.order_type = OrderType::Market;
+    order.price = None;
+    assert_eq!(manager.place_order(order).await.unwrap_err(), TradingError::Validation("Post-only orders must be limit orders".to_string()));
+}
+
+#[tokio::test]
+async fn test_post_only_without_market_data_is_rejected() {
+    let manager = manager_with_exchange().await;
+
+    let mut order = post_only_order(TradeDirection::Buy, 100.0);
+    order.exchange = "Unknown".to_string();
+    let error = manager.place_order(order).await.unwrap_err();
+    assert!(error.to_string().starts_with("Cannot check post-only order"), "{}", error);
+}
@@ -0,0 +1,87 @@
+use arb_platform::error::TradingError;
+use arb_platform::order::{Order, OrderEvent, OrderManager, OrderStatus, OrderType};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
+
+use std::time::Duration;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+const COOLDOWN: Duration = Duration::from_millis(300);
+
+fn create_order(direction: TradeDirection, order_type: OrderType, strategy_id: Option<&str>) -> Order {
+    Order {
+        direction,
+        price: (order_type == OrderType::Limit).then(|| Price::from(100.0)),
+        stop_price: (order_type == OrderType::StopLoss).then(|| Price::from(95.0)),
+        order_type,
+        exchange: "Mock".to_string(),
+        strategy_id: strategy_id.map(str::to_string),
+        ..test_order()
+    }
+}
+
+// Long 2 BTC, with the cooldown set
+async fn create_manager() -> OrderManager {
+    let mut manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    manager.get_position_manager().apply_fill("BTC/USD", TradeDirection::Buy, 2.0, 100.0).await;
+    manager.set_post_stop_cooldown(COOLDOWN);
+    manager
+}
+
+async fn fill(manager: &OrderManager, order: Order) {
+    let quantity = order.quantity;
+    let order_id = manager.place_order(order).await.unwrap();
+    // Filled once it has reached the exchange
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    manager.get_event_sender().send(OrderEvent::Update {
+        order_id,
+        status: Some(OrderStatus::Filled),
+        filled_qty: Some(quantity),
+        avg_fill_price: Some(95.0),
+    }).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+}
+
+#[tokio::test]
+async fn test_strategy_entries_are_suppressed_until_the_cooldown_ends() {
+    let manager = create_manager().await;
+    fill(&manager, create_order(TradeDirection::Sell, OrderType::StopLoss, Some("Momentum"))).await;
+    assert!(manager.post_stop_cooldown_remaining("BTC/USD").await.is_some());
+
+    let result = manager.place_order(create_order(TradeDirection::Buy, OrderType::Limit, Some("Momentum"))).await;
+    assert!(matches!(result, Err(TradingError::RiskViolation(ref msg)) if msg.contains("stopped out")), "{:?}", result);
+
+    tokio::time::sleep(COOLDOWN).await;
+    assert_eq!(manager.post_stop_cooldown_remaining("BTC/USD").await, None);
+    assert!(manager.place_order(create_order(TradeDirection::Buy, OrderType::Limit, Some("Momentum"))).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_exits_cancels_and_manual_orders_are_allowed_during_the_cooldown() {
+    let manager = create_manager().await;
+    let resting = manager.place_order(create_order(TradeDirection::Buy, OrderType::Limit, Some("Momentum"))).await.unwrap();
+    fill(&manager, create_order(TradeDirection::Sell, OrderType::StopLoss, Some("Momentum"))).await;
+    assert!(manager.post_stop_cooldown_remaining("BTC/USD").await.is_some());
+
+    // Still long 1 BTC, so a sell reduces the position
+    assert!(manager.place_order(create_order(TradeDirection::Sell, OrderType::Limit, Some("Momentum"))).await.is_ok());
+    assert!(manager.place_order(create_order(TradeDirection::Buy, OrderType::Limit, None)).await.is_ok());
+    manager.cancel_order(resting, "Stopped out".to_string()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_only_stop_fills_start_a_cooldown() {
+    let manager = create_manager().await;
+    fill(&manager, create_order(TradeDirection::Sell, OrderType::Limit, Some("Momentum"))).await;
+    assert_eq!(manager.post_stop_cooldown_remaining("BTC/USD").await, None);
+    assert!(manager.place_order(create_order(TradeDirection::Buy, OrderType::Limit, Some("Momentum"))).await.is_ok());
+
+    // Without a cooldown a stop fill holds nothing back
+    let mut manager = create_manager().await;
+    manager.set_post_stop_cooldown(Duration::ZERO);
+    fill(&manager, create_order(TradeDirection::Sell, OrderType::StopLoss, Some("Momentum"))).await;
+    assert!(manager.place_order(create_order(TradeDirection::Buy, OrderType::Limit, Some("Momentum"))).await.is_ok());
+}
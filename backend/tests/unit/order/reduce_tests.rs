@@ -0,0 +1,91 @@
+use arb_platform::error::TradingError;
+use arb_platform::exchange::OrderStatus as ExchangeOrderStatus;
+use arb_platform::order::{Order, OrderManager, OrderStatus};
+
+use crate::helpers::mock_exchange::{ExchangeCall, MockExchange};
+use crate::helpers::orders::test_order;
+
+use serde_json::json;
+use std::time::Duration;
+use uuid::Uuid;
+
+fn create_order(quantity: f64) -> Order {
+    Order {
+        quantity,
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+// Place an order on the mock and wait until it rests there
+async fn resting_order(manager: &OrderManager, quantity: f64) -> Uuid {
+    let order_id = manager.place_order(create_order(quantity)).await.unwrap();
+    for _ in 0..100 {
+        if manager.get_order(order_id).await.is_some_and(|order| order.is_fillable()) {
+            return order_id;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("order {} never reached the exchange", order_id);
+}
+
+async fn manager_with(exchange: &MockExchange) -> OrderManager {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(exchange.clone())).await.unwrap();
+    manager
+}
+
+#[tokio::test]
+async fn test_reduce_resting_order_amends_it_on_the_exchange() {
+    let exchange = MockExchange::new("Mock");
+    let manager = manager_with(&exchange).await;
+    let order_id = resting_order(&manager, 2.0).await;
+
+    manager.reduce_order(order_id, 1.5).await.unwrap();
+
+    let order = manager.get_order(order_id).await.unwrap();
+    assert_eq!(order.quantity, 1.5);
+    assert_eq!(order.status, OrderStatus::Submitted);
+    let active = manager.get_active_orders().await;
+    assert_eq!(active.iter().find(|order| order.id == order_id).unwrap().quantity, 1.5);
+    let amendment = order.amendment_history.last().unwrap();
+    assert_eq!((amendment.field.as_str(), amendment.reason.as_str()), ("quantity", "Quantity reduced"));
+    assert_eq!((amendment.old_value.clone(), amendment.new_value.clone()), (json!(2.0), json!(1.5)));
+    assert_eq!(amendment.timestamp, order.updated_at);
+    assert!(exchange.calls().iter().any(|call| matches!(call,
+        ExchangeCall::AmendOrder { order_id: id, new_quantity } if *id == order_id && *new_quantity == 1.5)));
+}
+
+#[tokio::test]
+async fn test_reduce_must_stay_above_filled_and_below_current_quantity() {
+    let exchange = MockExchange::new("Mock");
+    let manager = manager_with(&exchange).await;
+    let order_id = resting_order(&manager, 2.0).await;
+
+    exchange.set_order_status(order_id, ExchangeOrderStatus::PartiallyFilled, 0.5, Some(100.0));
+    manager.refresh_order_from_exchange(order_id).await.unwrap();
+    assert_eq!(manager.get_order(order_id).await.unwrap().filled_quantity, 0.5);
+
+    for new_quantity in [0.25, 0.5, 2.0, 3.0, f64::NAN] {
+        let result = manager.reduce_order(order_id, new_quantity).await;
+        assert!(matches!(result, Err(TradingError::Validation(_))), "{}: {:?}", new_quantity, result);
+    }
+    assert!(!exchange.calls().iter().any(|call| matches!(call, ExchangeCall::AmendOrder { .. })));
+    assert_eq!(manager.get_order(order_id).await.unwrap().quantity, 2.0);
+
+    manager.reduce_order(order_id, 0.75).await.unwrap();
+    let order = manager.get_order(order_id).await.unwrap();
+    assert_eq!(order.quantity, 0.75);
+    assert_eq!(order.filled_quantity, 0.5);
+}
+
+#[tokio::test]
+async fn test_reduce_finished_or_unknown_order_fails() {
+    let exchange = MockExchange::new("Mock");
+    let manager = manager_with(&exchange).await;
+    let order_id = resting_order(&manager, 2.0).await;
+    manager.cancel_order(order_id, "done".to_string()).await.unwrap();
+
+    assert!(matches!(manager.reduce_order(order_id, 1.0).await, Err(TradingError::Conflict(_))));
+    assert!(matches!(manager.reduce_order(Uuid::new_v4(), 1.0).await, Err(TradingError::NotFound(_))));
+}
@@ -0,0 +1,261 @@
+use arb_platform::clock::MockClock;
+use arb_platform::error::TradingError;
+use arb_platform::exchange::rejection_error;
+use arb_platform::exchange::circuit_breaker::{CircuitBreakerConfig, CircuitState};
+use arb_platform::order::{Order, OrderManager, OrderRouter, OrderStatus};
+use arb_platform::models::SymbolNormalizer;
+
+use crate::helpers::mock_exchange::{ExchangeCall, MockExchange};
+use crate::helpers::orders::test_order;
+
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+fn create_order(exchange: &str) -> Order {
+    Order {
+        exchange: exchange.to_string(),
+        ..test_order()
+    }
+}
+
+// A router with the given primary and a healthy Secondary behind it for BTC/USD
+async fn router_with_fallback(primary: Option<MockExchange>) -> (OrderRouter, MockExchange) {
+    let router = OrderRouter::new();
+    let secondary = MockExchange::new("Secondary");
+    if let Some(primary) = primary {
+        router.register_exchange(Box::new(primary)).await.unwrap();
+    }
+    router.register_exchange(Box::new(secondary.clone())).await.unwrap();
+    router.set_primary_exchange("BTC/USD", "Primary").await.unwrap();
+    router.set_fallback_chain("BTC/USD", vec!["Secondary".to_string()]).await.unwrap();
+    (router, secondary)
+}
+
+#[tokio::test]
+async fn test_failing_primary_falls_back_to_secondary() {
+    let primary = MockExchange::new("Primary");
+    primary.set_submit_order_response(|_| Err(TradingError::Exchange("connection reset by peer".to_string())));
+    let (router, secondary) = router_with_fallback(Some(primary.clone())).await;
+    
+    let order = create_order("");
+    let order_id = order.id;
+    assert_eq!(router.submit_order(order).await.unwrap(), "Secondary");
+    
+    primary.assert_order_submitted(order_id);
+    secondary.assert_order_submitted(order_id);
+    // Status is polled where the order ended up
+    assert!(router.get_order_status(order_id).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_unavailable_primary_falls_back_to_secondary() {
+    // Disconnected
+    let primary = MockExchange::disconnected("Primary");
+    let (router, secondary) = router_with_fallback(Some(primary.clone())).await;
+    let order = create_order("Primary");
+    let order_id = order.id;
+    assert_eq!(router.submit_order(order).await.unwrap(), "Secondary");
+    assert!(primary.submitted_orders().is_empty());
+    secondary.assert_order_submitted(order_id);
+    
+    // Never registered
+    let (router, secondary) = router_with_fallback(None).await;
+    let order = create_order("");
+    let order_id = order.id;
+    assert_eq!(router.submit_order(order).await.unwrap(), "Secondary");
+    secondary.assert_order_submitted(order_id);
+}
+
+#[tokio::test]
+async fn test_rejection_does_not_fall_back() {
+    let primary = MockExchange::new("Primary");
+    primary.set_submit_order_response(|_| Err(rejection_error("insufficient margin")));
+    let (router, secondary) = router_with_fallback(Some(primary)).await;
+    
+    let error = router.submit_order(create_order("")).await.unwrap_err();
+    assert_eq!(error, rejection_error("insufficient margin"));
+    assert!(secondary.submitted_orders().is_empty());
+}
+
+#[tokio::test]
+async fn test_exhausted_chain_reports_every_failure() {
+    let primary = MockExchange::new("Primary");
+    primary.set_submit_order_response(|_| Err(TradingError::Exchange("timed out".to_string())));
+    let (router, secondary) = router_with_fallback(Some(primary)).await;
+    secondary.set_submit_order_response(|_| Err(TradingError::Exchange("service unavailable".to_string())));
+    
+    let error = router.submit_order(create_order("")).await.unwrap_err();
+    assert!(error.to_string().contains("timed out") && error.to_string().contains("service unavailable"), "{}", error);
+}
+
+#[tokio::test]
+async fn test_repeated_failures_open_the_circuit_until_it_recovers() {
+    let primary = MockExchange::new("Primary");
+    primary.set_submit_order_response(|_| Err(TradingError::Exchange("service unavailable".to_string())));
+    let (router, secondary) = router_with_fallback(Some(primary.clone())).await;
+    let clock = Arc::new(MockClock::new(Utc::now()));
+    router.set_clock(clock.clone()).await;
+    router.set_circuit_breaker_config(CircuitBreakerConfig {
+        failure_threshold: 5,
+        success_threshold: 1,
+        open_duration: Duration::from_secs(30),
+    }).await;
+    
+    for _ in 0..5 {
+        assert_eq!(router.submit_order(create_order("")).await.unwrap(), "Secondary");
+    }
+    assert!(matches!(router.circuit_state("Primary").await, Some(CircuitState::Open { .. })));
+    
+    // Orders go straight to the fallback while the circuit is open
+    assert_eq!(router.submit_order(create_order("")).await.unwrap(), "Secondary");
+    assert_eq!(primary.submitted_orders().len(), 5);
+    assert_eq!(secondary.submitted_orders().len(), 6);
+    
+    // Once the open time is up a probe gets through, and closes the circuit
+    primary.set_submit_order_response(|_| Ok(()));
+    clock.advance(chrono::Duration::seconds(30));
+    assert_eq!(router.circuit_state("Primary").await, Some(CircuitState::HalfOpen));
+    assert_eq!(router.submit_order(create_order("")).await.unwrap(), "Primary");
+    assert_eq!(router.circuit_state("Primary").await, Some(CircuitState::Closed));
+}
+
+#[tokio::test]
+async fn test_every_circuit_open_reports_exchanges_unavailable() {
+    let primary = MockExchange::new("Primary");
+    primary.set_submit_order_response(|_| Err(TradingError::Exchange("timed out".to_string())));
+    let (router, secondary) = router_with_fallback(Some(primary)).await;
+    secondary.set_submit_order_response(|_| Err(TradingError::Exchange("timed out".to_string())));
+    router.set_circuit_breaker_config(CircuitBreakerConfig {
+        failure_threshold: 2,
+        success_threshold: 1,
+        open_duration: Duration::from_secs(60),
+    }).await;
+    
+    for _ in 0..2 {
+        assert!(router.submit_order(create_order("")).await.is_err());
+    }
+    let error = router.submit_order(create_order("")).await.unwrap_err();
+    assert_eq!(error, TradingError::Unavailable("All exchanges for BTC/USD are unavailable: circuit breaker open for Primary, Secondary".to_string()));
+    assert_eq!(secondary.submitted_orders().len(), 2);
+}
+
+#[tokio::test]
+async fn test_rejections_do_not_trip_the_circuit() {
+    let primary = MockExchange::new("Primary");
+    primary.set_submit_order_response(|_| Err(rejection_error("price out of range")));
+    let (router, _secondary) = router_with_fallback(Some(primary)).await;
+    router.set_circuit_breaker_config(CircuitBreakerConfig { failure_threshold: 1, ..CircuitBreakerConfig::default() }).await;
+    
+    for _ in 0..3 {
+        assert!(router.submit_order(create_order("")).await.is_err());
+    }
+    assert_eq!(router.circuit_state("Primary").await, Some(CircuitState::Closed));
+    assert_eq!(router.circuit_state("Unknown").await, None);
+}
+
+#[tokio::test]
+async fn test_cancel_goes_to_the_order_exchange_and_keeps_its_error() {
+    let primary = MockExchange::new("Primary");
+    let (router, secondary) = router_with_fallback(Some(primary.clone())).await;
+    let order = create_order("");
+    let order_id = order.id;
+    router.submit_order(order).await.unwrap();
+    
+    primary.fail_cancellations(TradingError::NotConnected("Primary".to_string()));
+    secondary.fail_cancellations(TradingError::Conflict("Secondary never had it".to_string()));
+    assert_eq!(router.cancel_order(order_id).await.unwrap_err(), TradingError::NotConnected("Primary".to_string()));
+    primary.assert_order_cancelled(order_id);
+    assert!(secondary.calls().iter().all(|call| !matches!(call, ExchangeCall::CancelOrder(_))));
+    
+    // Orders the router never submitted are not looked for elsewhere
+    let error = router.cancel_order(Uuid::new_v4()).await.unwrap_err();
+    assert!(matches!(error, TradingError::NotFound(_)), "{:?}", error);
+}
+
+#[tokio::test]
+async fn test_fallback_chain_rejects_duplicates() {
+    let router = OrderRouter::new();
+    let chain = vec!["Alpha".to_string(), "Beta".to_string(), "Alpha".to_string()];
+    assert!(router.set_fallback_chain("BTC/USD", chain).await.is_err());
+    assert!(router.get_fallback_chain("BTC/USD").await.is_empty());
+    
+    router.set_fallback_chain("BTC/USD", vec!["Beta".to_string()]).await.unwrap();
+    router.set_fallback_chain("BTC/USD", Vec::new()).await.unwrap();
+    assert!(router.get_fallback_chain("BTC/USD").await.is_empty());
+}
+
+#[tokio::test]
+async fn test_order_manager_records_fallback_exchange() {
+    let manager = OrderManager::new();
+    let router = manager.get_order_router();
+    let primary = MockExchange::new("Primary");
+    primary.set_submit_order_response(|_| Err(TradingError::Exchange("connection refused".to_string())));
+    let secondary = MockExchange::new("Secondary");
+    router.register_exchange(Box::new(primary)).await.unwrap();
+    router.register_exchange(Box::new(secondary.clone())).await.unwrap();
+    router.set_fallback_chain("BTC/USD", vec!["Secondary".to_string()]).await.unwrap();
+    
+    let order_id = manager.place_order(create_order("Primary")).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    
+    secondary.assert_order_submitted(order_id);
+    let order = manager.get_order(order_id).await.unwrap();
+    assert_eq!(order.status, OrderStatus::Submitted);
+    assert_eq!(order.exchange, "Secondary");
+}
+
+#[tokio::test]
+async fn test_orders_go_out_under_the_exchange_symbol() {
+    let primary = MockExchange::new("Primary");
+    let (router, secondary) = router_with_fallback(Some(primary.clone())).await;
+    let normalizer = Arc::new(SymbolNormalizer::new());
+    normalizer.register_mapping("Primary", "BTC/USD", "BTCUSDT");
+    router.set_symbol_normalizer(normalizer);
+    
+    assert_eq!(router.submit_order(create_order("")).await.unwrap(), "Primary");
+    assert_eq!(primary.submitted_orders()[0].symbol, "BTCUSDT");
+    assert_eq!(router.submit_order(create_order("Secondary")).await.unwrap(), "Secondary");
+    assert_eq!(secondary.submitted_orders()[0].symbol, "BTC/USD");
+    
+    router.get_market_data(&create_order("")).await.unwrap();
+    assert!(primary.calls().iter().any(|call| matches!(call, ExchangeCall::GetMarketData { symbol } if symbol == "BTCUSDT")));
+}
+
+fn order_for(symbol: &str) -> Order {
+    Order { symbol: symbol.to_string(), ..create_order("") }
+}
+
+#[tokio::test]
+async fn test_default_exchange_takes_unmapped_supported_symbols() {
+    let router = OrderRouter::new();
+    let only = MockExchange::new("Only");
+    router.register_exchange(Box::new(only.clone())).await.unwrap();
+    assert!(matches!(router.set_default_exchange("Missing").await, Err(TradingError::NotFound(_))));
+    
+    let error = router.submit_order(order_for("ETH/USD")).await.unwrap_err();
+    assert_eq!(error, TradingError::Validation("No primary exchange defined for ETH/USD".to_string()));
+    
+    router.set_default_exchange("Only").await.unwrap();
+    assert_eq!(router.get_default_exchange().await.as_deref(), Some("Only"));
+    assert_eq!(router.submit_order(order_for("ETH/USD")).await.unwrap(), "Only");
+    assert_eq!(only.submitted_orders()[0].symbol, "ETH/USD");
+    router.get_market_data(&order_for("SOL/USD")).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_default_exchange_refuses_symbols_it_does_not_list() {
+    let router = OrderRouter::new();
+    let only = MockExchange::new("Only");
+    router.register_exchange(Box::new(only.clone())).await.unwrap();
+    router.set_default_exchange("Only").await.unwrap();
+    
+    let error = router.submit_order(order_for("DOGE/USD")).await.unwrap_err();
+    assert!(matches!(&error, TradingError::Validation(message) if message.contains("default exchange Only does not support")), "{}", error);
+    assert!(only.submitted_orders().is_empty());
+    
+    // A primary exchange still wins over the default
+    router.set_primary_exchange("DOGE/USD", "Only").await.unwrap();
+    assert_eq!(router.submit_order(order_for("DOGE/USD")).await.unwrap(), "Only");
+}
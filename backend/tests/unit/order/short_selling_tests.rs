@@ -0,0 +1,67 @@
+use arb_platform::exchange::Position;
+use arb_platform::order::{Order, OrderManager};
+use arb_platform::strategy::TradeDirection;
+use arb_platform::models::Price;
+
+use chrono::Utc;
+
+use crate::helpers::orders::test_order;
+
+fn create_sell(quantity: f64) -> Order {
+    Order {
+        direction: TradeDirection::Sell,
+        quantity,
+        price: Some(Price::from(50000.0)),
+        exchange: "Test Exchange".to_string(),
+        ..test_order()
+    }
+}
+
+async fn manager_holding(quantity: f64, allow_short: bool) -> OrderManager {
+    let mut manager = OrderManager::new();
+    manager.set_allow_short(allow_short);
+    manager.get_position_manager().update_position(Position {
+        symbol: "BTC/USD".to_string(),
+        quantity,
+        avg_price: 48000.0,
+        current_price: 50000.0,
+        unrealized_pnl: 0.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }).await;
+    manager
+}
+
+#[tokio::test]
+async fn test_short_selling_allowed_by_default() {
+    assert!(OrderManager::new().allow_short());
+}
+
+#[tokio::test]
+async fn test_sell_within_long_position_is_accepted() {
+    let manager = manager_holding(5.0, false).await;
+    assert!(manager.place_order(create_sell(3.0)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_sell_beyond_long_position_is_rejected_without_shorting() {
+    let manager = manager_holding(5.0, false).await;
+    let err = manager.place_order(create_sell(8.0)).await.unwrap_err();
+    assert!(err.to_string().contains("short selling is disabled"), "unexpected error: {}", err);
+    assert!(manager.get_active_orders().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_sell_without_position_is_rejected_without_shorting() {
+    let mut manager = OrderManager::new();
+    manager.set_allow_short(false);
+    assert!(manager.place_order(create_sell(1.0)).await.is_err());
+}
+
+#[tokio::test]
+async fn test_sell_beyond_long_position_is_accepted_with_shorting() {
+    let manager = manager_holding(5.0, true).await;
+    assert!(manager.place_order(create_sell(8.0)).await.is_ok());
+}
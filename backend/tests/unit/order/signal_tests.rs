@@ -0,0 +1,102 @@
+use arb_platform::order::{Order, OrderManager, OrderType, DEFAULT_SIGNAL_SUBMISSION_DELAY};
+use arb_platform::strategy::{PrioritizedSignal, TradeDirection, TradeSignal, TimeInForce};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+
+use std::collections::BinaryHeap;
+use std::time::{Duration, Instant};
+
+fn signal(asset: &str, time_in_force: TimeInForce) -> TradeSignal {
+    TradeSignal {
+        asset: asset.to_string(),
+        direction: TradeDirection::Buy,
+        quantity: 1.0,
+        limit_price: Some(100.0),
+        stop_price: None,
+        time_in_force,
+        priority: time_in_force.default_signal_priority(),
+    }
+}
+
+async fn manager_with_exchange() -> OrderManager {
+    let manager = OrderManager::new();
+    let router = manager.get_order_router();
+    router.register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    for symbol in ["BTC/USD", "ETH/USD", "SOL/USD"] {
+        router.set_primary_exchange(symbol, "Mock").await.unwrap();
+    }
+    manager
+}
+
+#[test]
+fn test_order_type_follows_signal_prices() {
+    let mut limit = signal("BTC/USD", TimeInForce::Day);
+    let order = Order::from_signal(&limit, "Momentum");
+    assert_eq!(order.order_type, OrderType::Limit);
+    assert_eq!(order.price, Some(Price::from(100.0)));
+    assert_eq!(order.strategy_id.as_deref(), Some("Momentum"));
+    assert_eq!(order.time_in_force, TimeInForce::Day);
+    assert!(order.exchange.is_empty());
+
+    limit.stop_price = Some(95.0);
+    assert_eq!(Order::from_signal(&limit, "Momentum").order_type, OrderType::StopLimit);
+    limit.limit_price = None;
+    assert_eq!(Order::from_signal(&limit, "Momentum").order_type, OrderType::StopLoss);
+    limit.stop_price = None;
+    assert_eq!(Order::from_signal(&limit, "Momentum").order_type, OrderType::Market);
+}
+
+#[tokio::test]
+async fn test_signals_are_placed_highest_priority_first() {
+    let mut manager = manager_with_exchange().await;
+    manager.set_signal_submission_delay(Duration::ZERO);
+
+    let mut queue = BinaryHeap::new();
+    queue.push(PrioritizedSignal::new("Resting", signal("ETH/USD", TimeInForce::GoodTilCancelled), 0));
+    queue.push(PrioritizedSignal::new("Resting", signal("SOL/USD", TimeInForce::GoodTilCancelled), 1));
+    queue.push(PrioritizedSignal::new("Urgent", signal("BTC/USD", TimeInForce::ImmediateOrCancel), 2));
+
+    let results = manager.place_signals(queue).await;
+    assert_eq!(results.len(), 3);
+
+    let mut symbols = Vec::new();
+    for result in results {
+        let order = manager.get_order(result.unwrap()).await.unwrap();
+        symbols.push(order.symbol);
+    }
+    assert_eq!(symbols, vec!["BTC/USD", "ETH/USD", "SOL/USD"]);
+}
+
+#[tokio::test]
+async fn test_failed_signal_does_not_stop_the_rest() {
+    let manager = manager_with_exchange().await;
+
+    let mut invalid = signal("ETH/USD", TimeInForce::ImmediateOrCancel);
+    invalid.quantity = 0.0;
+    let mut queue = BinaryHeap::new();
+    queue.push(PrioritizedSignal::new("Urgent", invalid, 0));
+    queue.push(PrioritizedSignal::new("Resting", signal("BTC/USD", TimeInForce::GoodTilCancelled), 1));
+
+    let results = manager.place_signals(queue).await;
+    assert!(results[0].is_err());
+    assert!(results[1].is_ok());
+}
+
+#[tokio::test]
+async fn test_submissions_are_spaced_by_the_delay() {
+    let mut manager = manager_with_exchange().await;
+    assert_eq!(manager.signal_submission_delay(), DEFAULT_SIGNAL_SUBMISSION_DELAY);
+    manager.set_signal_submission_delay(Duration::from_millis(30));
+
+    let mut queue = BinaryHeap::new();
+    for (sequence, asset) in ["BTC/USD", "ETH/USD", "SOL/USD"].into_iter().enumerate() {
+        queue.push(PrioritizedSignal::new("Resting", signal(asset, TimeInForce::GoodTilCancelled), sequence as u64));
+    }
+
+    let started = Instant::now();
+    let results = manager.place_signals(queue).await;
+    assert!(results.iter().all(|result| result.is_ok()));
+    // Two pauses, none before the first order
+    assert!(started.elapsed() >= Duration::from_millis(60));
+}
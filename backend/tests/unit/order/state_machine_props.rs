@@ -0,0 +1,73 @@
+// Properties of the order status transition graph, checked over random pairs
+// of statuses. Built as its own test target: `cargo test --test state_machine_props`.
+use arb_platform::order::OrderStatus;
+
+use proptest::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+// Statuses reachable from `from` by one or more transitions, self transitions aside
+fn reachable_from(from: &OrderStatus) -> HashSet<OrderStatus> {
+    let mut reached = HashSet::new();
+    let mut queue = VecDeque::from([from.clone()]);
+    while let Some(status) = queue.pop_front() {
+        for next in OrderStatus::ALL {
+            if next != status && status.can_transition_to(&next) && reached.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+    reached
+}
+
+proptest! {
+    #[test]
+    fn all_lists_every_status(status in any::<OrderStatus>()) {
+        prop_assert!(OrderStatus::ALL.contains(&status), "{} is missing from OrderStatus::ALL", status);
+    }
+
+    #[test]
+    fn terminal_statuses_only_transition_to_themselves(from in any::<OrderStatus>(), to in any::<OrderStatus>()) {
+        if from.is_terminal() && from.can_transition_to(&to) {
+            prop_assert_eq!(from, to);
+        }
+    }
+
+    #[test]
+    fn transitions_never_lead_back(from in any::<OrderStatus>(), to in any::<OrderStatus>()) {
+        // A way back from `to` would close a cycle, which can be at most VARIANT_COUNT long
+        if from != to && from.can_transition_to(&to) {
+            prop_assert!(!reachable_from(&to).contains(&from), "{} -> {} is part of a cycle", from, to);
+        }
+    }
+
+    #[test]
+    fn every_status_is_reachable_from_created(status in any::<OrderStatus>()) {
+        prop_assume!(status != OrderStatus::Created);
+        prop_assert!(reachable_from(&OrderStatus::Created).contains(&status), "{} is unreachable", status);
+    }
+
+    #[test]
+    fn non_terminal_statuses_can_finish(status in any::<OrderStatus>()) {
+        prop_assume!(!status.is_terminal());
+        prop_assert!(reachable_from(&status).iter().any(OrderStatus::is_terminal), "{} can never finish", status);
+    }
+
+    #[test]
+    fn every_status_is_either_terminal_or_active(status in any::<OrderStatus>()) {
+        prop_assert!(status.is_terminal() ^ status.is_active(), "{} is terminal: {}, active: {}", status, status.is_terminal(), status.is_active());
+    }
+
+    #[test]
+    fn fillable_statuses_are_active_and_can_fill(status in any::<OrderStatus>()) {
+        if status.is_fillable() {
+            prop_assert!(status.is_active());
+            prop_assert!(status.can_transition_to(&OrderStatus::Filled), "{} cannot fill", status);
+        }
+    }
+}
+
+#[test]
+fn variant_count_matches_all() {
+    let distinct: HashSet<&OrderStatus> = OrderStatus::ALL.iter().collect();
+    assert_eq!(distinct.len(), OrderStatus::VARIANT_COUNT);
+}
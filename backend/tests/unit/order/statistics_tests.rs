@@ -0,0 +1,109 @@
+use arb_platform::order::{
+    Order, OrderHistoryFilter, OrderManager, OrderStatistics, OrderStatus, OrderType
+};
+use arb_platform::models::Price;
+
+use chrono::{Duration, Utc};
+
+use crate::helpers::orders::test_order;
+
+fn create_order(symbol: &str, order_type: OrderType, status: OrderStatus) -> Order {
+    let created_at = Utc::now() - Duration::minutes(10);
+    Order {
+        symbol: symbol.to_string(),
+        order_type: order_type.clone(),
+        quantity: 2.0,
+        price: match order_type {
+            OrderType::Market => None,
+            _ => Some(Price::from(100.0)),
+        },
+        status,
+        exchange: "Test Exchange".to_string(),
+        created_at,
+        updated_at: created_at,
+        ..test_order()
+    }
+}
+
+fn filled(mut order: Order, fill_price: f64, latency_ms: i64) -> Order {
+    order.status = OrderStatus::Filled;
+    order.filled_quantity = order.quantity;
+    order.average_fill_price = Some(Price::from(fill_price));
+    order.filled_at = Some(order.created_at + Duration::milliseconds(latency_ms));
+    order
+}
+
+#[test]
+fn test_statistics_for_empty_history() {
+    let stats = OrderStatistics::from_orders(Vec::<Order>::new().iter());
+    assert_eq!(stats, OrderStatistics::default());
+}
+
+#[test]
+fn test_statistics_calculation() {
+    let mut partial = create_order("ETH/USD", OrderType::Limit, OrderStatus::PartiallyFilled);
+    partial.filled_quantity = 1.0;
+    
+    let orders = [
+        filled(create_order("BTC/USD", OrderType::Limit, OrderStatus::Created), 100.5, 200), // 50 bps
+        filled(create_order("BTC/USD", OrderType::Limit, OrderStatus::Created), 99.9, 400),  // 10 bps
+        filled(create_order("BTC/USD", OrderType::Market, OrderStatus::Created), 101.0, 600), // No limit price
+        partial,
+        create_order("BTC/USD", OrderType::Limit, OrderStatus::Cancelled),
+        create_order("BTC/USD", OrderType::Limit, OrderStatus::Rejected),
+    ];
+    
+    let stats = OrderStatistics::from_orders(orders.iter());
+    
+    assert_eq!(stats.total_orders, 6);
+    assert!((stats.fill_rate - 3.0 / 5.0).abs() < 1e-9);
+    assert!((stats.avg_fill_latency_ms - 400.0).abs() < 1e-9);
+    assert!((stats.avg_slippage_bps - 30.0).abs() < 1e-6);
+    assert!((stats.partial_fill_rate - 1.0 / 6.0).abs() < 1e-9);
+    assert!((stats.rejection_rate - 1.0 / 6.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_history_filter() {
+    let order = create_order("BTC/USD", OrderType::Limit, OrderStatus::Created);
+    
+    assert!(OrderHistoryFilter::default().matches(&order));
+    
+    let by_symbol = OrderHistoryFilter { symbols: vec!["ETH/USD".to_string()], ..Default::default() };
+    assert!(!by_symbol.matches(&order));
+    
+    let by_range = OrderHistoryFilter {
+        start: Some(order.created_at - Duration::seconds(1)),
+        end: Some(order.created_at),
+        symbols: vec!["BTC/USD".to_string()],
+    };
+    assert!(by_range.matches(&order));
+    
+    let after = OrderHistoryFilter { start: Some(order.created_at + Duration::seconds(1)), ..Default::default() };
+    assert!(!after.matches(&order));
+}
+
+#[tokio::test]
+async fn test_order_manager_statistics_respect_filter() {
+    // No exchange is registered, so every order ends up Failed
+    let manager = OrderManager::new();
+    for symbol in ["BTC/USD", "BTC/USD", "ETH/USD"] {
+        manager.place_order(create_order(symbol, OrderType::Limit, OrderStatus::Created)).await.unwrap();
+    }
+    
+    let all = manager.get_order_statistics(OrderHistoryFilter::default()).await;
+    assert_eq!(all.total_orders, 3);
+    assert_eq!(all.fill_rate, 0.0);
+    
+    let btc = manager.get_order_statistics(OrderHistoryFilter {
+        symbols: vec!["BTC/USD".to_string()],
+        ..Default::default()
+    }).await;
+    assert_eq!(btc.total_orders, 2);
+    
+    let future = manager.get_order_statistics(OrderHistoryFilter {
+        start: Some(Utc::now() + Duration::hours(1)),
+        ..Default::default()
+    }).await;
+    assert_eq!(future.total_orders, 0);
+}
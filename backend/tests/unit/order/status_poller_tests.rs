@@ -0,0 +1,158 @@
+use arb_platform::exchange::{
+    Exchange, ExchangeType, MarketSnapshot, OrderStatusResponse, OrderStatus as ExchangeOrderStatus,
+    AccountBalance, Position, CancellationResult
+};
+use arb_platform::channel::{event_channel, BackpressurePolicy, ChannelConfig};
+use arb_platform::order::{Order, OrderEvent, OrderStatus, poll_until_terminal};
+
+use arb_platform::error::TradingError;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::test;
+use uuid::Uuid;
+
+// Exchange that answers status queries from a fixed script of (status, filled) pairs
+struct ScriptedExchange {
+    quantity: f64,
+    script: Mutex<VecDeque<(ExchangeOrderStatus, f64)>>,
+}
+
+impl ScriptedExchange {
+    fn new(quantity: f64, script: Vec<(ExchangeOrderStatus, f64)>) -> Self {
+        ScriptedExchange {
+            quantity,
+            script: Mutex::new(script.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl Exchange for ScriptedExchange {
+    fn name(&self) -> &str { "Scripted Exchange" }
+    fn exchange_type(&self) -> ExchangeType { ExchangeType::Crypto }
+    fn is_connected(&self) -> bool { true }
+    
+    async fn connect(&mut self) -> Result<(), TradingError> { Ok(()) }
+    async fn disconnect(&mut self) -> Result<(), TradingError> { Ok(()) }
+    
+    async fn get_supported_assets(&self) -> Result<Vec<String>, TradingError> { Ok(vec![]) }
+    async fn get_market_data(&self, _symbol: &str) -> Result<MarketSnapshot, TradingError> {
+        Err(TradingError::Exchange("Not supported".to_string()))
+    }
+    
+    async fn submit_order(&self, _order: Order) -> Result<(), TradingError> { Ok(()) }
+    async fn cancel_order(&self, _order_id: Uuid) -> Result<CancellationResult, TradingError> {
+        Ok(CancellationResult { exchange_order_id: None, cancelled_at: Utc::now(), unfilled_quantity: self.quantity })
+    }
+    
+    async fn get_order_status(&self, order_id: Uuid) -> Result<OrderStatusResponse, TradingError> {
+        let (status, filled) = self.script.lock().unwrap().pop_front()
+            .ok_or_else(|| TradingError::Exchange("Script exhausted".to_string()))?;
+        
+        Ok(OrderStatusResponse {
+            order_id,
+            exchange_order_id: Some("EX-1".to_string()),
+            status,
+            filled_quantity: filled,
+            remaining_quantity: self.quantity - filled,
+            average_price: if filled > 0.0 { Some(35000.0) } else { None },
+            last_update: Utc::now(),
+        })
+    }
+    
+    async fn get_account_balance(&self) -> Result<AccountBalance, TradingError> {
+        Err(TradingError::Exchange("Not supported".to_string()))
+    }
+    async fn get_positions(&self) -> Result<Vec<Position>, TradingError> { Ok(vec![]) }
+}
+
+fn status_response(status: ExchangeOrderStatus, filled: f64, remaining: f64) -> OrderStatusResponse {
+    OrderStatusResponse {
+        order_id: Uuid::new_v4(),
+        exchange_order_id: None,
+        status,
+        filled_quantity: filled,
+        remaining_quantity: remaining,
+        average_price: Some(100.0),
+        last_update: Utc::now(),
+    }
+}
+
+#[test]
+async fn test_status_response_derives_partial_fill() {
+    let response = status_response(ExchangeOrderStatus::PartiallyFilled, 0.4, 0.6);
+    
+    match response.to_order_event() {
+        OrderEvent::Update { order_id, status, filled_qty, avg_fill_price } => {
+            assert_eq!(order_id, response.order_id);
+            assert_eq!(status, Some(OrderStatus::PartiallyFilled));
+            assert_eq!(filled_qty, Some(0.4));
+            assert_eq!(avg_fill_price, Some(100.0));
+        },
+        other => panic!("Expected update event, got {:?}", other),
+    }
+}
+
+#[test]
+async fn test_status_response_with_no_remaining_is_filled() {
+    // Reported as partially filled, but nothing remains
+    let response = status_response(ExchangeOrderStatus::PartiallyFilled, 1.0, 0.0);
+    assert_eq!(response.derived_status(), Some(OrderStatus::Filled));
+    
+    // Reported as open, but fills have arrived
+    let response = status_response(ExchangeOrderStatus::Open, 0.25, 0.75);
+    assert_eq!(response.derived_status(), Some(OrderStatus::PartiallyFilled));
+    
+    let response = status_response(ExchangeOrderStatus::Open, 0.0, 1.0);
+    assert_eq!(response.derived_status(), Some(OrderStatus::Submitted));
+    
+    let response = status_response(ExchangeOrderStatus::Unknown, 0.0, 1.0);
+    assert_eq!(response.derived_status(), None);
+}
+
+#[test]
+async fn test_polled_partial_then_full_fill_emits_two_updates() {
+    let exchange = ScriptedExchange::new(1.0, vec![
+        (ExchangeOrderStatus::PartiallyFilled, 0.4),
+        (ExchangeOrderStatus::PartiallyFilled, 0.4), // unchanged, must not be re-emitted
+        (ExchangeOrderStatus::Filled, 1.0),
+    ]);
+    let order_id = Uuid::new_v4();
+    let (sender, mut receiver) = event_channel(ChannelConfig::new(10, BackpressurePolicy::Block));
+    
+    poll_until_terminal(&exchange, order_id, sender, Duration::from_millis(5)).await;
+    
+    let mut updates = Vec::new();
+    while let Some(event) = receiver.try_recv() {
+        match event {
+            OrderEvent::Update { order_id: id, status, filled_qty, .. } => {
+                assert_eq!(id, order_id);
+                updates.push((status, filled_qty));
+            },
+            other => panic!("Expected update event, got {:?}", other),
+        }
+    }
+    
+    assert_eq!(updates, vec![
+        (Some(OrderStatus::PartiallyFilled), Some(0.4)),
+        (Some(OrderStatus::Filled), Some(1.0)),
+    ]);
+}
+
+#[test]
+async fn test_polling_stops_after_repeated_failures() {
+    // Empty script: every poll fails
+    let exchange = ScriptedExchange::new(1.0, vec![]);
+    let (sender, mut receiver) = event_channel(ChannelConfig::new(10, BackpressurePolicy::Block));
+    
+    let result = tokio::time::timeout(
+        Duration::from_secs(1),
+        poll_until_terminal(&exchange, Uuid::new_v4(), sender, Duration::from_millis(5)),
+    ).await;
+    
+    assert!(result.is_ok());
+    assert!(receiver.try_recv().is_none());
+}
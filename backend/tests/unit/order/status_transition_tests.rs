@@ -0,0 +1,157 @@
+use arb_platform::order::{Order, OrderEvent, OrderManager, OrderStatus};
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use chrono::{Duration as ChronoDuration, Utc};
+use serde_json::json;
+use std::time::Duration;
+use uuid::Uuid;
+
+fn create_order() -> Order {
+    Order {
+        quantity: 2.0,
+        exchange: "Mock".to_string(),
+        updated_at: Utc::now() - ChronoDuration::seconds(60),
+        ..test_order()
+    }
+}
+
+// Place an order on the mock and wait until it rests there
+async fn resting_order(manager: &OrderManager) -> Uuid {
+    let order_id = manager.place_order(create_order()).await.unwrap();
+    for _ in 0..100 {
+        if manager.get_order(order_id).await.is_some_and(|order| order.status == OrderStatus::Submitted) {
+            return order_id;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("order {} never reached the exchange", order_id);
+}
+
+async fn wait_for_filled(manager: &OrderManager, order_id: Uuid, quantity: f64) -> Order {
+    for _ in 0..100 {
+        let order = manager.get_order(order_id).await.unwrap();
+        if order.filled_quantity == quantity {
+            return order;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("order {} never filled {}", order_id, quantity);
+}
+
+#[test]
+fn test_transition_to_applies_legal_moves_and_refuses_others() {
+    let mut order = create_order();
+    let stamped = order.updated_at;
+
+    assert!(order.transition_to(OrderStatus::Created, "test"));
+    assert_eq!(order.updated_at, stamped); // Staying put is a no-op
+
+    assert!(order.transition_to(OrderStatus::PendingSubmission, "test"));
+    assert_eq!(order.status, OrderStatus::PendingSubmission);
+    assert!(order.updated_at > stamped);
+
+    assert!(order.transition_to(OrderStatus::Submitted, "test"));
+    assert!(order.transition_to(OrderStatus::Filled, "test"));
+    let filled_at = order.updated_at;
+
+    assert!(!order.transition_to(OrderStatus::Submitted, "test"));
+    assert!(!order.transition_to(OrderStatus::Cancelled, "test"));
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(order.updated_at, filled_at);
+
+    // Only the moves made are recorded
+    let moves: Vec<_> = order.amendment_history.iter()
+        .map(|amendment| (amendment.field.as_str(), amendment.old_value.clone(), amendment.new_value.clone()))
+        .collect();
+    assert_eq!(moves, vec![
+        ("status", json!("created"), json!("pending_submission")),
+        ("status", json!("pending_submission"), json!("submitted")),
+        ("status", json!("submitted"), json!("filled")),
+    ]);
+    assert_eq!(order.amendment_history[2].timestamp, filled_at);
+}
+
+#[tokio::test]
+async fn test_illegal_manual_update_is_refused() {
+    let exchange = MockExchange::new("Mock");
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(exchange.clone())).await.unwrap();
+    let order_id = resting_order(&manager).await;
+
+    assert!(manager.update_order_status(order_id, OrderStatus::Filled).await);
+    let filled = manager.get_order(order_id).await.unwrap();
+
+    assert!(!manager.update_order_status(order_id, OrderStatus::Submitted).await);
+    assert!(!manager.update_order_status(Uuid::new_v4(), OrderStatus::Submitted).await);
+    let order = manager.get_order(order_id).await.unwrap();
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(order.updated_at, filled.updated_at);
+
+    let trail = manager.get_audit_trail(order_id).await;
+    assert_eq!(trail.last().unwrap().to_status, OrderStatus::Filled);
+    assert!(trail.iter().all(|entry| entry.from_status != Some(OrderStatus::Filled)));
+}
+
+#[tokio::test]
+async fn test_illegal_event_status_is_ignored_but_fills_still_apply() {
+    let exchange = MockExchange::new("Mock");
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(exchange.clone())).await.unwrap();
+    let order_id = resting_order(&manager).await;
+    let sender = manager.get_event_sender();
+
+    sender.send(OrderEvent::Update {
+        order_id,
+        status: Some(OrderStatus::Filled),
+        filled_qty: Some(1.0),
+        avg_fill_price: Some(100.0),
+    }).await.unwrap();
+    assert_eq!(wait_for_filled(&manager, order_id, 1.0).await.status, OrderStatus::Filled);
+
+    // Neither a late cancel nor a step back reopens the order
+    sender.send(OrderEvent::Cancel { order_id, reason: "late cancel".to_string() }).await.unwrap();
+    sender.send(OrderEvent::Update {
+        order_id,
+        status: Some(OrderStatus::PartiallyFilled),
+        filled_qty: Some(2.0),
+        avg_fill_price: Some(100.0),
+    }).await.unwrap();
+    let order = wait_for_filled(&manager, order_id, 2.0).await;
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(order.notes, None);
+
+    let position = manager.get_position_manager().get_position("BTC/USD").await.unwrap();
+    assert_eq!(position.quantity, 2.0);
+    let trail = manager.get_audit_trail(order_id).await;
+    assert!(trail.iter().all(|entry| entry.from_status != Some(OrderStatus::Filled)));
+}
+
+#[tokio::test]
+async fn test_event_status_changes_are_recorded_with_their_reason() {
+    let exchange = MockExchange::new("Mock");
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(exchange.clone())).await.unwrap();
+    let order_id = resting_order(&manager).await;
+
+    manager.get_event_sender().send(OrderEvent::Cancel { order_id, reason: "user request".to_string() }).await.unwrap();
+    let mut order = manager.get_order(order_id).await.unwrap();
+    for _ in 0..100 {
+        if order.status == OrderStatus::Cancelled {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        order = manager.get_order(order_id).await.unwrap();
+    }
+
+    let changes: Vec<_> = order.amendment_history.iter()
+        .map(|amendment| (amendment.new_value.clone(), amendment.reason.as_str()))
+        .collect();
+    assert_eq!(changes, vec![
+        (json!("pending_submission"), "Submitting to router"),
+        (json!("submitted"), "Accepted by exchange"),
+        (json!("cancelled"), "user request"),
+    ]);
+    assert!(order.amendment_history.iter().all(|amendment| amendment.field == "status"));
+}
@@ -0,0 +1,60 @@
+use arb_platform::order::{Order, OrderManager, OrderStatus};
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::time::Duration;
+
+fn create_order(symbol: &str) -> Order {
+    Order {
+        symbol: symbol.to_string(),
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+async fn manager_with_exchange() -> OrderManager {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    manager
+}
+
+#[tokio::test]
+async fn test_disabled_symbol_rejects_new_orders() {
+    let manager = manager_with_exchange().await;
+    assert!(manager.is_symbol_enabled("BTC/USD").await);
+
+    manager.set_symbol_enabled("BTC/USD", false).await;
+    assert!(!manager.is_symbol_enabled("BTC/USD").await);
+    assert_eq!(manager.disabled_symbols().await, vec!["BTC/USD".to_string()]);
+
+    let err = manager.place_order(create_order("BTC/USD")).await.unwrap_err();
+    assert!(err.to_string().contains("disabled"), "unexpected error: {}", err);
+    assert!(manager.get_active_orders().await.is_empty());
+
+    // Other symbols still trade
+    assert!(manager.place_order(create_order("ETH/USD")).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_open_orders_can_be_cancelled_while_disabled() {
+    let manager = manager_with_exchange().await;
+    let order_id = manager.place_order(create_order("BTC/USD")).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    manager.set_symbol_enabled("BTC/USD", false).await;
+    manager.cancel_order(order_id, "Symbol suspended".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(manager.get_order(order_id).await.unwrap().status, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_reenabled_symbol_trades_again() {
+    let manager = manager_with_exchange().await;
+    manager.set_symbol_enabled("BTC/USD", false).await;
+    assert!(manager.place_order(create_order("BTC/USD")).await.is_err());
+
+    manager.set_symbol_enabled("BTC/USD", true).await;
+    assert!(manager.disabled_symbols().await.is_empty());
+    assert!(manager.place_order(create_order("BTC/USD")).await.is_ok());
+}
@@ -0,0 +1,106 @@
+use arb_platform::order::{ExecAlgo, Order, OrderManager, SymbolTradingRules};
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::time::Duration;
+
+fn create_order(symbol: &str, quantity: f64) -> Order {
+    Order {
+        symbol: symbol.to_string(),
+        quantity,
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+async fn manager_with_rules(rules: SymbolTradingRules) -> OrderManager {
+    let mut manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    manager.set_symbol_rules("BTC/USD", rules).unwrap();
+    manager
+}
+
+#[tokio::test]
+async fn test_inconsistent_rules_are_refused() {
+    let mut manager = OrderManager::new();
+
+    assert!(manager.set_symbol_rules("BTC/USD", SymbolTradingRules::new().with_min_qty(0.0)).is_err());
+    assert!(manager.set_symbol_rules("BTC/USD", SymbolTradingRules::new().with_max_qty(-1.0)).is_err());
+    assert!(manager.set_symbol_rules("BTC/USD", SymbolTradingRules::new().with_min_qty(5.0).with_max_qty(1.0)).is_err());
+    assert!(manager.get_symbol_rules("BTC/USD").is_none());
+
+    let rules = SymbolTradingRules::new().with_min_qty(1.0).with_max_qty(5.0);
+    manager.set_symbol_rules("BTC/USD", rules.clone()).unwrap();
+    assert_eq!(manager.get_symbol_rules("BTC/USD"), Some(&rules));
+}
+
+#[tokio::test]
+async fn test_order_below_min_qty_is_rejected() {
+    let manager = manager_with_rules(SymbolTradingRules::new().with_min_qty(0.5)).await;
+
+    let err = manager.place_order(create_order("BTC/USD", 0.1)).await.unwrap_err();
+    assert!(err.to_string().contains("below the BTC/USD minimum"), "unexpected error: {}", err);
+    assert!(manager.get_active_orders().await.is_empty());
+
+    assert!(manager.place_order(create_order("BTC/USD", 0.5)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_order_above_max_qty_is_rejected() {
+    let manager = manager_with_rules(SymbolTradingRules::new().with_max_qty(10.0)).await;
+
+    let err = manager.place_order(create_order("BTC/USD", 10.5)).await.unwrap_err();
+    assert!(err.to_string().contains("above the BTC/USD maximum"), "unexpected error: {}", err);
+
+    assert!(manager.place_order(create_order("BTC/USD", 10.0)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_rules_only_apply_to_their_symbol() {
+    let manager = manager_with_rules(SymbolTradingRules::new().with_max_qty(1.0).with_max_orders_open(1)).await;
+
+    assert!(manager.place_order(create_order("ETH/USD", 50.0)).await.is_ok());
+    assert!(manager.place_order(create_order("ETH/USD", 50.0)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_orders_refused_once_open_order_cap_is_reached() {
+    let manager = manager_with_rules(SymbolTradingRules::new().with_max_orders_open(3)).await;
+
+    let mut order_ids = Vec::new();
+    for _ in 0..3 {
+        order_ids.push(manager.place_order(create_order("BTC/USD", 1.0)).await.unwrap());
+    }
+
+    let err = manager.place_order(create_order("BTC/USD", 1.0)).await.unwrap_err();
+    assert!(err.to_string().contains("already has 3 open orders"), "unexpected error: {}", err);
+    assert_eq!(manager.get_active_orders().await.len(), 3);
+
+    // Closing one makes room for another
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    manager.cancel_order(order_ids[0], "Make room".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(manager.place_order(create_order("BTC/USD", 1.0)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_algo_order_counts_once_towards_open_order_cap() {
+    let manager = manager_with_rules(SymbolTradingRules::new().with_max_orders_open(2)).await;
+
+    let algo = ExecAlgo::Twap { slices: 3, interval: Duration::from_secs(60) };
+    manager.place_algo_order(create_order("BTC/USD", 3.0), algo).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(manager.place_order(create_order("BTC/USD", 1.0)).await.is_ok());
+    assert!(manager.place_order(create_order("BTC/USD", 1.0)).await.is_err());
+}
+
+#[tokio::test]
+async fn test_removed_rules_stop_applying() {
+    let mut manager = manager_with_rules(SymbolTradingRules::new().with_max_qty(1.0)).await;
+    assert!(manager.place_order(create_order("BTC/USD", 2.0)).await.is_err());
+
+    assert!(manager.remove_symbol_rules("BTC/USD").is_some());
+    assert!(manager.place_order(create_order("BTC/USD", 2.0)).await.is_ok());
+}
@@ -0,0 +1,84 @@
+use arb_platform::order::{Order, OrderEvent, OrderManager, OrderStatus};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::time::Duration;
+use uuid::Uuid;
+
+fn create_order(tags: &[&str]) -> Order {
+    Order {
+        price: Some(Price::from(50000.0)),
+        exchange: "Mock".to_string(),
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        ..test_order()
+    }
+}
+
+async fn manager_with_exchange() -> OrderManager {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    manager
+}
+
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(100)).await;
+}
+
+#[tokio::test]
+async fn test_placed_orders_are_indexed_by_tag() {
+    let manager = manager_with_exchange().await;
+    let hedge = manager.place_order(create_order(&["hedging", "btc"])).await.unwrap();
+    let other = manager.place_order(create_order(&["btc"])).await.unwrap();
+    manager.place_order(create_order(&[])).await.unwrap();
+    settle().await;
+
+    assert_eq!(manager.get_open_order_ids_by_tag("hedging").await, vec![hedge]);
+    let mut btc = manager.get_open_order_ids_by_tag("btc").await;
+    btc.sort();
+    let mut expected = vec![hedge, other];
+    expected.sort();
+    assert_eq!(btc, expected);
+    assert!(manager.get_open_order_ids_by_tag("unknown").await.is_empty());
+
+    let tagged = manager.get_orders_by_tag("hedging").await;
+    assert_eq!(tagged.len(), 1);
+    assert_eq!(tagged[0].id, hedge);
+}
+
+#[tokio::test]
+async fn test_terminal_orders_leave_the_index_but_stay_queryable() {
+    let manager = manager_with_exchange().await;
+    let filled = manager.place_order(create_order(&["hedging"])).await.unwrap();
+    let cancelled = manager.place_order(create_order(&["hedging"])).await.unwrap();
+    let open = manager.place_order(create_order(&["hedging"])).await.unwrap();
+    settle().await;
+
+    manager.get_event_sender().send(OrderEvent::Update {
+        order_id: filled,
+        status: Some(OrderStatus::Filled),
+        filled_qty: Some(1.0),
+        avg_fill_price: Some(50000.0),
+    }).await.unwrap();
+    manager.cancel_order(cancelled, "No longer needed".to_string()).await.unwrap();
+    settle().await;
+
+    assert_eq!(manager.get_open_order_ids_by_tag("hedging").await, vec![open]);
+
+    // Lookups by tag still cover finished orders, oldest first
+    let ids: Vec<Uuid> = manager.get_orders_by_tag("hedging").await.iter().map(|order| order.id).collect();
+    assert_eq!(ids, vec![filled, cancelled, open]);
+}
+
+#[tokio::test]
+async fn test_failed_order_clears_its_tags() {
+    // Without an exchange, routing fails and the order ends up Failed
+    let manager = OrderManager::new();
+    let order_id = manager.place_order(create_order(&["hedging"])).await.unwrap();
+    settle().await;
+
+    assert_eq!(manager.get_order(order_id).await.unwrap().status, OrderStatus::Failed);
+    assert!(manager.get_open_order_ids_by_tag("hedging").await.is_empty());
+    assert_eq!(manager.get_orders_by_tag("hedging").await.len(), 1);
+}
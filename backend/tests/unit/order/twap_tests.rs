@@ -0,0 +1,108 @@
+use arb_platform::order::{ExecAlgo, Order, OrderManager, OrderType, ScheduledChild, TwapExecutor, TWAP_CHILD_TAG};
+use arb_platform::models::Price;
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+fn parent_order(quantity: f64) -> Order {
+    Order {
+        client_order_id: "twap-parent".to_string(),
+        quantity,
+        price: Some(Price::from(50000.0)),
+        exchange: "Mock".to_string(),
+        strategy_id: Some("Momentum".to_string()),
+        tags: vec!["rebalance".to_string()],
+        ..test_order()
+    }
+}
+
+#[test]
+fn test_schedule_is_validated() {
+    assert!(TwapExecutor::new(Duration::from_secs(60), 0).is_err());
+    assert!(TwapExecutor::new(Duration::ZERO, 5).is_err());
+
+    let executor = TwapExecutor::new(Duration::from_secs(60), 4).unwrap();
+    assert_eq!(executor.slice_interval(), Duration::from_secs(15));
+}
+
+#[test]
+fn test_children_split_the_parent_evenly() {
+    let executor = TwapExecutor::new(Duration::from_secs(60), 4).unwrap();
+    let parent = parent_order(10.0);
+    assert_eq!(executor.algo(), ExecAlgo::Twap { slices: 4, interval: Duration::from_secs(15) });
+    let children = executor.algo().schedule(&parent, parent.id);
+
+    assert_eq!(children.len(), 4);
+    for (index, ScheduledChild { offset, order: child }) in children.iter().enumerate() {
+        assert_eq!(*offset, Duration::from_secs(15) * index as u32);
+        assert_ne!(child.id, parent.id);
+        assert_eq!(child.quantity, 2.5);
+        assert_eq!(child.symbol, "BTC/USD");
+        assert_eq!(child.price, Some(Price::from(50000.0)));
+        assert_eq!(child.strategy_id.as_deref(), Some("Momentum"));
+        assert_eq!(child.tags, vec!["rebalance".to_string(), TWAP_CHILD_TAG.to_string()]);
+        assert_eq!(child.client_order_id, format!("twap-parent-{}", index + 1));
+    }
+}
+
+#[tokio::test]
+async fn test_children_are_submitted_over_the_duration() {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    let manager = Arc::new(RwLock::new(manager));
+
+    let executor = TwapExecutor::new(Duration::from_millis(600), 3).unwrap();
+    let parent = parent_order(3.0);
+    let execution = executor.execute(parent.clone(), manager.clone()).await.unwrap();
+    assert_eq!(execution.parent_id, parent.id);
+    assert_eq!(execution.child_ids.len(), 3);
+
+    // The first slice goes out straight away, the rest every 200ms
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let progress = execution.progress_snapshot();
+    assert_eq!(progress.slices_submitted, 1);
+    assert!(!progress.is_complete());
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let progress = execution.progress_snapshot();
+    assert_eq!(progress.slices_submitted, 3);
+    assert_eq!(progress.slices_failed, 0);
+    assert_eq!(progress.quantity_submitted, 3.0);
+    assert!(progress.is_complete());
+
+    let manager = manager.read().await;
+    for child_id in &execution.child_ids {
+        let child = manager.get_order(*child_id).await.unwrap();
+        assert_eq!(child.quantity, 1.0);
+        assert_eq!(child.strategy_id.as_deref(), Some("Momentum"));
+    }
+    let tagged = manager.get_orders_by_tag(TWAP_CHILD_TAG).await;
+    assert_eq!(tagged.len(), 3);
+
+    let tracked = manager.get_twap_execution(parent.id).await.unwrap();
+    assert_eq!(tracked.child_ids, execution.child_ids);
+}
+
+#[tokio::test]
+async fn test_refused_slices_are_recorded() {
+    let manager = Arc::new(RwLock::new(OrderManager::new()));
+    let executor = TwapExecutor::new(Duration::from_millis(100), 2).unwrap();
+
+    // Market orders may not carry a price, so every slice is refused
+    let mut parent = parent_order(2.0);
+    parent.order_type = OrderType::Market;
+    let execution = executor.execute(parent, manager).await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let progress = execution.progress_snapshot();
+    assert_eq!(progress.slices_submitted, 0);
+    assert_eq!(progress.slices_failed, 2);
+    assert_eq!(progress.errors.len(), 2);
+    assert!(progress.is_complete());
+
+    assert!(executor.execute(parent_order(0.0), Arc::new(RwLock::new(OrderManager::new()))).await.is_err());
+}
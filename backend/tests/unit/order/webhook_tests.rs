@@ -0,0 +1,200 @@
+use arb_platform::order::{Order, OrderEvent, OrderManager, OrderStatus, WebhookConfig, WebhookEventKind, WebhookStats};
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+fn create_order() -> Order {
+    Order {
+        client_order_id: "hook-1".to_string(),
+        quantity: 2.0,
+        exchange: "Mock".to_string(),
+        strategy_id: Some("momentum".to_string()),
+        ..test_order()
+    }
+}
+
+fn fast_retries() -> WebhookConfig {
+    WebhookConfig {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(10),
+        timeout: Duration::from_secs(2),
+        ..WebhookConfig::default()
+    }
+}
+
+// Read one HTTP request and return its body
+async fn read_request(socket: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&request);
+        if let Some(header_end) = text.find("\r\n\r\n") {
+            let length = text.lines()
+                .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                .unwrap_or(0);
+            if request.len() >= header_end + 4 + length {
+                return text[header_end + 4..].to_string();
+            }
+        }
+        if n == 0 {
+            return String::new();
+        }
+    }
+}
+
+/// HTTP receiver answering requests with the scripted status lines, then 200
+/// OK, and forwarding each request body. Returns its URL.
+async fn mock_receiver(statuses: &[&'static str]) -> (String, mpsc::UnboundedReceiver<serde_json::Value>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/fills", listener.local_addr().unwrap());
+    let (bodies, received) = mpsc::unbounded_channel();
+    let mut statuses: Vec<&'static str> = statuses.iter().rev().copied().collect();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let body = read_request(&mut socket).await;
+            let status_line = statuses.pop().unwrap_or("HTTP/1.1 200 OK");
+            let response = format!("{}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status_line);
+            socket.write_all(response.as_bytes()).await.unwrap();
+            if status_line.contains("200") {
+                let _ = bodies.send(serde_json::from_str(&body).unwrap());
+            }
+        }
+    });
+    (url, received)
+}
+
+async fn manager_with_exchange() -> OrderManager {
+    let manager = OrderManager::new();
+    manager.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    manager
+}
+
+async fn wait_for_stats(manager: &OrderManager, webhook_id: Uuid, condition: impl Fn(&WebhookStats) -> bool) -> WebhookStats {
+    for _ in 0..200 {
+        let stats = manager.webhook_stats(webhook_id).await.unwrap();
+        if condition(&stats) {
+            return stats;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("webhook stats not reached: {:?}", manager.webhook_stats(webhook_id).await);
+}
+
+#[tokio::test]
+async fn test_fill_event_posts_expected_payload() {
+    let manager = manager_with_exchange().await;
+    let (url, mut received) = mock_receiver(&[]).await;
+    let webhook_id = manager.register_webhook(url, vec![WebhookEventKind::Fill]).await.unwrap();
+
+    let order_id = manager.place_order(create_order()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    manager.get_event_sender().send(OrderEvent::Update {
+        order_id,
+        status: Some(OrderStatus::Filled),
+        filled_qty: Some(2.0),
+        avg_fill_price: Some(101.5),
+    }).await.unwrap();
+
+    let payload = tokio::time::timeout(Duration::from_secs(2), received.recv()).await.unwrap().unwrap();
+    assert_eq!(payload["event"], "fill");
+    assert_eq!(payload["order_id"], order_id.to_string());
+    assert_eq!(payload["client_order_id"], "hook-1");
+    assert_eq!(payload["symbol"], "BTC/USD");
+    assert_eq!(payload["direction"], "buy");
+    assert_eq!(payload["status"], "filled");
+    assert_eq!(payload["quantity"], 2.0);
+    assert_eq!(payload["filled_quantity"], 2.0);
+    assert_eq!(payload["average_fill_price"], "101.5");
+    assert_eq!(payload["strategy_id"], "momentum");
+    assert!(payload["reason"].is_null());
+
+    // Placement and submission were not registered for
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(received.try_recv().is_err());
+    assert_eq!(manager.webhook_stats(webhook_id).await.unwrap(), WebhookStats { delivered: 1, failed: 0, dropped: 0 });
+}
+
+#[tokio::test]
+async fn test_failed_delivery_is_retried() {
+    let manager = OrderManager::new();
+    let (url, mut received) = mock_receiver(&["HTTP/1.1 503 Service Unavailable"]).await;
+    let webhook_id = manager.register_webhook_with_config(url, vec![WebhookEventKind::Error], fast_retries()).await.unwrap();
+
+    manager.get_event_sender().send(OrderEvent::Error { order_id: None, message: "Feed lost".to_string() }).await.unwrap();
+
+    let payload = tokio::time::timeout(Duration::from_secs(2), received.recv()).await.unwrap().unwrap();
+    assert_eq!(payload["event"], "error");
+    assert_eq!(payload["reason"], "Feed lost");
+    assert!(payload["order_id"].is_null());
+    let stats = wait_for_stats(&manager, webhook_id, |stats| stats.delivered == 1).await;
+    assert_eq!(stats.failed, 0);
+}
+
+#[tokio::test]
+async fn test_unreachable_endpoint_is_counted_without_blocking_orders() {
+    // Bind then drop to get a port with nothing listening
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/fills", listener.local_addr().unwrap());
+    drop(listener);
+
+    let manager = manager_with_exchange().await;
+    let webhook_id = manager.register_webhook_with_config(url, Vec::new(), fast_retries()).await.unwrap();
+
+    let order_id = manager.place_order(create_order()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    manager.cancel_order(order_id, "Changed my mind".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(manager.get_order(order_id).await.unwrap().status, OrderStatus::Cancelled);
+
+    // Placed, submitted and cancelled, each given up on after every attempt
+    let stats = wait_for_stats(&manager, webhook_id, |stats| stats.failed >= 3).await;
+    assert_eq!(stats.delivered, 0);
+}
+
+#[tokio::test]
+async fn test_full_queue_drops_payloads() {
+    // Accepts connections but never answers, so the first delivery hangs
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/fills", listener.local_addr().unwrap());
+    let _server = tokio::spawn(async move {
+        let mut open = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            open.push(socket);
+        }
+    });
+
+    let manager = OrderManager::new();
+    let config = WebhookConfig { queue_capacity: 1, timeout: Duration::from_secs(10), ..fast_retries() };
+    let webhook_id = manager.register_webhook_with_config(url, Vec::new(), config).await.unwrap();
+
+    let sender = manager.get_event_sender();
+    for index in 0..5 {
+        sender.send(OrderEvent::Error { order_id: None, message: format!("event {}", index) }).await.unwrap();
+    }
+
+    let stats = wait_for_stats(&manager, webhook_id, |stats| stats.dropped >= 3).await;
+    assert_eq!(stats.delivered, 0);
+}
+
+#[tokio::test]
+async fn test_invalid_url_is_refused_and_webhooks_can_be_removed() {
+    let manager = OrderManager::new();
+    assert!(manager.register_webhook("not a url".to_string(), Vec::new()).await.is_err());
+    assert!(manager.register_webhook("ftp://example.com/fills".to_string(), Vec::new()).await.is_err());
+
+    let webhook_id = manager.register_webhook("http://127.0.0.1:9/fills".to_string(), Vec::new()).await.unwrap();
+    assert!(manager.webhook_stats(webhook_id).await.is_some());
+    assert!(manager.remove_webhook(webhook_id).await);
+    assert!(!manager.remove_webhook(webhook_id).await);
+    assert!(manager.webhook_stats(webhook_id).await.is_none());
+}
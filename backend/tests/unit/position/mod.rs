@@ -0,0 +1,2 @@
+// Position module tests
+pub mod mod_tests;
@@ -0,0 +1,262 @@
+use arb_platform::order::{Order, OrderEvent, OrderManager};
+use arb_platform::position::{PositionManager, MAX_CLOSED_POSITIONS};
+use arb_platform::strategy::{AssetData, AssetType, MarketData, TradeDirection};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::helpers::orders::test_order;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "expected {}, got {}", expected, actual);
+}
+
+#[tokio::test]
+async fn test_fills_average_into_position() {
+    let manager = PositionManager::new();
+    manager.apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    manager.apply_fill("BTC/USD", TradeDirection::Buy, 3.0, 120.0).await;
+    
+    let position = manager.get_position("BTC/USD").await.unwrap();
+    assert_close(position.quantity, 4.0);
+    assert_close(position.avg_price, 115.0);
+    assert_close(position.realized_pnl, 0.0);
+}
+
+#[tokio::test]
+async fn test_reducing_fill_realizes_pnl() {
+    let manager = PositionManager::new();
+    manager.apply_fill("BTC/USD", TradeDirection::Buy, 4.0, 100.0).await;
+    manager.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 110.0).await;
+    
+    let position = manager.get_position("BTC/USD").await.unwrap();
+    assert_close(position.quantity, 3.0);
+    assert_close(position.avg_price, 100.0);
+    assert_close(position.realized_pnl, 10.0);
+    assert_close(manager.net_quantity("BTC/USD").await, 3.0);
+}
+
+#[tokio::test]
+async fn test_fill_through_flat_opens_opposite_side() {
+    let manager = PositionManager::new();
+    manager.apply_fill("ETH/USD", TradeDirection::Buy, 2.0, 100.0).await;
+    manager.apply_fill("ETH/USD", TradeDirection::Sell, 5.0, 90.0).await;
+    
+    let position = manager.get_position("ETH/USD").await.unwrap();
+    assert_close(position.quantity, -3.0);
+    assert_close(position.avg_price, 90.0);
+    assert_close(position.realized_pnl, -20.0);
+}
+
+#[tokio::test]
+async fn test_closing_fill_removes_position() {
+    let manager = PositionManager::new();
+    manager.apply_fill("ETH/USD", TradeDirection::Sell, 2.0, 100.0).await;
+    manager.apply_fill("ETH/USD", TradeDirection::Buy, 2.0, 95.0).await;
+    
+    assert!(manager.get_position("ETH/USD").await.is_none());
+    assert_close(manager.net_quantity("ETH/USD").await, 0.0);
+}
+
+#[tokio::test]
+async fn test_total_pnl_keeps_closed_positions() {
+    let manager = PositionManager::new();
+    manager.apply_fill("ETH/USD", TradeDirection::Sell, 2.0, 100.0).await;
+    manager.apply_fill("ETH/USD", TradeDirection::Buy, 2.0, 95.0).await;
+    manager.apply_fill("BTC/USD", TradeDirection::Buy, 2.0, 100.0).await;
+    manager.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 90.0).await;
+    
+    // 10 realized on ETH, -10 realized and -10 unrealized on BTC
+    assert_close(manager.total_pnl().await, -10.0);
+}
+
+#[tokio::test]
+async fn test_strategy_pnl_attributes_closed_positions() {
+    let manager = PositionManager::new();
+    manager.apply_strategy_fill("BTC/USD", Some("Momentum"), TradeDirection::Buy, 2.0, 100.0).await;
+    manager.apply_strategy_fill("BTC/USD", Some("Momentum"), TradeDirection::Sell, 2.0, 110.0).await;
+    manager.apply_strategy_fill("ETH/USD", Some("Momentum"), TradeDirection::Sell, 1.0, 100.0).await;
+    manager.apply_strategy_fill("ETH/USD", Some("Momentum"), TradeDirection::Buy, 1.0, 105.0).await;
+    manager.apply_strategy_fill("SOL/USD", Some("Momentum"), TradeDirection::Buy, 4.0, 10.0).await;
+    manager.apply_strategy_fill("SOL/USD", Some("Momentum"), TradeDirection::Sell, 1.0, 12.0).await;
+    manager.apply_strategy_fill("XRP/USD", Some("MarketMaking"), TradeDirection::Buy, 1.0, 1.0).await;
+    manager.apply_strategy_fill("XRP/USD", Some("MarketMaking"), TradeDirection::Sell, 1.0, 2.0).await;
+    
+    // +20 on BTC, -5 on ETH, +2 from reducing SOL; SOL's other 3 are open, marked at 12
+    let pnl = manager.get_strategy_pnl("Momentum").await;
+    assert_close(pnl.realized_pnl, 17.0);
+    assert_close(pnl.unrealized_pnl, 6.0);
+    assert_eq!(pnl.trade_count, 2);
+    assert_eq!(pnl.win_count, 1);
+    
+    let unknown = manager.get_strategy_pnl("Unknown").await;
+    assert_eq!(unknown.trade_count, 0);
+    assert_close(unknown.realized_pnl, 0.0);
+    assert_eq!(unknown.avg_holding_duration, Duration::ZERO);
+}
+
+#[tokio::test]
+async fn test_pnl_by_strategy_and_combined() {
+    let manager = PositionManager::new();
+    manager.apply_strategy_fill("BTC/USD", Some("Momentum"), TradeDirection::Buy, 1.0, 100.0).await;
+    manager.apply_strategy_fill("BTC/USD", Some("Momentum"), TradeDirection::Sell, 1.0, 90.0).await;
+    manager.apply_fill("ETH/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    manager.apply_fill("ETH/USD", TradeDirection::Sell, 1.0, 130.0).await;
+    // Still open, so no history for MarketMaking yet
+    manager.apply_strategy_fill("XRP/USD", Some("MarketMaking"), TradeDirection::Buy, 1.0, 1.0).await;
+    
+    let by_strategy = manager.get_pnl_by_strategy().await;
+    assert_eq!(by_strategy.len(), 1);
+    assert_close(by_strategy["Momentum"].realized_pnl, -10.0);
+    assert_eq!(by_strategy["Momentum"].win_count, 0);
+    
+    let combined = manager.get_combined_pnl().await;
+    assert_close(combined.realized_pnl, 20.0);
+    assert_eq!(combined.trade_count, 2);
+    assert_eq!(combined.win_count, 1);
+    assert_close(combined.realized_pnl + combined.unrealized_pnl, manager.total_pnl().await);
+    
+    let closed = manager.get_closed_positions().await;
+    assert_eq!(closed[0].strategy_id.as_deref(), Some("Momentum"));
+    assert_eq!(closed[1].strategy_id, None);
+    assert!(closed.iter().all(|position| position.opened_at.unwrap() <= position.timestamp));
+}
+
+#[tokio::test]
+async fn test_closed_positions_since_cursor() {
+    let manager = PositionManager::new();
+    let (closed, cursor) = manager.closed_positions_since(0).await;
+    assert!(closed.is_empty());
+    assert_eq!(cursor, 0);
+    
+    manager.apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    manager.apply_fill("BTC/USD", TradeDirection::Sell, 1.0, 90.0).await;
+    let (closed, cursor) = manager.closed_positions_since(0).await;
+    assert_eq!(closed.len(), 1);
+    assert_eq!(cursor, 1);
+    
+    manager.apply_fill("ETH/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    manager.apply_fill("ETH/USD", TradeDirection::Sell, 1.0, 130.0).await;
+    let (closed, cursor) = manager.closed_positions_since(cursor).await;
+    assert_eq!(closed.len(), 1);
+    assert_eq!(closed[0].symbol, "ETH/USD");
+    assert_eq!(cursor, 2);
+    assert!(manager.closed_positions_since(cursor).await.0.is_empty());
+}
+
+#[tokio::test]
+async fn test_closed_history_is_capped_but_pnl_covers_every_close() {
+    let manager = PositionManager::new();
+    let closes = MAX_CLOSED_POSITIONS + 5;
+    for _ in 0..closes {
+        manager.apply_strategy_fill("BTC/USD", Some("Momentum"), TradeDirection::Buy, 1.0, 100.0).await;
+        manager.apply_strategy_fill("BTC/USD", Some("Momentum"), TradeDirection::Sell, 1.0, 101.0).await;
+    }
+    
+    assert_eq!(manager.get_closed_positions().await.len(), MAX_CLOSED_POSITIONS);
+    let pnl = manager.get_strategy_pnl("Momentum").await;
+    assert_eq!(pnl.trade_count, closes);
+    assert_eq!(pnl.win_count, closes);
+    assert_close(pnl.realized_pnl, closes as f64);
+    assert_eq!(manager.get_combined_pnl().await.trade_count, closes);
+    assert_close(manager.total_pnl().await, closes as f64);
+    
+    // A reader that fell behind gets what is left of the history
+    let (closed, cursor) = manager.closed_positions_since(0).await;
+    assert_eq!(closed.len(), MAX_CLOSED_POSITIONS);
+    assert_eq!(cursor, closes);
+    assert_eq!(manager.closed_positions_since(closes - 2).await.0.len(), 2);
+}
+
+#[tokio::test]
+async fn test_order_fills_update_position() {
+    let manager = OrderManager::new();
+    let order_id = manager.place_order(Order {
+        client_order_id: "fill-test".to_string(),
+        quantity: 2.0,
+        exchange: "Test Exchange".to_string(),
+        strategy_id: Some("Momentum".to_string()),
+        ..test_order()
+    }).await.unwrap();
+    
+    // Cumulative fill reports: only the increase moves the position
+    let sender = manager.get_event_sender();
+    for filled in [0.5, 2.0] {
+        sender.send(OrderEvent::Update {
+            order_id,
+            status: None,
+            filled_qty: Some(filled),
+            avg_fill_price: Some(101.0),
+        }).await.unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    
+    let position = manager.get_position_manager().get_position("BTC/USD").await.unwrap();
+    assert_close(position.quantity, 2.0);
+    assert_close(position.avg_price, 101.0);
+    assert_eq!(position.strategy_id.as_deref(), Some("Momentum"));
+}
+
+fn snapshot(prices: &[(&str, f64)]) -> MarketData {
+    let asset_data = prices.iter().map(|(symbol, price)| (symbol.to_string(), AssetData {
+        symbol: symbol.to_string(),
+        asset_type: AssetType::Crypto,
+        price: Price::from(*price),
+        volume: 0.0,
+        bid: Price::from(*price),
+        ask: Price::from(*price),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Test Exchange".to_string(),
+        last_update: Utc::now(),
+    })).collect::<HashMap<_, _>>();
+    MarketData { timestamp: Utc::now(), asset_data }
+}
+
+#[tokio::test]
+async fn test_mark_to_market_reprices_open_positions() {
+    let manager = PositionManager::new();
+    manager.apply_fill("BTC/USD", TradeDirection::Buy, 2.0, 100.0).await;
+    manager.apply_fill("ETH/USD", TradeDirection::Sell, 3.0, 50.0).await;
+    
+    let stale = manager.mark_to_market(&snapshot(&[("BTC/USD", 112.5), ("ETH/USD", 45.0)])).await;
+    assert!(stale.is_empty());
+    
+    // Quantity times the move from the average price
+    let btc = manager.get_position("BTC/USD").await.unwrap();
+    assert_close(btc.current_price, 112.5);
+    assert_close(btc.unrealized_pnl, 2.0 * 12.5);
+    let eth = manager.get_position("ETH/USD").await.unwrap();
+    assert_close(eth.unrealized_pnl, -3.0 * -5.0);
+    
+    let pnl = manager.get_account_pnl().await;
+    assert_close(pnl.realized_pnl, 0.0);
+    assert_close(pnl.unrealized_pnl, 40.0);
+    assert_close(pnl.total_pnl, 40.0);
+}
+
+#[tokio::test]
+async fn test_symbols_without_data_keep_their_mark_and_are_stale() {
+    let manager = PositionManager::new();
+    manager.apply_fill("BTC/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    manager.apply_fill("SOL/USD", TradeDirection::Buy, 4.0, 20.0).await;
+    manager.apply_fill("SOL/USD", TradeDirection::Sell, 2.0, 25.0).await;
+    manager.mark_to_market(&snapshot(&[("BTC/USD", 110.0), ("SOL/USD", 22.0)])).await;
+    
+    let stale = manager.mark_to_market(&snapshot(&[("BTC/USD", 105.0)])).await;
+    assert_eq!(stale, vec!["SOL/USD"]);
+    let sol = manager.get_position("SOL/USD").await.unwrap();
+    assert_close(sol.current_price, 22.0);
+    assert_close(sol.unrealized_pnl, 2.0 * 2.0);
+    
+    let pnl = manager.get_account_pnl().await;
+    assert_close(pnl.realized_pnl, 10.0);
+    assert_close(pnl.unrealized_pnl, 5.0 + 4.0);
+    assert_eq!(pnl.stale_symbols, vec!["SOL/USD"]);
+    
+    // A fill is a fresh mark
+    manager.apply_fill("SOL/USD", TradeDirection::Buy, 1.0, 21.0).await;
+    assert!(manager.stale_symbols().await.is_empty());
+}
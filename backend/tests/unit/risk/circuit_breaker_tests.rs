@@ -0,0 +1,77 @@
+use arb_platform::risk::CircuitBreaker;
+
+use chrono::{Duration, TimeZone, Utc};
+
+#[test]
+fn test_breaker_trips_past_intraday_drawdown() {
+    let mut breaker = CircuitBreaker::new(0.05, 1000.0).unwrap();
+    let start = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+    
+    assert!(!breaker.record_pnl(100.0, start));
+    assert!(!breaker.record_pnl(50.0, start + Duration::minutes(1)));
+    assert!(!breaker.is_halted());
+    
+    // 1,100 peak to 1,040 is a 5.45% drawdown
+    assert!(breaker.record_pnl(40.0, start + Duration::minutes(2)));
+    assert!(breaker.is_halted());
+    
+    let status = breaker.status();
+    assert_eq!(status.peak_equity, 1100.0);
+    assert_eq!(status.current_equity, 1040.0);
+    assert!((status.intraday_drawdown - 60.0 / 1100.0).abs() < 1e-9);
+    assert_eq!(status.tripped_at, Some(start + Duration::minutes(2)));
+    
+    // Recovery does not lift the halt, and further losses do not trip it again
+    assert!(!breaker.record_pnl(100.0, start + Duration::minutes(3)));
+    assert!(!breaker.record_pnl(-100.0, start + Duration::minutes(4)));
+    assert!(breaker.is_halted());
+}
+
+#[test]
+fn test_reset_resumes_from_current_equity() {
+    let mut breaker = CircuitBreaker::new(0.05, 1000.0).unwrap();
+    let start = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+    breaker.record_pnl(-100.0, start);
+    assert!(breaker.is_halted());
+    
+    breaker.reset();
+    assert!(!breaker.is_halted());
+    assert_eq!(breaker.status().tripped_at, None);
+    assert_eq!(breaker.intraday_drawdown(), 0.0);
+    
+    // Small further losses from the new peak are tolerated
+    assert!(!breaker.record_pnl(-120.0, start + Duration::minutes(1)));
+    assert!(breaker.record_pnl(-160.0, start + Duration::minutes(2)));
+}
+
+#[test]
+fn test_peak_restarts_each_day() {
+    let mut breaker = CircuitBreaker::new(0.05, 1000.0).unwrap();
+    let day_one = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+    breaker.record_pnl(200.0, day_one);
+    breaker.record_pnl(160.0, day_one + Duration::hours(6));
+    assert!(!breaker.is_halted());
+    
+    // Yesterday's 1,200 peak no longer counts
+    let day_two = day_one + Duration::days(1);
+    assert!(!breaker.record_pnl(150.0, day_two));
+    assert_eq!(breaker.status().peak_equity, 1150.0);
+    assert!(!breaker.record_pnl(100.0, day_two + Duration::hours(1)));
+}
+
+#[test]
+fn test_out_of_order_update_is_ignored() {
+    let mut breaker = CircuitBreaker::new(0.05, 1000.0).unwrap();
+    let start = Utc::now();
+    breaker.record_pnl(0.0, start);
+    assert!(!breaker.record_pnl(-500.0, start - Duration::seconds(1)));
+    assert!(!breaker.is_halted());
+    assert_eq!(breaker.status().current_equity, 1000.0);
+}
+
+#[test]
+fn test_invalid_settings_are_rejected() {
+    assert!(CircuitBreaker::new(0.0, 1000.0).is_err());
+    assert!(CircuitBreaker::new(1.5, 1000.0).is_err());
+    assert!(CircuitBreaker::new(0.05, 0.0).is_err());
+}
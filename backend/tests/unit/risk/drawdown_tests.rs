@@ -0,0 +1,143 @@
+use arb_platform::risk::DrawdownMonitor;
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    AssetType, MarketData, Strategy, StrategyManager, StrategyParams, StrategyResult, StrategyState
+};
+
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+fn counting_monitor(threshold: f64) -> (DrawdownMonitor, Arc<Mutex<Vec<f64>>>) {
+    let fired = Arc::new(Mutex::new(Vec::new()));
+    let mut monitor = DrawdownMonitor::new(threshold);
+    let sink = fired.clone();
+    monitor.on_drawdown(move |drawdown| sink.lock().unwrap().push(drawdown));
+    (monitor, fired)
+}
+
+#[test]
+fn test_drawdown_tracks_high_water_mark() {
+    let mut monitor = DrawdownMonitor::new(0.5);
+    let start = Utc::now();
+    
+    monitor.record_equity(100.0, start);
+    monitor.record_equity(120.0, start + Duration::seconds(1));
+    monitor.record_equity(90.0, start + Duration::seconds(2));
+    
+    assert_eq!(monitor.high_water_mark(), 120.0);
+    assert_eq!(monitor.current_equity(), 90.0);
+    assert!((monitor.current_drawdown() - 0.25).abs() < 1e-9);
+    
+    monitor.record_equity(110.0, start + Duration::seconds(3));
+    assert!((monitor.current_drawdown() - 10.0 / 120.0).abs() < 1e-9);
+    assert!((monitor.max_drawdown_ever() - 0.25).abs() < 1e-9);
+    
+    let snapshot = monitor.snapshot();
+    assert_eq!(snapshot.high_water_mark, 120.0);
+    assert!((snapshot.max_drawdown_ever - 0.25).abs() < 1e-9);
+    assert_eq!(snapshot.last_update, Some(start + Duration::seconds(3)));
+}
+
+#[test]
+fn test_callback_fires_once_per_breach() {
+    let (mut monitor, fired) = counting_monitor(0.1);
+    let start = Utc::now();
+    
+    monitor.record_equity(100.0, start);
+    // Breach, then keep ticking below the threshold
+    for (i, equity) in [85.0, 80.0, 82.0, 75.0].iter().enumerate() {
+        monitor.record_equity(*equity, start + Duration::seconds(i as i64 + 1));
+    }
+    assert_eq!(fired.lock().unwrap().len(), 1);
+    assert!((fired.lock().unwrap()[0] - 0.15).abs() < 1e-9);
+    assert!(monitor.is_in_breach());
+    
+    // Recover, then breach again
+    monitor.record_equity(95.0, start + Duration::seconds(10));
+    assert!(!monitor.is_in_breach());
+    monitor.record_equity(70.0, start + Duration::seconds(11));
+    assert_eq!(fired.lock().unwrap().len(), 2);
+    assert!((monitor.max_drawdown_ever() - 0.30).abs() < 1e-9);
+}
+
+#[test]
+fn test_drawdown_at_threshold_does_not_fire() {
+    let (mut monitor, fired) = counting_monitor(0.1);
+    let start = Utc::now();
+    
+    monitor.record_equity(100.0, start);
+    monitor.record_equity(90.0, start + Duration::seconds(1));
+    assert!(fired.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_threshold_validation() {
+    let mut monitor = DrawdownMonitor::default();
+    assert!(monitor.set_threshold(0.05).is_ok());
+    assert_eq!(monitor.threshold(), 0.05);
+    assert!(monitor.set_threshold(0.0).is_err());
+    assert!(monitor.set_threshold(1.5).is_err());
+}
+
+struct NamedStrategy(&'static str);
+
+impl Strategy for NamedStrategy {
+    fn name(&self) -> &str { self.0 }
+    fn description(&self) -> &str { "Test strategy" }
+    fn asset_types(&self) -> Vec<AssetType> { vec![AssetType::Crypto] }
+    
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        StrategyResult {
+            signals: vec![],
+            confidence: 0.5,
+            expected_profit: 1.0,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+    
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_strategy_manager_pauses_running_strategies_on_breach() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(NamedStrategy("Alpha")));
+    manager.register_strategy(Box::new(NamedStrategy("Beta")));
+    manager.register_strategy(Box::new(NamedStrategy("Idle")));
+    manager.start_strategy("Alpha").unwrap();
+    manager.start_strategy("Beta").unwrap();
+    
+    let threshold = manager.get_drawdown_monitor().threshold();
+    let start = Utc::now();
+    manager.record_equity(100_000.0, start);
+    manager.record_equity(100_000.0 * (1.0 - threshold) - 1.0, start + Duration::seconds(1));
+    
+    assert_eq!(manager.get_strategy_state("Alpha"), Some(StrategyState::Paused));
+    assert_eq!(manager.get_strategy_state("Beta"), Some(StrategyState::Paused));
+    assert_eq!(manager.get_strategy_state("Idle"), Some(StrategyState::Ready));
+    assert!(manager.running_strategies().is_empty());
+    
+    // Paused strategies are not evaluated
+    let market_data = MarketData { timestamp: start, asset_data: HashMap::new() };
+    let results = manager.evaluate_strategies(&market_data);
+    assert_eq!(results.keys().collect::<Vec<_>>(), vec!["Idle"]);
+    
+    // Strategies can be resumed once the operator is satisfied
+    manager.start_strategy("Alpha").unwrap();
+    assert_eq!(manager.running_strategies(), vec!["Alpha".to_string()]);
+}
+
+#[test]
+fn test_strategy_state_transitions_are_validated() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(NamedStrategy("Alpha")));
+    
+    assert!(manager.pause_strategy("Alpha").is_err()); // Ready cannot be paused
+    assert!(manager.start_strategy("Missing").is_err());
+    assert!(manager.start_strategy("Alpha").is_ok());
+    assert!(manager.pause_strategy("Alpha").is_ok());
+}
@@ -0,0 +1,184 @@
+use arb_platform::error::TradingError;
+use arb_platform::exchange::Position;
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::models::Price;
+use arb_platform::order::{OrderManager, OrderType};
+use arb_platform::risk::{AutoHedgeConfig, PositionHedger, HEDGER_STRATEGY_ID};
+use arb_platform::strategy::{AssetData, AssetType, TradeDirection};
+
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::helpers::mock_exchange::MockExchange;
+
+fn create_position(symbol: &str, quantity: f64, price: f64) -> Position {
+    Position {
+        symbol: symbol.to_string(),
+        quantity,
+        avg_price: price,
+        current_price: price,
+        unrealized_pnl: 0.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }
+}
+
+async fn set_price(market_data: &Arc<RwLock<MarketDataManager>>, symbol: &str, price: f64) {
+    let current_data = market_data.read().await.get_current_data();
+    current_data.write().await.asset_data.insert(symbol.to_string(), AssetData {
+        symbol: symbol.to_string(),
+        asset_type: AssetType::Crypto,
+        price: Price::from(price),
+        volume: 0.0,
+        bid: Price::from(price),
+        ask: Price::from(price),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Primary".to_string(),
+        last_update: Utc::now(),
+    });
+}
+
+// Positions are held through "Primary"; "Hedge" is where hedges go
+async fn create_hedger(positions: &[(&str, f64, f64)]) -> (PositionHedger, MockExchange, Arc<RwLock<OrderManager>>, Arc<RwLock<MarketDataManager>>) {
+    let order_manager = OrderManager::new();
+    let router = order_manager.get_order_router();
+    router.register_exchange(Box::new(MockExchange::new("Primary"))).await.unwrap();
+    let hedge_exchange = MockExchange::new("Hedge");
+    router.register_exchange(Box::new(hedge_exchange.clone())).await.unwrap();
+
+    let position_manager = order_manager.get_position_manager();
+    for (symbol, quantity, price) in positions {
+        position_manager.update_position(create_position(symbol, *quantity, *price)).await;
+    }
+
+    let order_manager = Arc::new(RwLock::new(order_manager));
+    let market_data = Arc::new(RwLock::new(MarketDataManager::new()));
+    let hedger = PositionHedger::new(order_manager.clone(), market_data.clone());
+    (hedger, hedge_exchange, order_manager, market_data)
+}
+
+#[tokio::test]
+async fn test_hedge_sells_against_a_long_position_on_the_hedge_exchange() {
+    let (hedger, hedge_exchange, order_manager, market_data) = create_hedger(&[("BTC/USD", 2.0, 50000.0)]).await;
+    set_price(&market_data, "BTC/USD", 40000.0).await;
+
+    // Half of 100,000 USD at the current price of 40,000
+    let order_id = hedger.hedge_position("BTC/USD", 100000.0, 0.5, "Hedge").await.unwrap();
+    let order = order_manager.read().await.get_order(order_id).await.unwrap();
+    assert_eq!(order.direction, TradeDirection::Sell);
+    assert_eq!(order.order_type, OrderType::Market);
+    assert!((order.quantity - 1.25).abs() < 1e-12);
+    assert_eq!(order.exchange, "Hedge");
+    assert_eq!(order.strategy_id.as_deref(), Some(HEDGER_STRATEGY_ID));
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    hedge_exchange.assert_order_submitted(order_id);
+}
+
+#[tokio::test]
+async fn test_hedge_buys_against_a_short_position_at_the_position_price() {
+    // Without market data the position's own mark is used
+    let (hedger, _, order_manager, _) = create_hedger(&[("ETH/USD", -10.0, 2000.0)]).await;
+
+    let order_id = hedger.hedge_position("ETH/USD", 20000.0, 1.0, "Hedge").await.unwrap();
+    let order = order_manager.read().await.get_order(order_id).await.unwrap();
+    assert_eq!(order.direction, TradeDirection::Buy);
+    assert!((order.quantity - 10.0).abs() < 1e-12);
+}
+
+#[tokio::test]
+async fn test_hedge_is_refused_without_placing_an_order() {
+    let (hedger, hedge_exchange, order_manager, _) = create_hedger(&[("BTC/USD", 1.0, 50000.0), ("XRP/USD", 100.0, 0.5)]).await;
+
+    // The mock exchange does not list XRP
+    let result = hedger.hedge_position("XRP/USD", 50.0, 1.0, "Hedge").await;
+    assert!(matches!(result, Err(TradingError::Validation(ref msg)) if msg.contains("does not support")), "{:?}", result);
+
+    let result = hedger.hedge_position("BTC/USD", 50000.0, 1.0, "Elsewhere").await;
+    assert!(matches!(result, Err(TradingError::NotFound(_))), "{:?}", result);
+
+    let result = hedger.hedge_position("SOL/USD", 1000.0, 1.0, "Hedge").await;
+    assert!(matches!(result, Err(TradingError::Validation(ref msg)) if msg.contains("No open position")), "{:?}", result);
+
+    for (position_usd, hedge_ratio) in [(0.0, 1.0), (-5.0, 1.0), (50000.0, 0.0), (50000.0, f64::NAN)] {
+        let result = hedger.hedge_position("BTC/USD", position_usd, hedge_ratio, "Hedge").await;
+        assert!(matches!(result, Err(TradingError::Validation(_))), "{} at {}: {:?}", position_usd, hedge_ratio, result);
+    }
+
+    assert!(order_manager.read().await.get_active_orders().await.is_empty());
+    assert!(hedge_exchange.submitted_orders().is_empty());
+}
+
+#[tokio::test]
+async fn test_auto_hedge_only_above_the_var_threshold() {
+    // 95% VaR of 100 USD at 5% daily volatility is 8.225
+    let (hedger, _, order_manager, _) = create_hedger(&[("BTC/USD", 2.0, 50.0)]).await;
+    order_manager.read().await.get_portfolio_manager().set_volatility("BTC/USD", 0.05).await.unwrap();
+
+    let quiet = PositionHedger::new(order_manager.clone(), Arc::default())
+        .with_auto_hedge(AutoHedgeConfig::new(10.0, 1.0, "Hedge").unwrap());
+    assert!(quiet.auto_hedge().await.is_empty());
+    assert!(hedger.auto_hedge().await.is_empty(), "Auto-hedging is off unless configured");
+
+    let hedger = hedger.with_auto_hedge(AutoHedgeConfig::new(5.0, 0.5, "Hedge").unwrap());
+    let results = hedger.auto_hedge().await;
+    assert_eq!(results.len(), 1);
+    let (symbol, result) = &results[0];
+    assert_eq!(symbol, "BTC/USD");
+    let order = order_manager.read().await.get_order(*result.as_ref().unwrap()).await.unwrap();
+    assert_eq!(order.direction, TradeDirection::Sell);
+    assert!((order.quantity - 1.0).abs() < 1e-12);
+
+    // The hedge is still working, so it is not doubled up
+    assert!(hedger.auto_hedge().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_auto_hedge_on_var_estimated_from_market_prices() {
+    let market_data = Arc::new(RwLock::new(MarketDataManager::new()));
+    let mut order_manager = OrderManager::new();
+    order_manager.set_price_converter(market_data.read().await.get_price_converter(), "USD");
+    let router = order_manager.get_order_router();
+    router.register_exchange(Box::new(MockExchange::new("Primary"))).await.unwrap();
+    let hedge_exchange = MockExchange::new("Hedge");
+    router.register_exchange(Box::new(hedge_exchange.clone())).await.unwrap();
+    order_manager.get_position_manager().apply_fill("BTC/USD", TradeDirection::Buy, 2.0, 100.0).await;
+    let order_manager = Arc::new(RwLock::new(order_manager));
+    let hedger = PositionHedger::new(order_manager.clone(), market_data.clone())
+        .with_auto_hedge(AutoHedgeConfig::new(5.0, 0.5, "Hedge").unwrap());
+
+    // No prices sampled, so no VaR to hedge against
+    assert!(hedger.auto_hedge().await.is_empty());
+
+    // BTC alternating 5% either side of 100: 200 held at about 10% volatility
+    for i in 0..40 {
+        set_price(&market_data, "BTC/USD", if i % 2 == 0 { 105.0 } else { 95.0 }).await;
+        order_manager.read().await.record_portfolio_prices().await;
+    }
+    set_price(&market_data, "BTC/USD", 100.0).await;
+    assert!(order_manager.read().await.portfolio_var_exposure().await > 5.0);
+
+    let results = hedger.auto_hedge().await;
+    assert_eq!(results.len(), 1);
+    let order_id = results[0].1.as_ref().copied().unwrap();
+    let order = order_manager.read().await.get_order(order_id).await.unwrap();
+    assert_eq!(order.direction, TradeDirection::Sell);
+    assert!((order.quantity - 1.0).abs() < 1e-12);
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    hedge_exchange.assert_order_submitted(order_id);
+}
+
+#[test]
+fn test_auto_hedge_config_rejects_invalid_settings() {
+    assert!(AutoHedgeConfig::new(-1.0, 1.0, "Hedge").is_err());
+    assert!(AutoHedgeConfig::new(f64::INFINITY, 1.0, "Hedge").is_err());
+    assert!(AutoHedgeConfig::new(100.0, 0.0, "Hedge").is_err());
+    assert!(AutoHedgeConfig::new(100.0, 1.0, "").is_err());
+    assert_eq!(AutoHedgeConfig::new(100.0, 0.5, "Hedge").unwrap().hedge_ratio, 0.5);
+}
@@ -0,0 +1,6 @@
+// Risk module tests
+pub mod drawdown_tests;
+pub mod var_tests;
+pub mod circuit_breaker_tests;
+pub mod position_sizer_tests;
+pub mod hedger_tests;
@@ -0,0 +1,113 @@
+use arb_platform::risk::{FixedFractionalSizer, KellySizer, PositionSizer, TradeStats};
+use arb_platform::strategy::{StrategyManager, TimeInForce, TradeDirection, TradeSignal};
+
+use crate::helpers::fixed_side_strategy::{daily_history, FixedSideStrategy};
+
+use chrono::Utc;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "expected {}, got {}", expected, actual);
+}
+
+fn signal() -> TradeSignal {
+    TradeSignal {
+        asset: "BTC/USD".to_string(),
+        direction: TradeDirection::Buy,
+        quantity: 1.0,
+        limit_price: None,
+        stop_price: None,
+        time_in_force: TimeInForce::Day,
+        priority: TimeInForce::Day.default_signal_priority(),
+    }
+}
+
+#[test]
+fn test_kelly_fraction() {
+    // 60% wins of 2 against 40% losses of 1: (1.2 - 0.4) / 2
+    assert_close(KellySizer::kelly_fraction(0.6, 2.0, 1.0), 0.4);
+    // No edge, and a negative one
+    assert_close(KellySizer::kelly_fraction(0.5, 1.0, 1.0), 0.0);
+    assert!(KellySizer::kelly_fraction(0.3, 1.0, 1.0) < 0.0);
+    // Invalid inputs
+    assert_close(KellySizer::kelly_fraction(1.5, 2.0, 1.0), 0.0);
+    assert_close(KellySizer::kelly_fraction(0.6, 0.0, 1.0), 0.0);
+}
+
+#[test]
+fn test_kelly_sizer_scales_by_fraction_and_caps() {
+    let full = KellySizer { fraction: 1.0, max_position_pct: 1.0 };
+    let half = KellySizer { fraction: 0.5, max_position_pct: 1.0 };
+    assert_close(full.compute_size(&signal(), 100_000.0, 0.6, 2.0, 1.0), 40_000.0);
+    assert_close(half.compute_size(&signal(), 100_000.0, 0.6, 2.0, 1.0), 20_000.0);
+
+    let capped = KellySizer::new(1.0);
+    assert_close(capped.compute_size(&signal(), 100_000.0, 0.6, 2.0, 1.0), 25_000.0);
+
+    // Without an edge there is no position
+    assert_close(full.compute_size(&signal(), 100_000.0, 0.3, 1.0, 1.0), 0.0);
+}
+
+#[test]
+fn test_kelly_never_exceeds_equity() {
+    let sizers = [
+        KellySizer { fraction: 1.0, max_position_pct: 1.0 },
+        KellySizer { fraction: 3.0, max_position_pct: 1.0 },
+        KellySizer { fraction: 2.0, max_position_pct: 5.0 },
+    ];
+    for sizer in &sizers {
+        for win_rate in [0.0, 0.25, 0.5, 0.75, 0.99, 1.0] {
+            for (avg_win, avg_loss) in [(1.0, 1.0), (10.0, 0.1), (1000.0, 0.0), (0.01, 5.0)] {
+                let size = sizer.compute_size(&signal(), 50_000.0, win_rate, avg_win, avg_loss);
+                assert!((0.0..=50_000.0).contains(&size), "{} of 50000 equity", size);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fixed_fractional_sizer() {
+    let sizer = FixedFractionalSizer { fraction: 0.1 };
+    assert_close(sizer.compute_size(&signal(), 100_000.0, 0.0, 0.0, 0.0), 10_000.0);
+    assert_close(FixedFractionalSizer { fraction: 2.0 }.compute_size(&signal(), 100_000.0, 0.5, 1.0, 1.0), 100_000.0);
+}
+
+#[test]
+fn test_trade_stats() {
+    let mut stats = TradeStats::default();
+    for pnl in [30.0, -10.0, 10.0, -20.0] {
+        stats.record(pnl);
+    }
+    assert_eq!(stats.trades(), 4);
+    assert_close(stats.win_rate(), 0.5);
+    assert_close(stats.avg_win(), 20.0);
+    assert_close(stats.avg_loss(), 15.0);
+}
+
+#[test]
+fn test_strategy_manager_sizes_signals() {
+    let mut manager = StrategyManager::new();
+    manager.set_stale_data_threshold(None);
+    manager.register_strategy(FixedSideStrategy::factory()());
+    manager.set_position_sizer(Some(Box::new(KellySizer { fraction: 0.5, max_position_pct: 1.0 })));
+    let market_data = daily_history(&[200.0]).remove(0);
+
+    // Nothing to size from yet: the strategy's own quantity stands
+    let result = manager.evaluate_one("Fixed Side", &market_data).unwrap();
+    assert_close(result.signals[0].quantity, 1.0);
+
+    manager.record_equity(100_000.0, Utc::now());
+    for pnl in [200.0, 200.0, 200.0, -100.0, -100.0] {
+        manager.record_trade_result("Fixed Side", pnl);
+    }
+
+    // Half of a 0.4 Kelly fraction of 100000, at 200 a unit
+    let result = manager.evaluate_one("Fixed Side", &market_data).unwrap();
+    assert_close(result.signals[0].quantity, 100.0);
+
+    // A losing record sizes the signal away
+    for _ in 0..5 {
+        manager.record_trade_result("Fixed Side", -200.0);
+    }
+    let results = manager.evaluate_strategies(&market_data);
+    assert!(results["Fixed Side"].signals.is_empty());
+}
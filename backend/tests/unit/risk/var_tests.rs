@@ -0,0 +1,106 @@
+use arb_platform::exchange::Position;
+use arb_platform::position::PositionManager;
+use arb_platform::risk::{VarCalculator, VarMethod, MIN_VAR_OBSERVATIONS};
+
+use chrono::Utc;
+
+// 100 evenly spaced returns from -5.0% to +4.9%
+fn spaced_returns() -> Vec<f64> {
+    (0..100).map(|i| (i as f64 - 50.0) / 1000.0).rev().collect()
+}
+
+fn sample_std(returns: &[f64]) -> (f64, f64) {
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance.sqrt())
+}
+
+#[test]
+fn test_historical_var() {
+    let var = VarCalculator::historical_var(&spaced_returns(), 0.95).unwrap();
+    assert!((var - 0.045).abs() < 1e-12);
+    
+    let var_99 = VarCalculator::historical_var(&spaced_returns(), 0.99).unwrap();
+    assert!((var_99 - 0.049).abs() < 1e-12);
+}
+
+#[test]
+fn test_conditional_var_exceeds_var() {
+    let returns = spaced_returns();
+    let cvar = VarCalculator::conditional_var(&returns, 0.95).unwrap();
+    
+    // Mean of the six worst returns: -5.0% .. -4.5%
+    assert!((cvar - 0.0475).abs() < 1e-12);
+    assert!(cvar >= VarCalculator::historical_var(&returns, 0.95).unwrap());
+}
+
+#[test]
+fn test_parametric_var() {
+    let returns = spaced_returns();
+    let (mean, std) = sample_std(&returns);
+    
+    let var = VarCalculator::parametric_var(&returns, 0.95, 1000.0).unwrap();
+    let expected = 1000.0 * (1.6448536 * std - mean);
+    assert!((var - expected).abs() < 1e-3, "{} vs {}", var, expected);
+    
+    let var_99 = VarCalculator::parametric_var(&returns, 0.99, 1000.0).unwrap();
+    let expected_99 = 1000.0 * (2.3263479 * std - mean);
+    assert!((var_99 - expected_99).abs() < 1e-3, "{} vs {}", var_99, expected_99);
+}
+
+#[test]
+fn test_losses_are_reported_as_positive_and_gains_as_zero() {
+    let all_gains = vec![0.01; MIN_VAR_OBSERVATIONS];
+    assert_eq!(VarCalculator::historical_var(&all_gains, 0.95), Some(0.0));
+    assert_eq!(VarCalculator::conditional_var(&all_gains, 0.95), Some(0.0));
+    assert_eq!(VarCalculator::parametric_var(&all_gains, 0.95, 1000.0), Some(0.0));
+    
+    let all_losses = vec![-0.02; MIN_VAR_OBSERVATIONS];
+    assert!((VarCalculator::historical_var(&all_losses, 0.95).unwrap() - 0.02).abs() < 1e-12);
+}
+
+#[test]
+fn test_insufficient_data_or_invalid_confidence() {
+    let short = vec![-0.01; MIN_VAR_OBSERVATIONS - 1];
+    assert!(VarCalculator::historical_var(&short, 0.95).is_none());
+    assert!(VarCalculator::parametric_var(&short, 0.95, 1000.0).is_none());
+    assert!(VarCalculator::conditional_var(&short, 0.95).is_none());
+    
+    let returns = spaced_returns();
+    assert!(VarCalculator::historical_var(&returns, 0.0).is_none());
+    assert!(VarCalculator::historical_var(&returns, 1.0).is_none());
+}
+
+#[tokio::test]
+async fn test_portfolio_var_scales_with_position_value() {
+    let manager = PositionManager::new();
+    assert!(manager.current_portfolio_var(0.95, VarMethod::Historical).await.is_none());
+    
+    manager.update_position(Position {
+        symbol: "BTC/USD".to_string(),
+        quantity: 2.0,
+        avg_price: 30000.0,
+        current_price: 25000.0,
+        unrealized_pnl: -10000.0,
+        realized_pnl: 0.0,
+        timestamp: Utc::now(),
+        strategy_id: None,
+        opened_at: None,
+    }).await;
+    for daily_return in spaced_returns() {
+        manager.record_daily_return(daily_return).await;
+    }
+    
+    assert_eq!(manager.portfolio_value().await, 50000.0);
+    
+    let historical = manager.current_portfolio_var(0.95, VarMethod::Historical).await.unwrap();
+    assert!((historical - 0.045 * 50000.0).abs() < 1e-6);
+    
+    let parametric = manager.current_portfolio_var(0.95, VarMethod::Parametric).await.unwrap();
+    let expected = VarCalculator::parametric_var(&spaced_returns(), 0.95, 50000.0).unwrap();
+    assert!((parametric - expected).abs() < 1e-6);
+    
+    let cvar = manager.current_portfolio_cvar(0.95).await.unwrap();
+    assert!((cvar - 0.0475 * 50000.0).abs() < 1e-6);
+}
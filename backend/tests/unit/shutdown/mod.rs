@@ -0,0 +1,2 @@
+// Shutdown module tests
+pub mod mod_tests;
@@ -0,0 +1,98 @@
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::order::{Order, OrderManager, OrderStatus};
+use arb_platform::shutdown::{ShutdownCoordinator, DEFAULT_DRAIN_TIMEOUT, SHUTDOWN_CANCEL_REASON};
+use arb_platform::strategy::{StrategyEvaluationScheduler, StrategyManager};
+
+use crate::helpers::fixed_side_strategy::FixedSideStrategy;
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+fn create_order() -> Order {
+    Order {
+        exchange: "Mock".to_string(),
+        ..test_order()
+    }
+}
+
+async fn order_manager(exchange: &MockExchange) -> Arc<RwLock<OrderManager>> {
+    let mut order_manager = OrderManager::new();
+    order_manager.set_signal_submission_delay(Duration::ZERO);
+    let router = order_manager.get_order_router();
+    router.register_exchange(Box::new(exchange.clone())).await.unwrap();
+    router.set_primary_exchange("BTC/USD", "Mock").await.unwrap();
+    Arc::new(RwLock::new(order_manager))
+}
+
+async fn wait_for_status(order_manager: &RwLock<OrderManager>, order_id: Uuid, status: OrderStatus) {
+    for _ in 0..100 {
+        if order_manager.read().await.get_order(order_id).await.is_some_and(|order| order.status == status) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("order {} never reached {:?}", order_id, status);
+}
+
+#[tokio::test]
+async fn test_shutdown_stops_evaluation_and_cancels_open_orders() {
+    let exchange = MockExchange::new("Mock");
+    let order_manager = order_manager(&exchange).await;
+    let market_data_manager = Arc::new(RwLock::new(MarketDataManager::new()));
+    let mut strategies = StrategyManager::new();
+    strategies.register_strategy(Box::new(FixedSideStrategy::default()));
+    strategies.set_active_strategy("Fixed Side").unwrap();
+
+    let mut scheduler = StrategyEvaluationScheduler::new(
+        Arc::new(RwLock::new(strategies)), market_data_manager.clone(), order_manager.clone(), Duration::from_millis(20));
+    scheduler.start().unwrap();
+    let resting = order_manager.read().await.place_order(create_order()).await.unwrap();
+    wait_for_status(&order_manager, resting, OrderStatus::Submitted).await;
+
+    let coordinator = ShutdownCoordinator::new(order_manager.clone(), market_data_manager)
+        .with_scheduler(scheduler)
+        .with_drain_timeout(Duration::from_secs(1));
+    assert_eq!(coordinator.drain_timeout(), Duration::from_secs(1));
+    assert!(coordinator.shutdown().await.is_empty());
+
+    let manager = order_manager.read().await;
+    assert!(manager.get_active_orders().await.is_empty());
+    let order = manager.get_order(resting).await.unwrap();
+    assert_eq!(order.status, OrderStatus::Cancelled);
+    assert_eq!(order.notes.as_deref(), Some(SHUTDOWN_CANCEL_REASON));
+    exchange.assert_order_cancelled(resting);
+
+    // Evaluation has stopped, so nothing new is placed
+    drop(manager);
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert!(order_manager.read().await.get_active_orders().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_orders_left_after_the_drain_timeout_are_reported() {
+    let exchange = MockExchange::new("Mock");
+    exchange.set_submit_delay(Duration::from_secs(5));
+    let order_manager = order_manager(&exchange).await;
+    let stuck = order_manager.read().await.place_order(create_order()).await.unwrap();
+    wait_for_status(&order_manager, stuck, OrderStatus::PendingSubmission).await;
+
+    let coordinator = ShutdownCoordinator::new(order_manager.clone(), Arc::new(RwLock::new(MarketDataManager::new())))
+        .with_drain_timeout(Duration::from_millis(150));
+    assert_eq!(coordinator.shutdown().await, vec![stuck]);
+    assert_eq!(order_manager.read().await.get_order(stuck).await.unwrap().status, OrderStatus::PendingSubmission);
+}
+
+#[tokio::test]
+async fn test_shutdown_with_nothing_open_finishes_at_once() {
+    let exchange = MockExchange::new("Mock");
+    let coordinator = ShutdownCoordinator::new(order_manager(&exchange).await, Arc::new(RwLock::new(MarketDataManager::new())));
+    assert_eq!(coordinator.drain_timeout(), DEFAULT_DRAIN_TIMEOUT);
+
+    let started = std::time::Instant::now();
+    assert!(coordinator.shutdown().await.is_empty());
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
@@ -0,0 +1,142 @@
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    AssetData, AssetType, LiquidityConfig, LiquidityFilter, LiquidityRejection, LiquidityThresholds, MarketData,
+    Strategy, StrategyManager, StrategyParams, StrategyResult, TradeDirection, TradeSignal, TimeInForce
+};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+use std::collections::HashMap;
+
+// Buys every symbol it is shown
+struct BuyEverythingStrategy;
+
+impl Strategy for BuyEverythingStrategy {
+    fn name(&self) -> &str {
+        "Buy Everything"
+    }
+
+    fn description(&self) -> &str {
+        "Signals a buy for each symbol in the market data"
+    }
+
+    fn asset_types(&self) -> Vec<AssetType> {
+        vec![AssetType::Crypto, AssetType::Stock]
+    }
+
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        StrategyResult {
+            signals: market_data.asset_data.keys().map(|symbol| TradeSignal {
+                asset: symbol.clone(),
+                direction: TradeDirection::Buy,
+                quantity: 1.0,
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                priority: TimeInForce::Day.default_signal_priority(),
+            }).collect(),
+            confidence: 0.8,
+            expected_profit: 1.0,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+}
+
+fn asset(symbol: &str, asset_type: AssetType, bid: f64, ask: f64, volume: f64) -> AssetData {
+    AssetData {
+        symbol: symbol.to_string(),
+        asset_type,
+        price: Price::from((bid + ask) / 2.0),
+        volume,
+        bid: Price::from(bid),
+        ask: Price::from(ask),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Mock".to_string(),
+        last_update: Utc::now(),
+    }
+}
+
+fn market_data(assets: Vec<AssetData>) -> MarketData {
+    MarketData {
+        timestamp: Utc::now(),
+        asset_data: assets.into_iter().map(|asset| (asset.symbol.clone(), asset)).collect::<HashMap<_, _>>(),
+    }
+}
+
+fn crypto_config() -> LiquidityConfig {
+    LiquidityConfig::new().with_asset_type(AssetType::Crypto, LiquidityThresholds::new(50.0, 1000.0))
+}
+
+#[test]
+fn test_wide_spread_is_rejected() {
+    let filter = LiquidityFilter::new(crypto_config());
+
+    // 5% spread around a mid of 100
+    match filter.check(&asset("BTC/USD", AssetType::Crypto, 97.5, 102.5, 5000.0)) {
+        Err(LiquidityRejection::SpreadTooWide { spread_bps, max_spread_bps }) => {
+            assert!((spread_bps - 500.0).abs() < 1e-9);
+            assert_eq!(max_spread_bps, 50.0);
+        },
+        other => panic!("expected a spread rejection, got {:?}", other),
+    }
+    // 0.2% passes
+    assert!(filter.check(&asset("BTC/USD", AssetType::Crypto, 99.9, 100.1, 5000.0)).is_ok());
+}
+
+#[test]
+fn test_low_volume_is_rejected() {
+    let filter = LiquidityFilter::new(crypto_config());
+
+    assert_eq!(
+        filter.check(&asset("BTC/USD", AssetType::Crypto, 99.9, 100.1, 10.0)),
+        Err(LiquidityRejection::VolumeTooLow { volume: 10.0, min_volume_threshold: 1000.0 })
+    );
+}
+
+#[test]
+fn test_thresholds_are_per_asset_type() {
+    let config = crypto_config().with_default(LiquidityThresholds::new(5.0, 0.0));
+    assert_eq!(config.thresholds_for(AssetType::Crypto), Some(LiquidityThresholds::new(50.0, 1000.0)));
+    assert_eq!(config.thresholds_for(AssetType::Stock), Some(LiquidityThresholds::new(5.0, 0.0)));
+
+    // Without a default, other asset types are not filtered
+    let filter = LiquidityFilter::new(crypto_config());
+    assert!(filter.check(&asset("XYZ", AssetType::Stock, 90.0, 110.0, 0.0)).is_ok());
+}
+
+#[test]
+fn test_unquoted_spread_is_not_judged() {
+    let filter = LiquidityFilter::new(crypto_config());
+    assert!(filter.check(&asset("BTC/USD", AssetType::Crypto, 0.0, 0.0, 5000.0)).is_ok());
+}
+
+#[test]
+fn test_active_strategy_signals_are_filtered_and_counted() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(BuyEverythingStrategy));
+    manager.set_active_strategy("Buy Everything").unwrap();
+    manager.set_liquidity_config(crypto_config());
+
+    let market_data = market_data(vec![
+        asset("BTC/USD", AssetType::Crypto, 99.9, 100.1, 5000.0),
+        asset("ALT/USD", AssetType::Crypto, 97.5, 102.5, 5000.0),
+        asset("XYZ", AssetType::Stock, 90.0, 110.0, 0.0),
+    ]);
+
+    let result = manager.get_active_strategy_signals(&market_data).unwrap();
+    let mut assets: Vec<&str> = result.signals.iter().map(|signal| signal.asset.as_str()).collect();
+    assets.sort();
+    assert_eq!(assets, vec!["BTC/USD", "XYZ"]);
+    assert_eq!(manager.signals_filtered_by_liquidity(), 1);
+
+    // Changing the thresholds keeps the count
+    manager.set_liquidity_config(LiquidityConfig::new());
+    assert_eq!(manager.get_active_strategy_signals(&market_data).unwrap().signals.len(), 3);
+    assert_eq!(manager.signals_filtered_by_liquidity(), 1);
+}
@@ -0,0 +1,133 @@
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    AssetType, MarketData, MomentumStrategy, Strategy, StrategyParams, StrategyResult, TradeDirection, TradeSignal,
+    TimeInForce
+};
+
+use crate::helpers::strategy_harness::StrategyTestHarness;
+
+use chrono::Duration;
+use serde_json::json;
+use std::collections::HashSet;
+
+// Buys each symbol trading below 100 that it does not hold yet, and sells
+// what it holds once it trades above 110. Holdings come from fills.
+#[derive(Default)]
+struct DipBuyer {
+    held: HashSet<String>,
+}
+
+impl Strategy for DipBuyer {
+    fn name(&self) -> &str {
+        "Dip Buyer"
+    }
+
+    fn description(&self) -> &str {
+        "Buys dips and sells rallies"
+    }
+
+    fn asset_types(&self) -> Vec<AssetType> {
+        vec![AssetType::Crypto]
+    }
+
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        let mut symbols: Vec<&String> = market_data.asset_data.keys().collect();
+        symbols.sort();
+        let signals = symbols.into_iter().filter_map(|symbol| {
+            let price = market_data.asset_data[symbol].price.to_f64();
+            let held = self.held.contains(symbol);
+            let direction = match (held, price) {
+                (false, p) if p < 100.0 => TradeDirection::Buy,
+                (true, p) if p > 110.0 => TradeDirection::Sell,
+                _ => return None,
+            };
+            Some(TradeSignal {
+                asset: symbol.clone(),
+                direction,
+                quantity: 1.0,
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                priority: TimeInForce::Day.default_signal_priority(),
+            })
+        }).collect();
+
+        StrategyResult { signals, confidence: 1.0, expected_profit: 0.0, timestamp: market_data.timestamp, warming_up: false }
+    }
+
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+
+    fn on_fill(&mut self, signal: &TradeSignal, _fill_price: f64) {
+        match signal.direction {
+            TradeDirection::Buy => self.held.insert(signal.asset.clone()),
+            TradeDirection::Sell => self.held.remove(&signal.asset),
+        };
+    }
+}
+
+#[test]
+fn test_momentum_crossovers_through_harness() {
+    let mut strategy = MomentumStrategy::new();
+    strategy.update_params(StrategyParams {
+        params: [
+            ("fast_period".to_string(), json!(2)),
+            ("slow_period".to_string(), json!(4)),
+            ("signal_period".to_string(), json!(2)),
+            ("max_position_usd".to_string(), json!(1000.0)),
+        ].into_iter().collect(),
+    }).unwrap();
+
+    let run = StrategyTestHarness::new(strategy)
+        .add_symbol("BTC/USD", 100.0)
+        .add_price_sequence("BTC/USD", vec![100.0, 101.0, 102.0, 103.0, 104.0, 105.0, 103.0, 100.0, 97.0, 96.0, 98.0, 102.0, 106.0, 108.0])
+        .run();
+
+    assert_eq!(run.ticks(), 14);
+    assert_eq!(run.total_signals(), 2);
+    assert_eq!(run.signals_at(7)[0].direction, TradeDirection::Sell);
+    let first_buy = run.first_buy_signal_for("BTC/USD").unwrap();
+    assert!((first_buy.quantity - run.signals_at(11)[0].quantity).abs() < 1e-12);
+    assert!((first_buy.quantity - 1000.0 / 102.0).abs() < 1e-9);
+    assert!(run.first_buy_signal_for("ETH/USD").is_none());
+    assert!(run.signals_at(100).is_empty());
+}
+
+#[test]
+fn test_fills_are_fed_back_to_the_strategy() {
+    let run = StrategyTestHarness::new(DipBuyer::default())
+        .add_price_sequence("BTC/USD", vec![105.0, 98.0, 95.0, 112.0, 97.0])
+        .run();
+
+    // Bought once on the dip, not again while held, sold on the rally and bought back
+    let signals: Vec<(usize, TradeDirection)> = run.fills().iter()
+        .map(|(tick, signal, _)| (*tick, signal.direction))
+        .collect();
+    assert_eq!(signals, vec![(1, TradeDirection::Buy), (3, TradeDirection::Sell), (4, TradeDirection::Buy)]);
+    assert_eq!(run.fills()[1].2, 112.0);
+}
+
+#[test]
+fn test_symbols_without_a_sequence_hold_their_price() {
+    let run = StrategyTestHarness::new(DipBuyer::default())
+        .add_symbol("ETH/USD", 90.0)
+        .add_symbol("BTC/USD", 100.0)
+        .add_price_sequence("BTC/USD", vec![101.0, 99.0, 99.0])
+        .with_tick_interval(Duration::hours(1))
+        .run();
+
+    assert_eq!(run.ticks(), 3);
+    let assets: Vec<&str> = run.signals_at(0).iter().map(|signal| signal.asset.as_str()).collect();
+    assert_eq!(assets, vec!["ETH/USD"]);
+    assert_eq!(run.signals_at(1)[0].asset, "BTC/USD");
+    assert!(run.signals_at(2).is_empty());
+    assert_eq!(run.result_at(2).unwrap().timestamp - run.result_at(0).unwrap().timestamp, Duration::hours(2));
+}
+
+#[test]
+fn test_run_without_sequences_has_no_ticks() {
+    let run = StrategyTestHarness::new(DipBuyer::default()).add_symbol("BTC/USD", 90.0).run();
+    assert_eq!(run.ticks(), 0);
+    assert_eq!(run.total_signals(), 0);
+}
@@ -0,0 +1,143 @@
+use arb_platform::order::{Order, OrderManager, OrderStatus};
+use arb_platform::strategy::{
+    hot_swap_shared_strategy, HotSwapTransition, MomentumStrategy, StatisticalArbitrageStrategy, StrategyManager, StrategyState,
+};
+
+use crate::helpers::mock_exchange::MockExchange;
+use crate::helpers::orders::test_order;
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+const OLD: &str = "Statistical Arbitrage";
+const NEW: &str = "Momentum";
+
+fn create_order(strategy_id: &str) -> Order {
+    Order {
+        exchange: "Mock".to_string(),
+        strategy_id: Some(strategy_id.to_string()),
+        ..test_order()
+    }
+}
+
+// The old strategy running and active, with one open order each for it and another strategy
+async fn setup() -> (StrategyManager, Arc<OrderManager>, Uuid, Uuid) {
+    let mut strategies = StrategyManager::new();
+    strategies.register_strategy(Box::new(StatisticalArbitrageStrategy::new()));
+    strategies.register_strategy(Box::new(MomentumStrategy::new()));
+    strategies.start_strategy(OLD).unwrap();
+    strategies.set_active_strategy(OLD).unwrap();
+
+    let orders = OrderManager::new();
+    orders.get_order_router().register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    let old_order = orders.place_order(create_order(OLD)).await.unwrap();
+    let other_order = orders.place_order(create_order("Manual")).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    (strategies, Arc::new(orders), old_order, other_order)
+}
+
+#[tokio::test]
+async fn test_cancel_existing_orders_before_switching() {
+    let (mut strategies, orders, old_order, other_order) = setup().await;
+
+    strategies.hot_swap_strategy(NEW, HotSwapTransition::CancelExistingOrders, &orders).await.unwrap();
+    assert_eq!(strategies.active_strategy(), Some(NEW));
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(orders.get_order(old_order).await.unwrap().status, OrderStatus::Cancelled);
+    assert_eq!(orders.get_order(other_order).await.unwrap().status, OrderStatus::Submitted);
+    assert!(orders.get_open_orders_by_strategy(OLD).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_keep_existing_orders() {
+    let (mut strategies, orders, old_order, _) = setup().await;
+
+    strategies.hot_swap_strategy(NEW, HotSwapTransition::KeepExistingOrders, &orders).await.unwrap();
+    assert_eq!(strategies.active_strategy(), Some(NEW));
+    assert_eq!(strategies.get_strategy_state(OLD), Some(StrategyState::Running));
+
+    let open = orders.get_open_orders_by_strategy(OLD).await;
+    assert_eq!(open.len(), 1);
+    assert_eq!(open[0].id, old_order);
+}
+
+#[tokio::test]
+async fn test_wait_for_fill_switches_once_orders_finish() {
+    let (mut strategies, orders, old_order, _) = setup().await;
+
+    let filling = orders.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        filling.update_order_status(old_order, OrderStatus::Filled).await;
+    });
+
+    strategies.hot_swap_strategy(NEW, HotSwapTransition::WaitForFill, &orders).await.unwrap();
+    assert_eq!(strategies.active_strategy(), Some(NEW));
+    assert_eq!(strategies.get_strategy_state(OLD), Some(StrategyState::Paused));
+    assert_eq!(orders.get_order(old_order).await.unwrap().status, OrderStatus::Filled);
+}
+
+#[tokio::test]
+async fn test_wait_for_fill_times_out_and_resumes_old_strategy() {
+    let (mut strategies, orders, _, _) = setup().await;
+    strategies.set_hot_swap_timeout(Duration::from_millis(200));
+
+    let err = strategies.hot_swap_strategy(NEW, HotSwapTransition::WaitForFill, &orders).await.unwrap_err();
+    assert!(err.to_string().contains("still open"), "unexpected error: {}", err);
+    assert_eq!(strategies.active_strategy(), Some(OLD));
+    assert_eq!(strategies.get_strategy_state(OLD), Some(StrategyState::Running));
+}
+
+#[tokio::test]
+async fn test_unknown_strategy_is_rejected() {
+    let (mut strategies, orders, old_order, _) = setup().await;
+
+    assert!(strategies.hot_swap_strategy("Missing", HotSwapTransition::CancelExistingOrders, &orders).await.is_err());
+    assert_eq!(strategies.active_strategy(), Some(OLD));
+    assert_eq!(orders.get_order(old_order).await.unwrap().status, OrderStatus::Submitted);
+}
+
+#[tokio::test]
+async fn test_shared_wait_for_fill_leaves_the_manager_unlocked_while_waiting() {
+    let (strategies, orders, old_order, _) = setup().await;
+    let strategies = Arc::new(RwLock::new(strategies));
+
+    let swapping = (strategies.clone(), orders.clone());
+    let swap = tokio::spawn(async move {
+        hot_swap_shared_strategy(&swapping.0, NEW, HotSwapTransition::WaitForFill, &swapping.1).await
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // Paused and still active while its order works, and readable meanwhile
+    let manager = tokio::time::timeout(Duration::from_millis(50), strategies.read()).await
+        .expect("Strategy manager locked during the wait");
+    assert_eq!(manager.get_strategy_state(OLD), Some(StrategyState::Paused));
+    assert_eq!(manager.active_strategy(), Some(OLD));
+    drop(manager);
+
+    orders.update_order_status(old_order, OrderStatus::Filled).await;
+    swap.await.unwrap().unwrap();
+    assert_eq!(strategies.read().await.active_strategy(), Some(NEW));
+}
+
+#[tokio::test]
+async fn test_shared_wait_for_fill_does_not_override_a_swap_made_meanwhile() {
+    let (mut strategies, orders, _, _) = setup().await;
+    strategies.set_hot_swap_timeout(Duration::from_millis(300));
+    let strategies = Arc::new(RwLock::new(strategies));
+
+    let swapping = (strategies.clone(), orders.clone());
+    let swap = tokio::spawn(async move {
+        hot_swap_shared_strategy(&swapping.0, NEW, HotSwapTransition::WaitForFill, &swapping.1).await
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    strategies.write().await.set_active_strategy(NEW).unwrap();
+    orders.cancel_all_orders_for_strategy(OLD, "Manual swap").await.unwrap();
+
+    let err = swap.await.unwrap().unwrap_err();
+    assert!(err.to_string().contains("changed"), "unexpected error: {}", err);
+    assert_eq!(strategies.read().await.active_strategy(), Some(NEW));
+}
@@ -0,0 +1,169 @@
+use arb_platform::market_data::{MarketEvent, SentimentBuffer};
+use arb_platform::strategy::{
+    AssetData, AssetType, InformationArbitrageStrategy, MarketData, Strategy, StrategyParams, TradeDirection
+};
+use arb_platform::models::Price;
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn market_data_at(timestamp: DateTime<Utc>) -> MarketData {
+    let mut asset_data = HashMap::new();
+    for (symbol, price) in [("AAPL", 200.0), ("TSLA", 250.0)] {
+        asset_data.insert(symbol.to_string(), AssetData {
+            symbol: symbol.to_string(),
+            asset_type: AssetType::Stock,
+            price: Price::from(price),
+            volume: 1_000_000.0,
+            bid: Price::from(price - 0.05),
+            ask: Price::from(price + 0.05),
+            tick_size: None,
+            lot_size: None,
+            exchange: "NASDAQ".to_string(),
+            last_update: timestamp,
+        });
+    }
+    MarketData { timestamp, asset_data }
+}
+
+fn news(symbol: &str, source: &str, sentiment: f64, timestamp: DateTime<Utc>) -> MarketEvent {
+    MarketEvent::NewsItem {
+        headline: format!("{} news", symbol),
+        body: None,
+        symbols: vec![symbol.to_string()],
+        source: source.to_string(),
+        url: None,
+        sentiment: Some(sentiment),
+        timestamp,
+    }
+}
+
+fn params(entries: &[(&str, serde_json::Value)]) -> StrategyParams {
+    StrategyParams {
+        params: entries.iter().map(|(k, v)| (k.to_string(), v.clone())).collect(),
+    }
+}
+
+#[test]
+fn test_positive_news_burst_emits_buy() {
+    let buffer = SentimentBuffer::default();
+    let strategy = InformationArbitrageStrategy::new(buffer.clone());
+    let now = Utc::now();
+    
+    for i in 0..5 {
+        assert!(buffer.record_event(&news("AAPL", "Reuters", 0.8, now - Duration::seconds(i * 10))));
+    }
+    
+    let result = strategy.evaluate(&market_data_at(now));
+    assert_eq!(result.signals.len(), 1);
+    
+    let signal = &result.signals[0];
+    assert_eq!(signal.asset, "AAPL");
+    assert_eq!(signal.direction, TradeDirection::Buy);
+    assert!((signal.quantity - 100000.0 / 200.0).abs() < 1e-9);
+    assert!(signal.limit_price.unwrap() > 200.0);
+    assert!(result.confidence > 0.0 && result.confidence <= 0.95);
+    assert!(result.expected_profit > 0.0);
+}
+
+#[test]
+fn test_confidence_scales_with_corroborating_items() {
+    let now = Utc::now();
+    let confidence_for = |count: i64| {
+        let buffer = SentimentBuffer::default();
+        let strategy = InformationArbitrageStrategy::new(buffer.clone());
+        for i in 0..count {
+            buffer.record_event(&news("AAPL", "Reuters", 0.8, now - Duration::seconds(i)));
+        }
+        strategy.evaluate(&market_data_at(now)).confidence
+    };
+    
+    let one = confidence_for(1);
+    let three = confidence_for(3);
+    let ten = confidence_for(10);
+    assert!(one > 0.0);
+    assert!(three > one);
+    assert!(ten > three);
+}
+
+#[test]
+fn test_negative_sentiment_emits_sell() {
+    let buffer = SentimentBuffer::default();
+    let strategy = InformationArbitrageStrategy::new(buffer.clone());
+    let now = Utc::now();
+    
+    buffer.record_event(&MarketEvent::SocialMediaPost {
+        text: "TSLA recall".to_string(),
+        symbols: vec!["TSLA".to_string()],
+        source: "Twitter".to_string(),
+        url: None,
+        user: "analyst".to_string(),
+        followers: Some(50_000),
+        sentiment: Some(-0.9),
+        timestamp: now,
+    });
+    
+    let result = strategy.evaluate(&market_data_at(now));
+    assert_eq!(result.signals.len(), 1);
+    assert_eq!(result.signals[0].asset, "TSLA");
+    assert_eq!(result.signals[0].direction, TradeDirection::Sell);
+}
+
+#[test]
+fn test_weak_or_stale_sentiment_is_ignored() {
+    let buffer = SentimentBuffer::default();
+    let strategy = InformationArbitrageStrategy::new(buffer.clone());
+    let now = Utc::now();
+    
+    // Below threshold
+    buffer.record_event(&news("AAPL", "Reuters", 0.3, now));
+    // Outside the buffer window
+    buffer.record_event(&news("TSLA", "Reuters", 0.9, now - buffer.window() - Duration::seconds(1)));
+    
+    assert!(strategy.evaluate(&market_data_at(now)).signals.is_empty());
+}
+
+#[test]
+fn test_news_sources_filter() {
+    let buffer = SentimentBuffer::default();
+    let mut strategy = InformationArbitrageStrategy::new(buffer.clone());
+    let now = Utc::now();
+    
+    buffer.record_event(&news("AAPL", "RumorMill", 0.9, now));
+    buffer.record_event(&news("AAPL", "RumorMill", 0.9, now));
+    buffer.record_event(&news("AAPL", "Reuters", 0.3, now));
+    assert_eq!(strategy.evaluate(&market_data_at(now)).signals.len(), 1);
+    
+    strategy.update_params(params(&[("news_sources", json!(["reuters", "Bloomberg"]))])).unwrap();
+    assert!(strategy.evaluate(&market_data_at(now)).signals.is_empty());
+}
+
+#[test]
+fn test_update_params_validation() {
+    let mut strategy = InformationArbitrageStrategy::new(SentimentBuffer::default());
+    
+    assert!(strategy.update_params(params(&[("sentiment_threshold", json!(0.7))])).is_ok());
+    assert!(strategy.update_params(params(&[("sentiment_threshold", json!(1.5))])).is_err());
+    assert!(strategy.update_params(params(&[("max_position_size", json!(-1.0))])).is_err());
+    assert!(strategy.update_params(params(&[("news_sources", json!("Reuters"))])).is_err());
+    assert!(strategy.update_params(params(&[("unknown", json!(1))])).is_err());
+}
+
+#[tokio::test]
+async fn test_market_data_pipeline_feeds_sentiment_buffer() {
+    let mut manager = arb_platform::market_data::MarketDataManager::new();
+    let buffer = manager.get_sentiment_buffer();
+    manager.start_processing().await.unwrap();
+    
+    let now = Utc::now();
+    manager.get_event_sender().send(news("AAPL", "Reuters", 0.6, now)).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    
+    let observations = buffer.observations("AAPL", now);
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations[0].source, "Reuters");
+    assert_eq!(observations[0].score, 0.6);
+    
+    manager.shutdown().await.unwrap();
+}
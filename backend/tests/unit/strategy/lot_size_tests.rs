@@ -0,0 +1,86 @@
+use arb_platform::strategy::{
+    AssetData, AssetType, MarketData, StatisticalArbitrageStrategy, Strategy, StrategyManager, StrategyParams,
+    StrategyResult, TradeDirection,
+};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+use serde_json::json;
+use std::collections::HashMap;
+
+// AAA and BBB at the given prices, each with an optional lot size
+fn create_market_data(aaa: (f64, Option<f64>), bbb: (f64, Option<f64>)) -> MarketData {
+    let now = Utc::now();
+    let asset_data = [("AAA", aaa), ("BBB", bbb)].into_iter().map(|(symbol, (price, lot_size))| {
+        (symbol.to_string(), AssetData {
+            symbol: symbol.to_string(),
+            asset_type: AssetType::Stock,
+            price: Price::from(price),
+            volume: 1000.0,
+            bid: Price::from(price - 0.01),
+            ask: Price::from(price + 0.01),
+            tick_size: None,
+            lot_size,
+            exchange: "Test".to_string(),
+            last_update: now,
+        })
+    }).collect::<HashMap<_, _>>();
+
+    MarketData { timestamp: now, asset_data }
+}
+
+// A stat-arb manager whose spread window is full, so the next wide spread signals
+fn warmed_up_manager() -> StrategyManager {
+    let mut strategy = StatisticalArbitrageStrategy::new();
+    strategy.update_params(StrategyParams {
+        params: HashMap::from([
+            ("lookback_period".to_string(), json!(4)),
+            ("pairs".to_string(), json!([["AAA", "BBB"]])),
+        ]),
+    }).unwrap();
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(strategy));
+    for price in [100.0, 101.0, 99.0, 100.0] {
+        manager.evaluate_one("Statistical Arbitrage", &create_market_data((price, None), (100.0, None))).unwrap();
+    }
+    manager
+}
+
+fn quantities(result: &StrategyResult) -> Vec<(String, TradeDirection, f64)> {
+    let mut quantities: Vec<_> = result.signals.iter().map(|s| (s.asset.clone(), s.direction, s.quantity)).collect();
+    quantities.sort_by(|a, b| a.0.cmp(&b.0));
+    quantities
+}
+
+#[test]
+fn test_signal_quantities_are_rounded_down_to_the_lot_size() {
+    // Half of the 100000 position size: 312.5 of AAA at 160 and 500 of BBB at 100
+    let unrounded = warmed_up_manager()
+        .evaluate_one("Statistical Arbitrage", &create_market_data((160.0, None), (100.0, None)))
+        .unwrap();
+    assert_eq!(quantities(&unrounded), vec![
+        ("AAA".to_string(), TradeDirection::Sell, 312.5),
+        ("BBB".to_string(), TradeDirection::Buy, 500.0),
+    ]);
+
+    let rounded = warmed_up_manager()
+        .evaluate_one("Statistical Arbitrage", &create_market_data((160.0, Some(100.0)), (100.0, Some(30.0))))
+        .unwrap();
+    assert_eq!(quantities(&rounded), vec![
+        ("AAA".to_string(), TradeDirection::Sell, 300.0),
+        ("BBB".to_string(), TradeDirection::Buy, 480.0),
+    ]);
+    for (signal, lot_size) in rounded.signals.iter().map(|s| (s, if s.asset == "AAA" { 100.0 } else { 30.0 })) {
+        let lots = signal.quantity / lot_size;
+        assert_eq!(lots, lots.round(), "{} is not a multiple of {}", signal.quantity, lot_size);
+    }
+}
+
+#[test]
+fn test_signal_rounding_to_zero_is_dropped() {
+    let result = warmed_up_manager()
+        .evaluate_one("Statistical Arbitrage", &create_market_data((160.0, Some(1000.0)), (100.0, Some(100.0))))
+        .unwrap();
+
+    assert_eq!(quantities(&result), vec![("BBB".to_string(), TradeDirection::Buy, 500.0)]);
+}
@@ -0,0 +1,96 @@
+use arb_platform::market_data::{OrderBook, OrderBooks};
+use arb_platform::strategy::{MarketData, MarketMakingStrategy, Strategy, StrategyParams, TradeDirection};
+
+use chrono::Utc;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+fn add_book(books: &OrderBooks, symbol: &str, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+    let mut book = OrderBook::new(symbol, "Test");
+    book.apply_update(bids, asks, Utc::now());
+    books.write().unwrap().insert(symbol.to_string(), Arc::new(RwLock::new(book)));
+}
+
+fn empty_market_data() -> MarketData {
+    MarketData {
+        timestamp: Utc::now(),
+        asset_data: HashMap::new(),
+    }
+}
+
+fn quote(strategy: &MarketMakingStrategy, symbol: &str, direction: TradeDirection) -> f64 {
+    strategy.evaluate(&empty_market_data()).signals.iter()
+        .find(|s| s.asset == symbol && s.direction == direction)
+        .and_then(|s| s.limit_price)
+        .unwrap()
+}
+
+#[test]
+fn test_quotes_balanced_book_at_its_spread() {
+    let books = OrderBooks::default();
+    add_book(&books, "BTC/USD", &[(99.0, 5.0)], &[(101.0, 5.0)]);
+    let strategy = MarketMakingStrategy::new(books);
+    
+    let result = strategy.evaluate(&empty_market_data());
+    
+    assert_eq!(result.signals.len(), 2);
+    assert_eq!(quote(&strategy, "BTC/USD", TradeDirection::Buy), 99.0);
+    assert_eq!(quote(&strategy, "BTC/USD", TradeDirection::Sell), 101.0);
+    assert!((result.confidence - 0.8).abs() < 1e-9);
+}
+
+#[test]
+fn test_tight_book_is_quoted_at_minimum_spread() {
+    let books = OrderBooks::default();
+    add_book(&books, "BTC/USD", &[(99.99, 5.0)], &[(100.01, 5.0)]);
+    let strategy = MarketMakingStrategy::new(books);
+    
+    // Default minimum of 10bps around a 100.0 mid
+    assert!((quote(&strategy, "BTC/USD", TradeDirection::Buy) - 99.95).abs() < 1e-9);
+    assert!((quote(&strategy, "BTC/USD", TradeDirection::Sell) - 100.05).abs() < 1e-9);
+}
+
+#[test]
+fn test_bid_heavy_book_skews_quotes_up() {
+    let books = OrderBooks::default();
+    add_book(&books, "BTC/USD", &[(99.0, 30.0), (98.0, 30.0)], &[(101.0, 20.0)]);
+    let strategy = MarketMakingStrategy::new(books);
+    
+    // Imbalance of 0.5 shifts both quotes by a quarter of the half-spread
+    assert!((quote(&strategy, "BTC/USD", TradeDirection::Buy) - 99.25).abs() < 1e-9);
+    assert!((quote(&strategy, "BTC/USD", TradeDirection::Sell) - 101.25).abs() < 1e-9);
+}
+
+#[test]
+fn test_one_sided_books_and_unlisted_symbols_are_not_quoted() {
+    let books = OrderBooks::default();
+    add_book(&books, "BTC/USD", &[(99.0, 5.0)], &[(101.0, 5.0)]);
+    add_book(&books, "ETH/USD", &[(1999.0, 5.0)], &[(2001.0, 5.0)]);
+    add_book(&books, "SOL/USD", &[(20.0, 5.0)], &[]);
+    let mut strategy = MarketMakingStrategy::new(books);
+    
+    let result = strategy.evaluate(&empty_market_data());
+    assert_eq!(result.signals.len(), 4);
+    
+    let mut params = HashMap::new();
+    params.insert("symbols".to_string(), json!(["ETH/USD", "SOL/USD"]));
+    strategy.update_params(StrategyParams { params }).unwrap();
+    
+    let result = strategy.evaluate(&empty_market_data());
+    assert_eq!(result.signals.len(), 2);
+    assert!(result.signals.iter().all(|s| s.asset == "ETH/USD"));
+}
+
+#[test]
+fn test_invalid_params_are_rejected() {
+    let mut strategy = MarketMakingStrategy::new(OrderBooks::default());
+    
+    let mut params = HashMap::new();
+    params.insert("skew_factor".to_string(), json!(1.5));
+    assert!(strategy.update_params(StrategyParams { params }).is_err());
+    
+    let mut params = HashMap::new();
+    params.insert("spread".to_string(), json!(1.0));
+    assert!(strategy.update_params(StrategyParams { params }).is_err());
+}
@@ -1,2 +1,16 @@
 // Strategy module tests
 pub mod mod_tests;
+pub mod information_arbitrage_tests;
+pub mod selection_tests;
+pub mod regime_tests;
+pub mod staleness_tests;
+pub mod market_making_tests;
+pub mod params_tests;
+pub mod momentum_tests;
+pub mod hot_swap_tests;
+pub mod priority_tests;
+pub mod filter_tests;
+pub mod scheduler_tests;
+pub mod harness_tests;
+pub mod warmup_tests;
+pub mod lot_size_tests;
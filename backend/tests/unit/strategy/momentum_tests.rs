@@ -0,0 +1,72 @@
+use arb_platform::strategy::{MomentumStrategy, Strategy, StrategyParams, TradeDirection};
+
+use crate::helpers::fixed_side_strategy::daily_history;
+
+use serde_json::json;
+
+fn strategy(fast: u64, slow: u64, signal: u64) -> MomentumStrategy {
+    let mut strategy = MomentumStrategy::new();
+    strategy.update_params(StrategyParams {
+        params: [
+            ("fast_period".to_string(), json!(fast)),
+            ("slow_period".to_string(), json!(slow)),
+            ("signal_period".to_string(), json!(signal)),
+            ("max_position_usd".to_string(), json!(1000.0)),
+        ].into_iter().collect(),
+    }).unwrap();
+    strategy
+}
+
+#[test]
+fn test_signals_follow_ema_crossovers() {
+    let strategy = strategy(2, 4, 2);
+    let prices = [100.0, 101.0, 102.0, 103.0, 104.0, 105.0, 103.0, 100.0, 97.0, 96.0, 98.0, 102.0, 106.0, 108.0];
+    
+    // Worked by hand with k = 2/3 (fast) and 2/5 (slow):
+    //   day 6: fast 103.50 > slow 103.37; day 7: fast 101.17 < slow 102.02, a cross below
+    //   day 10: fast 97.60 < slow 98.24; day 11: fast 100.53 > slow 99.75, a cross above
+    let mut crossovers = Vec::new();
+    for (day, market_data) in daily_history(&prices).iter().enumerate() {
+        let result = strategy.evaluate(market_data);
+        for signal in &result.signals {
+            assert_eq!(signal.asset, "BTC/USD");
+            assert!((signal.quantity - 1000.0 / prices[day]).abs() < 1e-9);
+            assert!(result.confidence > 0.0 && result.confidence <= 1.0);
+            crossovers.push((day, signal.direction));
+        }
+    }
+    
+    assert_eq!(crossovers, vec![(7, TradeDirection::Sell), (11, TradeDirection::Buy)]);
+}
+
+#[test]
+fn test_confidence_scales_with_gap_between_averages() {
+    let small = strategy(2, 4, 2);
+    let large = strategy(2, 4, 2);
+    let mut small_result = None;
+    let mut large_result = None;
+    for market_data in daily_history(&[100.0, 100.0, 100.0, 100.0, 100.0, 99.0]) {
+        small_result = Some(small.evaluate(&market_data));
+    }
+    for market_data in daily_history(&[100.0, 100.0, 100.0, 100.0, 100.0, 90.0]) {
+        large_result = Some(large.evaluate(&market_data));
+    }
+    
+    let (small_result, large_result) = (small_result.unwrap(), large_result.unwrap());
+    assert_eq!(small_result.signals[0].direction, TradeDirection::Sell);
+    assert_eq!(large_result.signals[0].direction, TradeDirection::Sell);
+    assert!(small_result.confidence < large_result.confidence);
+}
+
+#[test]
+fn test_rejects_fast_period_not_shorter_than_slow() {
+    let mut strategy = MomentumStrategy::new();
+    let params = |fast: u64, slow: u64| StrategyParams {
+        params: [("fast_period".to_string(), json!(fast)), ("slow_period".to_string(), json!(slow))].into_iter().collect(),
+    };
+    
+    assert!(strategy.update_params(params(26, 12)).is_err());
+    assert!(strategy.update_params(params(10, 10)).is_err());
+    assert!(strategy.update_params(params(0, 10)).is_err());
+    assert!(strategy.update_params(params(5, 10)).is_ok());
+}
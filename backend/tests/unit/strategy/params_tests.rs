@@ -0,0 +1,101 @@
+use arb_platform::error::TradingError;
+use arb_platform::market_data::{OrderBook, OrderBooks};
+use arb_platform::strategy::{
+    validate_params, MarketData, MarketMakingStrategy, ParamError, ParamSpec, ParamType,
+    StatisticalArbitrageStrategy, Strategy, StrategyManager, StrategyParams,
+};
+
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+fn params(entries: &[(&str, Value)]) -> StrategyParams {
+    StrategyParams {
+        params: entries.iter().map(|(k, v)| (k.to_string(), v.clone())).collect(),
+    }
+}
+
+#[test]
+fn test_wrong_type_is_reported_by_name() {
+    let mut strategy = StatisticalArbitrageStrategy::new();
+    let error = strategy.update_params(params(&[("z_score_threshold", json!("high"))])).unwrap_err();
+    assert_eq!(error, TradingError::Validation("z_score_threshold expected number, got string".to_string()));
+
+    let error = validate_params(&strategy.param_schema(), &params(&[("lookback_period", json!(2.5))])).unwrap_err();
+    assert_eq!(error, ParamError::WrongType {
+        name: "lookback_period".to_string(),
+        expected: "integer",
+        got: "number",
+    });
+}
+
+#[test]
+fn test_out_of_range_and_unknown_params_are_rejected() {
+    let schema = StatisticalArbitrageStrategy::new().param_schema();
+
+    let error = validate_params(&schema, &params(&[("z_score_threshold", json!(-1.0))])).unwrap_err();
+    assert_eq!(error.to_string(), "z_score_threshold must be positive, got -1");
+
+    let error = validate_params(&schema, &params(&[("correlation_threshold", json!(1.5))])).unwrap_err();
+    assert_eq!(error.to_string(), "correlation_threshold must be between 0 and 1, got 1.5");
+
+    let error = validate_params(&schema, &params(&[("entry_threshold", json!(1.0))])).unwrap_err();
+    assert_eq!(error, ParamError::UnknownParameter("entry_threshold".to_string()));
+}
+
+#[test]
+fn test_malformed_pairs_are_rejected() {
+    let schema = StatisticalArbitrageStrategy::new().param_schema();
+    assert!(validate_params(&schema, &params(&[("pairs", json!([["BTC/USD", "ETH/USD"]]))])).is_ok());
+
+    let error = validate_params(&schema, &params(&[("pairs", json!([["BTC/USD"]]))])).unwrap_err();
+    assert_eq!(error.to_string(), "pairs expected array of [string, string] pairs, got array");
+}
+
+#[test]
+fn test_range_bounds() {
+    let schema = vec![
+        ParamSpec::new("size", ParamType::Number).positive(),
+        ParamSpec::new("spread", ParamType::Number).non_negative().at_most(10.0),
+    ];
+
+    assert!(validate_params(&schema, &params(&[("size", json!(0.0))])).is_err());
+    assert!(validate_params(&schema, &params(&[("spread", json!(0.0))])).is_ok());
+    assert!(validate_params(&schema, &params(&[("spread", json!(10.0))])).is_ok());
+    assert!(validate_params(&schema, &params(&[("spread", json!(10.5))])).is_err());
+}
+
+#[test]
+fn test_failed_update_applies_nothing() {
+    let books = OrderBooks::default();
+    let mut book = OrderBook::new("BTC/USD", "Test");
+    book.apply_update(&[(99.0, 5.0)], &[(101.0, 5.0)], Utc::now());
+    books.write().unwrap().insert("BTC/USD".to_string(), Arc::new(RwLock::new(book)));
+    let mut strategy = MarketMakingStrategy::new(books);
+
+    // The valid quote_size must not be applied alongside the invalid skew_factor
+    let update = params(&[("quote_size", json!(3.0)), ("skew_factor", json!(2.0))]);
+    assert!(strategy.update_params(update).is_err());
+
+    let market_data = MarketData {
+        timestamp: Utc::now(),
+        asset_data: HashMap::new(),
+    };
+    let result = strategy.evaluate(&market_data);
+    assert!(!result.signals.is_empty());
+    assert!(result.signals.iter().all(|s| s.quantity == 1.0));
+}
+
+#[test]
+fn test_manager_validates_without_applying() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(StatisticalArbitrageStrategy::new()));
+
+    let error = manager.validate_params("Statistical Arbitrage", &params(&[("z_score_threshold", json!("high"))])).unwrap_err();
+    assert_eq!(error.to_string(), "z_score_threshold expected number, got string");
+    assert!(manager.validate_params("Statistical Arbitrage", &params(&[("z_score_threshold", json!(3.0))])).is_ok());
+
+    let error = manager.validate_params("Momentum", &params(&[])).unwrap_err();
+    assert_eq!(error, ParamError::UnknownStrategy("Momentum".to_string()));
+}
@@ -0,0 +1,123 @@
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    AssetType, MarketData, PrioritizedSignal, Strategy, StrategyManager, StrategyParams, StrategyResult,
+    TradeDirection, TradeSignal, TimeInForce, DEFAULT_SIGNAL_PRIORITY, URGENT_SIGNAL_PRIORITY
+};
+
+use chrono::Utc;
+use std::collections::{BinaryHeap, HashMap};
+
+fn signal(asset: &str, time_in_force: TimeInForce) -> TradeSignal {
+    TradeSignal {
+        asset: asset.to_string(),
+        direction: TradeDirection::Buy,
+        quantity: 1.0,
+        limit_price: Some(100.0),
+        stop_price: None,
+        time_in_force,
+        priority: time_in_force.default_signal_priority(),
+    }
+}
+
+// Signals the assets it was built with, in that order
+struct FixedSignalsStrategy {
+    name: String,
+    signals: Vec<TradeSignal>,
+}
+
+impl Strategy for FixedSignalsStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Signals a fixed list"
+    }
+
+    fn asset_types(&self) -> Vec<AssetType> {
+        vec![AssetType::Crypto]
+    }
+
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        StrategyResult {
+            signals: self.signals.clone(),
+            confidence: 1.0,
+            expected_profit: 0.0,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+}
+
+fn drain(mut queue: BinaryHeap<PrioritizedSignal>) -> Vec<String> {
+    let mut assets = Vec::new();
+    while let Some(queued) = queue.pop() {
+        assets.push(queued.signal.asset);
+    }
+    assets
+}
+
+#[test]
+fn test_default_priority_follows_time_in_force() {
+    assert_eq!(TimeInForce::ImmediateOrCancel.default_signal_priority(), URGENT_SIGNAL_PRIORITY);
+    assert_eq!(TimeInForce::FillOrKill.default_signal_priority(), URGENT_SIGNAL_PRIORITY);
+    assert_eq!(TimeInForce::GoodTilCancelled.default_signal_priority(), 128);
+    assert_eq!(TimeInForce::Day.default_signal_priority(), DEFAULT_SIGNAL_PRIORITY);
+    assert_eq!(URGENT_SIGNAL_PRIORITY, 255);
+}
+
+#[test]
+fn test_heap_pops_highest_priority_first_then_oldest() {
+    let mut low = signal("LOW", TimeInForce::GoodTilCancelled);
+    low.priority = 0;
+
+    let mut queue = BinaryHeap::new();
+    queue.push(PrioritizedSignal::new("A", low, 0));
+    queue.push(PrioritizedSignal::new("A", signal("GTC-1", TimeInForce::GoodTilCancelled), 1));
+    queue.push(PrioritizedSignal::new("A", signal("IOC-1", TimeInForce::ImmediateOrCancel), 2));
+    queue.push(PrioritizedSignal::new("A", signal("GTC-2", TimeInForce::GoodTilCancelled), 3));
+    queue.push(PrioritizedSignal::new("A", signal("IOC-2", TimeInForce::ImmediateOrCancel), 4));
+
+    assert_eq!(drain(queue), vec!["IOC-1", "IOC-2", "GTC-1", "GTC-2", "LOW"]);
+}
+
+#[test]
+fn test_missing_priority_deserializes_to_default() {
+    let signal: TradeSignal = serde_json::from_value(serde_json::json!({
+        "asset": "BTC/USD",
+        "direction": "buy",
+        "quantity": 1.0,
+        "limit_price": null,
+        "stop_price": null,
+        "time_in_force": "gtc",
+    })).unwrap();
+
+    assert_eq!(signal.priority, DEFAULT_SIGNAL_PRIORITY);
+}
+
+#[test]
+fn test_aggregated_signals_come_out_by_priority_across_strategies() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(FixedSignalsStrategy {
+        name: "Resting".to_string(),
+        signals: vec![
+            signal("ETH/USD", TimeInForce::GoodTilCancelled),
+            signal("SOL/USD", TimeInForce::GoodTilCancelled),
+        ],
+    }));
+    manager.register_strategy(Box::new(FixedSignalsStrategy {
+        name: "Urgent".to_string(),
+        signals: vec![signal("BTC/USD", TimeInForce::ImmediateOrCancel)],
+    }));
+
+    let market_data = MarketData { timestamp: Utc::now(), asset_data: HashMap::new() };
+    let queue = manager.get_aggregated_signals(&market_data);
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.peek().unwrap().strategy, "Urgent");
+
+    assert_eq!(drain(queue), vec!["BTC/USD", "ETH/USD", "SOL/USD"]);
+}
@@ -0,0 +1,176 @@
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    MarketRegime, RegimeDetector, Strategy, StrategyManager, StrategyParams, StrategyResult,
+    MarketData, AssetData, AssetType, MIN_REGIME_OBSERVATIONS
+};
+use arb_platform::strategy::regime::hurst_exponent;
+use arb_platform::models::Price;
+
+use chrono::{Duration, Utc};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+
+// Returns where each bar carries over `persistence` of the previous one
+fn autoregressive_returns(persistence: f64, scale: f64, count: usize) -> Vec<f64> {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut previous = 0.0;
+    
+    (0..count).map(|_| {
+        let noise: f64 = rng.gen_range(-1.0..1.0) * scale;
+        previous = persistence * previous + noise;
+        previous
+    }).collect()
+}
+
+struct RegimeStrategy {
+    name: String,
+    regimes: Vec<MarketRegime>,
+}
+
+impl RegimeStrategy {
+    fn new(name: &str, regimes: Vec<MarketRegime>) -> Box<Self> {
+        Box::new(RegimeStrategy { name: name.to_string(), regimes })
+    }
+}
+
+impl Strategy for RegimeStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn description(&self) -> &str {
+        "Strategy with a regime preference"
+    }
+    
+    fn asset_types(&self) -> Vec<AssetType> {
+        vec![AssetType::Crypto]
+    }
+    
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        StrategyResult {
+            signals: vec![],
+            confidence: 0.5,
+            expected_profit: 1.0,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+    
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+    
+    fn suitable_regimes(&self) -> Vec<MarketRegime> {
+        self.regimes.clone()
+    }
+}
+
+fn create_manager() -> StrategyManager {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(RegimeStrategy::new("Any", vec![]));
+    manager.register_strategy(RegimeStrategy::new("Momentum", vec![MarketRegime::Trending { strength: 0.0 }]));
+    manager.register_strategy(RegimeStrategy::new("Pairs", vec![MarketRegime::MeanReverting { half_life_bars: 0.0 }]));
+    manager
+}
+
+fn create_market_data(price: f64, offset_secs: i64) -> MarketData {
+    let timestamp = Utc::now() + Duration::seconds(offset_secs);
+    let mut asset_data = HashMap::new();
+    asset_data.insert("BTC/USD".to_string(), AssetData {
+        symbol: "BTC/USD".to_string(),
+        asset_type: AssetType::Crypto,
+        price: Price::from(price),
+        volume: 100.0,
+        bid: Price::from(price - 1.0),
+        ask: Price::from(price + 1.0),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Test".to_string(),
+        last_update: timestamp,
+    });
+    
+    MarketData {
+        timestamp,
+        asset_data,
+    }
+}
+
+#[test]
+fn test_hurst_exponent_separates_persistent_and_anti_persistent_returns() {
+    let persistent = hurst_exponent(&autoregressive_returns(0.8, 0.005, 512)).unwrap();
+    let anti_persistent = hurst_exponent(&autoregressive_returns(-0.8, 0.005, 512)).unwrap();
+    
+    assert!(persistent > 0.5, "persistent H = {}", persistent);
+    assert!(anti_persistent < 0.5, "anti-persistent H = {}", anti_persistent);
+    assert!(persistent - anti_persistent > 0.2);
+    assert!(hurst_exponent(&[0.01; 16]).is_none());
+}
+
+#[test]
+fn test_detects_each_regime() {
+    let detector = RegimeDetector::default();
+    
+    match detector.detect(&autoregressive_returns(0.8, 0.005, 512)) {
+        MarketRegime::Trending { strength } => assert!(strength > 0.0 && strength <= 1.0),
+        other => panic!("expected a trending regime, got {:?}", other),
+    }
+    
+    match detector.detect(&autoregressive_returns(-0.8, 0.005, 512)) {
+        MarketRegime::MeanReverting { half_life_bars } => assert!(half_life_bars > 0.0),
+        other => panic!("expected a mean-reverting regime, got {:?}", other),
+    }
+    
+    assert_eq!(detector.detect(&autoregressive_returns(0.8, 0.2, 512)), MarketRegime::Volatile);
+}
+
+#[test]
+fn test_regime_kinds_ignore_measurements() {
+    let trending = MarketRegime::Trending { strength: 0.2 };
+    
+    assert!(trending.same_kind(&MarketRegime::Trending { strength: 0.9 }));
+    assert!(!trending.same_kind(&MarketRegime::MeanReverting { half_life_bars: 0.2 }));
+    assert!(!trending.same_kind(&MarketRegime::Volatile));
+}
+
+#[test]
+fn test_all_strategies_run_until_regime_is_known() {
+    let manager = create_manager();
+    
+    let results = manager.evaluate_strategies(&create_market_data(100.0, 0));
+    
+    assert_eq!(manager.current_regime(), None);
+    assert_eq!(results.len(), 3);
+}
+
+#[test]
+fn test_evaluation_activates_strategies_for_detected_regime() {
+    let manager = create_manager();
+    for r in autoregressive_returns(0.8, 0.005, 256) {
+        manager.record_market_return(r);
+    }
+    
+    let results = manager.evaluate_strategies(&create_market_data(100.0, 0));
+    
+    assert!(matches!(manager.current_regime(), Some(MarketRegime::Trending { .. })));
+    assert!(results.contains_key("Any"));
+    assert!(results.contains_key("Momentum"));
+    assert!(!results.contains_key("Pairs"));
+}
+
+#[test]
+fn test_regime_is_detected_from_market_snapshots() {
+    let manager = create_manager();
+    
+    // Prices that swing back and forth around a level
+    let mut price = 100.0;
+    for (i, r) in autoregressive_returns(-0.8, 0.005, MIN_REGIME_OBSERVATIONS * 4).into_iter().enumerate() {
+        manager.evaluate_strategies(&create_market_data(price, i as i64));
+        price *= 1.0 + r;
+    }
+    let results = manager.evaluate_strategies(&create_market_data(price, 10_000));
+    
+    assert!(matches!(manager.current_regime(), Some(MarketRegime::MeanReverting { .. })));
+    assert!(results.contains_key("Pairs"));
+    assert!(!results.contains_key("Momentum"));
+}
@@ -0,0 +1,152 @@
+use arb_platform::market_data::MarketDataManager;
+use arb_platform::order::OrderManager;
+use arb_platform::strategy::{StrategyEvaluationScheduler, StrategyManager, StrategyState, TradeDirection, DEFAULT_EVALUATION_INTERVAL};
+
+use crate::helpers::fixed_side_strategy::FixedSideStrategy;
+use crate::helpers::mock_exchange::MockExchange;
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+struct Managers {
+    strategy_manager: Arc<RwLock<StrategyManager>>,
+    market_data_manager: Arc<RwLock<MarketDataManager>>,
+    order_manager: Arc<RwLock<OrderManager>>,
+}
+
+// Fixed Side as the active strategy, with orders routed to a mock exchange
+async fn managers(confidence: f64) -> Managers {
+    let mut strategy_manager = StrategyManager::new();
+    strategy_manager.register_strategy(Box::new(FixedSideStrategy::with_confidence(confidence)));
+    strategy_manager.set_active_strategy("Fixed Side").unwrap();
+
+    let mut order_manager = OrderManager::new();
+    order_manager.set_signal_submission_delay(Duration::ZERO);
+    let router = order_manager.get_order_router();
+    router.register_exchange(Box::new(MockExchange::new("Mock"))).await.unwrap();
+    router.set_primary_exchange("BTC/USD", "Mock").await.unwrap();
+
+    Managers {
+        strategy_manager: Arc::new(RwLock::new(strategy_manager)),
+        market_data_manager: Arc::new(RwLock::new(MarketDataManager::new())),
+        order_manager: Arc::new(RwLock::new(order_manager)),
+    }
+}
+
+fn scheduler(managers: &Managers, interval: Duration) -> StrategyEvaluationScheduler {
+    StrategyEvaluationScheduler::new(
+        managers.strategy_manager.clone(),
+        managers.market_data_manager.clone(),
+        managers.order_manager.clone(),
+        interval,
+    )
+}
+
+async fn order_count(managers: &Managers) -> usize {
+    managers.order_manager.read().await.get_open_orders_by_strategy("Fixed Side").await.len()
+}
+
+#[tokio::test]
+async fn test_scheduler_places_signals_until_stopped() {
+    let managers = managers(0.9).await;
+    let mut scheduler = scheduler(&managers, Duration::from_millis(20)).with_min_confidence(0.5);
+    assert!(!scheduler.is_running());
+
+    scheduler.start().unwrap();
+    assert!(scheduler.is_running());
+    assert!(scheduler.start().is_err());
+    tokio::time::sleep(Duration::from_millis(110)).await;
+    scheduler.stop().await.unwrap();
+    assert!(!scheduler.is_running());
+
+    let metrics = scheduler.metrics();
+    assert!(metrics.evaluations >= 3, "{:?}", metrics);
+    assert_eq!(metrics.signals_generated, metrics.evaluations);
+    assert_eq!(metrics.signals_placed, metrics.evaluations);
+    assert!(metrics.evaluations_per_second > 0.0);
+    assert!((metrics.signals_generated_per_minute - metrics.evaluations_per_second * 60.0).abs() < 1e-6);
+
+    // Nothing more is placed once stopped
+    let placed = order_count(&managers).await;
+    assert_eq!(placed as u64, metrics.signals_placed);
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(order_count(&managers).await, placed);
+}
+
+#[tokio::test]
+async fn test_results_below_min_confidence_are_not_placed() {
+    let managers = managers(0.3).await;
+    let scheduler = scheduler(&managers, DEFAULT_EVALUATION_INTERVAL).with_min_confidence(0.5);
+    assert_eq!(scheduler.min_confidence(), 0.5);
+
+    assert_eq!(scheduler.evaluate_once().await, 0);
+    let metrics = scheduler.metrics();
+    assert_eq!((metrics.evaluations, metrics.signals_generated, metrics.signals_placed), (1, 1, 0));
+    assert_eq!(order_count(&managers).await, 0);
+
+    // Not started, so no rates yet
+    assert_eq!(metrics.evaluations_per_second, 0.0);
+}
+
+#[tokio::test]
+async fn test_paused_or_missing_strategy_places_nothing() {
+    let managers = managers(1.0).await;
+    let scheduler = scheduler(&managers, DEFAULT_EVALUATION_INTERVAL);
+    assert_eq!(scheduler.evaluate_once().await, 1);
+
+    let mut strategy_manager = managers.strategy_manager.write().await;
+    strategy_manager.start_strategy("Fixed Side").unwrap();
+    strategy_manager.pause_strategy("Fixed Side").unwrap();
+    drop(strategy_manager);
+    assert_eq!(scheduler.evaluate_once().await, 0);
+    assert_eq!(scheduler.metrics().evaluations, 2);
+}
+
+#[tokio::test]
+async fn test_closed_positions_feed_strategy_trade_records() {
+    let managers = managers(1.0).await;
+    let scheduler = scheduler(&managers, DEFAULT_EVALUATION_INTERVAL);
+    let positions = managers.order_manager.read().await.get_position_manager();
+    positions.apply_strategy_fill("BTC/USD", Some("Fixed Side"), TradeDirection::Buy, 1.0, 100.0).await;
+    positions.apply_strategy_fill("BTC/USD", Some("Fixed Side"), TradeDirection::Sell, 1.0, 110.0).await;
+    positions.apply_fill("ETH/USD", TradeDirection::Buy, 1.0, 100.0).await;
+    positions.apply_fill("ETH/USD", TradeDirection::Sell, 1.0, 90.0).await;
+
+    scheduler.evaluate_once().await;
+    let stats = managers.strategy_manager.read().await.get_trade_stats("Fixed Side").unwrap();
+    assert_eq!((stats.wins, stats.losses), (1, 0));
+    assert_eq!(stats.total_win, 10.0);
+
+    // Each close is only recorded once
+    scheduler.evaluate_once().await;
+    assert_eq!(managers.strategy_manager.read().await.get_trade_stats("Fixed Side").unwrap().trades(), 1);
+}
+
+#[tokio::test]
+async fn test_equity_from_positions_pauses_strategies_on_drawdown() {
+    let managers = managers(1.0).await;
+    managers.strategy_manager.write().await.start_strategy("Fixed Side").unwrap();
+    let scheduler = scheduler(&managers, DEFAULT_EVALUATION_INTERVAL).with_capital(1000.0);
+    assert_eq!(scheduler.capital(), Some(1000.0));
+    assert_eq!(scheduler.evaluate_once().await, 1);
+    assert_eq!(managers.strategy_manager.read().await.get_drawdown_monitor().current_equity(), 1000.0);
+
+    // A 300 loss is a 30% drawdown, past the default 20% limit
+    let positions = managers.order_manager.read().await.get_position_manager();
+    positions.apply_fill("ETH/USD", TradeDirection::Buy, 1.0, 400.0).await;
+    positions.apply_fill("ETH/USD", TradeDirection::Sell, 1.0, 100.0).await;
+    assert_eq!(scheduler.evaluate_once().await, 0);
+
+    let strategy_manager = managers.strategy_manager.read().await;
+    assert_eq!(strategy_manager.get_drawdown_monitor().current_equity(), 700.0);
+    assert_eq!(strategy_manager.get_strategy_state("Fixed Side"), Some(StrategyState::Paused));
+}
+
+#[tokio::test]
+async fn test_zero_interval_is_refused() {
+    let managers = managers(1.0).await;
+    let mut scheduler = scheduler(&managers, Duration::ZERO);
+    assert!(scheduler.start().is_err());
+    scheduler.stop().await.unwrap();
+}
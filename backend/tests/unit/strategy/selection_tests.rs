@@ -0,0 +1,139 @@
+use arb_platform::models::Price;
+use arb_platform::strategy::{
+    AssetData, AssetType, MarketData, SelectionMode, StrategyManager, StrategyResult, StrategySelector,
+    TradeDirection, TradeSignal, TimeInForce
+};
+
+use chrono::Utc;
+use std::collections::HashMap;
+
+fn create_result(asset: &str, expected_profit: f64, confidence: f64) -> StrategyResult {
+    StrategyResult {
+        signals: vec![TradeSignal {
+            asset: asset.to_string(),
+            direction: TradeDirection::Buy,
+            quantity: 10.0,
+            limit_price: Some(100.0),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            priority: TimeInForce::Day.default_signal_priority(),
+        }],
+        confidence,
+        expected_profit,
+        timestamp: Utc::now(),
+        warming_up: false,
+    }
+}
+
+// A large but barely-better-than-a-coin-flip edge against a smaller, surer one
+fn create_results() -> HashMap<String, StrategyResult> {
+    let mut results = HashMap::new();
+    results.insert("Aggressive".to_string(), create_result("BTC/USD", 1000.0, 0.55));
+    results.insert("Cautious".to_string(), create_result("ETH/USD", 500.0, 0.9));
+    results
+}
+
+#[test]
+fn test_scores_per_mode() {
+    let result = create_result("BTC/USD", 1000.0, 0.75);
+    
+    assert_eq!(StrategySelector::new(SelectionMode::ExpectedProfit).score(&result), 1000.0);
+    assert_eq!(StrategySelector::new(SelectionMode::ConfidenceWeighted).score(&result), 750.0);
+    assert_eq!(StrategySelector::new(SelectionMode::RiskAdjusted).score(&result), 500.0);
+    
+    // Below even odds the risk-adjusted score turns negative
+    let coin_flip = create_result("BTC/USD", 1000.0, 0.4);
+    assert!(StrategySelector::new(SelectionMode::RiskAdjusted).score(&coin_flip) < 0.0);
+}
+
+#[test]
+fn test_higher_confidence_wins_only_when_risk_adjusted() {
+    let results = create_results();
+    let mut manager = StrategyManager::new();
+    
+    assert_eq!(manager.selection_mode(), SelectionMode::ExpectedProfit);
+    assert_eq!(manager.get_best_strategy(&results), Some("Aggressive".to_string()));
+    
+    manager.set_selection_mode(SelectionMode::RiskAdjusted);
+    assert_eq!(manager.get_best_strategy(&results), Some("Cautious".to_string()));
+}
+
+#[test]
+fn test_blend_weights_signals_by_score() {
+    let mut manager = StrategyManager::new();
+    manager.set_selection_mode(SelectionMode::ConfidenceWeighted);
+    
+    // Scores of 550 and 450 split the allocation 55/45
+    let blended = manager.get_blended_signals(&create_results(), 0.5).unwrap();
+    
+    assert_eq!(blended.signals.len(), 2);
+    let quantity = |asset: &str| blended.signals.iter().find(|s| s.asset == asset).unwrap().quantity;
+    assert!((quantity("BTC/USD") - 5.5).abs() < 1e-9);
+    assert!((quantity("ETH/USD") - 4.5).abs() < 1e-9);
+    assert!((blended.expected_profit - (1000.0 * 0.55 + 500.0 * 0.45)).abs() < 1e-9);
+    assert!((blended.confidence - (0.55 * 0.55 + 0.9 * 0.45)).abs() < 1e-9);
+}
+
+#[test]
+fn test_blend_respects_confidence_floor() {
+    let selector = StrategySelector::new(SelectionMode::ExpectedProfit);
+    let results = create_results();
+    
+    let blended = selector.blend(&results, 0.8).unwrap();
+    assert_eq!(blended.signals.len(), 1);
+    assert_eq!(blended.signals[0].asset, "ETH/USD");
+    assert_eq!(blended.signals[0].quantity, 10.0); // Sole strategy keeps its full size
+    
+    assert!(selector.blend(&results, 0.95).is_none());
+}
+
+#[test]
+fn test_blend_skips_non_positive_scores() {
+    let selector = StrategySelector::new(SelectionMode::RiskAdjusted);
+    let mut results = HashMap::new();
+    results.insert("Unsure".to_string(), create_result("BTC/USD", 1000.0, 0.3));
+    
+    assert!(selector.blend(&results, 0.0).is_none());
+    assert!(selector.select_best(&HashMap::new()).is_none());
+}
+
+#[test]
+fn test_required_capital_prices_signals_at_limit_stop_or_market() {
+    let mut result = create_result("BTC/USD", 100.0, 0.8);
+    result.signals.push(TradeSignal { asset: "ETH/USD".to_string(), limit_price: None, stop_price: Some(50.0), quantity: 2.0, ..result.signals[0].clone() });
+    result.signals.push(TradeSignal { asset: "SOL/USD".to_string(), limit_price: None, quantity: 3.0, ..result.signals[0].clone() });
+    result.signals.push(TradeSignal { asset: "XRP/USD".to_string(), limit_price: None, direction: TradeDirection::Sell, ..result.signals[0].clone() });
+    
+    let mut market_data = MarketData { timestamp: Utc::now(), asset_data: HashMap::new() };
+    assert_eq!(result.required_capital(&market_data), 1100.0);
+    
+    market_data.asset_data.insert("SOL/USD".to_string(), AssetData {
+        symbol: "SOL/USD".to_string(),
+        asset_type: AssetType::Crypto,
+        price: Price::from(20.0),
+        volume: 0.0,
+        bid: Price::from(20.0),
+        ask: Price::from(20.0),
+        tick_size: None,
+        lot_size: None,
+        exchange: "Test".to_string(),
+        last_update: Utc::now(),
+    });
+    assert_eq!(result.required_capital(&market_data), 1160.0);
+}
+
+#[test]
+fn test_select_best_per_capital_skips_strategies_needing_none() {
+    let selector = StrategySelector::default();
+    let mut results = create_results();
+    results.insert("Idle".to_string(), StrategyResult { signals: Vec::new(), ..create_result("SOL/USD", 10.0, 0.9) });
+    let capital = HashMap::from([
+        ("Aggressive".to_string(), 100_000.0),
+        ("Cautious".to_string(), 1_000.0),
+        ("Idle".to_string(), 0.0),
+    ]);
+    
+    assert_eq!(selector.select_best(&results).as_deref(), Some("Aggressive"));
+    assert_eq!(selector.select_best_per_capital(&results, &capital).as_deref(), Some("Cautious"));
+    assert_eq!(selector.select_best_per_capital(&results, &HashMap::new()), None);
+}
@@ -0,0 +1,109 @@
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    AssetData, AssetType, MarketData, Strategy, StrategyManager, StrategyParams, StrategyResult,
+    TradeDirection, TradeSignal, TimeInForce
+};
+use arb_platform::models::Price;
+
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+
+// Buys every symbol it is shown
+struct BuyEverythingStrategy;
+
+impl Strategy for BuyEverythingStrategy {
+    fn name(&self) -> &str {
+        "Buy Everything"
+    }
+    
+    fn description(&self) -> &str {
+        "Signals a buy for each symbol in the market data"
+    }
+    
+    fn asset_types(&self) -> Vec<AssetType> {
+        vec![AssetType::Crypto]
+    }
+    
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        StrategyResult {
+            signals: market_data.asset_data.keys().map(|symbol| TradeSignal {
+                asset: symbol.clone(),
+                direction: TradeDirection::Buy,
+                quantity: 1.0,
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                priority: TimeInForce::Day.default_signal_priority(),
+            }).collect(),
+            confidence: 0.8,
+            expected_profit: 1.0,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+    
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+}
+
+fn create_market_data(ages_secs: &[(&str, i64)]) -> MarketData {
+    let now = Utc::now();
+    let asset_data = ages_secs.iter().map(|(symbol, age)| {
+        (symbol.to_string(), AssetData {
+            symbol: symbol.to_string(),
+            asset_type: AssetType::Crypto,
+            price: Price::from(100.0),
+            volume: 10.0,
+            bid: Price::from(99.9),
+            ask: Price::from(100.1),
+            tick_size: None,
+            lot_size: None,
+            exchange: "Test".to_string(),
+            last_update: now - Duration::seconds(*age),
+        })
+    }).collect::<HashMap<_, _>>();
+    
+    MarketData { timestamp: now, asset_data }
+}
+
+fn signalled_assets(result: &StrategyResult) -> Vec<String> {
+    let mut assets: Vec<String> = result.signals.iter().map(|s| s.asset.clone()).collect();
+    assets.sort();
+    assets
+}
+
+#[test]
+fn test_stale_symbols_are_reported() {
+    let data = create_market_data(&[("BTC/USD", 5), ("ETH/USD", 120)]);
+    
+    assert_eq!(data.stale_symbols(Duration::seconds(60), Utc::now()), vec!["ETH/USD".to_string()]);
+    assert!(data.stale_symbols(Duration::seconds(300), Utc::now()).is_empty());
+}
+
+#[test]
+fn test_stale_symbols_are_excluded_from_evaluation() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(BuyEverythingStrategy));
+    manager.set_stale_data_threshold(Some(Duration::seconds(30)));
+    
+    let data = create_market_data(&[("BTC/USD", 5), ("ETH/USD", 31)]);
+    
+    let results = manager.evaluate_strategies(&data);
+    assert_eq!(signalled_assets(&results["Buy Everything"]), vec!["BTC/USD"]);
+    
+    let result = manager.evaluate_one("Buy Everything", &data).unwrap();
+    assert_eq!(signalled_assets(&result), vec!["BTC/USD"]);
+}
+
+#[test]
+fn test_staleness_check_can_be_disabled() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(BuyEverythingStrategy));
+    manager.set_stale_data_threshold(None);
+    
+    let data = create_market_data(&[("BTC/USD", 5), ("ETH/USD", 3600)]);
+    
+    let results = manager.evaluate_strategies(&data);
+    assert_eq!(signalled_assets(&results["Buy Everything"]), vec!["BTC/USD", "ETH/USD"]);
+}
@@ -0,0 +1,153 @@
+use arb_platform::error::TradingError;
+use arb_platform::strategy::{
+    AssetData, AssetType, MarketData, StatisticalArbitrageStrategy, Strategy, StrategyManager, StrategyParams,
+    StrategyResult, TradeDirection, TradeSignal, TimeInForce
+};
+use arb_platform::models::Price;
+
+use chrono::Utc;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Buys BTC on every evaluation, but only counts as ready after `required` of them
+struct SlowStartStrategy {
+    required: usize,
+    seen: AtomicUsize,
+}
+
+impl Strategy for SlowStartStrategy {
+    fn name(&self) -> &str {
+        "Slow Start"
+    }
+
+    fn description(&self) -> &str {
+        "Signals a buy once it has seen enough updates"
+    }
+
+    fn asset_types(&self) -> Vec<AssetType> {
+        vec![AssetType::Crypto]
+    }
+
+    fn evaluate(&self, market_data: &MarketData) -> StrategyResult {
+        self.seen.fetch_add(1, Ordering::Relaxed);
+        StrategyResult {
+            signals: vec![TradeSignal {
+                asset: "BTC/USD".to_string(),
+                direction: TradeDirection::Buy,
+                quantity: 1.0,
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                priority: TimeInForce::Day.default_signal_priority(),
+            }],
+            confidence: 0.8,
+            expected_profit: 1.0,
+            timestamp: market_data.timestamp,
+            warming_up: false,
+        }
+    }
+
+    fn update_params(&mut self, _params: StrategyParams) -> Result<(), TradingError> {
+        Ok(())
+    }
+
+    fn is_warmed_up(&self) -> bool {
+        self.seen.load(Ordering::Relaxed) >= self.required
+    }
+}
+
+fn create_market_data(prices: &[(&str, f64)]) -> MarketData {
+    let now = Utc::now();
+    let asset_data = prices.iter().map(|(symbol, price)| {
+        (symbol.to_string(), AssetData {
+            symbol: symbol.to_string(),
+            asset_type: AssetType::Stock,
+            price: Price::from(*price),
+            volume: 1000.0,
+            bid: Price::from(price - 0.01),
+            ask: Price::from(price + 0.01),
+            tick_size: None,
+            lot_size: None,
+            exchange: "Test".to_string(),
+            last_update: now,
+        })
+    }).collect::<HashMap<_, _>>();
+
+    MarketData { timestamp: now, asset_data }
+}
+
+fn stat_arb(lookback_period: usize) -> StatisticalArbitrageStrategy {
+    let mut strategy = StatisticalArbitrageStrategy::new();
+    strategy.update_params(StrategyParams {
+        params: HashMap::from([
+            ("lookback_period".to_string(), json!(lookback_period)),
+            ("z_score_threshold".to_string(), json!(2.0)),
+            ("pairs".to_string(), json!([["AAA", "BBB"]])),
+        ]),
+    }).unwrap();
+    strategy
+}
+
+#[test]
+fn test_signals_are_held_back_until_the_strategy_is_warmed_up() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(SlowStartStrategy { required: 3, seen: AtomicUsize::new(0) }));
+    let data = create_market_data(&[("BTC/USD", 100.0)]);
+
+    let result = &manager.evaluate_strategies(&data)["Slow Start"];
+    assert!(result.warming_up);
+    assert!(result.signals.is_empty());
+    assert_eq!(result.confidence, 0.0);
+    assert!(manager.get_aggregated_signals(&data).is_empty());
+
+    // The evaluation that completes the warm-up already counts
+    let result = manager.evaluate_one("Slow Start", &data).unwrap();
+    assert!(!result.warming_up);
+    assert_eq!(result.signals.len(), 1);
+    assert_eq!(manager.get_aggregated_signals(&data).len(), 1);
+}
+
+#[test]
+fn test_stat_arb_warms_up_once_its_spread_window_is_full() {
+    let strategy = stat_arb(5);
+    assert!(!strategy.is_warmed_up());
+
+    for price in [100.0, 101.0, 99.0, 100.0] {
+        strategy.evaluate(&create_market_data(&[("AAA", price), ("BBB", 100.0)]));
+        assert!(!strategy.is_warmed_up());
+    }
+    strategy.evaluate(&create_market_data(&[("AAA", 101.0), ("BBB", 100.0)]));
+    assert!(strategy.is_warmed_up());
+
+    // A longer window has to fill again
+    let mut strategy = strategy;
+    strategy.update_params(StrategyParams {
+        params: HashMap::from([("lookback_period".to_string(), json!(6))]),
+    }).unwrap();
+    assert!(!strategy.is_warmed_up());
+}
+
+#[test]
+fn test_stat_arb_signals_only_after_the_required_updates() {
+    let mut manager = StrategyManager::new();
+    manager.register_strategy(Box::new(stat_arb(5)));
+
+    // A spread far outside the window so far would signal, but the window is short
+    for price in [100.0, 101.0, 99.0, 130.0] {
+        let result = manager.evaluate_one("Statistical Arbitrage", &create_market_data(&[("AAA", price), ("BBB", 100.0)])).unwrap();
+        assert!(result.warming_up, "at {}", price);
+        assert!(result.signals.is_empty(), "at {}", price);
+    }
+
+    let result = manager.evaluate_one("Statistical Arbitrage", &create_market_data(&[("AAA", 100.0), ("BBB", 100.0)])).unwrap();
+    assert!(!result.warming_up);
+    assert!(result.signals.is_empty());
+
+    // Spread well above the window's mean: sell the rich leg, buy the cheap one
+    let result = manager.evaluate_one("Statistical Arbitrage", &create_market_data(&[("AAA", 160.0), ("BBB", 100.0)])).unwrap();
+    assert!(!result.warming_up);
+    let mut sides: Vec<(String, TradeDirection)> = result.signals.iter().map(|s| (s.asset.clone(), s.direction)).collect();
+    sides.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(sides, vec![("AAA".to_string(), TradeDirection::Sell), ("BBB".to_string(), TradeDirection::Buy)]);
+}
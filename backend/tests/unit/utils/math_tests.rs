@@ -0,0 +1,28 @@
+use arb_platform::utils::math::{ema, ema_series, round_down_to_lot};
+
+#[test]
+fn test_ema_series_follows_recurrence() {
+    // Period 3 gives k = 0.5
+    assert_eq!(ema_series(&[1.0, 2.0, 3.0, 5.0], 3), vec![1.0, 1.5, 2.25, 3.625]);
+    // Period 1 tracks the values exactly
+    assert_eq!(ema_series(&[4.0, 8.0], 1), vec![4.0, 8.0]);
+}
+
+#[test]
+fn test_ema_edge_cases() {
+    assert!(ema_series(&[], 5).is_empty());
+    assert!(ema_series(&[1.0, 2.0], 0).is_empty());
+    assert_eq!(ema(&[], 5), None);
+    assert_eq!(ema(&[1.0, 2.0, 3.0, 5.0], 3), Some(3.625));
+}
+
+#[test]
+fn test_round_down_to_lot_gives_exact_multiples() {
+    assert_eq!(round_down_to_lot(0.37, 0.1), 0.3);
+    assert_eq!(round_down_to_lot(312.5, 100.0), 300.0);
+    assert_eq!(round_down_to_lot(500.0, 100.0), 500.0);
+    assert_eq!(round_down_to_lot(0.05, 0.1), 0.0);
+    // No usable lot size leaves the quantity alone
+    assert_eq!(round_down_to_lot(0.37, 0.0), 0.37);
+    assert_eq!(round_down_to_lot(0.37, f64::NAN), 0.37);
+}
@@ -0,0 +1,3 @@
+// Utils module tests
+pub mod math_tests;
+pub mod text_tests;
@@ -0,0 +1,16 @@
+use arb_platform::utils::text::{name_key, to_snake_case};
+
+#[test]
+fn test_to_snake_case() {
+    assert_eq!(to_snake_case("PartiallyFilled"), "partially_filled");
+    assert_eq!(to_snake_case("GoodTilCancelled"), "good_til_cancelled");
+    assert_eq!(to_snake_case("Buy"), "buy");
+    assert_eq!(to_snake_case(""), "");
+}
+
+#[test]
+fn test_name_key_ignores_case_and_separators() {
+    assert_eq!(name_key("PartiallyFilled"), "partiallyfilled");
+    assert_eq!(name_key("partially_filled"), "partiallyfilled");
+    assert_eq!(name_key(" Stop-Limit "), "stoplimit");
+}
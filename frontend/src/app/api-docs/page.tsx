@@ -274,7 +274,7 @@ export default function ApiDocs() {
               filled_quantity: 0.0,
               price: 34500.0,
               stop_price: null,
-              time_in_force: 'goodtilcanceled',
+              time_in_force: 'good_til_cancelled',
               status: 'submitted',
               exchange: 'Binance',
               created_at: '2023-06-15T12:30:45Z',
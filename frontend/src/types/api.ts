@@ -39,8 +39,8 @@ export interface StrategyEvaluation {
 // ======= Order Types =======
 
 export type OrderDirection = 'buy' | 'sell';
-export type OrderType = 'market' | 'limit' | 'stop' | 'stoplimit' | 'trailingstop';
-export type OrderStatus = 'created' | 'pendingsubmission' | 'submitted' | 'partiallyfilled' | 'filled' | 'cancelled' | 'rejected' | 'failed';
+export type OrderType = 'market' | 'limit' | 'stop_loss' | 'stop_limit' | 'trailing_stop';
+export type OrderStatus = 'created' | 'pending_submission' | 'submitted' | 'partially_filled' | 'filled' | 'cancelled' | 'rejected' | 'failed';
 export type TimeInForce = 'gtc' | 'ioc' | 'fok' | 'day';
 
 export interface OrderRequest {
@@ -113,6 +113,16 @@ export interface Position {
   unrealized_pnl: number;
   realized_pnl: number;
   timestamp: string;
+  strategy_id?: string | null;
+  opened_at?: string | null;
+}
+
+export interface StrategyPnl {
+  realized_pnl: number;
+  unrealized_pnl: number;
+  trade_count: number;
+  win_count: number;
+  avg_holding_duration_secs: number;
 }
 
 // ======= Backtest Types =======